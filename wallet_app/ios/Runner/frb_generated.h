@@ -24,6 +24,37 @@ typedef struct wire_cst_list_prim_u_8_strict {
   int32_t len;
 } wire_cst_list_prim_u_8_strict;
 
+typedef struct wire_cst_list_prim_u_16_strict {
+  uint16_t *ptr;
+  int32_t len;
+} wire_cst_list_prim_u_16_strict;
+
+typedef struct wire_cst_list_list_prim_u_16_strict {
+  struct wire_cst_list_prim_u_16_strict **ptr;
+  int32_t len;
+} wire_cst_list_list_prim_u_16_strict;
+
+typedef struct wire_cst_list_opt_box_autoadd_u_16 {
+  uint16_t **ptr;
+  int32_t len;
+} wire_cst_list_opt_box_autoadd_u_16;
+
+typedef struct wire_cst_list_String {
+  struct wire_cst_list_prim_u_8_strict **ptr;
+  int32_t len;
+} wire_cst_list_String;
+
+typedef struct wire_cst_disclosure_attribute_identifier {
+  uint16_t attestation_option_index;
+  uint16_t attestation_index;
+  struct wire_cst_list_String *attribute_key;
+} wire_cst_disclosure_attribute_identifier;
+
+typedef struct wire_cst_list_disclosure_attribute_identifier {
+  struct wire_cst_disclosure_attribute_identifier *ptr;
+  int32_t len;
+} wire_cst_list_disclosure_attribute_identifier;
+
 typedef struct wire_cst_AttestationIdentity_Fixed {
   struct wire_cst_list_prim_u_8_strict *id;
 } wire_cst_AttestationIdentity_Fixed;
@@ -207,6 +238,23 @@ typedef struct wire_cst_list_attestation_attribute {
   int32_t len;
 } wire_cst_list_attestation_attribute;
 
+typedef struct wire_cst_svg_template_properties {
+  int32_t *orientation;
+  int32_t *color_scheme;
+  int32_t *contrast;
+} wire_cst_svg_template_properties;
+
+typedef struct wire_cst_rendered_svg_template {
+  struct wire_cst_list_prim_u_8_strict *locale;
+  struct wire_cst_svg_template_properties *properties;
+  uintptr_t svg;
+} wire_cst_rendered_svg_template;
+
+typedef struct wire_cst_list_rendered_svg_template {
+  struct wire_cst_rendered_svg_template *ptr;
+  int32_t len;
+} wire_cst_list_rendered_svg_template;
+
 typedef struct wire_cst_attestation_presentation {
   struct wire_cst_attestation_identity identity;
   int32_t format;
@@ -216,6 +264,7 @@ typedef struct wire_cst_attestation_presentation {
   int32_t *revocation_status;
   struct wire_cst_validity_status validity_status;
   struct wire_cst_list_attestation_attribute *attributes;
+  struct wire_cst_list_rendered_svg_template *svg_templates;
 } wire_cst_attestation_presentation;
 
 typedef struct wire_cst_record_string_string {
@@ -331,6 +380,16 @@ typedef struct wire_cst_list_attestation_presentation {
   int32_t len;
 } wire_cst_list_attestation_presentation;
 
+typedef struct wire_cst_disclosure_credential_set {
+  bool required;
+  struct wire_cst_list_list_prim_u_16_strict *options;
+} wire_cst_disclosure_credential_set;
+
+typedef struct wire_cst_list_disclosure_credential_set {
+  struct wire_cst_disclosure_credential_set *ptr;
+  int32_t len;
+} wire_cst_list_disclosure_credential_set;
+
 typedef struct wire_cst_disclosure_options {
   struct wire_cst_list_attestation_presentation *field0;
 } wire_cst_disclosure_options;
@@ -359,11 +418,6 @@ typedef struct wire_cst_list_pid_attestation {
   int32_t len;
 } wire_cst_list_pid_attestation;
 
-typedef struct wire_cst_list_prim_u_16_strict {
-  uint16_t *ptr;
-  int32_t len;
-} wire_cst_list_prim_u_16_strict;
-
 typedef struct wire_cst_record_i_32_notification_type {
   int32_t field0;
   struct wire_cst_notification_type field1;
@@ -432,6 +486,24 @@ typedef struct wire_cst_accept_disclosure_result {
   union AcceptDisclosureResultKind kind;
 } wire_cst_accept_disclosure_result;
 
+typedef struct wire_cst_AcceptIssuanceResult_Ok {
+  bool is_deferred;
+} wire_cst_AcceptIssuanceResult_Ok;
+
+typedef struct wire_cst_AcceptIssuanceResult_InstructionError {
+  struct wire_cst_wallet_instruction_error *error;
+} wire_cst_AcceptIssuanceResult_InstructionError;
+
+typedef union AcceptIssuanceResultKind {
+  struct wire_cst_AcceptIssuanceResult_Ok Ok;
+  struct wire_cst_AcceptIssuanceResult_InstructionError InstructionError;
+} AcceptIssuanceResultKind;
+
+typedef struct wire_cst_accept_issuance_result {
+  int32_t tag;
+  union AcceptIssuanceResultKind kind;
+} wire_cst_accept_issuance_result;
+
 typedef struct wire_cst_CloseProximityDisclosureFlutterUpdate_Errored {
   struct wire_cst_list_prim_u_8_strict *error;
 } wire_cst_CloseProximityDisclosureFlutterUpdate_Errored;
@@ -550,6 +622,9 @@ typedef struct wire_cst_StartDisclosureResult_Request {
   struct wire_cst_list_localized_string *request_purpose;
   struct wire_cst_list_prim_u_8_strict *request_origin_base_url;
   int32_t request_type;
+  struct wire_cst_list_prim_u_16_strict *multiple_selection_indices;
+  struct wire_cst_list_disclosure_credential_set *credential_sets;
+  struct wire_cst_list_disclosure_attribute_identifier *optional_attributes;
 } wire_cst_StartDisclosureResult_Request;
 
 typedef struct wire_cst_StartDisclosureResult_RequestAttributesMissing {
@@ -590,6 +665,17 @@ void frbgen_wallet_core_wire__crate__api__full__accept_disclosure(int64_t port_,
                                                                   struct wire_cst_list_prim_u_16_loose *selected_indices,
                                                                   struct wire_cst_list_prim_u_8_strict *pin);
 
+void frbgen_wallet_core_wire__crate__api__full__accept_disclosure_with_deselected_attributes(int64_t port_,
+                                                                                             struct wire_cst_list_list_prim_u_16_strict *selected_indices,
+                                                                                             struct wire_cst_list_opt_box_autoadd_u_16 *selected_credential_set_options,
+                                                                                             struct wire_cst_list_disclosure_attribute_identifier *deselected_attributes,
+                                                                                             struct wire_cst_list_prim_u_8_strict *pin);
+
+void frbgen_wallet_core_wire__crate__api__full__accept_disclosure_with_multiple_selection(int64_t port_,
+                                                                                          struct wire_cst_list_list_prim_u_16_strict *selected_indices,
+                                                                                          struct wire_cst_list_opt_box_autoadd_u_16 *selected_credential_set_options,
+                                                                                          struct wire_cst_list_prim_u_8_strict *pin);
+
 void frbgen_wallet_core_wire__crate__api__full__accept_issuance(int64_t port_,
                                                                 struct wire_cst_list_prim_u_8_strict *pin);
 
@@ -746,6 +832,10 @@ void frbgen_wallet_core_wire__crate__api__full__start_disclosure(int64_t port_,
 void frbgen_wallet_core_wire__crate__api__full__start_issuance_from_offer(int64_t port_,
                                                                           struct wire_cst_list_prim_u_8_strict *offer_uri);
 
+void frbgen_wallet_core_wire__crate__api__full__start_issuance_from_offer_with_transaction_code(int64_t port_,
+                                                                                                struct wire_cst_list_prim_u_8_strict *offer_uri,
+                                                                                                struct wire_cst_list_prim_u_8_strict *transaction_code);
+
 void frbgen_wallet_core_wire__crate__api__full__transfer_wallet(int64_t port_);
 
 void frbgen_wallet_core_wire__crate__api__full__unlock_wallet(int64_t port_,
@@ -773,12 +863,24 @@ struct wire_cst_request_policy *frbgen_wallet_core_cst_new_box_autoadd_request_p
 
 int32_t *frbgen_wallet_core_cst_new_box_autoadd_revocation_status(int32_t value);
 
+int32_t *frbgen_wallet_core_cst_new_box_autoadd_svg_template_color_scheme(int32_t value);
+
+int32_t *frbgen_wallet_core_cst_new_box_autoadd_svg_template_contrast(int32_t value);
+
+int32_t *frbgen_wallet_core_cst_new_box_autoadd_svg_template_orientation(int32_t value);
+
+struct wire_cst_svg_template_properties *frbgen_wallet_core_cst_new_box_autoadd_svg_template_properties(void);
+
+uint16_t *frbgen_wallet_core_cst_new_box_autoadd_u_16(uint16_t value);
+
 uint64_t *frbgen_wallet_core_cst_new_box_autoadd_u_64(uint64_t value);
 
 struct wire_cst_wallet_instruction_error *frbgen_wallet_core_cst_new_box_autoadd_wallet_instruction_error(void);
 
 struct wire_cst_wallet_state *frbgen_wallet_core_cst_new_box_wallet_state(void);
 
+struct wire_cst_list_String *frbgen_wallet_core_cst_new_list_String(int32_t len);
+
 struct wire_cst_list_app_notification *frbgen_wallet_core_cst_new_list_app_notification(int32_t len);
 
 struct wire_cst_list_attestation_attribute *frbgen_wallet_core_cst_new_list_attestation_attribute(int32_t len);
@@ -789,16 +891,24 @@ struct wire_cst_list_attribute_value *frbgen_wallet_core_cst_new_list_attribute_
 
 struct wire_cst_list_claim_display_metadata *frbgen_wallet_core_cst_new_list_claim_display_metadata(int32_t len);
 
+struct wire_cst_list_disclosure_attribute_identifier *frbgen_wallet_core_cst_new_list_disclosure_attribute_identifier(int32_t len);
+
+struct wire_cst_list_disclosure_credential_set *frbgen_wallet_core_cst_new_list_disclosure_credential_set(int32_t len);
+
 struct wire_cst_list_disclosure_options *frbgen_wallet_core_cst_new_list_disclosure_options(int32_t len);
 
 struct wire_cst_list_display_metadata *frbgen_wallet_core_cst_new_list_display_metadata(int32_t len);
 
 struct wire_cst_list_display_target *frbgen_wallet_core_cst_new_list_display_target(int32_t len);
 
+struct wire_cst_list_list_prim_u_16_strict *frbgen_wallet_core_cst_new_list_list_prim_u_16_strict(int32_t len);
+
 struct wire_cst_list_localized_string *frbgen_wallet_core_cst_new_list_localized_string(int32_t len);
 
 struct wire_cst_list_missing_attribute *frbgen_wallet_core_cst_new_list_missing_attribute(int32_t len);
 
+struct wire_cst_list_opt_box_autoadd_u_16 *frbgen_wallet_core_cst_new_list_opt_box_autoadd_u_16(int32_t len);
+
 struct wire_cst_list_pid_attestation *frbgen_wallet_core_cst_new_list_pid_attestation(int32_t len);
 
 struct wire_cst_list_prim_u_16_loose *frbgen_wallet_core_cst_new_list_prim_u_16_loose(int32_t len);
@@ -809,6 +919,8 @@ struct wire_cst_list_prim_u_8_strict *frbgen_wallet_core_cst_new_list_prim_u_8_s
 
 struct wire_cst_list_record_i_32_notification_type *frbgen_wallet_core_cst_new_list_record_i_32_notification_type(int32_t len);
 
+struct wire_cst_list_rendered_svg_template *frbgen_wallet_core_cst_new_list_rendered_svg_template(int32_t len);
+
 struct wire_cst_list_wallet_event *frbgen_wallet_core_cst_new_list_wallet_event(int32_t len);
 static int64_t dummy_method_to_enforce_bundling(void) {
     int64_t dummy_var = 0;
@@ -820,28 +932,41 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_box_autoadd_rendering_metadata);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_box_autoadd_request_policy);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_box_autoadd_revocation_status);
+    dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_box_autoadd_svg_template_color_scheme);
+    dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_box_autoadd_svg_template_contrast);
+    dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_box_autoadd_svg_template_orientation);
+    dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_box_autoadd_svg_template_properties);
+    dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_box_autoadd_u_16);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_box_autoadd_u_64);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_box_autoadd_wallet_instruction_error);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_box_wallet_state);
+    dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_list_String);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_list_app_notification);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_list_attestation_attribute);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_list_attestation_presentation);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_list_attribute_value);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_list_claim_display_metadata);
+    dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_list_disclosure_attribute_identifier);
+    dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_list_disclosure_credential_set);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_list_disclosure_options);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_list_display_metadata);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_list_display_target);
+    dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_list_list_prim_u_16_strict);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_list_localized_string);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_list_missing_attribute);
+    dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_list_opt_box_autoadd_u_16);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_list_pid_attestation);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_list_prim_u_16_loose);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_list_prim_u_16_strict);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_list_prim_u_8_strict);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_list_record_i_32_notification_type);
+    dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_list_rendered_svg_template);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_cst_new_list_wallet_event);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSanitizedSvg);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSanitizedSvg);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_wire__crate__api__full__accept_disclosure);
+    dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_wire__crate__api__full__accept_disclosure_with_deselected_attributes);
+    dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_wire__crate__api__full__accept_disclosure_with_multiple_selection);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_wire__crate__api__full__accept_issuance);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_wire__crate__api__full__accept_pid_issuance);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_wire__crate__api__full__cancel_disclosure);
@@ -903,6 +1028,7 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_wire__crate__api__full__start_close_proximity_disclosure);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_wire__crate__api__full__start_disclosure);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_wire__crate__api__full__start_issuance_from_offer);
+    dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_wire__crate__api__full__start_issuance_from_offer_with_transaction_code);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_wire__crate__api__full__transfer_wallet);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_wire__crate__api__full__unlock_wallet);
     dummy_var ^= ((int64_t) (void*) frbgen_wallet_core_wire__crate__api__full__unlock_wallet_with_biometrics);
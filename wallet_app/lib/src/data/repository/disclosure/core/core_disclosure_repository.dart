@@ -80,6 +80,42 @@ class CoreDisclosureRepository implements DisclosureRepository {
   @override
   Future<String?> acceptDisclosure(String pin, List<int> selectedIndices) async {
     final result = await _walletCore.acceptDisclosure(pin, selectedIndices);
+    return _processAcceptDisclosureResult(result);
+  }
+
+  @override
+  Future<String?> acceptDisclosureWithSelection(
+    String pin,
+    List<List<int>> selectedIndices,
+    List<int?> selectedCredentialSetOptions, {
+    List<DisclosureAttributeIdentifier> deselectedAttributes = const [],
+  }) async {
+    if (deselectedAttributes.isEmpty) {
+      final result = await _walletCore.acceptDisclosureWithMultipleSelection(
+        pin,
+        selectedIndices,
+        selectedCredentialSetOptions,
+      );
+      return _processAcceptDisclosureResult(result);
+    }
+    final result = await _walletCore.acceptDisclosureWithDeselectedAttributes(
+      pin,
+      selectedIndices,
+      selectedCredentialSetOptions,
+      deselectedAttributes.map(_toCoreAttributeIdentifier).toList(),
+    );
+    return _processAcceptDisclosureResult(result);
+  }
+
+  core.DisclosureAttributeIdentifier _toCoreAttributeIdentifier(DisclosureAttributeIdentifier identifier) {
+    return core.DisclosureAttributeIdentifier(
+      attestationOptionIndex: identifier.attestationOptionIndex,
+      attestationIndex: identifier.attestationIndex,
+      attributeKey: identifier.attributeKey,
+    );
+  }
+
+  String? _processAcceptDisclosureResult(core.AcceptDisclosureResult result) {
     switch (result) {
       case core.AcceptDisclosureResult_Ok():
         return result.returnUrl;
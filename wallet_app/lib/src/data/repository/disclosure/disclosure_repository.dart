@@ -1,5 +1,7 @@
+import '../../../domain/model/disclosure/disclosure_attribute_identifier.dart';
 import '../../../domain/model/disclosure/start_disclosure_result.dart';
 
+export '../../../domain/model/disclosure/disclosure_attribute_identifier.dart';
 export '../../../domain/model/disclosure/start_disclosure_result.dart';
 
 abstract class DisclosureRepository {
@@ -8,4 +10,13 @@ abstract class DisclosureRepository {
   Future<StartDisclosureResult> continueCloseProximityDisclosure();
 
   Future<String?> acceptDisclosure(String pin, List<int> selectedIndices);
+
+  /// Accept the disclosure request with (possibly) multiple selected cards per disclosure option, a chosen option
+  /// per credential set and, optionally, the [deselectedAttributes] that should not be shared.
+  Future<String?> acceptDisclosureWithSelection(
+    String pin,
+    List<List<int>> selectedIndices,
+    List<int?> selectedCredentialSetOptions, {
+    List<DisclosureAttributeIdentifier> deselectedAttributes = const [],
+  });
 }
@@ -72,8 +72,14 @@ class CoreIssuanceRepository implements IssuanceRepository {
   }
 
   @override
-  Future<StartIssuanceResult> startIssuanceFromOffer(String issuanceUri, {bool isQrCode = false}) async {
-    final result = await _walletCore.startIssuanceFromOffer(issuanceUri);
+  Future<StartIssuanceResult> startIssuanceFromOffer(
+    String issuanceUri, {
+    bool isQrCode = false,
+    String? transactionCode,
+  }) async {
+    final result = transactionCode == null
+        ? await _walletCore.startIssuanceFromOffer(issuanceUri)
+        : await _walletCore.startIssuanceFromOfferWithTransactionCode(issuanceUri, transactionCode);
     switch (result) {
       case core.IssuanceStartResult_AuthorizationUrl(field0: final authUrl):
         return StartIssuanceAuthorizationRequired(authUrl);
@@ -104,9 +110,9 @@ class CoreIssuanceRepository implements IssuanceRepository {
     // [cards] are currently unused, these will become relevant when we implement selective issuance.
     final result = await _walletCore.acceptIssuance(pin);
     switch (result) {
-      case core.WalletInstructionResult_Ok():
+      case core.AcceptIssuanceResult_Ok():
         return;
-      case core.WalletInstructionResult_InstructionError():
+      case core.AcceptIssuanceResult_InstructionError():
         throw result.error;
     }
   }
@@ -6,7 +6,11 @@ export '../../../domain/model/disclosure/start_disclosure_result.dart';
 abstract class IssuanceRepository {
   Future<StartIssuanceResult> startIssuance(String disclosureUri, {required bool isQrCode});
 
-  Future<StartIssuanceResult> startIssuanceFromOffer(String disclosureUri, {required bool isQrCode});
+  Future<StartIssuanceResult> startIssuanceFromOffer(
+    String disclosureUri, {
+    required bool isQrCode,
+    String? transactionCode,
+  });
 
   Future<List<WalletCard>> discloseForIssuance(String pin, List<int> selectedIndices);
 
@@ -27,6 +27,8 @@ sealed class CardStatus with _$CardStatus {
 
   const factory CardStatus.revoked() = CardStatusRevoked;
 
+  const factory CardStatus.suspended() = CardStatusSuspended;
+
   const factory CardStatus.corrupted() = CardStatusCorrupted;
 
   const factory CardStatus.undetermined() = CardStatusUndetermined;
@@ -34,6 +34,10 @@ CardStatus _$CardStatusFromJson(
                 case 'revoked':
           return CardStatusRevoked.fromJson(
             json
+          );
+                case 'suspended':
+          return CardStatusSuspended.fromJson(
+            json
           );
                 case 'corrupted':
           return CardStatusCorrupted.fromJson(
@@ -101,7 +105,7 @@ extension CardStatusPatterns on CardStatus {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( CardStatusValidSoon value)?  validSoon,TResult Function( CardStatusValid value)?  valid,TResult Function( CardStatusExpiresSoon value)?  expiresSoon,TResult Function( CardStatusExpired value)?  expired,TResult Function( CardStatusRevoked value)?  revoked,TResult Function( CardStatusCorrupted value)?  corrupted,TResult Function( CardStatusUndetermined value)?  undetermined,required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( CardStatusValidSoon value)?  validSoon,TResult Function( CardStatusValid value)?  valid,TResult Function( CardStatusExpiresSoon value)?  expiresSoon,TResult Function( CardStatusExpired value)?  expired,TResult Function( CardStatusRevoked value)?  revoked,TResult Function( CardStatusSuspended value)?  suspended,TResult Function( CardStatusCorrupted value)?  corrupted,TResult Function( CardStatusUndetermined value)?  undetermined,required TResult orElse(),}){
 final _that = this;
 switch (_that) {
 case CardStatusValidSoon() when validSoon != null:
@@ -109,7 +113,8 @@ return validSoon(_that);case CardStatusValid() when valid != null:
 return valid(_that);case CardStatusExpiresSoon() when expiresSoon != null:
 return expiresSoon(_that);case CardStatusExpired() when expired != null:
 return expired(_that);case CardStatusRevoked() when revoked != null:
-return revoked(_that);case CardStatusCorrupted() when corrupted != null:
+return revoked(_that);case CardStatusSuspended() when suspended != null:
+return suspended(_that);case CardStatusCorrupted() when corrupted != null:
 return corrupted(_that);case CardStatusUndetermined() when undetermined != null:
 return undetermined(_that);case _:
   return orElse();
@@ -129,7 +134,7 @@ return undetermined(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( CardStatusValidSoon value)  validSoon,required TResult Function( CardStatusValid value)  valid,required TResult Function( CardStatusExpiresSoon value)  expiresSoon,required TResult Function( CardStatusExpired value)  expired,required TResult Function( CardStatusRevoked value)  revoked,required TResult Function( CardStatusCorrupted value)  corrupted,required TResult Function( CardStatusUndetermined value)  undetermined,}){
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( CardStatusValidSoon value)  validSoon,required TResult Function( CardStatusValid value)  valid,required TResult Function( CardStatusExpiresSoon value)  expiresSoon,required TResult Function( CardStatusExpired value)  expired,required TResult Function( CardStatusRevoked value)  revoked,required TResult Function( CardStatusSuspended value)  suspended,required TResult Function( CardStatusCorrupted value)  corrupted,required TResult Function( CardStatusUndetermined value)  undetermined,}){
 final _that = this;
 switch (_that) {
 case CardStatusValidSoon():
@@ -137,7 +142,8 @@ return validSoon(_that);case CardStatusValid():
 return valid(_that);case CardStatusExpiresSoon():
 return expiresSoon(_that);case CardStatusExpired():
 return expired(_that);case CardStatusRevoked():
-return revoked(_that);case CardStatusCorrupted():
+return revoked(_that);case CardStatusSuspended():
+return suspended(_that);case CardStatusCorrupted():
 return corrupted(_that);case CardStatusUndetermined():
 return undetermined(_that);}
 }
@@ -153,7 +159,7 @@ return undetermined(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( CardStatusValidSoon value)?  validSoon,TResult? Function( CardStatusValid value)?  valid,TResult? Function( CardStatusExpiresSoon value)?  expiresSoon,TResult? Function( CardStatusExpired value)?  expired,TResult? Function( CardStatusRevoked value)?  revoked,TResult? Function( CardStatusCorrupted value)?  corrupted,TResult? Function( CardStatusUndetermined value)?  undetermined,}){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( CardStatusValidSoon value)?  validSoon,TResult? Function( CardStatusValid value)?  valid,TResult? Function( CardStatusExpiresSoon value)?  expiresSoon,TResult? Function( CardStatusExpired value)?  expired,TResult? Function( CardStatusRevoked value)?  revoked,TResult? Function( CardStatusSuspended value)?  suspended,TResult? Function( CardStatusCorrupted value)?  corrupted,TResult? Function( CardStatusUndetermined value)?  undetermined,}){
 final _that = this;
 switch (_that) {
 case CardStatusValidSoon() when validSoon != null:
@@ -161,7 +167,8 @@ return validSoon(_that);case CardStatusValid() when valid != null:
 return valid(_that);case CardStatusExpiresSoon() when expiresSoon != null:
 return expiresSoon(_that);case CardStatusExpired() when expired != null:
 return expired(_that);case CardStatusRevoked() when revoked != null:
-return revoked(_that);case CardStatusCorrupted() when corrupted != null:
+return revoked(_that);case CardStatusSuspended() when suspended != null:
+return suspended(_that);case CardStatusCorrupted() when corrupted != null:
 return corrupted(_that);case CardStatusUndetermined() when undetermined != null:
 return undetermined(_that);case _:
   return null;
@@ -180,14 +187,15 @@ return undetermined(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( DateTime validFrom)?  validSoon,TResult Function( DateTime? validUntil)?  valid,TResult Function( DateTime validUntil)?  expiresSoon,TResult Function( DateTime validUntil)?  expired,TResult Function()?  revoked,TResult Function()?  corrupted,TResult Function()?  undetermined,required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( DateTime validFrom)?  validSoon,TResult Function( DateTime? validUntil)?  valid,TResult Function( DateTime validUntil)?  expiresSoon,TResult Function( DateTime validUntil)?  expired,TResult Function()?  revoked,TResult Function()?  suspended,TResult Function()?  corrupted,TResult Function()?  undetermined,required TResult orElse(),}) {final _that = this;
 switch (_that) {
 case CardStatusValidSoon() when validSoon != null:
 return validSoon(_that.validFrom);case CardStatusValid() when valid != null:
 return valid(_that.validUntil);case CardStatusExpiresSoon() when expiresSoon != null:
 return expiresSoon(_that.validUntil);case CardStatusExpired() when expired != null:
 return expired(_that.validUntil);case CardStatusRevoked() when revoked != null:
-return revoked();case CardStatusCorrupted() when corrupted != null:
+return revoked();case CardStatusSuspended() when suspended != null:
+return suspended();case CardStatusCorrupted() when corrupted != null:
 return corrupted();case CardStatusUndetermined() when undetermined != null:
 return undetermined();case _:
   return orElse();
@@ -207,14 +215,15 @@ return undetermined();case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( DateTime validFrom)  validSoon,required TResult Function( DateTime? validUntil)  valid,required TResult Function( DateTime validUntil)  expiresSoon,required TResult Function( DateTime validUntil)  expired,required TResult Function()  revoked,required TResult Function()  corrupted,required TResult Function()  undetermined,}) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( DateTime validFrom)  validSoon,required TResult Function( DateTime? validUntil)  valid,required TResult Function( DateTime validUntil)  expiresSoon,required TResult Function( DateTime validUntil)  expired,required TResult Function()  revoked,required TResult Function()  suspended,required TResult Function()  corrupted,required TResult Function()  undetermined,}) {final _that = this;
 switch (_that) {
 case CardStatusValidSoon():
 return validSoon(_that.validFrom);case CardStatusValid():
 return valid(_that.validUntil);case CardStatusExpiresSoon():
 return expiresSoon(_that.validUntil);case CardStatusExpired():
 return expired(_that.validUntil);case CardStatusRevoked():
-return revoked();case CardStatusCorrupted():
+return revoked();case CardStatusSuspended():
+return suspended();case CardStatusCorrupted():
 return corrupted();case CardStatusUndetermined():
 return undetermined();}
 }
@@ -230,14 +239,15 @@ return undetermined();}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( DateTime validFrom)?  validSoon,TResult? Function( DateTime? validUntil)?  valid,TResult? Function( DateTime validUntil)?  expiresSoon,TResult? Function( DateTime validUntil)?  expired,TResult? Function()?  revoked,TResult? Function()?  corrupted,TResult? Function()?  undetermined,}) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( DateTime validFrom)?  validSoon,TResult? Function( DateTime? validUntil)?  valid,TResult? Function( DateTime validUntil)?  expiresSoon,TResult? Function( DateTime validUntil)?  expired,TResult? Function()?  revoked,TResult? Function()?  suspended,TResult? Function()?  corrupted,TResult? Function()?  undetermined,}) {final _that = this;
 switch (_that) {
 case CardStatusValidSoon() when validSoon != null:
 return validSoon(_that.validFrom);case CardStatusValid() when valid != null:
 return valid(_that.validUntil);case CardStatusExpiresSoon() when expiresSoon != null:
 return expiresSoon(_that.validUntil);case CardStatusExpired() when expired != null:
 return expired(_that.validUntil);case CardStatusRevoked() when revoked != null:
-return revoked();case CardStatusCorrupted() when corrupted != null:
+return revoked();case CardStatusSuspended() when suspended != null:
+return suspended();case CardStatusCorrupted() when corrupted != null:
 return corrupted();case CardStatusUndetermined() when undetermined != null:
 return undetermined();case _:
   return null;
@@ -582,6 +592,45 @@ String toString() {
 
 
 
+/// @nodoc
+@JsonSerializable()
+
+class CardStatusSuspended implements CardStatus {
+  const CardStatusSuspended({final  String? $type}): $type = $type ?? 'suspended';
+  factory CardStatusSuspended.fromJson(Map<String, dynamic> json) => _$CardStatusSuspendedFromJson(json);
+
+
+
+@JsonKey(name: 'runtimeType')
+final String $type;
+
+
+
+@override
+Map<String, dynamic> toJson() {
+  return _$CardStatusSuspendedToJson(this, );
+}
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CardStatusSuspended);
+}
+
+@JsonKey(includeFromJson: false, includeToJson: false)
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'CardStatus.suspended()';
+}
+
+
+}
+
+
+
+
 /// @nodoc
 @JsonSerializable()
 
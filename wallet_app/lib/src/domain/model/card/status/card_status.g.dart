@@ -59,6 +59,13 @@ Map<String, dynamic> _$CardStatusRevokedToJson(CardStatusRevoked instance) => <S
   'runtimeType': instance.$type,
 };
 
+CardStatusSuspended _$CardStatusSuspendedFromJson(Map<String, dynamic> json) =>
+    CardStatusSuspended($type: json['runtimeType'] as String?);
+
+Map<String, dynamic> _$CardStatusSuspendedToJson(CardStatusSuspended instance) => <String, dynamic>{
+  'runtimeType': instance.$type,
+};
+
 CardStatusCorrupted _$CardStatusCorruptedFromJson(Map<String, dynamic> json) =>
     CardStatusCorrupted($type: json['runtimeType'] as String?);
 
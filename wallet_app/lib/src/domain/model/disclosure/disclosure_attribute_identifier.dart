@@ -0,0 +1,23 @@
+import 'package:equatable/equatable.dart';
+
+/// Identifies a single attribute of a candidate card in a disclosure request, e.g. an optional
+/// attribute that the user chose not to share.
+class DisclosureAttributeIdentifier extends Equatable {
+  /// The index of the disclosure option (i.e. the [DiscloseCardRequest]) the attribute belongs to.
+  final int attestationOptionIndex;
+
+  /// The index of the selected card within the candidates of that disclosure option.
+  final int attestationIndex;
+
+  /// The path to the attribute within the card, e.g. `['address', 'street']`.
+  final List<String> attributeKey;
+
+  const DisclosureAttributeIdentifier({
+    required this.attestationOptionIndex,
+    required this.attestationIndex,
+    required this.attributeKey,
+  });
+
+  @override
+  List<Object?> get props => [attestationOptionIndex, attestationIndex, attributeKey];
+}
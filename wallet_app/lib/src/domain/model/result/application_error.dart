@@ -103,6 +103,15 @@ class PreAuthorizedCodeExpiredError extends ApplicationError {
   const PreAuthorizedCodeExpiredError({required super.sourceError});
 }
 
+class TransactionCodeRequiredError extends ApplicationError {
+  final TransactionCodeData transactionCode;
+
+  const TransactionCodeRequiredError({required this.transactionCode, required super.sourceError});
+
+  @override
+  List<Object?> get props => [...super.props, transactionCode];
+}
+
 class CloseProximityDisconnectedError extends ApplicationError {
   const CloseProximityDisconnectedError({required super.sourceError});
 }
@@ -0,0 +1,37 @@
+import '../../../model/result/result.dart';
+import '../accept_disclosure_usecase.dart';
+
+/// Variant of [AcceptDisclosureUseCaseImpl] for disclosure requests that allow more than one card per disclosure
+/// option, contain credential sets to choose from, or have optional attributes the user chose not to share.
+class AcceptDisclosureWithSelectionUseCaseImpl extends AcceptDisclosureUseCase {
+  final DisclosureRepository _disclosureRepository;
+
+  /// The indices of the cards the user chose to disclose, one list per disclosure option.
+  final List<List<int>> selectedIndices;
+
+  /// The chosen option for every credential set, or `null` when an optional credential set is skipped.
+  final List<int?> selectedCredentialSetOptions;
+
+  /// The optional attributes that should not be shared with the relying party.
+  final List<DisclosureAttributeIdentifier> deselectedAttributes;
+
+  AcceptDisclosureWithSelectionUseCaseImpl(
+    this._disclosureRepository,
+    this.selectedIndices,
+    this.selectedCredentialSetOptions, {
+    this.deselectedAttributes = const [],
+  });
+
+  @override
+  Future<Result<String?>> invoke(String pin) async {
+    return tryCatch(
+      () async => _disclosureRepository.acceptDisclosureWithSelection(
+        pin,
+        selectedIndices,
+        selectedCredentialSetOptions,
+        deselectedAttributes: deselectedAttributes,
+      ),
+      'Failed to accept disclosure',
+    );
+  }
+}
@@ -14,6 +14,7 @@ class StartIssuanceUseCaseImpl extends StartIssuanceUseCase {
     String issuanceUri, {
     bool isQrCode = false,
     required IssuanceType type,
+    String? transactionCode,
   }) async {
     return tryCatch(
       () async {
@@ -21,7 +22,11 @@ class StartIssuanceUseCaseImpl extends StartIssuanceUseCase {
           case IssuanceType.disclosureBasedIssuance:
             return _issuanceRepository.startIssuance(issuanceUri, isQrCode: isQrCode);
           case IssuanceType.credentialOffer:
-            return _issuanceRepository.startIssuanceFromOffer(issuanceUri, isQrCode: isQrCode);
+            return _issuanceRepository.startIssuanceFromOffer(
+              issuanceUri,
+              isQrCode: isQrCode,
+              transactionCode: transactionCode,
+            );
           case IssuanceType.authorizationCallback:
             throw UnsupportedError('$type should rely on continueIssuance()');
         }
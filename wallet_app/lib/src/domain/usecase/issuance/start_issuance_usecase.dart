@@ -4,5 +4,12 @@ import '../../model/result/result.dart';
 import '../wallet_usecase.dart';
 
 abstract class StartIssuanceUseCase extends WalletUseCase {
-  Future<Result<StartIssuanceResult>> invoke(String issuanceUri, {bool isQrCode = false, required IssuanceType type});
+  /// Starts the issuance session for [issuanceUri]. When the credential offer requires a
+  /// transaction code, the caller retries with the [transactionCode] entered by the user.
+  Future<Result<StartIssuanceResult>> invoke(
+    String issuanceUri, {
+    bool isQrCode = false,
+    required IssuanceType type,
+    String? transactionCode,
+  });
 }
@@ -20,6 +20,7 @@ import '../../../domain/usecase/issuance/start_issuance_usecase.dart';
 import '../../../domain/usecase/session/cancel_session_usecase.dart';
 import '../../../util/cast_util.dart';
 import '../../../util/extension/list_extension.dart';
+import '../../../wallet_core/error/core_error.dart';
 import '../argument/issuance_screen_argument.dart';
 
 part 'issuance_event.dart';
@@ -32,6 +33,9 @@ class IssuanceBloc extends Bloc<IssuanceEvent, IssuanceState> {
 
   StartIssuanceResult? _startIssuanceResult;
 
+  /// The event that started the session, used to restart it once the user provides a transaction code.
+  IssuanceSessionStarted? _sessionStartedEvent;
+
   Organization? get relyingParty => _startIssuanceResult?.relyingParty;
 
   bool get isCrossDeviceFlow {
@@ -48,6 +52,7 @@ class IssuanceBloc extends Bloc<IssuanceEvent, IssuanceState> {
     this._cancelSessionUseCase,
   ) : super(const IssuanceInitial()) {
     on<IssuanceSessionStarted>(_onSessionStarted);
+    on<IssuanceTransactionCodeSubmitted>(_onTransactionCodeSubmitted);
     on<IssuanceBackPressed>(_onIssuanceBackPressed);
     on<IssuanceOrganizationApproved>(_onIssuanceOrganizationApproved);
     on<IssuanceShareRequestedAttributesDeclined>(_onIssuanceShareRequestedAttributesDeclined);
@@ -65,10 +70,27 @@ class IssuanceBloc extends Bloc<IssuanceEvent, IssuanceState> {
     // Cancel any potential ongoing (disclosure based) issuance session, needed for when the user taps an issuance
     // deeplink during an active issuance (or disclosure) session (e.g. by switching back to the browser).
     await _cancelSessionUseCase.invoke();
+    _sessionStartedEvent = event;
+    await _startIssuance(event, emit);
+  }
+
+  Future<void> _onTransactionCodeSubmitted(IssuanceTransactionCodeSubmitted event, Emitter<IssuanceState> emit) async {
+    final sessionStartedEvent = _sessionStartedEvent;
+    if (sessionStartedEvent == null) throw UnsupportedError('Can not submit transaction code before session start');
+    emit(IssuanceLoadInProgress(state.stepperProgress));
+    await _startIssuance(sessionStartedEvent, emit, transactionCode: event.transactionCode);
+  }
+
+  Future<void> _startIssuance(
+    IssuanceSessionStarted event,
+    Emitter<IssuanceState> emit, {
+    String? transactionCode,
+  }) async {
     final startResult = await _startIssuanceUseCase.invoke(
       event.issuanceUri,
       isQrCode: event.isQrCode,
       type: event.type,
+      transactionCode: transactionCode,
     );
 
     /// Handle [error]/[ready to disclose]/[missing attributes] cases.
@@ -247,6 +269,8 @@ class IssuanceBloc extends Bloc<IssuanceEvent, IssuanceState> {
         _handleSessionExpiredError(emit, error);
       case ExternalScannerError():
         emit(IssuanceExternalScannerError(error: error));
+      case TransactionCodeRequiredError():
+        emit(IssuanceProvideTransactionCode(transactionCode: error.transactionCode));
       default:
         // Call cancelSession to avoid stale session and to try and provide more context (e.g. returnUrl).
         final cancelResult = await _cancelSessionUseCase.invoke();
@@ -279,6 +303,7 @@ class IssuanceBloc extends Bloc<IssuanceEvent, IssuanceState> {
   @override
   Future<void> close() async {
     _startIssuanceResult = null;
+    _sessionStartedEvent = null;
     _cardRequestsSelectionCache = null;
     // If state == IssuanceAuthenticateWithIssuer this is the old (about to be replaced) IssuanceBloc.
     if (state is! IssuanceAuthenticateWithIssuer) await _cancelSessionUseCase.invoke();
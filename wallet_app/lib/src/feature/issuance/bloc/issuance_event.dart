@@ -26,6 +26,15 @@ class IssuanceSessionStarted extends IssuanceEvent {
   List<Object?> get props => [issuanceUri, isQrCode];
 }
 
+class IssuanceTransactionCodeSubmitted extends IssuanceEvent {
+  final String transactionCode;
+
+  const IssuanceTransactionCodeSubmitted(this.transactionCode);
+
+  @override
+  List<Object?> get props => [transactionCode];
+}
+
 class IssuanceSessionContinued extends IssuanceEvent {
   final String authorizationUri;
 
@@ -45,6 +45,18 @@ class IssuanceAuthenticateWithIssuer extends IssuanceState {
   List<Object?> get props => [authUrl, ...super.props];
 }
 
+class IssuanceProvideTransactionCode extends IssuanceState {
+  final TransactionCodeData transactionCode;
+
+  const IssuanceProvideTransactionCode({required this.transactionCode});
+
+  @override
+  FlowProgress get stepperProgress => const FlowProgress(currentStep: 1, totalSteps: kIssuanceSteps);
+
+  @override
+  List<Object?> get props => [transactionCode, ...super.props];
+}
+
 class IssuanceCheckOrganization extends IssuanceState {
   final Organization organization;
   final List<DiscloseCardRequest> cardRequests;
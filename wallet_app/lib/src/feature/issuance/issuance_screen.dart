@@ -47,6 +47,7 @@ import 'issuance_request_details_screen.dart';
 import 'issuance_stop_sheet.dart';
 import 'page/issuance_confirm_pin_for_disclosure_page.dart';
 import 'page/issuance_confirm_pin_for_issuance_page.dart';
+import 'page/issuance_provide_transaction_code_page.dart';
 import 'page/issuance_review_cards_page.dart';
 import 'page/issuance_stopped_page.dart';
 import 'page/issuance_success_page.dart';
@@ -125,6 +126,7 @@ class IssuanceScreen extends StatelessWidget {
           IssuanceInitial() => _buildLoadingRequestPage(context),
           IssuanceLoadInProgress() => _buildLoadingCardsPage(context),
           IssuanceAuthenticateWithIssuer(:final authUrl) => _buildAuthenticateWithIssuer(context, authUrl),
+          IssuanceProvideTransactionCode() => _buildProvideTransactionCodePage(context, state),
           IssuanceCheckOrganization() => _buildCheckOrganizationPage(context, state),
           IssuanceMissingAttributes() => _buildMissingAttributes(context, state),
           IssuanceReviewCards() => _buildReviewCardsPage(context, state),
@@ -171,6 +173,13 @@ class IssuanceScreen extends StatelessWidget {
     );
   }
 
+  Widget _buildProvideTransactionCodePage(BuildContext context, IssuanceProvideTransactionCode state) {
+    return IssuanceProvideTransactionCodePage(
+      transactionCode: state.transactionCode,
+      onSubmitted: (transactionCode) => context.bloc.add(IssuanceTransactionCodeSubmitted(transactionCode)),
+    );
+  }
+
   Widget _buildCheckOrganizationPage(BuildContext context, IssuanceCheckOrganization state) {
     late String description;
     final attributes = state.cardRequests.map((it) => it.selection.attributes).flattened;
@@ -375,6 +384,7 @@ class IssuanceScreen extends StatelessWidget {
       IssuanceInitial() => context.l10n.issuanceLoadingRequestTitle,
       IssuanceLoadInProgress() => context.l10n.issuanceLoadingCardsTitle,
       IssuanceAuthenticateWithIssuer() => context.l10n.issuanceAuthenticateExternallyTitle,
+      IssuanceProvideTransactionCode() => context.l10n.issuanceProvideTransactionCodePageTitle,
       IssuanceCheckOrganization() => OrganizationApprovePage.resolveTitle(
         context,
         ApprovalPurpose.issuance,
@@ -0,0 +1,128 @@
+import 'package:flutter/material.dart';
+import 'package:flutter/services.dart';
+
+import '../../../util/extension/build_context_extension.dart';
+import '../../../wallet_core/error/core_error.dart';
+import '../../common/widget/button/confirm/confirm_buttons.dart';
+import '../../common/widget/button/primary_button.dart';
+import '../../common/widget/paragraphed_list.dart';
+import '../../common/widget/text/title_text.dart';
+import '../../common/widget/wallet_scrollbar.dart';
+
+/// Lets the user enter the transaction code that the issuer requires to redeem a pre-authorized credential offer.
+/// The keyboard and maximum length are derived from the [transactionCode] details provided by the issuer.
+class IssuanceProvideTransactionCodePage extends StatefulWidget {
+  final TransactionCodeData transactionCode;
+  final ValueChanged<String> onSubmitted;
+
+  const IssuanceProvideTransactionCodePage({
+    required this.transactionCode,
+    required this.onSubmitted,
+    super.key,
+  });
+
+  @override
+  State<IssuanceProvideTransactionCodePage> createState() => _IssuanceProvideTransactionCodePageState();
+}
+
+class _IssuanceProvideTransactionCodePageState extends State<IssuanceProvideTransactionCodePage> {
+  final TextEditingController _controller = TextEditingController();
+
+  /// An absent input mode should be treated as numeric, see [TransactionCodeInputMode].
+  bool get _isNumeric => widget.transactionCode.inputMode != TransactionCodeInputMode.text;
+
+  bool get _isValid {
+    final code = _controller.text;
+    final length = widget.transactionCode.length;
+    return length == null ? code.isNotEmpty : code.length == length;
+  }
+
+  @override
+  void initState() {
+    super.initState();
+    _controller.addListener(() => setState(() {}));
+  }
+
+  @override
+  void dispose() {
+    _controller.dispose();
+    super.dispose();
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    return SafeArea(
+      bottom: false,
+      child: LayoutBuilder(
+        builder: (context, constraints) {
+          return WalletScrollbar(
+            child: SingleChildScrollView(
+              child: ConstrainedBox(
+                constraints: BoxConstraints(minHeight: constraints.maxHeight),
+                child: Column(
+                  mainAxisAlignment: MainAxisAlignment.spaceBetween,
+                  children: [
+                    _buildContentSection(context),
+                    _buildBottomSection(context),
+                  ],
+                ),
+              ),
+            ),
+          );
+        },
+      ),
+    );
+  }
+
+  Widget _buildContentSection(BuildContext context) {
+    final description = widget.transactionCode.description;
+    return Padding(
+      padding: const EdgeInsets.fromLTRB(16, 12, 16, 24),
+      child: Column(
+        crossAxisAlignment: CrossAxisAlignment.start,
+        children: [
+          TitleText(context.l10n.issuanceProvideTransactionCodePageTitle),
+          const SizedBox(height: 8),
+          ParagraphedList.splitContent(description ?? context.l10n.issuanceProvideTransactionCodePageDescription),
+          const SizedBox(height: 24),
+          TextField(
+            key: const Key('transactionCodeTextField'),
+            controller: _controller,
+            autofocus: true,
+            keyboardType: _isNumeric ? TextInputType.number : TextInputType.text,
+            inputFormatters: [if (_isNumeric) FilteringTextInputFormatter.digitsOnly],
+            maxLength: widget.transactionCode.length,
+            textInputAction: TextInputAction.done,
+            onSubmitted: (_) => _submit(),
+            decoration: InputDecoration(labelText: context.l10n.issuanceProvideTransactionCodePageInputLabel),
+          ),
+        ],
+      ),
+    );
+  }
+
+  Widget _buildBottomSection(BuildContext context) {
+    final contentPadding = context.isLandscape ? ConfirmButtons.contentLandscapePadding : ConfirmButtons.contentPadding;
+    return SafeArea(
+      top: false,
+      child: Column(
+        mainAxisSize: MainAxisSize.min,
+        children: [
+          const Divider(),
+          Padding(
+            padding: contentPadding,
+            child: PrimaryButton(
+              key: const Key('primaryButtonCta'),
+              text: Text(context.l10n.issuanceProvideTransactionCodePageConfirmCta),
+              onPressed: _isValid ? _submit : null,
+            ),
+          ),
+        ],
+      ),
+    );
+  }
+
+  void _submit() {
+    if (_isValid) widget.onSubmitted(_controller.text);
+  }
+}
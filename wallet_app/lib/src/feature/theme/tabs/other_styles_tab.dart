@@ -453,6 +453,7 @@ class OtherStylesTab extends StatelessWidget {
       CardStatusExpiresSoon(validUntil: clock.now().add(const Duration(days: 5))),
       CardStatusExpired(validUntil: clock.now().subtract(const Duration(days: 1))),
       const CardStatusRevoked(),
+      const CardStatusSuspended(),
       const CardStatusCorrupted(),
       const CardStatusUndetermined(),
     ];
@@ -39,6 +39,10 @@ extension CoreErrorExtension on CoreError {
         canRegisterNewAccount: error.canRegisterNewAccount,
       ),
       CorePreAuthorizedCodeExpired() => PreAuthorizedCodeExpiredError(sourceError: error),
+      CoreTransactionCodeRequired() => TransactionCodeRequiredError(
+        transactionCode: error.transactionCode,
+        sourceError: error,
+      ),
     };
   }
 
@@ -15,6 +15,7 @@ class CardStatusMetadataCardDataScreenFormatter implements CardStatusMetadataFor
       CardStatusExpiresSoon() => false,
       CardStatusExpired() => true,
       CardStatusRevoked() => true,
+      CardStatusSuspended() => true,
       CardStatusCorrupted() => true,
       CardStatusUndetermined() => true,
     };
@@ -31,6 +32,7 @@ class CardStatusMetadataCardDataScreenFormatter implements CardStatusMetadataFor
       CardStatusExpiresSoon() => '',
       CardStatusExpired() => context.l10n.cardStatusMetadataCardDataScreenExpired,
       CardStatusRevoked() => context.l10n.cardStatusMetadataCardDataScreenRevoked,
+      CardStatusSuspended() => context.l10n.cardStatusMetadataCardDataScreenSuspended,
       CardStatusCorrupted() => context.l10n.cardStatusMetadataCardDataScreenCorrupted,
       CardStatusUndetermined() => context.l10n.cardStatusMetadataCardDataScreenUndetermined,
     };
@@ -44,6 +46,7 @@ class CardStatusMetadataCardDataScreenFormatter implements CardStatusMetadataFor
       CardStatusExpiresSoon() => context.colorScheme.onSurface,
       CardStatusExpired() => context.colorScheme.error,
       CardStatusRevoked() => context.colorScheme.error,
+      CardStatusSuspended() => context.colorScheme.onSurfaceVariant,
       CardStatusCorrupted() => context.colorScheme.error,
       CardStatusUndetermined() => context.colorScheme.onSurface,
     };
@@ -57,6 +60,7 @@ class CardStatusMetadataCardDataScreenFormatter implements CardStatusMetadataFor
       CardStatusExpiresSoon() => null,
       CardStatusExpired() => Icons.event_busy,
       CardStatusRevoked() => Icons.close,
+      CardStatusSuspended() => Icons.pause_circle_outline,
       CardStatusCorrupted() => Icons.block_flipped,
       CardStatusUndetermined() => Icons.warning_amber,
     };
@@ -70,6 +74,7 @@ class CardStatusMetadataCardDataScreenFormatter implements CardStatusMetadataFor
       CardStatusExpiresSoon() => null,
       CardStatusExpired() => context.colorScheme.error,
       CardStatusRevoked() => context.colorScheme.error,
+      CardStatusSuspended() => context.colorScheme.onSurfaceVariant,
       CardStatusCorrupted() => context.colorScheme.error,
       CardStatusUndetermined() => context.colorScheme.onSurfaceVariant,
     };
@@ -24,6 +24,7 @@ class CardStatusMetadataCardDetailScreenFormatter implements CardStatusMetadataF
       CardStatusExpiresSoon() => _formatCardStatusExpiresSoonText(context, status.validUntil, organisation, isPidCard),
       CardStatusExpired() => _formatCardStatusExpiredText(context, status.validUntil, organisation, isPidCard),
       CardStatusRevoked() => _formatCardStatusRevokedText(context, organisation, isPidCard),
+      CardStatusSuspended() => _formatCardStatusSuspendedText(context, organisation, isPidCard),
       CardStatusCorrupted() => _formatCardStatusCorruptedText(context, organisation, isPidCard),
       CardStatusUndetermined() => _formatCardStatusUndeterminedText(context, isPidCard),
     };
@@ -64,6 +65,12 @@ class CardStatusMetadataCardDetailScreenFormatter implements CardStatusMetadataF
         : context.l10n.cardStatusMetadataCardDetailScreenRevoked(organisation);
   }
 
+  String _formatCardStatusSuspendedText(BuildContext context, String organisation, bool isPidCard) {
+    return isPidCard
+        ? context.l10n.cardStatusMetadataCardDetailScreenSuspendedPid(organisation)
+        : context.l10n.cardStatusMetadataCardDetailScreenSuspended(organisation);
+  }
+
   String _formatCardStatusCorruptedText(BuildContext context, String organisation, bool isPidCard) {
     return isPidCard
         ? context.l10n.cardStatusMetadataCardDetailScreenCorruptedPid
@@ -84,6 +91,7 @@ class CardStatusMetadataCardDetailScreenFormatter implements CardStatusMetadataF
       CardStatusExpiresSoon() => _getStatusWarningColor(context),
       CardStatusExpired() => context.colorScheme.error,
       CardStatusRevoked() => context.colorScheme.error,
+      CardStatusSuspended() => _getStatusWarningColor(context),
       CardStatusCorrupted() => context.colorScheme.error,
       CardStatusUndetermined() => _getStatusWarningColor(context),
     };
@@ -97,6 +105,7 @@ class CardStatusMetadataCardDetailScreenFormatter implements CardStatusMetadataF
       CardStatusExpiresSoon() => Icons.schedule,
       CardStatusExpired() => Icons.event_busy,
       CardStatusRevoked() => Icons.close,
+      CardStatusSuspended() => Icons.pause_circle_outline,
       CardStatusCorrupted() => Icons.block_flipped,
       CardStatusUndetermined() => Icons.warning_amber,
     };
@@ -110,6 +119,7 @@ class CardStatusMetadataCardDetailScreenFormatter implements CardStatusMetadataF
       CardStatusExpiresSoon() => _getStatusWarningColor(context),
       CardStatusExpired() => context.colorScheme.error,
       CardStatusRevoked() => context.colorScheme.error,
+      CardStatusSuspended() => _getStatusWarningColor(context),
       CardStatusCorrupted() => context.colorScheme.error,
       CardStatusUndetermined() => _getStatusWarningColor(context),
     };
@@ -14,6 +14,7 @@ class CardStatusMetadataSharedAttributesCardFormatter implements CardStatusMetad
       CardStatusExpiresSoon() => false,
       CardStatusExpired() => true,
       CardStatusRevoked() => true,
+      CardStatusSuspended() => true,
       CardStatusCorrupted() => true,
       CardStatusUndetermined() => true,
     };
@@ -27,6 +28,7 @@ class CardStatusMetadataSharedAttributesCardFormatter implements CardStatusMetad
       CardStatusExpiresSoon() => '',
       CardStatusExpired() => context.l10n.cardStatusMetadataSharedAttributesCardExpired,
       CardStatusRevoked() => context.l10n.cardStatusMetadataSharedAttributesCardRevoked,
+      CardStatusSuspended() => context.l10n.cardStatusMetadataSharedAttributesCardSuspended,
       CardStatusCorrupted() => context.l10n.cardStatusMetadataSharedAttributesCardCorrupted,
       CardStatusUndetermined() => context.l10n.cardStatusMetadataSharedAttributesCardUndetermined,
     };
@@ -43,6 +45,7 @@ class CardStatusMetadataSharedAttributesCardFormatter implements CardStatusMetad
       CardStatusExpiresSoon() => null,
       CardStatusExpired() => Icons.warning_amber,
       CardStatusRevoked() => Icons.warning_amber,
+      CardStatusSuspended() => Icons.feedback_outlined,
       CardStatusCorrupted() => Icons.warning_amber,
       CardStatusUndetermined() => Icons.feedback_outlined,
     };
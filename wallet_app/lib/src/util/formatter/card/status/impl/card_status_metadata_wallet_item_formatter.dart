@@ -16,6 +16,7 @@ class CardStatusMetadataWalletItemFormatter implements CardStatusMetadataFormatt
       CardStatusExpiresSoon() => true,
       CardStatusExpired() => true,
       CardStatusRevoked() => true,
+      CardStatusSuspended() => true,
       CardStatusCorrupted() => true,
       CardStatusUndetermined() => true,
     };
@@ -32,6 +33,7 @@ class CardStatusMetadataWalletItemFormatter implements CardStatusMetadataFormatt
       ),
       CardStatusExpired() => context.l10n.cardStatusMetadataWalletItemExpired,
       CardStatusRevoked() => context.l10n.cardStatusMetadataWalletItemRevoked,
+      CardStatusSuspended() => context.l10n.cardStatusMetadataWalletItemSuspended,
       CardStatusCorrupted() => context.l10n.cardStatusMetadataWalletItemCorrupted,
       CardStatusUndetermined() => context.l10n.cardStatusMetadataWalletItemUndetermined,
     };
@@ -46,6 +48,7 @@ class CardStatusMetadataWalletItemFormatter implements CardStatusMetadataFormatt
       CardStatusExpiresSoon() => colorScheme.onSurface,
       CardStatusExpired() => colorScheme.onError,
       CardStatusRevoked() => colorScheme.onError,
+      CardStatusSuspended() => colorScheme.onError,
       CardStatusCorrupted() => colorScheme.onError,
       CardStatusUndetermined() => colorScheme.onError,
     };
@@ -59,6 +62,7 @@ class CardStatusMetadataWalletItemFormatter implements CardStatusMetadataFormatt
       CardStatusExpiresSoon() => Icons.schedule,
       CardStatusExpired() => Icons.event_busy,
       CardStatusRevoked() => Icons.close,
+      CardStatusSuspended() => Icons.pause_circle_outline,
       CardStatusCorrupted() => Icons.block_flipped,
       CardStatusUndetermined() => Icons.warning_amber,
     };
@@ -73,6 +77,7 @@ class CardStatusMetadataWalletItemFormatter implements CardStatusMetadataFormatt
       CardStatusExpiresSoon() => colorScheme.onSurfaceVariant,
       CardStatusExpired() => colorScheme.surface,
       CardStatusRevoked() => colorScheme.surface,
+      CardStatusSuspended() => colorScheme.surface,
       CardStatusCorrupted() => colorScheme.surface,
       CardStatusUndetermined() => colorScheme.surface,
     };
@@ -87,6 +92,7 @@ class CardStatusMetadataWalletItemFormatter implements CardStatusMetadataFormatt
       CardStatusExpiresSoon() => colorScheme.surface,
       CardStatusExpired() => colorScheme.error,
       CardStatusRevoked() => colorScheme.error,
+      CardStatusSuspended() => kStatusWarningColorLight,
       CardStatusCorrupted() => colorScheme.error,
       CardStatusUndetermined() => kStatusWarningColorLight,
     };
@@ -15,6 +15,7 @@ class CardStatusMapper extends Mapper<core.AttestationPresentation, CardStatus>
       null => _mapValidRevocationStatus(input),
       core.RevocationStatus.Valid => _mapValidRevocationStatus(input),
       core.RevocationStatus.Revoked => const CardStatusRevoked(),
+      core.RevocationStatus.Suspended => const CardStatusSuspended(),
       core.RevocationStatus.Corrupted => const CardStatusCorrupted(),
       core.RevocationStatus.Undetermined => const CardStatusUndetermined(),
     };
@@ -2,10 +2,12 @@ import 'package:equatable/equatable.dart';
 
 import 'data/redirect/redirect_error.dart';
 import 'data/revocation/revocation_data.dart';
+import 'data/transaction_code/transaction_code_data.dart';
 
 export 'data/redirect/redirect_error.dart';
 export 'data/revocation/revocation_data.dart';
 export 'data/session/session_type.dart';
+export 'data/transaction_code/transaction_code_data.dart';
 
 sealed class CoreError extends Equatable {
   final String? description;
@@ -69,6 +71,16 @@ class CorePreAuthorizedCodeExpired extends CoreError {
   const CorePreAuthorizedCodeExpired(super.description, {super.data});
 }
 
+/// The credential offer requires a transaction code, which should be provided as described by [transactionCode].
+class CoreTransactionCodeRequired extends CoreError {
+  final TransactionCodeData transactionCode;
+
+  const CoreTransactionCodeRequired(super.description, {super.data, required this.transactionCode});
+
+  @override
+  List<Object?> get props => [transactionCode, ...super.props];
+}
+
 class CoreCancelledSessionError extends CoreError {
   const CoreCancelledSessionError(super.description, {super.data});
 }
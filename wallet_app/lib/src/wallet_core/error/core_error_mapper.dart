@@ -38,6 +38,7 @@ class CoreErrorMapper extends Mapper<String, CoreError> {
       FlutterApiErrorType.deniedDigid => CoreDeniedDigidError(error.description),
       FlutterApiErrorType.revoked => _mapAccountRevokedError(error),
       FlutterApiErrorType.preAuthorizedCodeExpired => CorePreAuthorizedCodeExpired(error.description, data: error.data),
+      FlutterApiErrorType.transactionCodeRequired => _mapTransactionCodeRequiredError(error),
     };
   }
 
@@ -77,6 +78,15 @@ class CoreErrorMapper extends Mapper<String, CoreError> {
     );
   }
 
+  CoreError _mapTransactionCodeRequiredError(FlutterApiError error) {
+    final errorData = CoreErrorData.fromJson(error.data ?? {});
+    return CoreTransactionCodeRequired(
+      error.description,
+      transactionCode: errorData.transactionCode ?? TransactionCodeData(),
+      data: error.data,
+    );
+  }
+
   CoreError _mapAccountRevokedError(FlutterApiError error) {
     final errorData = CoreErrorData.fromJson(error.data ?? {});
     return CoreAccountRevokedError(
@@ -20,6 +20,7 @@ abstract class CoreErrorData with _$CoreErrorData {
     @JsonKey(name: 'session_type', unknownEnumValue: SessionType.unknown) SessionType? sessionType,
     @JsonKey(name: 'can_retry') bool? canRetry,
     @JsonKey(name: 'organization_name') String? organizationName,
+    @JsonKey(name: 'transaction_code') TransactionCodeData? transactionCode,
   }) = _CoreErrorData;
 
   const CoreErrorData._();
@@ -15,7 +15,7 @@ T _$identity<T>(T value) => value;
 /// @nodoc
 mixin _$CoreErrorData {
 
-@JsonKey(name: 'revocation_data') RevocationData? get revocationData;@JsonKey(name: 'redirect_error', unknownEnumValue: RedirectError.unknown) RedirectError? get redirectError;@JsonKey(name: 'session_type', unknownEnumValue: SessionType.unknown) SessionType? get sessionType;@JsonKey(name: 'can_retry') bool? get canRetry;@JsonKey(name: 'organization_name') String? get organizationName;
+@JsonKey(name: 'revocation_data') RevocationData? get revocationData;@JsonKey(name: 'redirect_error', unknownEnumValue: RedirectError.unknown) RedirectError? get redirectError;@JsonKey(name: 'session_type', unknownEnumValue: SessionType.unknown) SessionType? get sessionType;@JsonKey(name: 'can_retry') bool? get canRetry;@JsonKey(name: 'organization_name') String? get organizationName;@JsonKey(name: 'transaction_code') TransactionCodeData? get transactionCode;
 /// Create a copy of CoreErrorData
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
@@ -28,16 +28,16 @@ $CoreErrorDataCopyWith<CoreErrorData> get copyWith => _$CoreErrorDataCopyWithImp
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is CoreErrorData&&(identical(other.revocationData, revocationData) || other.revocationData == revocationData)&&(identical(other.redirectError, redirectError) || other.redirectError == redirectError)&&(identical(other.sessionType, sessionType) || other.sessionType == sessionType)&&(identical(other.canRetry, canRetry) || other.canRetry == canRetry)&&(identical(other.organizationName, organizationName) || other.organizationName == organizationName));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CoreErrorData&&(identical(other.revocationData, revocationData) || other.revocationData == revocationData)&&(identical(other.redirectError, redirectError) || other.redirectError == redirectError)&&(identical(other.sessionType, sessionType) || other.sessionType == sessionType)&&(identical(other.canRetry, canRetry) || other.canRetry == canRetry)&&(identical(other.organizationName, organizationName) || other.organizationName == organizationName)&&(identical(other.transactionCode, transactionCode) || other.transactionCode == transactionCode));
 }
 
 @JsonKey(includeFromJson: false, includeToJson: false)
 @override
-int get hashCode => Object.hash(runtimeType,revocationData,redirectError,sessionType,canRetry,organizationName);
+int get hashCode => Object.hash(runtimeType,revocationData,redirectError,sessionType,canRetry,organizationName,transactionCode);
 
 @override
 String toString() {
-  return 'CoreErrorData(revocationData: $revocationData, redirectError: $redirectError, sessionType: $sessionType, canRetry: $canRetry, organizationName: $organizationName)';
+  return 'CoreErrorData(revocationData: $revocationData, redirectError: $redirectError, sessionType: $sessionType, canRetry: $canRetry, organizationName: $organizationName, transactionCode: $transactionCode)';
 }
 
 
@@ -48,11 +48,12 @@ abstract mixin class $CoreErrorDataCopyWith<$Res>  {
   factory $CoreErrorDataCopyWith(CoreErrorData value, $Res Function(CoreErrorData) _then) = _$CoreErrorDataCopyWithImpl;
 @useResult
 $Res call({
-@JsonKey(name: 'revocation_data') RevocationData? revocationData,@JsonKey(name: 'redirect_error', unknownEnumValue: RedirectError.unknown) RedirectError? redirectError,@JsonKey(name: 'session_type', unknownEnumValue: SessionType.unknown) SessionType? sessionType,@JsonKey(name: 'can_retry') bool? canRetry,@JsonKey(name: 'organization_name') String? organizationName
+@JsonKey(name: 'revocation_data') RevocationData? revocationData,@JsonKey(name: 'redirect_error', unknownEnumValue: RedirectError.unknown) RedirectError? redirectError,@JsonKey(name: 'session_type', unknownEnumValue: SessionType.unknown) SessionType? sessionType,@JsonKey(name: 'can_retry') bool? canRetry,@JsonKey(name: 'organization_name') String? organizationName,@JsonKey(name: 'transaction_code') TransactionCodeData? transactionCode
 });
 
 
 $RevocationDataCopyWith<$Res>? get revocationData;
+$TransactionCodeDataCopyWith<$Res>? get transactionCode;
 
 }
 /// @nodoc
@@ -65,14 +66,15 @@ class _$CoreErrorDataCopyWithImpl<$Res>
 
 /// Create a copy of CoreErrorData
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? revocationData = freezed,Object? redirectError = freezed,Object? sessionType = freezed,Object? canRetry = freezed,Object? organizationName = freezed,}) {
+@pragma('vm:prefer-inline') @override $Res call({Object? revocationData = freezed,Object? redirectError = freezed,Object? sessionType = freezed,Object? canRetry = freezed,Object? organizationName = freezed,Object? transactionCode = freezed,}) {
   return _then(_self.copyWith(
 revocationData: freezed == revocationData ? _self.revocationData : revocationData // ignore: cast_nullable_to_non_nullable
 as RevocationData?,redirectError: freezed == redirectError ? _self.redirectError : redirectError // ignore: cast_nullable_to_non_nullable
 as RedirectError?,sessionType: freezed == sessionType ? _self.sessionType : sessionType // ignore: cast_nullable_to_non_nullable
 as SessionType?,canRetry: freezed == canRetry ? _self.canRetry : canRetry // ignore: cast_nullable_to_non_nullable
 as bool?,organizationName: freezed == organizationName ? _self.organizationName : organizationName // ignore: cast_nullable_to_non_nullable
-as String?,
+as String?,transactionCode: freezed == transactionCode ? _self.transactionCode : transactionCode // ignore: cast_nullable_to_non_nullable
+as TransactionCodeData?,
   ));
 }
 /// Create a copy of CoreErrorData
@@ -88,6 +90,19 @@ $RevocationDataCopyWith<$Res>? get revocationData {
     return _then(_self.copyWith(revocationData: value));
   });
 }
+/// Create a copy of CoreErrorData
+/// with the given fields replaced by the non-null parameter values.
+@override
+@pragma('vm:prefer-inline')
+$TransactionCodeDataCopyWith<$Res>? get transactionCode {
+    if (_self.transactionCode == null) {
+    return null;
+  }
+
+  return $TransactionCodeDataCopyWith<$Res>(_self.transactionCode!, (value) {
+    return _then(_self.copyWith(transactionCode: value));
+  });
+}
 }
 
 
@@ -169,10 +184,10 @@ return $default(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function(@JsonKey(name: 'revocation_data')  RevocationData? revocationData, @JsonKey(name: 'redirect_error', unknownEnumValue: RedirectError.unknown)  RedirectError? redirectError, @JsonKey(name: 'session_type', unknownEnumValue: SessionType.unknown)  SessionType? sessionType, @JsonKey(name: 'can_retry')  bool? canRetry, @JsonKey(name: 'organization_name')  String? organizationName)?  $default,{required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function(@JsonKey(name: 'revocation_data')  RevocationData? revocationData, @JsonKey(name: 'redirect_error', unknownEnumValue: RedirectError.unknown)  RedirectError? redirectError, @JsonKey(name: 'session_type', unknownEnumValue: SessionType.unknown)  SessionType? sessionType, @JsonKey(name: 'can_retry')  bool? canRetry, @JsonKey(name: 'organization_name')  String? organizationName, @JsonKey(name: 'transaction_code')  TransactionCodeData? transactionCode)?  $default,{required TResult orElse(),}) {final _that = this;
 switch (_that) {
 case _CoreErrorData() when $default != null:
-return $default(_that.revocationData,_that.redirectError,_that.sessionType,_that.canRetry,_that.organizationName);case _:
+return $default(_that.revocationData,_that.redirectError,_that.sessionType,_that.canRetry,_that.organizationName,_that.transactionCode);case _:
   return orElse();
 
 }
@@ -190,10 +205,10 @@ return $default(_that.revocationData,_that.redirectError,_that.sessionType,_that
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function(@JsonKey(name: 'revocation_data')  RevocationData? revocationData, @JsonKey(name: 'redirect_error', unknownEnumValue: RedirectError.unknown)  RedirectError? redirectError, @JsonKey(name: 'session_type', unknownEnumValue: SessionType.unknown)  SessionType? sessionType, @JsonKey(name: 'can_retry')  bool? canRetry, @JsonKey(name: 'organization_name')  String? organizationName)  $default,) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function(@JsonKey(name: 'revocation_data')  RevocationData? revocationData, @JsonKey(name: 'redirect_error', unknownEnumValue: RedirectError.unknown)  RedirectError? redirectError, @JsonKey(name: 'session_type', unknownEnumValue: SessionType.unknown)  SessionType? sessionType, @JsonKey(name: 'can_retry')  bool? canRetry, @JsonKey(name: 'organization_name')  String? organizationName, @JsonKey(name: 'transaction_code')  TransactionCodeData? transactionCode)  $default,) {final _that = this;
 switch (_that) {
 case _CoreErrorData():
-return $default(_that.revocationData,_that.redirectError,_that.sessionType,_that.canRetry,_that.organizationName);case _:
+return $default(_that.revocationData,_that.redirectError,_that.sessionType,_that.canRetry,_that.organizationName,_that.transactionCode);case _:
   throw StateError('Unexpected subclass');
 
 }
@@ -210,10 +225,10 @@ return $default(_that.revocationData,_that.redirectError,_that.sessionType,_that
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function(@JsonKey(name: 'revocation_data')  RevocationData? revocationData, @JsonKey(name: 'redirect_error', unknownEnumValue: RedirectError.unknown)  RedirectError? redirectError, @JsonKey(name: 'session_type', unknownEnumValue: SessionType.unknown)  SessionType? sessionType, @JsonKey(name: 'can_retry')  bool? canRetry, @JsonKey(name: 'organization_name')  String? organizationName)?  $default,) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function(@JsonKey(name: 'revocation_data')  RevocationData? revocationData, @JsonKey(name: 'redirect_error', unknownEnumValue: RedirectError.unknown)  RedirectError? redirectError, @JsonKey(name: 'session_type', unknownEnumValue: SessionType.unknown)  SessionType? sessionType, @JsonKey(name: 'can_retry')  bool? canRetry, @JsonKey(name: 'organization_name')  String? organizationName, @JsonKey(name: 'transaction_code')  TransactionCodeData? transactionCode)?  $default,) {final _that = this;
 switch (_that) {
 case _CoreErrorData() when $default != null:
-return $default(_that.revocationData,_that.redirectError,_that.sessionType,_that.canRetry,_that.organizationName);case _:
+return $default(_that.revocationData,_that.redirectError,_that.sessionType,_that.canRetry,_that.organizationName,_that.transactionCode);case _:
   return null;
 
 }
@@ -225,7 +240,7 @@ return $default(_that.revocationData,_that.redirectError,_that.sessionType,_that
 @JsonSerializable()
 
 class _CoreErrorData extends CoreErrorData {
-   _CoreErrorData({@JsonKey(name: 'revocation_data') this.revocationData, @JsonKey(name: 'redirect_error', unknownEnumValue: RedirectError.unknown) this.redirectError, @JsonKey(name: 'session_type', unknownEnumValue: SessionType.unknown) this.sessionType, @JsonKey(name: 'can_retry') this.canRetry, @JsonKey(name: 'organization_name') this.organizationName}): super._();
+   _CoreErrorData({@JsonKey(name: 'revocation_data') this.revocationData, @JsonKey(name: 'redirect_error', unknownEnumValue: RedirectError.unknown) this.redirectError, @JsonKey(name: 'session_type', unknownEnumValue: SessionType.unknown) this.sessionType, @JsonKey(name: 'can_retry') this.canRetry, @JsonKey(name: 'organization_name') this.organizationName, @JsonKey(name: 'transaction_code') this.transactionCode}): super._();
   factory _CoreErrorData.fromJson(Map<String, dynamic> json) => _$CoreErrorDataFromJson(json);
 
 @override@JsonKey(name: 'revocation_data') final  RevocationData? revocationData;
@@ -233,6 +248,7 @@ class _CoreErrorData extends CoreErrorData {
 @override@JsonKey(name: 'session_type', unknownEnumValue: SessionType.unknown) final  SessionType? sessionType;
 @override@JsonKey(name: 'can_retry') final  bool? canRetry;
 @override@JsonKey(name: 'organization_name') final  String? organizationName;
+@override@JsonKey(name: 'transaction_code') final  TransactionCodeData? transactionCode;
 
 /// Create a copy of CoreErrorData
 /// with the given fields replaced by the non-null parameter values.
@@ -247,16 +263,16 @@ Map<String, dynamic> toJson() {
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is _CoreErrorData&&(identical(other.revocationData, revocationData) || other.revocationData == revocationData)&&(identical(other.redirectError, redirectError) || other.redirectError == redirectError)&&(identical(other.sessionType, sessionType) || other.sessionType == sessionType)&&(identical(other.canRetry, canRetry) || other.canRetry == canRetry)&&(identical(other.organizationName, organizationName) || other.organizationName == organizationName));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _CoreErrorData&&(identical(other.revocationData, revocationData) || other.revocationData == revocationData)&&(identical(other.redirectError, redirectError) || other.redirectError == redirectError)&&(identical(other.sessionType, sessionType) || other.sessionType == sessionType)&&(identical(other.canRetry, canRetry) || other.canRetry == canRetry)&&(identical(other.organizationName, organizationName) || other.organizationName == organizationName)&&(identical(other.transactionCode, transactionCode) || other.transactionCode == transactionCode));
 }
 
 @JsonKey(includeFromJson: false, includeToJson: false)
 @override
-int get hashCode => Object.hash(runtimeType,revocationData,redirectError,sessionType,canRetry,organizationName);
+int get hashCode => Object.hash(runtimeType,revocationData,redirectError,sessionType,canRetry,organizationName,transactionCode);
 
 @override
 String toString() {
-  return 'CoreErrorData(revocationData: $revocationData, redirectError: $redirectError, sessionType: $sessionType, canRetry: $canRetry, organizationName: $organizationName)';
+  return 'CoreErrorData(revocationData: $revocationData, redirectError: $redirectError, sessionType: $sessionType, canRetry: $canRetry, organizationName: $organizationName, transactionCode: $transactionCode)';
 }
 
 
@@ -267,11 +283,12 @@ abstract mixin class _$CoreErrorDataCopyWith<$Res> implements $CoreErrorDataCopy
   factory _$CoreErrorDataCopyWith(_CoreErrorData value, $Res Function(_CoreErrorData) _then) = __$CoreErrorDataCopyWithImpl;
 @override @useResult
 $Res call({
-@JsonKey(name: 'revocation_data') RevocationData? revocationData,@JsonKey(name: 'redirect_error', unknownEnumValue: RedirectError.unknown) RedirectError? redirectError,@JsonKey(name: 'session_type', unknownEnumValue: SessionType.unknown) SessionType? sessionType,@JsonKey(name: 'can_retry') bool? canRetry,@JsonKey(name: 'organization_name') String? organizationName
+@JsonKey(name: 'revocation_data') RevocationData? revocationData,@JsonKey(name: 'redirect_error', unknownEnumValue: RedirectError.unknown) RedirectError? redirectError,@JsonKey(name: 'session_type', unknownEnumValue: SessionType.unknown) SessionType? sessionType,@JsonKey(name: 'can_retry') bool? canRetry,@JsonKey(name: 'organization_name') String? organizationName,@JsonKey(name: 'transaction_code') TransactionCodeData? transactionCode
 });
 
 
 @override $RevocationDataCopyWith<$Res>? get revocationData;
+@override $TransactionCodeDataCopyWith<$Res>? get transactionCode;
 
 }
 /// @nodoc
@@ -284,14 +301,15 @@ class __$CoreErrorDataCopyWithImpl<$Res>
 
 /// Create a copy of CoreErrorData
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? revocationData = freezed,Object? redirectError = freezed,Object? sessionType = freezed,Object? canRetry = freezed,Object? organizationName = freezed,}) {
+@override @pragma('vm:prefer-inline') $Res call({Object? revocationData = freezed,Object? redirectError = freezed,Object? sessionType = freezed,Object? canRetry = freezed,Object? organizationName = freezed,Object? transactionCode = freezed,}) {
   return _then(_CoreErrorData(
 revocationData: freezed == revocationData ? _self.revocationData : revocationData // ignore: cast_nullable_to_non_nullable
 as RevocationData?,redirectError: freezed == redirectError ? _self.redirectError : redirectError // ignore: cast_nullable_to_non_nullable
 as RedirectError?,sessionType: freezed == sessionType ? _self.sessionType : sessionType // ignore: cast_nullable_to_non_nullable
 as SessionType?,canRetry: freezed == canRetry ? _self.canRetry : canRetry // ignore: cast_nullable_to_non_nullable
 as bool?,organizationName: freezed == organizationName ? _self.organizationName : organizationName // ignore: cast_nullable_to_non_nullable
-as String?,
+as String?,transactionCode: freezed == transactionCode ? _self.transactionCode : transactionCode // ignore: cast_nullable_to_non_nullable
+as TransactionCodeData?,
   ));
 }
 
@@ -308,6 +326,19 @@ $RevocationDataCopyWith<$Res>? get revocationData {
     return _then(_self.copyWith(revocationData: value));
   });
 }
+/// Create a copy of CoreErrorData
+/// with the given fields replaced by the non-null parameter values.
+@override
+@pragma('vm:prefer-inline')
+$TransactionCodeDataCopyWith<$Res>? get transactionCode {
+    if (_self.transactionCode == null) {
+    return null;
+  }
+
+  return $TransactionCodeDataCopyWith<$Res>(_self.transactionCode!, (value) {
+    return _then(_self.copyWith(transactionCode: value));
+  });
+}
 }
 
 // dart format on
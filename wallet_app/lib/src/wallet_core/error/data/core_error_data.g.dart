@@ -24,6 +24,11 @@ _CoreErrorData _$CoreErrorDataFromJson(Map<String, dynamic> json) => _CoreErrorD
   ),
   canRetry: json['can_retry'] as bool?,
   organizationName: json['organization_name'] as String?,
+  transactionCode: json['transaction_code'] == null
+      ? null
+      : TransactionCodeData.fromJson(
+          json['transaction_code'] as Map<String, dynamic>,
+        ),
 );
 
 Map<String, dynamic> _$CoreErrorDataToJson(_CoreErrorData instance) => <String, dynamic>{
@@ -32,6 +37,7 @@ Map<String, dynamic> _$CoreErrorDataToJson(_CoreErrorData instance) => <String,
   'session_type': _$SessionTypeEnumMap[instance.sessionType],
   'can_retry': instance.canRetry,
   'organization_name': instance.organizationName,
+  'transaction_code': instance.transactionCode?.toJson(),
 };
 
 const _$RedirectErrorEnumMap = {
@@ -0,0 +1,25 @@
+import 'package:freezed_annotation/freezed_annotation.dart';
+
+part 'transaction_code_data.freezed.dart';
+
+part 'transaction_code_data.g.dart';
+
+/// The character set of a Transaction Code, an absent value should be treated as [numeric].
+enum TransactionCodeInputMode {
+  @JsonValue('numeric')
+  numeric,
+  @JsonValue('text')
+  text,
+}
+
+/// Describes the Transaction Code the user has to provide to accept a pre-authorized credential offer.
+@freezed
+abstract class TransactionCodeData with _$TransactionCodeData {
+  factory TransactionCodeData({
+    @JsonKey(name: 'input_mode', unknownEnumValue: TransactionCodeInputMode.text) TransactionCodeInputMode? inputMode,
+    int? length,
+    String? description,
+  }) = _TransactionCodeData;
+
+  factory TransactionCodeData.fromJson(Map<String, dynamic> json) => _$TransactionCodeDataFromJson(json);
+}
@@ -0,0 +1,283 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'transaction_code_data.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+
+/// @nodoc
+mixin _$TransactionCodeData {
+
+@JsonKey(name: 'input_mode', unknownEnumValue: TransactionCodeInputMode.text) TransactionCodeInputMode? get inputMode; int? get length; String? get description;
+/// Create a copy of TransactionCodeData
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$TransactionCodeDataCopyWith<TransactionCodeData> get copyWith => _$TransactionCodeDataCopyWithImpl<TransactionCodeData>(this as TransactionCodeData, _$identity);
+
+  /// Serializes this TransactionCodeData to a JSON map.
+  Map<String, dynamic> toJson();
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is TransactionCodeData&&(identical(other.inputMode, inputMode) || other.inputMode == inputMode)&&(identical(other.length, length) || other.length == length)&&(identical(other.description, description) || other.description == description));
+}
+
+@JsonKey(includeFromJson: false, includeToJson: false)
+@override
+int get hashCode => Object.hash(runtimeType,inputMode,length,description);
+
+@override
+String toString() {
+  return 'TransactionCodeData(inputMode: $inputMode, length: $length, description: $description)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $TransactionCodeDataCopyWith<$Res>  {
+  factory $TransactionCodeDataCopyWith(TransactionCodeData value, $Res Function(TransactionCodeData) _then) = _$TransactionCodeDataCopyWithImpl;
+@useResult
+$Res call({
+@JsonKey(name: 'input_mode', unknownEnumValue: TransactionCodeInputMode.text) TransactionCodeInputMode? inputMode, int? length, String? description
+});
+
+
+
+
+}
+/// @nodoc
+class _$TransactionCodeDataCopyWithImpl<$Res>
+    implements $TransactionCodeDataCopyWith<$Res> {
+  _$TransactionCodeDataCopyWithImpl(this._self, this._then);
+
+  final TransactionCodeData _self;
+  final $Res Function(TransactionCodeData) _then;
+
+/// Create a copy of TransactionCodeData
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? inputMode = freezed,Object? length = freezed,Object? description = freezed,}) {
+  return _then(_self.copyWith(
+inputMode: freezed == inputMode ? _self.inputMode : inputMode // ignore: cast_nullable_to_non_nullable
+as TransactionCodeInputMode?,length: freezed == length ? _self.length : length // ignore: cast_nullable_to_non_nullable
+as int?,description: freezed == description ? _self.description : description // ignore: cast_nullable_to_non_nullable
+as String?,
+  ));
+}
+
+}
+
+
+/// Adds pattern-matching-related methods to [TransactionCodeData].
+extension TransactionCodeDataPatterns on TransactionCodeData {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>(TResult Function( _TransactionCodeData value)?  $default,{required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case _TransactionCodeData() when $default != null:
+return $default(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>(TResult Function( _TransactionCodeData value)  $default,){
+final _that = this;
+switch (_that) {
+case _TransactionCodeData():
+return $default(_that);case _:
+  throw StateError('Unexpected subclass');
+
+}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>(TResult? Function( _TransactionCodeData value)?  $default,){
+final _that = this;
+switch (_that) {
+case _TransactionCodeData() when $default != null:
+return $default(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>(TResult Function(@JsonKey(name: 'input_mode', unknownEnumValue: TransactionCodeInputMode.text)  TransactionCodeInputMode? inputMode,  int? length,  String? description)?  $default,{required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case _TransactionCodeData() when $default != null:
+return $default(_that.inputMode,_that.length,_that.description);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>(TResult Function(@JsonKey(name: 'input_mode', unknownEnumValue: TransactionCodeInputMode.text)  TransactionCodeInputMode? inputMode,  int? length,  String? description)  $default,) {final _that = this;
+switch (_that) {
+case _TransactionCodeData():
+return $default(_that.inputMode,_that.length,_that.description);case _:
+  throw StateError('Unexpected subclass');
+
+}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>(TResult? Function(@JsonKey(name: 'input_mode', unknownEnumValue: TransactionCodeInputMode.text)  TransactionCodeInputMode? inputMode,  int? length,  String? description)?  $default,) {final _that = this;
+switch (_that) {
+case _TransactionCodeData() when $default != null:
+return $default(_that.inputMode,_that.length,_that.description);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+@JsonSerializable()
+
+class _TransactionCodeData implements TransactionCodeData {
+   _TransactionCodeData({@JsonKey(name: 'input_mode', unknownEnumValue: TransactionCodeInputMode.text) this.inputMode, this.length, this.description});
+  factory _TransactionCodeData.fromJson(Map<String, dynamic> json) => _$TransactionCodeDataFromJson(json);
+
+@override@JsonKey(name: 'input_mode', unknownEnumValue: TransactionCodeInputMode.text) final  TransactionCodeInputMode? inputMode;
+@override final  int? length;
+@override final  String? description;
+
+/// Create a copy of TransactionCodeData
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+_$TransactionCodeDataCopyWith<_TransactionCodeData> get copyWith => __$TransactionCodeDataCopyWithImpl<_TransactionCodeData>(this, _$identity);
+
+@override
+Map<String, dynamic> toJson() {
+  return _$TransactionCodeDataToJson(this, );
+}
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is _TransactionCodeData&&(identical(other.inputMode, inputMode) || other.inputMode == inputMode)&&(identical(other.length, length) || other.length == length)&&(identical(other.description, description) || other.description == description));
+}
+
+@JsonKey(includeFromJson: false, includeToJson: false)
+@override
+int get hashCode => Object.hash(runtimeType,inputMode,length,description);
+
+@override
+String toString() {
+  return 'TransactionCodeData(inputMode: $inputMode, length: $length, description: $description)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class _$TransactionCodeDataCopyWith<$Res> implements $TransactionCodeDataCopyWith<$Res> {
+  factory _$TransactionCodeDataCopyWith(_TransactionCodeData value, $Res Function(_TransactionCodeData) _then) = __$TransactionCodeDataCopyWithImpl;
+@override @useResult
+$Res call({
+@JsonKey(name: 'input_mode', unknownEnumValue: TransactionCodeInputMode.text) TransactionCodeInputMode? inputMode, int? length, String? description
+});
+
+
+
+
+}
+/// @nodoc
+class __$TransactionCodeDataCopyWithImpl<$Res>
+    implements _$TransactionCodeDataCopyWith<$Res> {
+  __$TransactionCodeDataCopyWithImpl(this._self, this._then);
+
+  final _TransactionCodeData _self;
+  final $Res Function(_TransactionCodeData) _then;
+
+/// Create a copy of TransactionCodeData
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? inputMode = freezed,Object? length = freezed,Object? description = freezed,}) {
+  return _then(_TransactionCodeData(
+inputMode: freezed == inputMode ? _self.inputMode : inputMode // ignore: cast_nullable_to_non_nullable
+as TransactionCodeInputMode?,length: freezed == length ? _self.length : length // ignore: cast_nullable_to_non_nullable
+as int?,description: freezed == description ? _self.description : description // ignore: cast_nullable_to_non_nullable
+as String?,
+  ));
+}
+
+
+}
+
+// dart format on
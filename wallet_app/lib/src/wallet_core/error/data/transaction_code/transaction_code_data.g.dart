@@ -0,0 +1,30 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+
+part of 'transaction_code_data.dart';
+
+// **************************************************************************
+// JsonSerializableGenerator
+// **************************************************************************
+
+_TransactionCodeData _$TransactionCodeDataFromJson(Map<String, dynamic> json) => _TransactionCodeData(
+  inputMode: $enumDecodeNullable(
+    _$TransactionCodeInputModeEnumMap,
+    json['input_mode'],
+    unknownValue: TransactionCodeInputMode.text,
+  ),
+  length: (json['length'] as num?)?.toInt(),
+  description: json['description'] as String?,
+);
+
+Map<String, dynamic> _$TransactionCodeDataToJson(
+  _TransactionCodeData instance,
+) => <String, dynamic>{
+  'input_mode': _$TransactionCodeInputModeEnumMap[instance.inputMode],
+  'length': instance.length,
+  'description': instance.description,
+};
+
+const _$TransactionCodeInputModeEnumMap = {
+  TransactionCodeInputMode.numeric: 'numeric',
+  TransactionCodeInputMode.text: 'text',
+};
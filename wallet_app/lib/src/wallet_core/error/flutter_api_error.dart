@@ -49,4 +49,6 @@ enum FlutterApiErrorType {
   revoked,
   @JsonValue('PreAuthorizedCodeExpired')
   preAuthorizedCodeExpired,
+  @JsonValue('TransactionCodeRequired')
+  transactionCodeRequired,
 }
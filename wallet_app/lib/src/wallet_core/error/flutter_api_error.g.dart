@@ -36,4 +36,5 @@ const _$FlutterApiErrorTypeEnumMap = {
   FlutterApiErrorType.deniedDigid: 'DeniedDigid',
   FlutterApiErrorType.revoked: 'Revoked',
   FlutterApiErrorType.preAuthorizedCodeExpired: 'PreAuthorizedCodeExpired',
+  FlutterApiErrorType.transactionCodeRequired: 'TransactionCodeRequired',
 };
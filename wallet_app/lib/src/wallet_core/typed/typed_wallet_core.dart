@@ -1,4 +1,5 @@
 import 'dart:async';
+import 'dart:typed_data';
 
 import 'package:fimber/fimber.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
@@ -162,8 +163,18 @@ class TypedWalletCore {
     runnable: () => core.startIssuanceFromOffer(offerUri: offerUri),
   );
 
+  Future<core.IssuanceStartResult> startIssuanceFromOfferWithTransactionCode(String offerUri, String transactionCode) =>
+      _callWithFlowBreadcrumb(
+        'issuance.start',
+        failureCode: 'issuance.fail.start',
+        runnable: () => core.startIssuanceFromOfferWithTransactionCode(
+          offerUri: offerUri,
+          transactionCode: transactionCode,
+        ),
+      );
+
   /// Accept offered attestations
-  Future<core.WalletInstructionResult> acceptIssuance(String pin) => _callWithFlowBreadcrumb(
+  Future<core.AcceptIssuanceResult> acceptIssuance(String pin) => _callWithFlowBreadcrumb(
     'issuance.accept',
     failureCode: 'issuance.fail.accept',
     runnable: () => core.acceptIssuance(pin: pin),
@@ -212,6 +223,41 @@ class TypedWalletCore {
         runnable: () => core.acceptDisclosure(selectedIndices: selectedIndices, pin: pin),
       );
 
+  /// Accept the disclosure request, where [selectedIndices] holds the selected candidates for every disclosure option
+  /// (more than one only for the options that allow multiple selection) and [selectedCredentialSetOptions] holds the
+  /// chosen option for every credential set (`null` to skip an optional credential set).
+  Future<core.AcceptDisclosureResult> acceptDisclosureWithMultipleSelection(
+    String pin,
+    List<List<int>> selectedIndices,
+    List<int?> selectedCredentialSetOptions,
+  ) => _callWithFlowBreadcrumb(
+    'disclosure.accept',
+    failureCode: 'disclosure.fail.accept',
+    runnable: () => core.acceptDisclosureWithMultipleSelection(
+      selectedIndices: selectedIndices.map(Uint16List.fromList).toList(),
+      selectedCredentialSetOptions: selectedCredentialSetOptions,
+      pin: pin,
+    ),
+  );
+
+  /// Same as [acceptDisclosureWithMultipleSelection], but leaves the optional [deselectedAttributes] out of the
+  /// disclosed cards.
+  Future<core.AcceptDisclosureResult> acceptDisclosureWithDeselectedAttributes(
+    String pin,
+    List<List<int>> selectedIndices,
+    List<int?> selectedCredentialSetOptions,
+    List<core.DisclosureAttributeIdentifier> deselectedAttributes,
+  ) => _callWithFlowBreadcrumb(
+    'disclosure.accept',
+    failureCode: 'disclosure.fail.accept',
+    runnable: () => core.acceptDisclosureWithDeselectedAttributes(
+      selectedIndices: selectedIndices.map(Uint16List.fromList).toList(),
+      selectedCredentialSetOptions: selectedCredentialSetOptions,
+      deselectedAttributes: deselectedAttributes,
+      pin: pin,
+    ),
+  );
+
   Stream<List<core.AttestationPresentation>> observeCards() => _attestations.stream;
 
   Future<core.WalletInstructionResult> deleteAttestation(String pin, String attestationId) => _callWithFlowBreadcrumb(
@@ -20,9 +20,7 @@ import '../models/uri.dart';
 import '../models/version_state.dart';
 import '../models/wallet_event.dart';
 import '../models/wallet_state.dart';
-import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
-
-// These functions are ignored because they are not marked as `pub`: `create_wallet`, `set_env_if_unset`, `wallet`
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';// These functions are ignored because they are not marked as `pub`: `create_wallet`, `credential_set_options`, `multiple_selection_indices`, `set_env_if_unset`, `spawn_deferred_issuance_poll`, `wallet`
 
 Future<bool> isInitialized() => WalletCore.instance.api.crateApiFullIsInitialized();
 
@@ -108,12 +106,20 @@ Future<void> cancelPinRecovery() => WalletCore.instance.api.crateApiFullCancelPi
 Future<IssuanceStartResult> startIssuanceFromOffer({required String offerUri}) =>
     WalletCore.instance.api.crateApiFullStartIssuanceFromOffer(offerUri: offerUri);
 
+Future<IssuanceStartResult> startIssuanceFromOfferWithTransactionCode({
+  required String offerUri,
+  required String transactionCode,
+}) => WalletCore.instance.api.crateApiFullStartIssuanceFromOfferWithTransactionCode(
+  offerUri: offerUri,
+  transactionCode: transactionCode,
+);
+
 Future<void> cancelIssuance() => WalletCore.instance.api.crateApiFullCancelIssuance();
 
 Future<List<AttestationPresentation>> continueIssuance({required String uri}) =>
     WalletCore.instance.api.crateApiFullContinueIssuance(uri: uri);
 
-Future<WalletInstructionResult> acceptIssuance({required String pin}) =>
+Future<AcceptIssuanceResult> acceptIssuance({required String pin}) =>
     WalletCore.instance.api.crateApiFullAcceptIssuance(pin: pin);
 
 Future<PidIssuanceResult> acceptPidIssuance({required String pin}) =>
@@ -136,6 +142,28 @@ Future<String?> cancelDisclosure() => WalletCore.instance.api.crateApiFullCancel
 Future<AcceptDisclosureResult> acceptDisclosure({required List<int> selectedIndices, required String pin}) =>
     WalletCore.instance.api.crateApiFullAcceptDisclosure(selectedIndices: selectedIndices, pin: pin);
 
+Future<AcceptDisclosureResult> acceptDisclosureWithMultipleSelection({
+  required List<Uint16List> selectedIndices,
+  required List<int?> selectedCredentialSetOptions,
+  required String pin,
+}) => WalletCore.instance.api.crateApiFullAcceptDisclosureWithMultipleSelection(
+  selectedIndices: selectedIndices,
+  selectedCredentialSetOptions: selectedCredentialSetOptions,
+  pin: pin,
+);
+
+Future<AcceptDisclosureResult> acceptDisclosureWithDeselectedAttributes({
+  required List<Uint16List> selectedIndices,
+  required List<int?> selectedCredentialSetOptions,
+  required List<DisclosureAttributeIdentifier> deselectedAttributes,
+  required String pin,
+}) => WalletCore.instance.api.crateApiFullAcceptDisclosureWithDeselectedAttributes(
+  selectedIndices: selectedIndices,
+  selectedCredentialSetOptions: selectedCredentialSetOptions,
+  deselectedAttributes: deselectedAttributes,
+  pin: pin,
+);
+
 Future<DisclosureBasedIssuanceResult> continueDisclosureBasedIssuance({
   required List<int> selectedIndices,
   required String pin,
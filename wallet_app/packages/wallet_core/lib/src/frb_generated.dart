@@ -82,7 +82,7 @@ class WalletCore extends BaseEntrypoint<WalletCoreApi, WalletCoreApiImpl, Wallet
   String get codegenVersion => '2.12.0';
 
   @override
-  int get rustContentHash => -591652018;
+  int get rustContentHash => 1209297986;
 
   static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
     stem: 'wallet_core',
@@ -100,7 +100,20 @@ abstract class WalletCoreApi extends BaseApi {
     required String pin,
   });
 
-  Future<WalletInstructionResult> crateApiFullAcceptIssuance({required String pin});
+  Future<AcceptDisclosureResult> crateApiFullAcceptDisclosureWithDeselectedAttributes({
+    required List<Uint16List> selectedIndices,
+    required List<int?> selectedCredentialSetOptions,
+    required List<DisclosureAttributeIdentifier> deselectedAttributes,
+    required String pin,
+  });
+
+  Future<AcceptDisclosureResult> crateApiFullAcceptDisclosureWithMultipleSelection({
+    required List<Uint16List> selectedIndices,
+    required List<int?> selectedCredentialSetOptions,
+    required String pin,
+  });
+
+  Future<AcceptIssuanceResult> crateApiFullAcceptIssuance({required String pin});
 
   Future<PidIssuanceResult> crateApiFullAcceptPidIssuance({required String pin});
 
@@ -229,6 +242,11 @@ abstract class WalletCoreApi extends BaseApi {
 
   Future<IssuanceStartResult> crateApiFullStartIssuanceFromOffer({required String offerUri});
 
+  Future<IssuanceStartResult> crateApiFullStartIssuanceFromOfferWithTransactionCode({
+    required String offerUri,
+    required String transactionCode,
+  });
+
   Future<void> crateApiFullTransferWallet();
 
   Future<WalletInstructionResult> crateApiFullUnlockWallet({required String pin});
@@ -305,7 +323,75 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
   );
 
   @override
-  Future<WalletInstructionResult> crateApiFullAcceptIssuance({required String pin}) {
+  Future<AcceptDisclosureResult> crateApiFullAcceptDisclosureWithDeselectedAttributes({
+    required List<Uint16List> selectedIndices,
+    required List<int?> selectedCredentialSetOptions,
+    required List<DisclosureAttributeIdentifier> deselectedAttributes,
+    required String pin,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 = cst_encode_list_list_prim_u_16_strict(selectedIndices);
+          var arg1 = cst_encode_list_opt_box_autoadd_u_16(selectedCredentialSetOptions);
+          var arg2 = cst_encode_list_disclosure_attribute_identifier(deselectedAttributes);
+          var arg3 = cst_encode_String(pin);
+          return wire.wire__crate__api__full__accept_disclosure_with_deselected_attributes(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_accept_disclosure_result,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiFullAcceptDisclosureWithDeselectedAttributesConstMeta,
+        argValues: [selectedIndices, selectedCredentialSetOptions, deselectedAttributes, pin],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFullAcceptDisclosureWithDeselectedAttributesConstMeta => const TaskConstMeta(
+    debugName: "accept_disclosure_with_deselected_attributes",
+    argNames: ["selectedIndices", "selectedCredentialSetOptions", "deselectedAttributes", "pin"],
+  );
+
+  @override
+  Future<AcceptDisclosureResult> crateApiFullAcceptDisclosureWithMultipleSelection({
+    required List<Uint16List> selectedIndices,
+    required List<int?> selectedCredentialSetOptions,
+    required String pin,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 = cst_encode_list_list_prim_u_16_strict(selectedIndices);
+          var arg1 = cst_encode_list_opt_box_autoadd_u_16(selectedCredentialSetOptions);
+          var arg2 = cst_encode_String(pin);
+          return wire.wire__crate__api__full__accept_disclosure_with_multiple_selection(port_, arg0, arg1, arg2);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_accept_disclosure_result,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiFullAcceptDisclosureWithMultipleSelectionConstMeta,
+        argValues: [selectedIndices, selectedCredentialSetOptions, pin],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFullAcceptDisclosureWithMultipleSelectionConstMeta => const TaskConstMeta(
+    debugName: "accept_disclosure_with_multiple_selection",
+    argNames: ["selectedIndices", "selectedCredentialSetOptions", "pin"],
+  );
+
+  @override
+  Future<AcceptIssuanceResult> crateApiFullAcceptIssuance({required String pin}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -313,7 +399,7 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
           return wire.wire__crate__api__full__accept_issuance(port_, arg0);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_wallet_instruction_result,
+          decodeSuccessData: dco_decode_accept_issuance_result,
           decodeErrorData: dco_decode_AnyhowException,
         ),
         constMeta: kCrateApiFullAcceptIssuanceConstMeta,
@@ -1775,6 +1861,34 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     argNames: ["offerUri"],
   );
 
+  @override
+  Future<IssuanceStartResult> crateApiFullStartIssuanceFromOfferWithTransactionCode({
+    required String offerUri,
+    required String transactionCode,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 = cst_encode_String(offerUri);
+          var arg1 = cst_encode_String(transactionCode);
+          return wire.wire__crate__api__full__start_issuance_from_offer_with_transaction_code(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_issuance_start_result,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiFullStartIssuanceFromOfferWithTransactionCodeConstMeta,
+        argValues: [offerUri, transactionCode],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFullStartIssuanceFromOfferWithTransactionCodeConstMeta => const TaskConstMeta(
+    debugName: "start_issuance_from_offer_with_transaction_code",
+    argNames: ["offerUri", "transactionCode"],
+  );
+
   @override
   Future<void> crateApiFullTransferWallet() {
     return handler.executeNormal(
@@ -2068,6 +2182,23 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     }
   }
 
+  @protected
+  AcceptIssuanceResult dco_decode_accept_issuance_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return AcceptIssuanceResult_Ok(
+          isDeferred: dco_decode_bool(raw[1]),
+        );
+      case 1:
+        return AcceptIssuanceResult_InstructionError(
+          error: dco_decode_box_autoadd_wallet_instruction_error(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
   @protected
   AppNotification dco_decode_app_notification(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2112,7 +2243,7 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
   AttestationPresentation dco_decode_attestation_presentation(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
     return AttestationPresentation(
       identity: dco_decode_attestation_identity(arr[0]),
       format: dco_decode_format(arr[1]),
@@ -2122,6 +2253,7 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
       revocationStatus: dco_decode_opt_box_autoadd_revocation_status(arr[5]),
       validityStatus: dco_decode_validity_status(arr[6]),
       attributes: dco_decode_list_attestation_attribute(arr[7]),
+      svgTemplates: dco_decode_list_rendered_svg_template(arr[8]),
     );
   }
 
@@ -2212,6 +2344,36 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     return dco_decode_revocation_status(raw);
   }
 
+  @protected
+  SvgTemplateColorScheme dco_decode_box_autoadd_svg_template_color_scheme(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_svg_template_color_scheme(raw);
+  }
+
+  @protected
+  SvgTemplateContrast dco_decode_box_autoadd_svg_template_contrast(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_svg_template_contrast(raw);
+  }
+
+  @protected
+  SvgTemplateOrientation dco_decode_box_autoadd_svg_template_orientation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_svg_template_orientation(raw);
+  }
+
+  @protected
+  SvgTemplateProperties dco_decode_box_autoadd_svg_template_properties(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_svg_template_properties(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
   @protected
   BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2261,6 +2423,18 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     }
   }
 
+  @protected
+  DisclosureAttributeIdentifier dco_decode_disclosure_attribute_identifier(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return DisclosureAttributeIdentifier(
+      attestationOptionIndex: dco_decode_u_16(arr[0]),
+      attestationIndex: dco_decode_u_16(arr[1]),
+      attributeKey: dco_decode_list_String(arr[2]),
+    );
+  }
+
   @protected
   DisclosureBasedIssuanceResult dco_decode_disclosure_based_issuance_result(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2278,6 +2452,17 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     }
   }
 
+  @protected
+  DisclosureCredentialSet dco_decode_disclosure_credential_set(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return DisclosureCredentialSet(
+      required_: dco_decode_bool(arr[0]),
+      options: dco_decode_list_list_prim_u_16_strict(arr[1]),
+    );
+  }
+
   @protected
   DisclosureOptions dco_decode_disclosure_options(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2456,6 +2641,12 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     }
   }
 
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
   @protected
   List<AppNotification> dco_decode_list_app_notification(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2486,6 +2677,18 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     return (raw as List<dynamic>).map(dco_decode_claim_display_metadata).toList();
   }
 
+  @protected
+  List<DisclosureAttributeIdentifier> dco_decode_list_disclosure_attribute_identifier(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_disclosure_attribute_identifier).toList();
+  }
+
+  @protected
+  List<DisclosureCredentialSet> dco_decode_list_disclosure_credential_set(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_disclosure_credential_set).toList();
+  }
+
   @protected
   List<DisclosureOptions> dco_decode_list_disclosure_options(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2504,6 +2707,12 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     return (raw as List<dynamic>).map(dco_decode_display_target).toList();
   }
 
+  @protected
+  List<Uint16List> dco_decode_list_list_prim_u_16_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_list_prim_u_16_strict).toList();
+  }
+
   @protected
   List<LocalizedString> dco_decode_list_localized_string(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2516,6 +2725,12 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     return (raw as List<dynamic>).map(dco_decode_missing_attribute).toList();
   }
 
+  @protected
+  List<int?> dco_decode_list_opt_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_opt_box_autoadd_u_16).toList();
+  }
+
   @protected
   List<PidAttestation> dco_decode_list_pid_attestation(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2546,6 +2761,12 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     return (raw as List<dynamic>).map(dco_decode_record_i_32_notification_type).toList();
   }
 
+  @protected
+  List<RenderedSvgTemplate> dco_decode_list_rendered_svg_template(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_rendered_svg_template).toList();
+  }
+
   @protected
   List<WalletEvent> dco_decode_list_wallet_event(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2631,6 +2852,36 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     return raw == null ? null : dco_decode_box_autoadd_revocation_status(raw);
   }
 
+  @protected
+  SvgTemplateColorScheme? dco_decode_opt_box_autoadd_svg_template_color_scheme(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_svg_template_color_scheme(raw);
+  }
+
+  @protected
+  SvgTemplateContrast? dco_decode_opt_box_autoadd_svg_template_contrast(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_svg_template_contrast(raw);
+  }
+
+  @protected
+  SvgTemplateOrientation? dco_decode_opt_box_autoadd_svg_template_orientation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_svg_template_orientation(raw);
+  }
+
+  @protected
+  SvgTemplateProperties? dco_decode_opt_box_autoadd_svg_template_properties(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_svg_template_properties(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_16(raw);
+  }
+
   @protected
   BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2729,6 +2980,18 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     );
   }
 
+  @protected
+  RenderedSvgTemplate dco_decode_rendered_svg_template(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return RenderedSvgTemplate(
+      locale: dco_decode_String(arr[0]),
+      properties: dco_decode_opt_box_autoadd_svg_template_properties(arr[1]),
+      svg: dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSanitizedSvg(arr[2]),
+    );
+  }
+
   @protected
   RenderingMetadata dco_decode_rendering_metadata(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2797,6 +3060,9 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
           requestPurpose: dco_decode_list_localized_string(raw[6]),
           requestOriginBaseUrl: dco_decode_String(raw[7]),
           requestType: dco_decode_disclosure_type(raw[8]),
+          multipleSelectionIndices: dco_decode_list_prim_u_16_strict(raw[9]),
+          credentialSets: dco_decode_list_disclosure_credential_set(raw[10]),
+          optionalAttributes: dco_decode_list_disclosure_attribute_identifier(raw[11]),
         );
       case 1:
         return StartDisclosureResult_RequestAttributesMissing(
@@ -2812,6 +3078,36 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     }
   }
 
+  @protected
+  SvgTemplateColorScheme dco_decode_svg_template_color_scheme(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SvgTemplateColorScheme.values[raw as int];
+  }
+
+  @protected
+  SvgTemplateContrast dco_decode_svg_template_contrast(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SvgTemplateContrast.values[raw as int];
+  }
+
+  @protected
+  SvgTemplateOrientation dco_decode_svg_template_orientation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SvgTemplateOrientation.values[raw as int];
+  }
+
+  @protected
+  SvgTemplateProperties dco_decode_svg_template_properties(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SvgTemplateProperties(
+      orientation: dco_decode_opt_box_autoadd_svg_template_orientation(arr[0]),
+      colorScheme: dco_decode_opt_box_autoadd_svg_template_color_scheme(arr[1]),
+      contrast: dco_decode_opt_box_autoadd_svg_template_contrast(arr[2]),
+    );
+  }
+
   @protected
   TransferRole dco_decode_transfer_role(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3091,6 +3387,23 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     }
   }
 
+  @protected
+  AcceptIssuanceResult sse_decode_accept_issuance_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_isDeferred = sse_decode_bool(deserializer);
+        return AcceptIssuanceResult_Ok(isDeferred: var_isDeferred);
+      case 1:
+        var var_error = sse_decode_box_autoadd_wallet_instruction_error(deserializer);
+        return AcceptIssuanceResult_InstructionError(error: var_error);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
   @protected
   AppNotification sse_decode_app_notification(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3137,6 +3450,7 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     var var_revocationStatus = sse_decode_opt_box_autoadd_revocation_status(deserializer);
     var var_validityStatus = sse_decode_validity_status(deserializer);
     var var_attributes = sse_decode_list_attestation_attribute(deserializer);
+    var var_svgTemplates = sse_decode_list_rendered_svg_template(deserializer);
     return AttestationPresentation(
       identity: var_identity,
       format: var_format,
@@ -3146,6 +3460,7 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
       revocationStatus: var_revocationStatus,
       validityStatus: var_validityStatus,
       attributes: var_attributes,
+      svgTemplates: var_svgTemplates,
     );
   }
 
@@ -3235,6 +3550,36 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     return (sse_decode_revocation_status(deserializer));
   }
 
+  @protected
+  SvgTemplateColorScheme sse_decode_box_autoadd_svg_template_color_scheme(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_svg_template_color_scheme(deserializer));
+  }
+
+  @protected
+  SvgTemplateContrast sse_decode_box_autoadd_svg_template_contrast(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_svg_template_contrast(deserializer));
+  }
+
+  @protected
+  SvgTemplateOrientation sse_decode_box_autoadd_svg_template_orientation(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_svg_template_orientation(deserializer));
+  }
+
+  @protected
+  SvgTemplateProperties sse_decode_box_autoadd_svg_template_properties(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_svg_template_properties(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_16(deserializer));
+  }
+
   @protected
   BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3284,6 +3629,19 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     }
   }
 
+  @protected
+  DisclosureAttributeIdentifier sse_decode_disclosure_attribute_identifier(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_attestationOptionIndex = sse_decode_u_16(deserializer);
+    var var_attestationIndex = sse_decode_u_16(deserializer);
+    var var_attributeKey = sse_decode_list_String(deserializer);
+    return DisclosureAttributeIdentifier(
+      attestationOptionIndex: var_attestationOptionIndex,
+      attestationIndex: var_attestationIndex,
+      attributeKey: var_attributeKey,
+    );
+  }
+
   @protected
   DisclosureBasedIssuanceResult sse_decode_disclosure_based_issuance_result(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3301,6 +3659,14 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     }
   }
 
+  @protected
+  DisclosureCredentialSet sse_decode_disclosure_credential_set(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_required_ = sse_decode_bool(deserializer);
+    var var_options = sse_decode_list_list_prim_u_16_strict(deserializer);
+    return DisclosureCredentialSet(required_: var_required_, options: var_options);
+  }
+
   @protected
   DisclosureOptions sse_decode_disclosure_options(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3489,6 +3855,18 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     }
   }
 
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<AppNotification> sse_decode_list_app_notification(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3549,6 +3927,30 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     return ans_;
   }
 
+  @protected
+  List<DisclosureAttributeIdentifier> sse_decode_list_disclosure_attribute_identifier(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DisclosureAttributeIdentifier>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_disclosure_attribute_identifier(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DisclosureCredentialSet> sse_decode_list_disclosure_credential_set(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DisclosureCredentialSet>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_disclosure_credential_set(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<DisclosureOptions> sse_decode_list_disclosure_options(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3585,6 +3987,18 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     return ans_;
   }
 
+  @protected
+  List<Uint16List> sse_decode_list_list_prim_u_16_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Uint16List>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_list_prim_u_16_strict(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<LocalizedString> sse_decode_list_localized_string(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3609,6 +4023,18 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     return ans_;
   }
 
+  @protected
+  List<int?> sse_decode_list_opt_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <int?>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_opt_box_autoadd_u_16(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<PidAttestation> sse_decode_list_pid_attestation(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3654,6 +4080,18 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     return ans_;
   }
 
+  @protected
+  List<RenderedSvgTemplate> sse_decode_list_rendered_svg_template(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RenderedSvgTemplate>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_rendered_svg_template(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<WalletEvent> sse_decode_list_wallet_event(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3768,6 +4206,61 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     }
   }
 
+  @protected
+  SvgTemplateColorScheme? sse_decode_opt_box_autoadd_svg_template_color_scheme(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_svg_template_color_scheme(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SvgTemplateContrast? sse_decode_opt_box_autoadd_svg_template_contrast(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_svg_template_contrast(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SvgTemplateOrientation? sse_decode_opt_box_autoadd_svg_template_orientation(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_svg_template_orientation(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SvgTemplateProperties? sse_decode_opt_box_autoadd_svg_template_properties(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_svg_template_properties(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_16(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3878,6 +4371,17 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     return (var_field0, var_field1);
   }
 
+  @protected
+  RenderedSvgTemplate sse_decode_rendered_svg_template(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_locale = sse_decode_String(deserializer);
+    var var_properties = sse_decode_opt_box_autoadd_svg_template_properties(deserializer);
+    var var_svg = sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSanitizedSvg(
+      deserializer,
+    );
+    return RenderedSvgTemplate(locale: var_locale, properties: var_properties, svg: var_svg);
+  }
+
   @protected
   RenderingMetadata sse_decode_rendering_metadata(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3956,6 +4460,9 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
         var var_requestPurpose = sse_decode_list_localized_string(deserializer);
         var var_requestOriginBaseUrl = sse_decode_String(deserializer);
         var var_requestType = sse_decode_disclosure_type(deserializer);
+        var var_multipleSelectionIndices = sse_decode_list_prim_u_16_strict(deserializer);
+        var var_credentialSets = sse_decode_list_disclosure_credential_set(deserializer);
+        var var_optionalAttributes = sse_decode_list_disclosure_attribute_identifier(deserializer);
         return StartDisclosureResult_Request(
           relyingParty: var_relyingParty,
           policy: var_policy,
@@ -3965,6 +4472,9 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
           requestPurpose: var_requestPurpose,
           requestOriginBaseUrl: var_requestOriginBaseUrl,
           requestType: var_requestType,
+          multipleSelectionIndices: var_multipleSelectionIndices,
+          credentialSets: var_credentialSets,
+          optionalAttributes: var_optionalAttributes,
         );
       case 1:
         var var_relyingParty = sse_decode_box_autoadd_organization(deserializer);
@@ -3986,6 +4496,36 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     }
   }
 
+  @protected
+  SvgTemplateColorScheme sse_decode_svg_template_color_scheme(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SvgTemplateColorScheme.values[inner];
+  }
+
+  @protected
+  SvgTemplateContrast sse_decode_svg_template_contrast(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SvgTemplateContrast.values[inner];
+  }
+
+  @protected
+  SvgTemplateOrientation sse_decode_svg_template_orientation(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SvgTemplateOrientation.values[inner];
+  }
+
+  @protected
+  SvgTemplateProperties sse_decode_svg_template_properties(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_orientation = sse_decode_opt_box_autoadd_svg_template_orientation(deserializer);
+    var var_colorScheme = sse_decode_opt_box_autoadd_svg_template_color_scheme(deserializer);
+    var var_contrast = sse_decode_opt_box_autoadd_svg_template_contrast(deserializer);
+    return SvgTemplateProperties(orientation: var_orientation, colorScheme: var_colorScheme, contrast: var_contrast);
+  }
+
   @protected
   TransferRole sse_decode_transfer_role(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4295,6 +4835,24 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     return cst_encode_i_32(raw.index);
   }
 
+  @protected
+  int cst_encode_svg_template_color_scheme(SvgTemplateColorScheme raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
+  @protected
+  int cst_encode_svg_template_contrast(SvgTemplateContrast raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
+  @protected
+  int cst_encode_svg_template_orientation(SvgTemplateOrientation raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
   @protected
   int cst_encode_transfer_role(TransferRole raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -4523,6 +5081,19 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     }
   }
 
+  @protected
+  void sse_encode_accept_issuance_result(AcceptIssuanceResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case AcceptIssuanceResult_Ok(isDeferred: final isDeferred):
+        sse_encode_i_32(0, serializer);
+        sse_encode_bool(isDeferred, serializer);
+      case AcceptIssuanceResult_InstructionError(error: final error):
+        sse_encode_i_32(1, serializer);
+        sse_encode_box_autoadd_wallet_instruction_error(error, serializer);
+    }
+  }
+
   @protected
   void sse_encode_app_notification(AppNotification self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4563,6 +5134,7 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     sse_encode_opt_box_autoadd_revocation_status(self.revocationStatus, serializer);
     sse_encode_validity_status(self.validityStatus, serializer);
     sse_encode_list_attestation_attribute(self.attributes, serializer);
+    sse_encode_list_rendered_svg_template(self.svgTemplates, serializer);
   }
 
   @protected
@@ -4646,6 +5218,36 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     sse_encode_revocation_status(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_svg_template_color_scheme(SvgTemplateColorScheme self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_svg_template_color_scheme(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_svg_template_contrast(SvgTemplateContrast self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_svg_template_contrast(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_svg_template_orientation(SvgTemplateOrientation self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_svg_template_orientation(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_svg_template_properties(SvgTemplateProperties self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_svg_template_properties(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_16(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4691,6 +5293,14 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     }
   }
 
+  @protected
+  void sse_encode_disclosure_attribute_identifier(DisclosureAttributeIdentifier self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_16(self.attestationOptionIndex, serializer);
+    sse_encode_u_16(self.attestationIndex, serializer);
+    sse_encode_list_String(self.attributeKey, serializer);
+  }
+
   @protected
   void sse_encode_disclosure_based_issuance_result(DisclosureBasedIssuanceResult self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4704,6 +5314,13 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     }
   }
 
+  @protected
+  void sse_encode_disclosure_credential_set(DisclosureCredentialSet self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.required_, serializer);
+    sse_encode_list_list_prim_u_16_strict(self.options, serializer);
+  }
+
   @protected
   void sse_encode_disclosure_options(DisclosureOptions self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4853,6 +5470,15 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     }
   }
 
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_app_notification(List<AppNotification> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4898,6 +5524,27 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     }
   }
 
+  @protected
+  void sse_encode_list_disclosure_attribute_identifier(
+    List<DisclosureAttributeIdentifier> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_disclosure_attribute_identifier(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_disclosure_credential_set(List<DisclosureCredentialSet> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_disclosure_credential_set(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_disclosure_options(List<DisclosureOptions> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4925,6 +5572,15 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     }
   }
 
+  @protected
+  void sse_encode_list_list_prim_u_16_strict(List<Uint16List> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_list_prim_u_16_strict(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_localized_string(List<LocalizedString> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4943,6 +5599,15 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     }
   }
 
+  @protected
+  void sse_encode_list_opt_box_autoadd_u_16(List<int?> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_opt_box_autoadd_u_16(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_pid_attestation(List<PidAttestation> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4982,6 +5647,15 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     }
   }
 
+  @protected
+  void sse_encode_list_rendered_svg_template(List<RenderedSvgTemplate> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_rendered_svg_template(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_wallet_event(List<WalletEvent> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5081,6 +5755,56 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_svg_template_color_scheme(SvgTemplateColorScheme? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_svg_template_color_scheme(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_svg_template_contrast(SvgTemplateContrast? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_svg_template_contrast(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_svg_template_orientation(SvgTemplateOrientation? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_svg_template_orientation(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_svg_template_properties(SvgTemplateProperties? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_svg_template_properties(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_16(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5167,6 +5891,17 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     sse_encode_String(self.$2, serializer);
   }
 
+  @protected
+  void sse_encode_rendered_svg_template(RenderedSvgTemplate self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.locale, serializer);
+    sse_encode_opt_box_autoadd_svg_template_properties(self.properties, serializer);
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSanitizedSvg(
+      self.svg,
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_rendering_metadata(RenderingMetadata self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5228,6 +5963,9 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
         requestPurpose: final requestPurpose,
         requestOriginBaseUrl: final requestOriginBaseUrl,
         requestType: final requestType,
+        multipleSelectionIndices: final multipleSelectionIndices,
+        credentialSets: final credentialSets,
+        optionalAttributes: final optionalAttributes,
       ):
         sse_encode_i_32(0, serializer);
         sse_encode_box_autoadd_organization(relyingParty, serializer);
@@ -5238,6 +5976,9 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
         sse_encode_list_localized_string(requestPurpose, serializer);
         sse_encode_String(requestOriginBaseUrl, serializer);
         sse_encode_disclosure_type(requestType, serializer);
+        sse_encode_list_prim_u_16_strict(multipleSelectionIndices, serializer);
+        sse_encode_list_disclosure_credential_set(credentialSets, serializer);
+        sse_encode_list_disclosure_attribute_identifier(optionalAttributes, serializer);
       case StartDisclosureResult_RequestAttributesMissing(
         relyingParty: final relyingParty,
         missingAttributes: final missingAttributes,
@@ -5256,6 +5997,32 @@ class WalletCoreApiImpl extends WalletCoreApiImplPlatform implements WalletCoreA
     }
   }
 
+  @protected
+  void sse_encode_svg_template_color_scheme(SvgTemplateColorScheme self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_svg_template_contrast(SvgTemplateContrast self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_svg_template_orientation(SvgTemplateOrientation self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_svg_template_properties(SvgTemplateProperties self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_svg_template_orientation(self.orientation, serializer);
+    sse_encode_opt_box_autoadd_svg_template_color_scheme(self.colorScheme, serializer);
+    sse_encode_opt_box_autoadd_svg_template_contrast(self.contrast, serializer);
+  }
+
   @protected
   void sse_encode_transfer_role(TransferRole self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
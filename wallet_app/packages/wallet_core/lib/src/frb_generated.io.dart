@@ -91,6 +91,9 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   AcceptDisclosureResult dco_decode_accept_disclosure_result(dynamic raw);
 
+  @protected
+  AcceptIssuanceResult dco_decode_accept_issuance_result(dynamic raw);
+
   @protected
   AppNotification dco_decode_app_notification(dynamic raw);
 
@@ -136,6 +139,21 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   RevocationStatus dco_decode_box_autoadd_revocation_status(dynamic raw);
 
+  @protected
+  SvgTemplateColorScheme dco_decode_box_autoadd_svg_template_color_scheme(dynamic raw);
+
+  @protected
+  SvgTemplateContrast dco_decode_box_autoadd_svg_template_contrast(dynamic raw);
+
+  @protected
+  SvgTemplateOrientation dco_decode_box_autoadd_svg_template_orientation(dynamic raw);
+
+  @protected
+  SvgTemplateProperties dco_decode_box_autoadd_svg_template_properties(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_16(dynamic raw);
+
   @protected
   BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
@@ -151,9 +169,15 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   CloseProximityDisclosureFlutterUpdate dco_decode_close_proximity_disclosure_flutter_update(dynamic raw);
 
+  @protected
+  DisclosureAttributeIdentifier dco_decode_disclosure_attribute_identifier(dynamic raw);
+
   @protected
   DisclosureBasedIssuanceResult dco_decode_disclosure_based_issuance_result(dynamic raw);
 
+  @protected
+  DisclosureCredentialSet dco_decode_disclosure_credential_set(dynamic raw);
+
   @protected
   DisclosureOptions dco_decode_disclosure_options(dynamic raw);
 
@@ -202,6 +226,9 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   IssuanceStartResult dco_decode_issuance_start_result(dynamic raw);
 
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
   @protected
   List<AppNotification> dco_decode_list_app_notification(dynamic raw);
 
@@ -217,6 +244,12 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   List<ClaimDisplayMetadata> dco_decode_list_claim_display_metadata(dynamic raw);
 
+  @protected
+  List<DisclosureAttributeIdentifier> dco_decode_list_disclosure_attribute_identifier(dynamic raw);
+
+  @protected
+  List<DisclosureCredentialSet> dco_decode_list_disclosure_credential_set(dynamic raw);
+
   @protected
   List<DisclosureOptions> dco_decode_list_disclosure_options(dynamic raw);
 
@@ -226,12 +259,18 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   List<DisplayTarget> dco_decode_list_display_target(dynamic raw);
 
+  @protected
+  List<Uint16List> dco_decode_list_list_prim_u_16_strict(dynamic raw);
+
   @protected
   List<LocalizedString> dco_decode_list_localized_string(dynamic raw);
 
   @protected
   List<MissingAttribute> dco_decode_list_missing_attribute(dynamic raw);
 
+  @protected
+  List<int?> dco_decode_list_opt_box_autoadd_u_16(dynamic raw);
+
   @protected
   List<PidAttestation> dco_decode_list_pid_attestation(dynamic raw);
 
@@ -247,6 +286,9 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   List<(int, NotificationType)> dco_decode_list_record_i_32_notification_type(dynamic raw);
 
+  @protected
+  List<RenderedSvgTemplate> dco_decode_list_rendered_svg_template(dynamic raw);
+
   @protected
   List<WalletEvent> dco_decode_list_wallet_event(dynamic raw);
 
@@ -277,6 +319,21 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   RevocationStatus? dco_decode_opt_box_autoadd_revocation_status(dynamic raw);
 
+  @protected
+  SvgTemplateColorScheme? dco_decode_opt_box_autoadd_svg_template_color_scheme(dynamic raw);
+
+  @protected
+  SvgTemplateContrast? dco_decode_opt_box_autoadd_svg_template_contrast(dynamic raw);
+
+  @protected
+  SvgTemplateOrientation? dco_decode_opt_box_autoadd_svg_template_orientation(dynamic raw);
+
+  @protected
+  SvgTemplateProperties? dco_decode_opt_box_autoadd_svg_template_properties(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
+
   @protected
   BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
@@ -304,6 +361,9 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   (String, String) dco_decode_record_string_string(dynamic raw);
 
+  @protected
+  RenderedSvgTemplate dco_decode_rendered_svg_template(dynamic raw);
+
   @protected
   RenderingMetadata dco_decode_rendering_metadata(dynamic raw);
 
@@ -319,6 +379,18 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   StartDisclosureResult dco_decode_start_disclosure_result(dynamic raw);
 
+  @protected
+  SvgTemplateColorScheme dco_decode_svg_template_color_scheme(dynamic raw);
+
+  @protected
+  SvgTemplateContrast dco_decode_svg_template_contrast(dynamic raw);
+
+  @protected
+  SvgTemplateOrientation dco_decode_svg_template_orientation(dynamic raw);
+
+  @protected
+  SvgTemplateProperties dco_decode_svg_template_properties(dynamic raw);
+
   @protected
   TransferRole dco_decode_transfer_role(dynamic raw);
 
@@ -405,6 +477,9 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   AcceptDisclosureResult sse_decode_accept_disclosure_result(SseDeserializer deserializer);
 
+  @protected
+  AcceptIssuanceResult sse_decode_accept_issuance_result(SseDeserializer deserializer);
+
   @protected
   AppNotification sse_decode_app_notification(SseDeserializer deserializer);
 
@@ -450,6 +525,21 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   RevocationStatus sse_decode_box_autoadd_revocation_status(SseDeserializer deserializer);
 
+  @protected
+  SvgTemplateColorScheme sse_decode_box_autoadd_svg_template_color_scheme(SseDeserializer deserializer);
+
+  @protected
+  SvgTemplateContrast sse_decode_box_autoadd_svg_template_contrast(SseDeserializer deserializer);
+
+  @protected
+  SvgTemplateOrientation sse_decode_box_autoadd_svg_template_orientation(SseDeserializer deserializer);
+
+  @protected
+  SvgTemplateProperties sse_decode_box_autoadd_svg_template_properties(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
+
   @protected
   BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
 
@@ -467,9 +557,15 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  DisclosureAttributeIdentifier sse_decode_disclosure_attribute_identifier(SseDeserializer deserializer);
+
   @protected
   DisclosureBasedIssuanceResult sse_decode_disclosure_based_issuance_result(SseDeserializer deserializer);
 
+  @protected
+  DisclosureCredentialSet sse_decode_disclosure_credential_set(SseDeserializer deserializer);
+
   @protected
   DisclosureOptions sse_decode_disclosure_options(SseDeserializer deserializer);
 
@@ -518,6 +614,9 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   IssuanceStartResult sse_decode_issuance_start_result(SseDeserializer deserializer);
 
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
   @protected
   List<AppNotification> sse_decode_list_app_notification(SseDeserializer deserializer);
 
@@ -533,6 +632,12 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   List<ClaimDisplayMetadata> sse_decode_list_claim_display_metadata(SseDeserializer deserializer);
 
+  @protected
+  List<DisclosureAttributeIdentifier> sse_decode_list_disclosure_attribute_identifier(SseDeserializer deserializer);
+
+  @protected
+  List<DisclosureCredentialSet> sse_decode_list_disclosure_credential_set(SseDeserializer deserializer);
+
   @protected
   List<DisclosureOptions> sse_decode_list_disclosure_options(SseDeserializer deserializer);
 
@@ -542,12 +647,18 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   List<DisplayTarget> sse_decode_list_display_target(SseDeserializer deserializer);
 
+  @protected
+  List<Uint16List> sse_decode_list_list_prim_u_16_strict(SseDeserializer deserializer);
+
   @protected
   List<LocalizedString> sse_decode_list_localized_string(SseDeserializer deserializer);
 
   @protected
   List<MissingAttribute> sse_decode_list_missing_attribute(SseDeserializer deserializer);
 
+  @protected
+  List<int?> sse_decode_list_opt_box_autoadd_u_16(SseDeserializer deserializer);
+
   @protected
   List<PidAttestation> sse_decode_list_pid_attestation(SseDeserializer deserializer);
 
@@ -563,6 +674,9 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   List<(int, NotificationType)> sse_decode_list_record_i_32_notification_type(SseDeserializer deserializer);
 
+  @protected
+  List<RenderedSvgTemplate> sse_decode_list_rendered_svg_template(SseDeserializer deserializer);
+
   @protected
   List<WalletEvent> sse_decode_list_wallet_event(SseDeserializer deserializer);
 
@@ -593,6 +707,21 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   RevocationStatus? sse_decode_opt_box_autoadd_revocation_status(SseDeserializer deserializer);
 
+  @protected
+  SvgTemplateColorScheme? sse_decode_opt_box_autoadd_svg_template_color_scheme(SseDeserializer deserializer);
+
+  @protected
+  SvgTemplateContrast? sse_decode_opt_box_autoadd_svg_template_contrast(SseDeserializer deserializer);
+
+  @protected
+  SvgTemplateOrientation? sse_decode_opt_box_autoadd_svg_template_orientation(SseDeserializer deserializer);
+
+  @protected
+  SvgTemplateProperties? sse_decode_opt_box_autoadd_svg_template_properties(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
+
   @protected
   BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
 
@@ -620,6 +749,9 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   (String, String) sse_decode_record_string_string(SseDeserializer deserializer);
 
+  @protected
+  RenderedSvgTemplate sse_decode_rendered_svg_template(SseDeserializer deserializer);
+
   @protected
   RenderingMetadata sse_decode_rendering_metadata(SseDeserializer deserializer);
 
@@ -635,6 +767,18 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   StartDisclosureResult sse_decode_start_disclosure_result(SseDeserializer deserializer);
 
+  @protected
+  SvgTemplateColorScheme sse_decode_svg_template_color_scheme(SseDeserializer deserializer);
+
+  @protected
+  SvgTemplateContrast sse_decode_svg_template_contrast(SseDeserializer deserializer);
+
+  @protected
+  SvgTemplateOrientation sse_decode_svg_template_orientation(SseDeserializer deserializer);
+
+  @protected
+  SvgTemplateProperties sse_decode_svg_template_properties(SseDeserializer deserializer);
+
   @protected
   TransferRole sse_decode_transfer_role(SseDeserializer deserializer);
 
@@ -838,6 +982,40 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
     return wire.cst_new_box_autoadd_revocation_status(cst_encode_revocation_status(raw));
   }
 
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_box_autoadd_svg_template_color_scheme(SvgTemplateColorScheme raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_svg_template_color_scheme(cst_encode_svg_template_color_scheme(raw));
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_box_autoadd_svg_template_contrast(SvgTemplateContrast raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_svg_template_contrast(cst_encode_svg_template_contrast(raw));
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_box_autoadd_svg_template_orientation(SvgTemplateOrientation raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_svg_template_orientation(cst_encode_svg_template_orientation(raw));
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_svg_template_properties> cst_encode_box_autoadd_svg_template_properties(
+    SvgTemplateProperties raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_svg_template_properties();
+    cst_api_fill_to_wire_svg_template_properties(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint16> cst_encode_box_autoadd_u_16(int raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_u_16(cst_encode_u_16(raw));
+  }
+
   @protected
   ffi.Pointer<ffi.Uint64> cst_encode_box_autoadd_u_64(BigInt raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -874,6 +1052,16 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
     return raw.toInt();
   }
 
+  @protected
+  ffi.Pointer<wire_cst_list_String> cst_encode_list_String(List<String> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_String(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      ans.ref.ptr[i] = cst_encode_String(raw[i]);
+    }
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_cst_list_app_notification> cst_encode_list_app_notification(List<AppNotification> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -930,6 +1118,30 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
     return ans;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_list_disclosure_attribute_identifier> cst_encode_list_disclosure_attribute_identifier(
+    List<DisclosureAttributeIdentifier> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_disclosure_attribute_identifier(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_disclosure_attribute_identifier(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_disclosure_credential_set> cst_encode_list_disclosure_credential_set(
+    List<DisclosureCredentialSet> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_disclosure_credential_set(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_disclosure_credential_set(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_cst_list_disclosure_options> cst_encode_list_disclosure_options(List<DisclosureOptions> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -960,6 +1172,16 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
     return ans;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_list_list_prim_u_16_strict> cst_encode_list_list_prim_u_16_strict(List<Uint16List> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_list_prim_u_16_strict(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      ans.ref.ptr[i] = cst_encode_list_prim_u_16_strict(raw[i]);
+    }
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_cst_list_localized_string> cst_encode_list_localized_string(List<LocalizedString> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -980,6 +1202,16 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
     return ans;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_list_opt_box_autoadd_u_16> cst_encode_list_opt_box_autoadd_u_16(List<int?> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_opt_box_autoadd_u_16(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      ans.ref.ptr[i] = cst_encode_opt_box_autoadd_u_16(raw[i]);
+    }
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_cst_list_pid_attestation> cst_encode_list_pid_attestation(List<PidAttestation> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1026,6 +1258,18 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
     return ans;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_list_rendered_svg_template> cst_encode_list_rendered_svg_template(
+    List<RenderedSvgTemplate> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_rendered_svg_template(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_rendered_svg_template(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_cst_list_wallet_event> cst_encode_list_wallet_event(List<WalletEvent> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1072,6 +1316,38 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
     return raw == null ? ffi.nullptr : cst_encode_box_autoadd_revocation_status(raw);
   }
 
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_opt_box_autoadd_svg_template_color_scheme(SvgTemplateColorScheme? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_svg_template_color_scheme(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_opt_box_autoadd_svg_template_contrast(SvgTemplateContrast? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_svg_template_contrast(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_opt_box_autoadd_svg_template_orientation(SvgTemplateOrientation? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_svg_template_orientation(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_svg_template_properties> cst_encode_opt_box_autoadd_svg_template_properties(
+    SvgTemplateProperties? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_svg_template_properties(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint16> cst_encode_opt_box_autoadd_u_16(int? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_16(raw);
+  }
+
   @protected
   ffi.Pointer<ffi.Uint64> cst_encode_opt_box_autoadd_u_64(BigInt? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1123,6 +1399,25 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
     }
   }
 
+  @protected
+  void cst_api_fill_to_wire_accept_issuance_result(
+    AcceptIssuanceResult apiObj,
+    wire_cst_accept_issuance_result wireObj,
+  ) {
+    if (apiObj is AcceptIssuanceResult_Ok) {
+      var pre_is_deferred = cst_encode_bool(apiObj.isDeferred);
+      wireObj.tag = 0;
+      wireObj.kind.Ok.is_deferred = pre_is_deferred;
+      return;
+    }
+    if (apiObj is AcceptIssuanceResult_InstructionError) {
+      var pre_error = cst_encode_box_autoadd_wallet_instruction_error(apiObj.error);
+      wireObj.tag = 1;
+      wireObj.kind.InstructionError.error = pre_error;
+      return;
+    }
+  }
+
   @protected
   void cst_api_fill_to_wire_app_notification(AppNotification apiObj, wire_cst_app_notification wireObj) {
     wireObj.id = cst_encode_i_32(apiObj.id);
@@ -1165,6 +1460,7 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
     wireObj.revocation_status = cst_encode_opt_box_autoadd_revocation_status(apiObj.revocationStatus);
     cst_api_fill_to_wire_validity_status(apiObj.validityStatus, wireObj.validity_status);
     wireObj.attributes = cst_encode_list_attestation_attribute(apiObj.attributes);
+    wireObj.svg_templates = cst_encode_list_rendered_svg_template(apiObj.svgTemplates);
   }
 
   @protected
@@ -1249,6 +1545,14 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
     cst_api_fill_to_wire_request_policy(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_svg_template_properties(
+    SvgTemplateProperties apiObj,
+    ffi.Pointer<wire_cst_svg_template_properties> wireObj,
+  ) {
+    cst_api_fill_to_wire_svg_template_properties(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_wallet_instruction_error(
     WalletInstructionError apiObj,
@@ -1297,6 +1601,16 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
     }
   }
 
+  @protected
+  void cst_api_fill_to_wire_disclosure_attribute_identifier(
+    DisclosureAttributeIdentifier apiObj,
+    wire_cst_disclosure_attribute_identifier wireObj,
+  ) {
+    wireObj.attestation_option_index = cst_encode_u_16(apiObj.attestationOptionIndex);
+    wireObj.attestation_index = cst_encode_u_16(apiObj.attestationIndex);
+    wireObj.attribute_key = cst_encode_list_String(apiObj.attributeKey);
+  }
+
   @protected
   void cst_api_fill_to_wire_disclosure_based_issuance_result(
     DisclosureBasedIssuanceResult apiObj,
@@ -1316,6 +1630,15 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
     }
   }
 
+  @protected
+  void cst_api_fill_to_wire_disclosure_credential_set(
+    DisclosureCredentialSet apiObj,
+    wire_cst_disclosure_credential_set wireObj,
+  ) {
+    wireObj.required = cst_encode_bool(apiObj.required_);
+    wireObj.options = cst_encode_list_list_prim_u_16_strict(apiObj.options);
+  }
+
   @protected
   void cst_api_fill_to_wire_disclosure_options(DisclosureOptions apiObj, wire_cst_disclosure_options wireObj) {
     wireObj.field0 = cst_encode_list_attestation_presentation(apiObj.field0);
@@ -1521,6 +1844,15 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
     wireObj.field1 = cst_encode_String(apiObj.$2);
   }
 
+  @protected
+  void cst_api_fill_to_wire_rendered_svg_template(RenderedSvgTemplate apiObj, wire_cst_rendered_svg_template wireObj) {
+    wireObj.locale = cst_encode_String(apiObj.locale);
+    wireObj.properties = cst_encode_opt_box_autoadd_svg_template_properties(apiObj.properties);
+    wireObj.svg = cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSanitizedSvg(
+      apiObj.svg,
+    );
+  }
+
   @protected
   void cst_api_fill_to_wire_rendering_metadata(RenderingMetadata apiObj, wire_cst_rendering_metadata wireObj) {
     if (apiObj is RenderingMetadata_Simple) {
@@ -1582,6 +1914,9 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
       var pre_request_purpose = cst_encode_list_localized_string(apiObj.requestPurpose);
       var pre_request_origin_base_url = cst_encode_String(apiObj.requestOriginBaseUrl);
       var pre_request_type = cst_encode_disclosure_type(apiObj.requestType);
+      var pre_multiple_selection_indices = cst_encode_list_prim_u_16_strict(apiObj.multipleSelectionIndices);
+      var pre_credential_sets = cst_encode_list_disclosure_credential_set(apiObj.credentialSets);
+      var pre_optional_attributes = cst_encode_list_disclosure_attribute_identifier(apiObj.optionalAttributes);
       wireObj.tag = 0;
       wireObj.kind.Request.relying_party = pre_relying_party;
       wireObj.kind.Request.policy = pre_policy;
@@ -1591,6 +1926,9 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
       wireObj.kind.Request.request_purpose = pre_request_purpose;
       wireObj.kind.Request.request_origin_base_url = pre_request_origin_base_url;
       wireObj.kind.Request.request_type = pre_request_type;
+      wireObj.kind.Request.multiple_selection_indices = pre_multiple_selection_indices;
+      wireObj.kind.Request.credential_sets = pre_credential_sets;
+      wireObj.kind.Request.optional_attributes = pre_optional_attributes;
       return;
     }
     if (apiObj is StartDisclosureResult_RequestAttributesMissing) {
@@ -1612,6 +1950,16 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
     }
   }
 
+  @protected
+  void cst_api_fill_to_wire_svg_template_properties(
+    SvgTemplateProperties apiObj,
+    wire_cst_svg_template_properties wireObj,
+  ) {
+    wireObj.orientation = cst_encode_opt_box_autoadd_svg_template_orientation(apiObj.orientation);
+    wireObj.color_scheme = cst_encode_opt_box_autoadd_svg_template_color_scheme(apiObj.colorScheme);
+    wireObj.contrast = cst_encode_opt_box_autoadd_svg_template_contrast(apiObj.contrast);
+  }
+
   @protected
   void cst_api_fill_to_wire_validity_status(ValidityStatus apiObj, wire_cst_validity_status wireObj) {
     if (apiObj is ValidityStatus_NotYetValid) {
@@ -1841,6 +2189,15 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   int cst_encode_revocation_status(RevocationStatus raw);
 
+  @protected
+  int cst_encode_svg_template_color_scheme(SvgTemplateColorScheme raw);
+
+  @protected
+  int cst_encode_svg_template_contrast(SvgTemplateContrast raw);
+
+  @protected
+  int cst_encode_svg_template_orientation(SvgTemplateOrientation raw);
+
   @protected
   int cst_encode_transfer_role(TransferRole raw);
 
@@ -1937,6 +2294,9 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   void sse_encode_accept_disclosure_result(AcceptDisclosureResult self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_accept_issuance_result(AcceptIssuanceResult self, SseSerializer serializer);
+
   @protected
   void sse_encode_app_notification(AppNotification self, SseSerializer serializer);
 
@@ -1982,6 +2342,21 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   void sse_encode_box_autoadd_revocation_status(RevocationStatus self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_svg_template_color_scheme(SvgTemplateColorScheme self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_svg_template_contrast(SvgTemplateContrast self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_svg_template_orientation(SvgTemplateOrientation self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_svg_template_properties(SvgTemplateProperties self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
 
@@ -2000,9 +2375,15 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_disclosure_attribute_identifier(DisclosureAttributeIdentifier self, SseSerializer serializer);
+
   @protected
   void sse_encode_disclosure_based_issuance_result(DisclosureBasedIssuanceResult self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_disclosure_credential_set(DisclosureCredentialSet self, SseSerializer serializer);
+
   @protected
   void sse_encode_disclosure_options(DisclosureOptions self, SseSerializer serializer);
 
@@ -2051,6 +2432,9 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   void sse_encode_issuance_start_result(IssuanceStartResult self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_app_notification(List<AppNotification> self, SseSerializer serializer);
 
@@ -2066,6 +2450,15 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   void sse_encode_list_claim_display_metadata(List<ClaimDisplayMetadata> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_disclosure_attribute_identifier(
+    List<DisclosureAttributeIdentifier> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_disclosure_credential_set(List<DisclosureCredentialSet> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_disclosure_options(List<DisclosureOptions> self, SseSerializer serializer);
 
@@ -2075,12 +2468,18 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   void sse_encode_list_display_target(List<DisplayTarget> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_list_prim_u_16_strict(List<Uint16List> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_localized_string(List<LocalizedString> self, SseSerializer serializer);
 
   @protected
   void sse_encode_list_missing_attribute(List<MissingAttribute> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_opt_box_autoadd_u_16(List<int?> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_pid_attestation(List<PidAttestation> self, SseSerializer serializer);
 
@@ -2096,6 +2495,9 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   void sse_encode_list_record_i_32_notification_type(List<(int, NotificationType)> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_rendered_svg_template(List<RenderedSvgTemplate> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_wallet_event(List<WalletEvent> self, SseSerializer serializer);
 
@@ -2126,6 +2528,21 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   void sse_encode_opt_box_autoadd_revocation_status(RevocationStatus? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_svg_template_color_scheme(SvgTemplateColorScheme? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_svg_template_contrast(SvgTemplateContrast? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_svg_template_orientation(SvgTemplateOrientation? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_svg_template_properties(SvgTemplateProperties? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
@@ -2153,6 +2570,9 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   void sse_encode_record_string_string((String, String) self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_rendered_svg_template(RenderedSvgTemplate self, SseSerializer serializer);
+
   @protected
   void sse_encode_rendering_metadata(RenderingMetadata self, SseSerializer serializer);
 
@@ -2168,6 +2588,18 @@ abstract class WalletCoreApiImplPlatform extends BaseApiImpl<WalletCoreWire> {
   @protected
   void sse_encode_start_disclosure_result(StartDisclosureResult self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_svg_template_color_scheme(SvgTemplateColorScheme self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_svg_template_contrast(SvgTemplateContrast self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_svg_template_orientation(SvgTemplateOrientation self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_svg_template_properties(SvgTemplateProperties self, SseSerializer serializer);
+
   @protected
   void sse_encode_transfer_role(TransferRole self, SseSerializer serializer);
 
@@ -2282,6 +2714,86 @@ class WalletCoreWire implements BaseWire {
         )
       >();
 
+  void wire__crate__api__full__accept_disclosure_with_deselected_attributes(
+    int port_,
+    ffi.Pointer<wire_cst_list_list_prim_u_16_strict> selected_indices,
+    ffi.Pointer<wire_cst_list_opt_box_autoadd_u_16> selected_credential_set_options,
+    ffi.Pointer<wire_cst_list_disclosure_attribute_identifier> deselected_attributes,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pin,
+  ) {
+    return _wire__crate__api__full__accept_disclosure_with_deselected_attributes(
+      port_,
+      selected_indices,
+      selected_credential_set_options,
+      deselected_attributes,
+      pin,
+    );
+  }
+
+  late final _wire__crate__api__full__accept_disclosure_with_deselected_attributesPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_list_prim_u_16_strict>,
+            ffi.Pointer<wire_cst_list_opt_box_autoadd_u_16>,
+            ffi.Pointer<wire_cst_list_disclosure_attribute_identifier>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >(
+        'frbgen_wallet_core_wire__crate__api__full__accept_disclosure_with_deselected_attributes',
+      );
+  late final _wire__crate__api__full__accept_disclosure_with_deselected_attributes =
+      _wire__crate__api__full__accept_disclosure_with_deselected_attributesPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_list_prim_u_16_strict>,
+              ffi.Pointer<wire_cst_list_opt_box_autoadd_u_16>,
+              ffi.Pointer<wire_cst_list_disclosure_attribute_identifier>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__full__accept_disclosure_with_multiple_selection(
+    int port_,
+    ffi.Pointer<wire_cst_list_list_prim_u_16_strict> selected_indices,
+    ffi.Pointer<wire_cst_list_opt_box_autoadd_u_16> selected_credential_set_options,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pin,
+  ) {
+    return _wire__crate__api__full__accept_disclosure_with_multiple_selection(
+      port_,
+      selected_indices,
+      selected_credential_set_options,
+      pin,
+    );
+  }
+
+  late final _wire__crate__api__full__accept_disclosure_with_multiple_selectionPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_list_prim_u_16_strict>,
+            ffi.Pointer<wire_cst_list_opt_box_autoadd_u_16>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >(
+        'frbgen_wallet_core_wire__crate__api__full__accept_disclosure_with_multiple_selection',
+      );
+  late final _wire__crate__api__full__accept_disclosure_with_multiple_selection =
+      _wire__crate__api__full__accept_disclosure_with_multiple_selectionPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_list_prim_u_16_strict>,
+              ffi.Pointer<wire_cst_list_opt_box_autoadd_u_16>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
   void wire__crate__api__full__accept_issuance(
     int port_,
     ffi.Pointer<wire_cst_list_prim_u_8_strict> pin,
@@ -3205,6 +3717,40 @@ class WalletCoreWire implements BaseWire {
   late final _wire__crate__api__full__start_issuance_from_offer = _wire__crate__api__full__start_issuance_from_offerPtr
       .asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
+  void wire__crate__api__full__start_issuance_from_offer_with_transaction_code(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> offer_uri,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> transaction_code,
+  ) {
+    return _wire__crate__api__full__start_issuance_from_offer_with_transaction_code(
+      port_,
+      offer_uri,
+      transaction_code,
+    );
+  }
+
+  late final _wire__crate__api__full__start_issuance_from_offer_with_transaction_codePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >(
+        'frbgen_wallet_core_wire__crate__api__full__start_issuance_from_offer_with_transaction_code',
+      );
+  late final _wire__crate__api__full__start_issuance_from_offer_with_transaction_code =
+      _wire__crate__api__full__start_issuance_from_offer_with_transaction_codePtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
   void wire__crate__api__full__transfer_wallet(int port_) {
     return _wire__crate__api__full__transfer_wallet(port_);
   }
@@ -3364,6 +3910,60 @@ class WalletCoreWire implements BaseWire {
   late final _cst_new_box_autoadd_revocation_status = _cst_new_box_autoadd_revocation_statusPtr
       .asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
 
+  ffi.Pointer<ffi.Int32> cst_new_box_autoadd_svg_template_color_scheme(int value) {
+    return _cst_new_box_autoadd_svg_template_color_scheme(value);
+  }
+
+  late final _cst_new_box_autoadd_svg_template_color_schemePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+        'frbgen_wallet_core_cst_new_box_autoadd_svg_template_color_scheme',
+      );
+  late final _cst_new_box_autoadd_svg_template_color_scheme = _cst_new_box_autoadd_svg_template_color_schemePtr
+      .asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<ffi.Int32> cst_new_box_autoadd_svg_template_contrast(int value) {
+    return _cst_new_box_autoadd_svg_template_contrast(value);
+  }
+
+  late final _cst_new_box_autoadd_svg_template_contrastPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+        'frbgen_wallet_core_cst_new_box_autoadd_svg_template_contrast',
+      );
+  late final _cst_new_box_autoadd_svg_template_contrast = _cst_new_box_autoadd_svg_template_contrastPtr
+      .asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<ffi.Int32> cst_new_box_autoadd_svg_template_orientation(int value) {
+    return _cst_new_box_autoadd_svg_template_orientation(value);
+  }
+
+  late final _cst_new_box_autoadd_svg_template_orientationPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+        'frbgen_wallet_core_cst_new_box_autoadd_svg_template_orientation',
+      );
+  late final _cst_new_box_autoadd_svg_template_orientation = _cst_new_box_autoadd_svg_template_orientationPtr
+      .asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<wire_cst_svg_template_properties> cst_new_box_autoadd_svg_template_properties() {
+    return _cst_new_box_autoadd_svg_template_properties();
+  }
+
+  late final _cst_new_box_autoadd_svg_template_propertiesPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_svg_template_properties> Function()>>(
+        'frbgen_wallet_core_cst_new_box_autoadd_svg_template_properties',
+      );
+  late final _cst_new_box_autoadd_svg_template_properties = _cst_new_box_autoadd_svg_template_propertiesPtr
+      .asFunction<ffi.Pointer<wire_cst_svg_template_properties> Function()>();
+
+  ffi.Pointer<ffi.Uint16> cst_new_box_autoadd_u_16(int value) {
+    return _cst_new_box_autoadd_u_16(value);
+  }
+
+  late final _cst_new_box_autoadd_u_16Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint16> Function(ffi.Uint16)>>(
+    'frbgen_wallet_core_cst_new_box_autoadd_u_16',
+  );
+  late final _cst_new_box_autoadd_u_16 = _cst_new_box_autoadd_u_16Ptr
+      .asFunction<ffi.Pointer<ffi.Uint16> Function(int)>();
+
   ffi.Pointer<ffi.Uint64> cst_new_box_autoadd_u_64(int value) {
     return _cst_new_box_autoadd_u_64(value);
   }
@@ -3395,6 +3995,17 @@ class WalletCoreWire implements BaseWire {
   late final _cst_new_box_wallet_state = _cst_new_box_wallet_statePtr
       .asFunction<ffi.Pointer<wire_cst_wallet_state> Function()>();
 
+  ffi.Pointer<wire_cst_list_String> cst_new_list_String(int len) {
+    return _cst_new_list_String(len);
+  }
+
+  late final _cst_new_list_StringPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_String> Function(ffi.Int32)>>(
+        'frbgen_wallet_core_cst_new_list_String',
+      );
+  late final _cst_new_list_String = _cst_new_list_StringPtr
+      .asFunction<ffi.Pointer<wire_cst_list_String> Function(int)>();
+
   ffi.Pointer<wire_cst_list_app_notification> cst_new_list_app_notification(
     int len,
   ) {
@@ -3458,6 +4069,28 @@ class WalletCoreWire implements BaseWire {
   late final _cst_new_list_claim_display_metadata = _cst_new_list_claim_display_metadataPtr
       .asFunction<ffi.Pointer<wire_cst_list_claim_display_metadata> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_disclosure_attribute_identifier> cst_new_list_disclosure_attribute_identifier(int len) {
+    return _cst_new_list_disclosure_attribute_identifier(len);
+  }
+
+  late final _cst_new_list_disclosure_attribute_identifierPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_disclosure_attribute_identifier> Function(ffi.Int32)>>(
+        'frbgen_wallet_core_cst_new_list_disclosure_attribute_identifier',
+      );
+  late final _cst_new_list_disclosure_attribute_identifier = _cst_new_list_disclosure_attribute_identifierPtr
+      .asFunction<ffi.Pointer<wire_cst_list_disclosure_attribute_identifier> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_disclosure_credential_set> cst_new_list_disclosure_credential_set(int len) {
+    return _cst_new_list_disclosure_credential_set(len);
+  }
+
+  late final _cst_new_list_disclosure_credential_setPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_disclosure_credential_set> Function(ffi.Int32)>>(
+        'frbgen_wallet_core_cst_new_list_disclosure_credential_set',
+      );
+  late final _cst_new_list_disclosure_credential_set = _cst_new_list_disclosure_credential_setPtr
+      .asFunction<ffi.Pointer<wire_cst_list_disclosure_credential_set> Function(int)>();
+
   ffi.Pointer<wire_cst_list_disclosure_options> cst_new_list_disclosure_options(
     int len,
   ) {
@@ -3497,6 +4130,17 @@ class WalletCoreWire implements BaseWire {
   late final _cst_new_list_display_target = _cst_new_list_display_targetPtr
       .asFunction<ffi.Pointer<wire_cst_list_display_target> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_list_prim_u_16_strict> cst_new_list_list_prim_u_16_strict(int len) {
+    return _cst_new_list_list_prim_u_16_strict(len);
+  }
+
+  late final _cst_new_list_list_prim_u_16_strictPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_list_prim_u_16_strict> Function(ffi.Int32)>>(
+        'frbgen_wallet_core_cst_new_list_list_prim_u_16_strict',
+      );
+  late final _cst_new_list_list_prim_u_16_strict = _cst_new_list_list_prim_u_16_strictPtr
+      .asFunction<ffi.Pointer<wire_cst_list_list_prim_u_16_strict> Function(int)>();
+
   ffi.Pointer<wire_cst_list_localized_string> cst_new_list_localized_string(
     int len,
   ) {
@@ -3523,6 +4167,17 @@ class WalletCoreWire implements BaseWire {
   late final _cst_new_list_missing_attribute = _cst_new_list_missing_attributePtr
       .asFunction<ffi.Pointer<wire_cst_list_missing_attribute> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_opt_box_autoadd_u_16> cst_new_list_opt_box_autoadd_u_16(int len) {
+    return _cst_new_list_opt_box_autoadd_u_16(len);
+  }
+
+  late final _cst_new_list_opt_box_autoadd_u_16Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_opt_box_autoadd_u_16> Function(ffi.Int32)>>(
+        'frbgen_wallet_core_cst_new_list_opt_box_autoadd_u_16',
+      );
+  late final _cst_new_list_opt_box_autoadd_u_16 = _cst_new_list_opt_box_autoadd_u_16Ptr
+      .asFunction<ffi.Pointer<wire_cst_list_opt_box_autoadd_u_16> Function(int)>();
+
   ffi.Pointer<wire_cst_list_pid_attestation> cst_new_list_pid_attestation(
     int len,
   ) {
@@ -3594,6 +4249,17 @@ class WalletCoreWire implements BaseWire {
         )
       >();
 
+  ffi.Pointer<wire_cst_list_rendered_svg_template> cst_new_list_rendered_svg_template(int len) {
+    return _cst_new_list_rendered_svg_template(len);
+  }
+
+  late final _cst_new_list_rendered_svg_templatePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_rendered_svg_template> Function(ffi.Int32)>>(
+        'frbgen_wallet_core_cst_new_list_rendered_svg_template',
+      );
+  late final _cst_new_list_rendered_svg_template = _cst_new_list_rendered_svg_templatePtr
+      .asFunction<ffi.Pointer<wire_cst_list_rendered_svg_template> Function(int)>();
+
   ffi.Pointer<wire_cst_list_wallet_event> cst_new_list_wallet_event(int len) {
     return _cst_new_list_wallet_event(len);
   }
@@ -3635,6 +4301,51 @@ final class wire_cst_list_prim_u_8_strict extends ffi.Struct {
   external int len;
 }
 
+final class wire_cst_list_prim_u_16_strict extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint16> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_list_list_prim_u_16_strict extends ffi.Struct {
+  external ffi.Pointer<ffi.Pointer<wire_cst_list_prim_u_16_strict>> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_list_opt_box_autoadd_u_16 extends ffi.Struct {
+  external ffi.Pointer<ffi.Pointer<ffi.Uint16>> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_list_String extends ffi.Struct {
+  external ffi.Pointer<ffi.Pointer<wire_cst_list_prim_u_8_strict>> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_disclosure_attribute_identifier extends ffi.Struct {
+  @ffi.Uint16()
+  external int attestation_option_index;
+
+  @ffi.Uint16()
+  external int attestation_index;
+
+  external ffi.Pointer<wire_cst_list_String> attribute_key;
+}
+
+final class wire_cst_list_disclosure_attribute_identifier extends ffi.Struct {
+  external ffi.Pointer<wire_cst_disclosure_attribute_identifier> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
 final class wire_cst_AttestationIdentity_Fixed extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> id;
 }
@@ -3874,6 +4585,30 @@ final class wire_cst_list_attestation_attribute extends ffi.Struct {
   external int len;
 }
 
+final class wire_cst_svg_template_properties extends ffi.Struct {
+  external ffi.Pointer<ffi.Int32> orientation;
+
+  external ffi.Pointer<ffi.Int32> color_scheme;
+
+  external ffi.Pointer<ffi.Int32> contrast;
+}
+
+final class wire_cst_rendered_svg_template extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> locale;
+
+  external ffi.Pointer<wire_cst_svg_template_properties> properties;
+
+  @ffi.UintPtr()
+  external int svg;
+}
+
+final class wire_cst_list_rendered_svg_template extends ffi.Struct {
+  external ffi.Pointer<wire_cst_rendered_svg_template> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
 final class wire_cst_attestation_presentation extends ffi.Struct {
   external wire_cst_attestation_identity identity;
 
@@ -3891,6 +4626,8 @@ final class wire_cst_attestation_presentation extends ffi.Struct {
   external wire_cst_validity_status validity_status;
 
   external ffi.Pointer<wire_cst_list_attestation_attribute> attributes;
+
+  external ffi.Pointer<wire_cst_list_rendered_svg_template> svg_templates;
 }
 
 final class wire_cst_record_string_string extends ffi.Struct {
@@ -4043,6 +4780,20 @@ final class wire_cst_list_attestation_presentation extends ffi.Struct {
   external int len;
 }
 
+final class wire_cst_disclosure_credential_set extends ffi.Struct {
+  @ffi.Bool()
+  external bool required;
+
+  external ffi.Pointer<wire_cst_list_list_prim_u_16_strict> options;
+}
+
+final class wire_cst_list_disclosure_credential_set extends ffi.Struct {
+  external ffi.Pointer<wire_cst_disclosure_credential_set> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
 final class wire_cst_disclosure_options extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_attestation_presentation> field0;
 }
@@ -4079,13 +4830,6 @@ final class wire_cst_list_pid_attestation extends ffi.Struct {
   external int len;
 }
 
-final class wire_cst_list_prim_u_16_strict extends ffi.Struct {
-  external ffi.Pointer<ffi.Uint16> ptr;
-
-  @ffi.Int32()
-  external int len;
-}
-
 final class wire_cst_record_i_32_notification_type extends ffi.Struct {
   @ffi.Int32()
   external int field0;
@@ -4182,6 +4926,28 @@ final class wire_cst_accept_disclosure_result extends ffi.Struct {
   external AcceptDisclosureResultKind kind;
 }
 
+final class wire_cst_AcceptIssuanceResult_Ok extends ffi.Struct {
+  @ffi.Bool()
+  external bool is_deferred;
+}
+
+final class wire_cst_AcceptIssuanceResult_InstructionError extends ffi.Struct {
+  external ffi.Pointer<wire_cst_wallet_instruction_error> error;
+}
+
+final class AcceptIssuanceResultKind extends ffi.Union {
+  external wire_cst_AcceptIssuanceResult_Ok Ok;
+
+  external wire_cst_AcceptIssuanceResult_InstructionError InstructionError;
+}
+
+final class wire_cst_accept_issuance_result extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external AcceptIssuanceResultKind kind;
+}
+
 final class wire_cst_CloseProximityDisclosureFlutterUpdate_Errored extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> error;
 }
@@ -4338,6 +5104,12 @@ final class wire_cst_StartDisclosureResult_Request extends ffi.Struct {
 
   @ffi.Int32()
   external int request_type;
+
+  external ffi.Pointer<wire_cst_list_prim_u_16_strict> multiple_selection_indices;
+
+  external ffi.Pointer<wire_cst_list_disclosure_credential_set> credential_sets;
+
+  external ffi.Pointer<wire_cst_list_disclosure_attribute_identifier> optional_attributes;
 }
 
 final class wire_cst_StartDisclosureResult_RequestAttributesMissing extends ffi.Struct {
@@ -58,6 +58,7 @@ class AttestationPresentation {
   final RevocationStatus? revocationStatus;
   final ValidityStatus validityStatus;
   final List<AttestationAttribute> attributes;
+  final List<RenderedSvgTemplate> svgTemplates;
 
   const AttestationPresentation({
     required this.identity,
@@ -68,6 +69,7 @@ class AttestationPresentation {
     this.revocationStatus,
     required this.validityStatus,
     required this.attributes,
+    required this.svgTemplates,
   });
 
   @override
@@ -79,7 +81,8 @@ class AttestationPresentation {
       issuer.hashCode ^
       revocationStatus.hashCode ^
       validityStatus.hashCode ^
-      attributes.hashCode;
+      attributes.hashCode ^
+      svgTemplates.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -93,7 +96,8 @@ class AttestationPresentation {
           issuer == other.issuer &&
           revocationStatus == other.revocationStatus &&
           validityStatus == other.validityStatus &&
-          attributes == other.attributes;
+          attributes == other.attributes &&
+          svgTemplates == other.svgTemplates;
 }
 
 @freezed
@@ -174,6 +178,31 @@ enum Format {
   SdJwt,
 }
 
+/// An SVG template from the display metadata, rendered using the attribute values of the attestation.
+class RenderedSvgTemplate {
+  final String locale;
+  final SvgTemplateProperties? properties;
+  final SanitizedSvg svg;
+
+  const RenderedSvgTemplate({
+    required this.locale,
+    this.properties,
+    required this.svg,
+  });
+
+  @override
+  int get hashCode => locale.hashCode ^ properties.hashCode ^ svg.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RenderedSvgTemplate &&
+          runtimeType == other.runtimeType &&
+          locale == other.locale &&
+          properties == other.properties &&
+          svg == other.svg;
+}
+
 @freezed
 sealed class RenderingMetadata with _$RenderingMetadata {
   const RenderingMetadata._();
@@ -187,6 +216,45 @@ sealed class RenderingMetadata with _$RenderingMetadata {
   const factory RenderingMetadata.svgTemplates() = RenderingMetadata_SvgTemplates;
 }
 
+enum SvgTemplateColorScheme {
+  Light,
+  Dark,
+}
+
+enum SvgTemplateContrast {
+  Normal,
+  High,
+}
+
+enum SvgTemplateOrientation {
+  Portrait,
+  Landscape,
+}
+
+class SvgTemplateProperties {
+  final SvgTemplateOrientation? orientation;
+  final SvgTemplateColorScheme? colorScheme;
+  final SvgTemplateContrast? contrast;
+
+  const SvgTemplateProperties({
+    this.orientation,
+    this.colorScheme,
+    this.contrast,
+  });
+
+  @override
+  int get hashCode => orientation.hashCode ^ colorScheme.hashCode ^ contrast.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SvgTemplateProperties &&
+          runtimeType == other.runtimeType &&
+          orientation == other.orientation &&
+          colorScheme == other.colorScheme &&
+          contrast == other.contrast;
+}
+
 @freezed
 sealed class ValidityStatus with _$ValidityStatus {
   const ValidityStatus._();
@@ -40,6 +40,51 @@ sealed class CloseProximityDisclosureFlutterUpdate with _$CloseProximityDisclosu
   }) = CloseProximityDisclosureFlutterUpdate_Errored;
 }
 
+class DisclosureAttributeIdentifier {
+  final int attestationOptionIndex;
+  final int attestationIndex;
+  final List<String> attributeKey;
+
+  const DisclosureAttributeIdentifier({
+    required this.attestationOptionIndex,
+    required this.attestationIndex,
+    required this.attributeKey,
+  });
+
+  @override
+  int get hashCode => attestationOptionIndex.hashCode ^ attestationIndex.hashCode ^ attributeKey.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DisclosureAttributeIdentifier &&
+          runtimeType == other.runtimeType &&
+          attestationOptionIndex == other.attestationOptionIndex &&
+          attestationIndex == other.attestationIndex &&
+          attributeKey == other.attributeKey;
+}
+
+class DisclosureCredentialSet {
+  final bool required_;
+  final List<Uint16List> options;
+
+  const DisclosureCredentialSet({
+    required this.required_,
+    required this.options,
+  });
+
+  @override
+  int get hashCode => required_.hashCode ^ options.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DisclosureCredentialSet &&
+          runtimeType == other.runtimeType &&
+          required_ == other.required_ &&
+          options == other.options;
+}
+
 class DisclosureOptions {
   final List<AttestationPresentation> field0;
 
@@ -126,6 +171,11 @@ sealed class StartDisclosureResult with _$StartDisclosureResult {
     required List<LocalizedString> requestPurpose,
     required String requestOriginBaseUrl,
     required DisclosureType requestType,
+    /// The indices of the disclosure options for which more than one attestation may be selected.
+    required Uint16List multipleSelectionIndices,
+    required List<DisclosureCredentialSet> credentialSets,
+    /// The attributes that are not required by the relying party, which the user may de-select.
+    required List<DisclosureAttributeIdentifier> optionalAttributes,
   }) = StartDisclosureResult_Request;
   const factory StartDisclosureResult.requestAttributesMissing({
     required Organization relyingParty,
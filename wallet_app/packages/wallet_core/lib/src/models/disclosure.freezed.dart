@@ -789,10 +789,10 @@ return requestAttributesMissing(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( Organization relyingParty,  RequestPolicy policy,  List<DisclosureOptions> disclosureOptions,  bool sharedDataWithRelyingPartyBefore,  DisclosureSessionType sessionType,  List<LocalizedString> requestPurpose,  String requestOriginBaseUrl,  DisclosureType requestType)?  request,TResult Function( Organization relyingParty,  List<MissingAttribute> missingAttributes,  bool sharedDataWithRelyingPartyBefore,  DisclosureSessionType sessionType,  List<LocalizedString> requestPurpose,  String requestOriginBaseUrl)?  requestAttributesMissing,required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( Organization relyingParty,  RequestPolicy policy,  List<DisclosureOptions> disclosureOptions,  bool sharedDataWithRelyingPartyBefore,  DisclosureSessionType sessionType,  List<LocalizedString> requestPurpose,  String requestOriginBaseUrl,  DisclosureType requestType,  Uint16List multipleSelectionIndices,  List<DisclosureCredentialSet> credentialSets,  List<DisclosureAttributeIdentifier> optionalAttributes)?  request,TResult Function( Organization relyingParty,  List<MissingAttribute> missingAttributes,  bool sharedDataWithRelyingPartyBefore,  DisclosureSessionType sessionType,  List<LocalizedString> requestPurpose,  String requestOriginBaseUrl)?  requestAttributesMissing,required TResult orElse(),}) {final _that = this;
 switch (_that) {
 case StartDisclosureResult_Request() when request != null:
-return request(_that.relyingParty,_that.policy,_that.disclosureOptions,_that.sharedDataWithRelyingPartyBefore,_that.sessionType,_that.requestPurpose,_that.requestOriginBaseUrl,_that.requestType);case StartDisclosureResult_RequestAttributesMissing() when requestAttributesMissing != null:
+return request(_that.relyingParty,_that.policy,_that.disclosureOptions,_that.sharedDataWithRelyingPartyBefore,_that.sessionType,_that.requestPurpose,_that.requestOriginBaseUrl,_that.requestType,_that.multipleSelectionIndices,_that.credentialSets,_that.optionalAttributes);case StartDisclosureResult_RequestAttributesMissing() when requestAttributesMissing != null:
 return requestAttributesMissing(_that.relyingParty,_that.missingAttributes,_that.sharedDataWithRelyingPartyBefore,_that.sessionType,_that.requestPurpose,_that.requestOriginBaseUrl);case _:
   return orElse();
 
@@ -811,10 +811,10 @@ return requestAttributesMissing(_that.relyingParty,_that.missingAttributes,_that
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( Organization relyingParty,  RequestPolicy policy,  List<DisclosureOptions> disclosureOptions,  bool sharedDataWithRelyingPartyBefore,  DisclosureSessionType sessionType,  List<LocalizedString> requestPurpose,  String requestOriginBaseUrl,  DisclosureType requestType)  request,required TResult Function( Organization relyingParty,  List<MissingAttribute> missingAttributes,  bool sharedDataWithRelyingPartyBefore,  DisclosureSessionType sessionType,  List<LocalizedString> requestPurpose,  String requestOriginBaseUrl)  requestAttributesMissing,}) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( Organization relyingParty,  RequestPolicy policy,  List<DisclosureOptions> disclosureOptions,  bool sharedDataWithRelyingPartyBefore,  DisclosureSessionType sessionType,  List<LocalizedString> requestPurpose,  String requestOriginBaseUrl,  DisclosureType requestType,  Uint16List multipleSelectionIndices,  List<DisclosureCredentialSet> credentialSets,  List<DisclosureAttributeIdentifier> optionalAttributes)  request,required TResult Function( Organization relyingParty,  List<MissingAttribute> missingAttributes,  bool sharedDataWithRelyingPartyBefore,  DisclosureSessionType sessionType,  List<LocalizedString> requestPurpose,  String requestOriginBaseUrl)  requestAttributesMissing,}) {final _that = this;
 switch (_that) {
 case StartDisclosureResult_Request():
-return request(_that.relyingParty,_that.policy,_that.disclosureOptions,_that.sharedDataWithRelyingPartyBefore,_that.sessionType,_that.requestPurpose,_that.requestOriginBaseUrl,_that.requestType);case StartDisclosureResult_RequestAttributesMissing():
+return request(_that.relyingParty,_that.policy,_that.disclosureOptions,_that.sharedDataWithRelyingPartyBefore,_that.sessionType,_that.requestPurpose,_that.requestOriginBaseUrl,_that.requestType,_that.multipleSelectionIndices,_that.credentialSets,_that.optionalAttributes);case StartDisclosureResult_RequestAttributesMissing():
 return requestAttributesMissing(_that.relyingParty,_that.missingAttributes,_that.sharedDataWithRelyingPartyBefore,_that.sessionType,_that.requestPurpose,_that.requestOriginBaseUrl);}
 }
 /// A variant of `when` that fallback to returning `null`
@@ -829,10 +829,10 @@ return requestAttributesMissing(_that.relyingParty,_that.missingAttributes,_that
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( Organization relyingParty,  RequestPolicy policy,  List<DisclosureOptions> disclosureOptions,  bool sharedDataWithRelyingPartyBefore,  DisclosureSessionType sessionType,  List<LocalizedString> requestPurpose,  String requestOriginBaseUrl,  DisclosureType requestType)?  request,TResult? Function( Organization relyingParty,  List<MissingAttribute> missingAttributes,  bool sharedDataWithRelyingPartyBefore,  DisclosureSessionType sessionType,  List<LocalizedString> requestPurpose,  String requestOriginBaseUrl)?  requestAttributesMissing,}) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( Organization relyingParty,  RequestPolicy policy,  List<DisclosureOptions> disclosureOptions,  bool sharedDataWithRelyingPartyBefore,  DisclosureSessionType sessionType,  List<LocalizedString> requestPurpose,  String requestOriginBaseUrl,  DisclosureType requestType,  Uint16List multipleSelectionIndices,  List<DisclosureCredentialSet> credentialSets,  List<DisclosureAttributeIdentifier> optionalAttributes)?  request,TResult? Function( Organization relyingParty,  List<MissingAttribute> missingAttributes,  bool sharedDataWithRelyingPartyBefore,  DisclosureSessionType sessionType,  List<LocalizedString> requestPurpose,  String requestOriginBaseUrl)?  requestAttributesMissing,}) {final _that = this;
 switch (_that) {
 case StartDisclosureResult_Request() when request != null:
-return request(_that.relyingParty,_that.policy,_that.disclosureOptions,_that.sharedDataWithRelyingPartyBefore,_that.sessionType,_that.requestPurpose,_that.requestOriginBaseUrl,_that.requestType);case StartDisclosureResult_RequestAttributesMissing() when requestAttributesMissing != null:
+return request(_that.relyingParty,_that.policy,_that.disclosureOptions,_that.sharedDataWithRelyingPartyBefore,_that.sessionType,_that.requestPurpose,_that.requestOriginBaseUrl,_that.requestType,_that.multipleSelectionIndices,_that.credentialSets,_that.optionalAttributes);case StartDisclosureResult_RequestAttributesMissing() when requestAttributesMissing != null:
 return requestAttributesMissing(_that.relyingParty,_that.missingAttributes,_that.sharedDataWithRelyingPartyBefore,_that.sessionType,_that.requestPurpose,_that.requestOriginBaseUrl);case _:
   return null;
 
@@ -845,7 +845,7 @@ return requestAttributesMissing(_that.relyingParty,_that.missingAttributes,_that
 
 
 class StartDisclosureResult_Request extends StartDisclosureResult {
-  const StartDisclosureResult_Request({required this.relyingParty, required this.policy, required final  List<DisclosureOptions> disclosureOptions, required this.sharedDataWithRelyingPartyBefore, required this.sessionType, required final  List<LocalizedString> requestPurpose, required this.requestOriginBaseUrl, required this.requestType}): _disclosureOptions = disclosureOptions,_requestPurpose = requestPurpose,super._();
+  const StartDisclosureResult_Request({required this.relyingParty, required this.policy, required final  List<DisclosureOptions> disclosureOptions, required this.sharedDataWithRelyingPartyBefore, required this.sessionType, required final  List<LocalizedString> requestPurpose, required this.requestOriginBaseUrl, required this.requestType, required this.multipleSelectionIndices, required final  List<DisclosureCredentialSet> credentialSets, required final  List<DisclosureAttributeIdentifier> optionalAttributes}): _disclosureOptions = disclosureOptions,_requestPurpose = requestPurpose,_credentialSets = credentialSets,_optionalAttributes = optionalAttributes,super._();
   
 
 @override final  Organization relyingParty;
@@ -868,6 +868,23 @@ class StartDisclosureResult_Request extends StartDisclosureResult {
 
 @override final  String requestOriginBaseUrl;
  final  DisclosureType requestType;
+/// The indices of the disclosure options for which more than one attestation may be selected.
+ final  Uint16List multipleSelectionIndices;
+ final  List<DisclosureCredentialSet> _credentialSets;
+ List<DisclosureCredentialSet> get credentialSets {
+  if (_credentialSets is EqualUnmodifiableListView) return _credentialSets;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_credentialSets);
+}
+
+ final  List<DisclosureAttributeIdentifier> _optionalAttributes;
+/// The attributes that are not required by the relying party, which the user may de-select.
+ List<DisclosureAttributeIdentifier> get optionalAttributes {
+  if (_optionalAttributes is EqualUnmodifiableListView) return _optionalAttributes;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_optionalAttributes);
+}
+
 
 /// Create a copy of StartDisclosureResult
 /// with the given fields replaced by the non-null parameter values.
@@ -879,16 +896,16 @@ $StartDisclosureResult_RequestCopyWith<StartDisclosureResult_Request> get copyWi
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is StartDisclosureResult_Request&&(identical(other.relyingParty, relyingParty) || other.relyingParty == relyingParty)&&(identical(other.policy, policy) || other.policy == policy)&&const DeepCollectionEquality().equals(other._disclosureOptions, _disclosureOptions)&&(identical(other.sharedDataWithRelyingPartyBefore, sharedDataWithRelyingPartyBefore) || other.sharedDataWithRelyingPartyBefore == sharedDataWithRelyingPartyBefore)&&(identical(other.sessionType, sessionType) || other.sessionType == sessionType)&&const DeepCollectionEquality().equals(other._requestPurpose, _requestPurpose)&&(identical(other.requestOriginBaseUrl, requestOriginBaseUrl) || other.requestOriginBaseUrl == requestOriginBaseUrl)&&(identical(other.requestType, requestType) || other.requestType == requestType));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is StartDisclosureResult_Request&&(identical(other.relyingParty, relyingParty) || other.relyingParty == relyingParty)&&(identical(other.policy, policy) || other.policy == policy)&&const DeepCollectionEquality().equals(other._disclosureOptions, _disclosureOptions)&&(identical(other.sharedDataWithRelyingPartyBefore, sharedDataWithRelyingPartyBefore) || other.sharedDataWithRelyingPartyBefore == sharedDataWithRelyingPartyBefore)&&(identical(other.sessionType, sessionType) || other.sessionType == sessionType)&&const DeepCollectionEquality().equals(other._requestPurpose, _requestPurpose)&&(identical(other.requestOriginBaseUrl, requestOriginBaseUrl) || other.requestOriginBaseUrl == requestOriginBaseUrl)&&(identical(other.requestType, requestType) || other.requestType == requestType)&&const DeepCollectionEquality().equals(other.multipleSelectionIndices, multipleSelectionIndices)&&const DeepCollectionEquality().equals(other._credentialSets, _credentialSets)&&const DeepCollectionEquality().equals(other._optionalAttributes, _optionalAttributes));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,relyingParty,policy,const DeepCollectionEquality().hash(_disclosureOptions),sharedDataWithRelyingPartyBefore,sessionType,const DeepCollectionEquality().hash(_requestPurpose),requestOriginBaseUrl,requestType);
+int get hashCode => Object.hash(runtimeType,relyingParty,policy,const DeepCollectionEquality().hash(_disclosureOptions),sharedDataWithRelyingPartyBefore,sessionType,const DeepCollectionEquality().hash(_requestPurpose),requestOriginBaseUrl,requestType,const DeepCollectionEquality().hash(multipleSelectionIndices),const DeepCollectionEquality().hash(_credentialSets),const DeepCollectionEquality().hash(_optionalAttributes));
 
 @override
 String toString() {
-  return 'StartDisclosureResult.request(relyingParty: $relyingParty, policy: $policy, disclosureOptions: $disclosureOptions, sharedDataWithRelyingPartyBefore: $sharedDataWithRelyingPartyBefore, sessionType: $sessionType, requestPurpose: $requestPurpose, requestOriginBaseUrl: $requestOriginBaseUrl, requestType: $requestType)';
+  return 'StartDisclosureResult.request(relyingParty: $relyingParty, policy: $policy, disclosureOptions: $disclosureOptions, sharedDataWithRelyingPartyBefore: $sharedDataWithRelyingPartyBefore, sessionType: $sessionType, requestPurpose: $requestPurpose, requestOriginBaseUrl: $requestOriginBaseUrl, requestType: $requestType, multipleSelectionIndices: $multipleSelectionIndices, credentialSets: $credentialSets, optionalAttributes: $optionalAttributes)';
 }
 
 
@@ -899,7 +916,7 @@ abstract mixin class $StartDisclosureResult_RequestCopyWith<$Res> implements $St
   factory $StartDisclosureResult_RequestCopyWith(StartDisclosureResult_Request value, $Res Function(StartDisclosureResult_Request) _then) = _$StartDisclosureResult_RequestCopyWithImpl;
 @override @useResult
 $Res call({
- Organization relyingParty, RequestPolicy policy, List<DisclosureOptions> disclosureOptions, bool sharedDataWithRelyingPartyBefore, DisclosureSessionType sessionType, List<LocalizedString> requestPurpose, String requestOriginBaseUrl, DisclosureType requestType
+ Organization relyingParty, RequestPolicy policy, List<DisclosureOptions> disclosureOptions, bool sharedDataWithRelyingPartyBefore, DisclosureSessionType sessionType, List<LocalizedString> requestPurpose, String requestOriginBaseUrl, DisclosureType requestType, Uint16List multipleSelectionIndices, List<DisclosureCredentialSet> credentialSets, List<DisclosureAttributeIdentifier> optionalAttributes
 });
 
 
@@ -916,7 +933,7 @@ class _$StartDisclosureResult_RequestCopyWithImpl<$Res>
 
 /// Create a copy of StartDisclosureResult
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? relyingParty = null,Object? policy = null,Object? disclosureOptions = null,Object? sharedDataWithRelyingPartyBefore = null,Object? sessionType = null,Object? requestPurpose = null,Object? requestOriginBaseUrl = null,Object? requestType = null,}) {
+@override @pragma('vm:prefer-inline') $Res call({Object? relyingParty = null,Object? policy = null,Object? disclosureOptions = null,Object? sharedDataWithRelyingPartyBefore = null,Object? sessionType = null,Object? requestPurpose = null,Object? requestOriginBaseUrl = null,Object? requestType = null,Object? multipleSelectionIndices = null,Object? credentialSets = null,Object? optionalAttributes = null,}) {
   return _then(StartDisclosureResult_Request(
 relyingParty: null == relyingParty ? _self.relyingParty : relyingParty // ignore: cast_nullable_to_non_nullable
 as Organization,policy: null == policy ? _self.policy : policy // ignore: cast_nullable_to_non_nullable
@@ -926,7 +943,10 @@ as bool,sessionType: null == sessionType ? _self.sessionType : sessionType // ig
 as DisclosureSessionType,requestPurpose: null == requestPurpose ? _self._requestPurpose : requestPurpose // ignore: cast_nullable_to_non_nullable
 as List<LocalizedString>,requestOriginBaseUrl: null == requestOriginBaseUrl ? _self.requestOriginBaseUrl : requestOriginBaseUrl // ignore: cast_nullable_to_non_nullable
 as String,requestType: null == requestType ? _self.requestType : requestType // ignore: cast_nullable_to_non_nullable
-as DisclosureType,
+as DisclosureType,multipleSelectionIndices: null == multipleSelectionIndices ? _self.multipleSelectionIndices : multipleSelectionIndices // ignore: cast_nullable_to_non_nullable
+as Uint16List,credentialSets: null == credentialSets ? _self._credentialSets : credentialSets // ignore: cast_nullable_to_non_nullable
+as List<DisclosureCredentialSet>,optionalAttributes: null == optionalAttributes ? _self._optionalAttributes : optionalAttributes // ignore: cast_nullable_to_non_nullable
+as List<DisclosureAttributeIdentifier>,
   ));
 }
 
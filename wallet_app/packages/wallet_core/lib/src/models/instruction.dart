@@ -13,6 +13,18 @@ import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 import 'revocation.dart';
 part 'instruction.freezed.dart';
 
+@freezed
+sealed class AcceptIssuanceResult with _$AcceptIssuanceResult {
+  const AcceptIssuanceResult._();
+
+  const factory AcceptIssuanceResult.ok({
+    required bool isDeferred,
+  }) = AcceptIssuanceResult_Ok;
+  const factory AcceptIssuanceResult.instructionError({
+    required WalletInstructionError error,
+  }) = AcceptIssuanceResult_InstructionError;
+}
+
 @freezed
 sealed class DisclosureBasedIssuanceResult with _$DisclosureBasedIssuanceResult {
   const DisclosureBasedIssuanceResult._();
@@ -11,6 +11,307 @@ part of 'instruction.dart';
 
 // dart format off
 T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$AcceptIssuanceResult {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is AcceptIssuanceResult);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'AcceptIssuanceResult()';
+}
+
+
+}
+
+/// @nodoc
+class $AcceptIssuanceResultCopyWith<$Res>  {
+$AcceptIssuanceResultCopyWith(AcceptIssuanceResult _, $Res Function(AcceptIssuanceResult) __);
+}
+
+
+/// Adds pattern-matching-related methods to [AcceptIssuanceResult].
+extension AcceptIssuanceResultPatterns on AcceptIssuanceResult {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( AcceptIssuanceResult_Ok value)?  ok,TResult Function( AcceptIssuanceResult_InstructionError value)?  instructionError,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case AcceptIssuanceResult_Ok() when ok != null:
+return ok(_that);case AcceptIssuanceResult_InstructionError() when instructionError != null:
+return instructionError(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( AcceptIssuanceResult_Ok value)  ok,required TResult Function( AcceptIssuanceResult_InstructionError value)  instructionError,}){
+final _that = this;
+switch (_that) {
+case AcceptIssuanceResult_Ok():
+return ok(_that);case AcceptIssuanceResult_InstructionError():
+return instructionError(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( AcceptIssuanceResult_Ok value)?  ok,TResult? Function( AcceptIssuanceResult_InstructionError value)?  instructionError,}){
+final _that = this;
+switch (_that) {
+case AcceptIssuanceResult_Ok() when ok != null:
+return ok(_that);case AcceptIssuanceResult_InstructionError() when instructionError != null:
+return instructionError(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( bool isDeferred)?  ok,TResult Function( WalletInstructionError error)?  instructionError,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case AcceptIssuanceResult_Ok() when ok != null:
+return ok(_that.isDeferred);case AcceptIssuanceResult_InstructionError() when instructionError != null:
+return instructionError(_that.error);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( bool isDeferred)  ok,required TResult Function( WalletInstructionError error)  instructionError,}) {final _that = this;
+switch (_that) {
+case AcceptIssuanceResult_Ok():
+return ok(_that.isDeferred);case AcceptIssuanceResult_InstructionError():
+return instructionError(_that.error);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( bool isDeferred)?  ok,TResult? Function( WalletInstructionError error)?  instructionError,}) {final _that = this;
+switch (_that) {
+case AcceptIssuanceResult_Ok() when ok != null:
+return ok(_that.isDeferred);case AcceptIssuanceResult_InstructionError() when instructionError != null:
+return instructionError(_that.error);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class AcceptIssuanceResult_Ok extends AcceptIssuanceResult {
+  const AcceptIssuanceResult_Ok({required this.isDeferred}): super._();
+  
+
+ final  bool isDeferred;
+
+/// Create a copy of AcceptIssuanceResult
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$AcceptIssuanceResult_OkCopyWith<AcceptIssuanceResult_Ok> get copyWith => _$AcceptIssuanceResult_OkCopyWithImpl<AcceptIssuanceResult_Ok>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is AcceptIssuanceResult_Ok&&(identical(other.isDeferred, isDeferred) || other.isDeferred == isDeferred));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,isDeferred);
+
+@override
+String toString() {
+  return 'AcceptIssuanceResult.ok(isDeferred: $isDeferred)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $AcceptIssuanceResult_OkCopyWith<$Res> implements $AcceptIssuanceResultCopyWith<$Res> {
+  factory $AcceptIssuanceResult_OkCopyWith(AcceptIssuanceResult_Ok value, $Res Function(AcceptIssuanceResult_Ok) _then) = _$AcceptIssuanceResult_OkCopyWithImpl;
+@useResult
+$Res call({
+ bool isDeferred
+});
+
+
+
+
+}
+/// @nodoc
+class _$AcceptIssuanceResult_OkCopyWithImpl<$Res>
+    implements $AcceptIssuanceResult_OkCopyWith<$Res> {
+  _$AcceptIssuanceResult_OkCopyWithImpl(this._self, this._then);
+
+  final AcceptIssuanceResult_Ok _self;
+  final $Res Function(AcceptIssuanceResult_Ok) _then;
+
+/// Create a copy of AcceptIssuanceResult
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? isDeferred = null,}) {
+  return _then(AcceptIssuanceResult_Ok(
+isDeferred: null == isDeferred ? _self.isDeferred : isDeferred // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class AcceptIssuanceResult_InstructionError extends AcceptIssuanceResult {
+  const AcceptIssuanceResult_InstructionError({required this.error}): super._();
+  
+
+ final  WalletInstructionError error;
+
+/// Create a copy of AcceptIssuanceResult
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$AcceptIssuanceResult_InstructionErrorCopyWith<AcceptIssuanceResult_InstructionError> get copyWith => _$AcceptIssuanceResult_InstructionErrorCopyWithImpl<AcceptIssuanceResult_InstructionError>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is AcceptIssuanceResult_InstructionError&&(identical(other.error, error) || other.error == error));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,error);
+
+@override
+String toString() {
+  return 'AcceptIssuanceResult.instructionError(error: $error)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $AcceptIssuanceResult_InstructionErrorCopyWith<$Res> implements $AcceptIssuanceResultCopyWith<$Res> {
+  factory $AcceptIssuanceResult_InstructionErrorCopyWith(AcceptIssuanceResult_InstructionError value, $Res Function(AcceptIssuanceResult_InstructionError) _then) = _$AcceptIssuanceResult_InstructionErrorCopyWithImpl;
+@useResult
+$Res call({
+ WalletInstructionError error
+});
+
+
+$WalletInstructionErrorCopyWith<$Res> get error;
+
+}
+/// @nodoc
+class _$AcceptIssuanceResult_InstructionErrorCopyWithImpl<$Res>
+    implements $AcceptIssuanceResult_InstructionErrorCopyWith<$Res> {
+  _$AcceptIssuanceResult_InstructionErrorCopyWithImpl(this._self, this._then);
+
+  final AcceptIssuanceResult_InstructionError _self;
+  final $Res Function(AcceptIssuanceResult_InstructionError) _then;
+
+/// Create a copy of AcceptIssuanceResult
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? error = null,}) {
+  return _then(AcceptIssuanceResult_InstructionError(
+error: null == error ? _self.error : error // ignore: cast_nullable_to_non_nullable
+as WalletInstructionError,
+  ));
+}
+
+/// Create a copy of AcceptIssuanceResult
+/// with the given fields replaced by the non-null parameter values.
+@override
+@pragma('vm:prefer-inline')
+$WalletInstructionErrorCopyWith<$Res> get error {
+  
+  return $WalletInstructionErrorCopyWith<$Res>(_self.error, (value) {
+    return _then(_self.copyWith(error: value));
+  });
+}
+}
+
 /// @nodoc
 mixin _$DisclosureBasedIssuanceResult {
 
@@ -28,6 +28,7 @@ final kPidAttestations = [
     issuer: kOrganizations[kRvigId]!,
     validityStatus: kValidityStatus,
     attributes: kMockPidAttestationAttributes,
+    svgTemplates: [],
   ),
   AttestationPresentation(
     identity: const AttestationIdentity_Ephemeral(),
@@ -44,6 +45,7 @@ final kPidAttestations = [
     issuer: kOrganizations[kRvigId]!,
     validityStatus: kValidityStatus,
     attributes: kMockAddressAttestationAttributes,
+    svgTemplates: [],
   ),
 ];
 
@@ -61,6 +63,7 @@ final kDiplomaAttestation = AttestationPresentation(
   ),
   validityStatus: kValidityStatus,
   attributes: kMockDiplomaAttestationAttributes,
+  svgTemplates: [],
   issuer: kOrganizations[kDuoId]!,
 );
 
@@ -78,6 +81,7 @@ final kMockMasterDiplomaWalletCard = AttestationPresentation(
   ),
   validityStatus: kValidityStatus,
   attributes: kMockMasterDiplomaDataAttributes,
+  svgTemplates: [],
   issuer: kOrganizations[kDuoId]!,
 );
 
@@ -95,6 +99,7 @@ final kMockDrivingLicenseWalletCard = AttestationPresentation(
   ),
   validityStatus: kValidityStatus,
   attributes: kMockDrivingLicenseDataAttributes,
+  svgTemplates: [],
   issuer: kOrganizations[kRdwId]!,
 );
 
@@ -112,6 +117,7 @@ final kMockDrivingLicenseRenewedWalletCard = AttestationPresentation(
   ),
   validityStatus: kValidityStatus,
   attributes: kMockDrivingLicenseRenewedDataAttributes,
+  svgTemplates: [],
   issuer: kOrganizations[kRdwId]!,
 );
 
@@ -129,6 +135,7 @@ final kMockHealthInsuranceWalletCard = AttestationPresentation(
   ),
   validityStatus: kValidityStatus,
   attributes: kMockHealthInsuranceDataAttributes,
+  svgTemplates: [],
   issuer: kOrganizations[kHealthInsuranceId]!,
 );
 
@@ -146,6 +153,7 @@ final kMockVOGWalletCard = AttestationPresentation(
   ),
   validityStatus: kValidityStatus,
   attributes: kMockVOGDataAttributes,
+  svgTemplates: [],
   issuer: kOrganizations[kRvigId]!,
 );
 
@@ -163,6 +171,7 @@ final kMockLoyaltyCard = AttestationPresentation(
   ),
   validityStatus: kValidityStatus,
   attributes: kLoyaltyAttributes,
+  svgTemplates: [],
   issuer: kOrganizations[kPharmacyId]!,
 );
 
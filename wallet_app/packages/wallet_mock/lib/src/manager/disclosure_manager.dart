@@ -1,4 +1,5 @@
 import 'dart:convert';
+import 'dart:typed_data';
 
 import 'package:wallet_core/core.dart';
 
@@ -48,6 +49,9 @@ class DisclosureManager {
         requestOriginBaseUrl: requestOriginBaseUrl,
         requestPurpose: request.purpose.untranslated,
         requestType: isLoginRequest ? DisclosureType.Login : DisclosureType.Regular,
+        multipleSelectionIndices: Uint16List(0),
+        credentialSets: [],
+        optionalAttributes: [],
       );
     } else {
       final requestedAttributesNotInWallet = _wallet.getMissingAttributeKeys(
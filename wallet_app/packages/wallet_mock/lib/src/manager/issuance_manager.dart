@@ -1,4 +1,5 @@
 import 'dart:convert';
+import 'dart:typed_data';
 
 import 'package:wallet_core/core.dart';
 
@@ -56,6 +57,9 @@ class IssuanceManager {
           const LocalizedString(language: 'nl', value: 'Kaart uitgifte'),
         ],
         requestType: DisclosureType.Regular,
+        multipleSelectionIndices: Uint16List(0),
+        credentialSets: [],
+        optionalAttributes: [],
       );
     } else {
       final requestedAttributesNotInWallet = _wallet.getMissingAttributeKeys(
@@ -108,7 +112,7 @@ class IssuanceManager {
     return attestation;
   }
 
-  Future<WalletInstructionResult> acceptIssuance(String pin, Iterable<String> cardDocTypes /* empty = all */) async {
+  Future<AcceptIssuanceResult> acceptIssuance(String pin, Iterable<String> cardDocTypes /* empty = all */) async {
     assert(_activeIssuanceResponse != null, 'Can not accept when no issuance is active');
     final result = _pinManager.checkPin(pin);
     switch (result) {
@@ -122,7 +126,7 @@ class IssuanceManager {
         selectedCards.forEach(_eventLog.logIssuance);
         _activeIssuanceResponse = null;
         _itemsHaveBeenDisclosed = false;
-        return result;
+        return const AcceptIssuanceResult_Ok(isDeferred: false);
       case WalletInstructionResult_InstructionError():
         throw result.error;
     }
@@ -9,6 +9,7 @@ extension AttestationPresentationExtension on AttestationPresentation {
       displayMetadata: displayMetadata,
       issuer: issuer,
       attributes: attributes,
+      svgTemplates: svgTemplates,
       validityStatus: validityStatus,
     );
   }
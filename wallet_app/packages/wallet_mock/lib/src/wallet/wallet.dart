@@ -45,6 +45,7 @@ class Wallet {
             validityStatus: e.key.validityStatus,
             issuer: e.key.issuer,
             attributes: e.value,
+            svgTemplates: [],
           ),
         )
         .toList();
@@ -76,7 +76,26 @@ class WalletCoreMock implements WalletCoreApi {
   }
 
   @override
-  Future<WalletInstructionResult> crateApiFullAcceptIssuance({required String pin}) async {
+  Future<AcceptDisclosureResult> crateApiFullAcceptDisclosureWithMultipleSelection({
+    required List<Uint16List> selectedIndices,
+    required List<int?> selectedCredentialSetOptions,
+    required String pin,
+  }) async {
+    return _disclosureManager.acceptDisclosure(pin);
+  }
+
+  @override
+  Future<AcceptDisclosureResult> crateApiFullAcceptDisclosureWithDeselectedAttributes({
+    required List<Uint16List> selectedIndices,
+    required List<int?> selectedCredentialSetOptions,
+    required List<DisclosureAttributeIdentifier> deselectedAttributes,
+    required String pin,
+  }) async {
+    return _disclosureManager.acceptDisclosure(pin);
+  }
+
+  @override
+  Future<AcceptIssuanceResult> crateApiFullAcceptIssuance({required String pin}) async {
     /// Check if the issuance manager has an active session that should be continued
     if (_issuanceManager.hasActiveIssuanceSession) return _issuanceManager.acceptIssuance(pin, []);
     throw StateError('No active issuance session');
@@ -143,6 +162,12 @@ class WalletCoreMock implements WalletCoreApi {
   Future<IssuanceStartResult> crateApiFullStartIssuanceFromOffer({required String offerUri}) =>
       _issuanceManager.startIssuanceFromOffer(offerUri);
 
+  @override
+  Future<IssuanceStartResult> crateApiFullStartIssuanceFromOfferWithTransactionCode({
+    required String offerUri,
+    required String transactionCode,
+  }) => _issuanceManager.startIssuanceFromOffer(offerUri);
+
   @override
   Future<String> crateApiFullCreatePidIssuanceRedirectUri() async => MockConstants.pidIssuanceRedirectUri;
 
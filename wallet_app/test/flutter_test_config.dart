@@ -81,6 +81,7 @@ void _setupMockitoDummies() {
 
   // Core wallet instruction result dummies
   provideDummy<core.WalletInstructionResult>(const core.WalletInstructionResult.ok());
+  provideDummy<core.AcceptIssuanceResult>(const core.AcceptIssuanceResult.ok(isDeferred: false));
 
   // Disclosure-related dummies
   provideDummy<StartDisclosureResult>(
@@ -1,3 +1,5 @@
+import 'dart:typed_data';
+
 import 'package:flutter_test/flutter_test.dart';
 import 'package:mockito/mockito.dart';
 import 'package:wallet/src/data/repository/disclosure/core/core_disclosure_repository.dart';
@@ -29,6 +31,52 @@ void main() {
     verify(mockTypedWalletCore.acceptDisclosure('123123', [0])).called(1);
   });
 
+  test('acceptDisclosureWithSelection without deselected attributes is forwarded as multiple selection', () async {
+    when(
+      mockTypedWalletCore.acceptDisclosureWithMultipleSelection(any, any, any),
+    ).thenAnswer((_) async => const AcceptDisclosureResult.ok(returnUrl: 'https://example.org'));
+
+    final result = await repository.acceptDisclosureWithSelection('123123', [
+      [0, 1],
+    ], [null]);
+
+    expect(result, 'https://example.org');
+    verify(
+      mockTypedWalletCore.acceptDisclosureWithMultipleSelection('123123', [
+        [0, 1],
+      ], [null]),
+    ).called(1);
+    verifyNever(mockTypedWalletCore.acceptDisclosureWithDeselectedAttributes(any, any, any, any));
+  });
+
+  test('acceptDisclosureWithSelection with deselected attributes is forwarded with core identifiers', () async {
+    when(
+      mockTypedWalletCore.acceptDisclosureWithDeselectedAttributes(any, any, any, any),
+    ).thenAnswer((_) async => const AcceptDisclosureResult.ok());
+
+    await repository.acceptDisclosureWithSelection(
+      '123123',
+      [
+        [0],
+      ],
+      [],
+      deselectedAttributes: const [
+        ui.DisclosureAttributeIdentifier(attestationOptionIndex: 0, attestationIndex: 0, attributeKey: ['email']),
+      ],
+    );
+
+    final captured = verify(
+      mockTypedWalletCore.acceptDisclosureWithDeselectedAttributes('123123', [
+        [0],
+      ], [], captureAny),
+    ).captured.single as List<DisclosureAttributeIdentifier>;
+    expect(captured, hasLength(1));
+    expect(captured.single.attestationOptionIndex, 0);
+    expect(captured.single.attestationIndex, 0);
+    expect(captured.single.attributeKey, ['email']);
+    verifyNever(mockTypedWalletCore.acceptDisclosureWithMultipleSelection(any, any, any));
+  });
+
   test('Call to startDisclosure is forwarded to wallet core with correct argument', () async {
     await repository.startDisclosure('uri', isQrCode: true);
     verify(mockTypedWalletCore.startDisclosure('uri', isQrCode: true)).called(1);
@@ -36,8 +84,8 @@ void main() {
 
   test('StartDisclosureResultRequest is mapped successfully to StartDisclosureReadyToDisclose', () async {
     when(mockTypedWalletCore.startDisclosure(any, isQrCode: anyNamed('isQrCode'))).thenAnswer((_) async {
-      return const StartDisclosureResult.request(
-        relyingParty: Organization(
+      return StartDisclosureResult.request(
+        relyingParty: const Organization(
           legalName: '',
           displayName: '',
           description: [],
@@ -49,13 +97,16 @@ void main() {
         sharedDataWithRelyingPartyBefore: false,
         sessionType: DisclosureSessionType.CrossDevice,
         requestPurpose: [],
-        policy: RequestPolicy(
+        policy: const RequestPolicy(
           dataSharedWithThirdParties: false,
           dataDeletionPossible: true,
           policyUrl: 'https://example.org',
         ),
         requestType: DisclosureType.Login,
         disclosureOptions: [],
+        multipleSelectionIndices: Uint16List(0),
+        credentialSets: [],
+        optionalAttributes: [],
       );
     });
     final result = await repository.startDisclosure('uri', isQrCode: true);
@@ -93,6 +93,7 @@ void main() {
               countryCode: '',
             ),
             attributes: [],
+            svgTemplates: [],
             validityStatus: core.ValidityStatus.valid(validUntil: null),
           ),
           renewed: false,
@@ -154,6 +155,7 @@ void main() {
               countryCode: '',
             ),
             attributes: [],
+            svgTemplates: [],
             validityStatus: core.ValidityStatus.valid(validUntil: null),
           ),
           renewed: false,
@@ -242,6 +244,7 @@ void main() {
               countryCode: '',
             ),
             attributes: [],
+            svgTemplates: [],
             validityStatus: core.ValidityStatus.valid(validUntil: null),
           ),
           renewed: false,
@@ -322,6 +325,7 @@ void main() {
               countryCode: '',
             ),
             attributes: [],
+            svgTemplates: [],
             validityStatus: core.ValidityStatus.valid(validUntil: null),
           ),
           renewed: false,
@@ -382,6 +386,7 @@ void main() {
                 countryCode: '',
               ),
               attributes: [],
+              svgTemplates: [],
               validityStatus: core.ValidityStatus.valid(validUntil: null),
             ),
             renewed: false,
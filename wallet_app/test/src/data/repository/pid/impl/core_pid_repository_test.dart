@@ -58,6 +58,7 @@ void main() {
         displayMetadata: [CoreMockData.enDisplayMetadata],
         issuer: CoreMockData.organization,
         attributes: CoreMockData.attestation.attributes,
+        svgTemplates: [],
         validityStatus: const ValidityStatus_Valid(validUntil: null),
       );
       final expectedAttributes = cardMapper.map(testAttestation).attributes;
@@ -143,6 +143,7 @@ void main() {
         format: .SdJwt,
         displayMetadata: [DisplayMetadata(locale: 'nl', name: 'card name')],
         attributes: [],
+        svgTemplates: [],
         issuer: CoreMockData.organization,
         validityStatus: ValidityStatus_Valid(validUntil: null),
       );
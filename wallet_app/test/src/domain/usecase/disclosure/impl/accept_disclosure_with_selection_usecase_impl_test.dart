@@ -0,0 +1,75 @@
+import 'package:flutter_test/flutter_test.dart';
+import 'package:mockito/mockito.dart';
+import 'package:wallet/src/domain/model/result/application_error.dart';
+import 'package:wallet/src/domain/usecase/disclosure/accept_disclosure_usecase.dart';
+import 'package:wallet/src/domain/usecase/disclosure/impl/accept_disclosure_with_selection_usecase_impl.dart';
+import 'package:wallet/src/wallet_core/error/core_error.dart';
+import 'package:wallet_core/core.dart' hide DisclosureAttributeIdentifier;
+
+import '../../../../mocks/wallet_mocks.dart';
+
+void main() {
+  late AcceptDisclosureUseCase usecase;
+  final mockRepo = Mocks.create<DisclosureRepository>() as MockDisclosureRepository;
+
+  const deselectedAttribute = DisclosureAttributeIdentifier(
+    attestationOptionIndex: 0,
+    attestationIndex: 1,
+    attributeKey: ['email'],
+  );
+
+  setUp(() {
+    reset(mockRepo);
+    usecase = AcceptDisclosureWithSelectionUseCaseImpl(
+      mockRepo,
+      [
+        [1],
+      ],
+      [0],
+      deselectedAttributes: [deselectedAttribute],
+    );
+  });
+
+  test('when acceptDisclosureWithSelection throws, result is error', () async {
+    when(
+      mockRepo.acceptDisclosureWithSelection(any, any, any, deselectedAttributes: anyNamed('deselectedAttributes')),
+    ).thenAnswer((_) async => throw const CoreGenericError('expected error'));
+    final result = await usecase.invoke('123123');
+    expect(result.hasError, isTrue);
+  });
+
+  test('when acceptDisclosureWithSelection returns instruction error, result is error', () async {
+    when(
+      mockRepo.acceptDisclosureWithSelection(any, any, any, deselectedAttributes: anyNamed('deselectedAttributes')),
+    ).thenThrow(const WalletInstructionError.incorrectPin(attemptsLeftInRound: 3, isFinalRound: false));
+    final result = await usecase.invoke('123123');
+    expect(result.hasError, isTrue);
+    expect(result.error, isA<CheckPinError>());
+  });
+
+  test('when acceptDisclosureWithSelection succeeds, result is ok', () async {
+    when(
+      mockRepo.acceptDisclosureWithSelection(any, any, any, deselectedAttributes: anyNamed('deselectedAttributes')),
+    ).thenAnswer((_) async => 'https://example.org');
+    final result = await usecase.invoke('123123');
+    expect(result.hasError, isFalse);
+    expect(result.value, 'https://example.org');
+  });
+
+  test('selection and deselected attributes are passed on to the repository', () async {
+    when(
+      mockRepo.acceptDisclosureWithSelection(any, any, any, deselectedAttributes: anyNamed('deselectedAttributes')),
+    ).thenAnswer((_) async => null);
+    await usecase.invoke('123123');
+    verify(
+      mockRepo.acceptDisclosureWithSelection(
+        '123123',
+        [
+          [1],
+        ],
+        [0],
+        deselectedAttributes: [deselectedAttribute],
+      ),
+    ).called(1);
+  });
+}
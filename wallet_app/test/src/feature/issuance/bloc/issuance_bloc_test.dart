@@ -8,8 +8,10 @@ import 'package:wallet/src/domain/model/disclosure/disclosure_type.dart';
 import 'package:wallet/src/domain/model/issuance/start_issuance_result.dart';
 import 'package:wallet/src/domain/model/result/application_error.dart';
 import 'package:wallet/src/domain/model/result/result.dart';
+import 'package:wallet/src/feature/issuance/argument/issuance_screen_argument.dart';
 import 'package:wallet/src/feature/issuance/bloc/issuance_bloc.dart';
 import 'package:wallet/src/util/extension/string_extension.dart';
+import 'package:wallet/src/wallet_core/error/core_error.dart';
 
 import '../../../mocks/wallet_mock_data.dart';
 import '../../../mocks/wallet_mocks.dart';
@@ -153,6 +155,49 @@ void main() {
     ],
   );
 
+  blocTest(
+    'ltc5 verify pre-authorized offer restarts with the provided transaction code',
+    build: () => createBloc(isRefreshFlow: false),
+    setUp: () {
+      final transactionCode = TransactionCodeData(inputMode: .numeric, length: 6);
+      when(startIssuanceUseCase.invoke(any, type: anyNamed('type'))).thenAnswer(
+        (_) async => Result.error(
+          TransactionCodeRequiredError(
+            transactionCode: transactionCode,
+            sourceError: CoreTransactionCodeRequired('', transactionCode: transactionCode),
+          ),
+        ),
+      );
+      when(startIssuanceUseCase.invoke(any, type: anyNamed('type'), transactionCode: '123456')).thenAnswer(
+        (_) async => Result.success(StartIssuancePreAuthorizedOffer([WalletMockData.card])),
+      );
+    },
+    act: (bloc) async {
+      bloc.add(const IssuanceSessionStarted('https://example.org', type: IssuanceType.credentialOffer));
+      await Future.delayed(_kBlockProcessDelay);
+      bloc.add(const IssuanceTransactionCodeSubmitted('123456'));
+    },
+    expect: () => [
+      isA<IssuanceLoadInProgress>(),
+      isA<IssuanceProvideTransactionCode>().having(
+        (it) => it.transactionCode.length,
+        'transaction code length should match',
+        6,
+      ),
+      isA<IssuanceLoadInProgress>(),
+      isA<IssuanceReviewCards>(),
+    ],
+    verify: (_) {
+      verify(
+        startIssuanceUseCase.invoke(
+          'https://example.org',
+          type: IssuanceType.credentialOffer,
+          transactionCode: '123456',
+        ),
+      ).called(1);
+    },
+  );
+
   blocTest(
     'ltc5 verify happy path - authorization required',
     build: () => createBloc(isRefreshFlow: false),
@@ -12,6 +12,7 @@ abstract class CoreMockData {
     displayMetadata: [enDisplayMetadata, nlDisplayMetadata],
     issuer: organization,
     attributes: [attestationAttributeName],
+    svgTemplates: [],
     validityStatus: ValidityStatus_Valid(validUntil: null),
   );
 
@@ -22,6 +23,7 @@ abstract class CoreMockData {
     displayMetadata: [enDisplayMetadata, nlDisplayMetadata],
     issuer: organization,
     attributes: [attestationAttributeName, attestationAttributeCity],
+    svgTemplates: [],
     validityStatus: ValidityStatus_Valid(validUntil: '2050-06-08T19:46:03Z'),
   );
 
@@ -6,6 +6,7 @@
 import 'dart:async' as _i11;
 import 'dart:collection' as _i132;
 import 'dart:ffi' as _i82;
+import 'dart:typed_data' as _i182;
 import 'dart:ui' as _i10;
 
 import 'package:bluetooth/bluetooth.dart' as _i59;
@@ -73,6 +74,7 @@ import 'package:wallet/src/domain/model/card/wallet_card.dart' as _i9;
 import 'package:wallet/src/domain/model/close_proximity/ble_connection_event.dart' as _i61;
 import 'package:wallet/src/domain/model/configuration/flutter_app_configuration.dart' as _i24;
 import 'package:wallet/src/domain/model/configuration/maintenance_state.dart' as _i146;
+import 'package:wallet/src/domain/model/disclosure/disclosure_attribute_identifier.dart' as _i183;
 import 'package:wallet/src/domain/model/disclosure/start_disclosure_request.dart' as _i174;
 import 'package:wallet/src/domain/model/disclosure/start_disclosure_result.dart' as _i26;
 import 'package:wallet/src/domain/model/event/wallet_event.dart' as _i46;
@@ -1847,6 +1849,24 @@ class MockDisclosureRepository extends _i1.Mock implements _i25.DisclosureReposi
             returnValueForMissingStub: _i11.Future<String?>.value(),
           )
           as _i11.Future<String?>);
+
+  @override
+  _i11.Future<String?> acceptDisclosureWithSelection(
+    String? pin,
+    List<List<int>>? selectedIndices,
+    List<int?>? selectedCredentialSetOptions, {
+    List<_i183.DisclosureAttributeIdentifier>? deselectedAttributes = const [],
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(
+              #acceptDisclosureWithSelection,
+              [pin, selectedIndices, selectedCredentialSetOptions],
+              {#deselectedAttributes: deselectedAttributes},
+            ),
+            returnValue: _i11.Future<String?>.value(),
+            returnValueForMissingStub: _i11.Future<String?>.value(),
+          )
+          as _i11.Future<String?>);
 }
 
 /// A class which mocks [HelpContentRepository].
@@ -1925,12 +1945,13 @@ class MockIssuanceRepository extends _i1.Mock implements _i30.IssuanceRepository
   _i11.Future<_i31.StartIssuanceResult> startIssuanceFromOffer(
     String? disclosureUri, {
     required bool? isQrCode,
+    String? transactionCode,
   }) =>
       (super.noSuchMethod(
             Invocation.method(
               #startIssuanceFromOffer,
               [disclosureUri],
-              {#isQrCode: isQrCode},
+              {#isQrCode: isQrCode, #transactionCode: transactionCode},
             ),
             returnValue: _i11.Future<_i31.StartIssuanceResult>.value(
               _i15.dummyValue<_i31.StartIssuanceResult>(
@@ -1938,7 +1959,7 @@ class MockIssuanceRepository extends _i1.Mock implements _i30.IssuanceRepository
                 Invocation.method(
                   #startIssuanceFromOffer,
                   [disclosureUri],
-                  {#isQrCode: isQrCode},
+                  {#isQrCode: isQrCode, #transactionCode: transactionCode},
                 ),
               ),
             ),
@@ -1948,7 +1969,7 @@ class MockIssuanceRepository extends _i1.Mock implements _i30.IssuanceRepository
                 Invocation.method(
                   #startIssuanceFromOffer,
                   [disclosureUri],
-                  {#isQrCode: isQrCode},
+                  {#isQrCode: isQrCode, #transactionCode: transactionCode},
                 ),
               ),
             ),
@@ -3815,23 +3836,54 @@ class MockTypedWalletCore extends _i1.Mock implements _i79.TypedWalletCore {
           as _i11.Future<_i19.IssuanceStartResult>);
 
   @override
-  _i11.Future<_i19.WalletInstructionResult> acceptIssuance(String? pin) =>
+  _i11.Future<_i19.IssuanceStartResult> startIssuanceFromOfferWithTransactionCode(
+    String? offerUri,
+    String? transactionCode,
+  ) =>
+      (super.noSuchMethod(
+            Invocation.method(#startIssuanceFromOfferWithTransactionCode, [
+              offerUri,
+              transactionCode,
+            ]),
+            returnValue: _i11.Future<_i19.IssuanceStartResult>.value(
+              _i15.dummyValue<_i19.IssuanceStartResult>(
+                this,
+                Invocation.method(#startIssuanceFromOfferWithTransactionCode, [
+                  offerUri,
+                  transactionCode,
+                ]),
+              ),
+            ),
+            returnValueForMissingStub: _i11.Future<_i19.IssuanceStartResult>.value(
+              _i15.dummyValue<_i19.IssuanceStartResult>(
+                this,
+                Invocation.method(#startIssuanceFromOfferWithTransactionCode, [
+                  offerUri,
+                  transactionCode,
+                ]),
+              ),
+            ),
+          )
+          as _i11.Future<_i19.IssuanceStartResult>);
+
+  @override
+  _i11.Future<_i19.AcceptIssuanceResult> acceptIssuance(String? pin) =>
       (super.noSuchMethod(
             Invocation.method(#acceptIssuance, [pin]),
-            returnValue: _i11.Future<_i19.WalletInstructionResult>.value(
-              _i15.dummyValue<_i19.WalletInstructionResult>(
+            returnValue: _i11.Future<_i19.AcceptIssuanceResult>.value(
+              _i15.dummyValue<_i19.AcceptIssuanceResult>(
                 this,
                 Invocation.method(#acceptIssuance, [pin]),
               ),
             ),
-            returnValueForMissingStub: _i11.Future<_i19.WalletInstructionResult>.value(
-              _i15.dummyValue<_i19.WalletInstructionResult>(
+            returnValueForMissingStub: _i11.Future<_i19.AcceptIssuanceResult>.value(
+              _i15.dummyValue<_i19.AcceptIssuanceResult>(
                 this,
                 Invocation.method(#acceptIssuance, [pin]),
               ),
             ),
           )
-          as _i11.Future<_i19.WalletInstructionResult>);
+          as _i11.Future<_i19.AcceptIssuanceResult>);
 
   @override
   _i11.Future<_i19.PidIssuanceResult> acceptPidIssuance(String? pin) =>
@@ -3965,6 +4017,80 @@ class MockTypedWalletCore extends _i1.Mock implements _i79.TypedWalletCore {
           )
           as _i11.Future<_i19.AcceptDisclosureResult>);
 
+  @override
+  _i11.Future<_i19.AcceptDisclosureResult> acceptDisclosureWithMultipleSelection(
+    String? pin,
+    List<List<int>>? selectedIndices,
+    List<int?>? selectedCredentialSetOptions,
+  ) =>
+      (super.noSuchMethod(
+            Invocation.method(#acceptDisclosureWithMultipleSelection, [
+              pin,
+              selectedIndices,
+              selectedCredentialSetOptions,
+            ]),
+            returnValue: _i11.Future<_i19.AcceptDisclosureResult>.value(
+              _i15.dummyValue<_i19.AcceptDisclosureResult>(
+                this,
+                Invocation.method(#acceptDisclosureWithMultipleSelection, [
+                  pin,
+                  selectedIndices,
+                  selectedCredentialSetOptions,
+                ]),
+              ),
+            ),
+            returnValueForMissingStub: _i11.Future<_i19.AcceptDisclosureResult>.value(
+              _i15.dummyValue<_i19.AcceptDisclosureResult>(
+                this,
+                Invocation.method(#acceptDisclosureWithMultipleSelection, [
+                  pin,
+                  selectedIndices,
+                  selectedCredentialSetOptions,
+                ]),
+              ),
+            ),
+          )
+          as _i11.Future<_i19.AcceptDisclosureResult>);
+
+  @override
+  _i11.Future<_i19.AcceptDisclosureResult> acceptDisclosureWithDeselectedAttributes(
+    String? pin,
+    List<List<int>>? selectedIndices,
+    List<int?>? selectedCredentialSetOptions,
+    List<_i19.DisclosureAttributeIdentifier>? deselectedAttributes,
+  ) =>
+      (super.noSuchMethod(
+            Invocation.method(#acceptDisclosureWithDeselectedAttributes, [
+              pin,
+              selectedIndices,
+              selectedCredentialSetOptions,
+              deselectedAttributes,
+            ]),
+            returnValue: _i11.Future<_i19.AcceptDisclosureResult>.value(
+              _i15.dummyValue<_i19.AcceptDisclosureResult>(
+                this,
+                Invocation.method(#acceptDisclosureWithDeselectedAttributes, [
+                  pin,
+                  selectedIndices,
+                  selectedCredentialSetOptions,
+                  deselectedAttributes,
+                ]),
+              ),
+            ),
+            returnValueForMissingStub: _i11.Future<_i19.AcceptDisclosureResult>.value(
+              _i15.dummyValue<_i19.AcceptDisclosureResult>(
+                this,
+                Invocation.method(#acceptDisclosureWithDeselectedAttributes, [
+                  pin,
+                  selectedIndices,
+                  selectedCredentialSetOptions,
+                  deselectedAttributes,
+                ]),
+              ),
+            ),
+          )
+          as _i11.Future<_i19.AcceptDisclosureResult>);
+
   @override
   _i11.Stream<List<_i19.AttestationPresentation>> observeCards() =>
       (super.noSuchMethod(
@@ -4414,19 +4540,117 @@ class MockWalletCoreApi extends _i1.Mock implements _i80.WalletCoreApi {
           as _i11.Future<_i19.AcceptDisclosureResult>);
 
   @override
-  _i11.Future<_i19.WalletInstructionResult> crateApiFullAcceptIssuance({
+  _i11.Future<_i19.AcceptDisclosureResult> crateApiFullAcceptDisclosureWithDeselectedAttributes({
+    required List<_i182.Uint16List>? selectedIndices,
+    required List<int?>? selectedCredentialSetOptions,
+    required List<_i19.DisclosureAttributeIdentifier>? deselectedAttributes,
+    required String? pin,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(
+              #crateApiFullAcceptDisclosureWithDeselectedAttributes,
+              [],
+              {
+                #selectedIndices: selectedIndices,
+                #selectedCredentialSetOptions: selectedCredentialSetOptions,
+                #deselectedAttributes: deselectedAttributes,
+                #pin: pin,
+              },
+            ),
+            returnValue: _i11.Future<_i19.AcceptDisclosureResult>.value(
+              _i15.dummyValue<_i19.AcceptDisclosureResult>(
+                this,
+                Invocation.method(
+                  #crateApiFullAcceptDisclosureWithDeselectedAttributes,
+                  [],
+                  {
+                    #selectedIndices: selectedIndices,
+                    #selectedCredentialSetOptions: selectedCredentialSetOptions,
+                    #deselectedAttributes: deselectedAttributes,
+                    #pin: pin,
+                  },
+                ),
+              ),
+            ),
+            returnValueForMissingStub: _i11.Future<_i19.AcceptDisclosureResult>.value(
+              _i15.dummyValue<_i19.AcceptDisclosureResult>(
+                this,
+                Invocation.method(
+                  #crateApiFullAcceptDisclosureWithDeselectedAttributes,
+                  [],
+                  {
+                    #selectedIndices: selectedIndices,
+                    #selectedCredentialSetOptions: selectedCredentialSetOptions,
+                    #deselectedAttributes: deselectedAttributes,
+                    #pin: pin,
+                  },
+                ),
+              ),
+            ),
+          )
+          as _i11.Future<_i19.AcceptDisclosureResult>);
+
+  @override
+  _i11.Future<_i19.AcceptDisclosureResult> crateApiFullAcceptDisclosureWithMultipleSelection({
+    required List<_i182.Uint16List>? selectedIndices,
+    required List<int?>? selectedCredentialSetOptions,
+    required String? pin,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(
+              #crateApiFullAcceptDisclosureWithMultipleSelection,
+              [],
+              {
+                #selectedIndices: selectedIndices,
+                #selectedCredentialSetOptions: selectedCredentialSetOptions,
+                #pin: pin,
+              },
+            ),
+            returnValue: _i11.Future<_i19.AcceptDisclosureResult>.value(
+              _i15.dummyValue<_i19.AcceptDisclosureResult>(
+                this,
+                Invocation.method(
+                  #crateApiFullAcceptDisclosureWithMultipleSelection,
+                  [],
+                  {
+                    #selectedIndices: selectedIndices,
+                    #selectedCredentialSetOptions: selectedCredentialSetOptions,
+                    #pin: pin,
+                  },
+                ),
+              ),
+            ),
+            returnValueForMissingStub: _i11.Future<_i19.AcceptDisclosureResult>.value(
+              _i15.dummyValue<_i19.AcceptDisclosureResult>(
+                this,
+                Invocation.method(
+                  #crateApiFullAcceptDisclosureWithMultipleSelection,
+                  [],
+                  {
+                    #selectedIndices: selectedIndices,
+                    #selectedCredentialSetOptions: selectedCredentialSetOptions,
+                    #pin: pin,
+                  },
+                ),
+              ),
+            ),
+          )
+          as _i11.Future<_i19.AcceptDisclosureResult>);
+
+  @override
+  _i11.Future<_i19.AcceptIssuanceResult> crateApiFullAcceptIssuance({
     required String? pin,
   }) =>
       (super.noSuchMethod(
             Invocation.method(#crateApiFullAcceptIssuance, [], {#pin: pin}),
-            returnValue: _i11.Future<_i19.WalletInstructionResult>.value(
-              _i15.dummyValue<_i19.WalletInstructionResult>(
+            returnValue: _i11.Future<_i19.AcceptIssuanceResult>.value(
+              _i15.dummyValue<_i19.AcceptIssuanceResult>(
                 this,
                 Invocation.method(#crateApiFullAcceptIssuance, [], {#pin: pin}),
               ),
             ),
-            returnValueForMissingStub: _i11.Future<_i19.WalletInstructionResult>.value(
-              _i15.dummyValue<_i19.WalletInstructionResult>(
+            returnValueForMissingStub: _i11.Future<_i19.AcceptIssuanceResult>.value(
+              _i15.dummyValue<_i19.AcceptIssuanceResult>(
                 this,
                 Invocation.method(#crateApiFullAcceptIssuance, [], {
                   #pin: pin,
@@ -4434,7 +4658,7 @@ class MockWalletCoreApi extends _i1.Mock implements _i80.WalletCoreApi {
               ),
             ),
           )
-          as _i11.Future<_i19.WalletInstructionResult>);
+          as _i11.Future<_i19.AcceptIssuanceResult>);
 
   @override
   _i11.Future<_i19.PidIssuanceResult> crateApiFullAcceptPidIssuance({
@@ -5351,6 +5575,40 @@ class MockWalletCoreApi extends _i1.Mock implements _i80.WalletCoreApi {
           )
           as _i11.Future<_i19.IssuanceStartResult>);
 
+  @override
+  _i11.Future<_i19.IssuanceStartResult> crateApiFullStartIssuanceFromOfferWithTransactionCode({
+    required String? offerUri,
+    required String? transactionCode,
+  }) =>
+      (super.noSuchMethod(
+            Invocation.method(
+              #crateApiFullStartIssuanceFromOfferWithTransactionCode,
+              [],
+              {#offerUri: offerUri, #transactionCode: transactionCode},
+            ),
+            returnValue: _i11.Future<_i19.IssuanceStartResult>.value(
+              _i15.dummyValue<_i19.IssuanceStartResult>(
+                this,
+                Invocation.method(
+                  #crateApiFullStartIssuanceFromOfferWithTransactionCode,
+                  [],
+                  {#offerUri: offerUri, #transactionCode: transactionCode},
+                ),
+              ),
+            ),
+            returnValueForMissingStub: _i11.Future<_i19.IssuanceStartResult>.value(
+              _i15.dummyValue<_i19.IssuanceStartResult>(
+                this,
+                Invocation.method(
+                  #crateApiFullStartIssuanceFromOfferWithTransactionCode,
+                  [],
+                  {#offerUri: offerUri, #transactionCode: transactionCode},
+                ),
+              ),
+            ),
+          )
+          as _i11.Future<_i19.IssuanceStartResult>);
+
   @override
   _i11.Future<void> crateApiFullTransferWallet() =>
       (super.noSuchMethod(
@@ -8956,12 +9214,17 @@ class MockStartIssuanceUseCase extends _i1.Mock implements _i175.StartIssuanceUs
     String? issuanceUri, {
     bool? isQrCode = false,
     required _i176.IssuanceType? type,
+    String? transactionCode,
   }) =>
       (super.noSuchMethod(
             Invocation.method(
               #invoke,
               [issuanceUri],
-              {#isQrCode: isQrCode, #type: type},
+              {
+                #isQrCode: isQrCode,
+                #type: type,
+                #transactionCode: transactionCode,
+              },
             ),
             returnValue: _i11.Future<_i86.Result<_i31.StartIssuanceResult>>.value(
               _i15.dummyValue<_i86.Result<_i31.StartIssuanceResult>>(
@@ -8969,7 +9232,11 @@ class MockStartIssuanceUseCase extends _i1.Mock implements _i175.StartIssuanceUs
                 Invocation.method(
                   #invoke,
                   [issuanceUri],
-                  {#isQrCode: isQrCode, #type: type},
+                  {
+                    #isQrCode: isQrCode,
+                    #type: type,
+                    #transactionCode: transactionCode,
+                  },
                 ),
               ),
             ),
@@ -8979,7 +9246,11 @@ class MockStartIssuanceUseCase extends _i1.Mock implements _i175.StartIssuanceUs
                 Invocation.method(
                   #invoke,
                   [issuanceUri],
-                  {#isQrCode: isQrCode, #type: type},
+                  {
+                    #isQrCode: isQrCode,
+                    #type: type,
+                    #transactionCode: transactionCode,
+                  },
                 ),
               ),
             ),
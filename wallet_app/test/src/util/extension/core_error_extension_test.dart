@@ -195,5 +195,17 @@ void main() {
       expect(applicationError, isA<ApplicationStateError>());
       expect(applicationError.sourceError, coreError);
     });
+
+    test('CoreTransactionCodeRequired maps to TransactionCodeRequiredError', () async {
+      final transactionCode = TransactionCodeData(inputMode: .numeric, length: 6);
+      final coreError = CoreTransactionCodeRequired('description', transactionCode: transactionCode);
+
+      final applicationError = await coreError.asApplicationError();
+
+      expect(applicationError, isA<TransactionCodeRequiredError>());
+      final transactionCodeError = applicationError as TransactionCodeRequiredError;
+      expect(transactionCodeError.transactionCode, transactionCode);
+      expect(transactionCodeError.sourceError, coreError);
+    });
   });
 }
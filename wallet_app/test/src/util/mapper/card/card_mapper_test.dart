@@ -24,6 +24,7 @@ const _kSampleCard = core.AttestationPresentation(
   format: .SdJwt,
   displayMetadata: [CoreMockData.enDisplayMetadata],
   attributes: [_kSampleAttributeName, _kSampleAttributeCity],
+  svgTemplates: [],
   issuer: _kSampleIssuer,
   validityStatus: core.ValidityStatus_Valid(validUntil: null),
 );
@@ -63,6 +64,7 @@ void main() {
         format: .SdJwt,
         displayMetadata: [CoreMockData.enDisplayMetadata],
         attributes: [],
+        svgTemplates: [],
         issuer: _kSampleIssuer,
         validityStatus: core.ValidityStatus_Valid(validUntil: null),
       );
@@ -67,6 +67,15 @@ void main() {
       expect(mapper.map(input), const CardStatusRevoked());
     });
 
+    test('Suspended revocationStatus should return CardStatusSuspended', () {
+      final input = _createMockAttestationPresentation(
+        revocationStatus: core.RevocationStatus.Suspended,
+        validityStatus: const core.ValidityStatus_Valid(validUntil: null),
+      );
+
+      expect(mapper.map(input), const CardStatusSuspended());
+    });
+
     test('Corrupted revocationStatus should return CardStatusCorrupted', () {
       final input = _createMockAttestationPresentation(
         revocationStatus: core.RevocationStatus.Corrupted,
@@ -109,6 +118,7 @@ core.AttestationPresentation _createMockAttestationPresentation({
     displayMetadata: [CoreMockData.enDisplayMetadata, CoreMockData.nlDisplayMetadata],
     issuer: CoreMockData.organization,
     attributes: [CoreMockData.attestationAttributeName],
+    svgTemplates: [],
     revocationStatus: revocationStatus,
     validityStatus: validityStatus,
   );
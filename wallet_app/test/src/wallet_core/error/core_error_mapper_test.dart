@@ -317,5 +317,29 @@ void main() {
       final result = errorMapper.map(errorJson);
       expect(result, CoreAccountRevokedError(defaultDescription, revocationData: revocationData, data: errorData));
     });
+
+    test('mapping FlutterApiErrorType.transactionCodeRequired results in CoreTransactionCodeRequired', () {
+      final error = const FlutterApiError(
+        type: FlutterApiErrorType.transactionCodeRequired,
+        description: defaultDescription,
+        data: {
+          'transaction_code': {'input_mode': 'text', 'length': 6, 'description': 'Sent by e-mail'},
+        },
+      );
+      final errorJson = jsonEncode(error);
+      final result = errorMapper.map(errorJson);
+      expect(
+        result,
+        CoreTransactionCodeRequired(
+          defaultDescription,
+          transactionCode: TransactionCodeData(
+            inputMode: .text,
+            length: 6,
+            description: 'Sent by e-mail',
+          ),
+          data: error.data,
+        ),
+      );
+    });
   });
 }
@@ -1,4 +1,5 @@
 import 'dart:convert';
+import 'dart:typed_data';
 
 import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
 import 'package:flutter_test/flutter_test.dart';
@@ -196,6 +197,7 @@ void main() {
           format: .SdJwt,
           displayMetadata: [],
           attributes: [],
+          svgTemplates: [],
           issuer: _kSampleIssuer,
           validityStatus: ValidityStatus_Valid(validUntil: null),
         ),
@@ -205,6 +207,7 @@ void main() {
           format: .SdJwt,
           displayMetadata: [],
           attributes: [],
+          svgTemplates: [],
           issuer: _kSampleIssuer,
           validityStatus: ValidityStatus_Valid(validUntil: null),
         ),
@@ -225,6 +228,7 @@ void main() {
           format: .SdJwt,
           displayMetadata: [],
           attributes: [],
+          svgTemplates: [],
           issuer: _kSampleIssuer,
           validityStatus: ValidityStatus_Valid(validUntil: null),
         ),
@@ -236,6 +240,7 @@ void main() {
           format: .SdJwt,
           displayMetadata: [],
           attributes: [],
+          svgTemplates: [],
           issuer: _kSampleIssuer,
           validityStatus: ValidityStatus_Valid(validUntil: null),
         ),
@@ -245,6 +250,7 @@ void main() {
           format: .SdJwt,
           displayMetadata: [],
           attributes: [],
+          svgTemplates: [],
           issuer: _kSampleIssuer,
           validityStatus: ValidityStatus_Valid(validUntil: null),
         ),
@@ -267,6 +273,7 @@ void main() {
           format: .SdJwt,
           displayMetadata: [],
           attributes: [],
+          svgTemplates: [],
           issuer: _kSampleIssuer,
           validityStatus: ValidityStatus_Valid(validUntil: null),
         ),
@@ -278,6 +285,7 @@ void main() {
           format: .SdJwt,
           displayMetadata: [],
           attributes: [],
+          svgTemplates: [],
           issuer: _kSampleIssuer,
           validityStatus: ValidityStatus_Valid(validUntil: null),
         ),
@@ -287,6 +295,7 @@ void main() {
           format: .SdJwt,
           displayMetadata: [],
           attributes: [],
+          svgTemplates: [],
           issuer: _kSampleIssuer,
           validityStatus: ValidityStatus_Valid(validUntil: null),
         ),
@@ -395,6 +404,16 @@ void main() {
       verify(core.crateApiFullStartIssuanceFromOffer(offerUri: 'uri')).called(1);
     });
 
+    test('startIssuanceFromOfferWithTransactionCode is passed on to core', () async {
+      when(
+        core.crateApiFullStartIssuanceFromOfferWithTransactionCode(offerUri: 'uri', transactionCode: '123456'),
+      ).thenAnswer((_) async => const IssuanceStartResult.previews([]));
+      await typedWalletCore.startIssuanceFromOfferWithTransactionCode('uri', '123456');
+      verify(
+        core.crateApiFullStartIssuanceFromOfferWithTransactionCode(offerUri: 'uri', transactionCode: '123456'),
+      ).called(1);
+    });
+
     test('acceptPidIssuance is passed on to core', () async {
       await typedWalletCore.acceptPidIssuance(_kSamplePin);
       verify(core.crateApiFullAcceptPidIssuance(pin: _kSamplePin)).called(1);
@@ -421,6 +440,21 @@ void main() {
       await typedWalletCore.acceptDisclosure(_kSamplePin, [1, 2]);
       verify(core.crateApiFullAcceptDisclosure(pin: _kSamplePin, selectedIndices: [1, 2])).called(1);
     });
+
+    test('acceptDisclosureWithMultipleSelection is passed on to core', () async {
+      await typedWalletCore.acceptDisclosureWithMultipleSelection(_kSamplePin, [
+        [1, 2],
+      ], [null]);
+      verify(
+        core.crateApiFullAcceptDisclosureWithMultipleSelection(
+          pin: _kSamplePin,
+          selectedIndices: [
+            Uint16List.fromList([1, 2]),
+          ],
+          selectedCredentialSetOptions: [null],
+        ),
+      ).called(1);
+    });
   });
 
   group('pin recovery', () {
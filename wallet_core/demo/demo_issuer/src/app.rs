@@ -346,7 +346,11 @@ async fn pre_authorized_usecase(
         .build()
         .map_err(anyhow::Error::from)?
         .post(pacf_issuance_server_url.join("offer"))
-        .json(&OfferRequest { documents })
+        .json(&OfferRequest {
+            documents,
+            deferred: false,
+            tx_code: None,
+        })
         .send()
         .await
         .and_then(|r| r.error_for_status())
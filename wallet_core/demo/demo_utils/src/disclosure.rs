@@ -19,8 +19,8 @@ pub struct DemoDisclosedAttestation {
     pub issuer_uri: HttpsUri,
     pub attestation_qualification: AttestationQualification,
 
-    /// The issuer CA's common name
-    pub ca: String,
+    /// The issuer CA's common name, absent if the issuer key was resolved using JWT VC Issuer Metadata
+    pub ca: Option<String>,
     pub issuance_validity: IssuanceValidity,
 }
 
@@ -68,7 +68,8 @@ mod test {
                 )])),
                 issuer_uri: "https://issuer.example.com/".parse().unwrap(),
                 attestation_qualification: AttestationQualification::default(),
-                ca: "ca.issuer.example.com".to_string(),
+                ca: Some("ca.issuer.example.com".to_string()),
+                jwt_vc_issuer: None,
                 issuance_validity: IssuanceValidity::new(
                     DateTime::UNIX_EPOCH,
                     Some(DateTime::UNIX_EPOCH),
@@ -101,7 +102,8 @@ mod test {
                 ),
                 issuer_uri: "https://issuer.example.com/".parse().unwrap(),
                 attestation_qualification: AttestationQualification::default(),
-                ca: "ca.issuer.example.com".to_string(),
+                ca: Some("ca.issuer.example.com".to_string()),
+                jwt_vc_issuer: None,
                 issuance_validity: IssuanceValidity::new(
                     DateTime::UNIX_EPOCH,
                     Some(DateTime::UNIX_EPOCH),
@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use flutter_api_macros::flutter_api_error;
@@ -13,6 +14,7 @@ use wallet::PidIssuancePurpose;
 use wallet::UnlockMethod;
 use wallet::Wallet;
 use wallet::errors::WalletInitError;
+use wallet::utils::VecNonEmpty;
 use wallet::utils::version_string;
 
 use crate::frb_generated::StreamSink;
@@ -21,7 +23,9 @@ use crate::models::attestation::AttestationPresentation;
 use crate::models::config::FlutterConfiguration;
 use crate::models::disclosure::AcceptDisclosureResult;
 use crate::models::disclosure::CloseProximityDisclosureFlutterUpdate;
+use crate::models::disclosure::DisclosureAttributeIdentifier;
 use crate::models::disclosure::StartDisclosureResult;
+use crate::models::instruction::AcceptIssuanceResult;
 use crate::models::instruction::DisclosureBasedIssuanceResult;
 use crate::models::instruction::PidIssuanceResult;
 use crate::models::instruction::RevocationCodeResult;
@@ -246,6 +250,11 @@ pub async fn unlock_wallet(pin: String) -> anyhow::Result<WalletInstructionResul
 
     let result = wallet.unlock(pin.into()).await.try_into()?;
 
+    drop(wallet);
+    if matches!(result, WalletInstructionResult::Ok) {
+        spawn_deferred_issuance_poll();
+    }
+
     Ok(result)
 }
 
@@ -376,6 +385,22 @@ pub async fn start_issuance_from_offer(offer_uri: String) -> anyhow::Result<Issu
     Ok(issuance_start_result.into())
 }
 
+#[flutter_api_error]
+pub async fn start_issuance_from_offer_with_transaction_code(
+    offer_uri: String,
+    transaction_code: String,
+) -> anyhow::Result<IssuanceStartResult> {
+    let offer_uri = Url::parse(&offer_uri)?;
+
+    let mut wallet = wallet().write().await;
+
+    let issuance_start_result = wallet
+        .start_issuance_from_offer_with_transaction_code(offer_uri, transaction_code)
+        .await?;
+
+    Ok(issuance_start_result.into())
+}
+
 // TODO remove this function (PVW-5927)
 #[flutter_api_error]
 pub async fn cancel_issuance() -> anyhow::Result<()> {
@@ -402,7 +427,7 @@ pub async fn continue_issuance(uri: String) -> anyhow::Result<Vec<AttestationPre
 }
 
 #[flutter_api_error]
-pub async fn accept_issuance(pin: String) -> anyhow::Result<WalletInstructionResult> {
+pub async fn accept_issuance(pin: String) -> anyhow::Result<AcceptIssuanceResult> {
     let mut wallet = wallet().write().await;
 
     let result = wallet.accept_issuance(pin.into()).await.try_into()?;
@@ -487,6 +512,70 @@ pub async fn accept_disclosure(selected_indices: Vec<u16>, pin: String) -> anyho
     Ok(result)
 }
 
+#[flutter_api_error]
+pub async fn accept_disclosure_with_multiple_selection(
+    selected_indices: Vec<Vec<u16>>,
+    selected_credential_set_options: Vec<Option<u16>>,
+    pin: String,
+) -> anyhow::Result<AcceptDisclosureResult> {
+    let selected_indices = multiple_selection_indices(selected_indices)?;
+    let selected_credential_set_options = credential_set_options(selected_credential_set_options);
+
+    let mut wallet = wallet().write().await;
+
+    let result = wallet
+        .accept_disclosure_with_multiple_selection(&selected_indices, &selected_credential_set_options, pin.into())
+        .await
+        .try_into()?;
+
+    Ok(result)
+}
+
+#[flutter_api_error]
+pub async fn accept_disclosure_with_deselected_attributes(
+    selected_indices: Vec<Vec<u16>>,
+    selected_credential_set_options: Vec<Option<u16>>,
+    deselected_attributes: Vec<DisclosureAttributeIdentifier>,
+    pin: String,
+) -> anyhow::Result<AcceptDisclosureResult> {
+    let selected_indices = multiple_selection_indices(selected_indices)?;
+    let selected_credential_set_options = credential_set_options(selected_credential_set_options);
+    let deselected_attributes = deselected_attributes
+        .into_iter()
+        .map(wallet::DisclosureAttributeIdentifier::try_from)
+        .collect::<Result<HashSet<_>, _>>()?;
+
+    let mut wallet = wallet().write().await;
+
+    let result = wallet
+        .accept_disclosure_with_deselected_attributes(
+            &selected_indices,
+            &selected_credential_set_options,
+            &deselected_attributes,
+            pin.into(),
+        )
+        .await
+        .try_into()?;
+
+    Ok(result)
+}
+
+fn multiple_selection_indices(selected_indices: Vec<Vec<u16>>) -> anyhow::Result<Vec<VecNonEmpty<usize>>> {
+    let selected_indices = selected_indices
+        .into_iter()
+        .map(|indices| VecNonEmpty::try_from(indices.into_iter().map(usize::from).collect_vec()))
+        .try_collect()?;
+
+    Ok(selected_indices)
+}
+
+fn credential_set_options(selected_credential_set_options: Vec<Option<u16>>) -> Vec<Option<usize>> {
+    selected_credential_set_options
+        .into_iter()
+        .map(|option| option.map(usize::from))
+        .collect()
+}
+
 #[flutter_api_error]
 pub async fn continue_disclosure_based_issuance(
     selected_indices: Vec<u16>,
@@ -504,6 +593,30 @@ pub async fn continue_disclosure_based_issuance(
     Ok(result)
 }
 
+/// Poll the issuers of any pending deferred issuance sessions in the background after the wallet has been unlocked, so
+/// that attestations issued in the meantime are received without the user having to wait for this. Errors are only
+/// logged, as the sessions will be polled again on the next unlock. The wallet is not held on to while the issuers are
+/// being polled, so that the user can continue to use it.
+fn spawn_deferred_issuance_poll() {
+    tokio::spawn(async {
+        let poll = wallet().read().await.prepare_deferred_issuance_poll().await;
+
+        let result = match poll {
+            Ok(Some(poll)) => {
+                let results = poll.execute().await;
+
+                wallet().write().await.finish_deferred_issuance_poll(results).await
+            }
+            Ok(None) => Ok(()),
+            Err(error) => Err(error),
+        };
+
+        if let Err(error) = result {
+            tracing::warn!("could not poll deferred issuance sessions: {error}");
+        }
+    });
+}
+
 #[flutter_api_error]
 pub async fn is_biometric_unlock_enabled() -> anyhow::Result<bool> {
     let wallet = wallet().read().await;
@@ -533,6 +646,9 @@ pub async fn unlock_wallet_with_biometrics() -> anyhow::Result<()> {
 
     wallet.unlock_without_pin().await?;
 
+    drop(wallet);
+    spawn_deferred_issuance_poll();
+
     Ok(())
 }
 
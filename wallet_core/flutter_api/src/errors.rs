@@ -33,6 +33,7 @@ use wallet::errors::WalletUnlockError;
 use wallet::errors::openid4vc::AuthorizationErrorCode;
 use wallet::errors::openid4vc::CredentialErrorCode;
 use wallet::errors::openid4vc::OAuthError;
+use wallet::errors::openid4vc::PreAuthTransactionCode;
 use wallet::errors::openid4vc::RemoteErrorCode;
 use wallet::errors::openid4vc::VpClientError;
 use wallet::errors::openid4vc::VpMessageClientError;
@@ -106,6 +107,10 @@ enum FlutterApiErrorType {
     /// A pre-authorized (QR/deeplink) issuance code is no longer valid: it has expired or was already used.
     PreAuthorizedCodeExpired,
 
+    /// A pre-authorized (QR/deeplink) issuance requires a Transaction Code, which the user should enter before
+    /// issuance is started again, see the `transaction_code` field of the error data for its requirements.
+    TransactionCodeRequired,
+
     /// Wallet has been revoked.
     Revoked,
 
@@ -274,6 +279,7 @@ struct IssuanceErrorData {
     redirect_error: Option<RemoteErrorCode<AuthorizationErrorCode>>,
     organization_name: Option<String>,
     revocation_data: Option<AccountRevokedData>,
+    transaction_code: Option<PreAuthTransactionCode>,
 }
 
 impl FlutterApiErrorFields for IssuanceError {
@@ -297,6 +303,10 @@ impl FlutterApiErrorFields for IssuanceError {
                 FlutterApiErrorType::PreAuthorizedCodeExpired
             }
 
+            IssuanceError::IssuanceSession(WalletIssuanceError::CredentialOfferTxCodeRequired(_)) => {
+                FlutterApiErrorType::TransactionCodeRequired
+            }
+
             // The credential endpoint rejected the access token with `invalid_token`. On the wire this
             // single code covers every access-token-rejection case the issuer can raise: the session
             // being gone or in a terminal/wrong state (`UnexpectedState` / `UnknownSession`, e.g. the
@@ -321,7 +331,12 @@ impl FlutterApiErrorFields for IssuanceError {
             IssuanceError::AttestationPreview(_)
             | IssuanceError::Attestation { .. }
             | IssuanceError::IssuerServer { .. }
-            | IssuanceError::MissingTypeMetadata(_) => FlutterApiErrorType::Issuer,
+            | IssuanceError::MissingTypeMetadata(_)
+            | IssuanceError::DeferredPidIssuance => FlutterApiErrorType::Issuer,
+
+            IssuanceError::CertificateRevocation(error) => {
+                detect_networking_error(error).unwrap_or(FlutterApiErrorType::Issuer)
+            }
 
             IssuanceError::AuthorizationDenied => FlutterApiErrorType::DeniedDigid,
             IssuanceError::RecoveryCode(RecoveryCodeError::IncorrectRecoveryCode { .. }) => {
@@ -337,6 +352,7 @@ impl FlutterApiErrorFields for IssuanceError {
             | IssuanceError::AttestationStorage(_)
             | IssuanceError::AttestationQuery(_)
             | IssuanceError::SessionStorage(_)
+            | IssuanceError::DeferredIssuanceStorage(_)
             | IssuanceError::KeyNotFound(_)
             | IssuanceError::Attestations(_)
             | IssuanceError::Notifications(_)
@@ -373,11 +389,23 @@ impl FlutterApiErrorFields for IssuanceError {
             None
         };
 
-        if redirect_error.is_some() || organization_name.is_some() || revocation_data.is_some() {
+        let transaction_code =
+            if let Self::IssuanceSession(WalletIssuanceError::CredentialOfferTxCodeRequired(requirements)) = self {
+                Some(requirements.as_ref().clone())
+            } else {
+                None
+            };
+
+        if redirect_error.is_some()
+            || organization_name.is_some()
+            || revocation_data.is_some()
+            || transaction_code.is_some()
+        {
             serde_json::to_value(IssuanceErrorData {
                 redirect_error,
                 organization_name,
                 revocation_data,
+                transaction_code,
             })
             .unwrap() // This conversion should never fail.
         } else {
@@ -425,6 +453,9 @@ impl FlutterApiErrorFields for DisclosureError {
             DisclosureError::VpVerifierServer { error, .. } => {
                 detect_networking_error(error).unwrap_or(FlutterApiErrorType::Verifier)
             }
+            DisclosureError::CertificateRevocation(error) => {
+                detect_networking_error(error).unwrap_or(FlutterApiErrorType::Verifier)
+            }
             DisclosureError::Instruction(error) => FlutterApiErrorType::from(error),
             DisclosureError::NonSelectivelyDisclosableClaim(_, _)
             | DisclosureError::NonSelectivelyDisclosableClaimsNotRequested(_, _, _)
@@ -440,7 +471,8 @@ impl FlutterApiErrorFields for DisclosureError {
             | DisclosureError::IncrementUsageCount(_)
             | DisclosureError::EventStorage(_)
             | DisclosureError::ChangePin(_)
-            | DisclosureError::PlatformCloseProximityDisclosureSessionError(_) => FlutterApiErrorType::Generic,
+            | DisclosureError::PlatformCloseProximityDisclosureSessionError(_)
+            | DisclosureError::DeselectedAttributeNotOptional(_) => FlutterApiErrorType::Generic,
             DisclosureError::CloseProximityDisclosureSessionError(error) => error.into(),
         }
     }
@@ -658,7 +690,8 @@ impl From<&CloseProximityDisclosureError> for FlutterApiErrorType {
             | CloseProximityDisclosureError::InvalidDocRequest(_)
             | CloseProximityDisclosureError::UnsupportedDocFormat { .. }
             | CloseProximityDisclosureError::MalformedDeviceRequest(_)
-            | CloseProximityDisclosureError::InvalidDeviceRequest(_) => FlutterApiErrorType::Verifier,
+            | CloseProximityDisclosureError::InvalidDeviceRequest(_)
+            | CloseProximityDisclosureError::CertificateRevocation(_) => FlutterApiErrorType::Verifier,
             CloseProximityDisclosureError::DeviceResponseEncoding(_)
             | CloseProximityDisclosureError::DeviceResponse(_) => FlutterApiErrorType::Generic,
             CloseProximityDisclosureError::Disconnected => FlutterApiErrorType::CloseProximityDisconnected,
@@ -842,6 +875,7 @@ mod tests {
     use wallet::errors::openid4vc::ErrorResponse;
     use wallet::errors::openid4vc::OAuthError;
     use wallet::errors::openid4vc::PostAuthResponseErrorCode;
+    use wallet::errors::openid4vc::PreAuthTransactionCode;
     use wallet::errors::openid4vc::RemoteErrorCode;
     use wallet::errors::openid4vc::VpClientError;
     use wallet::errors::openid4vc::VpMessageClientError;
@@ -894,6 +928,17 @@ mod tests {
         FlutterApiErrorType::RedirectUri,
         json!({"redirect_error": "some_error"})
     )]
+    #[case::issuance_tx_code_required(
+        IssuanceError::IssuanceSession(WalletIssuanceError::CredentialOfferTxCodeRequired(Box::new(
+            PreAuthTransactionCode {
+                input_mode: None,
+                length: Some(6),
+                description: Some("Sent by e-mail".to_string()),
+            }
+        ))),
+        FlutterApiErrorType::TransactionCodeRequired,
+        json!({"transaction_code": {"length": 6, "description": "Sent by e-mail"}})
+    )]
     #[case::issuance_credential_request_invalid_token(
         IssuanceError::IssuanceSession(WalletIssuanceError::CredentialRequest(Box::new(ErrorResponse {
             error: RemoteErrorCode::Known(CredentialErrorCode::InvalidToken),
@@ -39,7 +39,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueNom,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.12.0";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -591652018;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1209297986;
 
 // Section: executor
 
@@ -102,6 +102,75 @@ fn wire__crate__api__full__accept_disclosure_impl(
         },
     )
 }
+fn wire__crate__api__full__accept_disclosure_with_deselected_attributes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    selected_indices: impl CstDecode<Vec<Vec<u16>>>,
+    selected_credential_set_options: impl CstDecode<Vec<Option<u16>>>,
+    deselected_attributes: impl CstDecode<Vec<crate::models::disclosure::DisclosureAttributeIdentifier>>,
+    pin: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "accept_disclosure_with_deselected_attributes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_selected_indices = selected_indices.cst_decode();
+            let api_selected_credential_set_options = selected_credential_set_options.cst_decode();
+            let api_deselected_attributes = deselected_attributes.cst_decode();
+            let api_pin = pin.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::full::accept_disclosure_with_deselected_attributes(
+                            api_selected_indices,
+                            api_selected_credential_set_options,
+                            api_deselected_attributes,
+                            api_pin,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__full__accept_disclosure_with_multiple_selection_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    selected_indices: impl CstDecode<Vec<Vec<u16>>>,
+    selected_credential_set_options: impl CstDecode<Vec<Option<u16>>>,
+    pin: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "accept_disclosure_with_multiple_selection",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_selected_indices = selected_indices.cst_decode();
+            let api_selected_credential_set_options = selected_credential_set_options.cst_decode();
+            let api_pin = pin.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::full::accept_disclosure_with_multiple_selection(
+                            api_selected_indices,
+                            api_selected_credential_set_options,
+                            api_pin,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__full__accept_issuance_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     pin: impl CstDecode<String>,
@@ -1496,6 +1565,36 @@ fn wire__crate__api__full__start_issuance_from_offer_impl(
         },
     )
 }
+fn wire__crate__api__full__start_issuance_from_offer_with_transaction_code_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    offer_uri: impl CstDecode<String>,
+    transaction_code: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_issuance_from_offer_with_transaction_code",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_offer_uri = offer_uri.cst_decode();
+            let api_transaction_code = transaction_code.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::full::start_issuance_from_offer_with_transaction_code(
+                            api_offer_uri,
+                            api_transaction_code,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__full__transfer_wallet_impl(port_: flutter_rust_bridge::for_generated::MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
@@ -1763,12 +1862,43 @@ impl CstDecode<crate::models::revocation::RevocationStatus> for i32 {
         match self {
             0 => crate::models::revocation::RevocationStatus::Valid,
             1 => crate::models::revocation::RevocationStatus::Revoked,
-            2 => crate::models::revocation::RevocationStatus::Undetermined,
-            3 => crate::models::revocation::RevocationStatus::Corrupted,
+            2 => crate::models::revocation::RevocationStatus::Suspended,
+            3 => crate::models::revocation::RevocationStatus::Undetermined,
+            4 => crate::models::revocation::RevocationStatus::Corrupted,
             _ => unreachable!("Invalid variant for RevocationStatus: {}", self),
         }
     }
 }
+impl CstDecode<crate::models::attestation::SvgTemplateColorScheme> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::models::attestation::SvgTemplateColorScheme {
+        match self {
+            0 => crate::models::attestation::SvgTemplateColorScheme::Light,
+            1 => crate::models::attestation::SvgTemplateColorScheme::Dark,
+            _ => unreachable!("Invalid variant for SvgTemplateColorScheme: {}", self),
+        }
+    }
+}
+impl CstDecode<crate::models::attestation::SvgTemplateContrast> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::models::attestation::SvgTemplateContrast {
+        match self {
+            0 => crate::models::attestation::SvgTemplateContrast::Normal,
+            1 => crate::models::attestation::SvgTemplateContrast::High,
+            _ => unreachable!("Invalid variant for SvgTemplateContrast: {}", self),
+        }
+    }
+}
+impl CstDecode<crate::models::attestation::SvgTemplateOrientation> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::models::attestation::SvgTemplateOrientation {
+        match self {
+            0 => crate::models::attestation::SvgTemplateOrientation::Portrait,
+            1 => crate::models::attestation::SvgTemplateOrientation::Landscape,
+            _ => unreachable!("Invalid variant for SvgTemplateOrientation: {}", self),
+        }
+    }
+}
 impl CstDecode<crate::models::wallet_state::TransferRole> for i32 {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::models::wallet_state::TransferRole {
@@ -1950,6 +2080,28 @@ impl SseDecode for crate::models::disclosure::AcceptDisclosureResult {
     }
 }
 
+impl SseDecode for crate::models::instruction::AcceptIssuanceResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_isDeferred = <bool>::sse_decode(deserializer);
+                return crate::models::instruction::AcceptIssuanceResult::Ok {
+                    is_deferred: var_isDeferred,
+                };
+            }
+            1 => {
+                let mut var_error = <crate::models::instruction::WalletInstructionError>::sse_decode(deserializer);
+                return crate::models::instruction::AcceptIssuanceResult::InstructionError { error: var_error };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseDecode for crate::models::notification::AppNotification {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2010,6 +2162,7 @@ impl SseDecode for crate::models::attestation::AttestationPresentation {
         let mut var_revocationStatus = <Option<crate::models::revocation::RevocationStatus>>::sse_decode(deserializer);
         let mut var_validityStatus = <crate::models::attestation::ValidityStatus>::sse_decode(deserializer);
         let mut var_attributes = <Vec<crate::models::attestation::AttestationAttribute>>::sse_decode(deserializer);
+        let mut var_svgTemplates = <Vec<crate::models::attestation::RenderedSvgTemplate>>::sse_decode(deserializer);
         return crate::models::attestation::AttestationPresentation {
             identity: var_identity,
             format: var_format,
@@ -2019,6 +2172,7 @@ impl SseDecode for crate::models::attestation::AttestationPresentation {
             revocation_status: var_revocationStatus,
             validity_status: var_validityStatus,
             attributes: var_attributes,
+            svg_templates: var_svgTemplates,
         };
     }
 }
@@ -2120,6 +2274,20 @@ impl SseDecode for crate::models::disclosure::CloseProximityDisclosureFlutterUpd
     }
 }
 
+impl SseDecode for crate::models::disclosure::DisclosureAttributeIdentifier {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_attestationOptionIndex = <u16>::sse_decode(deserializer);
+        let mut var_attestationIndex = <u16>::sse_decode(deserializer);
+        let mut var_attributeKey = <Vec<String>>::sse_decode(deserializer);
+        return crate::models::disclosure::DisclosureAttributeIdentifier {
+            attestation_option_index: var_attestationOptionIndex,
+            attestation_index: var_attestationIndex,
+            attribute_key: var_attributeKey,
+        };
+    }
+}
+
 impl SseDecode for crate::models::instruction::DisclosureBasedIssuanceResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2143,6 +2311,18 @@ impl SseDecode for crate::models::instruction::DisclosureBasedIssuanceResult {
     }
 }
 
+impl SseDecode for crate::models::disclosure::DisclosureCredentialSet {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_required_ = <bool>::sse_decode(deserializer);
+        let mut var_options = <Vec<Vec<u16>>>::sse_decode(deserializer);
+        return crate::models::disclosure::DisclosureCredentialSet {
+            required: var_required_,
+            options: var_options,
+        };
+    }
+}
+
 impl SseDecode for crate::models::disclosure::DisclosureOptions {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2394,6 +2574,18 @@ impl SseDecode for crate::models::issuance::IssuanceStartResult {
     }
 }
 
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::models::notification::AppNotification> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2460,6 +2652,34 @@ impl SseDecode for Vec<crate::models::attestation::ClaimDisplayMetadata> {
     }
 }
 
+impl SseDecode for Vec<crate::models::disclosure::DisclosureAttributeIdentifier> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::models::disclosure::DisclosureAttributeIdentifier>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::models::disclosure::DisclosureCredentialSet> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::models::disclosure::DisclosureCredentialSet>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::models::disclosure::DisclosureOptions> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2496,6 +2716,18 @@ impl SseDecode for Vec<crate::models::notification::DisplayTarget> {
     }
 }
 
+impl SseDecode for Vec<Vec<u16>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<Vec<u16>>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::models::localize::LocalizedString> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2520,6 +2752,18 @@ impl SseDecode for Vec<crate::models::disclosure::MissingAttribute> {
     }
 }
 
+impl SseDecode for Vec<Option<u16>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<Option<u16>>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::models::config::PidAttestation> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2570,6 +2814,20 @@ impl SseDecode for Vec<(i32, crate::models::notification::NotificationType)> {
     }
 }
 
+impl SseDecode for Vec<crate::models::attestation::RenderedSvgTemplate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::models::attestation::RenderedSvgTemplate>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::models::wallet_event::WalletEvent> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2698,6 +2956,69 @@ impl SseDecode for Option<crate::models::revocation::RevocationStatus> {
     }
 }
 
+impl SseDecode for Option<crate::models::attestation::SvgTemplateColorScheme> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::models::attestation::SvgTemplateColorScheme>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::models::attestation::SvgTemplateContrast> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::models::attestation::SvgTemplateContrast>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::models::attestation::SvgTemplateOrientation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::models::attestation::SvgTemplateOrientation>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::models::attestation::SvgTemplateProperties> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::models::attestation::SvgTemplateProperties>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u16>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2831,6 +3152,20 @@ impl SseDecode for (String, String) {
     }
 }
 
+impl SseDecode for crate::models::attestation::RenderedSvgTemplate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_locale = <String>::sse_decode(deserializer);
+        let mut var_properties = <Option<crate::models::attestation::SvgTemplateProperties>>::sse_decode(deserializer);
+        let mut var_svg = <SanitizedSvg>::sse_decode(deserializer);
+        return crate::models::attestation::RenderedSvgTemplate {
+            locale: var_locale,
+            properties: var_properties,
+            svg: var_svg,
+        };
+    }
+}
+
 impl SseDecode for crate::models::attestation::RenderingMetadata {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2903,8 +3238,9 @@ impl SseDecode for crate::models::revocation::RevocationStatus {
         return match inner {
             0 => crate::models::revocation::RevocationStatus::Valid,
             1 => crate::models::revocation::RevocationStatus::Revoked,
-            2 => crate::models::revocation::RevocationStatus::Undetermined,
-            3 => crate::models::revocation::RevocationStatus::Corrupted,
+            2 => crate::models::revocation::RevocationStatus::Suspended,
+            3 => crate::models::revocation::RevocationStatus::Undetermined,
+            4 => crate::models::revocation::RevocationStatus::Corrupted,
             _ => unreachable!("Invalid variant for RevocationStatus: {}", inner),
         };
     }
@@ -2925,6 +3261,11 @@ impl SseDecode for crate::models::disclosure::StartDisclosureResult {
                 let mut var_requestPurpose = <Vec<crate::models::localize::LocalizedString>>::sse_decode(deserializer);
                 let mut var_requestOriginBaseUrl = <String>::sse_decode(deserializer);
                 let mut var_requestType = <crate::models::disclosure::DisclosureType>::sse_decode(deserializer);
+                let mut var_multipleSelectionIndices = <Vec<u16>>::sse_decode(deserializer);
+                let mut var_credentialSets =
+                    <Vec<crate::models::disclosure::DisclosureCredentialSet>>::sse_decode(deserializer);
+                let mut var_optionalAttributes =
+                    <Vec<crate::models::disclosure::DisclosureAttributeIdentifier>>::sse_decode(deserializer);
                 return crate::models::disclosure::StartDisclosureResult::Request {
                     relying_party: var_relyingParty,
                     policy: var_policy,
@@ -2934,6 +3275,9 @@ impl SseDecode for crate::models::disclosure::StartDisclosureResult {
                     request_purpose: var_requestPurpose,
                     request_origin_base_url: var_requestOriginBaseUrl,
                     request_type: var_requestType,
+                    multiple_selection_indices: var_multipleSelectionIndices,
+                    credential_sets: var_credentialSets,
+                    optional_attributes: var_optionalAttributes,
                 };
             }
             1 => {
@@ -2960,46 +3304,98 @@ impl SseDecode for crate::models::disclosure::StartDisclosureResult {
     }
 }
 
-impl SseDecode for crate::models::wallet_state::TransferRole {
+impl SseDecode for crate::models::attestation::SvgTemplateColorScheme {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut inner = <i32>::sse_decode(deserializer);
         return match inner {
-            0 => crate::models::wallet_state::TransferRole::Source,
-            1 => crate::models::wallet_state::TransferRole::Destination,
-            _ => unreachable!("Invalid variant for TransferRole: {}", inner),
+            0 => crate::models::attestation::SvgTemplateColorScheme::Light,
+            1 => crate::models::attestation::SvgTemplateColorScheme::Dark,
+            _ => unreachable!("Invalid variant for SvgTemplateColorScheme: {}", inner),
         };
     }
 }
 
-impl SseDecode for crate::models::transfer::TransferSessionState {
+impl SseDecode for crate::models::attestation::SvgTemplateContrast {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut inner = <i32>::sse_decode(deserializer);
         return match inner {
-            0 => crate::models::transfer::TransferSessionState::Created,
-            1 => crate::models::transfer::TransferSessionState::Paired,
-            2 => crate::models::transfer::TransferSessionState::Confirmed,
-            3 => crate::models::transfer::TransferSessionState::Uploaded,
-            4 => crate::models::transfer::TransferSessionState::Success,
-            5 => crate::models::transfer::TransferSessionState::Canceled,
-            6 => crate::models::transfer::TransferSessionState::Error,
-            _ => unreachable!("Invalid variant for TransferSessionState: {}", inner),
+            0 => crate::models::attestation::SvgTemplateContrast::Normal,
+            1 => crate::models::attestation::SvgTemplateContrast::High,
+            _ => unreachable!("Invalid variant for SvgTemplateContrast: {}", inner),
         };
     }
 }
 
-impl SseDecode for u16 {
+impl SseDecode for crate::models::attestation::SvgTemplateOrientation {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::models::attestation::SvgTemplateOrientation::Portrait,
+            1 => crate::models::attestation::SvgTemplateOrientation::Landscape,
+            _ => unreachable!("Invalid variant for SvgTemplateOrientation: {}", inner),
+        };
     }
 }
 
-impl SseDecode for u32 {
+impl SseDecode for crate::models::attestation::SvgTemplateProperties {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+        let mut var_orientation =
+            <Option<crate::models::attestation::SvgTemplateOrientation>>::sse_decode(deserializer);
+        let mut var_colorScheme =
+            <Option<crate::models::attestation::SvgTemplateColorScheme>>::sse_decode(deserializer);
+        let mut var_contrast = <Option<crate::models::attestation::SvgTemplateContrast>>::sse_decode(deserializer);
+        return crate::models::attestation::SvgTemplateProperties {
+            orientation: var_orientation,
+            color_scheme: var_colorScheme,
+            contrast: var_contrast,
+        };
+    }
+}
+
+impl SseDecode for crate::models::wallet_state::TransferRole {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::models::wallet_state::TransferRole::Source,
+            1 => crate::models::wallet_state::TransferRole::Destination,
+            _ => unreachable!("Invalid variant for TransferRole: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::models::transfer::TransferSessionState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::models::transfer::TransferSessionState::Created,
+            1 => crate::models::transfer::TransferSessionState::Paired,
+            2 => crate::models::transfer::TransferSessionState::Confirmed,
+            3 => crate::models::transfer::TransferSessionState::Uploaded,
+            4 => crate::models::transfer::TransferSessionState::Success,
+            5 => crate::models::transfer::TransferSessionState::Canceled,
+            6 => crate::models::transfer::TransferSessionState::Error,
+            _ => unreachable!("Invalid variant for TransferSessionState: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
     }
 }
 
@@ -3290,6 +3686,30 @@ impl flutter_rust_bridge::IntoIntoDart<crate::models::disclosure::AcceptDisclosu
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::instruction::AcceptIssuanceResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::models::instruction::AcceptIssuanceResult::Ok { is_deferred } => {
+                [0.into_dart(), is_deferred.into_into_dart().into_dart()].into_dart()
+            }
+            crate::models::instruction::AcceptIssuanceResult::InstructionError { error } => {
+                [1.into_dart(), error.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::models::instruction::AcceptIssuanceResult {}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::instruction::AcceptIssuanceResult>
+    for crate::models::instruction::AcceptIssuanceResult
+{
+    fn into_into_dart(self) -> crate::models::instruction::AcceptIssuanceResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::models::notification::AppNotification {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -3362,6 +3782,7 @@ impl flutter_rust_bridge::IntoDart for crate::models::attestation::AttestationPr
             self.revocation_status.into_into_dart().into_dart(),
             self.validity_status.into_into_dart().into_dart(),
             self.attributes.into_into_dart().into_dart(),
+            self.svg_templates.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -3478,6 +3899,28 @@ impl flutter_rust_bridge::IntoIntoDart<crate::models::disclosure::CloseProximity
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::disclosure::DisclosureAttributeIdentifier {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.attestation_option_index.into_into_dart().into_dart(),
+            self.attestation_index.into_into_dart().into_dart(),
+            self.attribute_key.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::disclosure::DisclosureAttributeIdentifier
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::disclosure::DisclosureAttributeIdentifier>
+    for crate::models::disclosure::DisclosureAttributeIdentifier
+{
+    fn into_into_dart(self) -> crate::models::disclosure::DisclosureAttributeIdentifier {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::models::instruction::DisclosureBasedIssuanceResult {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
@@ -3505,6 +3948,27 @@ impl flutter_rust_bridge::IntoIntoDart<crate::models::instruction::DisclosureBas
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::disclosure::DisclosureCredentialSet {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.required.into_into_dart().into_dart(),
+            self.options.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::disclosure::DisclosureCredentialSet
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::disclosure::DisclosureCredentialSet>
+    for crate::models::disclosure::DisclosureCredentialSet
+{
+    fn into_into_dart(self) -> crate::models::disclosure::DisclosureCredentialSet {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::models::disclosure::DisclosureOptions {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [self.0.into_into_dart().into_dart()].into_dart()
@@ -3924,6 +4388,25 @@ impl flutter_rust_bridge::IntoIntoDart<crate::models::pin::PinValidationResult>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::attestation::RenderedSvgTemplate {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.locale.into_into_dart().into_dart(),
+            self.properties.into_into_dart().into_dart(),
+            self.svg.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::models::attestation::RenderedSvgTemplate {}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::attestation::RenderedSvgTemplate>
+    for crate::models::attestation::RenderedSvgTemplate
+{
+    fn into_into_dart(self) -> crate::models::attestation::RenderedSvgTemplate {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::models::attestation::RenderingMetadata {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
@@ -4005,8 +4488,9 @@ impl flutter_rust_bridge::IntoDart for crate::models::revocation::RevocationStat
         match self {
             Self::Valid => 0.into_dart(),
             Self::Revoked => 1.into_dart(),
-            Self::Undetermined => 2.into_dart(),
-            Self::Corrupted => 3.into_dart(),
+            Self::Suspended => 2.into_dart(),
+            Self::Undetermined => 3.into_dart(),
+            Self::Corrupted => 4.into_dart(),
             _ => unreachable!(),
         }
     }
@@ -4032,6 +4516,9 @@ impl flutter_rust_bridge::IntoDart for crate::models::disclosure::StartDisclosur
                 request_purpose,
                 request_origin_base_url,
                 request_type,
+                multiple_selection_indices,
+                credential_sets,
+                optional_attributes,
             } => [
                 0.into_dart(),
                 relying_party.into_into_dart().into_dart(),
@@ -4042,6 +4529,9 @@ impl flutter_rust_bridge::IntoDart for crate::models::disclosure::StartDisclosur
                 request_purpose.into_into_dart().into_dart(),
                 request_origin_base_url.into_into_dart().into_dart(),
                 request_type.into_into_dart().into_dart(),
+                multiple_selection_indices.into_into_dart().into_dart(),
+                credential_sets.into_into_dart().into_dart(),
+                optional_attributes.into_into_dart().into_dart(),
             ]
             .into_dart(),
             crate::models::disclosure::StartDisclosureResult::RequestAttributesMissing {
@@ -4076,6 +4566,85 @@ impl flutter_rust_bridge::IntoIntoDart<crate::models::disclosure::StartDisclosur
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::attestation::SvgTemplateColorScheme {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Light => 0.into_dart(),
+            Self::Dark => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::attestation::SvgTemplateColorScheme
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::attestation::SvgTemplateColorScheme>
+    for crate::models::attestation::SvgTemplateColorScheme
+{
+    fn into_into_dart(self) -> crate::models::attestation::SvgTemplateColorScheme {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::attestation::SvgTemplateContrast {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Normal => 0.into_dart(),
+            Self::High => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::models::attestation::SvgTemplateContrast {}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::attestation::SvgTemplateContrast>
+    for crate::models::attestation::SvgTemplateContrast
+{
+    fn into_into_dart(self) -> crate::models::attestation::SvgTemplateContrast {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::attestation::SvgTemplateOrientation {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Portrait => 0.into_dart(),
+            Self::Landscape => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::attestation::SvgTemplateOrientation
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::attestation::SvgTemplateOrientation>
+    for crate::models::attestation::SvgTemplateOrientation
+{
+    fn into_into_dart(self) -> crate::models::attestation::SvgTemplateOrientation {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::attestation::SvgTemplateProperties {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.orientation.into_into_dart().into_dart(),
+            self.color_scheme.into_into_dart().into_dart(),
+            self.contrast.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::models::attestation::SvgTemplateProperties {}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::attestation::SvgTemplateProperties>
+    for crate::models::attestation::SvgTemplateProperties
+{
+    fn into_into_dart(self) -> crate::models::attestation::SvgTemplateProperties {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::models::wallet_state::TransferRole {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
@@ -4424,6 +4993,25 @@ impl SseEncode for crate::models::disclosure::AcceptDisclosureResult {
     }
 }
 
+impl SseEncode for crate::models::instruction::AcceptIssuanceResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::models::instruction::AcceptIssuanceResult::Ok { is_deferred } => {
+                <i32>::sse_encode(0, serializer);
+                <bool>::sse_encode(is_deferred, serializer);
+            }
+            crate::models::instruction::AcceptIssuanceResult::InstructionError { error } => {
+                <i32>::sse_encode(1, serializer);
+                <crate::models::instruction::WalletInstructionError>::sse_encode(error, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseEncode for crate::models::notification::AppNotification {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4472,6 +5060,7 @@ impl SseEncode for crate::models::attestation::AttestationPresentation {
         <Option<crate::models::revocation::RevocationStatus>>::sse_encode(self.revocation_status, serializer);
         <crate::models::attestation::ValidityStatus>::sse_encode(self.validity_status, serializer);
         <Vec<crate::models::attestation::AttestationAttribute>>::sse_encode(self.attributes, serializer);
+        <Vec<crate::models::attestation::RenderedSvgTemplate>>::sse_encode(self.svg_templates, serializer);
     }
 }
 
@@ -4569,6 +5158,15 @@ impl SseEncode for crate::models::disclosure::CloseProximityDisclosureFlutterUpd
     }
 }
 
+impl SseEncode for crate::models::disclosure::DisclosureAttributeIdentifier {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u16>::sse_encode(self.attestation_option_index, serializer);
+        <u16>::sse_encode(self.attestation_index, serializer);
+        <Vec<String>>::sse_encode(self.attribute_key, serializer);
+    }
+}
+
 impl SseEncode for crate::models::instruction::DisclosureBasedIssuanceResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4588,6 +5186,14 @@ impl SseEncode for crate::models::instruction::DisclosureBasedIssuanceResult {
     }
 }
 
+impl SseEncode for crate::models::disclosure::DisclosureCredentialSet {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.required, serializer);
+        <Vec<Vec<u16>>>::sse_encode(self.options, serializer);
+    }
+}
+
 impl SseEncode for crate::models::disclosure::DisclosureOptions {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4828,6 +5434,16 @@ impl SseEncode for crate::models::issuance::IssuanceStartResult {
     }
 }
 
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::models::notification::AppNotification> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4878,6 +5494,26 @@ impl SseEncode for Vec<crate::models::attestation::ClaimDisplayMetadata> {
     }
 }
 
+impl SseEncode for Vec<crate::models::disclosure::DisclosureAttributeIdentifier> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::models::disclosure::DisclosureAttributeIdentifier>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::models::disclosure::DisclosureCredentialSet> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::models::disclosure::DisclosureCredentialSet>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::models::disclosure::DisclosureOptions> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4908,6 +5544,16 @@ impl SseEncode for Vec<crate::models::notification::DisplayTarget> {
     }
 }
 
+impl SseEncode for Vec<Vec<u16>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <Vec<u16>>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::models::localize::LocalizedString> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4928,6 +5574,16 @@ impl SseEncode for Vec<crate::models::disclosure::MissingAttribute> {
     }
 }
 
+impl SseEncode for Vec<Option<u16>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <Option<u16>>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::models::config::PidAttestation> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4968,6 +5624,16 @@ impl SseEncode for Vec<(i32, crate::models::notification::NotificationType)> {
     }
 }
 
+impl SseEncode for Vec<crate::models::attestation::RenderedSvgTemplate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::models::attestation::RenderedSvgTemplate>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::models::wallet_event::WalletEvent> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5077,6 +5743,56 @@ impl SseEncode for Option<crate::models::revocation::RevocationStatus> {
     }
 }
 
+impl SseEncode for Option<crate::models::attestation::SvgTemplateColorScheme> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::models::attestation::SvgTemplateColorScheme>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::models::attestation::SvgTemplateContrast> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::models::attestation::SvgTemplateContrast>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::models::attestation::SvgTemplateOrientation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::models::attestation::SvgTemplateOrientation>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::models::attestation::SvgTemplateProperties> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::models::attestation::SvgTemplateProperties>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u16>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5185,6 +5901,15 @@ impl SseEncode for (String, String) {
     }
 }
 
+impl SseEncode for crate::models::attestation::RenderedSvgTemplate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.locale, serializer);
+        <Option<crate::models::attestation::SvgTemplateProperties>>::sse_encode(self.properties, serializer);
+        <SanitizedSvg>::sse_encode(self.svg, serializer);
+    }
+}
+
 impl SseEncode for crate::models::attestation::RenderingMetadata {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5247,8 +5972,9 @@ impl SseEncode for crate::models::revocation::RevocationStatus {
             match self {
                 crate::models::revocation::RevocationStatus::Valid => 0,
                 crate::models::revocation::RevocationStatus::Revoked => 1,
-                crate::models::revocation::RevocationStatus::Undetermined => 2,
-                crate::models::revocation::RevocationStatus::Corrupted => 3,
+                crate::models::revocation::RevocationStatus::Suspended => 2,
+                crate::models::revocation::RevocationStatus::Undetermined => 3,
+                crate::models::revocation::RevocationStatus::Corrupted => 4,
                 _ => {
                     unimplemented!("");
                 }
@@ -5271,6 +5997,9 @@ impl SseEncode for crate::models::disclosure::StartDisclosureResult {
                 request_purpose,
                 request_origin_base_url,
                 request_type,
+                multiple_selection_indices,
+                credential_sets,
+                optional_attributes,
             } => {
                 <i32>::sse_encode(0, serializer);
                 <crate::models::organization::Organization>::sse_encode(relying_party, serializer);
@@ -5281,6 +6010,12 @@ impl SseEncode for crate::models::disclosure::StartDisclosureResult {
                 <Vec<crate::models::localize::LocalizedString>>::sse_encode(request_purpose, serializer);
                 <String>::sse_encode(request_origin_base_url, serializer);
                 <crate::models::disclosure::DisclosureType>::sse_encode(request_type, serializer);
+                <Vec<u16>>::sse_encode(multiple_selection_indices, serializer);
+                <Vec<crate::models::disclosure::DisclosureCredentialSet>>::sse_encode(credential_sets, serializer);
+                <Vec<crate::models::disclosure::DisclosureAttributeIdentifier>>::sse_encode(
+                    optional_attributes,
+                    serializer,
+                );
             }
             crate::models::disclosure::StartDisclosureResult::RequestAttributesMissing {
                 relying_party,
@@ -5305,6 +6040,63 @@ impl SseEncode for crate::models::disclosure::StartDisclosureResult {
     }
 }
 
+impl SseEncode for crate::models::attestation::SvgTemplateColorScheme {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::models::attestation::SvgTemplateColorScheme::Light => 0,
+                crate::models::attestation::SvgTemplateColorScheme::Dark => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::models::attestation::SvgTemplateContrast {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::models::attestation::SvgTemplateContrast::Normal => 0,
+                crate::models::attestation::SvgTemplateContrast::High => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::models::attestation::SvgTemplateOrientation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::models::attestation::SvgTemplateOrientation::Portrait => 0,
+                crate::models::attestation::SvgTemplateOrientation::Landscape => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::models::attestation::SvgTemplateProperties {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<crate::models::attestation::SvgTemplateOrientation>>::sse_encode(self.orientation, serializer);
+        <Option<crate::models::attestation::SvgTemplateColorScheme>>::sse_encode(self.color_scheme, serializer);
+        <Option<crate::models::attestation::SvgTemplateContrast>>::sse_encode(self.contrast, serializer);
+    }
+}
+
 impl SseEncode for crate::models::wallet_state::TransferRole {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5713,6 +6505,26 @@ mod io {
             }
         }
     }
+    impl CstDecode<crate::models::instruction::AcceptIssuanceResult> for wire_cst_accept_issuance_result {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::models::instruction::AcceptIssuanceResult {
+            match self.tag {
+                0 => {
+                    let ans = unsafe { self.kind.Ok };
+                    crate::models::instruction::AcceptIssuanceResult::Ok {
+                        is_deferred: ans.is_deferred.cst_decode(),
+                    }
+                }
+                1 => {
+                    let ans = unsafe { self.kind.InstructionError };
+                    crate::models::instruction::AcceptIssuanceResult::InstructionError {
+                        error: ans.error.cst_decode(),
+                    }
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
     impl CstDecode<crate::models::notification::AppNotification> for wire_cst_app_notification {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> crate::models::notification::AppNotification {
@@ -5761,6 +6573,7 @@ mod io {
                 revocation_status: self.revocation_status.cst_decode(),
                 validity_status: self.validity_status.cst_decode(),
                 attributes: self.attributes.cst_decode(),
+                svg_templates: self.svg_templates.cst_decode(),
             }
         }
     }
@@ -5853,6 +6666,40 @@ mod io {
             CstDecode::<crate::models::revocation::RevocationStatus>::cst_decode(*wrap).into()
         }
     }
+    impl CstDecode<crate::models::attestation::SvgTemplateColorScheme> for *mut i32 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::models::attestation::SvgTemplateColorScheme {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::models::attestation::SvgTemplateColorScheme>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::models::attestation::SvgTemplateContrast> for *mut i32 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::models::attestation::SvgTemplateContrast {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::models::attestation::SvgTemplateContrast>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::models::attestation::SvgTemplateOrientation> for *mut i32 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::models::attestation::SvgTemplateOrientation {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::models::attestation::SvgTemplateOrientation>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::models::attestation::SvgTemplateProperties> for *mut wire_cst_svg_template_properties {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::models::attestation::SvgTemplateProperties {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::models::attestation::SvgTemplateProperties>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<u16> for *mut u16 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> u16 {
+            unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
+        }
+    }
     impl CstDecode<u64> for *mut u64 {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> u64 {
@@ -5902,6 +6749,16 @@ mod io {
             }
         }
     }
+    impl CstDecode<crate::models::disclosure::DisclosureAttributeIdentifier> for wire_cst_disclosure_attribute_identifier {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::models::disclosure::DisclosureAttributeIdentifier {
+            crate::models::disclosure::DisclosureAttributeIdentifier {
+                attestation_option_index: self.attestation_option_index.cst_decode(),
+                attestation_index: self.attestation_index.cst_decode(),
+                attribute_key: self.attribute_key.cst_decode(),
+            }
+        }
+    }
     impl CstDecode<crate::models::instruction::DisclosureBasedIssuanceResult>
         for wire_cst_disclosure_based_issuance_result
     {
@@ -5922,6 +6779,15 @@ mod io {
             }
         }
     }
+    impl CstDecode<crate::models::disclosure::DisclosureCredentialSet> for wire_cst_disclosure_credential_set {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::models::disclosure::DisclosureCredentialSet {
+            crate::models::disclosure::DisclosureCredentialSet {
+                required: self.required.cst_decode(),
+                options: self.options.cst_decode(),
+            }
+        }
+    }
     impl CstDecode<crate::models::disclosure::DisclosureOptions> for wire_cst_disclosure_options {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> crate::models::disclosure::DisclosureOptions {
@@ -6045,6 +6911,16 @@ mod io {
             }
         }
     }
+    impl CstDecode<Vec<String>> for *mut wire_cst_list_String {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<String> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
     impl CstDecode<Vec<crate::models::notification::AppNotification>> for *mut wire_cst_list_app_notification {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> Vec<crate::models::notification::AppNotification> {
@@ -6097,6 +6973,30 @@ mod io {
             vec.into_iter().map(CstDecode::cst_decode).collect()
         }
     }
+    impl CstDecode<Vec<crate::models::disclosure::DisclosureAttributeIdentifier>>
+        for *mut wire_cst_list_disclosure_attribute_identifier
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::models::disclosure::DisclosureAttributeIdentifier> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::models::disclosure::DisclosureCredentialSet>>
+        for *mut wire_cst_list_disclosure_credential_set
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::models::disclosure::DisclosureCredentialSet> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
     impl CstDecode<Vec<crate::models::disclosure::DisclosureOptions>> for *mut wire_cst_list_disclosure_options {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> Vec<crate::models::disclosure::DisclosureOptions> {
@@ -6127,6 +7027,16 @@ mod io {
             vec.into_iter().map(CstDecode::cst_decode).collect()
         }
     }
+    impl CstDecode<Vec<Vec<u16>>> for *mut wire_cst_list_list_prim_u_16_strict {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<Vec<u16>> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
     impl CstDecode<Vec<crate::models::localize::LocalizedString>> for *mut wire_cst_list_localized_string {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> Vec<crate::models::localize::LocalizedString> {
@@ -6147,6 +7057,16 @@ mod io {
             vec.into_iter().map(CstDecode::cst_decode).collect()
         }
     }
+    impl CstDecode<Vec<Option<u16>>> for *mut wire_cst_list_opt_box_autoadd_u_16 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<Option<u16>> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
     impl CstDecode<Vec<crate::models::config::PidAttestation>> for *mut wire_cst_list_pid_attestation {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> Vec<crate::models::config::PidAttestation> {
@@ -6196,6 +7116,16 @@ mod io {
             vec.into_iter().map(CstDecode::cst_decode).collect()
         }
     }
+    impl CstDecode<Vec<crate::models::attestation::RenderedSvgTemplate>> for *mut wire_cst_list_rendered_svg_template {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::models::attestation::RenderedSvgTemplate> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
     impl CstDecode<Vec<crate::models::wallet_event::WalletEvent>> for *mut wire_cst_list_wallet_event {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> Vec<crate::models::wallet_event::WalletEvent> {
@@ -6309,6 +7239,16 @@ mod io {
             (self.field0.cst_decode(), self.field1.cst_decode())
         }
     }
+    impl CstDecode<crate::models::attestation::RenderedSvgTemplate> for wire_cst_rendered_svg_template {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::models::attestation::RenderedSvgTemplate {
+            crate::models::attestation::RenderedSvgTemplate {
+                locale: self.locale.cst_decode(),
+                properties: self.properties.cst_decode(),
+                svg: self.svg.cst_decode(),
+            }
+        }
+    }
     impl CstDecode<crate::models::attestation::RenderingMetadata> for wire_cst_rendering_metadata {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> crate::models::attestation::RenderingMetadata {
@@ -6373,6 +7313,9 @@ mod io {
                         request_purpose: ans.request_purpose.cst_decode(),
                         request_origin_base_url: ans.request_origin_base_url.cst_decode(),
                         request_type: ans.request_type.cst_decode(),
+                        multiple_selection_indices: ans.multiple_selection_indices.cst_decode(),
+                        credential_sets: ans.credential_sets.cst_decode(),
+                        optional_attributes: ans.optional_attributes.cst_decode(),
                     }
                 }
                 1 => {
@@ -6390,6 +7333,16 @@ mod io {
             }
         }
     }
+    impl CstDecode<crate::models::attestation::SvgTemplateProperties> for wire_cst_svg_template_properties {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::models::attestation::SvgTemplateProperties {
+            crate::models::attestation::SvgTemplateProperties {
+                orientation: self.orientation.cst_decode(),
+                color_scheme: self.color_scheme.cst_decode(),
+                contrast: self.contrast.cst_decode(),
+            }
+        }
+    }
     impl CstDecode<crate::models::attestation::ValidityStatus> for wire_cst_validity_status {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> crate::models::attestation::ValidityStatus {
@@ -6545,6 +7498,19 @@ mod io {
             Self::new_with_null_ptr()
         }
     }
+    impl NewWithNullPtr for wire_cst_accept_issuance_result {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                tag: -1,
+                kind: AcceptIssuanceResultKind { nil__: () },
+            }
+        }
+    }
+    impl Default for wire_cst_accept_issuance_result {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
     impl NewWithNullPtr for wire_cst_app_notification {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -6598,6 +7564,7 @@ mod io {
                 revocation_status: core::ptr::null_mut(),
                 validity_status: Default::default(),
                 attributes: core::ptr::null_mut(),
+                svg_templates: core::ptr::null_mut(),
             }
         }
     }
@@ -6646,6 +7613,20 @@ mod io {
             Self::new_with_null_ptr()
         }
     }
+    impl NewWithNullPtr for wire_cst_disclosure_attribute_identifier {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                attestation_option_index: Default::default(),
+                attestation_index: Default::default(),
+                attribute_key: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_disclosure_attribute_identifier {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
     impl NewWithNullPtr for wire_cst_disclosure_based_issuance_result {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -6659,6 +7640,19 @@ mod io {
             Self::new_with_null_ptr()
         }
     }
+    impl NewWithNullPtr for wire_cst_disclosure_credential_set {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                required: Default::default(),
+                options: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_disclosure_credential_set {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
     impl NewWithNullPtr for wire_cst_disclosure_options {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -6883,6 +7877,20 @@ mod io {
             Self::new_with_null_ptr()
         }
     }
+    impl NewWithNullPtr for wire_cst_rendered_svg_template {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                locale: core::ptr::null_mut(),
+                properties: core::ptr::null_mut(),
+                svg: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_rendered_svg_template {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
     impl NewWithNullPtr for wire_cst_rendering_metadata {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -6937,6 +7945,20 @@ mod io {
             Self::new_with_null_ptr()
         }
     }
+    impl NewWithNullPtr for wire_cst_svg_template_properties {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                orientation: core::ptr::null_mut(),
+                color_scheme: core::ptr::null_mut(),
+                contrast: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_svg_template_properties {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
     impl NewWithNullPtr for wire_cst_validity_status {
         fn new_with_null_ptr() -> Self {
             Self {
@@ -7019,6 +8041,38 @@ mod io {
         wire__crate__api__full__accept_disclosure_impl(port_, selected_indices, pin)
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_wallet_core_wire__crate__api__full__accept_disclosure_with_deselected_attributes(
+        port_: i64,
+        selected_indices: *mut wire_cst_list_list_prim_u_16_strict,
+        selected_credential_set_options: *mut wire_cst_list_opt_box_autoadd_u_16,
+        deselected_attributes: *mut wire_cst_list_disclosure_attribute_identifier,
+        pin: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__full__accept_disclosure_with_deselected_attributes_impl(
+            port_,
+            selected_indices,
+            selected_credential_set_options,
+            deselected_attributes,
+            pin,
+        )
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_wallet_core_wire__crate__api__full__accept_disclosure_with_multiple_selection(
+        port_: i64,
+        selected_indices: *mut wire_cst_list_list_prim_u_16_strict,
+        selected_credential_set_options: *mut wire_cst_list_opt_box_autoadd_u_16,
+        pin: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__full__accept_disclosure_with_multiple_selection_impl(
+            port_,
+            selected_indices,
+            selected_credential_set_options,
+            pin,
+        )
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_wallet_core_wire__crate__api__full__accept_issuance(
         port_: i64,
@@ -7412,6 +8466,15 @@ mod io {
         wire__crate__api__full__start_issuance_from_offer_impl(port_, offer_uri)
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_wallet_core_wire__crate__api__full__start_issuance_from_offer_with_transaction_code(
+        port_: i64,
+        offer_uri: *mut wire_cst_list_prim_u_8_strict,
+        transaction_code: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__full__start_issuance_from_offer_with_transaction_code_impl(port_, offer_uri, transaction_code)
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_wallet_core_wire__crate__api__full__transfer_wallet(port_: i64) {
         wire__crate__api__full__transfer_wallet_impl(port_)
@@ -7495,6 +8558,32 @@ mod io {
         flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_wallet_core_cst_new_box_autoadd_svg_template_color_scheme(value: i32) -> *mut i32 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_wallet_core_cst_new_box_autoadd_svg_template_contrast(value: i32) -> *mut i32 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_wallet_core_cst_new_box_autoadd_svg_template_orientation(value: i32) -> *mut i32 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_wallet_core_cst_new_box_autoadd_svg_template_properties(
+    ) -> *mut wire_cst_svg_template_properties {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wire_cst_svg_template_properties::new_with_null_ptr())
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_wallet_core_cst_new_box_autoadd_u_16(value: u16) -> *mut u16 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_wallet_core_cst_new_box_autoadd_u_64(value: u64) -> *mut u64 {
         flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
@@ -7511,6 +8600,18 @@ mod io {
         flutter_rust_bridge::for_generated::new_leak_box_ptr(wire_cst_wallet_state::new_with_null_ptr())
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_wallet_core_cst_new_list_String(len: i32) -> *mut wire_cst_list_String {
+        let wrap = wire_cst_list_String {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <*mut wire_cst_list_prim_u_8_strict>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_wallet_core_cst_new_list_app_notification(
         len: i32,
@@ -7579,6 +8680,34 @@ mod io {
         flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_wallet_core_cst_new_list_disclosure_attribute_identifier(
+        len: i32,
+    ) -> *mut wire_cst_list_disclosure_attribute_identifier {
+        let wrap = wire_cst_list_disclosure_attribute_identifier {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_disclosure_attribute_identifier>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_wallet_core_cst_new_list_disclosure_credential_set(
+        len: i32,
+    ) -> *mut wire_cst_list_disclosure_credential_set {
+        let wrap = wire_cst_list_disclosure_credential_set {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_disclosure_credential_set>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_wallet_core_cst_new_list_disclosure_options(
         len: i32,
@@ -7619,6 +8748,20 @@ mod io {
         flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_wallet_core_cst_new_list_list_prim_u_16_strict(
+        len: i32,
+    ) -> *mut wire_cst_list_list_prim_u_16_strict {
+        let wrap = wire_cst_list_list_prim_u_16_strict {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <*mut wire_cst_list_prim_u_16_strict>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_wallet_core_cst_new_list_localized_string(
         len: i32,
@@ -7647,6 +8790,17 @@ mod io {
         flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_wallet_core_cst_new_list_opt_box_autoadd_u_16(
+        len: i32,
+    ) -> *mut wire_cst_list_opt_box_autoadd_u_16 {
+        let wrap = wire_cst_list_opt_box_autoadd_u_16 {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(core::ptr::null_mut(), len),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_wallet_core_cst_new_list_pid_attestation(len: i32) -> *mut wire_cst_list_pid_attestation {
         let wrap = wire_cst_list_pid_attestation {
@@ -7702,6 +8856,20 @@ mod io {
         flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_wallet_core_cst_new_list_rendered_svg_template(
+        len: i32,
+    ) -> *mut wire_cst_list_rendered_svg_template {
+        let wrap = wire_cst_list_rendered_svg_template {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_rendered_svg_template>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_wallet_core_cst_new_list_wallet_event(len: i32) -> *mut wire_cst_list_wallet_event {
         let wrap = wire_cst_list_wallet_event {
@@ -7739,6 +8907,29 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_accept_issuance_result {
+        tag: i32,
+        kind: AcceptIssuanceResultKind,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub union AcceptIssuanceResultKind {
+        Ok: wire_cst_AcceptIssuanceResult_Ok,
+        InstructionError: wire_cst_AcceptIssuanceResult_InstructionError,
+        nil__: (),
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_AcceptIssuanceResult_Ok {
+        is_deferred: bool,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_AcceptIssuanceResult_InstructionError {
+        error: *mut wire_cst_wallet_instruction_error,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_app_notification {
         id: i32,
         typ: wire_cst_notification_type,
@@ -7780,6 +8971,7 @@ mod io {
         revocation_status: *mut i32,
         validity_status: wire_cst_validity_status,
         attributes: *mut wire_cst_list_attestation_attribute,
+        svg_templates: *mut wire_cst_list_rendered_svg_template,
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
@@ -7842,6 +9034,13 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_disclosure_attribute_identifier {
+        attestation_option_index: u16,
+        attestation_index: u16,
+        attribute_key: *mut wire_cst_list_String,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_disclosure_based_issuance_result {
         tag: i32,
         kind: DisclosureBasedIssuanceResultKind,
@@ -7865,6 +9064,12 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_disclosure_credential_set {
+        required: bool,
+        options: *mut wire_cst_list_list_prim_u_16_strict,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_disclosure_options {
         field0: *mut wire_cst_list_attestation_presentation,
     }
@@ -7989,6 +9194,12 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_list_String {
+        ptr: *mut *mut wire_cst_list_prim_u_8_strict,
+        len: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_list_app_notification {
         ptr: *mut wire_cst_app_notification,
         len: i32,
@@ -8019,6 +9230,18 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_list_disclosure_attribute_identifier {
+        ptr: *mut wire_cst_disclosure_attribute_identifier,
+        len: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_disclosure_credential_set {
+        ptr: *mut wire_cst_disclosure_credential_set,
+        len: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_list_disclosure_options {
         ptr: *mut wire_cst_disclosure_options,
         len: i32,
@@ -8037,6 +9260,12 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_list_list_prim_u_16_strict {
+        ptr: *mut *mut wire_cst_list_prim_u_16_strict,
+        len: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_list_localized_string {
         ptr: *mut wire_cst_localized_string,
         len: i32,
@@ -8049,6 +9278,12 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_list_opt_box_autoadd_u_16 {
+        ptr: *mut *mut u16,
+        len: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_list_pid_attestation {
         ptr: *mut wire_cst_pid_attestation,
         len: i32,
@@ -8079,6 +9314,12 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_list_rendered_svg_template {
+        ptr: *mut wire_cst_rendered_svg_template,
+        len: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_list_wallet_event {
         ptr: *mut wire_cst_wallet_event,
         len: i32,
@@ -8182,6 +9423,13 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_rendered_svg_template {
+        locale: *mut wire_cst_list_prim_u_8_strict,
+        properties: *mut wire_cst_svg_template_properties,
+        svg: usize,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_rendering_metadata {
         tag: i32,
         kind: RenderingMetadataKind,
@@ -8255,6 +9503,9 @@ mod io {
         request_purpose: *mut wire_cst_list_localized_string,
         request_origin_base_url: *mut wire_cst_list_prim_u_8_strict,
         request_type: i32,
+        multiple_selection_indices: *mut wire_cst_list_prim_u_16_strict,
+        credential_sets: *mut wire_cst_list_disclosure_credential_set,
+        optional_attributes: *mut wire_cst_list_disclosure_attribute_identifier,
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
@@ -8268,6 +9519,13 @@ mod io {
     }
     #[repr(C)]
     #[derive(Clone, Copy)]
+    pub struct wire_cst_svg_template_properties {
+        orientation: *mut i32,
+        color_scheme: *mut i32,
+        contrast: *mut i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
     pub struct wire_cst_validity_status {
         tag: i32,
         kind: ValidityStatusKind,
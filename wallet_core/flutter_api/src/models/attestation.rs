@@ -1,11 +1,12 @@
 use chrono::Utc;
-use svg_sanitize::SanitizedSvg;
+use flutter_rust_bridge::frb;
 use tracing::warn;
 use wallet::attestation_data;
 use wallet::sd_jwt_vc_metadata::LogoMetadata;
 
 use crate::models::image::Image;
 use crate::models::image::ImageWithMetadata;
+use crate::models::image::SanitizedSvg;
 use crate::models::organization::Organization;
 use crate::models::revocation::RevocationStatus;
 
@@ -18,6 +19,7 @@ pub struct AttestationPresentation {
     pub revocation_status: Option<RevocationStatus>,
     pub validity_status: ValidityStatus,
     pub attributes: Vec<AttestationAttribute>,
+    pub svg_templates: Vec<RenderedSvgTemplate>,
 }
 
 impl From<wallet::AttestationPresentation> for AttestationPresentation {
@@ -31,6 +33,7 @@ impl From<wallet::AttestationPresentation> for AttestationPresentation {
             revocation_status: value.validity.revocation_status.map(Into::into),
             validity_status: wallet::ValidityStatus::from_window(&value.validity.validity_window, Utc::now()).into(),
             attributes: value.attributes.into_iter().map(AttestationAttribute::from).collect(),
+            svg_templates: value.svg_templates.into_iter().map(RenderedSvgTemplate::from).collect(),
         }
     }
 }
@@ -142,7 +145,83 @@ impl From<wallet::sd_jwt_vc_metadata::RenderingMetadata> for RenderingMetadata {
                 background_color,
                 text_color,
             },
-            wallet::sd_jwt_vc_metadata::RenderingMetadata::SvgTemplates => RenderingMetadata::SvgTemplates,
+            wallet::sd_jwt_vc_metadata::RenderingMetadata::SvgTemplates(_) => RenderingMetadata::SvgTemplates,
+        }
+    }
+}
+
+/// An SVG template from the display metadata, rendered using the attribute values of the attestation.
+#[frb(non_opaque)]
+pub struct RenderedSvgTemplate {
+    pub locale: String,
+    pub properties: Option<SvgTemplateProperties>,
+    pub svg: SanitizedSvg,
+}
+
+impl From<wallet::RenderedSvgTemplate> for RenderedSvgTemplate {
+    fn from(value: wallet::RenderedSvgTemplate) -> Self {
+        Self {
+            locale: value.locale,
+            properties: value.properties.map(SvgTemplateProperties::from),
+            svg: value.svg.into(),
+        }
+    }
+}
+
+pub struct SvgTemplateProperties {
+    pub orientation: Option<SvgTemplateOrientation>,
+    pub color_scheme: Option<SvgTemplateColorScheme>,
+    pub contrast: Option<SvgTemplateContrast>,
+}
+
+impl From<wallet::sd_jwt_vc_metadata::SvgTemplateProperties> for SvgTemplateProperties {
+    fn from(value: wallet::sd_jwt_vc_metadata::SvgTemplateProperties) -> Self {
+        Self {
+            orientation: value.orientation.map(SvgTemplateOrientation::from),
+            color_scheme: value.color_scheme.map(SvgTemplateColorScheme::from),
+            contrast: value.contrast.map(SvgTemplateContrast::from),
+        }
+    }
+}
+
+pub enum SvgTemplateOrientation {
+    Portrait,
+    Landscape,
+}
+
+impl From<wallet::sd_jwt_vc_metadata::SvgTemplateOrientation> for SvgTemplateOrientation {
+    fn from(value: wallet::sd_jwt_vc_metadata::SvgTemplateOrientation) -> Self {
+        match value {
+            wallet::sd_jwt_vc_metadata::SvgTemplateOrientation::Portrait => Self::Portrait,
+            wallet::sd_jwt_vc_metadata::SvgTemplateOrientation::Landscape => Self::Landscape,
+        }
+    }
+}
+
+pub enum SvgTemplateColorScheme {
+    Light,
+    Dark,
+}
+
+impl From<wallet::sd_jwt_vc_metadata::SvgTemplateColorScheme> for SvgTemplateColorScheme {
+    fn from(value: wallet::sd_jwt_vc_metadata::SvgTemplateColorScheme) -> Self {
+        match value {
+            wallet::sd_jwt_vc_metadata::SvgTemplateColorScheme::Light => Self::Light,
+            wallet::sd_jwt_vc_metadata::SvgTemplateColorScheme::Dark => Self::Dark,
+        }
+    }
+}
+
+pub enum SvgTemplateContrast {
+    Normal,
+    High,
+}
+
+impl From<wallet::sd_jwt_vc_metadata::SvgTemplateContrast> for SvgTemplateContrast {
+    fn from(value: wallet::sd_jwt_vc_metadata::SvgTemplateContrast) -> Self {
+        match value {
+            wallet::sd_jwt_vc_metadata::SvgTemplateContrast::Normal => Self::Normal,
+            wallet::sd_jwt_vc_metadata::SvgTemplateContrast::High => Self::High,
         }
     }
 }
@@ -156,7 +235,7 @@ impl TryFrom<LogoMetadata> for ImageWithMetadata {
                 wallet::attestation_types::Image::Jpeg(data) => Image::Jpeg { data },
                 wallet::attestation_types::Image::Png(data) => Image::Png { data },
                 wallet::attestation_types::Image::Svg(xml) => Image::Svg {
-                    svg: SanitizedSvg::try_new(&xml)?.into(),
+                    svg: svg_sanitize::SanitizedSvg::try_new(&xml)?.into(),
                 },
             },
             alt_text: value.alt_text.into_inner(),
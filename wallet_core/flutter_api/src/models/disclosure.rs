@@ -6,6 +6,7 @@ use wallet::DisclosureAttestationOptions;
 use wallet::DisclosureProposalPresentation;
 use wallet::errors::DisclosureError;
 use wallet::openid4vc::SessionType;
+use wallet::utils::VecNonEmpty;
 
 use super::attestation::AttestationPresentation;
 use super::instruction::WalletInstructionError;
@@ -75,6 +76,11 @@ pub enum StartDisclosureResult {
         request_purpose: Vec<LocalizedString>,
         request_origin_base_url: String,
         request_type: DisclosureType,
+        /// The indices of the disclosure options for which more than one attestation may be selected.
+        multiple_selection_indices: Vec<u16>,
+        credential_sets: Vec<DisclosureCredentialSet>,
+        /// The attributes that are not required by the relying party, which the user may de-select.
+        optional_attributes: Vec<DisclosureAttributeIdentifier>,
     },
     RequestAttributesMissing {
         relying_party: Organization,
@@ -88,6 +94,18 @@ pub enum StartDisclosureResult {
 
 pub struct DisclosureOptions(pub Vec<AttestationPresentation>);
 
+pub struct DisclosureCredentialSet {
+    pub required: bool,
+    pub options: Vec<Vec<u16>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DisclosureAttributeIdentifier {
+    pub attestation_option_index: u16,
+    pub attestation_index: u16,
+    pub attribute_key: Vec<String>,
+}
+
 pub enum AcceptDisclosureResult {
     Ok { return_url: Option<String> },
     InstructionError { error: WalletInstructionError },
@@ -152,10 +170,67 @@ impl From<DisclosureProposalPresentation> for StartDisclosureResult {
             // TODO PVW-5866 Replace with fields from registration certificate
             request_origin_base_url: "https://example.com".into(),
             request_type: proposal.disclosure_type.into(),
+            multiple_selection_indices: proposal
+                .multiple_selection_indices
+                .into_iter()
+                .map(disclosure_index)
+                .sorted()
+                .collect(),
+            credential_sets: proposal
+                .credential_sets
+                .into_iter()
+                .map(DisclosureCredentialSet::from)
+                .collect(),
+            optional_attributes: proposal
+                .optional_attributes
+                .into_iter()
+                .map(DisclosureAttributeIdentifier::from)
+                .sorted()
+                .collect(),
+        }
+    }
+}
+
+/// Converts an index into the disclosure proposal, which only contains a limited number of attestations.
+fn disclosure_index(index: usize) -> u16 {
+    u16::try_from(index).expect("disclosure proposal index should fit in u16")
+}
+
+impl From<wallet::DisclosureCredentialSet> for DisclosureCredentialSet {
+    fn from(value: wallet::DisclosureCredentialSet) -> Self {
+        Self {
+            required: value.required,
+            options: value
+                .options
+                .into_iter()
+                .map(|option| option.into_iter().map(disclosure_index).collect())
+                .collect(),
         }
     }
 }
 
+impl From<wallet::DisclosureAttributeIdentifier> for DisclosureAttributeIdentifier {
+    fn from(value: wallet::DisclosureAttributeIdentifier) -> Self {
+        Self {
+            attestation_option_index: disclosure_index(value.attestation_option_index),
+            attestation_index: disclosure_index(value.attestation_index),
+            attribute_key: value.attribute_key.into_inner(),
+        }
+    }
+}
+
+impl TryFrom<DisclosureAttributeIdentifier> for wallet::DisclosureAttributeIdentifier {
+    type Error = anyhow::Error;
+
+    fn try_from(value: DisclosureAttributeIdentifier) -> Result<Self, Self::Error> {
+        Ok(Self {
+            attestation_option_index: value.attestation_option_index.into(),
+            attestation_index: value.attestation_index.into(),
+            attribute_key: VecNonEmpty::try_from(value.attribute_key)?,
+        })
+    }
+}
+
 impl From<AttributesNotAvailable> for StartDisclosureResult {
     fn from(value: AttributesNotAvailable) -> Self {
         // TODO (PVW-4525): Have the UI actually display these as requested attributes,
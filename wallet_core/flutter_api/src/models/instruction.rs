@@ -17,6 +17,11 @@ pub enum WalletInstructionResult {
     InstructionError { error: WalletInstructionError },
 }
 
+pub enum AcceptIssuanceResult {
+    Ok { is_deferred: bool },
+    InstructionError { error: WalletInstructionError },
+}
+
 pub enum PidIssuanceResult {
     Ok { transfer_available: bool },
     InstructionError { error: WalletInstructionError },
@@ -88,18 +93,19 @@ impl TryFrom<Result<(), WalletUnlockError>> for WalletInstructionResult {
 
 /// This conversion distinguishes between 3 distinct cases:
 ///
-/// 1. In case of a successful result, [`WalletInstructionResult::Ok`] will be returned.
-/// 2. In case of an expected and/or specific error case a different variant of [`WalletInstructionResult`] by mapping
-///    the nested [`InstructionError`].
+/// 1. In case of a successful result, [`AcceptIssuanceResult::Ok`] will be returned, indicating whether the issuer has
+///    deferred issuance.
+/// 2. In case of an expected and/or specific error case a different variant of [`AcceptIssuanceResult`] by mapping the
+///    nested [`InstructionError`].
 /// 3. In any other cases, this is an unexpected and/or generic error and the [`IssuanceError`] will be returned
 ///    unchanged.
-impl TryFrom<Result<IssuanceResult, IssuanceError>> for WalletInstructionResult {
+impl TryFrom<Result<IssuanceResult, IssuanceError>> for AcceptIssuanceResult {
     type Error = IssuanceError;
 
     fn try_from(value: Result<IssuanceResult, IssuanceError>) -> Result<Self, Self::Error> {
         match value {
-            Ok(_) => Ok(WalletInstructionResult::Ok),
-            Err(IssuanceError::Instruction(instruction_error)) => Ok(WalletInstructionResult::InstructionError {
+            Ok(IssuanceResult { is_deferred, .. }) => Ok(AcceptIssuanceResult::Ok { is_deferred }),
+            Err(IssuanceError::Instruction(instruction_error)) => Ok(AcceptIssuanceResult::InstructionError {
                 error: instruction_error.try_into().map_err(IssuanceError::Instruction)?,
             }),
             Err(error) => Err(error),
@@ -144,7 +150,9 @@ impl TryFrom<Result<IssuanceResult, IssuanceError>> for PidIssuanceResult {
 
     fn try_from(value: Result<IssuanceResult, IssuanceError>) -> Result<Self, Self::Error> {
         match value {
-            Ok(IssuanceResult { transfer_session_id }) => Ok(PidIssuanceResult::Ok {
+            Ok(IssuanceResult {
+                transfer_session_id, ..
+            }) => Ok(PidIssuanceResult::Ok {
                 transfer_available: transfer_session_id.is_some(),
             }),
             Err(IssuanceError::Instruction(instruction_error)) => Ok(PidIssuanceResult::InstructionError {
@@ -1,6 +1,7 @@
 pub enum RevocationStatus {
     Valid,
     Revoked,
+    Suspended,
     Undetermined,
     Corrupted,
 }
@@ -10,6 +11,7 @@ impl From<wallet::RevocationStatus> for RevocationStatus {
         match value {
             wallet::RevocationStatus::Valid => RevocationStatus::Valid,
             wallet::RevocationStatus::Revoked => RevocationStatus::Revoked,
+            wallet::RevocationStatus::Suspended => RevocationStatus::Suspended,
             wallet::RevocationStatus::Undetermined => RevocationStatus::Undetermined,
             wallet::RevocationStatus::Corrupted => RevocationStatus::Corrupted,
         }
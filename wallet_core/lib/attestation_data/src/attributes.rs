@@ -1,7 +1,7 @@
-use std::collections::HashSet;
 use std::num::TryFromIntError;
 
 use attestation_types::claim_path::ClaimPath;
+use dcql::ClaimsQueryValue;
 use derive_more::AsRef;
 use derive_more::Display;
 use derive_more::From;
@@ -134,6 +134,18 @@ impl TryFrom<ClaimValue> for AttributeValue {
     }
 }
 
+/// An attribute value matches a DCQL claim value only if both its type and its value are the same.
+impl PartialEq<ClaimsQueryValue> for AttributeValue {
+    fn eq(&self, other: &ClaimsQueryValue) -> bool {
+        match (self, other) {
+            (AttributeValue::Integer(number), ClaimsQueryValue::Integer(other)) => number == other,
+            (AttributeValue::Bool(boolean), ClaimsQueryValue::Boolean(other)) => boolean == other,
+            (AttributeValue::Text(text), ClaimsQueryValue::String(other)) => text == other,
+            _ => false,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Attribute {
@@ -486,7 +498,9 @@ impl Attributes {
 
     /// Retrieve the attribute value at the specified location, if it exists.
     ///
-    /// NB: for now only all claim paths must be strings.
+    /// NB: the first element of the claim path must be a string. Elements of an array value can be selected by index,
+    /// while selecting all elements of an array is only supported at the end of the claim path, in which case the
+    /// array value itself is returned.
     pub fn get(
         &self,
         claim_paths: &VecNonEmpty<ClaimPath>,
@@ -500,27 +514,44 @@ impl Attributes {
             return Ok(None);
         };
 
-        // We already handled the first element above, so skip it here
-        for claim_path in &claim_paths[1..] {
-            let claim_path = claim_path
-                .try_key_path()
-                .ok_or(AttributesHandlingError::InvalidClaimPath)?;
+        // We already handled the first element above, so skip it here.
+        // Traverse the nested attributes by key until an attribute value is reached.
+        let mut remaining_claim_paths = &claim_paths[1..];
+        let mut value = loop {
+            let map = match attr {
+                Attribute::Single(value) => break value,
+                Attribute::Nested(map) => map,
+            };
 
-            attr = match attr {
-                Attribute::Single(_) => return Ok(None),
-                Attribute::Nested(map) => match map.get(claim_path) {
-                    Some(map) => map,
+            let Some((claim_path, rest)) = remaining_claim_paths.split_first() else {
+                return Ok(None);
+            };
+            remaining_claim_paths = rest;
+
+            attr = match claim_path.try_key_path().and_then(|key| map.get(key)) {
+                Some(attr) => attr,
+                None => return Ok(None),
+            };
+        };
+
+        // Any remaining claim path elements select elements of (nested) array values.
+        for (position, claim_path) in remaining_claim_paths.iter().enumerate() {
+            let is_last = position == remaining_claim_paths.len() - 1;
+
+            value = match (claim_path, value) {
+                (ClaimPath::SelectByIndex(index), AttributeValue::Array(values)) => match values.get(*index) {
+                    Some(value) => value,
                     None => return Ok(None),
                 },
+                (ClaimPath::SelectAll, AttributeValue::Array(_)) if is_last => value,
+                (ClaimPath::SelectAll, AttributeValue::Array(_)) => {
+                    return Err(AttributesHandlingError::InvalidClaimPath);
+                }
+                _ => return Ok(None),
             };
         }
 
-        let attr = match attr {
-            Attribute::Single(value) => value,
-            _ => return Ok(None),
-        };
-
-        Ok(Some(attr))
+        Ok(Some(value))
     }
 
     /// Check if the a value exists at all of the provided claim paths.
@@ -592,38 +623,62 @@ impl Attributes {
         }
     }
 
-    /// Prune attributes from a tree by only keeping those specified by a list of claim paths.
+    /// Prune attributes from a tree by only keeping those specified by a list of claim paths. Claim paths that select
+    /// elements of an array value, either by index or using a wildcard, only keep those elements of the array.
     pub fn prune<'a>(&mut self, keep_claim_paths: impl IntoIterator<Item = &'a VecNonEmpty<ClaimPath>>) {
-        fn nested_prune(
-            path_prefix: &[&str],
-            attributes: &mut IndexMap<String, Attribute>,
-            keep_claim_paths: &HashSet<Vec<&str>>,
-        ) -> bool {
+        fn nested_prune(attributes: &mut IndexMap<String, Attribute>, keep_claim_paths: &[&[ClaimPath]]) -> bool {
             attributes.retain(|path_element, attribute| {
-                let path = path_prefix
+                // Select the remainder of the claim paths that start with the key of this attribute.
+                let remaining_paths = keep_claim_paths
                     .iter()
-                    .copied()
-                    .chain(std::iter::once(path_element.as_str()))
+                    .filter_map(|path| match path.split_first() {
+                        Some((ClaimPath::SelectByKey(key), rest)) if key == path_element => Some(rest),
+                        _ => None,
+                    })
                     .collect_vec();
 
                 match attribute {
-                    Attribute::Single(_) => keep_claim_paths.contains(&path),
-                    Attribute::Nested(attributes) => nested_prune(&path, attributes, keep_claim_paths),
+                    Attribute::Single(value) => !remaining_paths.is_empty() && prune_value(value, &remaining_paths),
+                    Attribute::Nested(attributes) => nested_prune(attributes, &remaining_paths),
                 }
             });
 
             !attributes.is_empty()
         }
 
-        // Only key claim paths are supported for now, so if any path contains
-        // an element that is not a key, filter out the entire path.
-        let keep_claim_paths = keep_claim_paths
-            .into_iter()
-            .flat_map(|path| path.iter().map(ClaimPath::try_key_path).collect::<Option<Vec<_>>>())
-            .collect::<HashSet<_>>();
+        // Returns whether the value should be kept, after removing any array elements that are not selected.
+        fn prune_value(value: &mut AttributeValue, keep_claim_paths: &[&[ClaimPath]]) -> bool {
+            // A path that ends at this value selects it in its entirety.
+            if keep_claim_paths.iter().any(|path| path.is_empty()) {
+                return true;
+            }
+
+            let AttributeValue::Array(elements) = value else {
+                return false;
+            };
+
+            let mut index = 0;
+            elements.retain_mut(|element| {
+                let remaining_paths = keep_claim_paths
+                    .iter()
+                    .filter_map(|path| match path.split_first() {
+                        Some((ClaimPath::SelectAll, rest)) => Some(rest),
+                        Some((ClaimPath::SelectByIndex(path_index), rest)) if *path_index == index => Some(rest),
+                        _ => None,
+                    })
+                    .collect_vec();
+                index += 1;
+
+                !remaining_paths.is_empty() && prune_value(element, &remaining_paths)
+            });
+
+            !elements.is_empty()
+        }
+
+        let keep_claim_paths = keep_claim_paths.into_iter().map(|path| path.as_slice()).collect_vec();
 
         let Self(attributes) = self;
-        nested_prune(&[], attributes, &keep_claim_paths);
+        nested_prune(attributes, &keep_claim_paths);
     }
 }
 
@@ -731,6 +786,7 @@ pub mod test {
     use std::assert_matches;
 
     use attestation_types::claim_path::ClaimPath;
+    use dcql::ClaimsQueryValue;
     use indexmap::IndexMap;
     use mdoc::Entry;
     use mdoc::NameSpace;
@@ -790,6 +846,22 @@ pub mod test {
         ])
     }
 
+    #[rstest]
+    #[case(AttributeValue::Text("Doe".to_string()), ClaimsQueryValue::String("Doe".to_string()), true)]
+    #[case(AttributeValue::Text("Doe".to_string()), ClaimsQueryValue::String("Smith".to_string()), false)]
+    #[case(AttributeValue::Integer(42), ClaimsQueryValue::Integer(42), true)]
+    #[case(AttributeValue::Integer(42), ClaimsQueryValue::String("42".to_string()), false)]
+    #[case(AttributeValue::Bool(true), ClaimsQueryValue::Boolean(true), true)]
+    #[case(AttributeValue::Bool(true), ClaimsQueryValue::Integer(1), false)]
+    #[case(AttributeValue::Null, ClaimsQueryValue::Boolean(false), false)]
+    fn test_attribute_value_eq_claims_query_value(
+        #[case] attribute_value: AttributeValue,
+        #[case] claims_query_value: ClaimsQueryValue,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(attribute_value == claims_query_value, expected);
+    }
+
     #[test]
     fn test_traverse_groups() {
         let metadata_json = json!({
@@ -1089,6 +1161,164 @@ pub mod test {
         }
     }
 
+    fn setup_array_attributes() -> Attributes {
+        IndexMap::from_iter(vec![
+            (
+                "nationalities".to_string(),
+                Attribute::Single(AttributeValue::Array(vec![
+                    AttributeValue::Text("NL".to_string()),
+                    AttributeValue::Text("DE".to_string()),
+                ])),
+            ),
+            (
+                "address".to_string(),
+                Attribute::Nested(IndexMap::from_iter(vec![
+                    (
+                        "lines".to_string(),
+                        Attribute::Single(AttributeValue::Array(vec![
+                            AttributeValue::Text("Main St. 1".to_string()),
+                            AttributeValue::Text("The Capital".to_string()),
+                        ])),
+                    ),
+                    (
+                        "country".to_string(),
+                        Attribute::Single(AttributeValue::Text("NL".to_string())),
+                    ),
+                ])),
+            ),
+            (
+                "codes".to_string(),
+                Attribute::Single(AttributeValue::Array(vec![AttributeValue::Array(vec![
+                    AttributeValue::Integer(1),
+                    AttributeValue::Integer(2),
+                ])])),
+            ),
+        ])
+        .into()
+    }
+
+    #[rstest]
+    #[case(
+        vec_nonempty![ClaimPath::SelectByKey("nationalities".to_string()), ClaimPath::SelectAll],
+        Ok(Some(AttributeValue::Array(vec![
+            AttributeValue::Text("NL".to_string()),
+            AttributeValue::Text("DE".to_string()),
+        ])))
+    )]
+    #[case(
+        vec_nonempty![ClaimPath::SelectByKey("nationalities".to_string()), ClaimPath::SelectByIndex(1)],
+        Ok(Some(AttributeValue::Text("DE".to_string())))
+    )]
+    #[case(
+        vec_nonempty![ClaimPath::SelectByKey("nationalities".to_string()), ClaimPath::SelectByIndex(2)],
+        Ok(None)
+    )]
+    #[case(
+        vec_nonempty![
+            ClaimPath::SelectByKey("nationalities".to_string()),
+            ClaimPath::SelectByIndex(0),
+            ClaimPath::SelectByIndex(0)
+        ],
+        Ok(None)
+    )]
+    #[case(
+        vec_nonempty![
+            ClaimPath::SelectByKey("address".to_string()),
+            ClaimPath::SelectByKey("lines".to_string()),
+            ClaimPath::SelectByIndex(0)
+        ],
+        Ok(Some(AttributeValue::Text("Main St. 1".to_string())))
+    )]
+    #[case(
+        vec_nonempty![ClaimPath::SelectByKey("address".to_string()), ClaimPath::SelectByIndex(0)],
+        Ok(None)
+    )]
+    #[case(
+        vec_nonempty![
+            ClaimPath::SelectByKey("address".to_string()),
+            ClaimPath::SelectByKey("country".to_string()),
+            ClaimPath::SelectAll
+        ],
+        Ok(None)
+    )]
+    #[case(
+        vec_nonempty![
+            ClaimPath::SelectByKey("codes".to_string()),
+            ClaimPath::SelectByIndex(0),
+            ClaimPath::SelectByIndex(1)
+        ],
+        Ok(Some(AttributeValue::Integer(2)))
+    )]
+    #[case(
+        vec_nonempty![
+            ClaimPath::SelectByKey("codes".to_string()),
+            ClaimPath::SelectAll,
+            ClaimPath::SelectByIndex(1)
+        ],
+        Err(AttributesHandlingError::InvalidClaimPath)
+    )]
+    fn test_attributes_get_array_elements(
+        #[case] claim_paths: VecNonEmpty<ClaimPath>,
+        #[case] expected: Result<Option<AttributeValue>, AttributesHandlingError>,
+    ) {
+        let attributes = setup_array_attributes();
+
+        assert_eq!(attributes.get(&claim_paths).map(|value| value.cloned()), expected);
+    }
+
+    #[rstest]
+    #[case(
+        vec_nonempty![
+            ClaimPath::SelectByKey("address".to_string()),
+            ClaimPath::SelectByKey("lines".to_string()),
+            ClaimPath::SelectByIndex(1)
+        ],
+        json!({
+            "address": {
+                "lines": ["The Capital"]
+            }
+        })
+    )]
+    #[case(
+        vec_nonempty![ClaimPath::SelectByKey("nationalities".to_string()), ClaimPath::SelectAll],
+        json!({
+            "nationalities": ["NL", "DE"]
+        })
+    )]
+    #[case(
+        vec_nonempty![ClaimPath::SelectByKey("nationalities".to_string())],
+        json!({
+            "nationalities": ["NL", "DE"]
+        })
+    )]
+    #[case(
+        vec_nonempty![ClaimPath::SelectByKey("nationalities".to_string()), ClaimPath::SelectByIndex(2)],
+        json!({})
+    )]
+    #[case(
+        vec_nonempty![
+            ClaimPath::SelectByKey("codes".to_string()),
+            ClaimPath::SelectAll,
+            ClaimPath::SelectByIndex(1)
+        ],
+        json!({
+            "codes": [[2]]
+        })
+    )]
+    #[case(
+        vec_nonempty![ClaimPath::SelectByKey("address".to_string()), ClaimPath::SelectByKey("country".to_string()), ClaimPath::SelectAll],
+        json!({})
+    )]
+    fn test_attributes_prune_array_elements(
+        #[case] keep_claim_path: VecNonEmpty<ClaimPath>,
+        #[case] expected_json: serde_json::Value,
+    ) {
+        let mut attributes = setup_array_attributes();
+        attributes.prune(&[keep_claim_path]);
+
+        assert_eq!(serde_json::to_value(attributes).unwrap(), expected_json);
+    }
+
     #[rstest]
     #[case(
         vec![ClaimPath::SelectByKey("foo".to_string())],
@@ -6,19 +6,23 @@ use attestation_types::credential_kind::CredentialKind;
 use attestation_types::qualification::AttestationQualification;
 use crypto::x509::CertificateError;
 use crypto::x509::KeyIdentifier;
+use dcql::ClaimsQueryValue;
 use dcql::CredentialQueryIdentifier;
 use dcql::disclosure::DisclosedCredential;
+use dcql::normalized::ClaimRequest;
 use dcql::normalized::NormalizedCredentialRequest;
 use dcql::unique_id_vec::MayHaveUniqueId;
 use http_utils::urls::HttpsUri;
 use indexmap::IndexMap;
 use itertools::Itertools;
+use jwt::headers::HeaderWithKid;
 use mdoc::DataElementIdentifier;
 use mdoc::DataElementValue;
 use mdoc::ItemsRequest;
 use mdoc::NameSpace;
 use mdoc::holder::disclosure::claim_path_to_mdoc_path;
 use mdoc::verifier::DisclosedDocument;
+use sd_jwt::sd_jwt::SdJwtVcClaims;
 use sd_jwt::sd_jwt::VerifiedSdJwtPresentation;
 use serde::Deserialize;
 use serde::Serialize;
@@ -55,6 +59,20 @@ impl DisclosedAttributes {
         }
     }
 
+    /// Retrieve the attribute value at the specified claim path, if it exists.
+    pub fn get(&self, claim_path: &VecNonEmpty<ClaimPath>) -> Option<&AttributeValue> {
+        match self {
+            Self::MsoMdoc(name_spaces) => {
+                claim_path_to_mdoc_path(claim_path).and_then(|(name_space_id, attribute_id)| {
+                    name_spaces
+                        .get(name_space_id)
+                        .and_then(|name_space| name_space.get(attribute_id))
+                })
+            }
+            Self::SdJwt(attributes) => attributes.get(claim_path).ok().flatten(),
+        }
+    }
+
     /// Only keep the attributes specified by a list of claim paths, removing any other other claims.
     pub fn prune<'a>(&mut self, keep_claim_paths: impl IntoIterator<Item = &'a VecNonEmpty<ClaimPath>>) {
         match self {
@@ -98,8 +116,8 @@ impl TryFrom<IndexMap<NameSpace, IndexMap<DataElementIdentifier, DataElementValu
     }
 }
 
-/// Attestation that was disclosed; consisting of attributes, validity information, issuer URI and the issuer CA's
-/// common name.
+/// Attestation that was disclosed; consisting of attributes, validity information, issuer URI and either the issuer
+/// CA's common name or the issuer identifier that the issuer key was resolved for.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct DisclosedAttestation {
@@ -109,8 +127,14 @@ pub struct DisclosedAttestation {
     pub issuer_uri: HttpsUri,
     pub attestation_qualification: AttestationQualification,
 
-    /// The issuer CA's common name
-    pub ca: String,
+    /// The issuer CA's common name, absent if the issuer key was resolved using JWT VC Issuer Metadata
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ca: Option<String>,
+
+    /// The issuer identifier for which the issuer key was resolved using JWT VC Issuer Metadata
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jwt_vc_issuer: Option<HttpsUri>,
+
     pub issuance_validity: IssuanceValidity,
     pub revocation_status: Option<RevocationStatus>,
 
@@ -141,7 +165,8 @@ impl TryFrom<DisclosedDocument> for DisclosedAttestation {
             attributes: doc.attributes.try_into()?,
             issuer_uri: doc.issuer_uri,
             attestation_qualification: doc.attestation_qualification,
-            ca: doc.ca,
+            ca: Some(doc.ca),
+            jwt_vc_issuer: None,
             issuance_validity: (&doc.validity_info).try_into()?,
             revocation_status: doc.revocation_status,
             aki: doc.aki,
@@ -153,7 +178,6 @@ impl TryFrom<VerifiedSdJwtPresentation> for DisclosedAttestation {
     type Error = DisclosedAttestationError;
 
     fn try_from(sd_jwt_presentation: VerifiedSdJwtPresentation) -> Result<Self, Self::Error> {
-        let attributes = DisclosedAttributes::SdJwt(sd_jwt_presentation.sd_jwt().decoded_claims()?.try_into()?);
         let ca = sd_jwt_presentation
             .issuer_leaf_certificate()
             .issuer_common_names()?
@@ -161,8 +185,6 @@ impl TryFrom<VerifiedSdJwtPresentation> for DisclosedAttestation {
             .ok_or(DisclosedAttestationError::EmptyIssuerCommonName)?
             .to_string();
 
-        let revocation_status = sd_jwt_presentation.revocation_status();
-
         let aki = sd_jwt_presentation
             .sd_jwt()
             .issuer_certificate_chain()
@@ -170,6 +192,33 @@ impl TryFrom<VerifiedSdJwtPresentation> for DisclosedAttestation {
             .filter_map(|cert| cert.authority_key_id())
             .collect_vec();
 
+        Self::from_sd_jwt_presentation(sd_jwt_presentation, Some(ca), None, aki)
+    }
+}
+
+impl TryFrom<VerifiedSdJwtPresentation<SdJwtVcClaims, HeaderWithKid>> for DisclosedAttestation {
+    type Error = DisclosedAttestationError;
+
+    fn try_from(
+        sd_jwt_presentation: VerifiedSdJwtPresentation<SdJwtVcClaims, HeaderWithKid>,
+    ) -> Result<Self, Self::Error> {
+        // There is no issuer certificate when the issuer key was resolved using JWT VC Issuer Metadata
+        let jwt_vc_issuer = sd_jwt_presentation.sd_jwt().claims().iss.clone();
+
+        Self::from_sd_jwt_presentation(sd_jwt_presentation, None, Some(jwt_vc_issuer), Vec::new())
+    }
+}
+
+impl DisclosedAttestation {
+    fn from_sd_jwt_presentation<H>(
+        sd_jwt_presentation: VerifiedSdJwtPresentation<SdJwtVcClaims, H>,
+        ca: Option<String>,
+        jwt_vc_issuer: Option<HttpsUri>,
+        aki: Vec<KeyIdentifier>,
+    ) -> Result<Self, DisclosedAttestationError> {
+        let attributes = DisclosedAttributes::SdJwt(sd_jwt_presentation.sd_jwt().decoded_claims()?.try_into()?);
+        let revocation_status = sd_jwt_presentation.revocation_status();
+
         let claims = sd_jwt_presentation.into_claims();
 
         // Manually parse the attestation qualification from the SD-JWT claims.
@@ -189,6 +238,7 @@ impl TryFrom<VerifiedSdJwtPresentation> for DisclosedAttestation {
             issuer_uri: claims.iss,
             attestation_qualification,
             ca,
+            jwt_vc_issuer,
             issuance_validity,
             revocation_status,
             aki,
@@ -245,6 +295,12 @@ impl DisclosedCredential for DisclosedAttestation {
             .flat_map(|claim_path| (!self.attributes.has_claim_path(claim_path)).then(|| claim_path.clone()))
             .collect()
     }
+
+    fn matches_claim_values(&self, claim_path: &VecNonEmpty<ClaimPath>, values: &[ClaimsQueryValue]) -> bool {
+        self.attributes
+            .get(claim_path)
+            .is_some_and(|attribute_value| values.iter().any(|value| attribute_value == value))
+    }
 }
 
 pub trait AttestationRequest {
@@ -253,6 +309,9 @@ pub trait AttestationRequest {
     fn claim_paths(&self) -> impl Iterator<Item = VecNonEmpty<ClaimPath>>;
     fn aki(&self) -> &[KeyIdentifier];
 
+    /// The combinations of claims that satisfy the request, in order of preference.
+    fn claim_sets(&self) -> VecNonEmpty<Vec<ClaimRequest>>;
+
     /// The requested attestation types, each combined with the single format the request is made in.
     fn credential_kinds(&self) -> HashSet<CredentialKind> {
         let format = self.format();
@@ -282,6 +341,13 @@ impl AttestationRequest for NormalizedCredentialRequest {
     fn aki(&self) -> &[KeyIdentifier] {
         self.aki()
     }
+
+    fn claim_sets(&self) -> VecNonEmpty<Vec<ClaimRequest>> {
+        self.claim_sets()
+            .into_nonempty_iter()
+            .map(VecNonEmpty::into_inner)
+            .collect()
+    }
 }
 
 impl AttestationRequest for ItemsRequest {
@@ -300,6 +366,15 @@ impl AttestationRequest for ItemsRequest {
     fn aki(&self) -> &[KeyIdentifier] {
         &[]
     }
+
+    fn claim_sets(&self) -> VecNonEmpty<Vec<ClaimRequest>> {
+        // An mdoc `ItemsRequest` neither supports claim sets nor claim values.
+        vec_nonempty![
+            self.claims()
+                .map(|path| ClaimRequest { path, values: vec![] })
+                .collect()
+        ]
+    }
 }
 
 impl<T: AttestationRequest> AttestationRequest for &T {
@@ -318,6 +393,10 @@ impl<T: AttestationRequest> AttestationRequest for &T {
     fn aki(&self) -> &[KeyIdentifier] {
         (*self).aki()
     }
+
+    fn claim_sets(&self) -> VecNonEmpty<Vec<ClaimRequest>> {
+        (*self).claim_sets()
+    }
 }
 
 #[cfg(test)]
@@ -358,7 +437,8 @@ mod test {
                 )])),
                 issuer_uri: "https://example.com".parse().unwrap(),
                 attestation_qualification: AttestationQualification::default(),
-                ca: "Example CA".to_string(),
+                ca: Some("Example CA".to_string()),
+                jwt_vc_issuer: None,
                 issuance_validity: IssuanceValidity::new(Utc::now(), None, None),
                 revocation_status: Some(RevocationStatus::Valid),
                 aki: vec![],
@@ -383,7 +463,8 @@ mod test {
                 ),
                 issuer_uri: "https://example.com".parse().unwrap(),
                 attestation_qualification: AttestationQualification::default(),
-                ca: "Example CA".to_string(),
+                ca: Some("Example CA".to_string()),
+                jwt_vc_issuer: None,
                 issuance_validity: IssuanceValidity::new(Utc::now(), None, None),
                 revocation_status: Some(RevocationStatus::Valid),
                 aki: vec![],
@@ -451,6 +532,57 @@ mod test {
         };
     }
 
+    #[test]
+    fn test_disclosed_attributes_prune_array_elements() {
+        let lines = || {
+            AttributeValue::Array(vec![
+                AttributeValue::Text("Main St. 1".to_string()),
+                AttributeValue::Text("The Capital".to_string()),
+            ])
+        };
+        let lines_path = vec_nonempty![
+            ClaimPath::SelectByKey("address".to_string()),
+            ClaimPath::SelectByKey("lines".to_string())
+        ];
+        let first_line_path = vec_nonempty![
+            ClaimPath::SelectByKey("address".to_string()),
+            ClaimPath::SelectByKey("lines".to_string()),
+            ClaimPath::SelectByIndex(0)
+        ];
+
+        // For SD-JWT, only the selected array element should be kept.
+        let mut sd_jwt_attributes = DisclosedAttributes::SdJwt(
+            IndexMap::from([(
+                "address".to_string(),
+                Attribute::Nested(IndexMap::from([("lines".to_string(), Attribute::Single(lines()))])),
+            )])
+            .into(),
+        );
+        sd_jwt_attributes.prune(std::slice::from_ref(&first_line_path));
+
+        assert_eq!(
+            sd_jwt_attributes.get(&lines_path),
+            Some(&AttributeValue::Array(vec![AttributeValue::Text(
+                "Main St. 1".to_string()
+            )]))
+        );
+
+        // An mdoc data element can only be selected in its entirety, using a path consisting of a name space and an
+        // element identifier. A path that selects array elements does not select anything.
+        let mdoc_attributes = DisclosedAttributes::MsoMdoc(IndexMap::from([(
+            "address".to_string(),
+            IndexMap::from([("lines".to_string(), lines())]),
+        )]));
+
+        let mut pruned_attributes = mdoc_attributes.clone();
+        pruned_attributes.prune(std::slice::from_ref(&lines_path));
+        assert_eq!(pruned_attributes.get(&lines_path), Some(&lines()));
+
+        let mut pruned_attributes = mdoc_attributes;
+        pruned_attributes.prune(std::slice::from_ref(&first_line_path));
+        assert_eq!(pruned_attributes.get(&lines_path), None);
+    }
+
     #[rstest]
     #[case(json!([
         {
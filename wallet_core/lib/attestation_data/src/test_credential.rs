@@ -255,7 +255,9 @@ impl TestCredential {
         let claims = self
             .to_mdoc_claim_paths()
             .map(|path| MdocAttributeRequest {
+                id: None,
                 path,
+                values: vec![],
                 intent_to_retain: None,
             })
             .collect_vec()
@@ -265,7 +267,9 @@ impl TestCredential {
         NormalizedCredentialRequest::MsoMdoc {
             id: self.query_id.clone(),
             doctype_value: self.payload_preview.attestation_type.clone(),
+            multiple: false,
             claims,
+            claim_sets: vec![],
             aki: vec![],
         }
     }
@@ -275,7 +279,11 @@ impl TestCredential {
             .disclosure_attributes
             .claim_paths(AttributesTraversalBehaviour::OnlyLeaves)
             .into_iter()
-            .map(|path| SdJwtAttributeRequest { path })
+            .map(|path| SdJwtAttributeRequest {
+                id: None,
+                path,
+                values: vec![],
+            })
             .collect_vec()
             .try_into()
             .expect("TestCredential payload preview should have at least one attribute");
@@ -283,7 +291,9 @@ impl TestCredential {
         NormalizedCredentialRequest::SdJwt {
             id: self.query_id.clone(),
             vct_values: vec_nonempty![self.payload_preview.attestation_type.clone()],
+            multiple: false,
             claims,
+            claim_sets: vec![],
             aki: vec![],
         }
     }
@@ -31,6 +31,9 @@ use crate::serialization::cbor_serialize;
 /// COSE header label for `x5chain`, defined in RFC 9360.
 pub const COSE_X5CHAIN_HEADER_LABEL: i64 = 33;
 
+/// COSE `typ` header parameter, registered by RFC 9596.
+pub const COSE_TYPE_HEADER_LABEL: i64 = 16;
+
 pub fn header_with_x5chain(chain: &VecNonEmpty<&BorrowingCertificate>) -> Header {
     let encode_certificate = |certificate: &&BorrowingCertificate| Value::Bytes(certificate.to_vec());
 
@@ -95,7 +98,8 @@ impl<T> TypedCose<CoseSign1, T> {
             .into())
     }
 
-    pub(crate) async fn sign_with_protected_header(
+    /// Sign a COSE payload using the specified protected header, which should include the algorithm.
+    pub async fn sign_with_protected_header(
         payload: &T,
         protected_header: Header,
         unprotected_header: Header,
@@ -28,6 +28,7 @@ use serde::de::DeserializeOwned;
 use utils::generator::Generator;
 use utils::vec_at_least::VecNonEmpty;
 
+use crate::COSE_TYPE_HEADER_LABEL;
 use crate::COSE_X5CHAIN_HEADER_LABEL;
 use crate::CoseError;
 use crate::TypedCose;
@@ -41,8 +42,6 @@ pub const CWT_CLAIMS_HEADER_LABEL: i64 = 15;
 
 const COSE_ALGORITHM_HEADER_LABEL: i64 = 1;
 const COSE_CRITICAL_HEADER_LABEL: i64 = 2;
-/// COSE `typ` header parameter, registered by RFC 9596.
-const COSE_TYPE_HEADER_LABEL: i64 = 16;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct WrprcCwtHeader {
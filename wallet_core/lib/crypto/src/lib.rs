@@ -2,6 +2,7 @@ pub mod keys;
 pub mod p256_der;
 pub mod server_keys;
 pub mod trust_anchor;
+pub mod trusted_list;
 pub mod utils;
 pub mod wscd;
 pub mod x509;
@@ -78,6 +78,11 @@ impl<S: EcdsaKey> EcdsaKey for KeyPair<S> {
 
 #[cfg(any(test, feature = "generate"))]
 pub mod generate {
+    use der::Encode;
+    use der::Sequence;
+    use der::asn1::Ia5StringRef;
+    use der::asn1::ObjectIdentifier;
+    use itertools::Itertools;
     use p256::ecdsa::SigningKey;
     use p256::pkcs8::DecodePrivateKey;
     use rcgen::BasicConstraints;
@@ -110,6 +115,20 @@ pub mod generate {
     use crate::x509::CertificateUsage;
     use crate::x509::DistinguishedName;
 
+    /// OID of the `id-ad-ocsp` access method (RFC 5280, 4.2.2.1).
+    const OID_AD_OCSP: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.5.5.7.48.1");
+
+    /// OID of the Authority Information Access extension (RFC 5280, 4.2.2.1).
+    const OID_AUTHORITY_INFO_ACCESS: [u64; 9] = [1, 3, 6, 1, 5, 5, 7, 1, 1];
+
+    /// `AccessDescription` (RFC 5280, 4.2.2.1), restricted to an URI as access location.
+    #[derive(Sequence)]
+    struct AccessDescription<'a> {
+        access_method: ObjectIdentifier,
+        #[asn1(context_specific = "6", tag_mode = "IMPLICIT")]
+        access_location: Ia5StringRef<'a>,
+    }
+
     fn rcgen_cert_privkey(keypair: &rcgen::KeyPair) -> Result<SigningKey, CertificateError> {
         SigningKey::from_pkcs8_der(keypair.serialized_der())
             .map_err(|error| CertificateError::GeneratingPrivateKey(Box::new(error)))
@@ -229,11 +248,23 @@ pub mod generate {
             revoked_certs: Vec<RevokedCertParams>,
             this_update: OffsetDateTime,
             next_update: OffsetDateTime,
+        ) -> Result<CertificateRevocationList, CertificateError> {
+            self.generate_crl_with_number(revoked_certs, 1, this_update, next_update)
+        }
+
+        /// Generate a signed CRL from this CA, revoking the given certificates, with an explicit CRL number and
+        /// `thisUpdate`/`nextUpdate` fields. The CRL number should increase monotonically for each CRL issued.
+        pub fn generate_crl_with_number(
+            &self,
+            revoked_certs: Vec<RevokedCertParams>,
+            crl_number: u64,
+            this_update: OffsetDateTime,
+            next_update: OffsetDateTime,
         ) -> Result<CertificateRevocationList, CertificateError> {
             let params = CertificateRevocationListParams {
                 this_update,
                 next_update,
-                crl_number: SerialNumber::from(1u64),
+                crl_number: SerialNumber::from(crl_number),
                 issuing_distribution_point: None,
                 revoked_certs,
                 key_identifier_method: KeyIdMethod::Sha256,
@@ -354,6 +385,7 @@ pub mod generate {
                 result.not_after = OffsetDateTime::from_unix_timestamp_nanos(i128::from(not_after)).unwrap();
             }
             result.use_authority_key_identifier_extension = !source.exclude_aki;
+            result.serial_number = source.serial_number.map(SerialNumber::from);
             if let Some(usage) = source.usage {
                 result.extended_key_usages.push(usage.to_key_usage_purpose());
             }
@@ -367,6 +399,23 @@ pub mod generate {
                     uris: vec![uri.to_string()],
                 })
                 .collect();
+            if !source.ocsp_responders.is_empty() {
+                let access_descriptions = source
+                    .ocsp_responders
+                    .iter()
+                    .map(|url| AccessDescription {
+                        access_method: OID_AD_OCSP,
+                        access_location: Ia5StringRef::new(url.as_str()).expect("URL should only contain ASCII"),
+                    })
+                    .collect_vec();
+                let content = access_descriptions
+                    .to_der()
+                    .expect("authority information access should be encodable");
+                result.custom_extensions.push(rcgen::CustomExtension::from_oid_content(
+                    &OID_AUTHORITY_INFO_ACCESS,
+                    content,
+                ));
+            }
             result
         }
     }
@@ -437,6 +486,16 @@ pub mod generate {
                 self.generate_key_pair(RP_CERT_DN.clone(), Default::default(), NO_SAN)
             }
 
+            /// Generate a WRPAC verifier key pair with the given DNS name as the SAN, for use with the
+            /// `x509_san_dns` client_id prefix.
+            pub fn generate_wrpac_verifier_san_dns_mock(&self, dns_name: &str) -> Result<KeyPair, CertificateError> {
+                self.generate_key_pair(
+                    RP_CERT_DN.clone(),
+                    Default::default(),
+                    [SanType::DnsName(dns_name.try_into()?)],
+                )
+            }
+
             pub fn generate_pid_issuer_mock(&self) -> Result<KeyPair, CertificateError> {
                 self.generate_key_pair(
                     PID_ISSUER_CERT_DN.clone(),
@@ -128,6 +128,23 @@ impl TrustAnchors {
     pub fn contains(&self, certificate: &BorrowingCertificate) -> bool {
         self.certificates.contains(certificate)
     }
+
+    pub(crate) fn certificates(&self) -> impl Iterator<Item = &BorrowingCertificate> {
+        self.certificates.iter()
+    }
+
+    /// Returns these trust anchors combined with `trust_anchors`, e.g. as obtained from a trusted list.
+    pub fn extended(
+        self,
+        trust_anchors: impl IntoIterator<Item = BorrowingTrustAnchor>,
+    ) -> Result<Self, CertificateError> {
+        let mut certificates = self.certificates;
+        for trust_anchor in trust_anchors {
+            certificates.insert(BorrowingCertificate::from_der(trust_anchor.as_ref())?);
+        }
+
+        certificates.try_into()
+    }
 }
 
 impl PartialEq for TrustAnchors {
@@ -0,0 +1,680 @@
+//! Parsing and verification of trusted lists as specified in ETSI TS 119 612, which can be used as a source of trust
+//! anchors. A list of trusted lists (LoTL) is itself a trusted list that contains pointers to other trusted lists,
+//! including the certificates these are expected to be signed with.
+//!
+//! <https://www.etsi.org/deliver/etsi_ts/119600_119699/119612/02.03.01_60/ts_119612v020301p.pdf>
+
+mod xml;
+mod xmldsig;
+
+use std::time::Duration;
+
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use chrono::DateTime;
+use chrono::Utc;
+use http_utils::reqwest::bytes_with_max_response_size;
+use itertools::Itertools;
+use reqwest::Client;
+use tracing::warn;
+use url::Url;
+use utils::generator::Generator;
+
+use self::xml::Document;
+use self::xml::Element;
+pub use self::xml::XmlError;
+pub use self::xmldsig::XmlSignatureError;
+use self::xmldsig::verify_enveloped_signature;
+use crate::trust_anchor::BorrowingTrustAnchor;
+
+const TSL_NAMESPACE: &str = "http://uri.etsi.org/02231/v2#";
+const TSL_ADDITIONAL_TYPES_NAMESPACE: &str = "http://uri.etsi.org/02231/v2/additionaltypes#";
+
+/// The MIME type of an XML trusted list, as used in pointers to other trusted lists. Pointers with other MIME types,
+/// i.e. to human readable PDF versions, are not followed.
+pub const TSL_XML_MIME_TYPE: &str = "application/vnd.etsi.tsl+xml";
+
+/// Service type identifier of a certification authority issuing qualified certificates.
+pub const SERVICE_TYPE_CA_QC: &str = "http://uri.etsi.org/TrstSvc/Svctype/CA/QC";
+
+/// Service type identifier of a certification authority issuing non-qualified public key certificates.
+pub const SERVICE_TYPE_CA_PKC: &str = "http://uri.etsi.org/TrstSvc/Svctype/CA/PKC";
+
+/// Status of a service that is currently approved by the scheme operator. Only certificates of services with this
+/// status are used as trust anchors.
+pub const SERVICE_STATUS_GRANTED: &str = "http://uri.etsi.org/TrstSvc/TrustedList/Svcstatus/granted";
+
+/// Upper bound on the size of a single trusted list. National trusted lists are typically a few hundred KB, with the
+/// largest ones being a few MB, so this leaves ample headroom while still bounding memory use.
+const MAX_TRUSTED_LIST_SIZE: usize = 20 * 1024 * 1024;
+
+/// Timeout for a single trusted list fetch request.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, thiserror::Error)]
+pub enum TrustedListError {
+    #[error("could not parse trusted list XML: {0}")]
+    Xml(#[from] XmlError),
+    #[error("could not verify trusted list signature: {0}")]
+    Signature(#[from] XmlSignatureError),
+    #[error("trusted list is not signed by any of the expected certificates")]
+    UntrustedSigner,
+    #[error("unexpected root element, expected TrustServiceStatusList")]
+    UnexpectedRootElement,
+    #[error("missing trusted list element: {0}")]
+    MissingElement(&'static str),
+    #[error("invalid value for trusted list element {0}: {1}")]
+    InvalidValue(&'static str, String),
+    #[error("could not decode base64 certificate: {0}")]
+    Base64(#[from] base64::DecodeError),
+    #[error("trusted list expired at {0}")]
+    Expired(DateTime<Utc>),
+    #[error("could not convert certificate to trust anchor: {0}")]
+    TrustAnchor(#[source] webpki::Error),
+    #[error("HTTP error fetching trusted list: {0}")]
+    Http(#[source] reqwest::Error),
+    #[error("trusted list response exceeds maximum size of {MAX_TRUSTED_LIST_SIZE} bytes")]
+    TooLarge,
+    #[error("could not read trusted list file: {0}")]
+    Io(#[source] std::io::Error),
+    #[error("invalid trusted list file URL: {0}")]
+    InvalidFileUrl(Url),
+    #[error("trusted list is not valid UTF-8: {0}")]
+    Utf8(#[source] std::string::FromUtf8Error),
+    #[error("remote trusted list points to local file: {0}")]
+    LocalPointer(Url),
+    #[error("could not fetch or verify pointed to trusted lists: {}", .0.iter().join(", "))]
+    FailedPointers(Vec<Url>),
+}
+
+/// A parsed trusted list, containing the information from the list that is relevant for deriving trust anchors.
+#[derive(Debug, Clone)]
+pub struct TrustedList {
+    sequence_number: u64,
+    next_update: Option<DateTime<Utc>>,
+    pointers: Vec<OtherTslPointer>,
+    services: Vec<TrustService>,
+}
+
+/// A pointer to another trusted list, as contained in a list of trusted lists.
+#[derive(Debug, Clone)]
+pub struct OtherTslPointer {
+    pub location: Url,
+    pub mime_type: Option<String>,
+    /// DER encoded certificates, one of which the trusted list at `location` is expected to be signed with.
+    pub signing_certificates: Vec<Vec<u8>>,
+}
+
+/// A trusted list along with the trusted lists it points to, as fetched by [`TrustedListFetcher::fetch_with_pointers`].
+#[derive(Debug)]
+pub struct FetchedTrustedLists {
+    /// The fetched trusted list itself, followed by the pointed to lists that could be fetched and verified.
+    pub trusted_lists: Vec<TrustedList>,
+    /// The locations of the pointed to lists that could not be fetched or verified, along with the reason.
+    pub failed_pointers: Vec<(Url, TrustedListError)>,
+}
+
+/// A single service of a trust service provider in a trusted list.
+#[derive(Debug, Clone)]
+pub struct TrustService {
+    pub service_type: String,
+    pub status: String,
+    /// DER encoded certificates that identify the service.
+    pub certificates: Vec<Vec<u8>>,
+}
+
+impl TrustedList {
+    /// Parse a trusted list and verify its enveloped XML signature, which should be created using one of
+    /// `signing_certificates`. The list is rejected if its next update is in the past.
+    pub fn parse_and_verify(
+        xml: &str,
+        signing_certificates: &[impl AsRef<[u8]>],
+        time: &impl Generator<DateTime<Utc>>,
+    ) -> Result<Self, TrustedListError> {
+        let document = Document::parse(xml)?;
+
+        let signer = verify_enveloped_signature(&document)?;
+        if !signing_certificates
+            .iter()
+            .any(|certificate| certificate.as_ref() == signer.as_slice())
+        {
+            return Err(TrustedListError::UntrustedSigner);
+        }
+
+        let trusted_list = Self::from_document(&document)?;
+        if let Some(next_update) = trusted_list.next_update
+            && next_update < time.generate()
+        {
+            return Err(TrustedListError::Expired(next_update));
+        }
+
+        Ok(trusted_list)
+    }
+
+    /// Parse a trusted list without verifying its signature.
+    pub fn dangerous_parse_unverified(xml: &str) -> Result<Self, TrustedListError> {
+        Self::from_document(&Document::parse(xml)?)
+    }
+
+    fn from_document(document: &Document) -> Result<Self, TrustedListError> {
+        let root = document.root();
+        if !root.is(TSL_NAMESPACE, "TrustServiceStatusList") {
+            return Err(TrustedListError::UnexpectedRootElement);
+        }
+
+        let scheme_information = required_child(root, "SchemeInformation")?;
+
+        let sequence_number = required_child(scheme_information, "TSLSequenceNumber")?.text();
+        let sequence_number = sequence_number
+            .parse()
+            .map_err(|_| TrustedListError::InvalidValue("TSLSequenceNumber", sequence_number))?;
+
+        // A list without a next update date has been closed, in which case the next update is absent.
+        let next_update = scheme_information
+            .path(TSL_NAMESPACE, &["NextUpdate", "dateTime"])
+            .map(|date_time| {
+                let date_time = date_time.text();
+                DateTime::parse_from_rfc3339(&date_time)
+                    .map(|date_time| date_time.to_utc())
+                    .map_err(|_| TrustedListError::InvalidValue("NextUpdate", date_time))
+            })
+            .transpose()?;
+
+        let pointers = scheme_information
+            .child(TSL_NAMESPACE, "PointersToOtherTSL")
+            .into_iter()
+            .flat_map(|pointers| pointers.children_named(TSL_NAMESPACE, "OtherTSLPointer"))
+            .map(OtherTslPointer::from_element)
+            .try_collect()?;
+
+        let services = root
+            .child(TSL_NAMESPACE, "TrustServiceProviderList")
+            .into_iter()
+            .flat_map(|providers| providers.children_named(TSL_NAMESPACE, "TrustServiceProvider"))
+            .filter_map(|provider| provider.child(TSL_NAMESPACE, "TSPServices"))
+            .flat_map(|services| services.children_named(TSL_NAMESPACE, "TSPService"))
+            .map(TrustService::from_element)
+            .try_collect()?;
+
+        Ok(Self {
+            sequence_number,
+            next_update,
+            pointers,
+            services,
+        })
+    }
+
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+
+    pub fn next_update(&self) -> Option<DateTime<Utc>> {
+        self.next_update
+    }
+
+    pub fn pointers(&self) -> &[OtherTslPointer] {
+        &self.pointers
+    }
+
+    pub fn services(&self) -> &[TrustService] {
+        &self.services
+    }
+
+    /// Returns the certificates of all granted services of the specified type as trust anchors.
+    pub fn trust_anchors(&self, service_type: &str) -> Result<Vec<BorrowingTrustAnchor>, TrustedListError> {
+        self.services
+            .iter()
+            .filter(|service| service.service_type == service_type && service.status == SERVICE_STATUS_GRANTED)
+            .flat_map(|service| &service.certificates)
+            .map(|certificate| BorrowingTrustAnchor::from_der(certificate.as_slice()))
+            .try_collect()
+            .map_err(TrustedListError::TrustAnchor)
+    }
+}
+
+impl OtherTslPointer {
+    fn from_element(element: &Element) -> Result<Self, TrustedListError> {
+        let location = required_child(element, "TSLLocation")?.text();
+        let location = location
+            .parse()
+            .map_err(|_| TrustedListError::InvalidValue("TSLLocation", location))?;
+
+        let mime_type = element
+            .child(TSL_NAMESPACE, "AdditionalInformation")
+            .into_iter()
+            .flat_map(|information| information.children_named(TSL_NAMESPACE, "OtherInformation"))
+            .find_map(|information| information.child(TSL_ADDITIONAL_TYPES_NAMESPACE, "MimeType"))
+            .map(Element::text);
+
+        let signing_certificates = element
+            .child(TSL_NAMESPACE, "ServiceDigitalIdentities")
+            .into_iter()
+            .flat_map(|identities| identities.children_named(TSL_NAMESPACE, "ServiceDigitalIdentity"))
+            .flat_map(digital_identity_certificates)
+            .try_collect()?;
+
+        Ok(Self {
+            location,
+            mime_type,
+            signing_certificates,
+        })
+    }
+
+    /// Returns whether this pointer refers to an XML trusted list, assuming that it does when no MIME type is present.
+    pub fn is_xml(&self) -> bool {
+        self.mime_type
+            .as_deref()
+            .is_none_or(|mime_type| mime_type == TSL_XML_MIME_TYPE)
+    }
+}
+
+impl TrustService {
+    fn from_element(element: &Element) -> Result<Self, TrustedListError> {
+        let information = required_child(element, "ServiceInformation")?;
+
+        let service_type = required_child(information, "ServiceTypeIdentifier")?.text();
+        let status = required_child(information, "ServiceStatus")?.text();
+        let certificates = information
+            .child(TSL_NAMESPACE, "ServiceDigitalIdentity")
+            .into_iter()
+            .flat_map(digital_identity_certificates)
+            .try_collect()?;
+
+        Ok(Self {
+            service_type,
+            status,
+            certificates,
+        })
+    }
+}
+
+/// Fetches trusted lists, either over HTTP(S) or from the local filesystem using a `file` URL.
+pub struct TrustedListFetcher {
+    client: Client,
+}
+
+impl TrustedListFetcher {
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Fetch and verify a single trusted list.
+    pub async fn fetch(
+        &self,
+        url: &Url,
+        signing_certificates: &[impl AsRef<[u8]>],
+        time: &impl Generator<DateTime<Utc>>,
+    ) -> Result<TrustedList, TrustedListError> {
+        let bytes = if url.scheme() == "file" {
+            let path = url
+                .to_file_path()
+                .map_err(|_| TrustedListError::InvalidFileUrl(url.clone()))?;
+            tokio::fs::read(path).await.map_err(TrustedListError::Io)?
+        } else {
+            let mut response = self
+                .client
+                .get(url.clone())
+                .timeout(REQUEST_TIMEOUT)
+                .send()
+                .await
+                .map_err(TrustedListError::Http)?
+                .error_for_status()
+                .map_err(TrustedListError::Http)?;
+
+            bytes_with_max_response_size(&mut response, MAX_TRUSTED_LIST_SIZE)
+                .await
+                .map_err(TrustedListError::Http)?
+                .ok_or(TrustedListError::TooLarge)?
+        };
+
+        let xml = String::from_utf8(bytes).map_err(TrustedListError::Utf8)?;
+
+        TrustedList::parse_and_verify(&xml, signing_certificates, time)
+    }
+
+    /// Fetch and verify a trusted list, followed by the XML trusted lists it points to, which is the case for a list of
+    /// trusted lists. These are verified against the signing certificates contained in their pointer, while a list
+    /// fetched over the network may not point to a local file.
+    ///
+    /// Pointers are followed exactly one level deep, i.e. the pointers contained in the pointed to lists are ignored.
+    /// This matches the structure of the EU list of trusted lists, of which the national trusted lists only point back
+    /// to the list of trusted lists itself.
+    ///
+    /// A pointed to list that cannot be fetched or verified does not fail the whole fetch. Instead, it is logged and
+    /// returned in [`FetchedTrustedLists::failed_pointers`], so that the caller can decide whether to accept a partial
+    /// set of trust anchors.
+    pub async fn fetch_with_pointers(
+        &self,
+        url: &Url,
+        signing_certificates: &[impl AsRef<[u8]>],
+        time: &impl Generator<DateTime<Utc>>,
+    ) -> Result<FetchedTrustedLists, TrustedListError> {
+        let trusted_list = self.fetch(url, signing_certificates, time).await?;
+
+        let mut trusted_lists = Vec::with_capacity(trusted_list.pointers.len() + 1);
+        let mut failed_pointers = Vec::new();
+        for pointer in trusted_list.pointers.iter().filter(|pointer| pointer.is_xml()) {
+            let result = if pointer.location.scheme() == "file" && url.scheme() != "file" {
+                Err(TrustedListError::LocalPointer(pointer.location.clone()))
+            } else {
+                self.fetch(&pointer.location, pointer.signing_certificates.as_slice(), time)
+                    .await
+            };
+
+            match result {
+                Ok(pointed_list) => trusted_lists.push(pointed_list),
+                Err(error) => {
+                    warn!(
+                        "could not fetch or verify trusted list at {}: {error}",
+                        pointer.location
+                    );
+                    failed_pointers.push((pointer.location.clone(), error));
+                }
+            }
+        }
+        trusted_lists.insert(0, trusted_list);
+
+        Ok(FetchedTrustedLists {
+            trusted_lists,
+            failed_pointers,
+        })
+    }
+}
+
+fn required_child<'a>(element: &'a Element, local_name: &'static str) -> Result<&'a Element, TrustedListError> {
+    element
+        .child(TSL_NAMESPACE, local_name)
+        .ok_or(TrustedListError::MissingElement(local_name))
+}
+
+fn digital_identity_certificates(identity: &Element) -> impl Iterator<Item = Result<Vec<u8>, TrustedListError>> {
+    identity
+        .children_named(TSL_NAMESPACE, "DigitalId")
+        .filter_map(|digital_id| digital_id.child(TSL_NAMESPACE, "X509Certificate"))
+        .map(|certificate| {
+            let base64: String = certificate.text().split_ascii_whitespace().collect();
+            let der = BASE64_STANDARD.decode(base64)?;
+            Ok(der)
+        })
+}
+
+#[cfg(any(test, feature = "mock"))]
+pub mod mock {
+    use base64::Engine;
+    use base64::prelude::BASE64_STANDARD;
+    use chrono::DateTime;
+    use chrono::SecondsFormat;
+    use chrono::Utc;
+    use quick_xml::escape::escape;
+    use url::Url;
+
+    use super::SERVICE_STATUS_GRANTED;
+    use super::TSL_ADDITIONAL_TYPES_NAMESPACE;
+    use super::TSL_NAMESPACE;
+    use super::TSL_XML_MIME_TYPE;
+    use super::xmldsig::mock::sign_enveloped;
+    use crate::server_keys::KeyPair;
+
+    /// Generate a trusted list with the given granted services, each consisting of a service type and DER encoded
+    /// certificate, and pointers to other trusted lists, each consisting of a location and DER encoded signing
+    /// certificate. The list is signed using `key_pair`.
+    pub fn signed_trusted_list_xml(
+        services: &[(&str, &[u8])],
+        pointers: &[(&Url, &[u8])],
+        next_update: DateTime<Utc>,
+        key_pair: &KeyPair,
+    ) -> String {
+        let pointers: String = pointers
+            .iter()
+            .map(|(location, certificate)| {
+                format!(
+                    "<tsl:OtherTSLPointer><tsl:ServiceDigitalIdentities><tsl:ServiceDigitalIdentity><tsl:\
+                     DigitalId><tsl:X509Certificate>{}</tsl:X509Certificate></tsl:DigitalId></tsl:\
+                     ServiceDigitalIdentity></tsl:ServiceDigitalIdentities><tsl:TSLLocation>{}</tsl:TSLLocation><tsl:\
+                     AdditionalInformation><tsl:OtherInformation><tslx:MimeType>{TSL_XML_MIME_TYPE}</tslx:MimeType></\
+                     tsl:OtherInformation></tsl:AdditionalInformation></tsl:OtherTSLPointer>",
+                    BASE64_STANDARD.encode(certificate),
+                    escape(location.as_str()),
+                )
+            })
+            .collect();
+
+        let services: String = services
+            .iter()
+            .map(|(service_type, certificate)| {
+                format!(
+                    "<tsl:TSPService><tsl:ServiceInformation><tsl:ServiceTypeIdentifier>{service_type}</tsl:\
+                     ServiceTypeIdentifier><tsl:ServiceDigitalIdentity><tsl:DigitalId><tsl:X509Certificate>{}</tsl:\
+                     X509Certificate></tsl:DigitalId></tsl:ServiceDigitalIdentity><tsl:\
+                     ServiceStatus>{SERVICE_STATUS_GRANTED}</tsl:ServiceStatus></tsl:ServiceInformation></tsl:\
+                     TSPService>",
+                    BASE64_STANDARD.encode(certificate),
+                )
+            })
+            .collect();
+
+        let xml = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<tsl:TrustServiceStatusList xmlns:tsl=\"{TSL_NAMESPACE}\" \
+             xmlns:tslx=\"{TSL_ADDITIONAL_TYPES_NAMESPACE}\" \
+             Id=\"TrustServiceStatusList\">\n<tsl:SchemeInformation>\n<tsl:TSLVersionIdentifier>6</tsl:\
+             TSLVersionIdentifier>\n<tsl:TSLSequenceNumber>1</tsl:TSLSequenceNumber>\n<tsl:\
+             PointersToOtherTSL>{pointers}</tsl:PointersToOtherTSL>\n<tsl:NextUpdate><tsl:dateTime>{}</tsl:dateTime></\
+             tsl:NextUpdate>\n</tsl:SchemeInformation>\n<tsl:TrustServiceProviderList><tsl:TrustServiceProvider><tsl:\
+             TSPServices>{services}</tsl:TSPServices></tsl:TrustServiceProvider></tsl:TrustServiceProviderList>\n</\
+             tsl:TrustServiceStatusList>",
+            next_update.to_rfc3339_opts(SecondsFormat::Secs, true),
+        );
+
+        sign_enveloped(&xml, key_pair)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use chrono::Days;
+    use chrono::TimeZone;
+    use http_utils::httpmock::httpmock_reqwest_client_builder;
+    use httpmock::Method::GET;
+    use httpmock::MockServer;
+    use utils::generator::mock::MockTimeGenerator;
+
+    use super::mock::signed_trusted_list_xml;
+    use super::*;
+    use crate::server_keys::generate::Ca;
+
+    fn next_update() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_verify() {
+        let operator_ca = Ca::generate_mock();
+        let operator = operator_ca.generate_wrpac_verifier_mock().unwrap();
+        let issuer_ca = Ca::generate_issuer_mock_ca().unwrap();
+        let wrpac_ca = Ca::generate_wrpac_mock_ca().unwrap();
+
+        let xml = signed_trusted_list_xml(
+            &[
+                (SERVICE_TYPE_CA_QC, issuer_ca.certificate().as_ref()),
+                (SERVICE_TYPE_CA_PKC, wrpac_ca.certificate().as_ref()),
+            ],
+            &[],
+            next_update(),
+            &operator,
+        );
+
+        let trusted_list =
+            TrustedList::parse_and_verify(&xml, &[operator.certificate()], &MockTimeGenerator::epoch()).unwrap();
+
+        assert_eq!(trusted_list.sequence_number(), 1);
+        assert_eq!(trusted_list.next_update(), Some(next_update()));
+        assert_eq!(trusted_list.services().len(), 2);
+        assert!(trusted_list.pointers().is_empty());
+
+        let issuer_anchors = trusted_list.trust_anchors(SERVICE_TYPE_CA_QC).unwrap();
+        assert_eq!(issuer_anchors, vec![issuer_ca.to_borrowing_trust_anchor()]);
+
+        let wrpac_anchors = trusted_list.trust_anchors(SERVICE_TYPE_CA_PKC).unwrap();
+        assert_eq!(wrpac_anchors, vec![wrpac_ca.to_borrowing_trust_anchor()]);
+
+        assert!(trusted_list.trust_anchors("urn:unknown").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_and_verify_untrusted_signer() {
+        let operator_ca = Ca::generate_mock();
+        let operator = operator_ca.generate_wrpac_verifier_mock().unwrap();
+        let other = operator_ca.generate_wrpac_verifier_mock().unwrap();
+
+        let xml = signed_trusted_list_xml(&[], &[], next_update(), &operator);
+
+        let error = TrustedList::parse_and_verify(&xml, &[other.certificate()], &MockTimeGenerator::epoch())
+            .expect_err("verification should fail");
+        assert_matches!(error, TrustedListError::UntrustedSigner);
+    }
+
+    #[test]
+    fn test_parse_and_verify_expired() {
+        let operator_ca = Ca::generate_mock();
+        let operator = operator_ca.generate_wrpac_verifier_mock().unwrap();
+
+        let xml = signed_trusted_list_xml(&[], &[], next_update(), &operator);
+        let time = MockTimeGenerator::new(next_update().checked_add_days(Days::new(1)).unwrap());
+
+        let error = TrustedList::parse_and_verify(&xml, &[operator.certificate()], &time)
+            .expect_err("verification should fail");
+        assert_matches!(error, TrustedListError::Expired(_));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_with_pointers() {
+        let lotl_operator_ca = Ca::generate_mock();
+        let lotl_operator = lotl_operator_ca.generate_wrpac_verifier_mock().unwrap();
+        let tl_operator = lotl_operator_ca.generate_wrpac_verifier_mock().unwrap();
+        let issuer_ca = Ca::generate_issuer_mock_ca().unwrap();
+
+        let server = MockServer::start_async().await;
+        let tl_url: Url = server.url("/tl.xml").parse().unwrap();
+        let lotl_url: Url = server.url("/lotl.xml").parse().unwrap();
+
+        let tl = signed_trusted_list_xml(
+            &[(SERVICE_TYPE_CA_QC, issuer_ca.certificate().as_ref())],
+            &[],
+            next_update(),
+            &tl_operator,
+        );
+        let lotl = signed_trusted_list_xml(
+            &[],
+            &[(&tl_url, tl_operator.certificate().as_ref())],
+            next_update(),
+            &lotl_operator,
+        );
+
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/tl.xml");
+                then.status(200).body(tl);
+            })
+            .await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/lotl.xml");
+                then.status(200).body(lotl);
+            })
+            .await;
+
+        let fetcher = TrustedListFetcher::new(httpmock_reqwest_client_builder().build().unwrap());
+        let FetchedTrustedLists {
+            trusted_lists,
+            failed_pointers,
+        } = fetcher
+            .fetch_with_pointers(&lotl_url, &[lotl_operator.certificate()], &MockTimeGenerator::epoch())
+            .await
+            .unwrap();
+
+        assert!(failed_pointers.is_empty());
+        assert_eq!(trusted_lists.len(), 2);
+        assert_eq!(trusted_lists[0].pointers()[0].location, tl_url);
+
+        let anchors = trusted_lists[1].trust_anchors(SERVICE_TYPE_CA_QC).unwrap();
+        assert_eq!(anchors, vec![issuer_ca.to_borrowing_trust_anchor()]);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_with_pointers_returns_failing_pointers() {
+        let lotl_operator_ca = Ca::generate_mock();
+        let lotl_operator = lotl_operator_ca.generate_wrpac_verifier_mock().unwrap();
+        let tl_operator = lotl_operator_ca.generate_wrpac_verifier_mock().unwrap();
+        let issuer_ca = Ca::generate_issuer_mock_ca().unwrap();
+
+        let server = MockServer::start_async().await;
+        let file_url: Url = "file:///etc/passwd".parse().unwrap();
+        let missing_url: Url = server.url("/missing.xml").parse().unwrap();
+        let tl_url: Url = server.url("/tl.xml").parse().unwrap();
+
+        let tl = signed_trusted_list_xml(
+            &[(SERVICE_TYPE_CA_QC, issuer_ca.certificate().as_ref())],
+            &[],
+            next_update(),
+            &tl_operator,
+        );
+        let lotl = signed_trusted_list_xml(
+            &[],
+            &[
+                (&file_url, lotl_operator.certificate().as_ref()),
+                (&missing_url, tl_operator.certificate().as_ref()),
+                (&tl_url, tl_operator.certificate().as_ref()),
+            ],
+            next_update(),
+            &lotl_operator,
+        );
+
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/tl.xml");
+                then.status(200).body(tl);
+            })
+            .await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/missing.xml");
+                then.status(404);
+            })
+            .await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/lotl.xml");
+                then.status(200).body(lotl);
+            })
+            .await;
+
+        let fetcher = TrustedListFetcher::new(httpmock_reqwest_client_builder().build().unwrap());
+        let FetchedTrustedLists {
+            trusted_lists,
+            failed_pointers,
+        } = fetcher
+            .fetch_with_pointers(
+                &server.url("/lotl.xml").parse().unwrap(),
+                &[lotl_operator.certificate()],
+                &MockTimeGenerator::epoch(),
+            )
+            .await
+            .unwrap();
+
+        // Only the list of trusted lists itself and the list that could be fetched should be returned, while the
+        // pointers to the local file and the missing list should be reported as failed.
+        assert_eq!(trusted_lists.len(), 2);
+        assert_eq!(trusted_lists[0].pointers().len(), 3);
+
+        assert_eq!(failed_pointers.len(), 2);
+        assert_eq!(failed_pointers[0].0, file_url);
+        assert_matches!(failed_pointers[0].1, TrustedListError::LocalPointer(_));
+        assert_eq!(failed_pointers[1].0, missing_url);
+        assert_matches!(failed_pointers[1].1, TrustedListError::Http(_));
+
+        let anchors = trusted_lists[1].trust_anchors(SERVICE_TYPE_CA_QC).unwrap();
+        assert_eq!(anchors, vec![issuer_ca.to_borrowing_trust_anchor()]);
+    }
+}
@@ -0,0 +1,606 @@
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use quick_xml::Reader;
+use quick_xml::XmlVersion;
+use quick_xml::encoding::EncodingError;
+use quick_xml::escape::resolve_xml_entity;
+use quick_xml::events::BytesPI;
+use quick_xml::events::BytesStart;
+use quick_xml::events::Event;
+
+const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+
+/// Namespace declarations in scope for an element, mapping a prefix to a namespace URI. The default namespace uses
+/// the empty prefix, and an empty namespace URI means that the default namespace has been undeclared.
+type Namespaces = Rc<BTreeMap<String, String>>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum XmlError {
+    #[error("XML parsing error: {0}")]
+    Parsing(#[from] quick_xml::Error),
+    #[error("XML document type declarations are not supported")]
+    DocumentType,
+    #[error("unknown XML entity: {0}")]
+    UnknownEntity(String),
+    #[error("undeclared XML namespace prefix: {0}")]
+    UndeclaredPrefix(String),
+    #[error("XML document does not contain exactly one root element")]
+    RootElement,
+}
+
+/// A minimal, read-only XML document model, containing just enough information to both extract data from an XML
+/// document and to canonicalize (parts of) it for XML signature verification. Comments are not retained.
+#[derive(Debug)]
+pub(super) struct Document {
+    before_root: Vec<ProcessingInstruction>,
+    root: Element,
+    after_root: Vec<ProcessingInstruction>,
+}
+
+#[derive(Debug)]
+pub(super) struct Element {
+    name: String,
+    namespace: Option<String>,
+    local_name: String,
+    in_scope: Namespaces,
+    attributes: Vec<Attribute>,
+    children: Vec<Node>,
+}
+
+#[derive(Debug)]
+struct Attribute {
+    name: String,
+    namespace: Option<String>,
+    local_name: String,
+    value: String,
+}
+
+#[derive(Debug)]
+struct ProcessingInstruction {
+    target: String,
+    data: String,
+}
+
+#[derive(Debug)]
+enum Node {
+    Element(Element),
+    Text(String),
+    ProcessingInstruction(ProcessingInstruction),
+}
+
+/// The Canonical XML algorithms supported by [`Document::canonicalize`] and [`Element::canonicalize`], see
+/// <https://www.w3.org/TR/xml-c14n/> and <https://www.w3.org/TR/xml-exc-c14n/>. The variants that retain comments
+/// are not supported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Canonicalization {
+    Inclusive,
+    Exclusive { inclusive_prefixes: Vec<String> },
+}
+
+impl Document {
+    pub(super) fn parse(xml: &str) -> Result<Self, XmlError> {
+        let mut reader = Reader::from_str(xml);
+        let mut builder = DocumentBuilder::default();
+
+        loop {
+            match reader.read_event()? {
+                Event::Start(start) => {
+                    let element = Element::from_start(&start, builder.stack.last().map(|parent| &parent.in_scope))?;
+                    builder.stack.push(element);
+                }
+                Event::Empty(start) => {
+                    let element = Element::from_start(&start, builder.stack.last().map(|parent| &parent.in_scope))?;
+                    builder.close(element)?;
+                }
+                Event::End(_) => {
+                    // The reader verifies that end tags match start tags, so there is always an open element here.
+                    let element = builder.stack.pop().ok_or(XmlError::RootElement)?;
+                    builder.close(element)?;
+                }
+                Event::Text(text) => builder.push_text(&text.xml10_content().map_err(quick_xml::Error::from)?),
+                Event::CData(cdata) => builder.push_text(&cdata.decode().map_err(quick_xml::Error::from)?),
+                Event::GeneralRef(reference) => match reference.resolve_char_ref()? {
+                    Some(character) => builder.push_text(character.encode_utf8(&mut [0; 4])),
+                    None => {
+                        let entity = reference.decode().map_err(quick_xml::Error::from)?;
+                        let text =
+                            resolve_xml_entity(&entity).ok_or_else(|| XmlError::UnknownEntity(entity.into_owned()))?;
+                        builder.push_text(text);
+                    }
+                },
+                Event::PI(pi) => builder.push_processing_instruction(ProcessingInstruction::from_pi(&pi)?),
+                // Document type declarations may define entities, which are not supported for security reasons.
+                Event::DocType(_) => return Err(XmlError::DocumentType),
+                Event::Comment(_) | Event::Decl(_) => {}
+                Event::Eof => break,
+            }
+        }
+
+        builder.finish()
+    }
+
+    pub(super) fn root(&self) -> &Element {
+        &self.root
+    }
+
+    /// Canonicalize the entire document, optionally excluding a single element and its descendants, as is done by
+    /// the enveloped signature transform.
+    pub(super) fn canonicalize(&self, method: &Canonicalization, excluded: Option<&Element>) -> String {
+        let mut writer = CanonicalWriter::new(method, excluded);
+
+        for pi in &self.before_root {
+            writer.write_processing_instruction(pi);
+            writer.output.push('\n');
+        }
+        writer.write_element(&self.root, &BTreeMap::new());
+        for pi in &self.after_root {
+            writer.output.push('\n');
+            writer.write_processing_instruction(pi);
+        }
+
+        writer.output
+    }
+}
+
+impl Element {
+    fn from_start(start: &BytesStart, parent_scope: Option<&Namespaces>) -> Result<Self, XmlError> {
+        let mut declarations = Vec::new();
+        let mut attributes = Vec::new();
+
+        for attribute in start.attributes() {
+            let attribute = attribute.map_err(quick_xml::Error::from)?;
+            let name = utf8(attribute.key.as_ref())?.to_owned();
+            let value = attribute.normalized_value(XmlVersion::Implicit1_0)?.into_owned();
+
+            if name == "xmlns" {
+                declarations.push((String::new(), value));
+            } else if let Some(prefix) = name.strip_prefix("xmlns:") {
+                declarations.push((prefix.to_owned(), value));
+            } else {
+                attributes.push((name, value));
+            }
+        }
+
+        let in_scope = match parent_scope {
+            Some(parent_scope) if declarations.is_empty() => Rc::clone(parent_scope),
+            parent_scope => {
+                let mut in_scope = parent_scope.map(|scope| scope.as_ref().clone()).unwrap_or_default();
+                in_scope.extend(declarations);
+                Rc::new(in_scope)
+            }
+        };
+
+        let name = utf8(start.name().as_ref())?.to_owned();
+        let (namespace, local_name) = resolve_name(&in_scope, &name, true)?;
+
+        let attributes = attributes
+            .into_iter()
+            .map(|(name, value)| {
+                let (namespace, local_name) = resolve_name(&in_scope, &name, false)?;
+                Ok(Attribute {
+                    name,
+                    namespace,
+                    local_name,
+                    value,
+                })
+            })
+            .collect::<Result<_, XmlError>>()?;
+
+        Ok(Self {
+            name,
+            namespace,
+            local_name,
+            in_scope,
+            attributes,
+            children: Vec::new(),
+        })
+    }
+
+    pub(super) fn is(&self, namespace: &str, local_name: &str) -> bool {
+        self.namespace.as_deref() == Some(namespace) && self.local_name == local_name
+    }
+
+    pub(super) fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter().filter_map(|child| match child {
+            Node::Element(element) => Some(element),
+            _ => None,
+        })
+    }
+
+    pub(super) fn children_named<'a>(
+        &'a self,
+        namespace: &'a str,
+        local_name: &'a str,
+    ) -> impl Iterator<Item = &'a Element> {
+        self.elements().filter(move |element| element.is(namespace, local_name))
+    }
+
+    pub(super) fn child(&self, namespace: &str, local_name: &str) -> Option<&Element> {
+        self.elements().find(|element| element.is(namespace, local_name))
+    }
+
+    /// Follow a path of child elements that all share the same namespace.
+    pub(super) fn path(&self, namespace: &str, local_names: &[&str]) -> Option<&Element> {
+        local_names
+            .iter()
+            .try_fold(self, |element, local_name| element.child(namespace, local_name))
+    }
+
+    /// Returns the value of an attribute without namespace prefix.
+    pub(super) fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.namespace.is_none() && attribute.local_name == name)
+            .map(|attribute| attribute.value.as_str())
+    }
+
+    /// Returns the concatenated text content of this element, excluding that of any child elements, with leading and
+    /// trailing whitespace removed.
+    pub(super) fn text(&self) -> String {
+        self.children
+            .iter()
+            .filter_map(|child| match child {
+                Node::Text(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect::<String>()
+            .trim()
+            .to_owned()
+    }
+
+    /// Find the first element in document order for which `predicate` returns `true`, including this element itself.
+    pub(super) fn find(&self, predicate: &impl Fn(&Element) -> bool) -> Option<&Element> {
+        if predicate(self) {
+            return Some(self);
+        }
+
+        self.elements().find_map(|element| element.find(predicate))
+    }
+
+    /// Canonicalize the subtree rooted at this element, optionally excluding a single descendant element.
+    ///
+    /// Note that for inclusive canonicalization, attributes in the `xml` namespace are not inherited from ancestors.
+    pub(super) fn canonicalize(&self, method: &Canonicalization, excluded: Option<&Element>) -> String {
+        let mut writer = CanonicalWriter::new(method, excluded);
+        writer.write_element(self, &BTreeMap::new());
+
+        writer.output
+    }
+
+    fn prefix(&self) -> &str {
+        self.name.split_once(':').map(|(prefix, _)| prefix).unwrap_or_default()
+    }
+}
+
+impl ProcessingInstruction {
+    fn from_pi(pi: &BytesPI) -> Result<Self, XmlError> {
+        let processing_instruction = Self {
+            target: utf8(pi.target())?.to_owned(),
+            data: utf8(pi.content())?.trim_start().to_owned(),
+        };
+
+        Ok(processing_instruction)
+    }
+}
+
+#[derive(Default)]
+struct DocumentBuilder {
+    stack: Vec<Element>,
+    before_root: Vec<ProcessingInstruction>,
+    root: Option<Element>,
+    after_root: Vec<ProcessingInstruction>,
+}
+
+impl DocumentBuilder {
+    fn close(&mut self, element: Element) -> Result<(), XmlError> {
+        match (self.stack.last_mut(), &self.root) {
+            (Some(parent), _) => parent.children.push(Node::Element(element)),
+            (None, None) => self.root = Some(element),
+            (None, Some(_)) => return Err(XmlError::RootElement),
+        }
+
+        Ok(())
+    }
+
+    fn push_text(&mut self, text: &str) {
+        // Text outside of the root element can only consist of whitespace, which is not part of the document model.
+        let Some(element) = self.stack.last_mut() else {
+            return;
+        };
+
+        // Merge adjacent text, which the reader returns separately when it contains references.
+        if let Some(Node::Text(previous)) = element.children.last_mut() {
+            previous.push_str(text);
+        } else {
+            element.children.push(Node::Text(text.to_owned()));
+        }
+    }
+
+    fn push_processing_instruction(&mut self, pi: ProcessingInstruction) {
+        match (self.stack.last_mut(), &self.root) {
+            (Some(element), _) => element.children.push(Node::ProcessingInstruction(pi)),
+            (None, None) => self.before_root.push(pi),
+            (None, Some(_)) => self.after_root.push(pi),
+        }
+    }
+
+    fn finish(self) -> Result<Document, XmlError> {
+        let root = match (self.root, self.stack.is_empty()) {
+            (Some(root), true) => root,
+            _ => return Err(XmlError::RootElement),
+        };
+
+        Ok(Document {
+            before_root: self.before_root,
+            root,
+            after_root: self.after_root,
+        })
+    }
+}
+
+struct CanonicalWriter<'a> {
+    method: &'a Canonicalization,
+    excluded: Option<&'a Element>,
+    output: String,
+}
+
+impl<'a> CanonicalWriter<'a> {
+    fn new(method: &'a Canonicalization, excluded: Option<&'a Element>) -> Self {
+        Self {
+            method,
+            excluded,
+            output: String::new(),
+        }
+    }
+
+    /// Write a single element, where `rendered` contains the namespace declarations that are in effect because they
+    /// were output by an ancestor element.
+    fn write_element(&mut self, element: &Element, rendered: &BTreeMap<String, String>) {
+        if self.excluded.is_some_and(|excluded| std::ptr::eq(excluded, element)) {
+            return;
+        }
+
+        // Inclusive canonicalization considers all namespaces in scope, while exclusive canonicalization only
+        // considers the namespaces that are visibly utilized by the element or its attributes.
+        let candidates: Vec<&str> = match self.method {
+            Canonicalization::Inclusive => element.in_scope.keys().map(String::as_str).collect(),
+            Canonicalization::Exclusive { inclusive_prefixes } => std::iter::once(element.prefix())
+                .chain(
+                    element
+                        .attributes
+                        .iter()
+                        .filter_map(|attribute| attribute.name.split_once(':').map(|(prefix, _)| prefix)),
+                )
+                .chain(
+                    inclusive_prefixes
+                        .iter()
+                        .map(String::as_str)
+                        .filter(|prefix| element.in_scope.contains_key(*prefix)),
+                )
+                .collect(),
+        };
+
+        // Namespace declarations are only output when they differ from what is already in effect. As the keys of the
+        // map are sorted, the default namespace is output first, followed by the other declarations sorted by prefix.
+        let declarations: BTreeMap<&str, &str> = candidates
+            .into_iter()
+            .filter(|prefix| *prefix != "xml")
+            .filter_map(|prefix| {
+                let namespace = element.in_scope.get(prefix).map(String::as_str).unwrap_or_default();
+                let current = rendered.get(prefix).map(String::as_str).unwrap_or_default();
+                (namespace != current).then_some((prefix, namespace))
+            })
+            .collect();
+
+        self.output.push('<');
+        self.output.push_str(&element.name);
+
+        for (prefix, namespace) in &declarations {
+            if prefix.is_empty() {
+                self.output.push_str(" xmlns");
+            } else {
+                self.output.push_str(" xmlns:");
+                self.output.push_str(prefix);
+            }
+            self.write_attribute_value(namespace);
+        }
+
+        let mut attributes: Vec<&Attribute> = element.attributes.iter().collect();
+        attributes.sort_by(|a, b| {
+            (a.namespace.as_deref().unwrap_or_default(), &a.local_name)
+                .cmp(&(b.namespace.as_deref().unwrap_or_default(), &b.local_name))
+        });
+
+        for attribute in attributes {
+            self.output.push(' ');
+            self.output.push_str(&attribute.name);
+            self.write_attribute_value(&attribute.value);
+        }
+
+        self.output.push('>');
+
+        let rendered = if declarations.is_empty() {
+            rendered.clone()
+        } else {
+            rendered
+                .iter()
+                .map(|(prefix, namespace)| (prefix.clone(), namespace.clone()))
+                .chain(
+                    declarations
+                        .into_iter()
+                        .map(|(prefix, namespace)| (prefix.to_owned(), namespace.to_owned())),
+                )
+                .collect()
+        };
+
+        for child in &element.children {
+            match child {
+                Node::Element(child) => self.write_element(child, &rendered),
+                Node::Text(text) => self.write_text(text),
+                Node::ProcessingInstruction(pi) => self.write_processing_instruction(pi),
+            }
+        }
+
+        self.output.push_str("</");
+        self.output.push_str(&element.name);
+        self.output.push('>');
+    }
+
+    fn write_attribute_value(&mut self, value: &str) {
+        self.output.push_str("=\"");
+        for character in value.chars() {
+            match character {
+                '&' => self.output.push_str("&amp;"),
+                '<' => self.output.push_str("&lt;"),
+                '"' => self.output.push_str("&quot;"),
+                '\t' => self.output.push_str("&#x9;"),
+                '\n' => self.output.push_str("&#xA;"),
+                '\r' => self.output.push_str("&#xD;"),
+                character => self.output.push(character),
+            }
+        }
+        self.output.push('"');
+    }
+
+    fn write_text(&mut self, text: &str) {
+        for character in text.chars() {
+            match character {
+                '&' => self.output.push_str("&amp;"),
+                '<' => self.output.push_str("&lt;"),
+                '>' => self.output.push_str("&gt;"),
+                '\r' => self.output.push_str("&#xD;"),
+                character => self.output.push(character),
+            }
+        }
+    }
+
+    fn write_processing_instruction(&mut self, pi: &ProcessingInstruction) {
+        self.output.push_str("<?");
+        self.output.push_str(&pi.target);
+        if !pi.data.is_empty() {
+            self.output.push(' ');
+            self.output.push_str(&pi.data);
+        }
+        self.output.push_str("?>");
+    }
+}
+
+fn utf8(bytes: &[u8]) -> Result<&str, XmlError> {
+    let text = std::str::from_utf8(bytes).map_err(|error| quick_xml::Error::from(EncodingError::Utf8(error)))?;
+
+    Ok(text)
+}
+
+/// Resolve a qualified name to its namespace URI and local name. Note that unprefixed attribute names are never in the
+/// default namespace.
+fn resolve_name(in_scope: &Namespaces, name: &str, use_default: bool) -> Result<(Option<String>, String), XmlError> {
+    let (namespace, local_name) = match name.split_once(':') {
+        Some(("xml", local_name)) => (Some(XML_NAMESPACE), local_name),
+        Some((prefix, local_name)) => {
+            let namespace = in_scope
+                .get(prefix)
+                .filter(|namespace| !namespace.is_empty())
+                .ok_or_else(|| XmlError::UndeclaredPrefix(prefix.to_owned()))?;
+            (Some(namespace.as_str()), local_name)
+        }
+        None => {
+            let namespace = use_default
+                .then(|| in_scope.get(""))
+                .flatten()
+                .filter(|namespace| !namespace.is_empty())
+                .map(String::as_str);
+            (namespace, name)
+        }
+    };
+
+    Ok((namespace.map(str::to_owned), local_name.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let document = Document::parse(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <a:root xmlns:a="urn:a" xmlns="urn:default" id="1"><child>text &amp; <![CDATA[<more>]]> &#65;</child><!-- comment --><a:child/></a:root>"#,
+        )
+        .unwrap();
+
+        let root = document.root();
+        assert!(root.is("urn:a", "root"));
+        assert_eq!(root.attribute("id"), Some("1"));
+
+        let child = root.child("urn:default", "child").unwrap();
+        assert_eq!(child.text(), "text & <more> A");
+        assert!(root.child("urn:a", "child").is_some());
+    }
+
+    #[rstest]
+    #[case::doctype(r#"<!DOCTYPE root [<!ENTITY e "e">]><root>&e;</root>"#)]
+    #[case::unknown_entity("<root>&e;</root>")]
+    #[case::undeclared_prefix("<a:root/>")]
+    #[case::multiple_roots("<root/><root/>")]
+    #[case::no_root("")]
+    fn test_parse_error(#[case] xml: &str) {
+        let _ = Document::parse(xml).expect_err("parsing should fail");
+    }
+
+    // Examples adapted from the Canonical XML specifications.
+    #[rstest]
+    #[case::inclusive(
+        Canonicalization::Inclusive,
+        "<doc xmlns=\"urn:default\"><e1 xmlns:b=\"urn:b\"><b:e2 xmlns:a=\"urn:a\" attr=\"&amp;&quot;&#x9;\" \
+         b:attr=\"1\"> a &gt; b </b:e2></e1></doc>"
+    )]
+    #[case::exclusive(
+        Canonicalization::Exclusive { inclusive_prefixes: vec![] },
+        "<doc xmlns=\"urn:default\"><e1><b:e2 xmlns:b=\"urn:b\" attr=\"&amp;&quot;&#x9;\" b:attr=\"1\"> a &gt; b </b:e2></e1></doc>"
+    )]
+    #[case::exclusive_with_prefixes(
+        Canonicalization::Exclusive { inclusive_prefixes: vec!["a".to_string()] },
+        "<doc xmlns=\"urn:default\"><e1><b:e2 xmlns:a=\"urn:a\" xmlns:b=\"urn:b\" attr=\"&amp;&quot;&#x9;\" b:attr=\"1\"> a &gt; b </b:e2></e1></doc>"
+    )]
+    fn test_canonicalize(#[case] method: Canonicalization, #[case] expected: &str) {
+        let document = Document::parse(
+            "<?xml version=\"1.0\"?>\n<doc xmlns=\"urn:default\"><e1   xmlns:b='urn:b' ><b:e2 xmlns:a=\"urn:a\" \
+             b:attr='1' attr='&amp;\"&#9;'> a &gt; b </b:e2><!-- c --></e1></doc>",
+        )
+        .unwrap();
+
+        assert_eq!(document.canonicalize(&method, None), expected);
+    }
+
+    #[test]
+    fn test_canonicalize_subtree_and_exclusion() {
+        let document = Document::parse(
+            r#"<root xmlns="urn:default" xmlns:a="urn:a"><a:keep><empty/></a:keep><a:drop>text</a:drop></root>"#,
+        )
+        .unwrap();
+
+        let keep = document.root().child("urn:a", "keep").unwrap();
+        let drop = document.root().child("urn:a", "drop").unwrap();
+
+        assert_eq!(
+            keep.canonicalize(&Canonicalization::Inclusive, None),
+            r#"<a:keep xmlns="urn:default" xmlns:a="urn:a"><empty></empty></a:keep>"#
+        );
+        assert_eq!(
+            keep.canonicalize(
+                &Canonicalization::Exclusive {
+                    inclusive_prefixes: vec![]
+                },
+                None
+            ),
+            r#"<a:keep xmlns:a="urn:a"><empty xmlns="urn:default"></empty></a:keep>"#
+        );
+        assert_eq!(
+            document.canonicalize(&Canonicalization::Inclusive, Some(drop)),
+            r#"<root xmlns="urn:default" xmlns:a="urn:a"><a:keep><empty></empty></a:keep></root>"#
+        );
+    }
+}
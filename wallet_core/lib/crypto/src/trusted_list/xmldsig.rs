@@ -0,0 +1,385 @@
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use p256::ecdsa::Signature;
+use p256::ecdsa::VerifyingKey;
+use p256::ecdsa::signature::Verifier;
+use p256::pkcs8::DecodePublicKey;
+use rsa::Pkcs1v15Sign;
+use rsa::RsaPublicKey;
+use rsa::pkcs8::DecodePublicKey as _;
+use x509_parser::prelude::FromDer;
+use x509_parser::prelude::X509Certificate;
+use x509_parser::prelude::X509Error;
+
+use super::xml::Canonicalization;
+use super::xml::Document;
+use super::xml::Element;
+use crate::utils::sha256;
+
+pub(super) const DSIG_NAMESPACE: &str = "http://www.w3.org/2000/09/xmldsig#";
+
+const ENVELOPED_SIGNATURE_TRANSFORM: &str = "http://www.w3.org/2000/09/xmldsig#enveloped-signature";
+const INCLUSIVE_C14N: &str = "http://www.w3.org/TR/2001/REC-xml-c14n-20010315";
+const EXCLUSIVE_C14N: &str = "http://www.w3.org/2001/10/xml-exc-c14n#";
+const ECDSA_SHA256: &str = "http://www.w3.org/2001/04/xmldsig-more#ecdsa-sha256";
+const RSA_SHA256: &str = "http://www.w3.org/2001/04/xmldsig-more#rsa-sha256";
+const SHA256: &str = "http://www.w3.org/2001/04/xmlenc#sha256";
+
+/// The DER encoded `DigestInfo` prefix for SHA-256 from RFC 8017, which precedes the digest in a PKCS #1 v1.5
+/// signature.
+const SHA256_DIGEST_INFO_PREFIX: [u8; 19] = [
+    0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01, 0x05, 0x00, 0x04, 0x20,
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum XmlSignatureError {
+    #[error("missing XML signature element: {0}")]
+    MissingElement(&'static str),
+    #[error("missing XML signature attribute: {0}")]
+    MissingAttribute(&'static str),
+    #[error("unsupported XML signature algorithm: {0}")]
+    UnsupportedAlgorithm(String),
+    #[error("unsupported XML signature reference: {0}")]
+    UnsupportedReference(String),
+    #[error("XML signature reference not found: {0}")]
+    ReferenceNotFound(String),
+    #[error("digest of XML signature reference does not match: {0}")]
+    DigestMismatch(String),
+    #[error("XML signature does not cover the entire document")]
+    DocumentNotCovered,
+    #[error("could not decode base64 value: {0}")]
+    Base64(#[from] base64::DecodeError),
+    #[error("could not parse signing certificate: {0}")]
+    Certificate(#[source] x509_parser::nom::Err<X509Error>),
+    #[error("could not parse ECDSA public key of signing certificate: {0}")]
+    EcdsaPublicKey(#[source] p256::pkcs8::spki::Error),
+    #[error("could not parse RSA public key of signing certificate: {0}")]
+    RsaPublicKey(#[source] rsa::pkcs8::spki::Error),
+    #[error("XML signature verification failed: {0}")]
+    Signature(#[source] p256::ecdsa::Error),
+    #[error("XML RSA signature verification failed: {0}")]
+    RsaSignature(#[source] rsa::Error),
+}
+
+/// Verify the enveloped XML signature (<https://www.w3.org/TR/xmldsig-core1/>) that is a direct child of the root
+/// element of `document`, returning the DER encoded certificate from the `KeyInfo` the signature was verified against.
+/// Note that it is up to the caller to decide whether this certificate is trusted.
+///
+/// Only the profile used by ETSI trusted lists is supported: ECDSA (P-256) or RSA PKCS #1 v1.5 signatures using
+/// SHA-256, either an inclusive or an exclusive canonicalization method and references to either the entire document
+/// or to an element by its `Id` attribute. One of the references has to cover the entire document.
+pub(super) fn verify_enveloped_signature(document: &Document) -> Result<Vec<u8>, XmlSignatureError> {
+    let root = document.root();
+    let signature = required_child(root, "Signature")?;
+    let signed_info = required_child(signature, "SignedInfo")?;
+
+    let signed_info_canonicalization = canonicalization(required_child(signed_info, "CanonicalizationMethod")?)?;
+    let signature_method = algorithm(required_child(signed_info, "SignatureMethod")?)?;
+    if signature_method != ECDSA_SHA256 && signature_method != RSA_SHA256 {
+        return Err(XmlSignatureError::UnsupportedAlgorithm(signature_method.to_owned()));
+    }
+
+    let mut covers_document = false;
+    for reference in signed_info.children_named(DSIG_NAMESPACE, "Reference") {
+        let uri = reference
+            .attribute("URI")
+            .ok_or(XmlSignatureError::MissingAttribute("URI"))?;
+
+        let mut enveloped = false;
+        let mut reference_canonicalization = None;
+        for transform in reference
+            .child(DSIG_NAMESPACE, "Transforms")
+            .into_iter()
+            .flat_map(|transforms| transforms.children_named(DSIG_NAMESPACE, "Transform"))
+        {
+            match algorithm(transform)? {
+                ENVELOPED_SIGNATURE_TRANSFORM => enveloped = true,
+                _ => reference_canonicalization = Some(canonicalization(transform)?),
+            }
+        }
+
+        // Without an explicit canonicalization transform, the node set is converted using inclusive canonicalization.
+        let reference_canonicalization = reference_canonicalization.unwrap_or(Canonicalization::Inclusive);
+        let excluded = enveloped.then_some(signature);
+
+        let octets = if uri.is_empty() {
+            covers_document = true;
+            document.canonicalize(&reference_canonicalization, excluded)
+        } else if let Some(id) = uri.strip_prefix('#') {
+            root.find(&|element: &Element| element_id(element) == Some(id))
+                .ok_or_else(|| XmlSignatureError::ReferenceNotFound(uri.to_owned()))?
+                .canonicalize(&reference_canonicalization, excluded)
+        } else {
+            return Err(XmlSignatureError::UnsupportedReference(uri.to_owned()));
+        };
+
+        let digest_method = algorithm(required_child(reference, "DigestMethod")?)?;
+        if digest_method != SHA256 {
+            return Err(XmlSignatureError::UnsupportedAlgorithm(digest_method.to_owned()));
+        }
+
+        let digest_value = decode_base64(required_child(reference, "DigestValue")?)?;
+        if sha256(octets.as_bytes()) != digest_value {
+            return Err(XmlSignatureError::DigestMismatch(uri.to_owned()));
+        }
+    }
+
+    if !covers_document {
+        return Err(XmlSignatureError::DocumentNotCovered);
+    }
+
+    let certificate = required_child(signature, "KeyInfo")
+        .and_then(|key_info| required_child(key_info, "X509Data"))
+        .and_then(|x509_data| required_child(x509_data, "X509Certificate"))?;
+    let certificate = decode_base64(certificate)?;
+    let (_, x509_certificate) = X509Certificate::from_der(&certificate).map_err(XmlSignatureError::Certificate)?;
+    let public_key = x509_certificate.public_key().raw;
+
+    let signature_value = decode_base64(required_child(signature, "SignatureValue")?)?;
+    let signed_octets = signed_info.canonicalize(&signed_info_canonicalization, None);
+
+    if signature_method == RSA_SHA256 {
+        let public_key = RsaPublicKey::from_public_key_der(public_key).map_err(XmlSignatureError::RsaPublicKey)?;
+
+        public_key
+            .verify(pkcs1v15_sha256(), &sha256(signed_octets.as_bytes()), &signature_value)
+            .map_err(XmlSignatureError::RsaSignature)?;
+    } else {
+        let public_key = VerifyingKey::from_public_key_der(public_key).map_err(XmlSignatureError::EcdsaPublicKey)?;
+
+        // XML signatures contain the raw concatenation of r and s, rather than a DER encoded ECDSA signature.
+        let ecdsa_signature = Signature::from_slice(&signature_value).map_err(XmlSignatureError::Signature)?;
+
+        public_key
+            .verify(signed_octets.as_bytes(), &ecdsa_signature)
+            .map_err(XmlSignatureError::Signature)?;
+    }
+
+    Ok(certificate)
+}
+
+/// PKCS #1 v1.5 signature scheme using SHA-256, constructed manually as `Pkcs1v15Sign::new()` requires the `oid`
+/// feature of `sha2`.
+fn pkcs1v15_sha256() -> Pkcs1v15Sign {
+    Pkcs1v15Sign {
+        hash_len: Some(32),
+        prefix: Box::new(SHA256_DIGEST_INFO_PREFIX),
+    }
+}
+
+fn required_child<'a>(element: &'a Element, local_name: &'static str) -> Result<&'a Element, XmlSignatureError> {
+    element
+        .child(DSIG_NAMESPACE, local_name)
+        .ok_or(XmlSignatureError::MissingElement(local_name))
+}
+
+fn algorithm(element: &Element) -> Result<&str, XmlSignatureError> {
+    element
+        .attribute("Algorithm")
+        .ok_or(XmlSignatureError::MissingAttribute("Algorithm"))
+}
+
+fn canonicalization(element: &Element) -> Result<Canonicalization, XmlSignatureError> {
+    match algorithm(element)? {
+        INCLUSIVE_C14N => Ok(Canonicalization::Inclusive),
+        EXCLUSIVE_C14N => {
+            let inclusive_prefixes = element
+                .child(EXCLUSIVE_C14N, "InclusiveNamespaces")
+                .and_then(|inclusive_namespaces| inclusive_namespaces.attribute("PrefixList"))
+                .map(|prefix_list| {
+                    prefix_list
+                        .split_ascii_whitespace()
+                        .map(|prefix| match prefix {
+                            "#default" => String::new(),
+                            prefix => prefix.to_owned(),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            Ok(Canonicalization::Exclusive { inclusive_prefixes })
+        }
+        algorithm => Err(XmlSignatureError::UnsupportedAlgorithm(algorithm.to_owned())),
+    }
+}
+
+/// XAdES and ETSI trusted lists use the `Id` attribute, but other spellings occur in practice as well.
+fn element_id(element: &Element) -> Option<&str> {
+    element
+        .attribute("Id")
+        .or_else(|| element.attribute("ID"))
+        .or_else(|| element.attribute("id"))
+}
+
+/// Decode the base64 content of an element, which may be wrapped over multiple lines.
+fn decode_base64(element: &Element) -> Result<Vec<u8>, XmlSignatureError> {
+    let text: String = element.text().split_ascii_whitespace().collect();
+    let bytes = BASE64_STANDARD.decode(text)?;
+
+    Ok(bytes)
+}
+
+#[cfg(any(test, feature = "mock"))]
+pub(super) mod mock {
+    use base64::Engine;
+    use base64::prelude::BASE64_STANDARD;
+    use p256::ecdsa::Signature;
+    use p256::ecdsa::signature::Signer;
+
+    use super::DSIG_NAMESPACE;
+    use super::ECDSA_SHA256;
+    use super::ENVELOPED_SIGNATURE_TRANSFORM;
+    use super::EXCLUSIVE_C14N;
+    use super::SHA256;
+    use crate::server_keys::KeyPair;
+    use crate::trusted_list::xml::Canonicalization;
+    use crate::trusted_list::xml::Document;
+    use crate::utils::sha256;
+
+    /// Add an enveloped ECDSA signature over the entire document to `xml`, as the last child of its root element.
+    pub fn sign_enveloped(xml: &str, key_pair: &KeyPair) -> String {
+        sign_enveloped_with(xml, ECDSA_SHA256, key_pair.certificate().as_ref(), |signed_info| {
+            let signature: Signature = key_pair.private_key().sign(signed_info);
+            signature.to_bytes().to_vec()
+        })
+    }
+
+    /// Add an enveloped signature over the entire document to `xml`, as the last child of its root element, using the
+    /// specified signature method. The `sign` closure receives the canonicalized `SignedInfo` element.
+    pub fn sign_enveloped_with(
+        xml: &str,
+        signature_method: &str,
+        certificate: &[u8],
+        sign: impl FnOnce(&[u8]) -> Vec<u8>,
+    ) -> String {
+        let canonicalization = Canonicalization::Exclusive {
+            inclusive_prefixes: vec![],
+        };
+
+        // As the signature is inserted without any surrounding whitespace, the document excluding the signature
+        // canonicalizes to exactly the same output as the unsigned document.
+        let document = Document::parse(xml).expect("XML should be valid");
+        let digest = sha256(document.canonicalize(&canonicalization, None).as_bytes());
+
+        let signed_info = format!(
+            "<ds:SignedInfo><ds:CanonicalizationMethod \
+             Algorithm=\"{EXCLUSIVE_C14N}\"></ds:CanonicalizationMethod><ds:SignatureMethod \
+             Algorithm=\"{signature_method}\"></ds:SignatureMethod><ds:Reference \
+             URI=\"\"><ds:Transforms><ds:Transform \
+             Algorithm=\"{ENVELOPED_SIGNATURE_TRANSFORM}\"></ds:Transform><ds:Transform \
+             Algorithm=\"{EXCLUSIVE_C14N}\"></ds:Transform></ds:Transforms><ds:DigestMethod \
+             Algorithm=\"{SHA256}\"></ds:DigestMethod><ds:DigestValue>{}</ds:DigestValue></ds:Reference></ds:\
+             SignedInfo>",
+            BASE64_STANDARD.encode(digest)
+        );
+
+        // Exclusive canonicalization of the `SignedInfo` element only depends on the `ds` prefix, which is declared
+        // on the `Signature` element, so it can be canonicalized on its own by declaring that prefix.
+        let signed_info_document = Document::parse(&signed_info.replacen(
+            "<ds:SignedInfo>",
+            &format!("<ds:SignedInfo xmlns:ds=\"{DSIG_NAMESPACE}\">"),
+            1,
+        ))
+        .expect("SignedInfo should be valid");
+        let signature = sign(signed_info_document.canonicalize(&canonicalization, None).as_bytes());
+
+        let signature = format!(
+            "<ds:Signature \
+             xmlns:ds=\"{DSIG_NAMESPACE}\">{signed_info}<ds:SignatureValue>{}</ds:SignatureValue><ds:KeyInfo><ds:\
+             X509Data><ds:X509Certificate>{}</ds:X509Certificate></ds:X509Data></ds:KeyInfo></ds:Signature>",
+            BASE64_STANDARD.encode(signature),
+            BASE64_STANDARD.encode(certificate),
+        );
+
+        let index = xml.rfind("</").expect("XML should have a root element with an end tag");
+        let mut signed = xml.to_owned();
+        signed.insert_str(index, &signature);
+
+        signed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use rcgen::CertificateParams;
+    use rcgen::PKCS_RSA_SHA256;
+    use rcgen::RsaKeySize;
+    use rsa::RsaPrivateKey;
+    use rsa::pkcs8::DecodePrivateKey;
+    use rstest::rstest;
+
+    use super::mock::sign_enveloped;
+    use super::mock::sign_enveloped_with;
+    use super::*;
+    use crate::server_keys::generate::Ca;
+
+    const XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<list xmlns="urn:test" Id="list">
+    <item>first &amp; only</item>
+</list>"#;
+
+    #[test]
+    fn test_verify_enveloped_signature() {
+        let ca = Ca::generate_mock();
+        let key_pair = ca.generate_wrpac_verifier_mock().unwrap();
+
+        let signed = sign_enveloped(XML, &key_pair);
+        let document = Document::parse(&signed).unwrap();
+
+        let certificate = verify_enveloped_signature(&document).unwrap();
+        assert_eq!(certificate.as_slice(), key_pair.certificate().as_ref());
+    }
+
+    #[test]
+    fn test_verify_enveloped_signature_rsa() {
+        let key_pair = rcgen::KeyPair::generate_rsa_for(&PKCS_RSA_SHA256, RsaKeySize::_2048).unwrap();
+        let certificate = CertificateParams::default().self_signed(&key_pair).unwrap();
+        let private_key = RsaPrivateKey::from_pkcs8_der(&key_pair.serialize_der()).unwrap();
+
+        let signed = sign_enveloped_with(XML, RSA_SHA256, certificate.der(), |signed_info| {
+            private_key.sign(pkcs1v15_sha256(), &sha256(signed_info)).unwrap()
+        });
+        let document = Document::parse(&signed).unwrap();
+
+        let verified_certificate = verify_enveloped_signature(&document).unwrap();
+        assert_eq!(verified_certificate.as_slice(), certificate.der().as_ref());
+
+        let document = Document::parse(&signed.replace("<item>first", "<item>second")).unwrap();
+        let error = verify_enveloped_signature(&document).expect_err("verification should fail");
+        assert_matches!(error, XmlSignatureError::DigestMismatch(_));
+
+        // An RSA signature should not be accepted when the signature method claims ECDSA.
+        let document = Document::parse(&signed.replace(RSA_SHA256, ECDSA_SHA256)).unwrap();
+        let _ = verify_enveloped_signature(&document).expect_err("verification should fail");
+    }
+
+    #[rstest]
+    #[case::modified_content(|xml: String| xml.replace("<item>first", "<item>second"))]
+    #[case::modified_signed_info(|xml: String| xml.replace("<ds:Reference URI=\"\">", "<ds:Reference URI=\"#list\">"))]
+    #[case::unsupported_reference(|xml: String| xml.replace("<ds:Reference URI=\"\">", "<ds:Reference URI=\"https://example.com\">"))]
+    #[case::unsupported_algorithm(|xml: String| xml.replace(SHA256, "http://www.w3.org/2000/09/xmldsig#sha1"))]
+    #[case::missing_signature(|xml: String| xml.split("<ds:Signature").next().unwrap().to_owned() + "</list>")]
+    fn test_verify_enveloped_signature_error(#[case] modify: fn(String) -> String) {
+        let ca = Ca::generate_mock();
+        let key_pair = ca.generate_wrpac_verifier_mock().unwrap();
+
+        let signed = modify(sign_enveloped(XML, &key_pair));
+        let document = Document::parse(&signed).unwrap();
+
+        let _ = verify_enveloped_signature(&document).expect_err("verification should fail");
+    }
+
+    #[test]
+    fn test_verify_enveloped_signature_digest_mismatch() {
+        let ca = Ca::generate_mock();
+        let key_pair = ca.generate_wrpac_verifier_mock().unwrap();
+
+        let signed = sign_enveloped(XML, &key_pair).replace("&amp; only", "&amp; one");
+        let document = Document::parse(&signed).unwrap();
+
+        let error = verify_enveloped_signature(&document).expect_err("verification should fail");
+        assert_matches!(error, XmlSignatureError::DigestMismatch(uri) if uri.is_empty());
+    }
+}
@@ -25,6 +25,11 @@ pub fn random_string(len: usize) -> String {
     Alphanumeric.sample_string(&mut rand::thread_rng(), len)
 }
 
+pub fn random_digits(len: usize) -> String {
+    let mut rng = rand::thread_rng();
+    (0..len).map(|_| char::from(b'0' + rng.gen_range(0..10))).collect()
+}
+
 pub fn sha256(bts: &[u8]) -> Vec<u8> {
     Sha256::digest(bts).to_vec()
 }
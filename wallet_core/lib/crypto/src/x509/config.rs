@@ -13,6 +13,10 @@ pub struct CertificateConfiguration {
     /// TODO: PVW-5895 Remove when IssuerRegistration are removed
     pub extension: Option<rcgen::CustomExtension>,
     pub crl_distribution_points: Vec<Url>,
+    pub ocsp_responders: Vec<Url>,
+    /// Big-endian serial number of the certificate. When not set, it is derived from the public key of the
+    /// certificate, so that certificates for the same public key share a serial number.
+    pub serial_number: Option<Vec<u8>>,
 }
 
 impl CertificateConfiguration {
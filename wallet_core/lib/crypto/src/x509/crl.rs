@@ -96,6 +96,7 @@ impl Expiry<Url, Arc<CachedCrl>> for CrlExpiry {
 /// The cache TTL for each entry is derived from the CRL's `nextUpdate` field so entries are refreshed automatically
 /// when the CRL expires. A freshly-fetched CRL is only committed to the cache once it has been used in a successful
 /// `verify_chain` call (i.e. its signature has been checked by `rustls-webpki`).
+#[derive(Clone)]
 pub struct CrlProvider {
     client: Client,
     cache: Cache<Url, Arc<CachedCrl>>,
@@ -134,13 +135,19 @@ impl CrlProvider {
         leaf.verify(usage, intermediate_certs, time, trust_anchors, Some(crls.as_slice()))
             .map_err(|error| CrlProviderError::Verification(Box::new(error)))?;
 
-        // Commit any freshly-fetched CRLs to the cache after successful verification.
+        self.commit_verified(crls).await;
+
+        Ok(())
+    }
+
+    /// Commit any freshly-fetched CRLs to the cache, which should only be called after they have been used in a
+    /// successful verification of a certificate chain.
+    pub(super) async fn commit_verified(&self, crls: Vec<FetchedCrl>) {
         for fetched in crls {
             if let FetchedCrl::Fresh { url, fetched: cached } = fetched {
                 self.cache.insert(url, cached).await;
             }
         }
-        Ok(())
     }
 
     /// Fetch all CRLs referenced in the certificate's CDP extension, either from cache or the network.
@@ -60,6 +60,8 @@ mod config;
 pub mod crl;
 mod dn;
 mod key_identifier;
+pub mod ocsp;
+pub mod revocation;
 #[cfg(any(test, feature = "generate"))]
 mod san;
 mod usage;
@@ -219,6 +221,28 @@ impl BorrowingCertificate {
         time: &impl Generator<DateTime<Utc>>,
         trust_anchors: &TrustAnchors,
         crls: Option<&[FetchedCrl]>,
+    ) -> Result<(), CertificateError> {
+        self.verify_with_status_policy(
+            usage,
+            intermediate_certs,
+            time,
+            trust_anchors,
+            crls,
+            UnknownStatusPolicy::Deny,
+        )
+    }
+
+    /// Verify the certificate like [`Self::verify`], using `status_policy` for the certificates in the chain of which
+    /// the revocation status is not determined by any of the `crls`.
+    #[expect(clippy::too_many_arguments, reason = "Arguments needed for verification")]
+    pub(crate) fn verify_with_status_policy(
+        &self,
+        usage: Option<CertificateUsage>,
+        intermediate_certs: &[BorrowingCertificate],
+        time: &impl Generator<DateTime<Utc>>,
+        trust_anchors: &TrustAnchors,
+        crls: Option<&[FetchedCrl]>,
+        status_policy: UnknownStatusPolicy,
     ) -> Result<(), CertificateError> {
         let chain = once(self).chain(intermediate_certs).collect_vec();
 
@@ -248,7 +272,7 @@ impl BorrowingCertificate {
                 .expect("crl_refs is non-empty, checked above")
                 .with_depth(RevocationCheckDepth::Chain)
                 .with_expiration_policy(ExpirationPolicy::Enforce)
-                .with_status_policy(UnknownStatusPolicy::Deny)
+                .with_status_policy(status_policy)
                 .build()
         });
 
@@ -266,6 +290,15 @@ impl BorrowingCertificate {
             .map_err(|error| CertificateError::Verification(Box::new(error)))
     }
 
+    /// Returns whether this certificate was issued by `issuer`, i.e. whether its issuer name matches the subject of
+    /// `issuer` and its signature verifies against the public key of `issuer`.
+    pub fn is_issued_by(&self, issuer: &BorrowingCertificate) -> bool {
+        let certificate = self.x509_certificate();
+        let issuer = issuer.x509_certificate();
+
+        certificate.issuer() == issuer.subject() && certificate.verify_signature(Some(issuer.public_key())).is_ok()
+    }
+
     pub fn end_entity_certificate(&self) -> &EndEntityCert<'_> {
         &self.0.get().end_entity_cert
     }
@@ -359,6 +392,26 @@ impl BorrowingCertificate {
         Ok(san_https_uris)
     }
 
+    /// Returns the SAN DNS names from the certificate, which may be empty.
+    pub fn san_dns_names(&self) -> Result<Vec<&str>, CertificateError> {
+        let san_ext = self
+            .x509_certificate()
+            .subject_alternative_name()?
+            .ok_or(CertificateError::MissingSan)?;
+
+        let san_dns_names = san_ext
+            .value
+            .general_names
+            .iter()
+            .filter_map(|name| match name {
+                GeneralName::DNSName(name) => Some(*name),
+                _ => None,
+            })
+            .collect();
+
+        Ok(san_dns_names)
+    }
+
     /// From the AuthorityKeyIdentifier in the certificate, if present, return the key identifier field:
     /// the hash over the public key that signed this certificate.
     pub fn authority_key_id(&self) -> Option<KeyIdentifier> {
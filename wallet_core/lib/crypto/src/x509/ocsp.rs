@@ -0,0 +1,1081 @@
+use std::iter::once;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use chrono::DateTime;
+use chrono::Utc;
+use der::Decode;
+use der::Encode;
+use der::Enumerated;
+use der::Sequence;
+use der::Tag;
+use der::Tagged;
+use der::asn1::Any;
+use der::asn1::AnyRef;
+use der::asn1::BitStringRef;
+use der::asn1::GeneralizedTime;
+use der::asn1::ObjectIdentifier;
+use der::asn1::OctetString;
+use der::asn1::OctetStringRef;
+use der::asn1::Uint;
+use http_utils::reqwest::bytes_with_max_response_size;
+use itertools::Itertools;
+use moka::Expiry;
+use moka::future::Cache;
+use p256::ecdsa::signature::hazmat::PrehashVerifier;
+use reqwest::Client;
+use reqwest::header::CONTENT_TYPE;
+use ring::digest;
+use url::Url;
+use utils::generator::Generator;
+use utils::vec_at_least::VecNonEmpty;
+use x509_parser::extensions::GeneralName;
+use x509_parser::extensions::ParsedExtension;
+use x509_parser::oid_registry::OID_PKIX_ACCESS_DESCRIPTOR_OCSP;
+
+use crate::p256_der::DerSignature;
+use crate::x509::BorrowingCertificate;
+
+#[derive(Debug, thiserror::Error)]
+pub enum OcspError {
+    #[error("HTTP error fetching OCSP response: {0}")]
+    Http(#[source] reqwest::Error),
+    #[error("OCSP response exceeds maximum size of {MAX_OCSP_RESPONSE_SIZE} bytes")]
+    TooLarge,
+    #[error("Invalid URL: {0}")]
+    InvalidUrl(#[source] url::ParseError),
+    #[error("no OCSP responder available for certificate")]
+    NoResponder,
+    #[error("OCSP request encoding error: {0}")]
+    Encoding(#[source] der::Error),
+    #[error("OCSP response parsing error: {0}")]
+    Parsing(#[source] der::Error),
+    #[error("OCSP responder returned unsuccessful response status: {0:?}")]
+    ResponseStatus(OcspResponseStatus),
+    #[error("OCSP response does not contain response bytes")]
+    MissingResponseBytes,
+    #[error("unsupported OCSP response type: {0}")]
+    UnsupportedResponseType(ObjectIdentifier),
+    #[error("unsupported OCSP response signature algorithm: {0}")]
+    UnsupportedSignatureAlgorithm(ObjectIdentifier),
+    #[error("OCSP response signature is invalid")]
+    InvalidSignature,
+    #[error("OCSP response does not contain the status of the certificate")]
+    MissingCertificateStatus,
+    #[error("OCSP response is not valid at the time of verification")]
+    Outdated,
+}
+
+/// `id-sha1` (RFC 3279, 2.2.1), the hash algorithm that OCSP responders are required to support for `CertID`.
+const OID_SHA1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.14.3.2.26");
+
+/// `id-sha256` (RFC 5754, 2), which may also be used for `CertID`.
+const OID_SHA256: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.1");
+
+/// `ecdsa-with-SHA256` (RFC 5758, 3.2), in line with the signature algorithm of the certificates.
+const OID_ECDSA_WITH_SHA256: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.2");
+
+/// `ecdsa-with-SHA384` (RFC 5758, 3.2), which some OCSP responders use regardless of the algorithm of the certificates.
+/// Any other signature algorithm is rejected with [`OcspError::UnsupportedSignatureAlgorithm`].
+const OID_ECDSA_WITH_SHA384: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.3");
+
+/// `id-pkix-ocsp-basic` (RFC 6960, 4.2.1), the only response type OCSP responders are required to support.
+const OID_PKIX_OCSP_BASIC: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.5.5.7.48.1.1");
+
+const OCSP_REQUEST_MIME_TYPE: &str = "application/ocsp-request";
+
+/// Upper bound on the cache TTL derived from an OCSP response's `nextUpdate` field.
+const MAX_TTL: Duration = Duration::from_hours(7 * 24);
+
+/// Tolerance for the `thisUpdate` field of an OCSP response lying in the future, as responses are typically produced
+/// on demand and the clocks of the responder and the verifier may not be exactly in sync.
+const MAX_CLOCK_SKEW: Duration = Duration::from_mins(5);
+
+/// Upper bound on the age of the `thisUpdate` field of an OCSP response without a `nextUpdate` field. Such a response
+/// does not limit its own validity, so without this bound an arbitrarily old response, e.g. a stapled one obtained
+/// before the certificate was revoked, would be accepted.
+const MAX_AGE_WITHOUT_NEXT_UPDATE: Duration = Duration::from_hours(24);
+
+/// Upper bound on the size of a single OCSP response. A response for a single certificate is typically only a few KB,
+/// even when it includes the certificate of a delegated responder.
+const MAX_OCSP_RESPONSE_SIZE: usize = 256 * 1024;
+
+/// Timeout for a single OCSP request, so a hung or slow-drip connection doesn't stall certificate verification.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// `OCSPResponseStatus` (RFC 6960, 4.2.1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enumerated)]
+#[repr(u8)]
+pub enum OcspResponseStatus {
+    Successful = 0,
+    MalformedRequest = 1,
+    InternalError = 2,
+    TryLater = 3,
+    SigRequired = 5,
+    Unauthorized = 6,
+}
+
+/// The revocation status of a single certificate, as reported by an OCSP responder (RFC 6960, 4.2.1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OcspCertStatus {
+    Good,
+    Revoked,
+    Unknown,
+}
+
+/// `AlgorithmIdentifier ::= SEQUENCE { algorithm OBJECT IDENTIFIER, parameters ANY OPTIONAL }` (RFC 5280, 4.1.1.2).
+#[derive(Debug, Clone, Sequence)]
+struct AlgorithmIdentifier {
+    algorithm: ObjectIdentifier,
+    #[asn1(optional = "true")]
+    parameters: Option<Any>,
+}
+
+/// ```text
+/// CertID ::= SEQUENCE {
+///     hashAlgorithm       AlgorithmIdentifier,
+///     issuerNameHash      OCTET STRING,
+///     issuerKeyHash       OCTET STRING,
+///     serialNumber        CertificateSerialNumber }
+/// ```
+/// (RFC 6960, 4.1.1).
+#[derive(Debug, Clone, Sequence)]
+struct CertId {
+    hash_algorithm: AlgorithmIdentifier,
+    issuer_name_hash: OctetString,
+    issuer_key_hash: OctetString,
+    serial_number: Uint,
+}
+
+/// `Request ::= SEQUENCE { reqCert CertID }` (RFC 6960, 4.1.1), leaving out the OPTIONAL extensions.
+#[derive(Sequence)]
+struct Request {
+    req_cert: CertId,
+}
+
+/// `TBSRequest ::= SEQUENCE { requestList SEQUENCE OF Request }` (RFC 6960, 4.1.1), leaving out the version, which
+/// has a default, and the OPTIONAL requestor name and extensions.
+#[derive(Sequence)]
+struct TbsRequest {
+    request_list: Vec<Request>,
+}
+
+/// `OCSPRequest ::= SEQUENCE { tbsRequest TBSRequest }` (RFC 6960, 4.1.1), leaving out the OPTIONAL signature.
+#[derive(Sequence)]
+struct OcspRequest {
+    tbs_request: TbsRequest,
+}
+
+/// ```text
+/// OCSPResponse ::= SEQUENCE {
+///     responseStatus         OCSPResponseStatus,
+///     responseBytes          [0] EXPLICIT ResponseBytes OPTIONAL }
+/// ```
+/// (RFC 6960, 4.2.1).
+#[derive(Sequence)]
+struct OcspResponse<'a> {
+    response_status: OcspResponseStatus,
+    #[asn1(context_specific = "0", optional = "true")]
+    response_bytes: Option<ResponseBytes<'a>>,
+}
+
+/// `ResponseBytes ::= SEQUENCE { responseType OBJECT IDENTIFIER, response OCTET STRING }` (RFC 6960, 4.2.1).
+#[derive(Sequence)]
+struct ResponseBytes<'a> {
+    response_type: ObjectIdentifier,
+    response: OctetStringRef<'a>,
+}
+
+/// ```text
+/// BasicOCSPResponse ::= SEQUENCE {
+///     tbsResponseData      ResponseData,
+///     signatureAlgorithm   AlgorithmIdentifier,
+///     signature            BIT STRING,
+///     certs            [0] EXPLICIT SEQUENCE OF Certificate OPTIONAL }
+/// ```
+/// (RFC 6960, 4.2.1). The `tbsResponseData` and certificates are kept as raw DER, as the former is needed to verify
+/// the signature and the latter are parsed as [`BorrowingCertificate`].
+#[derive(Sequence)]
+struct BasicOcspResponse<'a> {
+    tbs_response_data: AnyRef<'a>,
+    signature_algorithm: AlgorithmIdentifier,
+    signature: BitStringRef<'a>,
+    #[asn1(context_specific = "0", optional = "true")]
+    certs: Option<Vec<AnyRef<'a>>>,
+}
+
+/// ```text
+/// ResponseData ::= SEQUENCE {
+///     version              [0] EXPLICIT Version DEFAULT v1,
+///     responderID              ResponderID,
+///     producedAt               GeneralizedTime,
+///     responses                SEQUENCE OF SingleResponse,
+///     responseExtensions   [1] EXPLICIT Extensions OPTIONAL }
+/// ```
+/// (RFC 6960, 4.2.1). The `responderID` CHOICE is not interpreted, as the signer is looked up by trying the issuer and
+/// any included delegated responder certificates instead.
+#[derive(Sequence)]
+struct ResponseData<'a> {
+    #[asn1(context_specific = "0", optional = "true")]
+    version: Option<u8>,
+    responder_id: AnyRef<'a>,
+    produced_at: GeneralizedTime,
+    responses: Vec<SingleResponse<'a>>,
+    #[asn1(context_specific = "1", optional = "true")]
+    response_extensions: Option<AnyRef<'a>>,
+}
+
+/// ```text
+/// SingleResponse ::= SEQUENCE {
+///     certID                       CertID,
+///     certStatus                   CertStatus,
+///     thisUpdate                   GeneralizedTime,
+///     nextUpdate         [0]       EXPLICIT GeneralizedTime OPTIONAL,
+///     singleExtensions   [1]       EXPLICIT Extensions OPTIONAL }
+/// ```
+/// (RFC 6960, 4.2.1). The `certStatus` CHOICE is interpreted by its implicit tag only, see [`cert_status`].
+#[derive(Sequence)]
+struct SingleResponse<'a> {
+    cert_id: CertId,
+    cert_status: AnyRef<'a>,
+    this_update: GeneralizedTime,
+    #[asn1(context_specific = "0", optional = "true")]
+    next_update: Option<GeneralizedTime>,
+    #[asn1(context_specific = "1", optional = "true")]
+    single_extensions: Option<AnyRef<'a>>,
+}
+
+/// The values identifying a certificate towards an OCSP responder, which are hashed into a `CertID`.
+struct CertificateId<'a> {
+    issuer_name: &'a [u8],
+    issuer_key: &'a [u8],
+    serial_number: &'a [u8],
+}
+
+impl<'a> CertificateId<'a> {
+    fn new(certificate: &'a BorrowingCertificate, issuer: &'a BorrowingCertificate) -> Self {
+        Self {
+            issuer_name: certificate.x509_certificate().issuer().as_raw(),
+            issuer_key: &issuer.x509_certificate().public_key().subject_public_key.data,
+            serial_number: certificate.x509_certificate().raw_serial(),
+        }
+    }
+
+    fn to_cert_id(&self) -> Result<CertId, der::Error> {
+        let cert_id = CertId {
+            hash_algorithm: AlgorithmIdentifier {
+                algorithm: OID_SHA1,
+                parameters: Some(Any::null()),
+            },
+            issuer_name_hash: OctetString::new(sha1(self.issuer_name))?,
+            issuer_key_hash: OctetString::new(sha1(self.issuer_key))?,
+            serial_number: Uint::new(self.serial_number)?,
+        };
+
+        Ok(cert_id)
+    }
+
+    /// Encode an `OCSPRequest` for this certificate, which is also used as the cache key for its status.
+    fn to_request_der(&self) -> Result<Vec<u8>, der::Error> {
+        OcspRequest {
+            tbs_request: TbsRequest {
+                request_list: vec![Request {
+                    req_cert: self.to_cert_id()?,
+                }],
+            },
+        }
+        .to_der()
+    }
+
+    /// Returns whether `cert_id` identifies this certificate, using the hash algorithm chosen by the responder.
+    fn matches(&self, cert_id: &CertId) -> bool {
+        let algorithm = if cert_id.hash_algorithm.algorithm == OID_SHA1 {
+            &digest::SHA1_FOR_LEGACY_USE_ONLY
+        } else if cert_id.hash_algorithm.algorithm == OID_SHA256 {
+            &digest::SHA256
+        } else {
+            return false;
+        };
+
+        digest::digest(algorithm, self.issuer_name).as_ref() == cert_id.issuer_name_hash.as_bytes()
+            && digest::digest(algorithm, self.issuer_key).as_ref() == cert_id.issuer_key_hash.as_bytes()
+            && Uint::new(self.serial_number).is_ok_and(|serial_number| serial_number == cert_id.serial_number)
+    }
+}
+
+fn sha1(data: &[u8]) -> Vec<u8> {
+    digest::digest(&digest::SHA1_FOR_LEGACY_USE_ONLY, data)
+        .as_ref()
+        .to_vec()
+}
+
+/// A verified certificate status together with its cache TTL.
+#[derive(Debug)]
+struct CachedStatus {
+    status: OcspCertStatus,
+    ttl: Duration,
+}
+
+struct OcspExpiry;
+
+impl Expiry<Vec<u8>, Arc<CachedStatus>> for OcspExpiry {
+    fn expire_after_create(&self, _key: &Vec<u8>, value: &Arc<CachedStatus>, _created_at: Instant) -> Option<Duration> {
+        Some(value.ttl)
+    }
+}
+
+/// Determines the revocation status of certificates using RFC 6960 OCSP responses, caching them per certificate.
+///
+/// The cache TTL for each entry is derived from the response's `nextUpdate` field. Responses without `nextUpdate` are
+/// not cached, as that indicates newer revocation information is available at any time. Unlike CRLs, the signature
+/// of an OCSP response is verified here, so a status is only ever cached after successful verification.
+#[derive(Clone)]
+pub struct OcspProvider {
+    client: Client,
+    cache: Cache<Vec<u8>, Arc<CachedStatus>>,
+}
+
+impl OcspProvider {
+    pub fn new(client: Client, max_capacity: u64) -> Self {
+        let cache = Cache::builder()
+            .max_capacity(max_capacity)
+            .expire_after(OcspExpiry)
+            .build();
+        Self { client, cache }
+    }
+
+    /// Determine the revocation status of `certificate`, which should have been issued by `issuer`.
+    ///
+    /// A valid response for the certificate among the `stapled_responses`, which were obtained along with the
+    /// certificate, takes precedence. Otherwise, the status is served from the cache or requested from the OCSP
+    /// responders in the certificate's Authority Information Access extension.
+    pub async fn cert_status(
+        &self,
+        certificate: &BorrowingCertificate,
+        issuer: &BorrowingCertificate,
+        stapled_responses: &[Vec<u8>],
+        time: &impl Generator<DateTime<Utc>>,
+    ) -> Result<OcspCertStatus, OcspError> {
+        let certificate_id = CertificateId::new(certificate, issuer);
+
+        // Stapled responses that are invalid, or that concern another certificate, are simply ignored.
+        if let Some((status, _)) = stapled_responses
+            .iter()
+            .find_map(|response| parse_and_verify_response(response, &certificate_id, issuer, time).ok())
+        {
+            return Ok(status);
+        }
+
+        let request = certificate_id.to_request_der().map_err(OcspError::Encoding)?;
+        if let Some(cached) = self.cache.get(&request).await {
+            return Ok(cached.status);
+        }
+
+        let urls = extract_ocsp_responders(certificate).ok_or(OcspError::NoResponder)?;
+
+        // Try every responder in turn, returning the error of the last one if none of them gives a valid response.
+        let mut result = Err(OcspError::NoResponder);
+        for url in urls {
+            result = self
+                .query_responder(&url, &request, &certificate_id, issuer, time)
+                .await;
+            if result.is_ok() {
+                break;
+            }
+        }
+        let (status, ttl) = result?;
+
+        if let Some(ttl) = ttl {
+            self.cache.insert(request, Arc::new(CachedStatus { status, ttl })).await;
+        }
+
+        Ok(status)
+    }
+
+    async fn query_responder(
+        &self,
+        url: &str,
+        request: &[u8],
+        certificate_id: &CertificateId<'_>,
+        issuer: &BorrowingCertificate,
+        time: &impl Generator<DateTime<Utc>>,
+    ) -> Result<(OcspCertStatus, Option<Duration>), OcspError> {
+        let url: Url = url.parse().map_err(OcspError::InvalidUrl)?;
+        let mut response = self
+            .client
+            .post(url)
+            .header(CONTENT_TYPE, OCSP_REQUEST_MIME_TYPE)
+            .body(request.to_vec())
+            .timeout(REQUEST_TIMEOUT)
+            .send()
+            .await
+            .map_err(OcspError::Http)?
+            .error_for_status()
+            .map_err(OcspError::Http)?;
+
+        let bytes = bytes_with_max_response_size(&mut response, MAX_OCSP_RESPONSE_SIZE)
+            .await
+            .map_err(OcspError::Http)?
+            .ok_or(OcspError::TooLarge)?;
+
+        parse_and_verify_response(&bytes, certificate_id, issuer, time)
+    }
+}
+
+/// Parse an `OCSPResponse`, verify its signature and validity period and return the status it contains for the
+/// certificate identified by `certificate_id`, together with the time remaining until its `nextUpdate`, if present.
+/// A response without `nextUpdate` is only accepted up to [`MAX_AGE_WITHOUT_NEXT_UPDATE`] after its `thisUpdate`.
+fn parse_and_verify_response(
+    response: &[u8],
+    certificate_id: &CertificateId,
+    issuer: &BorrowingCertificate,
+    time: &impl Generator<DateTime<Utc>>,
+) -> Result<(OcspCertStatus, Option<Duration>), OcspError> {
+    let response = OcspResponse::from_der(response).map_err(OcspError::Parsing)?;
+    if response.response_status != OcspResponseStatus::Successful {
+        return Err(OcspError::ResponseStatus(response.response_status));
+    }
+
+    let response_bytes = response.response_bytes.ok_or(OcspError::MissingResponseBytes)?;
+    if response_bytes.response_type != OID_PKIX_OCSP_BASIC {
+        return Err(OcspError::UnsupportedResponseType(response_bytes.response_type));
+    }
+
+    let basic_response = BasicOcspResponse::from_der(response_bytes.response.as_bytes()).map_err(OcspError::Parsing)?;
+    let tbs_response_data = basic_response.tbs_response_data.to_der().map_err(OcspError::Parsing)?;
+    verify_response_signature(&basic_response, &tbs_response_data, issuer, time)?;
+
+    let response_data = ResponseData::from_der(&tbs_response_data).map_err(OcspError::Parsing)?;
+    let single_response = response_data
+        .responses
+        .iter()
+        .find(|single_response| certificate_id.matches(&single_response.cert_id))
+        .ok_or(OcspError::MissingCertificateStatus)?;
+
+    let now = time.generate().timestamp();
+    let this_update = unix_timestamp(single_response.this_update);
+    let next_update = single_response.next_update.map(unix_timestamp);
+    let is_expired = match next_update {
+        Some(next_update) => next_update < now,
+        None => this_update < now - MAX_AGE_WITHOUT_NEXT_UPDATE.as_secs() as i64,
+    };
+    if this_update > now + MAX_CLOCK_SKEW.as_secs() as i64 || is_expired {
+        return Err(OcspError::Outdated);
+    }
+
+    let status = cert_status(single_response.cert_status)?;
+    let ttl = next_update.map(|next_update| Duration::from_secs((next_update - now) as u64).min(MAX_TTL));
+
+    Ok((status, ttl))
+}
+
+/// Verify the signature of a `BasicOCSPResponse`, which should be made either by the issuer of the certificate, or by
+/// a responder to which the issuer delegated this by issuing it a certificate with the `id-kp-OCSPSigning` extended
+/// key usage (RFC 6960, 4.2.2.2).
+fn verify_response_signature(
+    basic_response: &BasicOcspResponse,
+    tbs_response_data: &[u8],
+    issuer: &BorrowingCertificate,
+    time: &impl Generator<DateTime<Utc>>,
+) -> Result<(), OcspError> {
+    let algorithm = basic_response.signature_algorithm.algorithm;
+    let digest_algorithm = if algorithm == OID_ECDSA_WITH_SHA256 {
+        &digest::SHA256
+    } else if algorithm == OID_ECDSA_WITH_SHA384 {
+        &digest::SHA384
+    } else {
+        return Err(OcspError::UnsupportedSignatureAlgorithm(algorithm));
+    };
+    let prehash = digest::digest(digest_algorithm, tbs_response_data);
+
+    let signature = basic_response
+        .signature
+        .as_bytes()
+        .and_then(|signature| DerSignature::try_from(signature.to_vec()).ok())
+        .ok_or(OcspError::InvalidSignature)?;
+
+    let delegated_responders = basic_response
+        .certs
+        .iter()
+        .flatten()
+        .filter_map(|certificate| {
+            let der = certificate.to_der().ok()?;
+            BorrowingCertificate::from_der(der).ok()
+        })
+        .filter(|responder| is_authorized_responder(responder, issuer, time))
+        .collect_vec();
+
+    let is_valid = once(issuer).chain(&delegated_responders).any(|signer| {
+        signer
+            .public_key()
+            .verify_prehash(prehash.as_ref(), signature.as_inner())
+            .is_ok()
+    });
+
+    if !is_valid {
+        return Err(OcspError::InvalidSignature);
+    }
+
+    Ok(())
+}
+
+fn is_authorized_responder(
+    responder: &BorrowingCertificate,
+    issuer: &BorrowingCertificate,
+    time: &impl Generator<DateTime<Utc>>,
+) -> bool {
+    let now = time.generate().timestamp();
+    let x509_certificate = responder.x509_certificate();
+    let validity = x509_certificate.validity();
+
+    responder.is_issued_by(issuer)
+        && matches!(x509_certificate.extended_key_usage(), Ok(Some(eku)) if eku.value.ocsp_signing)
+        && validity.not_before.timestamp() <= now
+        && now <= validity.not_after.timestamp()
+}
+
+/// Interpret the `CertStatus` CHOICE (RFC 6960, 4.2.1) by its implicit context specific tag:
+///
+/// ```text
+/// CertStatus ::= CHOICE {
+///     good        [0]     IMPLICIT NULL,
+///     revoked     [1]     IMPLICIT RevokedInfo,
+///     unknown     [2]     IMPLICIT UnknownInfo }
+/// ```
+fn cert_status(cert_status: AnyRef) -> Result<OcspCertStatus, OcspError> {
+    match cert_status.tag() {
+        Tag::ContextSpecific { number, .. } if number.value() == 0 => Ok(OcspCertStatus::Good),
+        Tag::ContextSpecific { number, .. } if number.value() == 1 => Ok(OcspCertStatus::Revoked),
+        Tag::ContextSpecific { number, .. } if number.value() == 2 => Ok(OcspCertStatus::Unknown),
+        tag => Err(OcspError::Parsing(tag.value_error())),
+    }
+}
+
+fn unix_timestamp(time: GeneralizedTime) -> i64 {
+    time.to_unix_duration().as_secs() as i64
+}
+
+/// Extract all HTTP(S) OCSP responder URLs from the certificate's Authority Information Access extension.
+/// See RFC 5280, section 4.2.2.1.
+pub fn extract_ocsp_responders(cert: &BorrowingCertificate) -> Option<VecNonEmpty<String>> {
+    let ocsp_responders = cert
+        .x509_certificate()
+        .extensions()
+        .iter()
+        .filter_map(|ext| {
+            if let ParsedExtension::AuthorityInfoAccess(aia) = ext.parsed_extension() {
+                Some(aia)
+            } else {
+                None
+            }
+        })
+        .flat_map(|aia| aia.iter())
+        .filter(|access_description| access_description.access_method == OID_PKIX_ACCESS_DESCRIPTOR_OCSP)
+        .filter_map(|access_description| match &access_description.access_location {
+            // RFC 5280(4.2.2.1): When the id-ad-ocsp accessMethod is used, the accessLocation field specifies the
+            // location of the OCSP responder, using the conventions defined in [RFC2560].
+            // We only support HTTP via the URI type.
+            GeneralName::URI(uri) => Some(uri.to_string()),
+            _ => None,
+        })
+        .collect_vec();
+
+    VecNonEmpty::try_from(ocsp_responders).ok()
+}
+
+#[cfg(any(test, feature = "mock"))]
+pub mod mock {
+    use der::TagNumber;
+    use p256::ecdsa::Signature;
+    use p256::ecdsa::SigningKey;
+    use p256::ecdsa::signature::hazmat::PrehashSigner;
+
+    use super::*;
+
+    impl OcspProvider {
+        pub fn new_without_caching(client: Client) -> Self {
+            Self {
+                client,
+                cache: Cache::builder().max_capacity(0).build(),
+            }
+        }
+    }
+
+    fn generalized_time(time: DateTime<Utc>) -> GeneralizedTime {
+        GeneralizedTime::from_unix_duration(Duration::from_secs(time.timestamp() as u64)).unwrap()
+    }
+
+    /// Create a DER encoded `OCSPResponse` containing the `status` of `certificate`, which should have been issued by
+    /// `issuer`, signed with `signing_key` using `ecdsa-with-SHA256`.
+    pub fn ocsp_response(
+        certificate: &BorrowingCertificate,
+        issuer: &BorrowingCertificate,
+        status: OcspCertStatus,
+        this_update: DateTime<Utc>,
+        next_update: Option<DateTime<Utc>>,
+        signing_key: &SigningKey,
+    ) -> Vec<u8> {
+        ocsp_response_with_signature_algorithm(
+            certificate,
+            issuer,
+            status,
+            this_update,
+            next_update,
+            signing_key,
+            OID_ECDSA_WITH_SHA256,
+        )
+    }
+
+    /// Same as [`ocsp_response`], but signed using `signature_algorithm`. The response is signed over a SHA-384 digest
+    /// for `ecdsa-with-SHA384` and over a SHA-256 digest for any other algorithm.
+    #[expect(clippy::too_many_arguments, reason = "Mock constructor")]
+    pub fn ocsp_response_with_signature_algorithm(
+        certificate: &BorrowingCertificate,
+        issuer: &BorrowingCertificate,
+        status: OcspCertStatus,
+        this_update: DateTime<Utc>,
+        next_update: Option<DateTime<Utc>>,
+        signing_key: &SigningKey,
+        signature_algorithm: ObjectIdentifier,
+    ) -> Vec<u8> {
+        let certificate_id = CertificateId::new(certificate, issuer);
+
+        let revoked_info = generalized_time(this_update).to_der().unwrap();
+        let cert_status = match status {
+            OcspCertStatus::Good => AnyRef::new(
+                Tag::ContextSpecific {
+                    constructed: false,
+                    number: TagNumber::N0,
+                },
+                &[],
+            ),
+            OcspCertStatus::Revoked => AnyRef::new(
+                Tag::ContextSpecific {
+                    constructed: true,
+                    number: TagNumber::N1,
+                },
+                &revoked_info,
+            ),
+            OcspCertStatus::Unknown => AnyRef::new(
+                Tag::ContextSpecific {
+                    constructed: false,
+                    number: TagNumber::N2,
+                },
+                &[],
+            ),
+        }
+        .unwrap();
+
+        let key_hash = OctetString::new(sha1(certificate_id.issuer_key))
+            .unwrap()
+            .to_der()
+            .unwrap();
+        let responder_id = AnyRef::new(
+            Tag::ContextSpecific {
+                constructed: true,
+                number: TagNumber::N2,
+            },
+            &key_hash,
+        )
+        .unwrap();
+
+        let tbs_response_data = ResponseData {
+            version: None,
+            responder_id,
+            produced_at: generalized_time(this_update),
+            responses: vec![SingleResponse {
+                cert_id: certificate_id.to_cert_id().unwrap(),
+                cert_status,
+                this_update: generalized_time(this_update),
+                next_update: next_update.map(generalized_time),
+                single_extensions: None,
+            }],
+            response_extensions: None,
+        }
+        .to_der()
+        .unwrap();
+
+        let digest_algorithm = if signature_algorithm == OID_ECDSA_WITH_SHA384 {
+            &digest::SHA384
+        } else {
+            &digest::SHA256
+        };
+        let prehash = digest::digest(digest_algorithm, &tbs_response_data);
+        let signature: Signature = signing_key.sign_prehash(prehash.as_ref()).unwrap();
+        let signature = DerSignature::from(signature);
+        let basic_response = BasicOcspResponse {
+            tbs_response_data: AnyRef::from_der(&tbs_response_data).unwrap(),
+            signature_algorithm: AlgorithmIdentifier {
+                algorithm: signature_algorithm,
+                parameters: None,
+            },
+            signature: BitStringRef::from_bytes(signature.as_ref()).unwrap(),
+            certs: None,
+        }
+        .to_der()
+        .unwrap();
+
+        OcspResponse {
+            response_status: OcspResponseStatus::Successful,
+            response_bytes: Some(ResponseBytes {
+                response_type: OID_PKIX_OCSP_BASIC,
+                response: OctetStringRef::new(&basic_response).unwrap(),
+            }),
+        }
+        .to_der()
+        .unwrap()
+    }
+
+    /// Create a DER encoded `OCSPResponse` that only contains the unsuccessful `response_status`.
+    pub fn ocsp_error_response(response_status: OcspResponseStatus) -> Vec<u8> {
+        OcspResponse {
+            response_status,
+            response_bytes: None,
+        }
+        .to_der()
+        .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use http_utils::httpmock::httpmock_reqwest_client_builder;
+    use httpmock::Method::POST;
+    use httpmock::MockServer;
+    use rstest::rstest;
+    use url::Url;
+    use utils::generator::TimeGenerator;
+
+    use super::mock::ocsp_error_response;
+    use super::mock::ocsp_response;
+    use super::mock::ocsp_response_with_signature_algorithm;
+    use super::*;
+    use crate::server_keys::generate::Ca;
+    use crate::x509::CertificateConfiguration;
+    use crate::x509::DistinguishedName;
+    use crate::x509::NO_SAN;
+
+    fn ca_and_leaf_with_responders(urls: Vec<Url>) -> (Ca, BorrowingCertificate, BorrowingCertificate) {
+        let ca = Ca::generate_mock();
+        let config = CertificateConfiguration {
+            ocsp_responders: urls,
+            ..Default::default()
+        };
+        let leaf = ca
+            .generate_key_pair(DistinguishedName::create_mock("leaf"), config, NO_SAN)
+            .unwrap();
+        let issuer = ca.as_borrowing_certificate().unwrap();
+        let certificate = leaf.certificate().clone();
+        (ca, issuer, certificate)
+    }
+
+    fn response_for(
+        ca: &Ca,
+        issuer: &BorrowingCertificate,
+        leaf: &BorrowingCertificate,
+        status: OcspCertStatus,
+        next_update: Option<DateTime<Utc>>,
+    ) -> Vec<u8> {
+        ocsp_response(
+            leaf,
+            issuer,
+            status,
+            Utc::now() - Duration::hours(1),
+            next_update,
+            &ca.to_signing_key().unwrap(),
+        )
+    }
+
+    #[test]
+    fn no_ocsp_responders() {
+        let (_, _, leaf) = ca_and_leaf_with_responders(vec![]);
+        assert!(extract_ocsp_responders(&leaf).is_none());
+    }
+
+    #[test]
+    fn multiple_ocsp_responders() {
+        let url1: Url = "http://ocsp1.example.com/".parse().unwrap();
+        let url2: Url = "http://ocsp2.example.com/".parse().unwrap();
+        let (_, _, leaf) = ca_and_leaf_with_responders(vec![url1.clone(), url2.clone()]);
+
+        let responders = extract_ocsp_responders(&leaf).unwrap();
+        assert_eq!(responders.into_inner(), vec![url1.to_string(), url2.to_string()]);
+    }
+
+    #[rstest]
+    #[case::good(OcspCertStatus::Good)]
+    #[case::revoked(OcspCertStatus::Revoked)]
+    #[case::unknown(OcspCertStatus::Unknown)]
+    #[tokio::test]
+    async fn cert_status_returns_status_from_responder(#[case] status: OcspCertStatus) {
+        let server = MockServer::start_async().await;
+        let url: Url = server.url("/ocsp").parse().unwrap();
+        let (ca, issuer, leaf) = ca_and_leaf_with_responders(vec![url]);
+        let response = response_for(&ca, &issuer, &leaf, status, Some(Utc::now() + Duration::days(1)));
+        server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/ocsp")
+                    .header("content-type", OCSP_REQUEST_MIME_TYPE);
+                then.status(200).body(response);
+            })
+            .await;
+
+        let provider = OcspProvider::new(httpmock_reqwest_client_builder().build().unwrap(), 10);
+        let result = provider
+            .cert_status(&leaf, &issuer, &[], &TimeGenerator)
+            .await
+            .expect("OCSP response should be valid");
+
+        assert_eq!(result, status);
+    }
+
+    #[tokio::test]
+    async fn cert_status_caches_response_until_next_update() {
+        let server = MockServer::start_async().await;
+        let url: Url = server.url("/ocsp").parse().unwrap();
+        let (ca, issuer, leaf) = ca_and_leaf_with_responders(vec![url]);
+        let response = response_for(
+            &ca,
+            &issuer,
+            &leaf,
+            OcspCertStatus::Good,
+            Some(Utc::now() + Duration::days(1)),
+        );
+        let mock = server
+            .mock_async(|when, then| {
+                when.method(POST).path("/ocsp");
+                then.status(200).body(response);
+            })
+            .await;
+
+        let provider = OcspProvider::new(httpmock_reqwest_client_builder().build().unwrap(), 10);
+        provider.cert_status(&leaf, &issuer, &[], &TimeGenerator).await.unwrap();
+        provider.cert_status(&leaf, &issuer, &[], &TimeGenerator).await.unwrap();
+
+        // The second call should have been served from the cache
+        mock.assert_calls_async(1).await;
+    }
+
+    #[tokio::test]
+    async fn cert_status_does_not_cache_response_without_next_update() {
+        let server = MockServer::start_async().await;
+        let url: Url = server.url("/ocsp").parse().unwrap();
+        let (ca, issuer, leaf) = ca_and_leaf_with_responders(vec![url]);
+        let response = response_for(&ca, &issuer, &leaf, OcspCertStatus::Good, None);
+        let mock = server
+            .mock_async(|when, then| {
+                when.method(POST).path("/ocsp");
+                then.status(200).body(response);
+            })
+            .await;
+
+        let provider = OcspProvider::new(httpmock_reqwest_client_builder().build().unwrap(), 10);
+        provider.cert_status(&leaf, &issuer, &[], &TimeGenerator).await.unwrap();
+        provider.cert_status(&leaf, &issuer, &[], &TimeGenerator).await.unwrap();
+
+        mock.assert_calls_async(2).await;
+    }
+
+    #[tokio::test]
+    async fn cert_status_prefers_stapled_response() {
+        let (ca, issuer, leaf) = ca_and_leaf_with_responders(vec![]);
+        let other_leaf = ca
+            .generate_key_pair(
+                DistinguishedName::create_mock("other leaf"),
+                CertificateConfiguration::default(),
+                NO_SAN,
+            )
+            .unwrap();
+        let stapled_responses = vec![
+            b"invalid".to_vec(),
+            response_for(&ca, &issuer, other_leaf.certificate(), OcspCertStatus::Good, None),
+            response_for(&ca, &issuer, &leaf, OcspCertStatus::Revoked, None),
+        ];
+
+        let provider = OcspProvider::new_without_caching(httpmock_reqwest_client_builder().build().unwrap());
+        let status = provider
+            .cert_status(&leaf, &issuer, &stapled_responses, &TimeGenerator)
+            .await
+            .expect("stapled OCSP response should be used");
+
+        assert_eq!(status, OcspCertStatus::Revoked);
+    }
+
+    #[tokio::test]
+    async fn cert_status_ignores_old_stapled_response_without_next_update() {
+        let (ca, issuer, leaf) = ca_and_leaf_with_responders(vec![]);
+        let stapled_responses = vec![ocsp_response(
+            &leaf,
+            &issuer,
+            OcspCertStatus::Good,
+            Utc::now() - Duration::days(2),
+            None,
+            &ca.to_signing_key().unwrap(),
+        )];
+
+        let provider = OcspProvider::new_without_caching(httpmock_reqwest_client_builder().build().unwrap());
+        let error = provider
+            .cert_status(&leaf, &issuer, &stapled_responses, &TimeGenerator)
+            .await
+            .expect_err("old stapled OCSP response should not be used");
+
+        assert!(matches!(error, OcspError::NoResponder));
+    }
+
+    #[tokio::test]
+    async fn cert_status_returns_error_without_responder() {
+        let (_, issuer, leaf) = ca_and_leaf_with_responders(vec![]);
+
+        let provider = OcspProvider::new_without_caching(httpmock_reqwest_client_builder().build().unwrap());
+        let error = provider
+            .cert_status(&leaf, &issuer, &[], &TimeGenerator)
+            .await
+            .expect_err("status should not be determined");
+
+        assert!(matches!(error, OcspError::NoResponder));
+    }
+
+    #[tokio::test]
+    async fn cert_status_returns_http_error_on_server_failure() {
+        let server = MockServer::start_async().await;
+        let url: Url = server.url("/ocsp").parse().unwrap();
+        let (_, issuer, leaf) = ca_and_leaf_with_responders(vec![url]);
+        server
+            .mock_async(|when, then| {
+                when.method(POST).path("/ocsp");
+                then.status(500).body("server error");
+            })
+            .await;
+
+        let provider = OcspProvider::new_without_caching(httpmock_reqwest_client_builder().build().unwrap());
+        let error = provider
+            .cert_status(&leaf, &issuer, &[], &TimeGenerator)
+            .await
+            .expect_err("status should not be determined");
+
+        assert!(matches!(error, OcspError::Http(_)));
+    }
+
+    #[tokio::test]
+    async fn cert_status_returns_error_for_unsuccessful_response() {
+        let server = MockServer::start_async().await;
+        let url: Url = server.url("/ocsp").parse().unwrap();
+        let (_, issuer, leaf) = ca_and_leaf_with_responders(vec![url]);
+        server
+            .mock_async(|when, then| {
+                when.method(POST).path("/ocsp");
+                then.status(200).body(ocsp_error_response(OcspResponseStatus::TryLater));
+            })
+            .await;
+
+        let provider = OcspProvider::new_without_caching(httpmock_reqwest_client_builder().build().unwrap());
+        let error = provider
+            .cert_status(&leaf, &issuer, &[], &TimeGenerator)
+            .await
+            .expect_err("status should not be determined");
+
+        assert!(matches!(error, OcspError::ResponseStatus(OcspResponseStatus::TryLater)));
+    }
+
+    #[tokio::test]
+    async fn cert_status_rejects_response_signed_by_other_ca() {
+        let server = MockServer::start_async().await;
+        let url: Url = server.url("/ocsp").parse().unwrap();
+        let (_, issuer, leaf) = ca_and_leaf_with_responders(vec![url]);
+        let other_ca = Ca::generate_mock();
+        let response = response_for(&other_ca, &issuer, &leaf, OcspCertStatus::Good, None);
+        server
+            .mock_async(|when, then| {
+                when.method(POST).path("/ocsp");
+                then.status(200).body(response);
+            })
+            .await;
+
+        let provider = OcspProvider::new_without_caching(httpmock_reqwest_client_builder().build().unwrap());
+        let error = provider
+            .cert_status(&leaf, &issuer, &[], &TimeGenerator)
+            .await
+            .expect_err("OCSP response should not be valid");
+
+        assert!(matches!(error, OcspError::InvalidSignature));
+    }
+
+    #[rstest]
+    #[case::ecdsa_with_sha256(OID_ECDSA_WITH_SHA256, true)]
+    #[case::ecdsa_with_sha384(OID_ECDSA_WITH_SHA384, true)]
+    #[case::ecdsa_with_sha512(ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.4"), false)]
+    #[tokio::test]
+    async fn cert_status_checks_signature_algorithm(
+        #[case] signature_algorithm: ObjectIdentifier,
+        #[case] is_supported: bool,
+    ) {
+        let server = MockServer::start_async().await;
+        let url: Url = server.url("/ocsp").parse().unwrap();
+        let (ca, issuer, leaf) = ca_and_leaf_with_responders(vec![url]);
+        let response = ocsp_response_with_signature_algorithm(
+            &leaf,
+            &issuer,
+            OcspCertStatus::Good,
+            Utc::now() - Duration::hours(1),
+            Some(Utc::now() + Duration::days(1)),
+            &ca.to_signing_key().unwrap(),
+            signature_algorithm,
+        );
+        server
+            .mock_async(|when, then| {
+                when.method(POST).path("/ocsp");
+                then.status(200).body(response);
+            })
+            .await;
+
+        let provider = OcspProvider::new_without_caching(httpmock_reqwest_client_builder().build().unwrap());
+        let result = provider.cert_status(&leaf, &issuer, &[], &TimeGenerator).await;
+
+        if is_supported {
+            assert_eq!(result.expect("OCSP response should be valid"), OcspCertStatus::Good);
+        } else {
+            assert!(matches!(
+                result,
+                Err(OcspError::UnsupportedSignatureAlgorithm(algorithm)) if algorithm == signature_algorithm
+            ));
+        }
+    }
+
+    #[rstest]
+    #[case::next_update_passed(Utc::now() - Duration::hours(1), Some(Utc::now() - Duration::minutes(1)))]
+    #[case::old_without_next_update(Utc::now() - Duration::days(2), None)]
+    #[tokio::test]
+    async fn cert_status_rejects_outdated_response(
+        #[case] this_update: DateTime<Utc>,
+        #[case] next_update: Option<DateTime<Utc>>,
+    ) {
+        let server = MockServer::start_async().await;
+        let url: Url = server.url("/ocsp").parse().unwrap();
+        let (ca, issuer, leaf) = ca_and_leaf_with_responders(vec![url]);
+        let response = ocsp_response(
+            &leaf,
+            &issuer,
+            OcspCertStatus::Good,
+            this_update,
+            next_update,
+            &ca.to_signing_key().unwrap(),
+        );
+        server
+            .mock_async(|when, then| {
+                when.method(POST).path("/ocsp");
+                then.status(200).body(response);
+            })
+            .await;
+
+        let provider = OcspProvider::new_without_caching(httpmock_reqwest_client_builder().build().unwrap());
+        let error = provider
+            .cert_status(&leaf, &issuer, &[], &TimeGenerator)
+            .await
+            .expect_err("OCSP response should not be valid");
+
+        assert!(matches!(error, OcspError::Outdated));
+    }
+}
@@ -0,0 +1,624 @@
+use chrono::DateTime;
+use chrono::Utc;
+use reqwest::Client;
+use serde::Deserialize;
+use serde::Serialize;
+use tracing::warn;
+use utils::generator::Generator;
+use webpki::UnknownStatusPolicy;
+
+use crate::trust_anchor::TrustAnchors;
+use crate::x509::BorrowingCertificate;
+use crate::x509::CertificateError;
+use crate::x509::CertificateUsage;
+use crate::x509::crl::CrlProvider;
+use crate::x509::crl::CrlProviderError;
+use crate::x509::ocsp::OcspCertStatus;
+use crate::x509::ocsp::OcspError;
+use crate::x509::ocsp::OcspProvider;
+
+#[derive(Debug, thiserror::Error)]
+pub enum RevocationError {
+    #[error("certificate chain is empty")]
+    EmptyChain,
+    #[error("certificate verification failed: {0}")]
+    Verification(#[source] Box<CertificateError>),
+    #[error("certificate in chain is revoked")]
+    Revoked,
+    #[error("could not determine revocation status using CRL: {0}")]
+    Crl(#[source] CrlProviderError),
+    #[error("could not determine revocation status using OCSP: {0}")]
+    Ocsp(#[source] OcspError),
+    #[error("OCSP responder does not know the certificate")]
+    OcspUnknown,
+    #[error("could not determine revocation status")]
+    Undetermined,
+}
+
+/// The mechanisms used to determine the revocation status of the certificates in a chain.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RevocationMethod {
+    /// Only use the CRL distribution points of the certificates.
+    #[default]
+    Crl,
+    /// Only use the OCSP responders of the certificates, or stapled OCSP responses.
+    Ocsp,
+    /// Use CRLs where available, falling back to OCSP for certificates of which the CRLs do not determine the status.
+    Both,
+}
+
+impl RevocationMethod {
+    fn uses_crl(self) -> bool {
+        matches!(self, Self::Crl | Self::Both)
+    }
+
+    fn uses_ocsp(self) -> bool {
+        matches!(self, Self::Ocsp | Self::Both)
+    }
+}
+
+/// How to treat certificates of which the revocation status cannot be determined, e.g. because a CRL distribution
+/// point or OCSP responder is unreachable. A certificate that is known to be revoked, or of which the OCSP responder
+/// reports that it does not know it, is always rejected.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RevocationFailureMode {
+    /// Reject the certificate chain.
+    #[default]
+    HardFail,
+    /// Accept the certificate chain, logging a warning.
+    SoftFail,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RevocationPolicy {
+    #[serde(default)]
+    pub method: RevocationMethod,
+    #[serde(default)]
+    pub failure_mode: RevocationFailureMode,
+}
+
+/// Verifies certificate chains including the revocation status of every certificate in the chain, using CRLs and/or
+/// OCSP as configured in its [`RevocationPolicy`]. Clones share the caches of fetched CRLs and OCSP responses.
+#[derive(Clone)]
+pub struct RevocationChecker {
+    policy: RevocationPolicy,
+    crl_provider: CrlProvider,
+    ocsp_provider: OcspProvider,
+}
+
+impl RevocationChecker {
+    pub fn new(policy: RevocationPolicy, crl_provider: CrlProvider, ocsp_provider: OcspProvider) -> Self {
+        Self {
+            policy,
+            crl_provider,
+            ocsp_provider,
+        }
+    }
+
+    pub fn new_with_client(policy: RevocationPolicy, client: Client, max_capacity: u64) -> Self {
+        Self::new(
+            policy,
+            CrlProvider::new(client.clone(), max_capacity),
+            OcspProvider::new(client, max_capacity),
+        )
+    }
+
+    /// Returns a checker that applies `policy` instead, while sharing the caches of this checker.
+    pub fn with_policy(&self, policy: RevocationPolicy) -> Self {
+        Self { policy, ..self.clone() }
+    }
+
+    /// Verify a certificate chain and the revocation status of every certificate in it, except for the trust anchor.
+    ///
+    /// The `stapled_ocsp_responses` are OCSP responses obtained along with the certificate chain, which are used
+    /// instead of querying an OCSP responder for the certificates they concern.
+    pub async fn verify_chain(
+        &self,
+        chain: &[BorrowingCertificate],
+        trust_anchors: &TrustAnchors,
+        usage: Option<CertificateUsage>,
+        stapled_ocsp_responses: &[Vec<u8>],
+        time: &impl Generator<DateTime<Utc>>,
+    ) -> Result<(), RevocationError> {
+        let (leaf, intermediate_certs) = chain.split_first().ok_or(RevocationError::EmptyChain)?;
+
+        // Verify the chain itself first, so that the issuers used to check revocation can be trusted.
+        leaf.verify(usage, intermediate_certs, time, trust_anchors, None)
+            .map_err(|error| RevocationError::Verification(Box::new(error)))?;
+
+        let path = certification_path(leaf, intermediate_certs, trust_anchors);
+
+        // For every certificate in the path, either `Ok(())` if its status has been determined to be not revoked, or
+        // the reason why its status could not be determined (yet).
+        let mut statuses = path
+            .iter()
+            .map(|_| Err(RevocationError::Undetermined))
+            .collect::<Vec<_>>();
+
+        if self.policy.method.uses_crl() {
+            self.check_crls(chain, &path, trust_anchors, usage, time, &mut statuses)
+                .await?;
+        }
+
+        if self.policy.method.uses_ocsp() {
+            for ((certificate, issuer), status) in path.iter().zip(&mut statuses) {
+                if status.is_ok() {
+                    continue;
+                }
+
+                *status = match self
+                    .ocsp_provider
+                    .cert_status(certificate, issuer, stapled_ocsp_responses, time)
+                    .await
+                {
+                    Ok(OcspCertStatus::Good) => Ok(()),
+                    Ok(OcspCertStatus::Revoked) => return Err(RevocationError::Revoked),
+                    // The responder not knowing the certificate is not a failure to determine its status, as it
+                    // may indicate that the certificate was never issued by its purported issuer.
+                    Ok(OcspCertStatus::Unknown) => return Err(RevocationError::OcspUnknown),
+                    Err(error) => Err(RevocationError::Ocsp(error)),
+                };
+            }
+        }
+
+        for status in statuses {
+            if let Err(error) = status {
+                match self.policy.failure_mode {
+                    RevocationFailureMode::HardFail => return Err(error),
+                    RevocationFailureMode::SoftFail => {
+                        warn!("accepting certificate of which the revocation status is undetermined: {error}");
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check the certificates in `path` against the CRLs from their CRL distribution points, updating `statuses` for
+    /// the certificates of which the CRLs are available and do not revoke them.
+    #[expect(clippy::too_many_arguments, reason = "Arguments needed for verification")]
+    async fn check_crls(
+        &self,
+        chain: &[BorrowingCertificate],
+        path: &[(&BorrowingCertificate, &BorrowingCertificate)],
+        trust_anchors: &TrustAnchors,
+        usage: Option<CertificateUsage>,
+        time: &impl Generator<DateTime<Utc>>,
+        statuses: &mut [Result<(), RevocationError>],
+    ) -> Result<(), RevocationError> {
+        let mut crls = Vec::new();
+        let mut covered = Vec::new();
+        for ((certificate, _), status) in path.iter().zip(statuses.iter_mut()) {
+            match self.crl_provider.crls_for_cert(certificate, time).await {
+                Ok(fetched) if fetched.is_empty() => {
+                    *status = Err(RevocationError::Crl(CrlProviderError::NoCrlDistributionPoint));
+                }
+                Ok(fetched) => {
+                    crls.extend(fetched);
+                    covered.push(status);
+                }
+                Err(error) => *status = Err(RevocationError::Crl(error)),
+            }
+        }
+
+        if crls.is_empty() {
+            return Ok(());
+        }
+
+        let (leaf, intermediate_certs) = chain.split_first().ok_or(RevocationError::EmptyChain)?;
+
+        // Certificates without available CRLs are allowed here, as their status may still be determined using OCSP.
+        match leaf.verify_with_status_policy(
+            usage,
+            intermediate_certs,
+            time,
+            trust_anchors,
+            Some(crls.as_slice()),
+            UnknownStatusPolicy::Allow,
+        ) {
+            Ok(()) => {
+                for status in covered {
+                    *status = Ok(());
+                }
+                self.crl_provider.commit_verified(crls).await;
+            }
+            Err(CertificateError::Verification(error)) if matches!(*error, webpki::Error::CertRevoked) => {
+                return Err(RevocationError::Revoked);
+            }
+            Err(error) => {
+                // The CRLs could not be used to determine the status of any certificate, e.g. because one of them
+                // has expired. Only the first certificate reports the actual error, as it cannot be cloned.
+                let mut error = Some(RevocationError::Crl(CrlProviderError::Verification(Box::new(error))));
+                for status in covered {
+                    *status = Err(error.take().unwrap_or(RevocationError::Undetermined));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns each certificate on the path from `leaf` to a trust anchor, paired with the certificate that issued it.
+/// The trust anchor itself is not part of the path, as its revocation status is not checked.
+fn certification_path<'a>(
+    leaf: &'a BorrowingCertificate,
+    intermediate_certs: &'a [BorrowingCertificate],
+    trust_anchors: &'a TrustAnchors,
+) -> Vec<(&'a BorrowingCertificate, &'a BorrowingCertificate)> {
+    let mut path = Vec::new();
+    let mut certificate = leaf;
+
+    // The path can never be longer than the chain, which also protects against cycles of cross-signed certificates.
+    while path.len() <= intermediate_certs.len() {
+        if let Some(trust_anchor) = trust_anchors
+            .certificates()
+            .find(|anchor| certificate.is_issued_by(anchor))
+        {
+            path.push((certificate, trust_anchor));
+            break;
+        }
+
+        let Some(issuer) = intermediate_certs
+            .iter()
+            .find(|issuer| certificate.is_issued_by(issuer))
+        else {
+            break;
+        };
+        path.push((certificate, issuer));
+        certificate = issuer;
+    }
+
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use http_utils::httpmock::httpmock_reqwest_client_builder;
+    use httpmock::Method::GET;
+    use httpmock::Method::POST;
+    use httpmock::MockServer;
+    use rcgen::RevocationReason;
+    use rcgen::RevokedCertParams;
+    use rcgen::SerialNumber;
+    use rstest::rstest;
+    use serde_json::json;
+    use time::OffsetDateTime;
+    use url::Url;
+    use utils::generator::TimeGenerator;
+
+    use super::*;
+    use crate::server_keys::generate::Ca;
+    use crate::x509::CertificateConfiguration;
+    use crate::x509::DistinguishedName;
+    use crate::x509::NO_SAN;
+    use crate::x509::ocsp::mock::ocsp_response;
+
+    fn ca_and_leaf(crl_distribution_points: Vec<Url>, ocsp_responders: Vec<Url>) -> (Ca, BorrowingCertificate) {
+        let ca = Ca::generate_mock();
+        let config = CertificateConfiguration {
+            crl_distribution_points,
+            ocsp_responders,
+            ..Default::default()
+        };
+        let leaf = ca
+            .generate_key_pair(DistinguishedName::create_mock("leaf"), config, NO_SAN)
+            .unwrap();
+        let certificate = leaf.certificate().clone();
+        (ca, certificate)
+    }
+
+    fn checker(method: RevocationMethod, failure_mode: RevocationFailureMode) -> RevocationChecker {
+        RevocationChecker::new_with_client(
+            RevocationPolicy { method, failure_mode },
+            httpmock_reqwest_client_builder().build().unwrap(),
+            10,
+        )
+    }
+
+    fn response_for(ca: &Ca, leaf: &BorrowingCertificate, status: OcspCertStatus) -> Vec<u8> {
+        ocsp_response(
+            leaf,
+            &ca.as_borrowing_certificate().unwrap(),
+            status,
+            Utc::now() - Duration::hours(1),
+            Some(Utc::now() + Duration::days(1)),
+            &ca.to_signing_key().unwrap(),
+        )
+    }
+
+    fn revoked(leaf: &BorrowingCertificate) -> RevokedCertParams {
+        RevokedCertParams {
+            serial_number: SerialNumber::from_slice(leaf.x509_certificate().raw_serial()),
+            revocation_time: OffsetDateTime::now_utc(),
+            reason_code: Some(RevocationReason::KeyCompromise),
+            invalidity_date: None,
+        }
+    }
+
+    #[test]
+    fn deserialize_revocation_policy() {
+        let policy: RevocationPolicy =
+            serde_json::from_value(json!({ "method": "both", "failure_mode": "soft_fail" })).unwrap();
+        assert_eq!(
+            policy,
+            RevocationPolicy {
+                method: RevocationMethod::Both,
+                failure_mode: RevocationFailureMode::SoftFail,
+            }
+        );
+
+        let policy: RevocationPolicy = serde_json::from_value(json!({})).unwrap();
+        assert_eq!(policy, RevocationPolicy::default());
+    }
+
+    #[rstest]
+    #[case::good(OcspCertStatus::Good, RevocationFailureMode::HardFail, true)]
+    #[case::revoked(OcspCertStatus::Revoked, RevocationFailureMode::HardFail, false)]
+    #[case::unknown(OcspCertStatus::Unknown, RevocationFailureMode::HardFail, false)]
+    #[case::revoked_soft_fail(OcspCertStatus::Revoked, RevocationFailureMode::SoftFail, false)]
+    #[case::unknown_soft_fail(OcspCertStatus::Unknown, RevocationFailureMode::SoftFail, false)]
+    #[tokio::test]
+    async fn verify_chain_with_ocsp(
+        #[case] status: OcspCertStatus,
+        #[case] failure_mode: RevocationFailureMode,
+        #[case] should_verify: bool,
+    ) {
+        let server = MockServer::start_async().await;
+        let url: Url = server.url("/ocsp").parse().unwrap();
+        let (ca, leaf) = ca_and_leaf(vec![], vec![url]);
+        server
+            .mock_async(|when, then| {
+                when.method(POST).path("/ocsp");
+                then.status(200).body(response_for(&ca, &leaf, status));
+            })
+            .await;
+
+        let result = checker(RevocationMethod::Ocsp, failure_mode)
+            .verify_chain(&[leaf], &TrustAnchors::from(&ca), None, &[], &TimeGenerator)
+            .await;
+
+        if should_verify {
+            result.expect("certificate should verify");
+        } else {
+            assert!(matches!(
+                result,
+                Err(RevocationError::Revoked | RevocationError::OcspUnknown)
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn verify_chain_with_stapled_ocsp_response() {
+        let (ca, leaf) = ca_and_leaf(vec![], vec![]);
+        let stapled_responses = [response_for(&ca, &leaf, OcspCertStatus::Good)];
+
+        checker(RevocationMethod::Ocsp, RevocationFailureMode::HardFail)
+            .verify_chain(
+                &[leaf],
+                &TrustAnchors::from(&ca),
+                None,
+                &stapled_responses,
+                &TimeGenerator,
+            )
+            .await
+            .expect("certificate should verify using the stapled response");
+    }
+
+    #[rstest]
+    #[case::hard_fail(RevocationFailureMode::HardFail, false)]
+    #[case::soft_fail(RevocationFailureMode::SoftFail, true)]
+    #[tokio::test]
+    async fn verify_chain_with_unavailable_ocsp_responder(
+        #[case] failure_mode: RevocationFailureMode,
+        #[case] should_verify: bool,
+    ) {
+        let server = MockServer::start_async().await;
+        let url: Url = server.url("/ocsp").parse().unwrap();
+        let (ca, leaf) = ca_and_leaf(vec![], vec![url]);
+        server
+            .mock_async(|when, then| {
+                when.method(POST).path("/ocsp");
+                then.status(503).body("unavailable");
+            })
+            .await;
+
+        let result = checker(RevocationMethod::Ocsp, failure_mode)
+            .verify_chain(&[leaf], &TrustAnchors::from(&ca), None, &[], &TimeGenerator)
+            .await;
+
+        if should_verify {
+            result.expect("certificate should verify");
+        } else {
+            assert!(matches!(result, Err(RevocationError::Ocsp(OcspError::Http(_)))));
+        }
+    }
+
+    #[tokio::test]
+    async fn verify_chain_with_soft_fail_still_rejects_invalid_chain() {
+        let (_, leaf) = ca_and_leaf(vec![], vec![]);
+        let other_ca = Ca::generate_mock();
+
+        let error = checker(RevocationMethod::Both, RevocationFailureMode::SoftFail)
+            .verify_chain(&[leaf], &TrustAnchors::from(&other_ca), None, &[], &TimeGenerator)
+            .await
+            .expect_err("certificate should not verify");
+
+        assert!(matches!(error, RevocationError::Verification(_)));
+    }
+
+    #[rstest]
+    #[case::crl(RevocationMethod::Crl, false)]
+    #[case::both(RevocationMethod::Both, true)]
+    #[tokio::test]
+    async fn verify_chain_for_certificate_with_only_ocsp_responder(
+        #[case] method: RevocationMethod,
+        #[case] should_verify: bool,
+    ) {
+        let server = MockServer::start_async().await;
+        let url: Url = server.url("/ocsp").parse().unwrap();
+        let (ca, leaf) = ca_and_leaf(vec![], vec![url]);
+        server
+            .mock_async(|when, then| {
+                when.method(POST).path("/ocsp");
+                then.status(200).body(response_for(&ca, &leaf, OcspCertStatus::Good));
+            })
+            .await;
+
+        let result = checker(method, RevocationFailureMode::HardFail)
+            .verify_chain(&[leaf], &TrustAnchors::from(&ca), None, &[], &TimeGenerator)
+            .await;
+
+        if should_verify {
+            result.expect("certificate should verify");
+        } else {
+            assert!(matches!(
+                result,
+                Err(RevocationError::Crl(CrlProviderError::NoCrlDistributionPoint))
+            ));
+        }
+    }
+
+    #[rstest]
+    #[case::crl(RevocationMethod::Crl)]
+    #[case::ocsp(RevocationMethod::Ocsp)]
+    #[tokio::test]
+    async fn verify_chain_with_revoked_intermediate(#[case] method: RevocationMethod) {
+        let server = MockServer::start_async().await;
+        let root = Ca::generate_with_intermediate_count(
+            DistinguishedName::create_mock("root"),
+            CertificateConfiguration::default(),
+            1,
+        )
+        .unwrap();
+        let intermediate = root
+            .generate_intermediate(
+                DistinguishedName::create_mock("intermediate"),
+                CertificateConfiguration {
+                    crl_distribution_points: vec![server.url("/root.crl").parse().unwrap()],
+                    ocsp_responders: vec![server.url("/root/ocsp").parse().unwrap()],
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let leaf = intermediate
+            .generate_key_pair(
+                DistinguishedName::create_mock("leaf"),
+                CertificateConfiguration {
+                    crl_distribution_points: vec![server.url("/intermediate.crl").parse().unwrap()],
+                    ocsp_responders: vec![server.url("/intermediate/ocsp").parse().unwrap()],
+                    ..Default::default()
+                },
+                NO_SAN,
+            )
+            .unwrap()
+            .certificate()
+            .clone();
+        let intermediate_certificate = intermediate.as_borrowing_certificate().unwrap();
+
+        // The root revokes the intermediate, while the intermediate considers the leaf to be valid.
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/root.crl");
+                then.status(200).body(
+                    root.generate_crl(vec![revoked(&intermediate_certificate)])
+                        .unwrap()
+                        .der(),
+                );
+            })
+            .await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/intermediate.crl");
+                then.status(200).body(intermediate.generate_crl(vec![]).unwrap().der());
+            })
+            .await;
+        server
+            .mock_async(|when, then| {
+                when.method(POST).path("/root/ocsp");
+                then.status(200)
+                    .body(response_for(&root, &intermediate_certificate, OcspCertStatus::Revoked));
+            })
+            .await;
+        server
+            .mock_async(|when, then| {
+                when.method(POST).path("/intermediate/ocsp");
+                then.status(200)
+                    .body(response_for(&intermediate, &leaf, OcspCertStatus::Good));
+            })
+            .await;
+
+        let error = checker(method, RevocationFailureMode::SoftFail)
+            .verify_chain(
+                &[leaf, intermediate_certificate],
+                &TrustAnchors::from(&root),
+                None,
+                &[],
+                &TimeGenerator,
+            )
+            .await
+            .expect_err("chain with a revoked intermediate certificate should fail verification");
+
+        assert!(matches!(error, RevocationError::Revoked));
+    }
+
+    #[tokio::test]
+    async fn verify_chain_with_both_prefers_crl() {
+        let server = MockServer::start_async().await;
+        let crl_url: Url = server.url("/crl.der").parse().unwrap();
+        let ocsp_url: Url = server.url("/ocsp").parse().unwrap();
+        let (ca, leaf) = ca_and_leaf(vec![crl_url], vec![ocsp_url]);
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/crl.der");
+                then.status(200)
+                    .body(ca.generate_crl(vec![revoked(&leaf)]).unwrap().der());
+            })
+            .await;
+        let ocsp_mock = server
+            .mock_async(|when, then| {
+                when.method(POST).path("/ocsp");
+                then.status(200).body(response_for(&ca, &leaf, OcspCertStatus::Good));
+            })
+            .await;
+
+        let error = checker(RevocationMethod::Both, RevocationFailureMode::HardFail)
+            .verify_chain(&[leaf], &TrustAnchors::from(&ca), None, &[], &TimeGenerator)
+            .await
+            .expect_err("revoked certificate should fail verification");
+
+        assert!(matches!(error, RevocationError::Revoked));
+        ocsp_mock.assert_calls_async(0).await;
+    }
+
+    #[tokio::test]
+    async fn verify_chain_with_both_falls_back_to_ocsp_when_crl_is_unavailable() {
+        let server = MockServer::start_async().await;
+        let crl_url: Url = server.url("/crl.der").parse().unwrap();
+        let ocsp_url: Url = server.url("/ocsp").parse().unwrap();
+        let (ca, leaf) = ca_and_leaf(vec![crl_url], vec![ocsp_url]);
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/crl.der");
+                then.status(500).body("server error");
+            })
+            .await;
+        let ocsp_mock = server
+            .mock_async(|when, then| {
+                when.method(POST).path("/ocsp");
+                then.status(200).body(response_for(&ca, &leaf, OcspCertStatus::Good));
+            })
+            .await;
+
+        checker(RevocationMethod::Both, RevocationFailureMode::HardFail)
+            .verify_chain(&[leaf], &TrustAnchors::from(&ca), None, &[], &TimeGenerator)
+            .await
+            .expect("certificate should verify using OCSP");
+
+        ocsp_mock.assert_calls_async(1).await;
+    }
+}
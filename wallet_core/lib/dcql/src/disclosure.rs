@@ -9,7 +9,10 @@ use itertools::Either;
 use itertools::Itertools;
 use utils::vec_at_least::VecNonEmpty;
 
+use crate::ClaimsQueryValue;
 use crate::CredentialQueryIdentifier;
+use crate::normalized::ClaimRequest;
+use crate::normalized::NormalizedCredentialRequest;
 use crate::normalized::NormalizedCredentialRequests;
 
 #[derive(Debug, thiserror::Error)]
@@ -21,6 +24,8 @@ pub enum CredentialValidationError {
     MissingIdentifiers(HashSet<CredentialQueryIdentifier>),
     #[error("received unexpected identifier(s): {}", .0.iter().join(", "))]
     UnexpectedIdentifiers(HashSet<CredentialQueryIdentifier>),
+    #[error("received credential(s) do not satisfy required credential set(s) at index: {}", .0.iter().join(", "))]
+    UnsatisfiedCredentialSets(VecNonEmpty<usize>),
     #[error("received incorrect format for identifier(s): {}", .0.iter().map(|(id, (expected, received))| {
         format!("({id}): expected \"{expected}\", received \"{received}\"")
     }).join(" / "))]
@@ -40,6 +45,14 @@ pub enum CredentialValidationError {
         }).join(", "))
     }).join(" / "))]
     MissingAttributes(HashMap<CredentialQueryIdentifier, HashSet<VecNonEmpty<ClaimPath>>>),
+    #[error("requested attributes do not have any of the requested values for identifier(s): {}", .0.iter().map(|(id, paths)| {
+        format!("({}): {}", id, paths.iter().map(|path| {
+            format!("[{}]", path.iter().join(", "))
+        }).join(", "))
+    }).join(" / "))]
+    UnmatchedAttributeValues(HashMap<CredentialQueryIdentifier, HashSet<VecNonEmpty<ClaimPath>>>),
+    #[error("received credential(s) do not satisfy any of the requested claim sets for identifier(s): {}", .0.iter().join(", "))]
+    UnsatisfiedClaimSets(HashSet<CredentialQueryIdentifier>),
     #[error("requested AKIs did not match any of the returned credential(s): {}", .0.iter().map(|(id, (expected, received))| {
         format!(
             "({}): requested {}, received {}",
@@ -60,6 +73,8 @@ pub trait DisclosedCredential {
         &'a self,
         request_claim_paths: impl IntoIterator<Item = &'b VecNonEmpty<ClaimPath>>,
     ) -> HashSet<VecNonEmpty<ClaimPath>>;
+    /// Returns `true` if the claim at the path is present and its type and value match any of the provided values.
+    fn matches_claim_values(&self, claim_path: &VecNonEmpty<ClaimPath>, values: &[ClaimsQueryValue]) -> bool;
 }
 
 pub trait ExtendingVctRetriever {
@@ -73,51 +88,80 @@ impl NormalizedCredentialRequests {
         disclosed_credentials: &HashMap<CredentialQueryIdentifier, VecNonEmpty<impl DisclosedCredential>>,
         extending_vct_values: &impl ExtendingVctRetriever,
     ) -> Result<(), CredentialValidationError> {
-        // Credential queries that allow for multiple responses are not supported, so make the `HashMap` resolve to a
-        // single credential. If at least one of the values has more than one credential, this consitutes an error.
-        let (mut single_credentials, multiple_credential_ids): (HashMap<_, _>, HashSet<_>) =
-            disclosed_credentials.iter().partition_map(|(id, credentials)| {
-                if let Ok(credential) = credentials.iter().exactly_one() {
-                    Either::Left((id, credential))
-                } else {
-                    Either::Right(id.clone())
-                }
-            });
+        // Combine the queries and the credentials received for them into a single `HashMap`.
+        let mut remaining_credentials = disclosed_credentials.iter().collect::<HashMap<_, _>>();
+        let requests_and_credentials = self
+            .as_ref()
+            .iter()
+            .filter_map(|request| {
+                remaining_credentials
+                    .remove(request.id())
+                    .map(|credentials| (request.id(), (request, credentials)))
+            })
+            .collect::<HashMap<_, _>>();
+        let received_ids = requests_and_credentials.keys().copied().collect::<HashSet<_>>();
+
+        // Receiving more than one credential for a query is only allowed when the query explicitly allows for this.
+        let multiple_credential_ids = requests_and_credentials
+            .iter()
+            .filter(|(_, (request, credentials))| !request.multiple() && credentials.len().get() > 1)
+            .map(|(id, _)| (*id).clone())
+            .collect::<HashSet<_>>();
 
         if !multiple_credential_ids.is_empty() {
             return Err(CredentialValidationError::MultipleCredentials(multiple_credential_ids));
         }
 
-        // Combine the queries and credentials into a single `HashMap`. If a query identifier is not found in the
-        // credential response, this consitutes an error, as optional credentials are not supported.
-        let (requests_and_credentials, missing_ids): (HashMap<_, _>, HashSet<_>) =
-            self.as_ref().iter().partition_map(|request| {
-                if let Some(credential) = single_credentials.remove(request.id()) {
-                    Either::Left((request.id(), (request, credential)))
-                } else {
-                    Either::Right(request.id().clone())
-                }
-            });
+        let expected_ids = if self.credential_sets().is_empty() {
+            // Without credential sets, a query identifier that is not found in the credential response consitutes
+            // an error, as optional credentials are not supported.
+            let missing_ids = self
+                .as_ref()
+                .iter()
+                .map(NormalizedCredentialRequest::id)
+                .filter(|id| !received_ids.contains(id))
+                .cloned()
+                .collect::<HashSet<_>>();
 
-        if !missing_ids.is_empty() {
-            return Err(CredentialValidationError::MissingIdentifiers(missing_ids));
-        }
+            if !missing_ids.is_empty() {
+                return Err(CredentialValidationError::MissingIdentifiers(missing_ids));
+            }
 
-        // If the response contained a query identifier that was not part of the query, this is also an error.
-        if !single_credentials.is_empty() {
-            let unexpected_ids = single_credentials.into_keys().cloned().collect();
+            received_ids.clone()
+        } else {
+            // Otherwise, all of the required credential sets should be satisfied by the credential response.
+            // Only the credentials that are part of a satisfied credential set option are expected to be received.
+            self.satisfiable_credential_sets(&received_ids)
+                .map_err(CredentialValidationError::UnsatisfiedCredentialSets)?
+                .iter()
+                .flat_map(|credential_set| credential_set.options.iter().flatten())
+                .copied()
+                .collect()
+        };
 
+        // If the response contained a query identifier that was not part of the query
+        // or that is not expected by the credential sets, this is also an error.
+        let unexpected_ids = remaining_credentials
+            .into_keys()
+            .chain(received_ids.difference(&expected_ids).copied())
+            .cloned()
+            .collect::<HashSet<_>>();
+
+        if !unexpected_ids.is_empty() {
             return Err(CredentialValidationError::UnexpectedIdentifiers(unexpected_ids));
         }
 
         // Each received credential should be of the requested format.
         let format_mismatches = requests_and_credentials
             .iter()
-            .filter_map(|(id, (request, credential))| {
+            .filter_map(|(id, (request, credentials))| {
                 let expected_format = request.format();
-                let received_format = credential.format();
 
-                (received_format != expected_format).then(|| ((*id).clone(), (expected_format, received_format)))
+                credentials
+                    .iter()
+                    .map(DisclosedCredential::format)
+                    .find(|received_format| *received_format != expected_format)
+                    .map(|received_format| ((*id).clone(), (expected_format, received_format)))
             })
             .collect::<HashMap<_, _>>();
 
@@ -128,30 +172,32 @@ impl NormalizedCredentialRequests {
         // Each received credential should be of (one of) the requested credential type(s) for that query.
         let credential_type_mismatches = requests_and_credentials
             .iter()
-            .filter_map(|(id, (request, credential))| {
-                let credential_type = credential.credential_type();
-
-                (!request
-                    .credential_types()
-                    .chain(if credential.format() == Format::SdJwt {
-                        Either::Left(
-                            request
-                                .credential_types()
-                                .flat_map(|credential_type| extending_vct_values.retrieve(credential_type)),
-                        )
-                    } else {
-                        Either::Right(iter::empty())
+            .filter_map(|(id, (request, credentials))| {
+                credentials
+                    .iter()
+                    .find(|credential| {
+                        !request
+                            .credential_types()
+                            .chain(if credential.format() == Format::SdJwt {
+                                Either::Left(
+                                    request
+                                        .credential_types()
+                                        .flat_map(|credential_type| extending_vct_values.retrieve(credential_type)),
+                                )
+                            } else {
+                                Either::Right(iter::empty())
+                            })
+                            .contains(credential.credential_type())
                     })
-                    .contains(credential_type))
-                .then(|| {
-                    (
-                        (*id).clone(),
+                    .map(|credential| {
                         (
-                            request.credential_types().map(str::to_string).collect_vec(),
-                            credential_type.to_string(),
-                        ),
-                    )
-                })
+                            (*id).clone(),
+                            (
+                                request.credential_types().map(str::to_string).collect_vec(),
+                                credential.credential_type().to_string(),
+                            ),
+                        )
+                    })
             })
             .collect::<HashMap<_, _>>();
 
@@ -165,26 +211,61 @@ impl NormalizedCredentialRequests {
         // of the certificates of the received credential.
         let unmatched_akis = requests_and_credentials
             .iter()
-            .filter(|&(_, (request, credential))| {
-                !(request.aki().is_empty() || request.aki().iter().any(|aki| credential.aki().contains(aki)))
+            .filter_map(|(id, (request, credentials))| {
+                credentials
+                    .iter()
+                    .find(|credential| {
+                        !(request.aki().is_empty() || request.aki().iter().any(|aki| credential.aki().contains(aki)))
+                    })
+                    .map(|credential| ((*id).clone(), (request.aki().to_vec(), credential.aki().to_vec())))
             })
-            .map(|(id, (request, credential))| ((*id).clone(), (request.aki().to_vec(), credential.aki().to_vec())))
             .collect::<HashMap<_, _>>();
 
         if !unmatched_akis.is_empty() {
             return Err(CredentialValidationError::UnmatchedAkis(unmatched_akis));
         }
 
-        // Finally, each received credential should contain all of the requested attributes,
-        // as optional attributes are not supported.
-        let missing_attribute_credentials = requests_and_credentials
-            .into_iter()
-            .filter_map(|(id, (request, credential))| {
-                let missing_attributes = credential.missing_claim_paths(request.claim_paths());
+        // Finally, each received credential should contain the requested attributes, the values of which should match
+        // the requested values, if specified. When the request contains claim sets, any one of these should be
+        // satisfied. Otherwise all of the requested attributes are required, as optional attributes are not supported.
+        let mut missing_attribute_credentials = HashMap::new();
+        let mut unmatched_value_credentials = HashMap::new();
+        let mut unsatisfied_claim_set_ids = HashSet::new();
 
-                (!missing_attributes.is_empty()).then(|| (id.clone(), missing_attributes))
-            })
-            .collect::<HashMap<_, _>>();
+        for (id, (request, credentials)) in requests_and_credentials {
+            let claim_sets = request.claim_sets();
+
+            for credential in credentials {
+                if request.has_claim_sets() {
+                    let is_satisfied = claim_sets.iter().any(|claims| {
+                        let (missing_attributes, unmatched_values) = unsatisfied_claim_paths(credential, claims);
+
+                        missing_attributes.is_empty() && unmatched_values.is_empty()
+                    });
+
+                    if !is_satisfied {
+                        unsatisfied_claim_set_ids.insert(id.clone());
+                    }
+                } else {
+                    let (missing_attributes, unmatched_values) =
+                        unsatisfied_claim_paths(credential, claim_sets.first());
+
+                    if !missing_attributes.is_empty() {
+                        missing_attribute_credentials
+                            .entry(id.clone())
+                            .or_insert_with(HashSet::new)
+                            .extend(missing_attributes);
+                    }
+
+                    if !unmatched_values.is_empty() {
+                        unmatched_value_credentials
+                            .entry(id.clone())
+                            .or_insert_with(HashSet::new)
+                            .extend(unmatched_values);
+                    }
+                }
+            }
+        }
 
         if !missing_attribute_credentials.is_empty() {
             return Err(CredentialValidationError::MissingAttributes(
@@ -192,10 +273,43 @@ impl NormalizedCredentialRequests {
             ));
         }
 
+        if !unmatched_value_credentials.is_empty() {
+            return Err(CredentialValidationError::UnmatchedAttributeValues(
+                unmatched_value_credentials,
+            ));
+        }
+
+        if !unsatisfied_claim_set_ids.is_empty() {
+            return Err(CredentialValidationError::UnsatisfiedClaimSets(
+                unsatisfied_claim_set_ids,
+            ));
+        }
+
         Ok(())
     }
 }
 
+/// Returns both the paths of the requested claims that are missing from the credential and the paths of the claims
+/// that are present, but do not match any of the requested values.
+fn unsatisfied_claim_paths(
+    credential: &impl DisclosedCredential,
+    claims: &VecNonEmpty<ClaimRequest>,
+) -> (HashSet<VecNonEmpty<ClaimPath>>, HashSet<VecNonEmpty<ClaimPath>>) {
+    let missing_attributes = credential.missing_claim_paths(claims.iter().map(|claim| &claim.path));
+
+    let unmatched_values = claims
+        .iter()
+        .filter(|claim| {
+            !claim.values.is_empty()
+                && !missing_attributes.contains(&claim.path)
+                && !credential.matches_claim_values(&claim.path, &claim.values)
+        })
+        .map(|claim| claim.path.clone())
+        .collect();
+
+    (missing_attributes, unmatched_values)
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
@@ -214,6 +328,7 @@ mod tests {
     use super::CredentialValidationError;
     use super::DisclosedCredential;
     use super::ExtendingVctRetriever;
+    use crate::ClaimsQueryValue;
     use crate::CredentialQueryIdentifier;
     use crate::normalized::NormalizedCredentialRequests;
 
@@ -225,6 +340,7 @@ mod tests {
         format: Format,
         credential_type: String,
         claim_paths: HashSet<VecNonEmpty<ClaimPath>>,
+        claim_values: HashMap<VecNonEmpty<ClaimPath>, ClaimsQueryValue>,
         aki: Vec<KeyIdentifier>,
     }
 
@@ -242,6 +358,13 @@ mod tests {
                         ]
                     })
                     .collect(),
+                claim_values: HashMap::from([(
+                    vec_nonempty![
+                        ClaimPath::SelectByKey(EXAMPLE_NAMESPACE.to_string()),
+                        ClaimPath::SelectByKey("family_name".to_string())
+                    ],
+                    ClaimsQueryValue::String("Doe".to_string()),
+                )]),
                 aki: vec![],
             }
         }
@@ -254,6 +377,10 @@ mod tests {
                     .iter()
                     .map(|attribute| vec_nonempty![ClaimPath::SelectByKey(attribute.to_string())])
                     .collect(),
+                claim_values: HashMap::from([(
+                    vec_nonempty![ClaimPath::SelectByKey("family_name".to_string())],
+                    ClaimsQueryValue::String("Doe".to_string()),
+                )]),
                 aki: vec![],
             }
         }
@@ -284,6 +411,12 @@ mod tests {
                 .cloned()
                 .collect()
         }
+
+        fn matches_claim_values(&self, claim_path: &VecNonEmpty<ClaimPath>, values: &[ClaimsQueryValue]) -> bool {
+            self.claim_values
+                .get(claim_path)
+                .is_some_and(|claim_value| values.contains(claim_value))
+        }
     }
 
     fn example_mdoc_single_credential_requests() -> NormalizedCredentialRequests {
@@ -354,6 +487,63 @@ mod tests {
         ])
     }
 
+    fn example_sd_jwt_multiple_credential_requests() -> NormalizedCredentialRequests {
+        let request =
+            NormalizedCredentialRequests::new_mock_sd_jwt_from_slices(&[(&[EXAMPLE_VCT], &[&["family_name"]])])
+                .into_iter()
+                .next()
+                .unwrap()
+                .with_multiple();
+
+        vec![request].try_into().unwrap()
+    }
+
+    fn example_sd_jwt_credential_set_requests() -> NormalizedCredentialRequests {
+        NormalizedCredentialRequests::new_mock_sd_jwt_from_slices(&[
+            (&[EXAMPLE_VCT], &[&["family_name"]]),
+            (&[EXAMPLE_VCT], &[&["family_name"]]),
+            (&[EXAMPLE_VCT], &[&["family_name"]]),
+        ])
+        .with_credential_sets(&[(&[&["sd_jwt_0"], &["sd_jwt_1", "sd_jwt_2"]], true)])
+    }
+
+    fn example_sd_jwt_claim_values_requests(values: &[&str]) -> NormalizedCredentialRequests {
+        let request =
+            NormalizedCredentialRequests::new_mock_sd_jwt_from_slices(&[(&[EXAMPLE_VCT], &[&["family_name"]])])
+                .into_iter()
+                .next()
+                .unwrap()
+                .with_claim_values(
+                    0,
+                    values
+                        .iter()
+                        .map(|value| ClaimsQueryValue::String(value.to_string()))
+                        .collect(),
+                );
+
+        vec![request].try_into().unwrap()
+    }
+
+    fn example_sd_jwt_claim_set_requests(family_name_values: &[&str]) -> NormalizedCredentialRequests {
+        let request = NormalizedCredentialRequests::new_mock_sd_jwt_from_slices(&[(
+            &[EXAMPLE_VCT],
+            &[&["favourite_colour"], &["family_name"]],
+        )])
+        .into_iter()
+        .next()
+        .unwrap()
+        .with_claim_sets(&["colour", "name"], &[&["colour"], &["name"]])
+        .with_claim_values(
+            1,
+            family_name_values
+                .iter()
+                .map(|value| ClaimsQueryValue::String(value.to_string()))
+                .collect(),
+        );
+
+        vec![request].try_into().unwrap()
+    }
+
     #[rstest]
     #[case::mdoc_happy_path(
         example_mdoc_single_credential_requests(),
@@ -394,6 +584,31 @@ mod tests {
         ]),
         Err(CredentialValidationError::MultipleCredentials(HashSet::from(["mdoc_1".try_into().unwrap()]))),
     )]
+    #[case::sd_jwt_happy_path_multiple_credentials(
+        example_sd_jwt_multiple_credential_requests(),
+        &[],
+        HashMap::from([
+            ("sd_jwt_0".try_into().unwrap(), vec_nonempty![
+                MockDisclosedCredential::example_sd_jwt(EXAMPLE_VCT),
+                MockDisclosedCredential::example_sd_jwt(EXAMPLE_VCT),
+            ]),
+        ]),
+        Ok(()),
+    )]
+    #[case::sd_jwt_error_multiple_credentials_credential_type_mismatch(
+        example_sd_jwt_multiple_credential_requests(),
+        &[],
+        HashMap::from([
+            ("sd_jwt_0".try_into().unwrap(), vec_nonempty![
+                MockDisclosedCredential::example_sd_jwt(EXAMPLE_VCT),
+                MockDisclosedCredential::example_sd_jwt(EXTENDING_EXAMPLE_VCT),
+            ]),
+        ]),
+        Err(CredentialValidationError::CredentialTypeMismatch(
+            HashMap::from([("sd_jwt_0".try_into().unwrap(),
+            (vec![EXAMPLE_VCT.to_string()], EXTENDING_EXAMPLE_VCT.to_string()),
+        )]))),
+    )]
     #[case::mdoc_error_missing_identifier(
         example_mdoc_double_credential_requests(),
         &[],
@@ -409,6 +624,32 @@ mod tests {
         ]),
         Err(CredentialValidationError::UnexpectedIdentifiers(HashSet::from(["mdoc_1".try_into().unwrap()]))),
     )]
+    #[case::credential_sets_happy_path(
+        example_sd_jwt_credential_set_requests(),
+        &[],
+        HashMap::from([
+            ("sd_jwt_1".try_into().unwrap(), vec_nonempty![MockDisclosedCredential::example_sd_jwt(EXAMPLE_VCT)]),
+            ("sd_jwt_2".try_into().unwrap(), vec_nonempty![MockDisclosedCredential::example_sd_jwt(EXAMPLE_VCT)])
+        ]),
+        Ok(()),
+    )]
+    #[case::credential_sets_error_unsatisfied(
+        example_sd_jwt_credential_set_requests(),
+        &[],
+        HashMap::from([
+            ("sd_jwt_1".try_into().unwrap(), vec_nonempty![MockDisclosedCredential::example_sd_jwt(EXAMPLE_VCT)])
+        ]),
+        Err(CredentialValidationError::UnsatisfiedCredentialSets(vec_nonempty![0])),
+    )]
+    #[case::credential_sets_error_unexpected_identifier(
+        example_sd_jwt_credential_set_requests(),
+        &[],
+        HashMap::from([
+            ("sd_jwt_0".try_into().unwrap(), vec_nonempty![MockDisclosedCredential::example_sd_jwt(EXAMPLE_VCT)]),
+            ("sd_jwt_1".try_into().unwrap(), vec_nonempty![MockDisclosedCredential::example_sd_jwt(EXAMPLE_VCT)])
+        ]),
+        Err(CredentialValidationError::UnexpectedIdentifiers(HashSet::from(["sd_jwt_1".try_into().unwrap()]))),
+    )]
     #[case::sd_jwt_error_wrong_format(
         example_sd_jwt_single_credential_requests(),
         &[],
@@ -464,6 +705,33 @@ mod tests {
             ]),
         )]))),
     )]
+    #[case::sd_jwt_claim_values_happy_path(
+        example_sd_jwt_claim_values_requests(&["Smith", "Doe"]),
+        &[],
+        HashMap::from([("sd_jwt_0".try_into().unwrap(), vec_nonempty![MockDisclosedCredential::example_sd_jwt(EXAMPLE_VCT)])]),
+        Ok(()),
+    )]
+    #[case::sd_jwt_error_unmatched_claim_values(
+        example_sd_jwt_claim_values_requests(&["Smith"]),
+        &[],
+        HashMap::from([("sd_jwt_0".try_into().unwrap(), vec_nonempty![MockDisclosedCredential::example_sd_jwt(EXAMPLE_VCT)])]),
+        Err(CredentialValidationError::UnmatchedAttributeValues(
+            HashMap::from([("sd_jwt_0".try_into().unwrap(),
+            HashSet::from([vec_nonempty![ClaimPath::SelectByKey("family_name".to_string())]]),
+        )]))),
+    )]
+    #[case::sd_jwt_claim_sets_happy_path(
+        example_sd_jwt_claim_set_requests(&["Doe"]),
+        &[],
+        HashMap::from([("sd_jwt_0".try_into().unwrap(), vec_nonempty![MockDisclosedCredential::example_sd_jwt(EXAMPLE_VCT)])]),
+        Ok(()),
+    )]
+    #[case::sd_jwt_error_unsatisfied_claim_sets(
+        example_sd_jwt_claim_set_requests(&["Smith"]),
+        &[],
+        HashMap::from([("sd_jwt_0".try_into().unwrap(), vec_nonempty![MockDisclosedCredential::example_sd_jwt(EXAMPLE_VCT)])]),
+        Err(CredentialValidationError::UnsatisfiedClaimSets(HashSet::from(["sd_jwt_0".try_into().unwrap()]))),
+    )]
     fn test_normalized_credential_requests_is_satisfied_by_disclosed_credentials(
         #[case] requests: NormalizedCredentialRequests,
         #[case] accepted_vcts: &[&str],
@@ -1,3 +1,6 @@
+use std::collections::HashSet;
+use std::ops::Not;
+
 use attestation_types::credential_format::Format;
 use crypto::x509::KeyIdentifier;
 use derive_more::IntoIterator;
@@ -6,16 +9,22 @@ use itertools::Either;
 use itertools::Itertools;
 use serde::Deserialize;
 use serde::Serialize;
+use utils::vec_at_least::IntoNonEmptyIterator;
 use utils::vec_at_least::Iter;
 use utils::vec_at_least::NonEmptyIterator;
 use utils::vec_at_least::VecNonEmpty;
+use utils::vec_at_least::VecNonEmptyUnique;
+use utils::vec_nonempty;
 
 use crate::ClaimPath;
 use crate::ClaimsQuery;
+use crate::ClaimsQueryIdentifier;
+use crate::ClaimsQueryValue;
 use crate::ClaimsSelection;
 use crate::CredentialQuery;
 use crate::CredentialQueryFormat;
 use crate::CredentialQueryIdentifier;
+use crate::CredentialSetQuery;
 use crate::Query;
 use crate::TrustedAuthoritiesQuery;
 use crate::unique_id_vec::MayHaveUniqueId;
@@ -23,19 +32,98 @@ use crate::unique_id_vec::UniqueIdVec;
 use crate::unique_id_vec::UniqueIdVecError;
 
 #[derive(Debug, Clone, PartialEq, Eq, IntoIterator, Serialize, Deserialize)]
-pub struct NormalizedCredentialRequests(UniqueIdVec<NormalizedCredentialRequest>);
+pub struct NormalizedCredentialRequests {
+    #[into_iterator(owned)]
+    requests: UniqueIdVec<NormalizedCredentialRequest>,
+    /// The credential sets from the DCQL query. When this is empty, all of the requests are required.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    credential_sets: Vec<CredentialSetQuery>,
+}
 
 impl AsRef<[NormalizedCredentialRequest]> for NormalizedCredentialRequests {
     fn as_ref(&self) -> &[NormalizedCredentialRequest] {
-        let Self(unique_vec) = self;
-
-        unique_vec.as_ref()
+        self.requests.as_ref()
     }
 }
 
+/// A credential set of which at least one of the options can be satisfied. Each option consists of one or more
+/// identifiers of credential requests, all of which need to be disclosed to satisfy that option.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SatisfiableCredentialSet<'a> {
+    pub required: bool,
+    pub options: VecNonEmpty<VecNonEmpty<&'a CredentialQueryIdentifier>>,
+}
+
 impl NormalizedCredentialRequests {
     pub fn nonempty_iter<'a>(&'a self) -> Iter<'a, NormalizedCredentialRequest> {
-        self.0.nonempty_iter()
+        self.requests.nonempty_iter()
+    }
+
+    pub fn credential_sets(&self) -> &[CredentialSetQuery] {
+        &self.credential_sets
+    }
+
+    /// Evaluate the credential sets against the identifiers of the credential requests that can be satisfied. This
+    /// returns only those credential set options for which all of the credential requests can be satisfied, leaving
+    /// out optional credential sets that have no such options at all. If no credential sets are present, a single
+    /// required credential set is returned that has one option, which consists of all of the credential requests.
+    ///
+    /// If any of the required credential sets cannot be satisfied, the indices of these sets are returned as error.
+    pub fn satisfiable_credential_sets(
+        &self,
+        satisfiable_ids: &HashSet<&CredentialQueryIdentifier>,
+    ) -> Result<VecNonEmpty<SatisfiableCredentialSet<'_>>, VecNonEmpty<usize>> {
+        let credential_sets = if self.credential_sets.is_empty() {
+            vec![(
+                true,
+                vec![
+                    self.requests
+                        .nonempty_iter()
+                        .map(NormalizedCredentialRequest::id)
+                        .collect::<VecNonEmpty<_>>(),
+                ],
+            )]
+        } else {
+            self.credential_sets
+                .iter()
+                .map(|credential_set| {
+                    let options = credential_set
+                        .options
+                        .iter()
+                        .map(|option| option.nonempty_iter().collect::<VecNonEmpty<_>>())
+                        .collect_vec();
+
+                    (credential_set.required, options)
+                })
+                .collect_vec()
+        };
+
+        let (satisfiable_sets, unsatisfied_indices): (Vec<_>, Vec<_>) = credential_sets
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, (required, options))| {
+                let satisfiable_options = options
+                    .into_iter()
+                    .filter(|option| option.iter().all(|id| satisfiable_ids.contains(id)))
+                    .collect_vec();
+
+                match (VecNonEmpty::try_from(satisfiable_options), required) {
+                    (Ok(options), _) => Some(Ok(SatisfiableCredentialSet { required, options })),
+                    (Err(_), true) => Some(Err(index)),
+                    (Err(_), false) => None,
+                }
+            })
+            .partition_result();
+
+        if let Ok(unsatisfied_indices) = VecNonEmpty::try_from(unsatisfied_indices) {
+            return Err(unsatisfied_indices);
+        }
+
+        let satisfiable_sets = satisfiable_sets
+            .try_into()
+            .expect("there should always be at least one required credential set");
+
+        Ok(satisfiable_sets)
     }
 }
 
@@ -43,9 +131,12 @@ impl TryFrom<Vec<NormalizedCredentialRequest>> for NormalizedCredentialRequests
     type Error = UniqueIdVecError;
 
     fn try_from(value: Vec<NormalizedCredentialRequest>) -> Result<Self, Self::Error> {
-        let unique_vec = UniqueIdVec::try_from(value)?;
+        let requests = UniqueIdVec::try_from(value)?;
 
-        Ok(Self(unique_vec))
+        Ok(Self {
+            requests,
+            credential_sets: vec![],
+        })
     }
 }
 
@@ -55,18 +146,34 @@ pub enum NormalizedCredentialRequest {
     MsoMdoc {
         id: CredentialQueryIdentifier,
         doctype_value: String,
+        #[serde(default, skip_serializing_if = "<&bool>::not")]
+        multiple: bool,
         claims: VecNonEmpty<MdocAttributeRequest>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        claim_sets: Vec<VecNonEmptyUnique<ClaimsQueryIdentifier>>,
         aki: Vec<KeyIdentifier>,
     },
     #[serde(rename = "dc+sd-jwt")]
     SdJwt {
         id: CredentialQueryIdentifier,
         vct_values: VecNonEmpty<String>,
+        #[serde(default, skip_serializing_if = "<&bool>::not")]
+        multiple: bool,
         claims: VecNonEmpty<SdJwtAttributeRequest>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        claim_sets: Vec<VecNonEmptyUnique<ClaimsQueryIdentifier>>,
         aki: Vec<KeyIdentifier>,
     },
 }
 
+/// A single requested claim, regardless of the credential format. If `values` is not empty, the value of the claim
+/// should match one of these.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClaimRequest {
+    pub path: VecNonEmpty<ClaimPath>,
+    pub values: Vec<ClaimsQueryValue>,
+}
+
 impl NormalizedCredentialRequest {
     pub fn format(&self) -> Format {
         match self {
@@ -98,12 +205,74 @@ impl NormalizedCredentialRequest {
         }
     }
 
+    /// Indicates whether more than one credential may be disclosed for this request.
+    pub fn multiple(&self) -> bool {
+        match self {
+            Self::MsoMdoc { multiple, .. } => *multiple,
+            Self::SdJwt { multiple, .. } => *multiple,
+        }
+    }
+
     pub fn aki(&self) -> &[KeyIdentifier] {
         match self {
             NormalizedCredentialRequest::MsoMdoc { aki, .. } => aki,
             NormalizedCredentialRequest::SdJwt { aki, .. } => aki,
         }
     }
+
+    pub fn has_claim_sets(&self) -> bool {
+        match self {
+            Self::MsoMdoc { claim_sets, .. } => !claim_sets.is_empty(),
+            Self::SdJwt { claim_sets, .. } => !claim_sets.is_empty(),
+        }
+    }
+
+    /// Returns the combinations of claims that satisfy this request, in order of preference. If the request does not
+    /// contain any claim sets, the only combination consists of all of the requested claims.
+    pub fn claim_sets(&self) -> VecNonEmpty<VecNonEmpty<ClaimRequest>> {
+        let (claims, claim_sets) = match self {
+            Self::MsoMdoc { claims, claim_sets, .. } => (
+                claims
+                    .nonempty_iter()
+                    .map(|claim| (claim.id.as_ref(), ClaimRequest::from(claim)))
+                    .collect::<VecNonEmpty<_>>(),
+                claim_sets,
+            ),
+            Self::SdJwt { claims, claim_sets, .. } => (
+                claims
+                    .nonempty_iter()
+                    .map(|claim| (claim.id.as_ref(), ClaimRequest::from(claim)))
+                    .collect::<VecNonEmpty<_>>(),
+                claim_sets,
+            ),
+        };
+
+        let Ok(claim_sets) = VecNonEmpty::try_from(claim_sets.clone()) else {
+            return vec_nonempty![
+                claims
+                    .into_nonempty_iter()
+                    .map(|(_, claim)| claim)
+                    .collect::<VecNonEmpty<_>>()
+            ];
+        };
+
+        claim_sets
+            .into_nonempty_iter()
+            .map(|claim_ids| {
+                claim_ids
+                    .into_nonempty_iter()
+                    .map(|claim_id| {
+                        claims
+                            .iter()
+                            .find(|(id, _)| *id == Some(&claim_id))
+                            .map(|(_, claim)| claim.clone())
+                            // The conversion from `CredentialQuery` checks that all claim set identifiers are present.
+                            .expect("claim set should only reference claims in the request")
+                    })
+                    .collect()
+            })
+            .collect()
+    }
 }
 
 impl MayHaveUniqueId for NormalizedCredentialRequest {
@@ -112,38 +281,62 @@ impl MayHaveUniqueId for NormalizedCredentialRequest {
     }
 }
 
-/// Request for a single mdoc attribute with the given [path].
+/// Request for a single mdoc attribute with the given [path], optionally constrained to the given [values].
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MdocAttributeRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<ClaimsQueryIdentifier>,
     pub path: VecNonEmpty<ClaimPath>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub values: Vec<ClaimsQueryValue>,
     pub intent_to_retain: Option<bool>,
 }
 
-/// Request for a single SD-JWT attribute with the given [path].
+/// Request for a single SD-JWT attribute with the given [path], optionally constrained to the given [values].
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SdJwtAttributeRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<ClaimsQueryIdentifier>,
     pub path: VecNonEmpty<ClaimPath>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub values: Vec<ClaimsQueryValue>,
+}
+
+impl From<&MdocAttributeRequest> for ClaimRequest {
+    fn from(value: &MdocAttributeRequest) -> Self {
+        Self {
+            path: value.path.clone(),
+            values: value.values.clone(),
+        }
+    }
+}
+
+impl From<&SdJwtAttributeRequest> for ClaimRequest {
+    fn from(value: &SdJwtAttributeRequest) -> Self {
+        Self {
+            path: value.path.clone(),
+            values: value.values.clone(),
+        }
+    }
 }
 
 #[derive(Debug, thiserror::Error, ErrorCategory)]
 #[cfg_attr(test, derive(PartialEq, Eq))]
 #[category(critical)]
 pub enum UnsupportedDcqlFeatures {
-    #[error("'credential_sets' are not supported")]
-    CredentialSets,
-    #[error("multiple credential queries are not supported")]
-    MultipleCredentialQueries,
+    #[error("'credential_sets' reference unknown credential query identifier(s): {}", .0.iter().join(", "))]
+    UnknownCredentialSetIdentifiers(HashSet<CredentialQueryIdentifier>),
+    #[error("'credential_sets' that are all optional are not supported")]
+    NoRequiredCredentialSet,
     #[error("disclosing only non-selectively disclosable claims is not supported")]
     NoClaims,
-    #[error("'claim_sets' are not supported")]
-    ClaimSets,
-    #[error("claim query with 'values' is not supported")]
-    ClaimValues,
+    #[error("'claim_sets' reference unknown claim query identifier(s): {}", .0.iter().join(", "))]
+    UnknownClaimSetIdentifiers(HashSet<ClaimsQueryIdentifier>),
     #[error("received unsupported 'trusted_authorities' variant: {0:?}")]
     UnsupportedTrustedAuthority(VecNonEmpty<String>),
     #[error("requests that do not require a cryptographic holder binding proof are not supported")]
     CryptographicHolderBindingNotRequired,
-    #[error("unsupported ClaimPath variant, only SelectByKey is supported")]
+    #[error("unsupported ClaimPath variant for mso_mdoc, only SelectByKey is supported")]
     UnsupportedClaimPathVariant,
     #[error("'intent_to_retain' is not allowed for dc+sd-jwt format")]
     IntentToRetainPresent,
@@ -154,15 +347,42 @@ impl TryFrom<Query> for NormalizedCredentialRequests {
 
     fn try_from(source: Query) -> Result<Self, Self::Error> {
         if !source.credential_sets.is_empty() {
-            return Err(UnsupportedDcqlFeatures::CredentialSets);
+            let credential_ids = source.credentials.iter().map(|query| &query.id).collect::<HashSet<_>>();
+            let unknown_ids = source
+                .credential_sets
+                .iter()
+                .flat_map(|credential_set| credential_set.options.iter().flatten())
+                .filter(|id| !credential_ids.contains(id))
+                .cloned()
+                .collect::<HashSet<_>>();
+
+            if !unknown_ids.is_empty() {
+                return Err(UnsupportedDcqlFeatures::UnknownCredentialSetIdentifiers(unknown_ids));
+            }
+
+            // Having at least one required credential set guarantees that a credential is always disclosed.
+            if !source
+                .credential_sets
+                .iter()
+                .any(|credential_set| credential_set.required)
+            {
+                return Err(UnsupportedDcqlFeatures::NoRequiredCredentialSet);
+            }
         }
+
         let requests = source
             .credentials
             .into_iter()
             .map(TryInto::try_into)
-            .collect::<Result<Vec<_>, _>>()?;
-        // This unwrap is safe, because source.credentials is also `UniqueIdVec`.
-        Ok(requests.try_into().unwrap())
+            .collect::<Result<Vec<_>, _>>()?
+            .try_into()
+            // This unwrap is safe, because source.credentials is also `UniqueIdVec`.
+            .unwrap();
+
+        Ok(Self {
+            requests,
+            credential_sets: source.credential_sets,
+        })
     }
 }
 
@@ -170,13 +390,14 @@ impl From<NormalizedCredentialRequests> for Query {
     fn from(value: NormalizedCredentialRequests) -> Self {
         Self {
             credentials: value
+                .requests
                 .into_iter()
                 .map(CredentialQuery::from)
                 .collect_vec()
                 .try_into()
                 // This unwrap is safe, the source is also a `UniqueIdVec`.
                 .unwrap(),
-            credential_sets: vec![],
+            credential_sets: value.credential_sets,
         }
     }
 }
@@ -185,21 +406,33 @@ impl TryFrom<CredentialQuery> for NormalizedCredentialRequest {
     type Error = UnsupportedDcqlFeatures;
 
     fn try_from(source: CredentialQuery) -> Result<Self, Self::Error> {
-        if source.multiple {
-            return Err(UnsupportedDcqlFeatures::MultipleCredentialQueries);
-        }
         if !source.require_cryptographic_holder_binding {
             return Err(UnsupportedDcqlFeatures::CryptographicHolderBindingNotRequired);
         }
 
-        let claims = match source.claims_selection {
+        let (claims, claim_sets) = match source.claims_selection {
             ClaimsSelection::NoSelectivelyDisclosable => {
                 return Err(UnsupportedDcqlFeatures::NoClaims);
             }
-            ClaimsSelection::Combinations { .. } => {
-                return Err(UnsupportedDcqlFeatures::ClaimSets);
+            ClaimsSelection::Combinations { claims, claim_sets } => {
+                let claim_ids = claims
+                    .iter()
+                    .filter_map(|claim| claim.id.as_ref())
+                    .collect::<HashSet<_>>();
+                let unknown_ids = claim_sets
+                    .iter()
+                    .flatten()
+                    .filter(|id| !claim_ids.contains(id))
+                    .cloned()
+                    .collect::<HashSet<_>>();
+
+                if !unknown_ids.is_empty() {
+                    return Err(UnsupportedDcqlFeatures::UnknownClaimSetIdentifiers(unknown_ids));
+                }
+
+                (claims, claim_sets.into_inner())
             }
-            ClaimsSelection::All { claims } => claims,
+            ClaimsSelection::All { claims } => (claims, vec![]),
         };
 
         let aki = source
@@ -226,7 +459,9 @@ impl TryFrom<CredentialQuery> for NormalizedCredentialRequest {
                 Self::MsoMdoc {
                     id: source.id,
                     doctype_value,
+                    multiple: source.multiple,
                     claims,
+                    claim_sets,
                     aki,
                 }
             }
@@ -239,7 +474,9 @@ impl TryFrom<CredentialQuery> for NormalizedCredentialRequest {
                 Self::SdJwt {
                     id: source.id,
                     vct_values,
+                    multiple: source.multiple,
                     claims,
+                    claim_sets,
                     aki,
                 }
             }
@@ -251,31 +488,46 @@ impl TryFrom<CredentialQuery> for NormalizedCredentialRequest {
 
 impl From<NormalizedCredentialRequest> for CredentialQuery {
     fn from(value: NormalizedCredentialRequest) -> Self {
-        let (id, format, claims, aki) = match value {
+        let (id, format, multiple, claims, claim_sets, aki) = match value {
             NormalizedCredentialRequest::MsoMdoc {
                 id,
                 doctype_value,
+                multiple,
                 claims,
+                claim_sets,
                 aki,
             } => (
                 id,
                 CredentialQueryFormat::MsoMdoc { doctype_value },
+                multiple,
                 claims.into_iter().map(ClaimsQuery::from).collect_vec(),
+                claim_sets,
                 aki,
             ),
             NormalizedCredentialRequest::SdJwt {
                 id,
                 vct_values,
+                multiple,
                 claims,
+                claim_sets,
                 aki,
             } => (
                 id,
                 CredentialQueryFormat::SdJwt { vct_values },
+                multiple,
                 claims.into_iter().map(ClaimsQuery::from).collect_vec(),
+                claim_sets,
                 aki,
             ),
         };
 
+        // This unwrap is safe as the source is guaranteed not to be empty and its claim identifiers are unique.
+        let claims = claims.try_into().unwrap();
+        let claims_selection = match VecNonEmpty::try_from(claim_sets) {
+            Ok(claim_sets) => ClaimsSelection::Combinations { claims, claim_sets },
+            Err(_) => ClaimsSelection::All { claims },
+        };
+
         // The reverse mapping, in `TryFrom<CredentialQuery> for NormalizedCredentialRequest` above, flattens multiple
         // AKI values in the `trusted_authorities` array of the `CredentialQuery`, resulting in a `Vec<KeyIdentifier>`
         // as opposed to what otherwise might be a `Vec<Vec<KeyIdentifier>>`.
@@ -291,20 +543,16 @@ impl From<NormalizedCredentialRequest> for CredentialQuery {
         Self {
             id,
             format,
-            multiple: false,
+            multiple,
             trusted_authorities,
             require_cryptographic_holder_binding: true,
-            claims_selection: ClaimsSelection::All {
-                claims: claims
-                    .try_into()
-                    // This unwrap is safe as the source is guaranteed not to be empty and no identifiers are used.
-                    .unwrap(),
-            },
+            claims_selection,
         }
     }
 }
 
-fn check_claims_query(claims_query: &ClaimsQuery) -> Result<(), UnsupportedDcqlFeatures> {
+/// Check that the claim path only selects by key, as an mdoc does not contain any arrays to select elements from.
+fn check_mdoc_claims_query(claims_query: &ClaimsQuery) -> Result<(), UnsupportedDcqlFeatures> {
     if claims_query
         .path
         .nonempty_iter()
@@ -320,10 +568,12 @@ impl TryFrom<ClaimsQuery> for MdocAttributeRequest {
     type Error = UnsupportedDcqlFeatures;
 
     fn try_from(source: ClaimsQuery) -> Result<Self, Self::Error> {
-        check_claims_query(&source)?;
+        check_mdoc_claims_query(&source)?;
 
         let request = Self {
+            id: source.id,
             path: source.path,
+            values: source.values,
             intent_to_retain: source.intent_to_retain,
         };
 
@@ -334,10 +584,9 @@ impl TryFrom<ClaimsQuery> for MdocAttributeRequest {
 impl From<MdocAttributeRequest> for ClaimsQuery {
     fn from(value: MdocAttributeRequest) -> Self {
         Self {
-            // Just set the identifier to `None`, as it is only useful for `claim_sets`, which we do not support.
-            id: None,
+            id: value.id,
             path: value.path,
-            values: vec![],
+            values: value.values,
             intent_to_retain: value.intent_to_retain,
         }
     }
@@ -347,13 +596,15 @@ impl TryFrom<ClaimsQuery> for SdJwtAttributeRequest {
     type Error = UnsupportedDcqlFeatures;
 
     fn try_from(source: ClaimsQuery) -> Result<Self, Self::Error> {
-        check_claims_query(&source)?;
-
         if source.intent_to_retain.is_some() {
             return Err(UnsupportedDcqlFeatures::IntentToRetainPresent);
         }
 
-        let request = Self { path: source.path };
+        let request = Self {
+            id: source.id,
+            path: source.path,
+            values: source.values,
+        };
 
         Ok(request)
     }
@@ -362,10 +613,9 @@ impl TryFrom<ClaimsQuery> for SdJwtAttributeRequest {
 impl From<SdJwtAttributeRequest> for ClaimsQuery {
     fn from(value: SdJwtAttributeRequest) -> Self {
         Self {
-            // Just set the identifier to `None`, as it is only useful for `claim_sets`, which we do not support.
-            id: None,
+            id: value.id,
             path: value.path,
-            values: vec![],
+            values: value.values,
             intent_to_retain: None,
         }
     }
@@ -389,9 +639,12 @@ pub mod mock {
     use super::SdJwtAttributeRequest;
     use crate::ClaimPath;
     use crate::ClaimsQuery;
+    use crate::ClaimsQueryIdentifier;
+    use crate::ClaimsQueryValue;
     use crate::ClaimsSelection;
     use crate::CredentialQuery;
     use crate::CredentialQueryFormat;
+    use crate::CredentialSetQuery;
     use crate::Query;
 
     impl Query {
@@ -587,18 +840,48 @@ pub mod mock {
             .unwrap()
         }
 
-        pub fn example_with_name_and_address() -> Self {
-            vec![NormalizedCredentialRequest::new_mock_from_slices(
-                "my_credential",
-                MockCredentialFormat::SdJwt,
-                &["https://credentials.example.com/identity_credential"],
-                &[
-                    &["last_name"],
-                    &["first_name"],
-                    &["address", "street_address"],
-                    &["postal_code"],
-                ],
-            )]
+        pub fn example_with_values() -> Self {
+            vec![
+                NormalizedCredentialRequest::new_mock_from_slices(
+                    "my_credential",
+                    MockCredentialFormat::SdJwt,
+                    &["https://credentials.example.com/identity_credential"],
+                    &[
+                        &["last_name"],
+                        &["first_name"],
+                        &["address", "street_address"],
+                        &["postal_code"],
+                    ],
+                )
+                .with_claim_values(0, vec![ClaimsQueryValue::String("Doe".to_string())])
+                .with_claim_values(
+                    3,
+                    vec![
+                        ClaimsQueryValue::String("90210".to_string()),
+                        ClaimsQueryValue::String("90211".to_string()),
+                    ],
+                ),
+            ]
+            .try_into()
+            .unwrap()
+        }
+
+        pub fn example_with_claim_sets() -> Self {
+            vec![
+                NormalizedCredentialRequest::new_mock_from_slices(
+                    "pid",
+                    MockCredentialFormat::SdJwt,
+                    &["https://credentials.example.com/identity_credential"],
+                    &[
+                        &["last_name"],
+                        &["postal_code"],
+                        &["locality"],
+                        &["region"],
+                        &["date_of_birth"],
+                    ],
+                )
+                .with_claim_sets(&["a", "b", "c", "d", "e"], &[&["a", "c", "d", "e"], &["a", "b", "e"]]),
+            ]
             .try_into()
             .unwrap()
         }
@@ -624,6 +907,74 @@ pub mod mock {
             .try_into()
             .unwrap()
         }
+
+        pub fn example_with_credential_sets() -> Self {
+            let id_paths: &[&[&str]] = &[
+                &["org.iso.18013.5.1", "given_name"],
+                &["org.iso.18013.5.1", "family_name"],
+                &["org.iso.18013.5.1", "portrait"],
+            ];
+            let id_claim_ids: &[&str] = &["given_name", "family_name", "portrait"];
+            let address_paths: &[&[&str]] = &[
+                &["org.iso.18013.5.1", "resident_address"],
+                &["org.iso.18013.5.1", "resident_country"],
+            ];
+            let address_claim_ids: &[&str] = &["resident_address", "resident_country"];
+
+            let requests = [
+                ("mdl-id", "org.iso.18013.5.1.mDL", id_paths, id_claim_ids),
+                ("mdl-address", "org.iso.18013.5.1.mDL", address_paths, address_claim_ids),
+                ("photo_card-id", "org.iso.23220.photoid.1", id_paths, id_claim_ids),
+                (
+                    "photo_card-address",
+                    "org.iso.23220.photoid.1",
+                    address_paths,
+                    address_claim_ids,
+                ),
+            ]
+            .into_iter()
+            .map(|(id, doctype, paths, claim_ids)| {
+                NormalizedCredentialRequest::new_mock_from_slices(
+                    id,
+                    MockCredentialFormat::MsoMdoc { intent_to_retain: None },
+                    &[doctype],
+                    paths,
+                )
+                .with_claim_sets(claim_ids, &[])
+            })
+            .collect_vec();
+
+            Self::try_from(requests).unwrap().with_credential_sets(&[
+                (&[&["mdl-id"], &["photo_card-id"]], true),
+                (&[&["mdl-address"], &["photo_card-address"]], false),
+            ])
+        }
+
+        /// Replace the credential sets, where each credential set is specified as a slice of options consisting of
+        /// credential request identifiers, combined with a boolean that indicates if the set is required.
+        pub fn with_credential_sets(mut self, credential_sets: &[(&[&[&str]], bool)]) -> Self {
+            self.credential_sets = credential_sets
+                .iter()
+                .map(|(options, required)| CredentialSetQuery {
+                    options: options
+                        .iter()
+                        .map(|option| {
+                            option
+                                .iter()
+                                .map(|id| (*id).try_into().expect("identifier should be valid"))
+                                .collect_vec()
+                                .try_into()
+                                .expect("credential set option should contain unique identifiers")
+                        })
+                        .collect_vec()
+                        .try_into()
+                        .expect("credential set should contain at least one option"),
+                    required: *required,
+                })
+                .collect();
+
+            self
+        }
     }
 
     #[derive(Debug, Clone, Copy)]
@@ -638,25 +989,106 @@ pub mod mock {
                 Self::MsoMdoc {
                     id,
                     doctype_value,
+                    multiple,
                     claims,
+                    claim_sets,
                     ..
                 } => Self::MsoMdoc {
                     id,
                     doctype_value,
+                    multiple,
                     claims,
+                    claim_sets,
                     aki,
                 },
                 Self::SdJwt {
-                    id, vct_values, claims, ..
+                    id,
+                    vct_values,
+                    multiple,
+                    claims,
+                    claim_sets,
+                    ..
                 } => Self::SdJwt {
                     id,
                     vct_values,
+                    multiple,
                     claims,
+                    claim_sets,
                     aki,
                 },
             }
         }
 
+        /// Set the identifiers of the claims, in order, and replace the claim sets with ones that reference these.
+        pub fn with_claim_sets(mut self, claim_ids: &[&str], claim_sets: &[&[&str]]) -> Self {
+            let claim_ids = claim_ids
+                .iter()
+                .map(|id| ClaimsQueryIdentifier::try_from(*id).expect("identifier should be valid"))
+                .collect_vec();
+            let new_claim_sets = claim_sets
+                .iter()
+                .map(|claim_set| {
+                    claim_set
+                        .iter()
+                        .map(|id| ClaimsQueryIdentifier::try_from(*id).expect("identifier should be valid"))
+                        .collect_vec()
+                        .try_into()
+                        .expect("claim set should contain unique identifiers")
+                })
+                .collect_vec();
+
+            match &mut self {
+                Self::MsoMdoc { claims, claim_sets, .. } => {
+                    claims
+                        .iter_mut()
+                        .zip_eq(claim_ids)
+                        .for_each(|(claim, id)| claim.id = Some(id));
+                    *claim_sets = new_claim_sets;
+                }
+                Self::SdJwt { claims, claim_sets, .. } => {
+                    claims
+                        .iter_mut()
+                        .zip_eq(claim_ids)
+                        .for_each(|(claim, id)| claim.id = Some(id));
+                    *claim_sets = new_claim_sets;
+                }
+            }
+
+            self
+        }
+
+        /// Allow more than one credential to be disclosed for this request.
+        pub fn with_multiple(mut self) -> Self {
+            match &mut self {
+                Self::MsoMdoc { multiple, .. } => *multiple = true,
+                Self::SdJwt { multiple, .. } => *multiple = true,
+            }
+
+            self
+        }
+
+        /// Replace the values that the claim at the specified index should match.
+        pub fn with_claim_values(mut self, index: usize, values: Vec<ClaimsQueryValue>) -> Self {
+            match &mut self {
+                Self::MsoMdoc { claims, .. } => {
+                    claims
+                        .iter_mut()
+                        .nth(index)
+                        .expect("claim should exist at index")
+                        .values = values;
+                }
+                Self::SdJwt { claims, .. } => {
+                    claims
+                        .iter_mut()
+                        .nth(index)
+                        .expect("claim should exist at index")
+                        .values = values;
+                }
+            }
+
+            self
+        }
+
         fn new_mock_from_slices(
             id: &str,
             format: MockCredentialFormat,
@@ -682,11 +1114,18 @@ pub mod mock {
                     doctype_value: credential_types_iter
                         .exactly_one()
                         .expect("should have exactly one credential type for mdoc"),
+                    multiple: false,
                     claims: paths_iter
-                        .map(|path| MdocAttributeRequest { path, intent_to_retain })
+                        .map(|path| MdocAttributeRequest {
+                            id: None,
+                            path,
+                            values: vec![],
+                            intent_to_retain,
+                        })
                         .collect_vec()
                         .try_into()
                         .expect("should contain at least one claim"),
+                    claim_sets: vec![],
                     aki: vec![],
                 },
                 MockCredentialFormat::SdJwt => Self::SdJwt {
@@ -695,11 +1134,17 @@ pub mod mock {
                         .collect_vec()
                         .try_into()
                         .expect("should have at least one credential type for SD-JWT"),
+                    multiple: false,
                     claims: paths_iter
-                        .map(|path| SdJwtAttributeRequest { path })
+                        .map(|path| SdJwtAttributeRequest {
+                            id: None,
+                            path,
+                            values: vec![],
+                        })
                         .collect_vec()
                         .try_into()
                         .expect("should contain at least one claim"),
+                    claim_sets: vec![],
                     aki: vec![],
                 },
             }
@@ -745,7 +1190,11 @@ pub mod mock {
 
 #[cfg(test)]
 mod test {
+    use std::collections::HashSet;
+
+    use itertools::Itertools;
     use rstest::rstest;
+    use utils::vec_at_least::VecNonEmpty;
     use utils::vec_nonempty;
 
     use super::MdocAttributeRequest;
@@ -758,6 +1207,7 @@ mod test {
     use crate::ClaimsQueryValue;
     use crate::ClaimsSelection;
     use crate::CredentialQuery;
+    use crate::CredentialQueryIdentifier;
     use crate::Query;
     use crate::TrustedAuthoritiesQuery;
 
@@ -770,11 +1220,25 @@ mod test {
         Query::example_with_multiple_credentials(),
         Ok(NormalizedCredentialRequests::example_with_multiple_credentials())
     )]
-    #[case(Query::example_with_credential_sets(), Err(UnsupportedDcqlFeatures::CredentialSets))]
-    #[case(Query::example_with_claim_sets(), Err(UnsupportedDcqlFeatures::ClaimSets))]
+    #[case(
+        Query::example_with_credential_sets(),
+        Ok(NormalizedCredentialRequests::example_with_credential_sets())
+    )]
+    #[case(
+        query_with_unknown_credential_set_identifier(),
+        Err(UnsupportedDcqlFeatures::UnknownCredentialSetIdentifiers(HashSet::from(["mdl-unknown".try_into().unwrap()])))
+    )]
+    #[case(
+        query_with_only_optional_credential_sets(),
+        Err(UnsupportedDcqlFeatures::NoRequiredCredentialSet)
+    )]
+    #[case(
+        Query::example_with_claim_sets(),
+        Ok(NormalizedCredentialRequests::example_with_claim_sets())
+    )]
     #[case(
         Query::example_with_values(),
-        Ok(NormalizedCredentialRequests::example_with_name_and_address())
+        Ok(NormalizedCredentialRequests::example_with_values())
     )]
     #[case(
         Query::new_mock_mdoc_iso_example(),
@@ -784,16 +1248,16 @@ mod test {
         Query::new_mock_mdoc_pid_example(),
         Ok(NormalizedCredentialRequests::new_mock_mdoc_pid_example())
     )]
-    #[case(
-        mdoc_query_multiple_queries(),
-        Err(UnsupportedDcqlFeatures::MultipleCredentialQueries)
-    )]
+    #[case(mdoc_query_multiple(), Ok(normalized_mdoc_multiple()))]
     #[case(
         mdoc_query_with_unsupported_trusted_authorities(),
         Err(UnsupportedDcqlFeatures::UnsupportedTrustedAuthority(vec_nonempty!["unsupported".to_string()]))
     )]
     #[case(mdoc_query_without_claims(), Err(UnsupportedDcqlFeatures::NoClaims))]
-    #[case(mdoc_query_with_claim_sets(), Err(UnsupportedDcqlFeatures::ClaimSets))]
+    #[case(
+        mdoc_query_with_unknown_claim_set_identifier(),
+        Err(UnsupportedDcqlFeatures::UnknownClaimSetIdentifiers(HashSet::from(["1".try_into().unwrap()])))
+    )]
     #[case(
         mdoc_query_with_invalid_claim_path_variant_all(),
         Err(UnsupportedDcqlFeatures::UnsupportedClaimPathVariant)
@@ -802,13 +1266,14 @@ mod test {
         mdoc_query_with_invalid_claim_path_variant_by_index(),
         Err(UnsupportedDcqlFeatures::UnsupportedClaimPathVariant)
     )]
-    #[case(mdoc_query_with_values(), Ok(normalized_mdoc_with_ns_attr()))]
+    #[case(mdoc_query_with_values(), Ok(normalized_mdoc_with_values()))]
     #[case(
         mdoc_query_without_cryptographic_holder_binding_requirement(),
         Err(UnsupportedDcqlFeatures::CryptographicHolderBindingNotRequired)
     )]
     #[case(sd_jwt_single_query(), Ok(sd_jwt_single_request()))]
-    #[case(sd_jwt_values_query(), Ok(normalized_sd_jwt_family_name()))]
+    #[case(sd_jwt_values_query(), Ok(normalized_sd_jwt_with_values()))]
+    #[case(sd_jwt_array_elements_query(), Ok(normalized_sd_jwt_array_elements()))]
     #[case(sd_jwt_no_selectively_disclosable_query(), Err(UnsupportedDcqlFeatures::NoClaims))]
     #[case(sd_jwt_intent_to_retain_query(), Err(UnsupportedDcqlFeatures::IntentToRetainPresent))]
     fn test_conversion(
@@ -820,13 +1285,142 @@ mod test {
         assert_eq!(result, expected);
 
         // If the conversion succeeds, test that the conversion back matches the input.
-        // Note: this requires that the input does not use `ClaimsQuery` identifiers, and that
-        // claim values are stripped from the input before comparing, since normalization drops them.
         if let Ok(normalized) = result {
-            assert_eq!(Query::from(normalized), strip_claim_values(query));
+            assert_eq!(Query::from(normalized), query);
         }
     }
 
+    fn query_with_unknown_credential_set_identifier() -> Query {
+        let mut query = Query::example_with_credential_sets();
+        query.credential_sets[0].options = vec_nonempty![
+            vec_nonempty![CredentialQueryIdentifier::try_from("mdl-id").unwrap()].into(),
+            vec_nonempty![CredentialQueryIdentifier::try_from("mdl-unknown").unwrap()].into(),
+        ];
+        query
+    }
+
+    fn query_with_only_optional_credential_sets() -> Query {
+        let mut query = Query::example_with_credential_sets();
+        query.credential_sets[0].required = false;
+        query
+    }
+
+    fn ids<'a>(ids: &[&'a CredentialQueryIdentifier]) -> HashSet<&'a CredentialQueryIdentifier> {
+        ids.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_satisfiable_credential_sets() {
+        // PID OR (passport AND address), optionally followed by a phone number.
+        let requests = NormalizedCredentialRequests::new_mock_sd_jwt_from_slices(&[
+            (&["pid"], &[&["given_name"]]),
+            (&["passport"], &[&["given_name"]]),
+            (&["address"], &[&["street_address"]]),
+            (&["phone"], &[&["phone_number"]]),
+        ])
+        .with_credential_sets(&[
+            (&[&["sd_jwt_0"], &["sd_jwt_1", "sd_jwt_2"]], true),
+            (&[&["sd_jwt_3"]], false),
+        ]);
+        let [pid, passport, address, phone] =
+            ["sd_jwt_0", "sd_jwt_1", "sd_jwt_2", "sd_jwt_3"].map(|id| CredentialQueryIdentifier::try_from(id).unwrap());
+
+        // All options can be satisfied.
+        let sets = requests
+            .satisfiable_credential_sets(&ids(&[&pid, &passport, &address, &phone]))
+            .expect("credential sets should be satisfiable");
+
+        assert_eq!(sets.len().get(), 2);
+        assert!(sets[0].required);
+        assert_eq!(
+            sets[0].options,
+            vec_nonempty![vec_nonempty![&pid], vec_nonempty![&passport, &address]]
+        );
+        assert!(!sets[1].required);
+        assert_eq!(sets[1].options, vec_nonempty![vec_nonempty![&phone]]);
+
+        // Only the second option of the required set can be satisfied, the optional set is left out.
+        let sets = requests
+            .satisfiable_credential_sets(&ids(&[&passport, &address]))
+            .expect("credential sets should be satisfiable");
+
+        assert_eq!(sets.len().get(), 1);
+        assert_eq!(sets[0].options, vec_nonempty![vec_nonempty![&passport, &address]]);
+
+        // The required set cannot be satisfied when only part of an option is satisfiable.
+        let error = requests
+            .satisfiable_credential_sets(&ids(&[&passport, &phone]))
+            .expect_err("credential sets should not be satisfiable");
+
+        assert_eq!(error, vec_nonempty![0]);
+    }
+
+    #[test]
+    fn test_satisfiable_credential_sets_without_credential_sets() {
+        let requests = NormalizedCredentialRequests::example_with_multiple_credentials();
+        let [pid, mdl] = ["pid", "mdl"].map(|id| CredentialQueryIdentifier::try_from(id).unwrap());
+
+        let sets = requests
+            .satisfiable_credential_sets(&ids(&[&pid, &mdl]))
+            .expect("credential sets should be satisfiable");
+
+        assert_eq!(sets.len().get(), 1);
+        assert!(sets[0].required);
+        assert_eq!(sets[0].options, vec_nonempty![vec_nonempty![&pid, &mdl]]);
+
+        let error = requests
+            .satisfiable_credential_sets(&ids(&[&pid]))
+            .expect_err("credential sets should not be satisfiable");
+
+        assert_eq!(error, vec_nonempty![0]);
+    }
+
+    #[test]
+    fn test_claim_sets() {
+        let request = NormalizedCredentialRequests::example_with_claim_sets()
+            .into_iter()
+            .next()
+            .unwrap();
+
+        let claim_set_keys = request
+            .claim_sets()
+            .into_iter()
+            .map(|claim_set| {
+                claim_set
+                    .into_iter()
+                    .map(|claim| claim.path.first().try_key_path().unwrap().to_string())
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        assert_eq!(
+            claim_set_keys,
+            vec![
+                vec!["last_name", "locality", "region", "date_of_birth"],
+                vec!["last_name", "postal_code", "date_of_birth"],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_claim_sets_without_claim_sets() {
+        let request = NormalizedCredentialRequests::example_with_values()
+            .into_iter()
+            .next()
+            .unwrap();
+
+        let claim_sets = request.claim_sets();
+
+        assert!(!request.has_claim_sets());
+        assert_eq!(claim_sets.len().get(), 1);
+        assert_eq!(claim_sets[0].len().get(), 4);
+        assert_eq!(
+            claim_sets[0][0].values,
+            vec![ClaimsQueryValue::String("Doe".to_string())]
+        );
+        assert!(claim_sets[0][1].values.is_empty());
+    }
+
     fn mdoc_example_query_mutate_first_credential_query<F>(mutate: F) -> Query
     where
         F: FnOnce(CredentialQuery) -> CredentialQuery,
@@ -838,7 +1432,7 @@ mod test {
         query
     }
 
-    fn mdoc_query_multiple_queries() -> Query {
+    fn mdoc_query_multiple() -> Query {
         mdoc_example_query_mutate_first_credential_query(|mut c| {
             c.multiple = true;
             c
@@ -860,7 +1454,7 @@ mod test {
         })
     }
 
-    fn mdoc_query_with_claim_sets() -> Query {
+    fn mdoc_query_with_unknown_claim_set_identifier() -> Query {
         mdoc_example_query_mutate_first_credential_query(|mut c| {
             c.claims_selection = ClaimsSelection::Combinations {
                 claims: vec![mdoc_claims_query()].try_into().unwrap(),
@@ -946,31 +1540,45 @@ mod test {
         )])
     }
 
-    fn normalized_mdoc_with_ns_attr() -> NormalizedCredentialRequests {
+    fn normalized_mdoc_multiple() -> NormalizedCredentialRequests {
+        vec![NormalizedCredentialRequest::new_mock_mdoc_iso_example().with_multiple()]
+            .try_into()
+            .unwrap()
+    }
+
+    fn normalized_mdoc_with_values() -> NormalizedCredentialRequests {
         vec![NormalizedCredentialRequest::MsoMdoc {
             id: "mdoc_iso_example".try_into().unwrap(),
             doctype_value: "org.iso.18013.5.1.mDL".to_string(),
+            multiple: false,
             aki: vec![],
             claims: vec_nonempty![MdocAttributeRequest {
+                id: None,
                 path: vec_nonempty![
                     ClaimPath::SelectByKey("ns".to_string()),
                     ClaimPath::SelectByKey("attr".to_string()),
                 ],
+                values: vec![ClaimsQueryValue::Boolean(true)],
                 intent_to_retain: Some(true),
             }],
+            claim_sets: vec![],
         }]
         .try_into()
         .unwrap()
     }
 
-    fn normalized_sd_jwt_family_name() -> NormalizedCredentialRequests {
+    fn normalized_sd_jwt_with_values() -> NormalizedCredentialRequests {
         vec![NormalizedCredentialRequest::SdJwt {
             id: "intent_to_retain".try_into().unwrap(),
             vct_values: vec_nonempty!["pid".to_string()],
+            multiple: false,
             aki: vec![],
             claims: vec_nonempty![SdJwtAttributeRequest {
+                id: None,
                 path: vec_nonempty![ClaimPath::SelectByKey("family_name".to_string())],
+                values: vec![ClaimsQueryValue::String("Name".to_string())],
             }],
+            claim_sets: vec![],
         }]
         .try_into()
         .unwrap()
@@ -996,6 +1604,65 @@ mod test {
         }
     }
 
+    fn sd_jwt_array_element_claim_paths() -> [VecNonEmpty<ClaimPath>; 2] {
+        [
+            vec_nonempty![
+                ClaimPath::SelectByKey("nationalities".to_string()),
+                ClaimPath::SelectAll
+            ],
+            vec_nonempty![
+                ClaimPath::SelectByKey("address".to_string()),
+                ClaimPath::SelectByKey("lines".to_string()),
+                ClaimPath::SelectByIndex(0),
+            ],
+        ]
+    }
+
+    fn sd_jwt_array_elements_query() -> Query {
+        let mut credential_query = CredentialQuery::new_mock_sd_jwt("array_elements", &["pid"], &[&["family_name"]]);
+
+        credential_query.claims_selection = ClaimsSelection::All {
+            claims: sd_jwt_array_element_claim_paths()
+                .into_iter()
+                .map(|path| ClaimsQuery {
+                    id: None,
+                    path,
+                    values: vec![],
+                    intent_to_retain: None,
+                })
+                .collect_vec()
+                .try_into()
+                .unwrap(),
+        };
+
+        Query {
+            credentials: vec![credential_query].try_into().unwrap(),
+            credential_sets: vec![],
+        }
+    }
+
+    fn normalized_sd_jwt_array_elements() -> NormalizedCredentialRequests {
+        vec![NormalizedCredentialRequest::SdJwt {
+            id: "array_elements".try_into().unwrap(),
+            vct_values: vec_nonempty!["pid".to_string()],
+            multiple: false,
+            aki: vec![],
+            claims: sd_jwt_array_element_claim_paths()
+                .into_iter()
+                .map(|path| SdJwtAttributeRequest {
+                    id: None,
+                    path,
+                    values: vec![],
+                })
+                .collect_vec()
+                .try_into()
+                .unwrap(),
+            claim_sets: vec![],
+        }]
+        .try_into()
+        .unwrap()
+    }
+
     fn sd_jwt_no_selectively_disclosable_query() -> Query {
         let mut credential_query = CredentialQuery::new_mock_sd_jwt("intent_to_retain", &["pid"], &[&["family_name"]]);
 
@@ -1026,32 +1693,4 @@ mod test {
             credential_sets: vec![],
         }
     }
-
-    fn strip_claim_values(query: Query) -> Query {
-        Query {
-            credentials: query
-                .credentials
-                .into_iter()
-                .map(|mut cq| {
-                    if let ClaimsSelection::All { claims } = cq.claims_selection {
-                        cq.claims_selection = ClaimsSelection::All {
-                            claims: claims
-                                .into_iter()
-                                .map(|mut c| {
-                                    c.values = vec![];
-                                    c
-                                })
-                                .collect::<Vec<_>>()
-                                .try_into()
-                                .unwrap(),
-                        };
-                    }
-                    cq
-                })
-                .collect::<Vec<_>>()
-                .try_into()
-                .unwrap(),
-            credential_sets: query.credential_sets,
-        }
-    }
 }
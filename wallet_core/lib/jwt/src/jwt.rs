@@ -500,6 +500,18 @@ impl<T: Serialize + JwtTyp> SignedJwt<T, HeaderWithJwk> {
     }
 }
 
+impl<T: Serialize + JwtTyp> SignedJwt<T, HeaderWithKid> {
+    /// Sign a payload into a JWT, and put the provided key identifier in the `kid` field in the header. The resulting
+    /// JWT can be verified using [`UnverifiedJwt::parse_and_verify_with_jwkset`].
+    pub async fn sign_with_kid(
+        payload: &T,
+        kid: String,
+        privkey: &impl EcdsaKey,
+    ) -> Result<SignedJwt<T, HeaderWithKid>, JwtSignError> {
+        SignedJwt::sign_with_header(HeaderWithKid::from_kid(kid), payload, privkey).await
+    }
+}
+
 impl<T: Serialize + JwtTyp> SignedJwt<T> {
     /// Have the WSCD sign multiple [`UnverifiedJwt`]s at once, producing a PoA if more than one unique key is used.
     pub async fn sign_multiple<'a, W, K, P>(
@@ -1079,7 +1091,7 @@ impl From<VecNonEmpty<JsonJwtSignature>> for JsonJwtSignatures {
 }
 
 #[serde_as]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct JsonJwtSignature {
     /// Base64-enoded JWS header, the same as the header of a normal JWS. `alg` is required.
     pub protected: String,
@@ -1612,20 +1624,10 @@ mod tests {
         let jwks = JwkSet { keys: vec![jwk] };
 
         let payload = ToyMessage::default();
-        // Since there's no `sign_with_kid`, sign with a raw header that carries the kid and then cast the type by
-        // re-serializing
-        let header = Header {
-            alg: Algorithm::ES256,
-            kid: Some(kid.clone()),
-            ..Default::default()
-        };
-        let jwt: UnverifiedJwt<ToyMessage, HeaderWithKid> = SignedJwt::sign_with_header(header, &payload, &signing_key)
+        let jwt = SignedJwt::sign_with_kid(&payload, kid.clone(), &signing_key)
             .await
             .unwrap()
-            .into_unverified()
-            .serialization()
-            .parse()
-            .unwrap();
+            .into_unverified();
 
         let (verified_header, deserialized) = jwt
             .parse_and_verify_with_jwkset(&jwks, DEFAULT_VALIDATION.to_owned())
@@ -11,6 +11,7 @@ use utils::vec_at_least::VecNonEmpty;
 use super::MissingAttributesError;
 use crate::iso::disclosure::IssuerSigned;
 use crate::iso::mdocs::Attributes;
+use crate::iso::mdocs::DataElementValue;
 use crate::utils::serialization::TaggedBytes;
 
 /// Helper function for converting a claim path to a tuple of name space and element identifier.
@@ -65,6 +66,21 @@ impl IssuerSigned {
         Ok(())
     }
 
+    /// Returns the value of the attribute addressed by the claim path, if present. Note that any claim path that is not
+    /// a full key path or has anything else than two elements will not address any attribute.
+    pub fn attribute_value(&self, claim_path: &VecNonEmpty<ClaimPath>) -> Option<&DataElementValue> {
+        let (name_space, element_id) = claim_path_to_mdoc_path(claim_path)?;
+
+        self.name_spaces
+            .as_ref()?
+            .as_ref()
+            .get(name_space)?
+            .as_ref()
+            .iter()
+            .find(|TaggedBytes(signed_item)| signed_item.element_identifier == *element_id)
+            .map(|TaggedBytes(signed_item)| &signed_item.element_value)
+    }
+
     /// Prune the [`IssuerSigned`] of any attributes that are not covered by the claim paths. This may result in its
     /// `name_spaces` field to be set to `None`. Note that claim paths that are not full key paths or do not consist of
     /// two elements are unsupported and will result in an error.
@@ -240,6 +256,20 @@ mod tests {
         }
     }
 
+    #[rstest]
+    #[case(claim_path(&["org.iso.18013.5.1", "family_name"]), Some(ciborium::Value::Text("Doe".to_string())))]
+    #[case(claim_path(&["org.iso.18013.5.1", "is_rich"]), None)]
+    #[case(claim_path(&["vroom", "family_name"]), None)]
+    #[case(claim_path(&["org.iso.18013.5.1", "family_name", "first_letter"]), None)]
+    fn test_issuer_signed_attribute_value(
+        #[case] claim_path: VecNonEmpty<ClaimPath>,
+        #[case] expected_value: Option<ciborium::Value>,
+    ) {
+        let issuer_signed = issuer_signed_example();
+
+        assert_eq!(issuer_signed.attribute_value(&claim_path), expected_value.as_ref());
+    }
+
     #[derive(Debug)]
     enum IntoAttributeSubsetResult<'a> {
         Success(HashSet<(&'a str, &'a str)>),
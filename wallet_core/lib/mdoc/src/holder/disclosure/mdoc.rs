@@ -37,6 +37,17 @@ impl PartialMdoc {
         Ok(partial_mdoc)
     }
 
+    /// Further reduce the attributes contained in this [`PartialMdoc`] to those covered by the claim paths. Note that
+    /// this can only select attributes that are already present.
+    pub fn try_into_attribute_subset<'a>(
+        self,
+        claim_paths: impl IntoIterator<Item = &'a VecNonEmpty<ClaimPath>>,
+    ) -> std::result::Result<Self, MissingAttributesError> {
+        let issuer_signed = self.issuer_signed.into_attribute_subset(claim_paths)?;
+
+        Ok(Self { issuer_signed, ..self })
+    }
+
     pub fn doc_type(&self) -> &str {
         &self.doc_type
     }
@@ -345,6 +345,8 @@ where
             .write_auth_code_issued_session(AuthCodeIssued {
                 grant: Grant::AuthorizationCode(auth_request_values),
                 credential_ids_and_documents,
+                deferred: false,
+                tx_code: None,
             })
             .await
             .map_err(CompleteAuthorizationError::SessionStore)?;
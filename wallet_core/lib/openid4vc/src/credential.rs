@@ -4,6 +4,7 @@ use std::fmt::Formatter;
 use std::time::Duration;
 
 use attestation_types::credential_format::Format;
+use jwk_simple::Key;
 use jwt::UnverifiedJwt;
 use jwt::headers::HeaderWithJwk;
 use jwt::pop::JwtPopClaims;
@@ -22,12 +23,16 @@ use utils::spec::SpecOptional;
 use utils::vec_at_least::VecNonEmpty;
 use utils::vec_nonempty;
 
+use crate::jwe::JweCompressionAlgorithm;
+use crate::jwe::JweEncryptionAlgorithm;
+
 /// <https://openid.net/specs/openid-4-verifiable-credential-issuance-1_0-13.html#section-8.1>.
 /// Sent JSON-encoded to `POST /batch_credential`.
 #[skip_serializing_none]
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CredentialRequests {
     pub credential_requests: VecNonEmpty<CredentialRequest>,
+    pub credential_response_encryption: Option<CredentialResponseEncryptionParameters>,
 }
 
 /// <https://openid.net/specs/openid-4-verifiable-credential-issuance-1_0-13.html#section-7.2>.
@@ -38,6 +43,20 @@ pub struct CredentialRequest {
     #[serde(flatten)]
     pub credential_type: SpecOptional<CredentialRequestType>,
     pub proof: Option<CredentialRequestProof>,
+    pub credential_response_encryption: Option<CredentialResponseEncryptionParameters>,
+}
+
+/// Parameters by which the wallet requests the Credential Response to be encrypted, see:
+/// <https://openid.net/specs/openid-4-verifiable-credential-issuance-1_0.html#section-8.2>.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CredentialResponseEncryptionParameters {
+    /// Public key in JWK format to which the Credential Response should be encrypted.
+    pub jwk: Key,
+    /// The JWE content encryption algorithm to use for the Credential Response.
+    pub enc: JweEncryptionAlgorithm,
+    /// The JWE compression algorithm to apply to the Credential Response prior to encryption, if any.
+    pub zip: Option<JweCompressionAlgorithm>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -123,6 +142,36 @@ impl CredentialResponse {
         }
     }
 
+    pub fn new_deferred(transaction_id: String, interval: Duration) -> Self {
+        Self::Deferred {
+            transaction_id,
+            interval,
+        }
+    }
+
+    /// Set the `notification_id` of an immediate response, which the wallet can use to notify the issuer about the
+    /// status of the issued credential. This has no effect on a deferred response.
+    pub fn with_notification_id(self, notification_id: String) -> Self {
+        match self {
+            Self::Immediate { credentials, .. } => Self::Immediate {
+                credentials,
+                notification_id: Some(notification_id),
+            },
+            Self::Deferred { .. } => self,
+        }
+    }
+
+    pub fn is_deferred(&self) -> bool {
+        matches!(self, Self::Deferred { .. })
+    }
+
+    pub fn notification_id(&self) -> Option<&str> {
+        match self {
+            Self::Immediate { notification_id, .. } => notification_id.as_deref(),
+            Self::Deferred { .. } => None,
+        }
+    }
+
     // TODO (PVW-5554): Replace this with into_immediate_credential().
     pub fn into_immediate_credential(self) -> Option<Credential> {
         match self {
@@ -132,6 +181,48 @@ impl CredentialResponse {
     }
 }
 
+/// A response from the Credential Endpoint, which is sent as a JWE using the `application/jwt` media type when the
+/// wallet requested encryption of the response.
+#[derive(Clone, Debug)]
+pub enum MaybeEncrypted<T> {
+    Plain(T),
+    Encrypted(String),
+}
+
+/// A Deferred Credential Request, see:
+/// <https://openid.net/specs/openid-4-verifiable-credential-issuance-1_0.html#section-9.1>.
+/// Sent JSON-encoded to `POST /deferred_credential`.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DeferredCredentialRequest {
+    pub transaction_id: String,
+    pub credential_response_encryption: Option<CredentialResponseEncryptionParameters>,
+}
+
+/// A Deferred Credential Response, see:
+/// <https://openid.net/specs/openid-4-verifiable-credential-issuance-1_0.html#section-9.2>.
+///
+/// While the issuer has not yet made a decision, the same `transaction_id` is returned along with a new `interval`
+/// (using HTTP status code 202). Once the credentials are issued, these are returned in the same format as the
+/// response of the `/batch_credential` endpoint, containing one [`CredentialResponse`] per credential copy.
+#[serde_as]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DeferredCredentialResponse {
+    Issued(CredentialResponses),
+    Pending {
+        transaction_id: String,
+        #[serde_as(as = "DurationSeconds<u64>")]
+        interval: Duration,
+    },
+}
+
+impl DeferredCredentialResponse {
+    pub fn is_pending(&self) -> bool {
+        matches!(self, Self::Pending { .. })
+    }
+}
+
 #[serde_as]
 #[derive(Clone, Debug, Serialize)]
 #[serde(untagged)]
@@ -196,6 +287,29 @@ impl Credential {
     }
 }
 
+#[cfg(feature = "axum")]
+mod axum {
+    use axum::Json;
+    use axum::http::header::CONTENT_TYPE;
+    use axum::response::IntoResponse;
+    use axum::response::Response;
+    use serde::Serialize;
+
+    use super::MaybeEncrypted;
+
+    impl<T> IntoResponse for MaybeEncrypted<T>
+    where
+        T: Serialize,
+    {
+        fn into_response(self) -> Response {
+            match self {
+                Self::Plain(response) => Json(response).into_response(),
+                Self::Encrypted(jwe) => ([(CONTENT_TYPE, "application/jwt")], jwe).into_response(),
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::assert_matches;
@@ -212,6 +326,7 @@ mod tests {
 
     use super::Credential;
     use super::CredentialResponse;
+    use super::DeferredCredentialResponse;
 
     #[test]
     fn test_deferred_credential_response_serialization() {
@@ -238,6 +353,45 @@ mod tests {
         assert_eq!(json, output_json);
     }
 
+    #[test]
+    fn test_deferred_credential_response_pending_serialization() {
+        // Source: https://openid.net/specs/openid-4-verifiable-credential-issuance-1_0.html#section-9.2-5
+        let json = json!({
+            "transaction_id": "8xLOxBtZp8",
+            "interval": 86400
+        });
+
+        let response = serde_json::from_value::<DeferredCredentialResponse>(json.clone())
+            .expect("pending deferred credential response JSON should parse correctly");
+
+        assert_matches!(
+            &response,
+            DeferredCredentialResponse::Pending {
+                transaction_id,
+                interval
+            } if transaction_id == "8xLOxBtZp8" && *interval == Duration::from_hours(24)
+        );
+
+        let output_json =
+            serde_json::to_value(response).expect("pending deferred credential response should serialize to JSON");
+
+        assert_eq!(json, output_json);
+
+        let json = json!({
+            "credential_responses": [{
+                "credentials": [{ "credential": SD_JWT_VC }]
+            }]
+        });
+
+        let response = serde_json::from_value::<DeferredCredentialResponse>(json)
+            .expect("issued deferred credential response JSON should parse correctly");
+
+        assert_matches!(
+            response,
+            DeferredCredentialResponse::Issued(responses) if responses.credential_responses.len() == 1
+        );
+    }
+
     #[test]
     fn test_sd_jwt_credential_response_serialization() {
         // Source: https://openid.net/specs/openid-4-verifiable-credential-issuance-1_0.html#section-8.3-8
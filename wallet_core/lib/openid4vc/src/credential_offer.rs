@@ -108,10 +108,32 @@ impl CredentialOffer {
         credential_configuration_ids: VecNonEmptyUnique<CredentialConfigurationId>,
         pre_authorized_code: AuthorizationCode,
     ) -> Self {
+        Self::new_pre_authorized_with_tx_code(
+            credential_issuer,
+            credential_configuration_ids,
+            pre_authorized_code,
+            None,
+        )
+    }
+
+    pub fn new_pre_authorized_with_tx_code(
+        credential_issuer: IssuerIdentifier,
+        credential_configuration_ids: VecNonEmptyUnique<CredentialConfigurationId>,
+        pre_authorized_code: AuthorizationCode,
+        tx_code: Option<PreAuthTransactionCode>,
+    ) -> Self {
+        let grant_pre_authorized_code = GrantPreAuthorizedCode {
+            tx_code,
+            ..GrantPreAuthorizedCode::new(pre_authorized_code)
+        };
+
         Self {
             credential_issuer,
             credential_configuration_ids,
-            grants: Some(Grants::new_pre_authorized(pre_authorized_code)),
+            grants: Some(Grants {
+                pre_authorized_code: Some(grant_pre_authorized_code),
+                ..Grants::default()
+            }),
         }
     }
 }
@@ -225,7 +247,7 @@ impl GrantPreAuthorizedCode {
 ///
 /// <https://openid.net/specs/openid-4-verifiable-credential-issuance-1_0.html#section-4.1.1-5.2.2.2.1>
 #[skip_serializing_none]
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PreAuthTransactionCode {
     /// String specifying the input character set.
     pub input_mode: Option<PreAuthTransactionCodeInputMode>,
@@ -5,7 +5,6 @@ use dcql::normalized::NormalizedCredentialRequest;
 use derive_more::Constructor;
 use http_utils::reqwest::HttpClient;
 use http_utils::urls::BaseUrl;
-use serde::Deserialize;
 use tracing::info;
 use tracing::warn;
 use utils::single_unique::NonEmptySingleUnique;
@@ -30,7 +29,6 @@ use crate::openid4vp::VpAuthorizationRequest;
 use crate::openid4vp::VpRequestUri;
 use crate::openid4vp::VpRequestUriMethod;
 use crate::openid4vp::VpRequestUriObject;
-use crate::verifier::SessionType;
 
 #[derive(Debug, Constructor)]
 pub struct VpDisclosureClient<H = HttpVpMessageClient> {
@@ -62,7 +60,7 @@ impl<H> VpDisclosureClient<H> {
             | VpVerifierError::RpCertificate(_) => Some(VpAuthorizationErrorCode::InvalidRequest),
 
             // None.
-            VpVerifierError::Request(_) => None,
+            VpVerifierError::Request(_) | VpVerifierError::RequestObject(_) => None,
 
             // Formats not supported.
             VpVerifierError::VpFormatsNotSupported(_) => Some(VpAuthorizationErrorCode::VpFormatsNotSupported),
@@ -107,72 +105,59 @@ where
         let request = serde_qs::from_str::<VpRequestUri>(uri_query)
             .map_err(VpClientError::RequestUri)
             .map_err(VpSessionError::Client)?;
-        let (request_uri, request_uri_method) = match request.object {
+        // The Authorization Request JWT is either fetched from the `request_uri` or passed to us directly in the
+        // `request` parameter. Either way, it is authenticated below using the `client_id` scheme. Authorization
+        // Requests passed entirely as query parameters are not signed and can therefore not be authenticated.
+        let (jws, request_nonce) = match request.object {
             VpRequestUriObject::AsReference {
                 request_uri,
                 request_uri_method,
-            } => Ok((request_uri, request_uri_method)),
-            VpRequestUriObject::AsValue { .. } => Err(VpClientError::UnsupportedRequestUriVariant(
-                UnsupportedRequestUriVariant::RequestObjectAsValue,
-            )),
-            VpRequestUriObject::AsQueryParameters { .. } => Err(VpClientError::UnsupportedRequestUriVariant(
-                UnsupportedRequestUriVariant::RequestObjectAsQueryParameters,
-            )),
-        }
-        .map_err(VpSessionError::Client)?;
+            } => {
+                uri_source
+                    .check_url_session_type(request_uri.as_ref())
+                    .map_err(VpSessionError::Client)?;
+
+                // If the server supports it, require it to include a nonce in the Authorization Request JWT
+                let request_nonce = match request_uri_method.unwrap_or_default() {
+                    VpRequestUriMethod::GET => None,
+                    VpRequestUriMethod::POST => Some(crypto_utils::random_string(32)),
+                };
 
-        #[derive(Deserialize)]
-        struct UrlSessionType {
-            session_type: SessionType,
-        }
+                let jws = self
+                    .client
+                    .get_authorization_request(request_uri, request_nonce.clone())
+                    .await?;
 
-        // Parse the `SessionType` from the verifier URL.
-        // If it is not present, or if it does not contain one of the two expected values, we simply ignore it.
-        let url_session_type = request_uri
-            .as_ref()
-            .query()
-            .and_then(|query| serde_qs::from_str(query).ok()) // discard the error: see comment below
-            .map(|params: UrlSessionType| params.session_type);
-
-        let source_session_type = uri_source.session_type();
-
-        // If the verifier URL contained a `session_type` parameter of one of the two allowed values,
-        // (i.e., `same_device` or `cross_device`), check it against the source of the URI.
-        // A `same_device` session is expected to come from a Universal Link, while a `cross_device` session
-        // should come from a scanned QR code.
-        //
-        // The `verification_server` will always use the `session_type` parameters. In case of other verifiers:
-        // - If they don't use it, or if they do but with values other than `same_device` or `cross_device`, the
-        //   deserialization above maps it to `None`. (This does not break the downgrade attack prevention that this
-        //   mechanism realizes for the `verification_server`.)
-        // - If they use it in the correct manner (e.g. `same_device` for a UL), this will work fine.
-        // - If they use it incorrectly (e.g. `same_device` for a QR code) this will break the session and therefore
-        //   compatibility with that verifier, but such a situation would be bizarre and is not to be expected.
-        if let Some(session_type) = url_session_type
-            && source_session_type != session_type
-        {
-            return Err(VpSessionError::Client(VpClientError::DisclosureUriSourceMismatch(
-                session_type,
-                uri_source,
-            )));
-        }
-
-        // If the server supports it, require it to include a nonce in the Authorization Request JWT
-        let request_method = request_uri_method.unwrap_or_default();
-        let request_nonce = match request_method {
-            VpRequestUriMethod::GET => None,
-            VpRequestUriMethod::POST => Some(crypto_utils::random_string(32)),
+                (jws, request_nonce)
+            }
+            // There is no request to the verifier in which we could include a nonce in this case.
+            VpRequestUriObject::AsValue { request } => {
+                let jws = request
+                    .parse()
+                    .map_err(VpVerifierError::RequestObject)
+                    .map_err(VpSessionError::Verifier)?;
+
+                (jws, None)
+            }
+            VpRequestUriObject::AsQueryParameters { .. } => {
+                return Err(VpSessionError::Client(VpClientError::UnsupportedRequestUriVariant(
+                    UnsupportedRequestUriVariant::RequestObjectAsQueryParameters,
+                )));
+            }
         };
 
-        let jws = self
-            .client
-            .get_authorization_request(request_uri, request_nonce.clone())
-            .await?;
-
         let (vp_auth_request, certificate) = VpAuthorizationRequest::try_new(&jws, trust_anchors)?;
         let response_uri = vp_auth_request.response_uri.clone();
         let state = vp_auth_request.oauth_request.state.clone();
 
+        // The authenticated `response_uri` is subject to the same `session_type` check as the `request_uri`. This is
+        // the only verifier URL we have when the Authorization Request was passed by value.
+        if let Some(response_uri) = &response_uri {
+            uri_source
+                .check_url_session_type(response_uri.as_ref())
+                .map_err(VpSessionError::Client)?;
+        }
+
         // The `client_id` in the Authorization Request, which has been authenticated, has to equal
         // the `client_id` that the RP sent in the request URI at the start of the session.
         if vp_auth_request.oauth_request.client_id != request.client_id.to_string() {
@@ -243,7 +228,7 @@ where
 
         let session = VpDisclosureSession::new(
             self.client.clone(),
-            source_session_type,
+            uri_source.session_type(),
             certificate,
             auth_request,
             selected_encryption_algorithm,
@@ -521,6 +506,8 @@ mod tests {
             &RevocationVerifier::new_without_caching(Arc::new(StatusListClientStub::new(
                 ca.generate_issuer_status_list_mock().unwrap(),
             ))),
+            None,
+            None,
             false,
         )
         .now_or_never()
@@ -594,8 +581,66 @@ mod tests {
         assert_matches!(error, VpSessionError::Client(VpClientError::RequestUri(_)));
     }
 
+    fn start_disclosure_session_by_value<SF>(
+        session_type: SessionType,
+        uri_source: DisclosureUriSource,
+        transform_verifier_session: SF,
+    ) -> StartDisclosureResult
+    where
+        SF: FnOnce(MockVerifierSession) -> MockVerifierSession,
+    {
+        let verifier_session = MockVerifierSession::new(
+            &VERIFIER_URL,
+            session_type,
+            VpRequestUriMethod::default(),
+            None,
+            NormalizedCredentialRequests::new_mock_mdoc_pid_example(),
+        );
+        let verifier_session = Arc::new(transform_verifier_session(verifier_session));
+        let client = VpDisclosureClient::new(MockVerifierVpMessageClient::new(Arc::clone(&verifier_session)));
+
+        let disclosure_session_result = client
+            .start(
+                &verifier_session.request_by_value_query(),
+                uri_source,
+                &verifier_session.trust_anchors,
+            )
+            .now_or_never()
+            .unwrap();
+
+        match disclosure_session_result {
+            Ok(disclosure_session) => Ok((disclosure_session, verifier_session)),
+            Err(err) => Err((Box::new(err), verifier_session)),
+        }
+    }
+
+    #[rstest]
+    #[case(SessionType::SameDevice, DisclosureUriSource::Link)]
+    #[case(SessionType::CrossDevice, DisclosureUriSource::QrCode)]
+    fn test_vp_disclosure_client_start_request_object_as_value(
+        #[case] session_type: SessionType,
+        #[case] uri_source: DisclosureUriSource,
+    ) {
+        let (disclosure_session, verifier_session) =
+            start_disclosure_session_by_value(session_type, uri_source, std::convert::identity)
+                .expect("starting a new disclosure session with a request object passed by value should succeed");
+
+        // The Authorization Request was passed by value, so the verifier should not have been contacted.
+        assert!(verifier_session.wallet_messages.lock().is_empty());
+
+        assert_eq!(disclosure_session.session_type(), session_type);
+        assert_eq!(
+            *disclosure_session.credential_requests(),
+            NormalizedCredentialRequests::new_mock_mdoc_pid_example()
+        );
+        assert_eq!(
+            disclosure_session.certificate(),
+            verifier_session.key_pair.certificate()
+        );
+    }
+
     #[test]
-    fn test_vp_disclosure_client_start_error_unsupported_request_object_as_value() {
+    fn test_vp_disclosure_client_start_error_request_object_as_value_invalid_jwt() {
         let client = VpDisclosureClient::new(MockErrorFactoryVpMessageClient::new(
             || panic!("message client should not be called"),
             false,
@@ -604,7 +649,7 @@ mod tests {
         let query = serde_qs::to_string(&VpRequestUri {
             client_id: "client_id".into(),
             object: VpRequestUriObject::AsValue {
-                request: "eyJhbGciOiJSUzI1NiJ9.eyJpc3MiOiJzIiwiYXVkIjoicyJ9.sig".to_string(),
+                request: "not_a_jwt".to_string(),
             },
         })
         .unwrap();
@@ -614,15 +659,68 @@ mod tests {
             .now_or_never()
             .unwrap()
             .expect_err(
-                "starting a new disclosure session with a request uri object passed as value should not succeed",
+                "starting a new disclosure session with an invalid request object passed by value should not succeed",
             );
 
+        assert_matches!(error, VpSessionError::Verifier(VpVerifierError::RequestObject(_)));
+    }
+
+    #[test]
+    fn test_vp_disclosure_client_start_error_request_object_as_value_validation() {
+        let (error, verifier_session) = start_disclosure_session_by_value(
+            SessionType::SameDevice,
+            DisclosureUriSource::Link,
+            |mut verifier_session| {
+                verifier_session.trust_anchors = TrustAnchors::empty();
+
+                verifier_session
+            },
+        )
+        .expect_err(
+            "starting a new disclosure session with a request object passed by value signed by an untrusted \
+             certificate should not succeed",
+        );
+
         assert_matches!(
-            error,
-            VpSessionError::Client(VpClientError::UnsupportedRequestUriVariant(
-                UnsupportedRequestUriVariant::RequestObjectAsValue
+            *error,
+            VpSessionError::Verifier(VpVerifierError::AuthRequestValidation(
+                AuthRequestValidationError::JwtVerification(_)
             ))
         );
+        assert!(verifier_session.wallet_messages.lock().is_empty());
+    }
+
+    #[test]
+    fn test_vp_disclosure_client_start_error_request_object_as_value_incorrect_client_id() {
+        let (error, verifier_session) = start_disclosure_session_by_value(
+            SessionType::SameDevice,
+            DisclosureUriSource::Link,
+            |mut verifier_session| {
+                verifier_session.client_id = "other_client_id".to_string();
+
+                verifier_session
+            },
+        )
+        .expect_err(
+            "starting a new disclosure session with a request object passed by value where the client_id does not \
+             match the RP certificate client_id should not succeed",
+        );
+
+        assert_matches!(
+            *error,
+            VpSessionError::Verifier(VpVerifierError::IncorrectClientId {
+                expected,
+                ..
+            }) if expected == *"other_client_id"
+        );
+
+        // This error should be reported back to the verifier.
+        let wallet_messages = verifier_session.wallet_messages.lock();
+        assert_eq!(wallet_messages.len(), 1);
+        assert_matches!(
+            &wallet_messages[0],
+            WalletMessage::Error(response) if response.error() == &VpAuthorizationErrorCode::InvalidRequest
+        );
     }
 
     #[test]
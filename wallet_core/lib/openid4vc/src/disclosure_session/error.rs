@@ -5,6 +5,7 @@ use attestation_types::credential_format::Format;
 use derive_more::Constructor;
 use derive_more::Display;
 use error_category::ErrorCategory;
+use jwt::error::JwtParseError;
 
 use super::VpMessageClientError;
 use super::uri_source::DisclosureUriSource;
@@ -79,6 +80,9 @@ pub enum VpVerifierError {
     #[error("error sending OpenID4VP message: {0}")]
     Request(#[source] VpMessageClientError),
 
+    #[error("error parsing Authorization Request JWT passed by value: {0}")]
+    RequestObject(#[source] JwtParseError),
+
     #[error("error verifying Authorization Request: {0}")]
     AuthRequestValidation(#[source] AuthRequestValidationError),
 
@@ -96,8 +100,6 @@ pub enum VpVerifierError {
 
 #[derive(Debug, thiserror::Error)]
 pub enum UnsupportedRequestUriVariant {
-    #[error("request object as value is not supported")]
-    RequestObjectAsValue,
     #[error("request object as query parameters is not supported")]
     RequestObjectAsQueryParameters,
 }
@@ -221,6 +221,20 @@ impl MockVerifierSession {
         .unwrap()
     }
 
+    /// Generate a request URI query that contains the signed Authorization Request by value.
+    pub fn request_by_value_query(&self) -> String {
+        let request = self
+            .signed_auth_request(WalletRequest { wallet_nonce: None })
+            .into_unverified()
+            .to_string();
+
+        serde_qs::to_string(&VpRequestUri {
+            client_id: self.client_id.as_str().into(),
+            object: VpRequestUriObject::AsValue { request },
+        })
+        .unwrap()
+    }
+
     pub fn normalized_auth_request(&self, wallet_nonce: Option<String>) -> NormalizedVpAuthorizationRequest {
         let mut auth_request = NormalizedVpAuthorizationRequest::new_from_certificate(
             self.credential_requests.clone(),
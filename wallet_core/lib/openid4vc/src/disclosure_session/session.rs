@@ -12,6 +12,7 @@ use itertools::Itertools;
 use jwe::algorithm::EncryptionAlgorithm;
 use mdoc::iso::disclosure::DeviceResponse;
 use sd_jwt::key_binding_jwt::KeyBindingJwtBuilder;
+use sd_jwt::sd_jwt::SdJwtSerialization;
 use sd_jwt::sd_jwt::UnsignedSdJwtPresentation;
 use tracing::info;
 use tracing::warn;
@@ -230,7 +231,10 @@ where
                             .try_into()
                             .unwrap();
 
-                        (id, VerifiablePresentation::SdJwt(presentations))
+                        (
+                            id,
+                            VerifiablePresentation::SdJwt(presentations, SdJwtSerialization::Compact),
+                        )
                     })
                     .collect();
                 (vp_token, poa)
@@ -1,3 +1,7 @@
+use serde::Deserialize;
+use url::Url;
+
+use super::error::VpClientError;
 use crate::verifier::SessionType;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display)]
@@ -20,4 +24,35 @@ impl DisclosureUriSource {
             Self::QrCode => SessionType::CrossDevice,
         }
     }
+
+    /// Check the `session_type` parameter of a verifier URL against this source of the URI, if it is present and
+    /// contains one of the two allowed values (i.e., `same_device` or `cross_device`). A `same_device` session is
+    /// expected to come from a Universal Link, while a `cross_device` session should come from a scanned QR code.
+    ///
+    /// The `verification_server` will always use the `session_type` parameters. In case of other verifiers:
+    /// - If they don't use it, or if they do but with values other than `same_device` or `cross_device`, the
+    ///   deserialization below maps it to `None`. (This does not break the downgrade attack prevention that this
+    ///   mechanism realizes for the `verification_server`.)
+    /// - If they use it in the correct manner (e.g. `same_device` for a UL), this will work fine.
+    /// - If they use it incorrectly (e.g. `same_device` for a QR code) this will break the session and therefore
+    ///   compatibility with that verifier, but such a situation would be bizarre and is not to be expected.
+    pub(super) fn check_url_session_type(self, url: &Url) -> Result<(), VpClientError> {
+        #[derive(Deserialize)]
+        struct UrlSessionType {
+            session_type: SessionType,
+        }
+
+        let url_session_type = url
+            .query()
+            .and_then(|query| serde_qs::from_str(query).ok()) // discard the error: see comment above
+            .map(|params: UrlSessionType| params.session_type);
+
+        if let Some(session_type) = url_session_type
+            && self.session_type() != session_type
+        {
+            return Err(VpClientError::DisclosureUriSourceMismatch(session_type, self));
+        }
+
+        Ok(())
+    }
 }
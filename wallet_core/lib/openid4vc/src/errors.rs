@@ -25,6 +25,7 @@ use crate::authorizing_issuer::ParError;
 use crate::issuer::CredentialPreviewError;
 use crate::issuer::CredentialRequestError;
 use crate::issuer::IssuanceError;
+use crate::issuer::NotificationRequestError;
 use crate::issuer::TokenRequestError;
 use crate::issuer::WiaVerificationError;
 use crate::verifier::CancelSessionError;
@@ -483,8 +484,8 @@ impl ErrorWithCode for TokenRequestError {
             // expired) both mean the authorization grant presented at `/token` is no longer valid.
             // Per RFC 6749 section 5.2 that is exactly `invalid_grant` ("invalid, expired, revoked ...").
             //
-            // In the pre-authorized-code flow `invalid_grant` can *only* result from these two cases
-            // (there are no PKCE / client_id / scope / redirect_uri checks that also yield it), so the
+            // In the pre-authorized-code flow without a Transaction Code `invalid_grant` can *only* result from
+            // these two cases (there are no PKCE / client_id / scope / redirect_uri checks that also yield it), so the
             // wallet can unambiguously map a pre-authorized `invalid_grant` onto a specific error without
             // relying on a non-standard error code.
             Self::SessionNotFound | Self::IssuanceError(IssuanceError::UnexpectedState) => TokenErrorCode::InvalidGrant,
@@ -514,6 +515,21 @@ impl ErrorWithCode for TokenRequestError {
             Self::MissingRedirectUri | Self::RedirectUriMismatch { .. } => TokenErrorCode::InvalidRequest,
 
             Self::CredentialConfigNotOffered(_) => TokenErrorCode::ServerError,
+
+            Self::RefreshTokenUnsupported => TokenErrorCode::UnsupportedGrantType,
+
+            Self::UnknownRefreshToken | Self::RefreshTokenDpopKeyMismatch => TokenErrorCode::InvalidGrant,
+
+            Self::RefreshTokenStore(_) | Self::DocumentSource(_) | Self::RefreshedDocument(_) => {
+                TokenErrorCode::ServerError
+            }
+
+            Self::RefreshTokenScopeUnsupported(_) => TokenErrorCode::InvalidScope,
+
+            // See: <https://openid.net/specs/openid-4-verifiable-credential-issuance-1_0.html#section-6.3>
+            Self::MissingTxCode => TokenErrorCode::InvalidRequest,
+
+            Self::IncorrectTxCode { .. } | Self::TxCodeAttemptsExceeded => TokenErrorCode::InvalidGrant,
         }
     }
 }
@@ -567,6 +583,9 @@ pub enum CredentialErrorCode {
     InvalidEncryptionParameters,
     CredentialRequestDenied,
 
+    // From https://openid.net/specs/openid-4-verifiable-credential-issuance-1_0.html#section-9.3
+    InvalidTransactionId,
+
     // From https://www.rfc-editor.org/rfc/rfc6750.html#section-3.1
     InvalidRequest,
     InvalidToken,
@@ -588,6 +607,7 @@ impl ErrorStatusCode for CredentialErrorCode {
             | Self::InvalidNonce
             | Self::InvalidEncryptionParameters
             | Self::CredentialRequestDenied
+            | Self::InvalidTransactionId
             | Self::InvalidRequest => StatusCode::BAD_REQUEST,
 
             Self::InvalidToken => StatusCode::UNAUTHORIZED,
@@ -604,7 +624,6 @@ impl ErrorWithCode for CredentialRequestError {
 
     fn error_code(&self) -> Self::ErrorCode {
         // TODO (PVW-5541): Return `CredentialErrorCode::UnknownCredentialIdentifier` when appropriate.
-        // TODO (PVW-5538): Return `CredentialErrorCode::InvalidEncryptionParameters` when appropriate.
         match self {
             // The session backing the access token is gone (cleaned up) or in a terminal/wrong state:
             // the token is no longer valid, which per RFC 6750 is `invalid_token`. This is hit when the
@@ -647,6 +666,63 @@ impl ErrorWithCode for CredentialRequestError {
             }
 
             Self::ObtainStatusClaim(_) | Self::IncorrectNumberOfStatusClaims(_) => CredentialErrorCode::ServerError,
+
+            Self::InvalidTransactionId => CredentialErrorCode::InvalidTransactionId,
+
+            Self::DeferredIssuanceRejected => CredentialErrorCode::CredentialRequestDenied,
+
+            Self::ResponseEncryptionRequired
+            | Self::ResponseEncryptionUnsupported
+            | Self::ResponseEncryptionJwk(_)
+            | Self::ResponseEncryptionKeyAlgorithm(_)
+            | Self::ResponseEncryptionAlgorithm(_)
+            | Self::ResponseEncryptionCompression(_) => CredentialErrorCode::InvalidEncryptionParameters,
+
+            Self::ResponseEncryption(_) => CredentialErrorCode::ServerError,
+        }
+    }
+}
+
+/// See <https://openid.net/specs/openid-4-verifiable-credential-issuance-1_0.html#section-11.3>.
+#[derive(Debug, Clone, PartialEq, Eq, strum::Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum NotificationErrorCode {
+    InvalidNotificationId,
+    InvalidNotificationRequest,
+
+    // From https://www.rfc-editor.org/rfc/rfc6750.html#section-3.1
+    InvalidToken,
+
+    /// This can be returned in case of internal server errors, i.e. with HTTP status code 5xx.
+    ServerError,
+}
+
+impl ErrorStatusCode for NotificationErrorCode {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::InvalidNotificationId | Self::InvalidNotificationRequest => StatusCode::BAD_REQUEST,
+
+            Self::InvalidToken => StatusCode::UNAUTHORIZED,
+
+            Self::ServerError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl ErrorWithCode for NotificationRequestError {
+    type ErrorCode = NotificationErrorCode;
+
+    fn error_code(&self) -> Self::ErrorCode {
+        match self {
+            Self::IssuanceError(IssuanceError::DpopInvalid(_)) => NotificationErrorCode::InvalidNotificationRequest,
+
+            Self::Unauthorized => NotificationErrorCode::InvalidToken,
+
+            Self::UnknownNotificationId(_) => NotificationErrorCode::InvalidNotificationId,
+
+            Self::IssuanceError(_) | Self::Unsupported | Self::IssuedCredentialStore(_) => {
+                NotificationErrorCode::ServerError
+            }
         }
     }
 }
@@ -1096,11 +1172,13 @@ mod tests {
     use super::CredentialErrorCode;
     use super::CredentialRequestError;
     use super::ErrorWithCode;
+    use super::NotificationErrorCode;
     use super::ParErrorCode;
     use super::RemoteErrorCode;
     use super::RemoteErrorResponse;
     use super::TokenErrorCode;
     use crate::issuer::IssuanceError;
+    use crate::issuer::NotificationRequestError;
     use crate::issuer::TokenRequestError;
 
     #[rstest]
@@ -1153,4 +1231,18 @@ mod tests {
             CredentialErrorCode::InvalidToken
         );
     }
+
+    #[test]
+    fn unknown_notification_id_maps_to_invalid_notification_id() {
+        // Notifications are processed against the issued credential store rather than the issuance session, so an
+        // unknown or expired `notification_id` is reported as such instead of as an invalid access token.
+        assert_eq!(
+            NotificationRequestError::UnknownNotificationId(String::from("test")).error_code(),
+            NotificationErrorCode::InvalidNotificationId
+        );
+        assert_eq!(
+            NotificationRequestError::Unauthorized.error_code(),
+            NotificationErrorCode::InvalidToken
+        );
+    }
 }
@@ -5,11 +5,14 @@ use attestation_types::credential_kind::CredentialKind;
 use attestation_types::qualification::AttestationQualification;
 use chrono::DateTime;
 use chrono::Utc;
+use derive_more::AsRef;
+use derive_more::From;
 use http_utils::urls::HttpsUri;
 use sd_jwt_vc_metadata::NormalizedTypeMetadata;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_valid::Validate;
+use token_status_list::status_list_service::SubjectLookupHash;
 use uuid::Uuid;
 
 /// Generic data model used to pass the attributes to be issued from the issuer backend to the wallet server. This model
@@ -24,6 +27,16 @@ use uuid::Uuid;
 ///         "lastname": "Doe"
 ///     }
 /// }
+/// ```
+///
+/// The optional `subject_lookup_hash` is a salted hash of an identifier of the subject, which is stored with the
+/// issued attestation batch, so that all attestations issued to that subject can be revoked at once.
+///
+/// The optional `source_reference` identifies the document at the issuer backend, so that it can be fetched again when
+/// the wallet has the attestation reissued using a refresh token.
+///
+/// Both of these can only be set by the issuer itself and are never (de)serialized, as an issuer backend should not be
+/// able to link its documents to arbitrary subjects. See [`StoredIssuableDocument`] for storing them in a session.
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 #[cfg_attr(feature = "mock", derive(derive_more::Into))]
 pub struct IssuableDocument {
@@ -32,8 +45,53 @@ pub struct IssuableDocument {
     pub credential_kind: CredentialKind,
     #[validate(custom = IssuableDocument::validate_attributes)]
     attributes: Attributes,
+    #[serde(skip)]
+    #[cfg_attr(feature = "mock", into(skip))]
+    pub subject_lookup_hash: Option<SubjectLookupHash>,
+    #[serde(skip)]
+    #[cfg_attr(feature = "mock", into(skip))]
+    pub source_reference: Option<DocumentReference>,
 }
 
+/// Representation of an [`IssuableDocument`] in the issuer's own session store, which does include the fields that
+/// are skipped when (de)serializing an [`IssuableDocument`] received from an issuer backend.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct StoredIssuableDocument {
+    #[serde(flatten)]
+    document: IssuableDocument,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    subject_lookup_hash: Option<SubjectLookupHash>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    source_reference: Option<DocumentReference>,
+}
+
+impl From<IssuableDocument> for StoredIssuableDocument {
+    fn from(mut document: IssuableDocument) -> Self {
+        Self {
+            subject_lookup_hash: document.subject_lookup_hash.take(),
+            source_reference: document.source_reference.take(),
+            document,
+        }
+    }
+}
+
+impl From<StoredIssuableDocument> for IssuableDocument {
+    fn from(stored: StoredIssuableDocument) -> Self {
+        Self {
+            subject_lookup_hash: stored.subject_lookup_hash,
+            source_reference: stored.source_reference,
+            ..stored.document
+        }
+    }
+}
+
+/// Opaque reference to the source of an [`IssuableDocument`] at the issuer backend, e.g. an identifier of the subject.
+/// As this may contain personal data, it is not included in the `Debug` output.
+#[derive(Clone, PartialEq, Eq, derive_more::Debug, AsRef, From, Serialize, Deserialize)]
+#[debug("DocumentReference(..)")]
+#[as_ref(str)]
+pub struct DocumentReference(String);
+
 impl IssuableDocument {
     pub fn try_new(
         id: Uuid,
@@ -45,10 +103,22 @@ impl IssuableDocument {
             id,
             credential_kind,
             attributes,
+            subject_lookup_hash: None,
+            source_reference: None,
         };
         Ok(document)
     }
 
+    pub fn with_subject_lookup_hash(mut self, subject_lookup_hash: SubjectLookupHash) -> Self {
+        self.subject_lookup_hash = Some(subject_lookup_hash);
+        self
+    }
+
+    pub fn with_source_reference(mut self, source_reference: DocumentReference) -> Self {
+        self.source_reference = Some(source_reference);
+        self
+    }
+
     pub fn try_new_with_random_id(
         credential_kind: CredentialKind,
         attributes: Attributes,
@@ -150,3 +220,38 @@ pub mod mock {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issuable_document_serde_skips_subject_fields() {
+        let document = IssuableDocument::new_mock_degree("MSc".to_string())
+            .with_subject_lookup_hash(SubjectLookupHash::from("hash".to_string()))
+            .with_source_reference(DocumentReference::from("reference".to_string()));
+
+        let mut json = serde_json::to_value(&document).unwrap();
+        assert!(json.get("subject_lookup_hash").is_none());
+        assert!(json.get("source_reference").is_none());
+
+        // Fields provided by an issuer backend should be ignored.
+        json["subject_lookup_hash"] = "other_hash".into();
+        json["source_reference"] = "other_reference".into();
+        let deserialized: IssuableDocument = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized.subject_lookup_hash, None);
+        assert_eq!(deserialized.source_reference, None);
+
+        // The issuer's own session store does retain them.
+        let stored = serde_json::to_value(StoredIssuableDocument::from(document)).unwrap();
+        let document: IssuableDocument = serde_json::from_value::<StoredIssuableDocument>(stored).unwrap().into();
+        assert_eq!(
+            document.subject_lookup_hash,
+            Some(SubjectLookupHash::from("hash".to_string()))
+        );
+        assert_eq!(
+            document.source_reference,
+            Some(DocumentReference::from("reference".to_string()))
+        );
+    }
+}
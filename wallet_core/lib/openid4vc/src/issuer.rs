@@ -8,6 +8,7 @@ use std::sync::Arc;
 use std::sync::LazyLock;
 use std::time::Duration;
 
+use async_trait::async_trait;
 use attestation_data::attributes::AttributesError;
 use attestation_data::credential_payload::CredentialPayload;
 use attestation_data::credential_payload::CredentialPayloadIntoSignedMdocError;
@@ -24,7 +25,10 @@ use crypto::EcdsaKeySend;
 use crypto::PublicKey;
 use crypto::server_keys::KeyPair;
 use crypto::trust_anchor::TrustAnchors;
+use crypto::utils::random_bytes;
+use crypto::utils::random_digits;
 use crypto::utils::random_string;
+use dashmap::DashMap;
 use derive_more::Constructor;
 use derive_more::Debug;
 use futures::future::try_join_all;
@@ -32,6 +36,13 @@ use futures::join;
 use http_utils::urls::BaseUrl;
 use indexmap::IndexSet;
 use itertools::Itertools;
+use jwe::algorithm::EcdhAlgorithm;
+use jwe::algorithm::EncryptionAlgorithm;
+use jwe::encryption::JweCompression;
+use jwe::encryption::JweEncrypter;
+use jwe::encryption::JwePublicKey;
+use jwe::error::EcdhPublicJwkError;
+use jwe::error::JweJsonEncryptionError;
 use jwt::Algorithm;
 use jwt::JwtValidation;
 use jwt::SignedJwt;
@@ -45,17 +56,25 @@ use jwt::wia::WiaClaims;
 use jwt::wia::WiaDisclosure;
 use jwt::wia::WiaError;
 use reqwest::Method;
+use ring::hmac;
 use sd_jwt_vc_metadata::TypeMetadataDocuments;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_with::FromInto;
+use serde_with::base64::Base64;
+use serde_with::serde_as;
 use token_status_list::status_list_service::StatusListService;
+use token_status_list::status_list_service::SubjectLookupHash;
 use tokio::task::AbortHandle;
 use tracing::info;
+use tracing::warn;
 use url::Url;
 use utils::generator::Generator;
+use utils::generator::TimeGenerator;
 use utils::vec_at_least::IntoNonEmptyIterator;
 use utils::vec_at_least::NonEmptyIterator;
 use utils::vec_at_least::VecNonEmpty;
+use utils::vec_nonempty;
 use uuid::Uuid;
 
 use crate::authorization_details::AuthorizationDetails;
@@ -66,20 +85,31 @@ use crate::credential::CredentialRequest;
 use crate::credential::CredentialRequestProof;
 use crate::credential::CredentialRequests;
 use crate::credential::CredentialResponse;
+use crate::credential::CredentialResponseEncryptionParameters;
 use crate::credential::CredentialResponses;
+use crate::credential::DeferredCredentialRequest;
+use crate::credential::DeferredCredentialResponse;
+use crate::credential::MaybeEncrypted;
 use crate::credential_configurations::CredentialConfiguration;
 use crate::credential_configurations::CredentialConfigurationParameters;
 use crate::credential_configurations::CredentialConfigurations;
 use crate::credential_configurations::CredentialConfigurationsError;
 use crate::credential_offer::CredentialOffer;
+use crate::credential_offer::PreAuthTransactionCode;
+use crate::credential_offer::PreAuthTransactionCodeInputMode;
 use crate::dpop::Dpop;
 use crate::dpop::DpopError;
+use crate::issuable_document::DocumentReference;
 use crate::issuable_document::IssuableDocument;
+use crate::issuable_document::StoredIssuableDocument;
 use crate::issuer_identifier::IssuerIdentifier;
 use crate::jose::JwsAlgorithm;
+use crate::jwe::JweCompressionAlgorithm;
+use crate::jwe::JweEncryptionAlgorithm;
 use crate::metadata::issuer_metadata::AtLeastTwoU64;
 use crate::metadata::issuer_metadata::BatchCredentialIssuance;
 use crate::metadata::issuer_metadata::CredentialConfigurationId;
+use crate::metadata::issuer_metadata::CredentialResponseEncryption;
 use crate::metadata::issuer_metadata::IssuerEndpoints;
 use crate::metadata::issuer_metadata::IssuerMetadata;
 use crate::metadata::issuer_metadata::SignedIssuerMetadataPayload;
@@ -87,6 +117,8 @@ use crate::metadata::oauth_metadata::AuthorizationServerMetadata;
 use crate::nonce::store::NonceStatus;
 use crate::nonce::store::NonceStore;
 use crate::nonce::store::NonceStoreError;
+use crate::notification::NotificationEvent;
+use crate::notification::NotificationRequest;
 use crate::pkce::S256PkcePair;
 use crate::preview::CredentialPreviewResponse;
 use crate::scope::Scope;
@@ -98,9 +130,11 @@ use crate::server_state::SessionState;
 use crate::server_state::SessionStore;
 use crate::server_state::SessionStoreError;
 use crate::server_state::SessionToken;
+use crate::store::Consumed;
 use crate::token::AccessToken;
 use crate::token::AuthorizationCode;
 use crate::token::CredentialPreview;
+use crate::token::RefreshToken;
 use crate::token::TokenRequest;
 use crate::token::TokenRequestGrantType;
 use crate::token::TokenResponse;
@@ -144,6 +178,15 @@ pub enum PreAuthorizedSessionError {
 
     #[error("failed to store new session: {0}")]
     SessionStore(#[source] SessionStoreError),
+
+    #[error("unsupported Transaction Code input mode: {0}")]
+    TxCodeInputMode(String),
+
+    #[error("Transaction Code should have a length of at least {MIN_TX_CODE_LENGTH}, received: {0}")]
+    TxCodeLength(u8),
+
+    #[error("Transaction Code description exceeds {MAX_TX_CODE_DESCRIPTION_LENGTH} characters")]
+    TxCodeDescriptionLength,
 }
 
 /// Errors that can occur during processing of the token request.
@@ -204,6 +247,36 @@ pub enum TokenRequestError {
 
     #[error("credential configuration not offered: {0}")]
     CredentialConfigNotOffered(CredentialConfigurationId),
+
+    #[error("the Refresh Token grant type is not supported by this issuer")]
+    RefreshTokenUnsupported,
+
+    #[error("unknown or expired refresh token")]
+    UnknownRefreshToken,
+
+    #[error("error accessing refresh token store: {0}")]
+    RefreshTokenStore(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+
+    #[error("use of scope values in Refresh Token Request is not supported, received: {}", .0.iter().join(" "))]
+    RefreshTokenScopeUnsupported(HashSet<Scope>),
+
+    #[error("DPoP key does not match the key the refresh token was bound to")]
+    RefreshTokenDpopKeyMismatch,
+
+    #[error("error fetching document to reissue: {0}")]
+    DocumentSource(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+
+    #[error("document to reissue is not valid: {0}")]
+    RefreshedDocument(#[source] IssuableDocumentError),
+
+    #[error("missing Transaction Code in Pre-Authorized Token Request")]
+    MissingTxCode,
+
+    #[error("incorrect Transaction Code in Pre-Authorized Token Request, {remaining_attempts} attempt(s) remaining")]
+    IncorrectTxCode { remaining_attempts: u8 },
+
+    #[error("too many incorrect Transaction Codes in Pre-Authorized Token Request")]
+    TxCodeAttemptsExceeded,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -283,6 +356,43 @@ pub enum CredentialRequestError {
 
     #[error("incorrect number of status claims for attestation_type: {0}")]
     IncorrectNumberOfStatusClaims(String),
+
+    #[error("unknown transaction_id in deferred credential request")]
+    InvalidTransactionId,
+
+    #[error("deferred credential issuance was rejected by the issuer")]
+    DeferredIssuanceRejected,
+
+    #[error("credential response encryption is required, but no encryption parameters were provided")]
+    ResponseEncryptionRequired,
+
+    #[error("credential response encryption is not supported by the issuer")]
+    ResponseEncryptionUnsupported,
+
+    #[error("invalid credential response encryption JWK: {0}")]
+    ResponseEncryptionJwk(#[source] EcdhPublicJwkError),
+
+    #[error("unsupported credential response encryption JWK algorithm: {0}")]
+    ResponseEncryptionKeyAlgorithm(EcdhAlgorithm),
+
+    #[error("unsupported credential response encryption algorithm: {0}")]
+    ResponseEncryptionAlgorithm(JweEncryptionAlgorithm),
+
+    #[error("unsupported credential response compression algorithm: {0}")]
+    ResponseEncryptionCompression(JweCompressionAlgorithm),
+
+    #[error("could not encrypt credential response: {0}")]
+    ResponseEncryption(#[source] JweJsonEncryptionError),
+}
+
+/// Errors that can occur when the issuer approves or rejects a deferred issuance session.
+#[derive(Debug, thiserror::Error)]
+pub enum DeferredIssuanceDecisionError {
+    #[error("issuance error: {0}")]
+    IssuanceError(#[from] IssuanceError),
+
+    #[error("deferred issuance has already been approved or rejected")]
+    AlreadyDecided,
 }
 
 /// Errors that can occur during handling of the credential preview request.
@@ -301,12 +411,53 @@ pub enum CredentialPreviewError {
     MissingCredentialConfiguration(CredentialConfigurationId),
 }
 
+/// Errors that can occur during handling of the notification request.
+#[derive(Debug, thiserror::Error)]
+pub enum NotificationRequestError {
+    #[error("issuance error: {0}")]
+    IssuanceError(#[from] IssuanceError),
+
+    #[error("notifications are not supported by this issuer")]
+    Unsupported,
+
+    #[error("unauthorized: incorrect access token")]
+    Unauthorized,
+
+    #[error("unknown or expired notification_id: {0}")]
+    UnknownNotificationId(String),
+
+    #[error("error accessing issued credential store: {0}")]
+    IssuedCredentialStore(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+}
+
 /// Session keyed by a code that the wallet will exchange at `/token`. Covers both grant types:
 /// `Grant::PreAuthorizedCode` (no PKCE) and `Grant::AuthorizationCode` (PKCE-verified at `/token`).
+#[serde_as]
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct AuthCodeIssued {
     pub grant: Grant,
+    #[serde_as(as = "Vec<(_, FromInto<StoredIssuableDocument>)>")]
     pub credential_ids_and_documents: VecNonEmpty<(CredentialConfigurationId, IssuableDocument)>,
+    /// If set, the credentials are not issued in response to the Credential Request, but only after the issuer has
+    /// approved the session, see [`CredentialsDeferred`].
+    #[serde(default)]
+    pub deferred: bool,
+    /// If set, the wallet needs to include this Transaction Code in its Token Request. Only applicable to the
+    /// Pre-Authorized Code flow.
+    #[serde(default)]
+    pub tx_code: Option<ExpectedTxCode>,
+}
+
+/// The Transaction Code that is expected in the Token Request, which is stored as an HMAC keyed with a random salt,
+/// together with the amount of Transaction Codes the wallet has sent so far.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExpectedTxCode {
+    #[serde_as(as = "Base64")]
+    pub salt: Vec<u8>,
+    #[serde_as(as = "Base64")]
+    pub tx_code_mac: Vec<u8>,
+    pub attempts: u8,
 }
 
 /// Values present in the (pushed) Authorization Request that initiated the Authorization Code Flow.
@@ -324,6 +475,11 @@ pub struct AuthRequestValues {
 pub enum Grant {
     PreAuthorizedCode,
     AuthorizationCode(AuthRequestValues),
+    /// The session was started by the wallet redeeming a refresh token, which is bound to the DPoP key that the
+    /// wallet used when it received that refresh token.
+    RefreshToken {
+        dpop_public_key: PublicKey,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -332,6 +488,38 @@ pub struct AccessTokenIssued {
     pub prepared_credentials: VecNonEmpty<PreparedCredential>,
     pub dpop_public_key: PublicKey,
     pub dpop_nonce: String,
+    #[serde(default)]
+    pub deferred: bool,
+}
+
+/// The Credential Request(s) of a deferred session have been received and verified, but the credentials will only be
+/// issued once the issuer approves the session. Until then, the wallet polls the `/deferred_credential` endpoint using
+/// the `transaction_id`. Rather than expiring after a period of inactivity, the session expires at `expires_at`, so
+/// that it survives the wallet not polling for a while.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CredentialsDeferred {
+    pub access_token: AccessToken,
+    pub dpop_public_key: PublicKey,
+    pub dpop_nonce: String,
+    pub transaction_id: String,
+    pub credentials: VecNonEmpty<DeferredCredential>,
+    pub approval: DeferredApproval,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// A credential that is to be issued to the holder public keys from the Credential Request(s), once approved.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeferredCredential {
+    pub prepared_credential: PreparedCredential,
+    pub holder_pubkeys: VecNonEmpty<PublicKey>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeferredApproval {
+    Pending,
+    Approved,
+    Rejected,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -340,6 +528,8 @@ pub struct PreparedCredential {
     pub format: Format,
     pub credential_payload: PreviewableCredentialPayload,
     pub batch_id: Uuid,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject_lookup_hash: Option<SubjectLookupHash>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -351,6 +541,7 @@ pub struct Done {
 pub enum IssuanceData {
     AuthCodeIssued(Box<AuthCodeIssued>),
     AccessTokenIssued(Box<AccessTokenIssued>),
+    CredentialsDeferred(Box<CredentialsDeferred>),
     Done(Done),
 }
 
@@ -361,7 +552,7 @@ impl SessionDataType for IssuanceData {
 impl HasProgress for IssuanceData {
     fn progress(&self) -> Progress {
         match self {
-            Self::AuthCodeIssued(_) | Self::AccessTokenIssued(_) => Progress::Active,
+            Self::AuthCodeIssued(_) | Self::AccessTokenIssued(_) | Self::CredentialsDeferred(_) => Progress::Active,
             Self::Done(done) => Progress::Finished {
                 has_succeeded: matches!(done.session_result, SessionResult::Done),
             },
@@ -384,11 +575,19 @@ impl Expirable for IssuanceData {
             session_result: SessionResult::Expired,
         });
     }
+
+    fn expires_at(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Self::CredentialsDeferred(deferred) => Some(deferred.expires_at),
+            Self::AuthCodeIssued(_) | Self::AccessTokenIssued(_) | Self::Done(_) => None,
+        }
+    }
 }
 
 pub trait IssuanceState {}
 impl IssuanceState for AuthCodeIssued {}
 impl IssuanceState for AccessTokenIssued {}
+impl IssuanceState for CredentialsDeferred {}
 impl IssuanceState for Done {}
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -400,15 +599,397 @@ pub enum SessionResult {
     Expired,
 }
 
-#[derive(Debug)]
+/// The credentials issued in a successfully finished session, along with the values needed to authorize the
+/// Notification Requests that the wallet may send about these credentials to the `/notification` endpoint. These are
+/// persisted in the [`IssuedCredentialStore`], as the wallet may send notifications long after the session has ended.
+#[derive(Debug, Clone)]
+pub struct IssuedCredentials {
+    pub authorization: NotificationAuthorization,
+    pub credentials: Vec<IssuedCredential>,
+}
+
+/// The access token and DPoP key with which the wallet obtained a credential, which it also needs to use for the
+/// Notification Requests about that credential.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotificationAuthorization {
+    pub access_token: AccessToken,
+    pub dpop_public_key: PublicKey,
+    pub dpop_nonce: String,
+}
+
+/// A credential that was issued to the wallet, including all of its copies, identified by its `notification_id`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IssuedCredential {
+    pub notification_id: String,
+    pub credential_configuration_id: CredentialConfigurationId,
+    pub attestation_type: String,
+    pub batch_id: Uuid,
+    /// Notifications about the credential are accepted until this moment, which is when the credential expires.
+    pub expires_at: DateTime<Utc>,
+}
+
+/// The amount of days for which notifications are accepted about credentials that do not expire.
+const ISSUED_CREDENTIAL_MAX_RETENTION_DAYS: i64 = 365;
+
+impl IssuedCredential {
+    fn new(credential: &PreparedCredential) -> Self {
+        let expires_at = credential
+            .credential_payload
+            .expires
+            .map(DateTime::from)
+            .unwrap_or_else(|| Utc::now() + chrono::Duration::days(ISSUED_CREDENTIAL_MAX_RETENTION_DAYS));
+
+        Self {
+            notification_id: random_string(32),
+            credential_configuration_id: credential.credential_configuration_id.clone(),
+            attestation_type: credential.credential_payload.attestation_type.clone(),
+            batch_id: credential.batch_id,
+            expires_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct Session<S: IssuanceState> {
     pub state: SessionState<S>,
 }
 
+/// The JWE content encryption algorithms with which the issuer is able to encrypt Credential Responses.
+const CREDENTIAL_RESPONSE_ENCRYPTION_ALGORITHMS: &[EncryptionAlgorithm] =
+    &[EncryptionAlgorithm::A128Gcm, EncryptionAlgorithm::A256Gcm];
+
+/// Configures whether the issuer encrypts Credential Responses, on top of TLS, to a key provided by the wallet.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CredentialResponseEncryptionPolicy {
+    /// Credential Responses are never encrypted and this is not advertised in the metadata.
+    #[default]
+    Unsupported,
+    /// Credential Responses are encrypted only when the wallet requests this.
+    Optional,
+    /// The wallet is required to request encryption of every Credential Response.
+    Required,
+}
+
+impl CredentialResponseEncryptionPolicy {
+    fn to_metadata(self) -> Option<CredentialResponseEncryption> {
+        let encryption_required = match self {
+            Self::Unsupported => return None,
+            Self::Optional => false,
+            Self::Required => true,
+        };
+
+        let metadata = CredentialResponseEncryption {
+            alg_values_supported: vec_nonempty![EcdhAlgorithm::EcdhEs.into()],
+            enc_values_supported: CREDENTIAL_RESPONSE_ENCRYPTION_ALGORITHMS
+                .iter()
+                .copied()
+                .map(JweEncryptionAlgorithm::from)
+                .collect_vec()
+                .try_into()
+                // The CREDENTIAL_RESPONSE_ENCRYPTION_ALGORITHMS constant is guaranteed to contain algorithms.
+                .unwrap(),
+            zip_values_supported: Some(vec_nonempty![JweCompressionAlgorithm::Deflate]),
+            encryption_required,
+        };
+
+        Some(metadata)
+    }
+}
+
+/// Encrypts a Credential Response to the key that the wallet included in its Credential Request.
+struct CredentialResponseEncrypter {
+    encrypter: JweEncrypter,
+    encryption_algorithm: EncryptionAlgorithm,
+    compression: JweCompression,
+}
+
+impl CredentialResponseEncrypter {
+    fn encrypt<T: Serialize>(&self, response: &T) -> Result<String, CredentialRequestError> {
+        self.encrypter
+            .encrypt_json(response, self.encryption_algorithm, None, None, self.compression)
+            .map_err(CredentialRequestError::ResponseEncryption)
+    }
+
+    fn maybe_encrypt<T: Serialize>(
+        encrypter: Option<&Self>,
+        response: T,
+    ) -> Result<MaybeEncrypted<T>, CredentialRequestError> {
+        match encrypter {
+            Some(encrypter) => encrypter.encrypt(&response).map(MaybeEncrypted::Encrypted),
+            None => Ok(MaybeEncrypted::Plain(response)),
+        }
+    }
+}
+
+/// The interval the wallet is asked to wait for before polling the `/deferred_credential` endpoint.
+const DEFERRED_CREDENTIAL_INTERVAL: Duration = Duration::from_mins(5);
+
+/// The time after which a deferred session expires, counted from the moment the wallet sent its Credential Request(s).
+/// As the approval of the issuer may take a while and the wallet only polls when it is in use, this is independent of
+/// the session expiry timeout of inactivity.
+const DEFERRED_SESSION_EXPIRY: Duration = Duration::from_hours(30 * 24);
+
+/// The amount of times the wallet may send an incorrect Transaction Code in its Token Request, after which the session
+/// fails. This allows the user to correct a typo, while preventing the Transaction Code from being brute forced.
+pub const MAX_TX_CODE_ATTEMPTS: u8 = 3;
+
+/// The length of a generated Transaction Code, when not specified otherwise.
+const DEFAULT_TX_CODE_LENGTH: u8 = 6;
+
+/// Shorter Transaction Codes would be too easy to guess within [`MAX_TX_CODE_ATTEMPTS`] attempts.
+const MIN_TX_CODE_LENGTH: u8 = 4;
+
+/// See: <https://openid.net/specs/openid-4-verifiable-credential-issuance-1_0.html#section-4.1.1-5.2.2.2.2.3>
+const MAX_TX_CODE_DESCRIPTION_LENGTH: usize = 300;
+
+/// The length of the random salt with which the expected Transaction Code is stored.
+const TX_CODE_SALT_LENGTH: usize = 32;
+
+/// Storage for the credentials issued by the issuer, keyed by their `notification_id`. Each entry is retained until
+/// the credential expires, so that the issuer can process the notifications that the wallet sends about it, e.g. when
+/// the user deletes the credential, independently of the lifetime of the issuance session.
+#[async_trait] // This makes the trait object safe so we can use `dyn IssuedCredentialStore` below.
+pub trait IssuedCredentialStore {
+    async fn store_issued_credentials(
+        &self,
+        issued_credentials: IssuedCredentials,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>>;
+
+    /// Returns the credential and its authorization, but only if the credential has not yet expired.
+    async fn fetch_issued_credential(
+        &self,
+        notification_id: &str,
+    ) -> Result<Option<(NotificationAuthorization, IssuedCredential)>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+
+    async fn remove_issued_credential(
+        &self,
+        notification_id: &str,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>>;
+
+    async fn cleanup_issued_credentials(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>>;
+}
+
+/// Keeps the credentials issued by the issuer in memory.
+#[derive(Debug, Default)]
+pub struct MemoryIssuedCredentialStore<G = TimeGenerator> {
+    time: G,
+    issued_credentials: DashMap<String, (NotificationAuthorization, IssuedCredential)>,
+}
+
+impl MemoryIssuedCredentialStore {
+    pub fn new() -> Self {
+        Self::new_with_time(TimeGenerator)
+    }
+}
+
+impl<G> MemoryIssuedCredentialStore<G> {
+    pub fn new_with_time(time: G) -> Self {
+        Self {
+            time,
+            issued_credentials: DashMap::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl<G> IssuedCredentialStore for MemoryIssuedCredentialStore<G>
+where
+    G: Generator<DateTime<Utc>> + Send + Sync,
+{
+    async fn store_issued_credentials(
+        &self,
+        issued_credentials: IssuedCredentials,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let IssuedCredentials {
+            authorization,
+            credentials,
+        } = issued_credentials;
+
+        for credential in credentials {
+            self.issued_credentials
+                .insert(credential.notification_id.clone(), (authorization.clone(), credential));
+        }
+
+        Ok(())
+    }
+
+    async fn fetch_issued_credential(
+        &self,
+        notification_id: &str,
+    ) -> Result<Option<(NotificationAuthorization, IssuedCredential)>, Box<dyn std::error::Error + Send + Sync + 'static>>
+    {
+        let now = self.time.generate();
+        let issued_credential = self
+            .issued_credentials
+            .get(notification_id)
+            .map(|entry| entry.value().clone())
+            .filter(|(_, credential)| now < credential.expires_at);
+
+        Ok(issued_credential)
+    }
+
+    async fn remove_issued_credential(
+        &self,
+        notification_id: &str,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        self.issued_credentials.remove(notification_id);
+
+        Ok(())
+    }
+
+    async fn cleanup_issued_credentials(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let now = self.time.generate();
+        self.issued_credentials
+            .retain(|_, (_, credential)| now < credential.expires_at);
+
+        Ok(())
+    }
+}
+
+/// Everything the issuer needs to reissue credentials when the wallet redeems a refresh token. The refresh token is
+/// sender-constrained, i.e. it can only be redeemed using the DPoP key that the wallet used to obtain it. Only
+/// references to the documents are stored, which are fetched again from their source on redemption, so that the
+/// attributes of the reissued credentials are up to date and no personal data is retained for the lifetime of the
+/// refresh token.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefreshTokenData {
+    pub dpop_public_key: PublicKey,
+    pub documents: VecNonEmpty<RefreshableDocument>,
+}
+
+/// Reference to an issued document, from which it can be fetched again by an [`IssuableDocumentSource`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RefreshableDocument {
+    pub credential_kind: CredentialKind,
+    pub reference: DocumentReference,
+}
+
+/// The source of the documents that are reissued when the wallet redeems a refresh token, which is typically the same
+/// issuer backend that the documents were fetched from when they were first issued.
+#[async_trait] // This makes the trait object safe so we can use `dyn IssuableDocumentSource` below.
+pub trait IssuableDocumentSource {
+    async fn fetch_issuable_document(
+        &self,
+        document: &RefreshableDocument,
+    ) -> Result<IssuableDocument, Box<dyn std::error::Error + Send + Sync + 'static>>;
+}
+
+/// Storage for the refresh tokens handed out by the issuer. Note that a refresh token is removed from storage when it
+/// is redeemed, as a new refresh token is issued along with the reissued credentials.
+#[async_trait] // This makes the trait object safe so we can use `dyn RefreshTokenStore` below.
+pub trait RefreshTokenStore {
+    async fn store_refresh_token(
+        &self,
+        refresh_token: RefreshToken,
+        data: RefreshTokenData,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>>;
+
+    /// Returns the data of the refresh token without removing it, but only if the refresh token has not yet expired.
+    async fn fetch_refresh_token(
+        &self,
+        refresh_token: &RefreshToken,
+    ) -> Result<Option<RefreshTokenData>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+
+    async fn consume_refresh_token(
+        &self,
+        refresh_token: &RefreshToken,
+    ) -> Result<Consumed<RefreshTokenData>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+
+    async fn cleanup_refresh_tokens(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>>;
+}
+
+/// Keeps the refresh tokens handed out by the issuer in memory.
+#[derive(Debug)]
+pub struct MemoryRefreshTokenStore<G = TimeGenerator> {
+    ttl: chrono::Duration,
+    time: G,
+    refresh_tokens: DashMap<RefreshToken, (DateTime<Utc>, RefreshTokenData)>,
+}
+
+impl MemoryRefreshTokenStore {
+    pub fn new(ttl: chrono::Duration) -> Self {
+        Self::new_with_time(ttl, TimeGenerator)
+    }
+}
+
+impl<G> MemoryRefreshTokenStore<G> {
+    pub fn new_with_time(ttl: chrono::Duration, time: G) -> Self {
+        Self {
+            ttl,
+            time,
+            refresh_tokens: DashMap::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl<G> RefreshTokenStore for MemoryRefreshTokenStore<G>
+where
+    G: Generator<DateTime<Utc>> + Send + Sync,
+{
+    async fn store_refresh_token(
+        &self,
+        refresh_token: RefreshToken,
+        data: RefreshTokenData,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let expires_at = self.time.generate() + self.ttl;
+        self.refresh_tokens.insert(refresh_token, (expires_at, data));
+
+        Ok(())
+    }
+
+    async fn fetch_refresh_token(
+        &self,
+        refresh_token: &RefreshToken,
+    ) -> Result<Option<RefreshTokenData>, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let now = self.time.generate();
+        let data = self
+            .refresh_tokens
+            .get(refresh_token)
+            .filter(|entry| now < entry.value().0)
+            .map(|entry| entry.value().1.clone());
+
+        Ok(data)
+    }
+
+    async fn consume_refresh_token(
+        &self,
+        refresh_token: &RefreshToken,
+    ) -> Result<Consumed<RefreshTokenData>, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let consumed = match self.refresh_tokens.remove(refresh_token) {
+            Some((_, (expires_at, data))) if self.time.generate() < expires_at => Consumed::Live(data),
+            Some((_, (_, data))) => Consumed::Expired(data),
+            None => Consumed::Absent,
+        };
+
+        Ok(consumed)
+    }
+
+    async fn cleanup_refresh_tokens(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let now = self.time.generate();
+        self.refresh_tokens.retain(|_, (expires_at, _)| now < *expires_at);
+
+        Ok(())
+    }
+}
+
+/// Everything the issuer needs to hand out refresh tokens and to reissue credentials when these are redeemed.
+pub struct CredentialRefresh {
+    pub refresh_token_store: Box<dyn RefreshTokenStore + Send + Sync>,
+    pub document_source: Box<dyn IssuableDocumentSource + Send + Sync>,
+}
+
 pub struct Issuer<K, L, S, N> {
     issuer_data: IssuerData<K, L>,
     sessions: Arc<S>,
     nonce_store: Arc<N>,
+    /// If present, the issuer advertises a Notification Endpoint, at which the wallet can notify it about the
+    /// credentials that are stored in here.
+    issued_credential_store: Option<Box<dyn IssuedCredentialStore + Send + Sync>>,
+    /// If present, the issuer hands out refresh tokens along with the credentials it issues (unless issuance was
+    /// deferred), which the wallet can later redeem to have its credentials reissued.
+    credential_refresh: Option<CredentialRefresh>,
     status_list_refresh_tasks: Vec<AbortHandle>,
 }
 
@@ -478,6 +1059,65 @@ impl<K, L> IssuerData<K, L> {
             client_id,
         )
     }
+
+    /// Check the parameters that the wallet sent for encrypting the Credential Response against the metadata and
+    /// return the [`CredentialResponseEncrypter`] to use, if any. Note that this is done before processing the
+    /// Credential Request, so that invalid parameters do not cause the session to fail.
+    fn credential_response_encrypter(
+        &self,
+        parameters: Option<&CredentialResponseEncryptionParameters>,
+    ) -> Result<Option<CredentialResponseEncrypter>, CredentialRequestError> {
+        let Some(metadata) = self.metadata.credential_response_encryption.as_ref() else {
+            return match parameters {
+                Some(_) => Err(CredentialRequestError::ResponseEncryptionUnsupported),
+                None => Ok(None),
+            };
+        };
+
+        let Some(parameters) = parameters else {
+            return if metadata.encryption_required {
+                Err(CredentialRequestError::ResponseEncryptionRequired)
+            } else {
+                Ok(None)
+            };
+        };
+
+        let public_key =
+            JwePublicKey::try_from_jwk(&parameters.jwk).map_err(CredentialRequestError::ResponseEncryptionJwk)?;
+
+        if !metadata
+            .alg_values_supported
+            .as_slice()
+            .contains(&public_key.algorithm().into())
+        {
+            return Err(CredentialRequestError::ResponseEncryptionKeyAlgorithm(
+                public_key.algorithm(),
+            ));
+        }
+
+        let encryption_algorithm = match &parameters.enc {
+            JweEncryptionAlgorithm::Known(algorithm)
+                if metadata.enc_values_supported.as_slice().contains(&parameters.enc) =>
+            {
+                *algorithm
+            }
+            enc => return Err(CredentialRequestError::ResponseEncryptionAlgorithm(enc.clone())),
+        };
+
+        let compression = match &parameters.zip {
+            None => JweCompression::None,
+            Some(JweCompressionAlgorithm::Deflate) => JweCompression::Deflate,
+            Some(zip) => return Err(CredentialRequestError::ResponseEncryptionCompression(zip.clone())),
+        };
+
+        let encrypter = CredentialResponseEncrypter {
+            encrypter: JweEncrypter::from(public_key),
+            encryption_algorithm,
+            compression,
+        };
+
+        Ok(Some(encrypter))
+    }
 }
 
 impl PreparedCredential {
@@ -512,6 +1152,7 @@ impl PreparedCredential {
         let valid_until = now.add(credential_config.valid_days);
 
         let format = issuable_document.credential_kind.format;
+        let subject_lookup_hash = issuable_document.subject_lookup_hash.clone();
         let (batch_id, credential_payload) = issuable_document.into_id_and_previewable_credential_payload(
             now,
             valid_until,
@@ -524,6 +1165,7 @@ impl PreparedCredential {
             format,
             credential_payload,
             batch_id,
+            subject_lookup_hash,
         };
 
         Ok(credential)
@@ -605,18 +1247,25 @@ where
         issuer_identifier: IssuerIdentifier,
         keypair: KeyPair<K>,
         batch_size: NonZeroU8,
+        credential_response_encryption: CredentialResponseEncryptionPolicy,
         wallet_client_ids: HashSet<String>,
         credential_config_params: HashMap<CredentialConfigurationId, CredentialConfigurationParameters<K, L>>,
         wia_trust_anchors: TrustAnchors,
         sessions: Arc<S>,
         nonce_store: N,
+        issued_credential_store: Option<Box<dyn IssuedCredentialStore + Send + Sync>>,
+        credential_refresh: Option<CredentialRefresh>,
     ) -> Result<Self, CredentialConfigurationsError> {
         let credential_configs = CredentialConfigurations::try_new(credential_config_params)?;
 
         let server_url = issuer_identifier.as_issuer_url().join_issuer_url("/issuance");
         let credential_endpoint = server_url.join_issuer_url("/credential");
         let batch_credential_endpoint = server_url.join_issuer_url("/batch_credential");
+        let deferred_credential_endpoint = server_url.join_issuer_url("/deferred_credential");
         let nonce_endpoint = server_url.join_issuer_url("/nonce");
+        let notification_endpoint = issued_credential_store
+            .is_some()
+            .then(|| server_url.join_issuer_url("/notification"));
         let credential_preview_endpoint = server_url.join_issuer_url("/credential_preview");
         let type_metadata_base_url = server_url.join_issuer_url("/type_metadata");
 
@@ -630,12 +1279,12 @@ where
                 credential_endpoint,
                 batch_credential_endpoint: Some(batch_credential_endpoint),
                 nonce_endpoint: Some(nonce_endpoint),
-                deferred_credential_endpoint: None,
-                notification_endpoint: None,
+                deferred_credential_endpoint: Some(deferred_credential_endpoint),
+                notification_endpoint,
                 credential_preview_endpoint: Some(credential_preview_endpoint),
             },
             credential_request_encryption: None,
-            credential_response_encryption: None,
+            credential_response_encryption: credential_response_encryption.to_metadata(),
             batch_credential_issuance,
             display: None,
             credential_configurations_supported: credential_configs
@@ -666,6 +1315,8 @@ where
             issuer_data,
             sessions,
             nonce_store,
+            issued_credential_store,
+            credential_refresh,
             status_list_refresh_tasks,
         };
 
@@ -680,13 +1331,34 @@ where
     S: SessionStore<IssuanceData> + Send + Sync,
     N: NonceStore + Send + Sync,
 {
-    /// Removes expired sessions and proof nonces.
+    /// Removes expired sessions, proof nonces, refresh tokens and issued credentials.
     ///
     /// Scheduled by the server via [`start_cleanup_task`](crate::cleanup::start_cleanup_task).
     async fn cleanup(&self) {
+        let refresh_token_cleanup = async {
+            if let Some(credential_refresh) = &self.credential_refresh {
+                log_cleanup_error(
+                    "refresh token",
+                    credential_refresh.refresh_token_store.cleanup_refresh_tokens(),
+                )
+                .await;
+            }
+        };
+        let issued_credential_cleanup = async {
+            if let Some(issued_credential_store) = self.issued_credential_store.as_deref() {
+                log_cleanup_error(
+                    "issued credential",
+                    issued_credential_store.cleanup_issued_credentials(),
+                )
+                .await;
+            }
+        };
+
         let _ = join!(
             log_cleanup_error("session", self.sessions.cleanup()),
             log_cleanup_error("nonce", self.nonce_store.remove_expired_nonces()),
+            refresh_token_cleanup,
+            issued_credential_cleanup,
         );
     }
 }
@@ -736,10 +1408,57 @@ where
         &self,
         issuable_documents: VecNonEmpty<IssuableDocument>,
     ) -> Result<CredentialOffer, PreAuthorizedSessionError> {
+        let (credential_offer, _, _) = self
+            .write_preauthorized_session(issuable_documents, false, None)
+            .await?;
+
+        Ok(credential_offer)
+    }
+
+    /// Create and store a new Pre-Authorized session for which issuance is deferred. The wallet will receive a
+    /// `transaction_id` in response to its Credential Request, while the credentials are only issued after calling
+    /// [`Issuer::approve_deferred_issuance`] using the returned [`SessionToken`].
+    pub async fn new_deferred_preauthorized_session(
+        &self,
+        issuable_documents: VecNonEmpty<IssuableDocument>,
+    ) -> Result<(CredentialOffer, SessionToken), PreAuthorizedSessionError> {
+        let (credential_offer, token, _) = self.write_preauthorized_session(issuable_documents, true, None).await?;
+
+        Ok((credential_offer, token))
+    }
+
+    /// Create and store a new Pre-Authorized session for which the wallet needs to include a Transaction Code in its
+    /// Token Request. The Transaction Code is generated according to `tx_code` and returned, so that the caller can
+    /// deliver it to the user through a different channel than the Credential Offer.
+    pub async fn new_preauthorized_session_with_tx_code(
+        &self,
+        issuable_documents: VecNonEmpty<IssuableDocument>,
+        deferred: bool,
+        tx_code: PreAuthTransactionCode,
+    ) -> Result<(CredentialOffer, SessionToken, String), PreAuthorizedSessionError> {
+        let (credential_offer, token, tx_code) = self
+            .write_preauthorized_session(issuable_documents, deferred, Some(tx_code))
+            .await?;
+
+        Ok((
+            credential_offer,
+            token,
+            tx_code.expect("a Transaction Code should have been generated"),
+        ))
+    }
+
+    async fn write_preauthorized_session(
+        &self,
+        issuable_documents: VecNonEmpty<IssuableDocument>,
+        deferred: bool,
+        tx_code: Option<PreAuthTransactionCode>,
+    ) -> Result<(CredentialOffer, SessionToken, Option<String>), PreAuthorizedSessionError> {
         let credential_ids_and_documents = self
             .validate_issuable_documents(issuable_documents)
             .map_err(PreAuthorizedSessionError::IssuableDocument)?;
 
+        let (offer_tx_code, tx_code) = tx_code.map(generate_tx_code).transpose()?.unzip();
+
         let config_ids = credential_ids_and_documents
             .nonempty_iter()
             .map(|(config_id, _document)| config_id)
@@ -750,17 +1469,20 @@ where
             .write_auth_code_issued_session(AuthCodeIssued {
                 grant: Grant::PreAuthorizedCode,
                 credential_ids_and_documents,
+                deferred,
+                tx_code: tx_code.as_deref().map(ExpectedTxCode::new),
             })
             .await
             .map_err(PreAuthorizedSessionError::SessionStore)?;
 
-        let credential_offer = CredentialOffer::new_pre_authorized(
+        let credential_offer = CredentialOffer::new_pre_authorized_with_tx_code(
             self.issuer_data.metadata.credential_issuer.clone(),
             config_ids,
-            token.into(),
+            token.clone().into(),
+            offer_tx_code,
         );
 
-        Ok(credential_offer)
+        Ok((credential_offer, token, tx_code))
     }
 
     /// Persist a new session that is in the initial [`AuthCodeIssued`] state. This is called both for Pre-Authorized
@@ -887,14 +1609,24 @@ where
     /// Process a token request. The session must already exist, populated by a flow-specific
     /// provisioner: either via [`Issuer::new_preauthorized_session`] for the pre-authorized-code
     /// flow, or via [`AuthorizingIssuer::complete_authorization`] for the authorization-code
-    /// flow (wallet PKCE is then verified by the `openid4vc` layer at `/token`).
+    /// flow (wallet PKCE is then verified by the `openid4vc` layer at `/token`). When the wallet
+    /// redeems a refresh token, the session is created here from the contents of the refresh token store.
     pub async fn process_token_request(
         &self,
         token_request: TokenRequest,
         dpop: Dpop,
         wia_disclosure: WiaDisclosure,
     ) -> Result<(TokenResponse, String), TokenRequestError> {
-        let session_token = token_request.code().clone().into();
+        let session_token = match &token_request.grant_type {
+            TokenRequestGrantType::AuthorizationCode { code }
+            | TokenRequestGrantType::PreAuthorizedCode {
+                pre_authorized_code: code,
+                ..
+            } => code.clone().into(),
+            TokenRequestGrantType::RefreshToken { refresh_token } => {
+                self.write_refresh_token_session(refresh_token, &dpop).await?
+            }
+        };
 
         let session = self
             .sessions
@@ -903,8 +1635,18 @@ where
             .map_err(IssuanceError::SessionStore)?
             .ok_or(TokenRequestError::SessionNotFound)?;
 
-        let result = Session::<AuthCodeIssued>::try_from(session)
-            .map_err(TokenRequestError::IssuanceError)?
+        let session = Session::<AuthCodeIssued>::try_from(session).map_err(TokenRequestError::IssuanceError)?;
+
+        // An incorrect Transaction Code does not necessarily fail the session, so this is checked separately.
+        let session = self.verify_tx_code(session, &token_request).await?;
+
+        // Hold on to references to the documents of a session that is not deferred, so that these can be fetched again
+        // and reissued when the wallet redeems the refresh token that it receives in the Token Response.
+        let refreshable_documents = (self.credential_refresh.is_some() && !session.session_data().deferred)
+            .then(|| refreshable_document_references(&session.session_data().credential_ids_and_documents))
+            .flatten();
+
+        let result = session
             .process_token_request(
                 &token_request,
                 dpop,
@@ -916,7 +1658,28 @@ where
             .await;
 
         let (response, next) = match result {
-            Ok((response, dpop_nonce, next)) => (Ok((response, dpop_nonce)), next.into()),
+            Ok((mut response, dpop_nonce, next)) => {
+                // A redeemed refresh token is only removed now that the Token Request has succeeded, so that the wallet
+                // may retry after an error. If it was redeemed concurrently in the meantime, this session is failed.
+                match self.consume_refresh_token(&token_request).await {
+                    Ok(()) => {
+                        if let Some(documents) = refreshable_documents {
+                            response.refresh_token = self
+                                .issue_refresh_token(RefreshTokenData {
+                                    dpop_public_key: next.session_data().dpop_public_key.clone(),
+                                    documents,
+                                })
+                                .await;
+                        }
+
+                        (Ok((response, dpop_nonce)), next.into())
+                    }
+                    Err(err) => {
+                        let next = next.transition_fail(&err);
+                        (Err(err), next.into())
+                    }
+                }
+            }
             Err(boxed) => {
                 let (err, next) = *boxed;
                 (Err(err), next.into())
@@ -930,22 +1693,187 @@ where
 
         response
     }
-}
 
-impl<K, L, S, N> Issuer<K, L, S, N>
-where
-    K: EcdsaKey,
-    L: StatusListService,
-    S: SessionStore<IssuanceData>,
-    N: NonceStore,
-{
-    pub async fn process_credential(
+    /// Redeem a refresh token by persisting a new session in the [`AuthCodeIssued`] state, containing the documents
+    /// of the session in which the refresh token was issued, as freshly fetched from their source. The DPoP proof is
+    /// verified against the key the refresh token was bound to before anything else is done, while the refresh token
+    /// itself is only removed from the refresh token store once the Token Request has succeeded.
+    async fn write_refresh_token_session(
         &self,
-        access_token: AccessToken,
-        dpop: Dpop,
-        credential_request: CredentialRequest,
-    ) -> Result<CredentialResponse, CredentialRequestError> {
+        refresh_token: &RefreshToken,
+        dpop: &Dpop,
+    ) -> Result<SessionToken, TokenRequestError> {
+        let credential_refresh = self
+            .credential_refresh
+            .as_ref()
+            .ok_or(TokenRequestError::RefreshTokenUnsupported)?;
+
+        let RefreshTokenData {
+            dpop_public_key,
+            documents,
+        } = credential_refresh
+            .refresh_token_store
+            .fetch_refresh_token(refresh_token)
+            .await
+            .map_err(TokenRequestError::RefreshTokenStore)?
+            .ok_or(TokenRequestError::UnknownRefreshToken)?;
+
+        let request_dpop_public_key = dpop
+            .clone()
+            .verify(&self.issuer_data.server_url.join("token"), &Method::POST, None)
+            .map_err(|err| TokenRequestError::IssuanceError(IssuanceError::DpopInvalid(err)))?;
+
+        if request_dpop_public_key != dpop_public_key {
+            return Err(TokenRequestError::RefreshTokenDpopKeyMismatch);
+        }
+
+        let issuable_documents: VecNonEmpty<_> = try_join_all(documents.iter().map(|document| async {
+            credential_refresh
+                .document_source
+                .fetch_issuable_document(document)
+                .await
+                .map(|issuable_document| issuable_document.with_source_reference(document.reference.clone()))
+        }))
+        .await
+        .map_err(TokenRequestError::DocumentSource)?
+        .try_into()
+        .expect("the number of fetched documents should equal the number of stored references");
+
+        let credential_ids_and_documents = self
+            .validate_issuable_documents(issuable_documents)
+            .map_err(TokenRequestError::RefreshedDocument)?;
+
+        self.write_auth_code_issued_session(AuthCodeIssued {
+            grant: Grant::RefreshToken { dpop_public_key },
+            credential_ids_and_documents,
+            deferred: false,
+            tx_code: None,
+        })
+        .await
+        .map_err(|e| TokenRequestError::IssuanceError(IssuanceError::SessionStore(e)))
+    }
+
+    /// Verify the Transaction Code of the [`TokenRequest`], if the session requires one. Rather than failing the
+    /// session immediately, an incorrect Transaction Code is counted, so that the session only fails after
+    /// [`MAX_TX_CODE_ATTEMPTS`] attempts. Every attempt is persisted before it is answered, and only if the session was
+    /// not written to in the meantime, so that concurrent Token Requests cannot make more attempts than this.
+    async fn verify_tx_code(
+        &self,
+        mut session: Session<AuthCodeIssued>,
+        token_request: &TokenRequest,
+    ) -> Result<Session<AuthCodeIssued>, TokenRequestError> {
+        // Any other grant type will be rejected when the grant type is verified.
+        let TokenRequestGrantType::PreAuthorizedCode { tx_code, .. } = &token_request.grant_type else {
+            return Ok(session);
+        };
+
+        if session.session_data().tx_code.is_none() {
+            return Ok(session);
+        }
+
+        let tx_code = tx_code.as_deref().ok_or(TokenRequestError::MissingTxCode)?;
+
+        loop {
+            let last_active = session.state.last_active;
+
+            let (next, is_correct) = session.count_tx_code_attempt(tx_code);
+
+            let is_written = self
+                .sessions
+                .write_if_unchanged(next.clone().into(), last_active)
+                .await
+                .map_err(|e| TokenRequestError::IssuanceError(IssuanceError::SessionStore(e)))?;
+
+            // If another Token Request changed the session in the meantime, count the attempt against its result.
+            if !is_written {
+                let current = self
+                    .sessions
+                    .get(&next.state.token)
+                    .await
+                    .map_err(IssuanceError::SessionStore)?
+                    .ok_or(TokenRequestError::SessionNotFound)?;
+
+                session = Session::<AuthCodeIssued>::try_from(current).map_err(TokenRequestError::IssuanceError)?;
+                continue;
+            }
+
+            if is_correct {
+                return Ok(next);
+            }
+
+            let attempts = next
+                .session_data()
+                .tx_code
+                .as_ref()
+                .map_or(0, |tx_code| tx_code.attempts);
+            let remaining_attempts = MAX_TX_CODE_ATTEMPTS.saturating_sub(attempts);
+            if remaining_attempts > 0 {
+                return Err(TokenRequestError::IncorrectTxCode { remaining_attempts });
+            }
+
+            let err = TokenRequestError::TxCodeAttemptsExceeded;
+            self.sessions
+                .write(next.transition_fail(&err).into(), false)
+                .await
+                .map_err(|e| TokenRequestError::IssuanceError(IssuanceError::SessionStore(e)))?;
+
+            return Err(err);
+        }
+    }
+
+    /// Remove the refresh token redeemed by the [`TokenRequest`] from the refresh token store, if any.
+    async fn consume_refresh_token(&self, token_request: &TokenRequest) -> Result<(), TokenRequestError> {
+        let (TokenRequestGrantType::RefreshToken { refresh_token }, Some(credential_refresh)) =
+            (&token_request.grant_type, &self.credential_refresh)
+        else {
+            return Ok(());
+        };
+
+        credential_refresh
+            .refresh_token_store
+            .consume_refresh_token(refresh_token)
+            .await
+            .map_err(TokenRequestError::RefreshTokenStore)?
+            .live()
+            .ok_or(TokenRequestError::UnknownRefreshToken)?;
+
+        Ok(())
+    }
+
+    /// Generate and store a new refresh token. As the refresh token is optional for the wallet, failing to store it
+    /// is not considered fatal and will result in no refresh token being handed out.
+    async fn issue_refresh_token(&self, data: RefreshTokenData) -> Option<RefreshToken> {
+        let credential_refresh = self.credential_refresh.as_ref()?;
+        let refresh_token = RefreshToken::new_random();
+
+        credential_refresh
+            .refresh_token_store
+            .store_refresh_token(refresh_token.clone(), data)
+            .await
+            .inspect_err(|error| warn!("could not store refresh token: {error}"))
+            .ok()?;
+
+        Some(refresh_token)
+    }
+}
+
+impl<K, L, S, N> Issuer<K, L, S, N>
+where
+    K: EcdsaKey,
+    L: StatusListService,
+    S: SessionStore<IssuanceData>,
+    N: NonceStore,
+{
+    pub async fn process_credential(
+        &self,
+        access_token: AccessToken,
+        dpop: Dpop,
+        credential_request: CredentialRequest,
+    ) -> Result<MaybeEncrypted<CredentialResponse>, CredentialRequestError> {
         let code = access_token.code().ok_or(CredentialRequestError::MalformedToken)?;
+        let encrypter = self
+            .issuer_data
+            .credential_response_encrypter(credential_request.credential_response_encryption.as_ref())?;
         let session = self.get_session(code).await?;
 
         let (response, next) = session
@@ -959,11 +1887,14 @@ where
             .await;
 
         self.sessions
-            .write(next.into(), false)
+            .write(next, false)
             .await
             .map_err(IssuanceError::SessionStore)?;
 
-        logged_issuance_result(response)
+        let (response, issued_credentials) = logged_issuance_result(response)?;
+        self.store_issued_credentials(issued_credentials).await;
+
+        CredentialResponseEncrypter::maybe_encrypt(encrypter.as_ref(), response)
     }
 
     pub async fn process_batch_credential(
@@ -971,8 +1902,11 @@ where
         access_token: AccessToken,
         dpop: Dpop,
         credential_requests: CredentialRequests,
-    ) -> Result<CredentialResponses, CredentialRequestError> {
+    ) -> Result<MaybeEncrypted<CredentialResponses>, CredentialRequestError> {
         let code = access_token.code().ok_or(CredentialRequestError::MalformedToken)?;
+        let encrypter = self
+            .issuer_data
+            .credential_response_encrypter(credential_requests.credential_response_encryption.as_ref())?;
         let session = self.get_session(code).await?;
 
         let (response, next) = session
@@ -986,11 +1920,69 @@ where
             .await;
 
         self.sessions
-            .write(next.into(), false)
+            .write(next, false)
+            .await
+            .map_err(IssuanceError::SessionStore)?;
+
+        let (response, issued_credentials) = logged_issuance_result(response)?;
+        self.store_issued_credentials(issued_credentials).await;
+
+        CredentialResponseEncrypter::maybe_encrypt(encrypter.as_ref(), response)
+    }
+
+    /// Process a Deferred Credential Request, returning the response along with whether the credentials are still
+    /// pending, as the response itself may be encrypted.
+    pub async fn process_deferred_credential(
+        &self,
+        access_token: AccessToken,
+        dpop: Dpop,
+        deferred_credential_request: DeferredCredentialRequest,
+    ) -> Result<(MaybeEncrypted<DeferredCredentialResponse>, bool), CredentialRequestError> {
+        let code = access_token.code().ok_or(CredentialRequestError::MalformedToken)?;
+        let encrypter = self
+            .issuer_data
+            .credential_response_encrypter(deferred_credential_request.credential_response_encryption.as_ref())?;
+        let session = self.get_deferred_session(code).await?;
+
+        // Note that a failure to authorize the request does not affect the session state.
+        session.check_deferred_credential_endpoint_access(
+            &access_token,
+            dpop,
+            &self.issuer_data.server_url,
+            &deferred_credential_request.transaction_id,
+        )?;
+
+        let (response, next) = session.process_deferred_credential(&self.issuer_data).await;
+
+        self.sessions
+            .write(next, false)
             .await
             .map_err(IssuanceError::SessionStore)?;
 
-        logged_issuance_result(response)
+        let (response, issued_credentials) = logged_issuance_result(response)?;
+        self.store_issued_credentials(issued_credentials).await;
+
+        let is_pending = response.is_pending();
+        let response = CredentialResponseEncrypter::maybe_encrypt(encrypter.as_ref(), response)?;
+
+        Ok((response, is_pending))
+    }
+
+    /// Persist the credentials issued to the wallet, so that the wallet can send notifications about these later on.
+    /// As notifications are sent on a best-effort basis, failing to store these is not considered fatal.
+    async fn store_issued_credentials(&self, issued_credentials: Option<IssuedCredentials>) {
+        let (Some(issued_credential_store), Some(issued_credentials)) =
+            (self.issued_credential_store.as_deref(), issued_credentials)
+        else {
+            return;
+        };
+
+        if let Err(error) = issued_credential_store
+            .store_issued_credentials(issued_credentials)
+            .await
+        {
+            warn!("could not store issued credentials: {error}");
+        }
     }
 }
 
@@ -998,6 +1990,61 @@ impl<K, L, S, N> Issuer<K, L, S, N>
 where
     S: SessionStore<IssuanceData>,
 {
+    /// Approve a deferred issuance session, after which the credentials will be issued the next time the wallet polls
+    /// the `/deferred_credential` endpoint. The session needs to have progressed to the point where the wallet has
+    /// sent its Credential Request(s).
+    pub async fn approve_deferred_issuance(&self, token: &SessionToken) -> Result<(), DeferredIssuanceDecisionError> {
+        self.decide_deferred_issuance(token, DeferredApproval::Approved).await
+    }
+
+    /// Reject a deferred issuance session, which the wallet will receive as an error the next time it polls the
+    /// `/deferred_credential` endpoint.
+    pub async fn reject_deferred_issuance(&self, token: &SessionToken) -> Result<(), DeferredIssuanceDecisionError> {
+        self.decide_deferred_issuance(token, DeferredApproval::Rejected).await
+    }
+
+    async fn decide_deferred_issuance(
+        &self,
+        token: &SessionToken,
+        approval: DeferredApproval,
+    ) -> Result<(), DeferredIssuanceDecisionError> {
+        let session: Session<CredentialsDeferred> = self
+            .sessions
+            .get(token)
+            .await
+            .map_err(IssuanceError::SessionStore)?
+            .ok_or_else(|| IssuanceError::UnknownSession(token.clone().into()))?
+            .try_into()?;
+
+        if session.session_data().approval != DeferredApproval::Pending {
+            return Err(DeferredIssuanceDecisionError::AlreadyDecided);
+        }
+
+        let mut session_data = session.session_data().clone();
+        session_data.approval = approval;
+        let next = session.transition(session_data);
+
+        self.sessions
+            .write(next.into(), false)
+            .await
+            .map_err(IssuanceError::SessionStore)?;
+
+        Ok(())
+    }
+
+    async fn get_deferred_session(
+        &self,
+        code: AuthorizationCode,
+    ) -> Result<Session<CredentialsDeferred>, CredentialRequestError> {
+        self.sessions
+            .get(&code.clone().into())
+            .await
+            .map_err(IssuanceError::SessionStore)?
+            .ok_or(IssuanceError::UnknownSession(code))?
+            .try_into()
+            .map_err(CredentialRequestError::IssuanceError)
+    }
+
     pub async fn process_reject_issuance(
         &self,
         access_token: AccessToken,
@@ -1035,6 +2082,63 @@ where
     }
 }
 
+impl<K, L, S, N> Issuer<K, L, S, N> {
+    /// Process a Notification Request sent by the wallet about one of the credentials issued to it. This is accepted
+    /// for as long as the credential is valid, using the same access token and DPoP key as were used to obtain the
+    /// credential. As the wallet will not send any further notifications about a credential after it has been deleted
+    /// or could not be stored, the credential is then removed from the [`IssuedCredentialStore`].
+    pub async fn process_notification(
+        &self,
+        access_token: AccessToken,
+        dpop: Dpop,
+        notification_request: NotificationRequest,
+    ) -> Result<(), NotificationRequestError> {
+        let issued_credential_store = self
+            .issued_credential_store
+            .as_deref()
+            .ok_or(NotificationRequestError::Unsupported)?;
+
+        let (authorization, credential) = issued_credential_store
+            .fetch_issued_credential(&notification_request.notification_id)
+            .await
+            .map_err(NotificationRequestError::IssuedCredentialStore)?
+            .ok_or_else(|| {
+                NotificationRequestError::UnknownNotificationId(notification_request.notification_id.clone())
+            })?;
+
+        // Check authorization of the request
+        if authorization.access_token != access_token {
+            return Err(NotificationRequestError::Unauthorized);
+        }
+
+        dpop.verify_expecting_key(
+            authorization.dpop_public_key,
+            &self.issuer_data.server_url.join("notification"),
+            &Method::POST,
+            Some(&access_token),
+            Some(&authorization.dpop_nonce),
+        )
+        .map_err(IssuanceError::DpopInvalid)?;
+
+        info!(
+            "Received {} notification for issued credential with batch id {} of type {}",
+            notification_request.event, credential.batch_id, credential.attestation_type
+        );
+
+        match notification_request.event {
+            NotificationEvent::CredentialAccepted => {}
+            NotificationEvent::CredentialDeleted | NotificationEvent::CredentialFailure => {
+                issued_credential_store
+                    .remove_issued_credential(&credential.notification_id)
+                    .await
+                    .map_err(NotificationRequestError::IssuedCredentialStore)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl<K, L, S, N> Issuer<K, L, S, N>
 where
     N: NonceStore,
@@ -1074,6 +2178,16 @@ async fn verify_wia_and_consume_nonce<K, L>(
     Ok(wia_claims)
 }
 
+impl From<Session<AuthCodeIssued>> for SessionState<IssuanceData> {
+    fn from(value: Session<AuthCodeIssued>) -> Self {
+        SessionState {
+            data: IssuanceData::AuthCodeIssued(Box::new(value.state.data)),
+            token: value.state.token,
+            last_active: value.state.last_active,
+        }
+    }
+}
+
 impl TryFrom<SessionState<IssuanceData>> for Session<AuthCodeIssued> {
     type Error = IssuanceError;
 
@@ -1091,6 +2205,23 @@ impl TryFrom<SessionState<IssuanceData>> for Session<AuthCodeIssued> {
     }
 }
 
+/// Returns references to the documents, which can be used to fetch these again when a refresh token is redeemed, but
+/// only if every document carries a reference to its source.
+fn refreshable_document_references(
+    credential_ids_and_documents: &VecNonEmpty<(CredentialConfigurationId, IssuableDocument)>,
+) -> Option<VecNonEmpty<RefreshableDocument>> {
+    credential_ids_and_documents
+        .iter()
+        .map(|(_, document)| {
+            document.source_reference.clone().map(|reference| RefreshableDocument {
+                credential_kind: document.credential_kind.clone(),
+                reference,
+            })
+        })
+        .collect::<Option<Vec<_>>>()
+        .map(|documents| documents.try_into().expect("should contain at least one document"))
+}
+
 fn utc_now_truncated_to_days() -> DateTime<Utc> {
     Utc::now()
         .duration_trunc(chrono::Duration::days(1))
@@ -1110,6 +2241,7 @@ impl Grant {
         match (self, &token_request.grant_type) {
             (Grant::PreAuthorizedCode, TokenRequestGrantType::PreAuthorizedCode { .. }) => Ok(()),
             (Grant::AuthorizationCode(_), TokenRequestGrantType::AuthorizationCode { .. }) => Ok(()),
+            (Grant::RefreshToken { .. }, TokenRequestGrantType::RefreshToken { .. }) => Ok(()),
             _ => Err(TokenRequestError::UnexpectedGrantType {
                 expected: self.to_string(),
                 actual: token_request.grant_type.to_string(),
@@ -1117,12 +2249,12 @@ impl Grant {
         }
     }
 
-    /// Verify the wallet's PKCE `code_verifier` (RFC 7636). `PreAuthorizedCode` carries no PKCE and
-    /// passes unconditionally; `AuthorizationCode` requires a `code_verifier` whose S256 challenge
+    /// Verify the wallet's PKCE `code_verifier` (RFC 7636). `PreAuthorizedCode` and `RefreshToken` carry no PKCE
+    /// and pass unconditionally; `AuthorizationCode` requires a `code_verifier` whose S256 challenge
     /// matches the one captured at `/authorize`.
     fn verify_pkce(&self, token_request: &TokenRequest) -> Result<(), TokenRequestError> {
         let Grant::AuthorizationCode(AuthRequestValues { code_challenge, .. }) = self else {
-            // Pre-authorized-code grant: no PKCE to verify.
+            // Pre-authorized-code or refresh token grant: no PKCE to verify.
             return Ok(());
         };
 
@@ -1135,7 +2267,8 @@ impl Grant {
 
     /// Verify the following about the `client_id`:
     ///
-    /// - In the Pre-Authorized flow, check that `client_id` is one of the allowed IDs.
+    /// - In the Pre-Authorized flow and when redeeming a refresh token, check that `client_id` is one of the allowed
+    ///   IDs.
     /// - In the Authorization Code flow, check that the `client_id` is exactly the same as the one provided in the
     ///   Authorization Request.
     fn verify_client_id(
@@ -1144,7 +2277,7 @@ impl Grant {
         accepted_wallet_client_ids: &HashSet<String>,
     ) -> Result<(), TokenRequestError> {
         match self {
-            Grant::PreAuthorizedCode => {
+            Grant::PreAuthorizedCode | Grant::RefreshToken { .. } => {
                 if !accepted_wallet_client_ids.contains(&client_id) {
                     return Err(TokenRequestError::UnknownClient(client_id));
                 }
@@ -1192,6 +2325,14 @@ impl Grant {
                     return Err(TokenRequestError::PreAuthorizedScopeUnsupported(scope.clone()));
                 }
             }
+
+            Grant::RefreshToken { .. } => {
+                // RFC 6749 allows the client to request a narrower scope when redeeming a refresh token, which we do
+                // not support, as the credentials are always reissued in their entirety.
+                if let Some(scope) = token_request.scope.as_ref() {
+                    return Err(TokenRequestError::RefreshTokenScopeUnsupported(scope.clone()));
+                }
+            }
         }
 
         Ok(())
@@ -1219,9 +2360,39 @@ impl Grant {
 
         Ok(())
     }
+
+    /// Verify that the DPoP key used in the [`TokenRequest`] is the one the refresh token was bound to, when
+    /// redeeming a refresh token. See <https://www.rfc-editor.org/rfc/rfc9449.html#section-5-5>.
+    fn verify_dpop_public_key(&self, dpop_public_key: &PublicKey) -> Result<(), TokenRequestError> {
+        if let Grant::RefreshToken {
+            dpop_public_key: expected_dpop_public_key,
+        } = self
+            && dpop_public_key != expected_dpop_public_key
+        {
+            return Err(TokenRequestError::RefreshTokenDpopKeyMismatch);
+        }
+
+        Ok(())
+    }
 }
 
 impl Session<AuthCodeIssued> {
+    /// Count an attempt to provide the Transaction Code, returning the session with the attempt counted and whether
+    /// `tx_code` is correct. The `last_active` timestamp of the session is advanced, so that the session is recognized
+    /// as changed by [`SessionStore::write_if_unchanged()`].
+    fn count_tx_code_attempt(mut self, tx_code: &str) -> (Self, bool) {
+        let Some(expected_tx_code) = self.state.data.tx_code.as_mut() else {
+            return (self, true);
+        };
+
+        expected_tx_code.attempts = expected_tx_code.attempts.saturating_add(1);
+        let is_correct = expected_tx_code.attempts <= MAX_TX_CODE_ATTEMPTS && expected_tx_code.matches(tx_code);
+
+        self.state.last_active = Utc::now().max(self.state.last_active + chrono::Duration::microseconds(1));
+
+        (self, is_correct)
+    }
+
     #[expect(
         clippy::too_many_arguments,
         reason = "passes through validate_and_build_token_response's parameters"
@@ -1284,13 +2455,17 @@ impl Session<AuthCodeIssued> {
         session_data.grant.verify_scope(token_request)?;
         session_data.grant.verify_redirect_uri(token_request)?;
 
-        build_token_response(
-            token_request.code(),
+        let (token_response, prepared_credentials, dpop_public_key, dpop_nonce) = build_token_response(
+            &self.state.token.clone().into(),
             dpop,
             server_url,
             session_data.credential_ids_and_documents.clone(),
             issuer_data,
-        )
+        )?;
+
+        session_data.grant.verify_dpop_public_key(&dpop_public_key)?;
+
+        Ok((token_response, prepared_credentials, dpop_public_key, dpop_nonce))
     }
 
     /// Apply the state transition on a `Session<AuthCodeIssued>` based on the result of
@@ -1302,11 +2477,13 @@ impl Session<AuthCodeIssued> {
     ) -> ProcessTokenRequest {
         match result {
             Ok((token_response, prepared_credentials, dpop_pubkey, dpop_nonce)) => {
+                let deferred = self.session_data().deferred;
                 let next = self.transition(AccessTokenIssued {
                     access_token: token_response.access_token.clone(),
                     prepared_credentials,
                     dpop_public_key: dpop_pubkey,
                     dpop_nonce: dpop_nonce.clone(),
+                    deferred,
                 });
                 Ok((token_response, dpop_nonce, next))
             }
@@ -1318,6 +2495,65 @@ impl Session<AuthCodeIssued> {
     }
 }
 
+impl ExpectedTxCode {
+    fn new(tx_code: &str) -> Self {
+        let salt = random_bytes(TX_CODE_SALT_LENGTH);
+        let tx_code_mac = hmac::sign(&hmac::Key::new(hmac::HMAC_SHA256, &salt), tx_code.as_bytes())
+            .as_ref()
+            .to_vec();
+
+        Self {
+            salt,
+            tx_code_mac,
+            attempts: 0,
+        }
+    }
+
+    fn matches(&self, tx_code: &str) -> bool {
+        hmac::verify(
+            &hmac::Key::new(hmac::HMAC_SHA256, &self.salt),
+            tx_code.as_bytes(),
+            &self.tx_code_mac,
+        )
+        .is_ok()
+    }
+}
+
+/// Generate a random Transaction Code according to the requirements in `tx_code`. These requirements are returned
+/// with the length filled in, to be included in the Credential Offer.
+fn generate_tx_code(
+    tx_code: PreAuthTransactionCode,
+) -> Result<(PreAuthTransactionCode, String), PreAuthorizedSessionError> {
+    let length = tx_code.length.unwrap_or(DEFAULT_TX_CODE_LENGTH);
+
+    if length < MIN_TX_CODE_LENGTH {
+        return Err(PreAuthorizedSessionError::TxCodeLength(length));
+    }
+
+    if tx_code
+        .description
+        .as_ref()
+        .is_some_and(|description| description.chars().count() > MAX_TX_CODE_DESCRIPTION_LENGTH)
+    {
+        return Err(PreAuthorizedSessionError::TxCodeDescriptionLength);
+    }
+
+    let value = match tx_code.input_mode.as_ref() {
+        None | Some(PreAuthTransactionCodeInputMode::Numeric) => random_digits(length.into()),
+        Some(PreAuthTransactionCodeInputMode::Text) => random_string(length.into()),
+        Some(PreAuthTransactionCodeInputMode::Other(input_mode)) => {
+            return Err(PreAuthorizedSessionError::TxCodeInputMode(input_mode.clone()));
+        }
+    };
+
+    let tx_code = PreAuthTransactionCode {
+        length: Some(length),
+        ..tx_code
+    };
+
+    Ok((tx_code, value))
+}
+
 /// Verify DPoP, prepare credentials from the supplied (pre-provisioned) issuables, and
 /// generate a fresh access token + DPoP nonce. Shared by the pre-authorized-code and
 /// authorization-code token-request paths.
@@ -1399,7 +2635,10 @@ impl Session<AccessTokenIssued> {
         dpop: Dpop,
         issuer_data: &IssuerData<K, L>,
         nonce_store: &N,
-    ) -> (Result<CredentialResponse, CredentialRequestError>, Session<Done>)
+    ) -> (
+        Result<(CredentialResponse, Option<IssuedCredentials>), CredentialRequestError>,
+        SessionState<IssuanceData>,
+    )
     where
         K: EcdsaKey,
         N: NonceStore,
@@ -1409,17 +2648,65 @@ impl Session<AccessTokenIssued> {
             .process_credential_inner(credential_request, access_token, dpop, issuer_data, nonce_store)
             .await;
 
-        // In case of success, transition the session to done. This means the client won't be able to reuse its access
-        // token in more requests to this endpoint. (The OpenID4VCI and OAuth specs allow reuse of access tokens, but
-        // don't forbid that a server doesn't allow that.)
-        let next = match &result {
-            Ok(_) => self.transition(Done {
-                session_result: SessionResult::Done,
-            }),
-            Err(err) => self.transition_fail(err),
-        };
+        self.finalize_credential_request(result, |credential_response| credential_response)
+    }
 
-        (result, next)
+    /// Apply the state transition based on the result of processing the Credential Request(s): issued credentials
+    /// transition the session to `Done`, deferred credentials to `CredentialsDeferred` and errors to a failed `Done`.
+    /// Any issued credentials are returned along with the response, so that these can be persisted by the caller.
+    #[expect(clippy::type_complexity)]
+    fn finalize_credential_request<T>(
+        self,
+        result: Result<CredentialRequestOutcome<T>, CredentialRequestError>,
+        deferred_response: impl FnOnce(CredentialResponse) -> T,
+    ) -> (
+        Result<(T, Option<IssuedCredentials>), CredentialRequestError>,
+        SessionState<IssuanceData>,
+    ) {
+        match result {
+            // In case of success, transition the session to done. This means the client won't be able to reuse its
+            // access token in more requests to this endpoint. (The OpenID4VCI and OAuth specs allow reuse of access
+            // tokens, but don't forbid that a server doesn't allow that.)
+            Ok(CredentialRequestOutcome::Issued(response, credentials)) => {
+                let session_data = self.session_data();
+                let issued_credentials = IssuedCredentials {
+                    authorization: NotificationAuthorization {
+                        access_token: session_data.access_token.clone(),
+                        dpop_public_key: session_data.dpop_public_key.to_owned(),
+                        dpop_nonce: session_data.dpop_nonce.clone(),
+                    },
+                    credentials,
+                };
+
+                let next = self.transition(Done {
+                    session_result: SessionResult::Done,
+                });
+
+                (Ok((response, Some(issued_credentials))), next.into())
+            }
+            Ok(CredentialRequestOutcome::Deferred(credentials)) => {
+                let session_data = self.session_data();
+                let transaction_id = random_string(32);
+                let response = CredentialResponse::new_deferred(transaction_id.clone(), DEFERRED_CREDENTIAL_INTERVAL);
+
+                let next = CredentialsDeferred {
+                    access_token: session_data.access_token.clone(),
+                    dpop_public_key: session_data.dpop_public_key.to_owned(),
+                    dpop_nonce: session_data.dpop_nonce.clone(),
+                    transaction_id,
+                    credentials,
+                    approval: DeferredApproval::Pending,
+                    expires_at: Utc::now() + DEFERRED_SESSION_EXPIRY,
+                };
+
+                (Ok((deferred_response(response), None)), self.transition(next).into())
+            }
+            Err(err) => {
+                let next = self.transition_fail(&err);
+
+                (Err(err), next.into())
+            }
+        }
     }
 
     pub fn check_credential_endpoint_access(
@@ -1456,7 +2743,7 @@ impl Session<AccessTokenIssued> {
         dpop: Dpop,
         issuer_data: &IssuerData<K, L>,
         nonce_store: &N,
-    ) -> Result<CredentialResponse, CredentialRequestError>
+    ) -> Result<CredentialRequestOutcome<CredentialResponse>, CredentialRequestError>
     where
         K: EcdsaKey,
         L: StatusListService,
@@ -1500,6 +2787,13 @@ impl Session<AccessTokenIssued> {
             return Err(CredentialRequestError::InvalidNonce);
         }
 
+        if session_data.deferred {
+            return Ok(CredentialRequestOutcome::Deferred(vec_nonempty![DeferredCredential {
+                prepared_credential: credential.clone(),
+                holder_pubkeys: vec_nonempty![holder_pubkey],
+            }]));
+        }
+
         let credential_config = issuer_data
             .get_credential_config_for_prepared_credential(credential)
             .ok_or_else(|| {
@@ -1510,6 +2804,7 @@ impl Session<AccessTokenIssued> {
             .status_list
             .obtain_status_claims(
                 credential.batch_id,
+                credential.subject_lookup_hash.clone(),
                 credential.credential_payload.expires,
                 NonZeroUsize::MIN,
             )
@@ -1517,6 +2812,7 @@ impl Session<AccessTokenIssued> {
             .map_err(|err| CredentialRequestError::ObtainStatusClaim(Box::new(err)))?
             .into_first();
 
+        let issued_credential = IssuedCredential::new(credential);
         let credential_response = CredentialResponse::new(
             requested_format,
             credential.credential_payload.clone(),
@@ -1525,9 +2821,13 @@ impl Session<AccessTokenIssued> {
             credential_config,
             status_claim,
         )
-        .await?;
+        .await?
+        .with_notification_id(issued_credential.notification_id.clone());
 
-        Ok(credential_response)
+        Ok(CredentialRequestOutcome::Issued(
+            credential_response,
+            vec![issued_credential],
+        ))
     }
 
     async fn process_batch_credential<K, L, N>(
@@ -1537,7 +2837,10 @@ impl Session<AccessTokenIssued> {
         dpop: Dpop,
         issuer_data: &IssuerData<K, L>,
         nonce_store: &N,
-    ) -> (Result<CredentialResponses, CredentialRequestError>, Session<Done>)
+    ) -> (
+        Result<(CredentialResponses, Option<IssuedCredentials>), CredentialRequestError>,
+        SessionState<IssuanceData>,
+    )
     where
         K: EcdsaKey,
         L: StatusListService,
@@ -1547,73 +2850,305 @@ impl Session<AccessTokenIssued> {
             .process_batch_credential_inner(credential_requests, access_token, dpop, issuer_data, nonce_store)
             .await;
 
-        // In case of success, transition the session to done. This means the client won't be able to reuse its access
-        // token in more requests to this endpoint. (The OpenID4VCI and OAuth specs allow reuse of access tokens, but
-        // don't forbid that a server doesn't allow that.)
-        let next = match &result {
-            Ok(_) => self.transition(Done {
-                session_result: SessionResult::Done,
-            }),
-            Err(err) => self.transition_fail(err),
-        };
+        // A deferred batch is answered with a single deferred response, as all credentials share the same transaction.
+        self.finalize_credential_request(result, |credential_response| CredentialResponses {
+            credential_responses: vec![credential_response],
+        })
+    }
+
+    async fn process_batch_credential_inner<K, L, N>(
+        &self,
+        credential_requests: CredentialRequests,
+        access_token: AccessToken,
+        dpop: Dpop,
+        issuer_data: &IssuerData<K, L>,
+        nonce_store: &N,
+    ) -> Result<CredentialRequestOutcome<CredentialResponses>, CredentialRequestError>
+    where
+        K: EcdsaKey,
+        L: StatusListService,
+        N: NonceStore,
+    {
+        let session_data = self.session_data();
+
+        self.check_credential_endpoint_access(&access_token, dpop, &issuer_data.server_url, "batch_credential")?;
+
+        let mut request_nonces = Vec::with_capacity(credential_requests.credential_requests.as_ref().len());
+        let credentials_and_holder_pubkeys = session_data
+            .prepared_credentials
+            .iter()
+            .map(|credential| {
+                // For every credential collect for every copy the verified key
+                let copy_count = issuer_data.metadata.batch_size().get();
+                let format_pubkeys: VecNonEmpty<_> = (0..copy_count)
+                    .map(|_| {
+                        let cred_req = credential_requests
+                            .credential_requests
+                            .as_ref()
+                            .get(request_nonces.len())
+                            .ok_or(CredentialRequestError::WrongNumberOfCredentialRequests)?;
+
+                        // Verify the assumption that the order of the incoming requests matches exactly
+                        // that of the flattened batch_size by matching the requested format.
+                        if credential.format != cred_req.credential_type.as_ref().format() {
+                            return Err(CredentialRequestError::CredentialTypeMismatch {
+                                offered: credential.format,
+                                requested: cred_req.credential_type.as_ref().format(),
+                            });
+                        }
+
+                        let (key, nonce) = cred_req.verify(
+                            issuer_data.accepted_wallet_client_ids_vec(),
+                            &issuer_data.metadata.credential_issuer,
+                        )?;
+
+                        request_nonces.push(nonce);
+
+                        Ok(key)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?
+                    .try_into()
+                    .expect("guaranteerd to be non-empty because copy_count's source is non-zero");
+
+                let credential_config = issuer_data
+                    .get_credential_config_for_prepared_credential(credential)
+                    .ok_or_else(|| {
+                        CredentialRequestError::MissingCredentialConfiguration(
+                            credential.credential_configuration_id.clone(),
+                        )
+                    })?;
+
+                Ok((credential, credential_config, format_pubkeys))
+            })
+            .collect::<Result<Vec<_>, CredentialRequestError>>()?;
+
+        // Verify that we have consumed all credential requests
+        if request_nonces.len() != credential_requests.credential_requests.as_ref().len() {
+            return Err(CredentialRequestError::WrongNumberOfCredentialRequests);
+        }
+
+        // Check the validity of all of the nonces used, which may be equal to each other.
+        let nonce_status = nonce_store
+            .check_nonce_status_and_remove(request_nonces.iter())
+            .await
+            .map_err(|error| CredentialRequestError::ProofNonceStore(Box::new(error)))?;
+
+        if !matches!(nonce_status, NonceStatus::AllValid) {
+            return Err(CredentialRequestError::InvalidNonce);
+        }
+
+        if session_data.deferred {
+            let credentials = credentials_and_holder_pubkeys
+                .into_iter()
+                .map(|(credential, _, holder_pubkeys)| DeferredCredential {
+                    prepared_credential: credential.clone(),
+                    holder_pubkeys,
+                })
+                .collect_vec()
+                .try_into()
+                .expect("guaranteed to be non-empty because the prepared credentials are non-empty");
+
+            return Ok(CredentialRequestOutcome::Deferred(credentials));
+        }
+
+        let (credential_responses, issued_credentials) = sign_credentials(&credentials_and_holder_pubkeys).await?;
+
+        Ok(CredentialRequestOutcome::Issued(
+            CredentialResponses { credential_responses },
+            issued_credentials,
+        ))
+    }
+}
+
+/// The result of successfully processing Credential Request(s): either the signed credentials and the information
+/// needed to process notifications about them or, for a deferred session, the credentials to be issued once the
+/// session is approved.
+enum CredentialRequestOutcome<T> {
+    Issued(T, Vec<IssuedCredential>),
+    Deferred(VecNonEmpty<DeferredCredential>),
+}
+
+/// Obtain status claims for and sign all copies of the credentials, one copy per holder public key. All copies of a
+/// credential share the same `notification_id`, which is returned along with the other details of each credential.
+async fn sign_credentials<K, L>(
+    credentials_and_holder_pubkeys: &[(
+        &PreparedCredential,
+        &CredentialConfiguration<K, L>,
+        VecNonEmpty<PublicKey>,
+    )],
+) -> Result<(Vec<CredentialResponse>, Vec<IssuedCredential>), CredentialRequestError>
+where
+    K: EcdsaKey,
+    L: StatusListService,
+{
+    // Obtain a status claim for every attestation copy, linked to a single batch id per credential
+    let status_claims = try_join_all(credentials_and_holder_pubkeys.iter().map(
+        |(credential, credential_config, format_pubkeys)| async move {
+            let claims = credential_config
+                .status_list
+                .obtain_status_claims(
+                    credential.batch_id,
+                    credential.subject_lookup_hash.clone(),
+                    credential.credential_payload.expires,
+                    format_pubkeys.len(),
+                )
+                .await
+                .map_err(|err| CredentialRequestError::ObtainStatusClaim(Box::new(err)))?;
+            if claims.len() != format_pubkeys.len() {
+                return Err(CredentialRequestError::IncorrectNumberOfStatusClaims(
+                    credential.credential_payload.attestation_type.clone(),
+                ));
+            }
+            Ok(claims)
+        },
+    ))
+    .await?;
+
+    // Make sure all credentials are issued with the same `issued_at` timestamp
+    let issued_at = utc_now_truncated_to_days();
+    let credential_responses = try_join_all(
+        credentials_and_holder_pubkeys
+            .iter()
+            // The claims size is explicitly checked to be equal to the number of copies
+            .zip_eq(status_claims)
+            .flat_map(|((credential, credential_config, format_pubkeys), claims)| {
+                format_pubkeys.into_iter().zip(claims.into_inner()).map(|(key, claim)| {
+                    CredentialResponse::new(
+                        credential.format,
+                        credential.credential_payload.clone(),
+                        issued_at,
+                        key,
+                        credential_config,
+                        claim,
+                    )
+                })
+            }),
+    )
+    .await?;
+
+    let issued_credentials = credentials_and_holder_pubkeys
+        .iter()
+        .map(|(credential, _, _)| IssuedCredential::new(credential))
+        .collect_vec();
+
+    // The responses are in the same order as the credentials, with one response per holder public key
+    let notification_ids = credentials_and_holder_pubkeys.iter().zip(&issued_credentials).flat_map(
+        |((_, _, format_pubkeys), issued_credential)| {
+            std::iter::repeat_n(&issued_credential.notification_id, format_pubkeys.len().get())
+        },
+    );
+    let credential_responses = credential_responses
+        .into_iter()
+        .zip_eq(notification_ids)
+        .map(|(response, notification_id)| response.with_notification_id(notification_id.clone()))
+        .collect();
+
+    Ok((credential_responses, issued_credentials))
+}
+
+impl Session<CredentialsDeferred> {
+    fn check_deferred_credential_endpoint_access(
+        &self,
+        access_token: &AccessToken,
+        dpop: Dpop,
+        server_url: &BaseUrl,
+        transaction_id: &str,
+    ) -> Result<(), CredentialRequestError> {
+        let session_data = self.session_data();
+
+        if session_data.access_token != *access_token {
+            return Err(CredentialRequestError::Unauthorized);
+        }
+
+        dpop.verify_expecting_key(
+            session_data.dpop_public_key.to_owned(),
+            &server_url.join("deferred_credential"),
+            &Method::POST,
+            Some(access_token),
+            Some(&session_data.dpop_nonce),
+        )
+        .map_err(|err| CredentialRequestError::IssuanceError(IssuanceError::DpopInvalid(err)))?;
+
+        if session_data.transaction_id != transaction_id {
+            return Err(CredentialRequestError::InvalidTransactionId);
+        }
 
-        (result, next)
+        Ok(())
     }
 
-    async fn process_batch_credential_inner<K, L, N>(
-        &self,
-        credential_requests: CredentialRequests,
-        access_token: AccessToken,
-        dpop: Dpop,
+    async fn process_deferred_credential<K, L>(
+        self,
         issuer_data: &IssuerData<K, L>,
-        nonce_store: &N,
-    ) -> Result<CredentialResponses, CredentialRequestError>
+    ) -> (
+        Result<(DeferredCredentialResponse, Option<IssuedCredentials>), CredentialRequestError>,
+        SessionState<IssuanceData>,
+    )
     where
         K: EcdsaKey,
         L: StatusListService,
-        N: NonceStore,
     {
         let session_data = self.session_data();
 
-        self.check_credential_endpoint_access(&access_token, dpop, &issuer_data.server_url, "batch_credential")?;
+        let result = match session_data.approval {
+            DeferredApproval::Pending => {
+                let response = DeferredCredentialResponse::Pending {
+                    transaction_id: session_data.transaction_id.clone(),
+                    interval: DEFERRED_CREDENTIAL_INTERVAL,
+                };
 
-        let mut request_nonces = Vec::with_capacity(credential_requests.credential_requests.as_ref().len());
-        let credentials_and_holder_pubkeys = session_data
-            .prepared_credentials
-            .iter()
-            .map(|credential| {
-                // For every credential collect for every copy the verified key
-                let copy_count = issuer_data.metadata.batch_size().get();
-                let format_pubkeys: VecNonEmpty<_> = (0..copy_count)
-                    .map(|_| {
-                        let cred_req = credential_requests
-                            .credential_requests
-                            .as_ref()
-                            .get(request_nonces.len())
-                            .ok_or(CredentialRequestError::WrongNumberOfCredentialRequests)?;
+                // Write back the unchanged session, which updates its `last_active` timestamp.
+                let session_data = session_data.clone();
+                return (Ok((response, None)), self.transition(session_data).into());
+            }
+            DeferredApproval::Rejected => Err(CredentialRequestError::DeferredIssuanceRejected),
+            DeferredApproval::Approved => self.issue_deferred_credentials(issuer_data).await,
+        };
 
-                        // Verify the assumption that the order of the incoming requests matches exactly
-                        // that of the flattened batch_size by matching the requested format.
-                        if credential.format != cred_req.credential_type.as_ref().format() {
-                            return Err(CredentialRequestError::CredentialTypeMismatch {
-                                offered: credential.format,
-                                requested: cred_req.credential_type.as_ref().format(),
-                            });
-                        }
+        match result {
+            Ok((credential_responses, credentials)) => {
+                let issued_credentials = IssuedCredentials {
+                    authorization: NotificationAuthorization {
+                        access_token: session_data.access_token.clone(),
+                        dpop_public_key: session_data.dpop_public_key.to_owned(),
+                        dpop_nonce: session_data.dpop_nonce.clone(),
+                    },
+                    credentials,
+                };
 
-                        let (key, nonce) = cred_req.verify(
-                            issuer_data.accepted_wallet_client_ids_vec(),
-                            &issuer_data.metadata.credential_issuer,
-                        )?;
+                let next = self.transition(Done {
+                    session_result: SessionResult::Done,
+                });
 
-                        request_nonces.push(nonce);
+                (
+                    Ok((
+                        DeferredCredentialResponse::Issued(CredentialResponses { credential_responses }),
+                        Some(issued_credentials),
+                    )),
+                    next.into(),
+                )
+            }
+            Err(err) => {
+                let next = self.transition_fail(&err);
 
-                        Ok(key)
-                    })
-                    .collect::<Result<Vec<_>, _>>()?
-                    .try_into()
-                    .expect("guaranteerd to be non-empty because copy_count's source is non-zero");
+                (Err(err), next.into())
+            }
+        }
+    }
 
+    async fn issue_deferred_credentials<K, L>(
+        &self,
+        issuer_data: &IssuerData<K, L>,
+    ) -> Result<(Vec<CredentialResponse>, Vec<IssuedCredential>), CredentialRequestError>
+    where
+        K: EcdsaKey,
+        L: StatusListService,
+    {
+        let credentials_and_holder_pubkeys = self
+            .session_data()
+            .credentials
+            .iter()
+            .map(|deferred| {
+                let credential = &deferred.prepared_credential;
                 let credential_config = issuer_data
                     .get_credential_config_for_prepared_credential(credential)
                     .ok_or_else(|| {
@@ -1622,70 +3157,38 @@ impl Session<AccessTokenIssued> {
                         )
                     })?;
 
-                Ok((credential, credential_config, format_pubkeys))
+                Ok((credential, credential_config, deferred.holder_pubkeys.clone()))
             })
             .collect::<Result<Vec<_>, CredentialRequestError>>()?;
 
-        // Verify that we have consumed all credential requests
-        if request_nonces.len() != credential_requests.credential_requests.as_ref().len() {
-            return Err(CredentialRequestError::WrongNumberOfCredentialRequests);
-        }
-
-        // Check the validity of all of the nonces used, which may be equal to each other.
-        let nonce_status = nonce_store
-            .check_nonce_status_and_remove(request_nonces.iter())
-            .await
-            .map_err(|error| CredentialRequestError::ProofNonceStore(Box::new(error)))?;
+        sign_credentials(&credentials_and_holder_pubkeys).await
+    }
+}
 
-        if !matches!(nonce_status, NonceStatus::AllValid) {
-            return Err(CredentialRequestError::InvalidNonce);
+impl From<Session<CredentialsDeferred>> for SessionState<IssuanceData> {
+    fn from(value: Session<CredentialsDeferred>) -> Self {
+        SessionState {
+            data: IssuanceData::CredentialsDeferred(Box::new(value.state.data)),
+            token: value.state.token,
+            last_active: value.state.last_active,
         }
+    }
+}
 
-        // Obtain a status claim for every attestation copy, linked to a single batch id per credential
-        let status_claims = try_join_all(credentials_and_holder_pubkeys.iter().map(
-            |(credential, credential_config, format_pubkeys)| async move {
-                let claims = credential_config
-                    .status_list
-                    .obtain_status_claims(
-                        credential.batch_id,
-                        credential.credential_payload.expires,
-                        format_pubkeys.len(),
-                    )
-                    .await
-                    .map_err(|err| CredentialRequestError::ObtainStatusClaim(Box::new(err)))?;
-                if claims.len() != format_pubkeys.len() {
-                    return Err(CredentialRequestError::IncorrectNumberOfStatusClaims(
-                        credential.credential_payload.attestation_type.clone(),
-                    ));
-                }
-                Ok(claims)
-            },
-        ))
-        .await?;
-
-        // Make sure all credentials are issued with the same `issued_at` timestamp
-        let issued_at = utc_now_truncated_to_days();
-        let credential_responses = try_join_all(
-            credentials_and_holder_pubkeys
-                .iter()
-                // The claims size is explicitly checked to be equal to the number of copies
-                .zip_eq(status_claims)
-                .flat_map(|((credential, credential_config, format_pubkeys), claims)| {
-                    format_pubkeys.into_iter().zip(claims.into_inner()).map(|(key, claim)| {
-                        CredentialResponse::new(
-                            credential.format,
-                            credential.credential_payload.clone(),
-                            issued_at,
-                            key,
-                            credential_config,
-                            claim,
-                        )
-                    })
-                }),
-        )
-        .await?;
+impl TryFrom<SessionState<IssuanceData>> for Session<CredentialsDeferred> {
+    type Error = IssuanceError;
 
-        Ok(CredentialResponses { credential_responses })
+    fn try_from(value: SessionState<IssuanceData>) -> Result<Self, Self::Error> {
+        let IssuanceData::CredentialsDeferred(session_data) = value.data else {
+            return Err(IssuanceError::UnexpectedState);
+        };
+        Ok(Session::<CredentialsDeferred> {
+            state: SessionState {
+                data: *session_data,
+                token: value.token,
+                last_active: value.last_active,
+            },
+        })
     }
 }
 
@@ -1836,6 +3339,7 @@ mod tests {
     use p256::ecdsa::SigningKey;
     use p256::elliptic_curve::Generate;
     use sd_jwt_vc_metadata::TypeMetadataDocuments;
+    use ssri::Integrity;
     use thiserror::Error;
     use tracing_test::traced_test;
     use url::Url;
@@ -1857,6 +3361,7 @@ mod tests {
     use crate::errors::CredentialErrorCode;
     use crate::errors::CredentialPreviewErrorCode;
     use crate::errors::ErrorResponse;
+    use crate::errors::NotificationErrorCode;
     use crate::errors::RemoteErrorCode;
     use crate::errors::TokenErrorCode;
     use crate::issuable_document::IssuableDocument;
@@ -1867,6 +3372,7 @@ mod tests {
     use crate::server_state::MemorySessionStore;
     use crate::server_state::test::memory_session_store_with_mock_time;
     use crate::server_state::test::test_memory_store_with_cleanup_task;
+    use crate::test::MockIssuableDocumentSource;
     use crate::test::MockIssuer;
     use crate::test::mock_issuable_documents;
     use crate::test::setup_mock_issuer;
@@ -1949,6 +3455,55 @@ mod tests {
         assert!(logs_contain("Issuance error: MyError"));
     }
 
+    #[tokio::test]
+    async fn test_memory_issued_credential_store_expiry() {
+        let time_generator = MockTimeGenerator::default();
+        let store = MemoryIssuedCredentialStore::new_with_time(time_generator.clone());
+
+        let authorization = NotificationAuthorization {
+            access_token: AccessToken::from("access_token".to_string()),
+            dpop_public_key: (*SigningKey::generate().verifying_key()).into(),
+            dpop_nonce: "nonce".to_string(),
+        };
+        let credential = IssuedCredential {
+            notification_id: "notification_id".to_string(),
+            credential_configuration_id: "com.example.degree_dc+sd-jwt".to_string().into(),
+            attestation_type: "com.example.degree".to_string(),
+            batch_id: Uuid::new_v4(),
+            expires_at: time_generator.generate() + chrono::Duration::days(30),
+        };
+
+        store
+            .store_issued_credentials(IssuedCredentials {
+                authorization: authorization.clone(),
+                credentials: vec![credential.clone()],
+            })
+            .await
+            .unwrap();
+
+        // Notifications remain possible long after the issuance session itself has been cleaned up.
+        *time_generator.time.write() += chrono::Duration::days(29);
+        store.cleanup_issued_credentials().await.unwrap();
+
+        let (fetched_authorization, fetched_credential) =
+            store.fetch_issued_credential("notification_id").await.unwrap().unwrap();
+        assert_eq!(fetched_authorization, authorization);
+        assert_eq!(fetched_credential, credential);
+
+        // Once the credential has expired, the entry is no longer returned, nor retained after cleanup.
+        *time_generator.time.write() += chrono::Duration::days(1);
+        assert!(
+            store
+                .fetch_issued_credential("notification_id")
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        store.cleanup_issued_credentials().await.unwrap();
+        assert!(store.issued_credentials.is_empty());
+    }
+
     // Error injection tests
 
     fn setup_simple_mock_issuer() -> (MockIssuer, TrustAnchors, IssuerIdentifier, KeyPair) {
@@ -2081,6 +3636,14 @@ mod tests {
             Ok(self.issuer.type_metadata(&id).unwrap())
         }
 
+        async fn resolve_type_metadata(
+            &self,
+            _vct: &str,
+            _vct_integrity: Option<Integrity>,
+        ) -> Result<Option<TypeMetadataDocuments>, WalletIssuanceError> {
+            Ok(None)
+        }
+
         async fn request_nonce(&self, _url: Url) -> Result<(NonceResponse, Option<String>), WalletIssuanceError> {
             let c_nonce = self.issuer.generate_nonce().await.unwrap();
             Ok((NonceResponse { c_nonce }, None))
@@ -2092,7 +3655,7 @@ mod tests {
             credential_request: &CredentialRequest,
             dpop_header: &str,
             access_token_header: &str,
-        ) -> Result<CredentialResponse, WalletIssuanceError> {
+        ) -> Result<MaybeEncrypted<CredentialResponse>, WalletIssuanceError> {
             self.issuer
                 .process_credential(
                     self.access_token(access_token_header),
@@ -2113,7 +3676,7 @@ mod tests {
             credential_requests: &CredentialRequests,
             dpop_header: &str,
             access_token_header: &str,
-        ) -> Result<CredentialResponses, WalletIssuanceError> {
+        ) -> Result<MaybeEncrypted<CredentialResponses>, WalletIssuanceError> {
             self.issuer
                 .process_batch_credential(
                     self.access_token(access_token_header),
@@ -2128,6 +3691,28 @@ mod tests {
                 })
         }
 
+        async fn request_deferred_credential(
+            &self,
+            _url: &Url,
+            deferred_credential_request: &DeferredCredentialRequest,
+            dpop_header: &str,
+            access_token_header: &str,
+        ) -> Result<MaybeEncrypted<DeferredCredentialResponse>, WalletIssuanceError> {
+            self.issuer
+                .process_deferred_credential(
+                    self.access_token(access_token_header),
+                    self.dpop_header(dpop_header),
+                    deferred_credential_request.clone(),
+                )
+                .await
+                .map(|(response, _)| response)
+                .map_err(|error| {
+                    let error_response = ErrorResponse::<CredentialErrorCode>::from(error);
+
+                    WalletIssuanceError::CredentialRequest(Box::new(error_response.into()))
+                })
+        }
+
         async fn reject(
             &self,
             _url: &Url,
@@ -2147,6 +3732,27 @@ mod tests {
                     WalletIssuanceError::CredentialRejection(Box::new(error_response.into()))
                 })
         }
+
+        async fn send_notification(
+            &self,
+            _url: &Url,
+            notification_request: &NotificationRequest,
+            dpop_header: &str,
+            access_token_header: &str,
+        ) -> Result<(), WalletIssuanceError> {
+            self.issuer
+                .process_notification(
+                    self.access_token(access_token_header),
+                    self.dpop_header(dpop_header),
+                    notification_request.clone(),
+                )
+                .await
+                .map_err(|error| {
+                    let error_response = ErrorResponse::<NotificationErrorCode>::from(error);
+
+                    WalletIssuanceError::Notification(Box::new(error_response.into()))
+                })
+        }
     }
 
     async fn start_and_accept_err(
@@ -2185,12 +3791,17 @@ mod tests {
             .unwrap()
             .pre_authorized_code;
         let batch_size = issuer_metadata.batch_size().try_into().unwrap();
+        let response_encryption = issuer_metadata
+            .credential_response_encryption
+            .as_ref()
+            .and_then(CredentialResponseEncryption::select_encryption_algorithm);
         let mut session = HttpIssuanceSession::create(
             message_client,
             credential_configs,
             issuer_metadata.credential_issuer,
             issuer_metadata.endpoints,
             batch_size,
+            response_encryption,
             &oauth_metadata.token_endpoint,
             ClientAttestationChallengeMechanism::ChallengeEndpoint(oauth_metadata.challenge_endpoint.unwrap()),
             TokenRequest::new_mock_with_pre_authorized_code(code),
@@ -2248,6 +3859,7 @@ mod tests {
             issuer_metadata.credential_issuer,
             issuer_metadata.endpoints,
             batch_size,
+            None,
             &oauth_metadata.token_endpoint,
             ClientAttestationChallengeMechanism::ChallengeEndpoint(oauth_metadata.challenge_endpoint.unwrap()),
             TokenRequest::new_mock_with_pre_authorized_code(session_token),
@@ -2434,6 +4046,229 @@ mod tests {
         assert_matches!(error, TokenRequestError::Wia(WiaVerificationError::InvalidChallenge));
     }
 
+    #[tokio::test]
+    async fn token_request_issues_and_redeems_refresh_token() {
+        let (mut issuer, _trust_anchor, issuer_identifier, wia_keypair) = setup_simple_mock_issuer();
+        issuer.credential_refresh = Some(CredentialRefresh {
+            refresh_token_store: Box::new(MemoryRefreshTokenStore::new(chrono::Duration::days(1))),
+            document_source: Box::new(MockIssuableDocumentSource),
+        });
+
+        let wia_client = MockWiaClient::new_with_wia_keypair(wia_keypair);
+        let dpop_signing_key = SigningKey::generate();
+        let token_url = issuer.issuer_data.server_url.join("token");
+
+        let process_token_request = async |token_request: TokenRequest, signing_key: &SigningKey| {
+            let nonce = issuer.generate_nonce().await.unwrap();
+            let wia = wia_client
+                .issue_wia(issuer_identifier.to_string(), Some(nonce))
+                .await
+                .unwrap();
+            let dpop = Dpop::new(signing_key, token_url.clone(), &Method::POST, None, None).unwrap();
+
+            issuer.process_token_request(token_request, dpop, wia).await
+        };
+
+        // Only documents that refer to their source can be reissued, so no refresh token is handed out otherwise.
+        let (token_request, _) = mock_token_request_and_dpop(&issuer).await;
+        let (token_response, _) = process_token_request(token_request, &dpop_signing_key).await.unwrap();
+        assert!(token_response.refresh_token.is_none());
+
+        let code = issuer
+            .new_preauthorized_session(
+                mock_issuable_documents(NonZeroUsize::MIN)
+                    .into_nonempty_iter()
+                    .map(|document| document.with_source_reference("reference".to_string().into()))
+                    .collect(),
+            )
+            .await
+            .unwrap()
+            .grants
+            .unwrap()
+            .pre_authorized_code
+            .unwrap()
+            .pre_authorized_code;
+        let (token_response, _) =
+            process_token_request(TokenRequest::new_mock_with_pre_authorized_code(code), &dpop_signing_key)
+                .await
+                .unwrap();
+        let refresh_token = token_response
+            .refresh_token
+            .expect("Token Response should contain a refresh token");
+
+        // The refresh token is bound to the DPoP key of the wallet. Using a different key does not invalidate it.
+        let error = process_token_request(
+            TokenRequest::new_refresh_token(refresh_token.clone()),
+            &SigningKey::generate(),
+        )
+        .await
+        .expect_err("redeeming refresh token using a different DPoP key should fail");
+        assert_matches!(error, TokenRequestError::RefreshTokenDpopKeyMismatch);
+
+        // Redeeming the refresh token results in a new access token for the same credentials as fetched again from
+        // their source, as well as a new refresh token.
+        let (token_response, _) = process_token_request(
+            TokenRequest::new_refresh_token(refresh_token.clone()),
+            &dpop_signing_key,
+        )
+        .await
+        .expect("redeeming refresh token should succeed");
+
+        assert!(token_response.authorization_details.is_some());
+        let new_refresh_token = token_response
+            .refresh_token
+            .expect("Token Response should contain a new refresh token");
+        assert_ne!(new_refresh_token, refresh_token);
+
+        // The old refresh token can not be redeemed a second time.
+        let error = process_token_request(TokenRequest::new_refresh_token(refresh_token), &dpop_signing_key)
+            .await
+            .expect_err("redeeming refresh token twice should fail");
+        assert_matches!(error, TokenRequestError::UnknownRefreshToken);
+
+        // The new refresh token can in turn be redeemed.
+        process_token_request(TokenRequest::new_refresh_token(new_refresh_token), &dpop_signing_key)
+            .await
+            .expect("redeeming new refresh token should succeed");
+    }
+
+    #[tokio::test]
+    async fn token_request_verifies_tx_code() {
+        let (issuer, _trust_anchor, issuer_identifier, wia_keypair) = setup_simple_mock_issuer();
+
+        let wia_client = MockWiaClient::new_with_wia_keypair(wia_keypair);
+        let token_url = issuer.issuer_data.server_url.join("token");
+
+        let process_token_request = async |token_request: TokenRequest| {
+            let nonce = issuer.generate_nonce().await.unwrap();
+            let wia = wia_client
+                .issue_wia(issuer_identifier.to_string(), Some(nonce))
+                .await
+                .unwrap();
+            let dpop = Dpop::new(&SigningKey::generate(), token_url.clone(), &Method::POST, None, None).unwrap();
+
+            issuer.process_token_request(token_request, dpop, wia).await
+        };
+
+        let new_session = async || {
+            let (credential_offer, _, tx_code) = issuer
+                .new_preauthorized_session_with_tx_code(
+                    mock_issuable_documents(NonZeroUsize::MIN),
+                    false,
+                    PreAuthTransactionCode::default(),
+                )
+                .await
+                .unwrap();
+
+            let grant = credential_offer.grants.unwrap().pre_authorized_code.unwrap();
+
+            // The Credential Offer should contain the requirements of the generated Transaction Code.
+            assert_eq!(grant.tx_code.unwrap().length, Some(6));
+            assert_eq!(tx_code.len(), 6);
+            assert!(tx_code.chars().all(|c| c.is_ascii_digit()));
+
+            (grant.pre_authorized_code, tx_code)
+        };
+
+        // A missing Transaction Code is rejected, but does not count as an attempt.
+        let (code, tx_code) = new_session().await;
+        let error = process_token_request(TokenRequest::new_pre_authorized(code.clone()))
+            .await
+            .unwrap_err();
+        assert_matches!(error, TokenRequestError::MissingTxCode);
+
+        // An incorrect Transaction Code may be corrected afterwards.
+        let error = process_token_request(TokenRequest::new_pre_authorized_with_tx_code(
+            code.clone(),
+            Some("wrong".to_string()),
+        ))
+        .await
+        .unwrap_err();
+        assert_matches!(error, TokenRequestError::IncorrectTxCode { remaining_attempts: 2 });
+
+        process_token_request(TokenRequest::new_pre_authorized_with_tx_code(code, Some(tx_code)))
+            .await
+            .expect("token request with correct Transaction Code should succeed");
+
+        // After too many incorrect attempts, the session fails, even for the correct Transaction Code.
+        let (code, tx_code) = new_session().await;
+        for remaining in (1..MAX_TX_CODE_ATTEMPTS).rev() {
+            let error = process_token_request(TokenRequest::new_pre_authorized_with_tx_code(
+                code.clone(),
+                Some("wrong".to_string()),
+            ))
+            .await
+            .unwrap_err();
+            assert_matches!(error, TokenRequestError::IncorrectTxCode { remaining_attempts } if remaining_attempts == remaining);
+        }
+
+        let error = process_token_request(TokenRequest::new_pre_authorized_with_tx_code(
+            code.clone(),
+            Some("wrong".to_string()),
+        ))
+        .await
+        .unwrap_err();
+        assert_matches!(error, TokenRequestError::TxCodeAttemptsExceeded);
+
+        let error = process_token_request(TokenRequest::new_pre_authorized_with_tx_code(code, Some(tx_code)))
+            .await
+            .unwrap_err();
+        assert_matches!(error, TokenRequestError::IssuanceError(_));
+
+        // Concurrent Token Requests cannot make more incorrect attempts than allowed in total.
+        let (code, _) = new_session().await;
+        let errors = futures::future::join_all((0..10).map(|_| {
+            process_token_request(TokenRequest::new_pre_authorized_with_tx_code(
+                code.clone(),
+                Some("wrong".to_string()),
+            ))
+        }))
+        .await
+        .into_iter()
+        .map(Result::unwrap_err)
+        .collect_vec();
+
+        let incorrect_count = errors
+            .iter()
+            .filter(|error| matches!(error, TokenRequestError::IncorrectTxCode { .. }))
+            .count();
+        assert_eq!(incorrect_count, usize::from(MAX_TX_CODE_ATTEMPTS - 1));
+        assert!(
+            errors
+                .iter()
+                .any(|error| matches!(error, TokenRequestError::TxCodeAttemptsExceeded))
+        );
+    }
+
+    #[tokio::test]
+    async fn token_request_rejects_refresh_token_without_store() {
+        let (issuer, _trust_anchor, issuer_identifier, wia_keypair) = setup_simple_mock_issuer();
+        let (token_request, dpop) = mock_token_request_and_dpop(&issuer).await;
+
+        let nonce = issuer.generate_nonce().await.unwrap();
+        let wia = MockWiaClient::new_with_wia_keypair(wia_keypair)
+            .issue_wia(issuer_identifier.to_string(), Some(nonce))
+            .await
+            .unwrap();
+
+        // Without a refresh token store, no refresh tokens are handed out or accepted.
+        let (token_response, _) = issuer
+            .process_token_request(token_request, dpop.clone(), wia.clone())
+            .await
+            .unwrap();
+        assert!(token_response.refresh_token.is_none());
+
+        let error = issuer
+            .process_token_request(
+                TokenRequest::new_refresh_token("refresh_token".to_string().into()),
+                dpop,
+                wia,
+            )
+            .await
+            .unwrap_err();
+        assert_matches!(error, TokenRequestError::RefreshTokenUnsupported);
+    }
+
     #[tokio::test]
     async fn wrong_access_token() {
         let (issuer, trust_anchor, issuer_identifier, wia_issuer_privkey) = setup_simple_mock_issuer();
@@ -5,6 +5,7 @@ pub mod credential;
 pub mod credential_offer;
 pub mod issuer_identifier;
 pub mod metadata;
+pub mod notification;
 pub mod par;
 pub mod scope;
 pub mod token;
@@ -29,6 +30,7 @@ pub mod disclosure_session;
 pub mod openid4vp;
 pub mod return_url;
 pub mod verifier;
+pub mod webhook;
 
 // Errors used throughout the crate.
 pub mod errors;
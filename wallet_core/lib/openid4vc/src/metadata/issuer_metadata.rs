@@ -15,6 +15,7 @@ use derive_more::Display;
 use derive_more::From;
 use derive_more::Into;
 use itertools::Itertools;
+use jwe::algorithm::EncryptionAlgorithm;
 use jwk_simple::Algorithm;
 use jwk_simple::Key;
 use jwt::JwtTyp;
@@ -230,6 +231,18 @@ pub struct CredentialResponseEncryption {
     pub encryption_required: bool,
 }
 
+impl CredentialResponseEncryption {
+    /// Select the preferred JWE encryption algorithm supported by the issuer, provided that it also supports ECDH-ES
+    /// key agreement. Returns `None` if the wallet is not able to have the Credential Response encrypted.
+    pub fn select_encryption_algorithm(&self) -> Option<EncryptionAlgorithm> {
+        if !self.alg_values_supported.as_slice().contains(&Algorithm::EcdhEs) {
+            return None;
+        }
+
+        JweEncryptionAlgorithm::find_preferred_known(&self.enc_values_supported)
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 #[error("batch size must be 2 or greater, received: {0}")]
 pub struct AtLeastTwoU64Error(NonZeroU64);
@@ -669,7 +682,7 @@ impl From<DisplayMetadata> for CredentialDisplay {
                 background_color,
                 text_color,
             }) => (logo, background_image, background_color, text_color),
-            Some(RenderingMetadata::SvgTemplates) | None => (None, None, None, None),
+            Some(RenderingMetadata::SvgTemplates(_)) | None => (None, None, None, None),
         };
 
         Self {
@@ -757,11 +770,15 @@ mod tests {
     use jwk_simple::Algorithm;
     use jwk_simple::KeyParams;
     use jwt::VerifiedJwt;
+    use rstest::rstest;
     use serde_json::json;
+    use utils::vec_at_least::VecNonEmpty;
+    use utils::vec_nonempty;
 
     use super::CoseAlgorithmIdentifier;
     use super::CredentialConfiguration;
     use super::CredentialFormat;
+    use super::CredentialResponseEncryption;
     use super::CryptographicBindingMethod;
     use super::IssuerMetadata;
     use super::JwsAlgorithm;
@@ -769,6 +786,38 @@ mod tests {
     use super::SignedIssuerMetadataPayload;
     use crate::issuer_identifier::IssuerIdentifier;
     use crate::jwe::JweCompressionAlgorithm;
+    use crate::jwe::JweEncryptionAlgorithm;
+
+    #[rstest]
+    #[case::preferred(
+        vec_nonempty![Algorithm::EcdhEs],
+        vec_nonempty![EncryptionAlgorithm::A128Gcm.into(), EncryptionAlgorithm::A256Gcm.into()],
+        Some(EncryptionAlgorithm::A256Gcm)
+    )]
+    #[case::unknown_enc(
+        vec_nonempty![Algorithm::EcdhEs],
+        vec_nonempty![JweEncryptionAlgorithm::Unknown("A512GCM".to_string())],
+        None
+    )]
+    #[case::no_ecdh_es(
+        vec_nonempty![Algorithm::EcdhEsA128kw],
+        vec_nonempty![EncryptionAlgorithm::A128Gcm.into()],
+        None
+    )]
+    fn test_credential_response_encryption_select_encryption_algorithm(
+        #[case] alg_values_supported: VecNonEmpty<Algorithm>,
+        #[case] enc_values_supported: VecNonEmpty<JweEncryptionAlgorithm>,
+        #[case] expected_algorithm: Option<EncryptionAlgorithm>,
+    ) {
+        let response_encryption = CredentialResponseEncryption {
+            alg_values_supported,
+            enc_values_supported,
+            zip_values_supported: None,
+            encryption_required: true,
+        };
+
+        assert_eq!(response_encryption.select_encryption_algorithm(), expected_algorithm);
+    }
 
     #[test]
     fn test_sd_jwt_issuer_metadata_deserialization() {
@@ -63,7 +63,9 @@ impl IssuerMetadata {
         let issuer_url = issuer_identifier.as_issuer_url();
         let credential_endpoint = issuer_url.join_issuer_url("/issuance/credential");
         let batch_credential_endpoint = issuer_url.join_issuer_url("/issuance/batch_credential");
+        let deferred_credential_endpoint = issuer_url.join_issuer_url("/issuance/deferred_credential");
         let nonce_endpoint = issuer_url.join_issuer_url("/issuance/nonce");
+        let notification_endpoint = issuer_url.join_issuer_url("/issuance/notification");
         let credential_preview_endpoint = issuer_url.join_issuer_url("/issuance/credential_preview");
 
         let credential_configurations_supported = credential_configs
@@ -104,8 +106,8 @@ impl IssuerMetadata {
                 credential_endpoint,
                 batch_credential_endpoint: Some(batch_credential_endpoint),
                 nonce_endpoint: Some(nonce_endpoint),
-                deferred_credential_endpoint: None,
-                notification_endpoint: None,
+                deferred_credential_endpoint: Some(deferred_credential_endpoint),
+                notification_endpoint: Some(notification_endpoint),
                 credential_preview_endpoint: Some(credential_preview_endpoint),
             },
             credential_request_encryption: None,
@@ -0,0 +1,59 @@
+use serde::Deserialize;
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// A Notification Request, see:
+/// <https://openid.net/specs/openid-4-verifiable-credential-issuance-1_0.html#section-11.1>.
+/// Sent JSON-encoded to `POST /notification`, using the same access token as was used to obtain the credential.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotificationRequest {
+    pub notification_id: String,
+    pub event: NotificationEvent,
+    pub event_description: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum NotificationEvent {
+    /// The credential was successfully stored by the wallet.
+    CredentialAccepted,
+    /// The credential was not stored by the wallet, for a reason other than the user deleting or rejecting it.
+    CredentialFailure,
+    /// The credential was stored by the wallet, but was subsequently deleted by the user.
+    CredentialDeleted,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_notification_request_serialization() {
+        // Source: https://openid.net/specs/openid-4-verifiable-credential-issuance-1_0.html#section-11.1-6
+        let json = json!({
+            "notification_id": "3fwe98js",
+            "event": "credential_failure",
+            "event_description": "Could not store the Credential. Out of storage."
+        });
+
+        let request = serde_json::from_value::<NotificationRequest>(json.clone())
+            .expect("notification request JSON should parse correctly");
+
+        assert_eq!(
+            request,
+            NotificationRequest {
+                notification_id: "3fwe98js".to_string(),
+                event: NotificationEvent::CredentialFailure,
+                event_description: Some("Could not store the Credential. Out of storage.".to_string()),
+            }
+        );
+
+        let output_json = serde_json::to_value(request).expect("notification request should serialize to JSON");
+
+        assert_eq!(json, output_json);
+    }
+}
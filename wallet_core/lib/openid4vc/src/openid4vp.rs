@@ -17,6 +17,10 @@ use cose::KnownCoseAlgorithmIdentifier;
 use crypto::PublicKey;
 use crypto::trust_anchor::TrustAnchors;
 use crypto::x509::BorrowingCertificate;
+use crypto::x509::CertificateError;
+use crypto::x509::CertificateUsage;
+use crypto::x509::revocation::RevocationChecker;
+use crypto::x509::revocation::RevocationError;
 use dcql::CredentialQueryIdentifier;
 use dcql::Query;
 use dcql::disclosure::CredentialValidationError;
@@ -41,6 +45,7 @@ use jwe::error::JweJsonDecryptionError;
 use jwe::error::JweJsonEncryptionError;
 use jwk_simple::Key;
 use jwt::Algorithm;
+use jwt::JsonJwt;
 use jwt::JwtTyp;
 use jwt::JwtValidation;
 use jwt::UnverifiedJwt;
@@ -50,7 +55,11 @@ use jwt::nonce::Nonce;
 use mdoc::DeviceResponse;
 use mdoc::SessionTranscript;
 use mdoc::utils::serialization::CborBase64;
+use sd_jwt::jwt_vc_issuer::HttpJwtVcIssuerMetadataClient;
+use sd_jwt::jwt_vc_issuer::JwtVcIssuerKeyResolver;
 use sd_jwt::key_binding_jwt::KbVerificationOptions;
+use sd_jwt::sd_jwt::SdJwtSerialization;
+use sd_jwt::sd_jwt::SdJwtVcClaims;
 use sd_jwt::sd_jwt::UnverifiedSdJwtPresentation;
 use serde::Deserialize;
 use serde::Deserializer;
@@ -115,20 +124,19 @@ pub enum VpRequestUriMethod {
 pub enum VpRequestUriObject {
     /// A request object by reference, i.e., contains a `request_uri` parameter
     /// pointing to where to fetch the actual authorization request.
-    /// Note that this is the only supported variant of VpRequestUriObject.
     AsReference {
         request_uri: BaseUrl,
         request_uri_method: Option<VpRequestUriMethod>,
     },
 
-    /// A request object by value, i.e., a `request` parameter with an inline JWT.
-    /// Note that technically, instead of String, the request value could be an
-    /// UnverifiedJwt<VpAuthorizationRequest, HeaderWithX5c>, but since we don't
-    /// actually support AsValue, we (currently) don't bother.
+    /// A request object by value, i.e., a `request` parameter with an inline JWT. This is kept as a string here so
+    /// that this type can remain comparable; the wallet parses it to an
+    /// `UnverifiedJwt<VpAuthorizationRequest, HeaderWithX5c>` before verifying it.
     AsValue { request: String },
 
     /// A direct authorization request with required fields as query parameters.
-    /// Note that, as with AsValue, we do not support the AsQueryParameters variant.
+    /// Note that we do not support this variant, as such an unsigned request cannot be authenticated
+    /// using the `client_id` scheme.
     AsQueryParameters { response_type: String, nonce: String },
 }
 
@@ -297,6 +305,24 @@ impl ClientId {
     pub fn x509_hash_from_certificate(certificate: &BorrowingCertificate) -> Self {
         Self::x509_hash(Self::x509_hash_value(certificate))
     }
+
+    pub fn x509_san_dns(dns_name: impl Into<String>) -> Self {
+        Self {
+            id: dns_name.into(),
+            scheme: Some(ClientIdScheme::X509SanDns),
+        }
+    }
+
+    /// Construct an `x509_san_dns` client_id from the first SAN DNS name in the certificate.
+    pub fn x509_san_dns_from_certificate(certificate: &BorrowingCertificate) -> Result<Self, CertificateError> {
+        let dns_name = certificate
+            .san_dns_names()?
+            .into_iter()
+            .next()
+            .ok_or(CertificateError::MissingSanDnsNameOrUri)?;
+
+        Ok(Self::x509_san_dns(dns_name))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -397,10 +423,13 @@ pub enum AuthRequestValidationError {
     )]
     #[category(critical)]
     NoSupportedEncryptedResponseEnc(VecNonEmpty<JweEncryptionAlgorithm>),
-    #[error("unsupported client_id scheme: {scheme}. Only x509_hash is currently supported")]
+    #[error("unsupported client_id scheme: {scheme}. Only x509_hash and x509_san_dns are currently supported")]
     #[category(critical)]
     UnsupportedClientIdScheme { scheme: ClientIdScheme },
-    #[error("unsupported client_id without a scheme (pre-registered). Only x509_hash is currently supported")]
+    #[error(
+        "unsupported client_id without a scheme (pre-registered). Only x509_hash and x509_san_dns are currently \
+         supported"
+    )]
     #[category(critical)]
     UnsupportedClientIdWithoutScheme,
     #[error("unsupported DCQL query: {0}")]
@@ -414,6 +443,22 @@ pub enum AuthRequestValidationError {
         client_id: String,
         certificate_hash: String,
     },
+    #[error(
+        "client_id from Authorization Request was {client_id}, which does not match any of the SAN DNS names of the \
+         leaf X.509 certificate from the x5c JOSE header: {}",
+        .san_dns_names.join(", ")
+    )]
+    #[category(critical)]
+    UnauthorizedClientIdSanDns {
+        client_id: String,
+        san_dns_names: Vec<String>,
+    },
+    #[error("could not read SAN DNS names from leaf X.509 certificate: {0}")]
+    #[category(critical)]
+    CertificateSanDnsNames(#[source] CertificateError),
+    #[error("host of response_uri {response_uri} does not match client_id {client_id}")]
+    #[category(critical)]
+    ResponseUriHostMismatch { client_id: String, response_uri: String },
     #[error("failed to verify Authorization Request JWT: {0}")]
     JwtVerification(#[from] JwtX5cVerifyError),
     #[error("mismatch in wallet nonce: did not receive nonce when one was expected, or vice versa")]
@@ -446,7 +491,9 @@ impl VpAuthorizationRequest {
 
     /// Validate that an Authorization Request satisfies the following:
     /// - the request contents are compliant with the OpenID4VP specification.
-    /// - the `client_id` uses the `x509_hash` scheme and matches the leaf X.509 certificate.
+    /// - the `client_id` uses either the `x509_hash` scheme and matches the hash of the leaf X.509 certificate, or the
+    ///   `x509_san_dns` scheme and matches one of the SAN DNS names of the leaf X.509 certificate. In the latter case,
+    ///   the host of the `response_uri` has to equal the DNS name as well.
     ///
     /// This method consumes `self` and turns it into an [`NormalizedVpAuthorizationRequest`], which
     /// contains only the fields we need and use.
@@ -477,6 +524,31 @@ impl VpAuthorizationRequest {
                     });
                 }
             }
+            Some(ClientIdScheme::X509SanDns) => {
+                // A certificate without a SAN extension simply does not authorize any DNS name.
+                let san_dns_names = match rp_cert.san_dns_names() {
+                    Ok(san_dns_names) => san_dns_names,
+                    Err(CertificateError::MissingSan) => Vec::new(),
+                    Err(error) => return Err(AuthRequestValidationError::CertificateSanDnsNames(error)),
+                };
+
+                if !san_dns_names.contains(&client_id.id.as_str()) {
+                    return Err(AuthRequestValidationError::UnauthorizedClientIdSanDns {
+                        client_id: client_id.to_string(),
+                        san_dns_names: san_dns_names.into_iter().map(String::from).collect(),
+                    });
+                }
+
+                // Since we do not keep a list of trusted client_id values, the response_uri is not allowed to point to
+                // any other host than the one authenticated by the certificate.
+                // See: <https://openid.net/specs/openid-4-verifiable-presentations-1_0.html#section-5.9.3>
+                if validated_auth_request.response_uri.as_ref().host_str() != Some(client_id.id.as_str()) {
+                    return Err(AuthRequestValidationError::ResponseUriHostMismatch {
+                        client_id: client_id.to_string(),
+                        response_uri: validated_auth_request.response_uri.to_string(),
+                    });
+                }
+            }
             Some(scheme) => {
                 return Err(AuthRequestValidationError::UnsupportedClientIdScheme { scheme: scheme.clone() });
             }
@@ -744,6 +816,9 @@ pub enum AuthResponseError {
     #[error("not all revocation statuses are valid")]
     #[category(expected)]
     RevocationStatusNotAllValid,
+
+    #[error("error checking revocation status of issuer certificate: {0}")]
+    IssuerCertificateRevocation(#[source] RevocationError),
 }
 
 /// Disclosure of a credential, generally containing the issuer-signed credential itself, the disclosed attributes,
@@ -755,28 +830,85 @@ pub enum VerifiablePresentation {
     // NB: a `DeviceResponse` can contain disclosures of multiple mdocs. In case of other (not yet supported) formats,
     //     each credential is expected to result in a separate Verifiable Presentation.
     MsoMdoc(#[serde_as(as = "Vec<CborBase64>")] VecNonEmpty<DeviceResponse>),
-    SdJwt(VecNonEmpty<UnverifiedSdJwtPresentation>),
+    /// SD-JWT presentations, which are serialized as strings when using the compact serialization or as JSON objects
+    /// when using the JWS JSON serialization. As OpenID4VP provides no way for a verifier to advertise support for the
+    /// latter, the wallet always uses the compact serialization. The JWS JSON serialization is only accepted as input.
+    #[serde(serialize_with = "serialize_sd_jwt_presentations")]
+    SdJwt(VecNonEmpty<UnverifiedSdJwtPresentation>, SdJwtSerialization),
+}
+
+#[expect(clippy::trivially_copy_pass_by_ref, reason = "signature required by serde")]
+fn serialize_sd_jwt_presentations<S: Serializer>(
+    presentations: &VecNonEmpty<UnverifiedSdJwtPresentation>,
+    serialization: &SdJwtSerialization,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let to_jws_json = match serialization {
+        SdJwtSerialization::Compact => return presentations.serialize(serializer),
+        SdJwtSerialization::JwsJsonFlattened => UnverifiedSdJwtPresentation::to_jws_json_flattened,
+        SdJwtSerialization::JwsJsonGeneral => UnverifiedSdJwtPresentation::to_jws_json_general,
+    };
+
+    presentations
+        .iter()
+        .map(to_jws_json)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(serde::ser::Error::custom)?
+        .serialize(serializer)
+}
+
+/// A single entry of a Verifiable Presentation, which is either a string or an SD-JWT in the JWS JSON serialization.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum VerifiablePresentationPayload {
+    String(String),
+    JwsJson(JsonJwt<SdJwtVcClaims, HeaderWithX5c>),
 }
 
 /// Manual implementation of [`Deserialize`] for [`VerifiablePresentation`] is necessary, in order to help `serde`
 /// discern between the two enum variants without attempting to do a full base64 / CBOR decode.
 impl<'de> Deserialize<'de> for VerifiablePresentation {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        let payloads = VecNonEmpty::<String>::deserialize(deserializer)?;
+        let payloads = VecNonEmpty::<VerifiablePresentationPayload>::deserialize(deserializer)?;
+
+        // Assume the payloads are SD-JWT if any of them is a JSON object or contains
+        // a tilde character, which does not occur in URL-safe Base64.
+        let is_sd_jwt = payloads.iter().any(|payload| match payload {
+            VerifiablePresentationPayload::String(payload) => payload.contains('~'),
+            VerifiablePresentationPayload::JwsJson(_) => true,
+        });
 
-        // Assume the payloads are SD-JWT if any of them contains a
-        // tilde character, which does not occur in URL-safe Base64.
-        let verifiable_presentation = if payloads.iter().any(|payload| payload.contains('~')) {
+        let verifiable_presentation = if is_sd_jwt {
+            let serialization = match payloads.first() {
+                VerifiablePresentationPayload::String(_) => SdJwtSerialization::Compact,
+                VerifiablePresentationPayload::JwsJson(json_jwt) => json_jwt.into(),
+            };
             let presentations = payloads
                 .into_nonempty_iter()
-                .map(|sd_jwt| UnverifiedSdJwtPresentation::deserialize(StringDeserializer::new(sd_jwt)))
-                .collect::<Result<_, _>>()?;
-            Self::SdJwt(presentations)
+                .map(|payload| match payload {
+                    VerifiablePresentationPayload::String(sd_jwt) => {
+                        UnverifiedSdJwtPresentation::deserialize(StringDeserializer::new(sd_jwt))
+                    }
+                    VerifiablePresentationPayload::JwsJson(json_jwt) => {
+                        UnverifiedSdJwtPresentation::try_from(json_jwt).map_err(serde::de::Error::custom)
+                    }
+                })
+                .collect::<Result<_, D::Error>>()?;
+
+            Self::SdJwt(presentations, serialization)
         } else {
             let device_responses = payloads
                 .into_nonempty_iter()
-                .map(|base64| CborBase64::deserialize_as(StringDeserializer::new(base64)))
-                .collect::<Result<_, _>>()?;
+                .map(|payload| match payload {
+                    VerifiablePresentationPayload::String(base64) => {
+                        CborBase64::deserialize_as(StringDeserializer::new(base64))
+                    }
+                    // This cannot occur, as JSON objects are always assumed to be SD-JWT.
+                    VerifiablePresentationPayload::JwsJson(_) => Err(serde::de::Error::custom(
+                        "unexpected JSON object in mdoc Verifiable Presentation",
+                    )),
+                })
+                .collect::<Result<_, D::Error>>()?;
 
             Self::MsoMdoc(device_responses)
         };
@@ -846,6 +978,8 @@ impl VpAuthorizationResponse {
         trust_anchors: &TrustAnchors,
         extending_vct_values: &impl ExtendingVctRetriever,
         revocation_verifier: &RevocationVerifier<C>,
+        certificate_revocation_checker: Option<&RevocationChecker>,
+        jwt_vc_issuer_key_resolver: Option<&JwtVcIssuerKeyResolver<HttpJwtVcIssuerMetadataClient>>,
         accept_undetermined_revocation_status: bool,
     ) -> Result<UniqueIdVec<DisclosedAttestations>, AuthResponseError>
     where
@@ -861,6 +995,8 @@ impl VpAuthorizationResponse {
                 trust_anchors,
                 extending_vct_values,
                 revocation_verifier,
+                certificate_revocation_checker,
+                jwt_vc_issuer_key_resolver,
                 accept_undetermined_revocation_status,
             )
             .await
@@ -885,6 +1021,8 @@ impl VpAuthorizationResponse {
         trust_anchors: &TrustAnchors,
         extending_vct_values: &impl ExtendingVctRetriever,
         revocation_verifier: &RevocationVerifier<C>,
+        certificate_revocation_checker: Option<&RevocationChecker>,
+        jwt_vc_issuer_key_resolver: Option<&JwtVcIssuerKeyResolver<HttpJwtVcIssuerMetadataClient>>,
         accept_undetermined_revocation_status: bool,
     ) -> Result<UniqueIdVec<DisclosedAttestations>, AuthResponseError>
     where
@@ -911,6 +1049,7 @@ impl VpAuthorizationResponse {
                                 time,
                                 trust_anchors,
                                 revocation_verifier,
+                                certificate_revocation_checker,
                             )
                             .await
                         }))
@@ -926,7 +1065,7 @@ impl VpAuthorizationResponse {
                         keys,
                     )
                 }
-                VerifiablePresentation::SdJwt(sdw_jwt_payloads) => {
+                VerifiablePresentation::SdJwt(sdw_jwt_payloads, _) => {
                     let (keys, attestations): (Vec<_>, Vec<_>) = try_join_all(
                         sdw_jwt_payloads
                             .into_nonempty_iter()
@@ -937,6 +1076,8 @@ impl VpAuthorizationResponse {
                                     time,
                                     trust_anchors,
                                     revocation_verifier,
+                                    certificate_revocation_checker,
+                                    jwt_vc_issuer_key_resolver,
                                 )
                                 .await
                             }),
@@ -995,26 +1136,26 @@ impl VpAuthorizationResponse {
         //         However, we discard them here in order to provide a predictable API to the RP by publishing exactly
         //         those attributes it requested at the `disclosed_attributes` endpoint.
         //
-        //         Note that the order of the attributes within a `DisclosedAttestation` is undefined.
+        //         Note that when the DCQL request contains credential sets, only the Credential Requests that are
+        //         part of the satisfied credential set options will have a matching disclosed attestation. Also note
+        //         that the order of the attributes within a `DisclosedAttestation` is undefined.
         let disclosed_attestations = auth_request
             .credential_requests
             .as_ref()
             .iter()
-            .map(|credential_request| {
-                // Safety: in step 4 we checked that for each `credential_request`
-                //         there is a matching disclosed attestation.
-                let (id, mut attestations) = disclosed_attestations.remove_entry(credential_request.id()).unwrap();
+            .filter_map(|credential_request| {
+                let (id, mut attestations) = disclosed_attestations.remove_entry(credential_request.id())?;
 
                 for attestation in &mut attestations {
                     attestation.attributes.prune(credential_request.claim_paths());
                 }
 
-                DisclosedAttestations { id, attestations }
+                Some(DisclosedAttestations { id, attestations })
             })
             .collect_vec();
 
-        // Safety: this comes from mapping over auth_request.credential_requests, which is
-        // a newtype around a `UniqueIdVec`.
+        // Safety: this comes from filtering auth_request.credential_requests, which contains a `UniqueIdVec`. In step 5
+        //         we checked that at least one of the credential requests is satisfied by a disclosed attestation.
         let disclosed_attestations = UniqueIdVec::try_from(disclosed_attestations).unwrap();
 
         Ok(disclosed_attestations)
@@ -1026,6 +1167,7 @@ impl VpAuthorizationResponse {
         time: &impl Generator<DateTime<Utc>>,
         trust_anchors: &TrustAnchors,
         revocation_verifier: &RevocationVerifier<C>,
+        certificate_revocation_checker: Option<&RevocationChecker>,
     ) -> Result<Vec<(PublicKey, DisclosedAttestation)>, AuthResponseError>
     where
         C: StatusListClient,
@@ -1036,6 +1178,22 @@ impl VpAuthorizationResponse {
             .verify(None, session_transcript, time, trust_anchors, revocation_verifier)
             .await?;
 
+        for document in device_response.documents.iter().flatten() {
+            let issuer_certificate_chain = document
+                .issuer_signed
+                .issuer_auth
+                .x5chain()
+                .map_err(|error| AuthResponseError::MdocVerification(error.into()))?;
+
+            Self::check_issuer_certificate_revocation(
+                issuer_certificate_chain.as_slice(),
+                time,
+                trust_anchors,
+                certificate_revocation_checker,
+            )
+            .await?;
+        }
+
         // Then attempt to convert the disclosed documents to `DisclosedAttestation`s.
         let disclosed_attestations = disclosed_documents
             .into_iter()
@@ -1051,12 +1209,15 @@ impl VpAuthorizationResponse {
         Ok(disclosed_attestations)
     }
 
+    #[expect(clippy::too_many_arguments)]
     async fn sd_jwt_to_disclosed_attestation<C>(
         unverified_presentation: UnverifiedSdJwtPresentation,
         auth_request: &NormalizedVpAuthorizationRequest,
         time: &impl Generator<DateTime<Utc>>,
         trust_anchors: &TrustAnchors,
         revocation_verifier: &RevocationVerifier<C>,
+        certificate_revocation_checker: Option<&RevocationChecker>,
+        jwt_vc_issuer_key_resolver: Option<&JwtVcIssuerKeyResolver<HttpJwtVcIssuerMetadataClient>>,
     ) -> Result<(PublicKey, DisclosedAttestation), AuthResponseError>
     where
         C: StatusListClient,
@@ -1068,10 +1229,37 @@ impl VpAuthorizationResponse {
             iat_acceptance_window: SD_JWT_IAT_WINDOW,
         };
 
+        // If enabled, SD-JWTs without an `x5c` header are verified by resolving the issuer key from the JWT VC Issuer
+        // Metadata instead, provided that the issuer is allowed by the policy of the key resolver.
+        if let Some(key_resolver) = jwt_vc_issuer_key_resolver
+            && !unverified_presentation.has_x5c()?
+        {
+            let presentation = unverified_presentation
+                .into_verified_against_jwt_vc_issuer(key_resolver, &kb_verification_options, time)
+                .await?;
+
+            let holder_public_key = presentation
+                .sd_jwt()
+                .holder_pubkey()
+                .map_err(AuthResponseError::SdJwtJwkConversion)?;
+
+            let disclosed_attestation = DisclosedAttestation::try_from(presentation)?;
+
+            return Ok((holder_public_key, disclosed_attestation));
+        }
+
         let presentation = unverified_presentation
             .into_verified_against_trust_anchors(trust_anchors, &kb_verification_options, time, revocation_verifier)
             .await?;
 
+        Self::check_issuer_certificate_revocation(
+            presentation.sd_jwt().issuer_certificate_chain().as_slice(),
+            time,
+            trust_anchors,
+            certificate_revocation_checker,
+        )
+        .await?;
+
         let holder_public_key = presentation
             .sd_jwt()
             .holder_pubkey()
@@ -1082,6 +1270,31 @@ impl VpAuthorizationResponse {
         Ok((holder_public_key, disclosed_attestation))
     }
 
+    /// Check the revocation status of the certificate chain of the issuer of a disclosed credential using its CRLs
+    /// and/or OCSP responders, if a [`RevocationChecker`] is configured. Note that this is separate from the revocation
+    /// status of the credential itself, which is determined using its status list.
+    async fn check_issuer_certificate_revocation(
+        issuer_certificate_chain: &[BorrowingCertificate],
+        time: &impl Generator<DateTime<Utc>>,
+        trust_anchors: &TrustAnchors,
+        certificate_revocation_checker: Option<&RevocationChecker>,
+    ) -> Result<(), AuthResponseError> {
+        let Some(checker) = certificate_revocation_checker else {
+            return Ok(());
+        };
+
+        checker
+            .verify_chain(
+                issuer_certificate_chain,
+                trust_anchors,
+                Some(CertificateUsage::Mdl),
+                &[],
+                time,
+            )
+            .await
+            .map_err(AuthResponseError::IssuerCertificateRevocation)
+    }
+
     fn evaluate_revocation_policy<'a>(
         statuses: impl Iterator<Item = &'a Option<RevocationStatus>>,
         accept_undetermined_revocation_status: bool,
@@ -1153,6 +1366,7 @@ mod tests {
     use crypto::server_keys::generate::Ca;
     use crypto::trust_anchor::TrustAnchors;
     use dcql::CredentialQueryIdentifier;
+    use dcql::disclosure::CredentialValidationError;
     use dcql::normalized::NormalizedCredentialRequest;
     use dcql::normalized::NormalizedCredentialRequests;
     use futures::FutureExt;
@@ -1171,6 +1385,7 @@ mod tests {
     use sd_jwt::builder::SignedSdJwt;
     use sd_jwt::examples::WITH_KB_SD_JWT;
     use sd_jwt::key_binding_jwt::KeyBindingJwtBuilder;
+    use sd_jwt::sd_jwt::SdJwtSerialization;
     use sd_jwt::sd_jwt::UnsignedSdJwtPresentation;
     use serde::Deserialize;
     use serde_json::json;
@@ -1402,6 +1617,87 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_client_id_x509_san_dns_from_certificate() {
+        let ca = Ca::generate_mock();
+        let key_pair = ca.generate_wrpac_verifier_san_dns_mock("cert.rp.example.com").unwrap();
+
+        let client_id = ClientId::x509_san_dns_from_certificate(key_pair.certificate()).unwrap();
+
+        assert_eq!(client_id.to_string(), "x509_san_dns:cert.rp.example.com");
+        assert_matches!(client_id.scheme, Some(ClientIdScheme::X509SanDns));
+
+        let key_pair = ca.generate_wrpac_verifier_mock().unwrap();
+        let _ = ClientId::x509_san_dns_from_certificate(key_pair.certificate())
+            .expect_err("certificate without SAN DNS names should not result in x509_san_dns client_id");
+    }
+
+    fn setup_x509_san_dns(client_id: &str, response_uri: &str) -> (KeyPair, VpAuthorizationRequest) {
+        let (_, _, _, auth_request) = setup_mdoc();
+        let rp_keypair = Ca::generate_mock()
+            .generate_wrpac_verifier_san_dns_mock("cert.rp.example.com")
+            .unwrap();
+
+        let mut auth_request = VpAuthorizationRequest::from(auth_request);
+        auth_request.oauth_request.client_id = client_id.to_string();
+        auth_request.response_uri = Some(response_uri.parse().unwrap());
+
+        (rp_keypair, auth_request)
+    }
+
+    #[test]
+    fn test_authorization_request_validate_x509_san_dns_client_id() {
+        let (rp_keypair, auth_request) = setup_x509_san_dns(
+            "x509_san_dns:cert.rp.example.com",
+            "https://cert.rp.example.com/response_uri",
+        );
+
+        let (auth_request, _) = auth_request.validate(rp_keypair.certificate(), None).unwrap();
+        assert_eq!(auth_request.client_id, ClientId::x509_san_dns("cert.rp.example.com"));
+    }
+
+    #[test]
+    fn test_authorization_request_validate_unauthorized_x509_san_dns_client_id() {
+        let (rp_keypair, auth_request) = setup_x509_san_dns(
+            "x509_san_dns:other.example.com",
+            "https://other.example.com/response_uri",
+        );
+
+        let err = auth_request
+            .clone()
+            .validate(rp_keypair.certificate(), None)
+            .unwrap_err();
+        assert_matches!(
+            err,
+            AuthRequestValidationError::UnauthorizedClientIdSanDns { client_id, san_dns_names }
+            if client_id == "x509_san_dns:other.example.com" && san_dns_names == vec!["cert.rp.example.com"]
+        );
+
+        // A certificate without SAN DNS names does not authorize any x509_san_dns client_id.
+        let (_, rp_keypair, _, _) = setup_mdoc();
+        let err = auth_request.validate(rp_keypair.certificate(), None).unwrap_err();
+        assert_matches!(
+            err,
+            AuthRequestValidationError::UnauthorizedClientIdSanDns { san_dns_names, .. } if san_dns_names.is_empty()
+        );
+    }
+
+    #[test]
+    fn test_authorization_request_validate_x509_san_dns_response_uri_host_mismatch() {
+        let (rp_keypair, auth_request) = setup_x509_san_dns(
+            "x509_san_dns:cert.rp.example.com",
+            "https://other.example.com/response_uri",
+        );
+
+        let err = auth_request.validate(rp_keypair.certificate(), None).unwrap_err();
+        assert_matches!(
+            err,
+            AuthRequestValidationError::ResponseUriHostMismatch { client_id, response_uri }
+            if client_id == "x509_san_dns:cert.rp.example.com"
+                && response_uri == "https://other.example.com/response_uri"
+        );
+    }
+
     #[test]
     fn test_authorization_request_validate_unsupported_client_id_scheme() {
         let (_, rp_keypair, _, auth_request) = setup_mdoc();
@@ -2200,12 +2496,14 @@ mod tests {
 
         assert_eq!(auth_response.vp_token.len(), 1);
 
-        let VerifiablePresentation::SdJwt(sd_jwt_presentations) = auth_response.vp_token.into_values().next().unwrap()
+        let VerifiablePresentation::SdJwt(sd_jwt_presentations, serialization) =
+            auth_response.vp_token.into_values().next().unwrap()
         else {
             panic!("received format should be SD-JWT")
         };
 
         assert_eq!(sd_jwt_presentations.len().get(), 1);
+        assert_eq!(serialization, SdJwtSerialization::Compact);
 
         // TODO (PVW-4817): Test the deserialized types once we no longer use `String` to transport SD-JWT.
     }
@@ -2286,6 +2584,8 @@ mod tests {
                 &RevocationVerifier::new_without_caching(Arc::new(StatusListClientStub::new(
                     ca.generate_issuer_status_list_mock().unwrap(),
                 ))),
+                None,
+                None,
                 false,
             )
             .now_or_never()
@@ -2314,8 +2614,11 @@ mod tests {
         );
     }
 
-    #[test]
-    fn test_verify_sd_jwt_authorization_response() {
+    #[rstest]
+    #[case::compact(SdJwtSerialization::Compact)]
+    #[case::jws_json_flattened(SdJwtSerialization::JwsJsonFlattened)]
+    #[case::jws_json_general(SdJwtSerialization::JwsJsonGeneral)]
+    fn test_verify_sd_jwt_authorization_response(#[case] serialization: SdJwtSerialization) {
         // Set up an authorization request with two credential queries.
         let (_, _, _, auth_request) =
             setup_with_credential_requests(NormalizedCredentialRequests::new_mock_sd_jwt_from_slices(&[
@@ -2375,15 +2678,26 @@ mod tests {
         let vp_token = HashMap::from([
             (
                 "sd_jwt_0".try_into().unwrap(),
-                VerifiablePresentation::SdJwt(vec_nonempty![sd_jwt_presentation1.into_unverified()]),
+                VerifiablePresentation::SdJwt(vec_nonempty![sd_jwt_presentation1.into_unverified()], serialization),
             ),
             (
                 "sd_jwt_1".try_into().unwrap(),
-                VerifiablePresentation::SdJwt(vec_nonempty![sd_jwt_presentation2.into_unverified()]),
+                VerifiablePresentation::SdJwt(vec_nonempty![sd_jwt_presentation2.into_unverified()], serialization),
             ),
         ]);
         let auth_response = VpAuthorizationResponse::new(vp_token, auth_request.state.clone(), poa);
 
+        // Send the response through a JSON round trip, as it would be when transmitted to the verifier.
+        let auth_response_json = serde_json::to_value(&auth_response).unwrap();
+        let expect_object = serialization != SdJwtSerialization::Compact;
+        assert_eq!(auth_response_json["vp_token"]["sd_jwt_0"][0].is_object(), expect_object);
+
+        let auth_response: VpAuthorizationResponse = serde_json::from_value(auth_response_json).unwrap();
+        assert!(auth_response.vp_token.values().all(|presentation| matches!(
+            presentation,
+            VerifiablePresentation::SdJwt(_, received_serialization) if *received_serialization == serialization
+        )));
+
         let attestations = auth_response
             .verify(
                 &auth_request,
@@ -2394,6 +2708,8 @@ mod tests {
                 &RevocationVerifier::new_without_caching(Arc::new(StatusListClientStub::new(
                     ca.generate_issuer_status_list_mock().unwrap(),
                 ))),
+                None,
+                None,
                 false,
             )
             .now_or_never()
@@ -2453,6 +2769,110 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::multiple(true)]
+    #[case::single(false)]
+    fn test_verify_sd_jwt_authorization_response_multiple(#[case] multiple: bool) {
+        // Set up an authorization request with a single credential query, which may allow for multiple credentials.
+        let request = NormalizedCredentialRequest::new_mock_sd_jwt_pid_example();
+        let request = if multiple { request.with_multiple() } else { request };
+        let (_, _, _, auth_request) = setup_with_credential_requests(vec![request].try_into().unwrap());
+
+        // Setup both issuer and holder keys.
+        let ca = Ca::generate_issuer_mock_ca().unwrap();
+        let issuer_key_pair = ca.generate_issuer_mock().unwrap();
+
+        let key_ids = ["sd_jwt_key_1", "sd_jwt_key_2"];
+        let holder_keys = key_ids.map(|key_id| MockRemoteEcdsaKey::new_random(key_id.to_string()));
+        let unsigned_presentations = holder_keys
+            .iter()
+            .zip(key_ids)
+            .map(|(holder_key, key_id)| {
+                let holder_public_key = PublicKey::from(*holder_key.verifying_key());
+
+                let unsigned_presentation = SignedSdJwt::pid_example(&issuer_key_pair, &holder_public_key)
+                    .into_verified()
+                    .into_presentation_builder()
+                    .disclose(&vec_nonempty![ClaimPath::SelectByKey("bsn".to_string())])
+                    .unwrap()
+                    .disclose(&vec_nonempty![ClaimPath::SelectByKey("given_name".to_string())])
+                    .unwrap()
+                    .disclose(&vec_nonempty![ClaimPath::SelectByKey("family_name".to_string())])
+                    .unwrap()
+                    .finish();
+
+                (unsigned_presentation, key_id)
+            })
+            .collect_vec()
+            .try_into()
+            .unwrap();
+        let wscd = MockRemoteWscd::new(holder_keys.to_vec());
+
+        // Sign these into two `SdJwtPresentation`s and a PoA, then include both of them for the same query identifier.
+        let kb_jwt_builder = KeyBindingJwtBuilder::new(auth_request.client_id.to_string(), auth_request.nonce.clone());
+        let poa_input = JwtPoaInput::new(Some(auth_request.nonce.clone()), auth_request.client_id.to_string());
+        let (sd_jwt_presentations, poa) = UnsignedSdJwtPresentation::sign_multiple(
+            unsigned_presentations,
+            kb_jwt_builder,
+            &wscd,
+            poa_input,
+            &MockTimeGenerator::default(),
+        )
+        .now_or_never()
+        .unwrap()
+        .unwrap();
+
+        let vp_token = HashMap::from([(
+            "sd_jwt_pid_example".try_into().unwrap(),
+            VerifiablePresentation::SdJwt(
+                sd_jwt_presentations
+                    .into_iter()
+                    .map(|presentation| presentation.into_unverified())
+                    .collect_vec()
+                    .try_into()
+                    .unwrap(),
+                SdJwtSerialization::Compact,
+            ),
+        )]);
+        let auth_response = VpAuthorizationResponse::new(vp_token, auth_request.state.clone(), poa);
+
+        let result = auth_response
+            .verify(
+                &auth_request,
+                &[MOCK_WALLET_CLIENT_ID.to_string()],
+                &MockTimeGenerator::default(),
+                &TrustAnchors::from(&ca),
+                &ExtendingVctRetrieverStub,
+                &RevocationVerifier::new_without_caching(Arc::new(StatusListClientStub::new(
+                    ca.generate_issuer_status_list_mock().unwrap(),
+                ))),
+                None,
+                None,
+                false,
+            )
+            .now_or_never()
+            .unwrap();
+
+        if multiple {
+            let attestations = result.expect("VpAuthorizationResponse should be valid");
+            let disclosed_attestations = attestations.into_iter().exactly_one().unwrap().attestations;
+
+            assert_eq!(disclosed_attestations.len().get(), 2);
+            assert!(
+                disclosed_attestations
+                    .iter()
+                    .all(|attestation| attestation.attestation_type == PID_ATTESTATION_TYPE)
+            );
+        } else {
+            assert_matches!(
+                result,
+                Err(AuthResponseError::UnsatisfiedCredentialRequest(
+                    CredentialValidationError::MultipleCredentials(ids)
+                )) if ids == HashSet::from(["sd_jwt_pid_example".try_into().unwrap()])
+            );
+        }
+    }
+
     #[test]
     fn test_verify_mixed_authorization_response() {
         // Set up an authorization request with two credential queries, one for mdoc and one for SD_JWT.
@@ -2523,7 +2943,10 @@ mod tests {
             ),
             (
                 "sd_jwt_pid_example".try_into().unwrap(),
-                VerifiablePresentation::SdJwt(vec_nonempty![sd_jwt_presentation.into_unverified()]),
+                VerifiablePresentation::SdJwt(
+                    vec_nonempty![sd_jwt_presentation.into_unverified()],
+                    SdJwtSerialization::Compact,
+                ),
             ),
         ]);
 
@@ -2554,6 +2977,8 @@ mod tests {
                 &RevocationVerifier::new_without_caching(Arc::new(StatusListClientStub::new(
                     ca.generate_issuer_status_list_mock().unwrap(),
                 ))),
+                None,
+                None,
                 false,
             )
             .now_or_never()
@@ -2601,6 +3026,8 @@ mod tests {
                 &RevocationVerifier::new_without_caching(Arc::new(StatusListClientStub::new(
                     ca.generate_pid_issuer_status_list_mock().unwrap(),
                 ))),
+                None,
+                None,
                 false,
             )
             .now_or_never()
@@ -2625,6 +3052,8 @@ mod tests {
                 &RevocationVerifier::new_without_caching(Arc::new(StatusListClientStub::new(
                     ca.generate_pid_issuer_status_list_mock().unwrap(),
                 ))),
+                None,
+                None,
                 false,
             )
             .now_or_never()
@@ -2654,6 +3083,8 @@ mod tests {
                 &RevocationVerifier::new_without_caching(Arc::new(StatusListClientStub::new(
                     ca.generate_pid_issuer_status_list_mock().unwrap(),
                 ))),
+                None,
+                None,
                 false,
             )
             .now_or_never()
@@ -11,11 +11,16 @@ use derive_more::From;
 use derive_more::Into;
 use serde::Deserialize;
 use serde::Serialize;
+use tokio::sync::broadcast;
 use utils::generator::Generator;
 use utils::generator::TimeGenerator;
 
 use crate::token::AuthorizationCode;
 
+/// The number of session changes that a subscriber may lag behind before it misses changes,
+/// see [`SessionStore::subscribe()`].
+pub const SESSION_CHANGES_CAPACITY: usize = 1024;
+
 pub trait SessionDataType {
     const TYPE: &'static str;
 }
@@ -28,11 +33,23 @@ pub enum Progress {
 
 pub trait HasProgress {
     fn progress(&self) -> Progress;
+
+    /// When the session has a notification that still needs to be sent, the time at which the next attempt is due.
+    /// Sessions with a pending notification are not removed by [`SessionStore::cleanup()`].
+    fn notification_due(&self) -> Option<DateTime<Utc>> {
+        None
+    }
 }
 
 pub trait Expirable {
     fn is_expired(&self) -> bool;
     fn expire(&mut self);
+
+    /// The time at which an active session expires, regardless of its activity. If absent, the session is expired by
+    /// [`SessionStore::cleanup()`] after the [`SessionStoreTimeouts::expiration`] timeout of inactivity instead.
+    fn expires_at(&self) -> Option<DateTime<Utc>> {
+        None
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -61,7 +78,26 @@ where
 {
     async fn get(&self, token: &SessionToken) -> Result<Option<SessionState<T>>, SessionStoreError>;
     async fn write(&self, session: SessionState<T>, is_new: bool) -> Result<(), SessionStoreError>;
+    /// Update an existing session, but only if it has not been written to since it was read, i.e. if its `last_active`
+    /// timestamp is still equal to `last_active`. Returns whether the session was updated.
+    async fn write_if_unchanged(
+        &self,
+        session: SessionState<T>,
+        last_active: DateTime<Utc>,
+    ) -> Result<bool, SessionStoreError>;
     async fn cleanup(&self) -> Result<(), SessionStoreError>;
+    /// Claims and returns all sessions of which the next notification attempt is due, as reported by
+    /// [`HasProgress::notification_due()`]. A claimed session is not returned again by this method for the duration of
+    /// `claim_duration`, or until the session is written to the store. This allows multiple instances sharing the same
+    /// store to deliver notifications without sending them more than once.
+    async fn claim_pending_notifications(
+        &self,
+        claim_duration: Duration,
+    ) -> Result<Vec<SessionState<T>>, SessionStoreError>;
+    /// Subscribe to changes of the sessions in this store. The returned receiver yields the token of every session
+    /// that is written to the store or expired by [`SessionStore::cleanup()`] after this method returns. Note that
+    /// a subscriber that lags behind more than [`SESSION_CHANGES_CAPACITY`] changes misses the oldest ones.
+    async fn subscribe(&self) -> Result<broadcast::Receiver<SessionToken>, SessionStoreError>;
 }
 
 /// Different timeout values that should be used by the [`SessionStore::cleanup()`] implementation.
@@ -81,6 +117,9 @@ pub struct MemorySessionStore<T, G = TimeGenerator> {
     time: G,
     // Store the session state and expired boolean as the value
     sessions: DashMap<SessionToken, SessionState<T>>,
+    // The time until which the pending notification of a session is claimed
+    notification_claims: DashMap<SessionToken, DateTime<Utc>>,
+    changes: broadcast::Sender<SessionToken>,
 }
 
 impl<T> SessionState<T> {
@@ -109,6 +148,8 @@ impl<T, G> MemorySessionStore<T, G> {
             timeouts,
             time,
             sessions: DashMap::new(),
+            notification_claims: DashMap::new(),
+            changes: broadcast::channel(SESSION_CHANGES_CAPACITY).0,
         }
     }
 }
@@ -146,11 +187,41 @@ where
             return Err(SessionStoreError::DuplicateToken(session.token));
         }
 
+        let token = session.token.clone();
         entry.insert(session);
+        self.notification_claims.remove(&token);
+
+        // Sending only fails if there are no subscribers, which is fine.
+        let _ = self.changes.send(token);
 
         Ok(())
     }
 
+    async fn write_if_unchanged(
+        &self,
+        session: SessionState<T>,
+        last_active: DateTime<Utc>,
+    ) -> Result<bool, SessionStoreError> {
+        // Holding a reference into the `DashMap` locks the session, so that it cannot change after it is compared.
+        let Some(mut current) = self
+            .sessions
+            .get_mut(&session.token)
+            .filter(|current| current.last_active == last_active)
+        else {
+            return Ok(false);
+        };
+
+        let token = session.token.clone();
+        *current = session;
+        drop(current);
+        self.notification_claims.remove(&token);
+
+        // Sending only fails if there are no subscribers, which is fine.
+        let _ = self.changes.send(token);
+
+        Ok(true)
+    }
+
     async fn cleanup(&self) -> Result<(), SessionStoreError> {
         let now = self.time.generate();
         let succeeded_cutoff = now - self.timeouts.successful_deletion;
@@ -158,6 +229,11 @@ where
         let expiry_cutoff = now - self.timeouts.expiration;
 
         self.sessions.retain(|_, session| {
+            // Keep all sessions that still have a notification to send.
+            if session.data.notification_due().is_some() {
+                return true;
+            }
+
             match (session.data.progress(), session.data.is_expired()) {
                 // Remove all succeeded sessions that are older than the "successful_deletion" timeout.
                 (Progress::Finished { has_succeeded }, false) if has_succeeded => {
@@ -168,21 +244,57 @@ where
                 _ => true,
             }
         });
+        self.notification_claims
+            .retain(|token, _| self.sessions.contains_key(token));
 
-        // For all active sessions that are older than the "expiration" timeout,
+        // For all active sessions that are older than the "expiration" timeout, or that are past their own expiry time,
         // update the last active time and set them to expired.
         self.sessions.iter_mut().for_each(|mut session| {
-            if !session.data.is_expired()
-                && matches!(session.data.progress(), Progress::Active)
-                && session.last_active < expiry_cutoff
-            {
+            let is_past_expiry = match session.data.expires_at() {
+                Some(expires_at) => expires_at < now,
+                None => session.last_active < expiry_cutoff,
+            };
+
+            if !session.data.is_expired() && matches!(session.data.progress(), Progress::Active) && is_past_expiry {
                 session.last_active = now;
                 session.data.expire();
+
+                let _ = self.changes.send(session.token.clone());
             }
         });
 
         Ok(())
     }
+
+    async fn claim_pending_notifications(
+        &self,
+        claim_duration: Duration,
+    ) -> Result<Vec<SessionState<T>>, SessionStoreError> {
+        let now = self.time.generate();
+
+        let sessions = self
+            .sessions
+            .iter()
+            .filter(|session| session.data.notification_due().is_some_and(|due| due <= now))
+            .filter(|session| {
+                // Holding the `Entry` prevents a race condition between checking and inserting the claim.
+                match self.notification_claims.entry(session.token.clone()) {
+                    Entry::Occupied(claim) if *claim.get() > now => false,
+                    entry => {
+                        entry.insert(now + claim_duration);
+                        true
+                    }
+                }
+            })
+            .map(|session| session.clone())
+            .collect();
+
+        Ok(sessions)
+    }
+
+    async fn subscribe(&self) -> Result<broadcast::Receiver<SessionToken>, SessionStoreError> {
+        Ok(self.changes.subscribe())
+    }
 }
 
 /// Identifies a session in a URL, as passed from the issuer/RP to the holder using the `url` field of
@@ -228,6 +340,11 @@ pub mod test {
         fn new_random() -> Self;
     }
 
+    // Helper trait that signifies that a type has a constructor for data with a pending notification.
+    pub trait NotificationData {
+        fn new_with_notification_due(notification_due: DateTime<Utc>) -> Self;
+    }
+
     /// Test reading and writing to a `SessionStore` implementation.
     pub async fn test_session_store_get_write<T>(session_store: &impl SessionStore<T>)
     where
@@ -299,6 +416,84 @@ pub mod test {
         );
     }
 
+    /// Test that a `SessionStore` implementation only updates a session conditionally if it has not changed.
+    pub async fn test_session_store_write_if_unchanged<T>(session_store: &impl SessionStore<T>)
+    where
+        T: Debug + Clone + HasProgress + Expirable + RandomData + Eq,
+    {
+        let token = SessionToken::new_random();
+
+        // A session that is not present in the store cannot be updated.
+        let session = SessionState::new(token.clone(), T::new_random());
+        assert!(
+            !session_store
+                .write_if_unchanged(session.clone(), session.last_active)
+                .await
+                .expect("should succeed")
+        );
+
+        session_store
+            .write(session.clone(), true)
+            .await
+            .expect("should succeed");
+        let session = session_store
+            .get(&token)
+            .await
+            .expect("should succeed")
+            .expect("should return session");
+
+        let updated_session = SessionState {
+            data: T::new_random(),
+            token: token.clone(),
+            last_active: session.last_active + Duration::from_secs(1),
+        };
+
+        // The update succeeds if the session is unchanged, after which the same update cannot be applied again.
+        for expected in [true, false] {
+            let is_updated = session_store
+                .write_if_unchanged(updated_session.clone(), session.last_active)
+                .await
+                .expect("should succeed");
+
+            assert_eq!(is_updated, expected);
+        }
+
+        let session_read = session_store
+            .get(&token)
+            .await
+            .expect("should succeed")
+            .expect("should return session");
+
+        assert_eq!(session_read.data, updated_session.data);
+    }
+
+    /// Test that a `SessionStore` implementation notifies subscribers of written sessions.
+    pub async fn test_session_store_subscribe<T>(session_store: &impl SessionStore<T>)
+    where
+        T: Debug + Clone + HasProgress + Expirable + RandomData,
+    {
+        let mut changes = session_store.subscribe().await.expect("should succeed");
+
+        let token = SessionToken::new_random();
+        let session = SessionState::new(token.clone(), T::new_random());
+
+        // Both writing a new session and updating it should notify the subscriber.
+        session_store
+            .write(session.clone(), true)
+            .await
+            .expect("should succeed");
+        session_store.write(session, false).await.expect("should succeed");
+
+        for _ in 0..2 {
+            let changed_token = time::timeout(Duration::from_secs(5), changes.recv())
+                .await
+                .expect("should receive change before timeout")
+                .expect("should receive change");
+
+            assert_eq!(changed_token, token);
+        }
+    }
+
     pub async fn test_session_store_cleanup<T>(
         session_store: &impl SessionStore<T>,
         mock_time: &RwLock<DateTime<Utc>>,
@@ -434,6 +629,61 @@ pub mod test {
         assert!(session.is_none());
     }
 
+    /// Test that a `SessionStore` implementation returns a pending notification only once while it is claimed.
+    pub async fn test_session_store_claim_pending_notifications<T>(
+        session_store: &impl SessionStore<T>,
+        mock_time: &RwLock<DateTime<Utc>>,
+    ) where
+        T: HasProgress + Expirable + NotificationData,
+    {
+        let claim_duration = Duration::from_secs(60);
+        let now = *mock_time.read();
+
+        let token = SessionToken::new_random();
+        let session = SessionState {
+            data: T::new_with_notification_due(now),
+            token: token.clone(),
+            last_active: now,
+        };
+        session_store.write(session, true).await.unwrap();
+
+        // A session of which the notification is not yet due should never be claimed.
+        let later_session = SessionState {
+            data: T::new_with_notification_due(now + Duration::from_secs(60 * 60)),
+            token: SessionToken::new_random(),
+            last_active: now,
+        };
+        session_store.write(later_session, true).await.unwrap();
+
+        let claim = async || {
+            session_store
+                .claim_pending_notifications(claim_duration)
+                .await
+                .expect("should succeed")
+        };
+
+        let claimed = claim().await;
+        assert_eq!(claimed.len(), 1);
+        assert_eq!(claimed[0].token, token);
+
+        // While the session is claimed, it should not be returned again.
+        assert!(claim().await.is_empty());
+
+        // Once the claim has expired, the session can be claimed again.
+        *mock_time.write() = now + claim_duration + Duration::from_millis(1);
+
+        let session = claim().await.into_iter().next().expect("should return session");
+        assert_eq!(session.token, token);
+        assert!(claim().await.is_empty());
+
+        // Writing the session releases the claim, as the notification is still due according to its data.
+        session_store.write(session, false).await.unwrap();
+
+        let claimed = claim().await;
+        assert_eq!(claimed.len(), 1);
+        assert_eq!(claimed[0].token, token);
+    }
+
     pub async fn test_memory_store_with_cleanup_task<T, G>(
         session_store: Arc<MemorySessionStore<T, G>>,
         token: SessionToken,
@@ -479,6 +729,7 @@ pub mod test {
 
 #[cfg(test)]
 mod tests {
+    use self::test::NotificationData;
     use self::test::RandomData;
     use self::test::memory_session_store_with_mock_time;
     use super::*;
@@ -488,6 +739,8 @@ mod tests {
     struct MockSessionData {
         progress: Progress,
         is_expired: bool,
+        expires_at: Option<DateTime<Utc>>,
+        notification_due: Option<DateTime<Utc>>,
         data: Vec<u8>,
     }
 
@@ -496,6 +749,8 @@ mod tests {
             Self {
                 progress,
                 is_expired: false,
+                expires_at: None,
+                notification_due: None,
                 data: crypto::utils::random_bytes(32),
             }
         }
@@ -511,6 +766,10 @@ mod tests {
         fn progress(&self) -> Progress {
             self.progress
         }
+
+        fn notification_due(&self) -> Option<DateTime<Utc>> {
+            self.notification_due
+        }
     }
 
     impl Expirable for MockSessionData {
@@ -521,6 +780,10 @@ mod tests {
         fn expire(&mut self) {
             self.is_expired = true;
         }
+
+        fn expires_at(&self) -> Option<DateTime<Utc>> {
+            self.expires_at
+        }
     }
 
     impl RandomData for MockSessionData {
@@ -529,12 +792,53 @@ mod tests {
         }
     }
 
+    impl NotificationData for MockSessionData {
+        fn new_with_notification_due(notification_due: DateTime<Utc>) -> Self {
+            Self {
+                notification_due: Some(notification_due),
+                ..Self::new(Progress::Finished { has_succeeded: true })
+            }
+        }
+    }
+
     #[tokio::test]
     async fn test_memory_session_store_get_write() {
         let session_store = MemorySessionStore::<MockSessionData, _>::default();
         test::test_session_store_get_write(&session_store).await;
     }
 
+    #[tokio::test]
+    async fn test_memory_session_store_write_if_unchanged() {
+        let session_store = MemorySessionStore::<MockSessionData, _>::default();
+        test::test_session_store_write_if_unchanged(&session_store).await;
+    }
+
+    #[tokio::test]
+    async fn test_memory_session_store_subscribe() {
+        let session_store = MemorySessionStore::<MockSessionData, _>::default();
+        test::test_session_store_subscribe(&session_store).await;
+    }
+
+    #[tokio::test]
+    async fn test_memory_session_store_subscribe_expiration() {
+        let (session_store, mock_time) = memory_session_store_with_mock_time::<MockSessionData>();
+
+        let token = SessionToken::new_random();
+        session_store
+            .write(SessionState::new(token.clone(), MockSessionData::new_random()), true)
+            .await
+            .unwrap();
+
+        let mut changes = session_store.subscribe().await.unwrap();
+
+        // Expiring the session during cleanup should notify the subscriber.
+        *mock_time.write() = Utc::now() + session_store.timeouts.expiration + Duration::from_secs(1);
+        session_store.cleanup().await.unwrap();
+
+        assert_eq!(changes.try_recv().unwrap(), token);
+        assert!(changes.try_recv().is_err());
+    }
+
     #[tokio::test]
     async fn test_memory_session_store_cleanup_expiration() {
         let (session_store, mock_time) = memory_session_store_with_mock_time::<MockSessionData>();
@@ -561,4 +865,40 @@ mod tests {
         test::test_session_store_cleanup_failed_deletion(&session_store, &session_store.timeouts, mock_time.as_ref())
             .await;
     }
+
+    #[tokio::test]
+    async fn test_memory_session_store_claim_pending_notifications() {
+        let (session_store, mock_time) = memory_session_store_with_mock_time::<MockSessionData>();
+
+        test::test_session_store_claim_pending_notifications(&session_store, mock_time.as_ref()).await;
+    }
+
+    #[tokio::test]
+    async fn test_memory_session_store_cleanup_expires_at() {
+        let (session_store, mock_time) = memory_session_store_with_mock_time::<MockSessionData>();
+
+        let now = Utc::now();
+        let expires_at = now + Duration::from_secs(24 * 60 * 60);
+        let token = SessionToken::new_random();
+        let session = SessionState::new(
+            token.clone(),
+            MockSessionData {
+                expires_at: Some(expires_at),
+                ..MockSessionData::new_random()
+            },
+        );
+        session_store.write(session, true).await.unwrap();
+
+        // A session with its own expiry time is not expired after the expiration timeout of inactivity...
+        *mock_time.write() = now + session_store.timeouts.expiration + Duration::from_secs(1);
+        session_store.cleanup().await.unwrap();
+
+        assert!(!session_store.get(&token).await.unwrap().unwrap().data.is_expired());
+
+        // ...but only once its expiry time has passed.
+        *mock_time.write() = expires_at + Duration::from_secs(1);
+        session_store.cleanup().await.unwrap();
+
+        assert!(session_store.get(&token).await.unwrap().unwrap().data.is_expired());
+    }
 }
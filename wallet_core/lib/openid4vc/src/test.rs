@@ -3,6 +3,7 @@ use std::num::NonZeroU8;
 use std::num::NonZeroUsize;
 use std::sync::Arc;
 
+use async_trait::async_trait;
 use attestation_data::attributes::Attribute;
 use attestation_data::attributes::AttributeValue;
 use attestation_data::auth::issuer_auth::IssuerRegistration;
@@ -43,8 +44,12 @@ use crate::credential_configurations::CredentialConfigurationParameters;
 use crate::errors::AuthorizationErrorCode;
 use crate::errors::ErrorWithCode;
 use crate::issuable_document::IssuableDocument;
+use crate::issuer::CredentialResponseEncryptionPolicy;
+use crate::issuer::IssuableDocumentSource;
 use crate::issuer::IssuanceData;
 use crate::issuer::Issuer;
+use crate::issuer::MemoryIssuedCredentialStore;
+use crate::issuer::RefreshableDocument;
 use crate::issuer_identifier::IssuerIdentifier;
 use crate::mock::MOCK_WALLET_CLIENT_ID;
 use crate::nonce::memory_store::MemoryNonceStore;
@@ -165,6 +170,24 @@ impl AuthorizationCodeFlow for StaticAuthorizingFlow {
     }
 }
 
+/// Test-only implementation of [`IssuableDocumentSource`], which returns a document of the requested kind containing
+/// [`MOCK_ATTRS`], referring back to the same source.
+pub struct MockIssuableDocumentSource;
+
+#[async_trait]
+impl IssuableDocumentSource for MockIssuableDocumentSource {
+    async fn fetch_issuable_document(
+        &self,
+        document: &RefreshableDocument,
+    ) -> Result<IssuableDocument, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let issuable_document =
+            mock_issuable_document_with_attrs(&document.credential_kind.attestation_type, &MOCK_ATTRS)
+                .with_source_reference(document.reference.clone());
+
+        Ok(issuable_document)
+    }
+}
+
 /// Create a mock [`Issuer`] based on an [`IssuerIdentifier`] with a shared session store and some default credential
 /// configurations.
 pub fn setup_mock_issuer<G>(
@@ -230,7 +253,7 @@ where
             let status_list_uri_path = config_id.replace(':', "-");
             status_list
                 .expect_obtain_status_claims()
-                .returning(move |_, _, copies| {
+                .returning(move |_, _, _, copies| {
                     let uri = format!("https://tsl.example.com/{status_list_uri_path}")
                         .parse()
                         .unwrap();
@@ -266,11 +289,14 @@ where
         issuer_identifier,
         metadata_keypair,
         NonZeroU8::new(4).unwrap(),
+        CredentialResponseEncryptionPolicy::Optional,
         HashSet::from([MOCK_WALLET_CLIENT_ID.to_string()]),
         config_params,
         trust_anchors.clone(),
         sessions,
         MemoryNonceStore::new(),
+        Some(Box::new(MemoryIssuedCredentialStore::new())),
+        None,
     )
     .unwrap();
 
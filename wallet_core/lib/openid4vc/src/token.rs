@@ -56,6 +56,25 @@ impl AsRef<str> for AccessToken {
     }
 }
 
+/// A refresh token as defined by RFC 6749, which the wallet may use to have the credentials it received reissued
+/// without further user authorization.
+///
+/// See: <https://www.rfc-editor.org/rfc/rfc6749.html#section-1.5>.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, From)]
+pub struct RefreshToken(String);
+
+impl AsRef<str> for RefreshToken {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl RefreshToken {
+    pub(crate) fn new_random() -> Self {
+        Self(random_string(32))
+    }
+}
+
 impl From<AuthorizationCode> for SessionToken {
     fn from(value: AuthorizationCode) -> Self {
         SessionToken::from(value.0)
@@ -138,8 +157,26 @@ impl TokenRequest {
     }
 
     pub fn new_pre_authorized(pre_authorized_code: AuthorizationCode) -> Self {
+        Self::new_pre_authorized_with_tx_code(pre_authorized_code, None)
+    }
+
+    pub fn new_pre_authorized_with_tx_code(pre_authorized_code: AuthorizationCode, tx_code: Option<String>) -> Self {
         Self {
-            grant_type: TokenRequestGrantType::PreAuthorizedCode { pre_authorized_code },
+            grant_type: TokenRequestGrantType::PreAuthorizedCode {
+                pre_authorized_code,
+                tx_code,
+            },
+            client_id: None, // Not required as our implementation sends a WIA which contains the client_id in the sub
+            redirect_uri: None,
+            scope: None,
+            code_verifier: None,
+            authorization_details: None,
+        }
+    }
+
+    pub fn new_refresh_token(refresh_token: RefreshToken) -> Self {
+        Self {
+            grant_type: TokenRequestGrantType::RefreshToken { refresh_token },
             client_id: None, // Not required as our implementation sends a WIA which contains the client_id in the sub
             redirect_uri: None,
             scope: None,
@@ -149,12 +186,14 @@ impl TokenRequest {
     }
 
     /// Retrieve either the authorization code or the pre-authorized code, depending on the authorization grant type.
-    pub fn code(&self) -> &AuthorizationCode {
+    /// This returns `None` for the Refresh Token grant type, as that does not refer to an existing session.
+    pub fn code(&self) -> Option<&AuthorizationCode> {
         match &self.grant_type {
-            TokenRequestGrantType::AuthorizationCode { code } => code,
+            TokenRequestGrantType::AuthorizationCode { code } => Some(code),
             TokenRequestGrantType::PreAuthorizedCode {
                 pre_authorized_code, ..
-            } => pre_authorized_code,
+            } => Some(pre_authorized_code),
+            TokenRequestGrantType::RefreshToken { .. } => None,
         }
     }
 }
@@ -170,12 +209,13 @@ pub enum TokenRequestGrantType {
     PreAuthorizedCode {
         #[serde(rename = "pre-authorized_code")]
         pre_authorized_code: AuthorizationCode,
-        // According to OpenID4VCI, a Token Request containing a pre-authorized code also must contain a `tx_code` if
-        // the inciting Credential Offer contains a `tx_code` field itself. However, as the wallet does not support the
-        // concept of a transaction code, we do not include it in the data structure here.
-        //
-        // See: <https://openid.net/specs/openid-4-verifiable-credential-issuance-1_0.html#section-6.1-3.2>
+        /// The Transaction Code, which MUST be present if the Credential Offer contained a `tx_code` field.
+        ///
+        /// See: <https://openid.net/specs/openid-4-verifiable-credential-issuance-1_0.html#section-6.1-3.2>
+        tx_code: Option<String>,
     },
+    #[serde(rename = "refresh_token")]
+    RefreshToken { refresh_token: RefreshToken },
 }
 
 /// See
@@ -187,7 +227,7 @@ pub enum TokenRequestGrantType {
 pub struct TokenResponse {
     pub access_token: AccessToken,
     pub token_type: TokenType,
-    pub refresh_token: Option<String>,
+    pub refresh_token: Option<RefreshToken>,
 
     /// Section 3.3 of RFC 6749 states that if the issued access token scope is different than the one requested by the
     /// client, the server MUST include a scope response parameter to inform the client of the actual scope granted.
@@ -314,6 +354,7 @@ mod tests {
             serde_qs::to_string(&TokenRequest {
                 grant_type: TokenRequestGrantType::PreAuthorizedCode {
                     pre_authorized_code: "123".to_string().into(),
+                    tx_code: None,
                 },
                 client_id: Some("myclient".to_string()),
                 redirect_uri: Some("https://example.com".parse().unwrap()),
@@ -330,6 +371,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn token_request_refresh_token_serialization() {
+        let token_request = TokenRequest::new_refresh_token("tGzv3JOkF0XG5Qx2TlKWIA".to_string().into());
+        let serialized = serde_qs::to_string(&token_request).unwrap();
+
+        // Source: <https://www.rfc-editor.org/rfc/rfc6749.html#section-6>
+        assert_eq!(
+            serialized,
+            "grant_type=refresh_token&refresh_token=tGzv3JOkF0XG5Qx2TlKWIA"
+        );
+
+        let token_request = serde_qs::from_str::<TokenRequest>(&serialized).unwrap();
+
+        let TokenRequestGrantType::RefreshToken { refresh_token } = &token_request.grant_type else {
+            panic!("grant type should be refresh_token");
+        };
+        assert_eq!(refresh_token.as_ref(), "tGzv3JOkF0XG5Qx2TlKWIA");
+        assert!(token_request.code().is_none());
+    }
+
     #[test]
     fn token_response_serialization() {
         let token_response = TokenResponse {
@@ -397,11 +458,16 @@ mod tests {
         let token_request =
             serde_qs::from_str::<TokenRequest>(example).expect("deserializing TokenRequest should succeed");
 
-        let TokenRequestGrantType::PreAuthorizedCode { pre_authorized_code } = &token_request.grant_type else {
+        let TokenRequestGrantType::PreAuthorizedCode {
+            pre_authorized_code,
+            tx_code,
+        } = &token_request.grant_type
+        else {
             panic!("grant type should be pre-authorized");
         };
 
         assert_eq!(pre_authorized_code.as_ref(), "SplxlOBeZQQYbYS6WxSbIA");
+        assert_eq!(tx_code.as_deref(), Some("493536"));
 
         assert!(token_request.client_id.is_none());
         assert!(token_request.redirect_uri.is_none());
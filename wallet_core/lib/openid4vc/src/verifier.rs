@@ -16,6 +16,9 @@ use crypto::keys::EcdsaKey;
 use crypto::server_keys::KeyPair;
 use crypto::trust_anchor::TrustAnchors;
 use crypto::utils::random_string;
+use crypto::x509::BorrowingCertificate;
+use crypto::x509::CertificateError;
+use crypto::x509::revocation::RevocationChecker;
 use dcql::Query;
 use dcql::disclosure::ExtendingVctRetriever;
 use dcql::normalized::NormalizedCredentialRequests;
@@ -31,6 +34,8 @@ use jwt::error::JwtSignError;
 use jwt::headers::HeaderWithX5c;
 use jwt::nonce::Nonce;
 use ring::hmac;
+use sd_jwt::jwt_vc_issuer::HttpJwtVcIssuerMetadataClient;
+use sd_jwt::jwt_vc_issuer::JwtVcIssuerKeyResolver;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_with::DeserializeFromStr;
@@ -40,6 +45,7 @@ use serde_with::serde_as;
 use serde_with::skip_serializing_none;
 use token_status_list::verification::client::StatusListClient;
 use token_status_list::verification::verifier::RevocationVerifier;
+use tokio::sync::broadcast;
 use tokio::task::AbortHandle;
 use tracing::debug;
 use tracing::info;
@@ -73,6 +79,13 @@ use crate::server_state::SessionState;
 use crate::server_state::SessionStore;
 use crate::server_state::SessionStoreError;
 use crate::server_state::SessionToken;
+use crate::webhook::WEBHOOK_CLAIM_DURATION;
+use crate::webhook::WEBHOOK_DELIVERY_INTERVAL;
+use crate::webhook::Webhook;
+use crate::webhook::WebhookClient;
+use crate::webhook::WebhookDelivery;
+use crate::webhook::WebhookNotification;
+use crate::webhook::WebhookSessionStatus;
 
 /// The cleanup task that removes stale sessions runs every so often.
 const CLEANUP_INTERVAL: Duration = Duration::from_secs(120);
@@ -229,6 +242,9 @@ pub struct WaitingForResponse {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Done {
     session_result: SessionResult,
+    /// The webhook notification about this session, if the use case of the session has a webhook configured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    webhook: Option<WebhookDelivery>,
 }
 
 /// The outcome of a session: the disclosed attributes if they have been successfully received and verified.
@@ -301,6 +317,15 @@ impl HasProgress for DisclosureData {
             },
         }
     }
+
+    fn notification_due(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Done(Done {
+                webhook: Some(webhook), ..
+            }) => webhook.next_attempt(),
+            _ => None,
+        }
+    }
 }
 
 impl Expirable for DisclosureData {
@@ -308,7 +333,8 @@ impl Expirable for DisclosureData {
         matches!(
             self,
             Self::Done(Done {
-                session_result: SessionResult::Expired
+                session_result: SessionResult::Expired,
+                ..
             })
         )
     }
@@ -316,6 +342,7 @@ impl Expirable for DisclosureData {
     fn expire(&mut self) {
         *self = Self::Done(Done {
             session_result: SessionResult::Expired,
+            webhook: None,
         });
     }
 }
@@ -394,7 +421,7 @@ impl From<Session<Done>> for SessionState<DisclosureData> {
 /// `verification_server`. As this endpoint is meant to be public, it contains no other data than the (flattened) state,
 /// plus a potential universal link that the wallet app can use to start disclosure.
 #[skip_serializing_none]
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[cfg_attr(
     all(test, feature = "ts_rs"),
     derive(ts_rs::TS),
@@ -433,7 +460,7 @@ impl From<DisclosureData> for SessionStatus {
         match value {
             DisclosureData::Created(_) => Self::Created,
             DisclosureData::WaitingForResponse(_) => Self::WaitingForResponse,
-            DisclosureData::Done(Done { session_result }) => match session_result {
+            DisclosureData::Done(Done { session_result, .. }) => match session_result {
                 SessionResult::Done { .. } => Self::Done,
                 SessionResult::Failed { error } => Self::Failed { error },
                 SessionResult::Cancelled => Self::Cancelled,
@@ -477,6 +504,26 @@ pub enum SessionTypeReturnUrl {
     Both,
 }
 
+/// The `client_id` prefix that a use case uses to identify itself in its Authorization Requests.
+/// When using `x509_san_dns`, the first SAN DNS name of the use case certificate becomes the `client_id`,
+/// which the wallet will also require to be the host of the `response_uri`.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UseCaseClientIdScheme {
+    #[default]
+    X509Hash,
+    X509SanDns,
+}
+
+impl UseCaseClientIdScheme {
+    pub fn client_id_for_certificate(&self, certificate: &BorrowingCertificate) -> Result<ClientId, CertificateError> {
+        match self {
+            Self::X509Hash => Ok(ClientId::x509_hash_from_certificate(certificate)),
+            Self::X509SanDns => ClientId::x509_san_dns_from_certificate(certificate),
+        }
+    }
+}
+
 /// Data that is shared between [`UseCase`] impls.
 #[derive(Debug)]
 pub struct UseCaseData<K> {
@@ -495,6 +542,20 @@ impl<K> UseCaseData<K> {
             session_type_return_url,
         }
     }
+
+    pub fn try_new_with_client_id_scheme(
+        key_pair: KeyPair<K>,
+        session_type_return_url: SessionTypeReturnUrl,
+        client_id_scheme: UseCaseClientIdScheme,
+    ) -> Result<Self, CertificateError> {
+        let client_id = client_id_scheme.client_id_for_certificate(key_pair.certificate())?;
+
+        Ok(Self {
+            key_pair,
+            client_id,
+            session_type_return_url,
+        })
+    }
 }
 
 pub trait UseCase {
@@ -512,6 +573,10 @@ pub trait UseCase {
     fn disclosure_base_deep_link(&self) -> Option<&BaseUrl> {
         None
     }
+
+    fn webhook(&self) -> Option<&Webhook> {
+        None
+    }
 }
 
 #[trait_variant::make(Send)]
@@ -544,6 +609,7 @@ pub struct RpInitiatedUseCase<K> {
     return_url_template: Option<ReturnUrlTemplate>,
     disclosure_base_deep_link: Option<BaseUrl>,
     accept_undetermined_revocation_status: bool,
+    webhook: Option<Webhook>,
 }
 
 #[derive(Debug, Constructor)]
@@ -560,6 +626,7 @@ impl<K> RpInitiatedUseCase<K> {
         return_url_template: Option<ReturnUrlTemplate>,
         disclosure_base_deep_link: Option<BaseUrl>,
         accept_undetermined_revocation_status: bool,
+        webhook: Option<Webhook>,
     ) -> Self {
         Self {
             data,
@@ -567,6 +634,7 @@ impl<K> RpInitiatedUseCase<K> {
             return_url_template,
             disclosure_base_deep_link,
             accept_undetermined_revocation_status,
+            webhook,
         }
     }
 }
@@ -582,6 +650,10 @@ impl<K: EcdsaKeySend> UseCase for RpInitiatedUseCase<K> {
         self.disclosure_base_deep_link.as_ref()
     }
 
+    fn webhook(&self) -> Option<&Webhook> {
+        self.webhook.as_ref()
+    }
+
     fn new_session(
         &self,
         id: String,
@@ -850,18 +922,25 @@ pub struct Verifier<S, US, C> {
     use_cases: US,
     sessions: Arc<S>,
     cleanup_task: AbortHandle,
+    webhook_task: Option<AbortHandle>,
     trust_anchors: TrustAnchors,
     #[debug(skip)]
     result_handler: Option<Box<dyn DisclosureResultHandler + Send + Sync>>,
     accepted_wallet_client_ids: Vec<String>,
     extending_vct_values_store: HashMap<String, VecNonEmpty<String>>,
     revocation_verifier: RevocationVerifier<C>,
+    #[debug(skip)]
+    certificate_revocation_checker: Option<RevocationChecker>,
+    jwt_vc_issuer_key_resolver: Option<JwtVcIssuerKeyResolver<HttpJwtVcIssuerMetadataClient>>,
 }
 
 impl<S, K, C> Drop for Verifier<S, K, C> {
     fn drop(&mut self) {
-        // Stop the task at the next .await
+        // Stop the tasks at the next .await
         self.cleanup_task.abort();
+        if let Some(webhook_task) = &self.webhook_task {
+            webhook_task.abort();
+        }
     }
 }
 
@@ -909,13 +988,62 @@ where
         Self {
             use_cases,
             cleanup_task,
+            webhook_task: None,
             sessions,
             trust_anchors,
             result_handler,
             accepted_wallet_client_ids,
             extending_vct_values_store,
             revocation_verifier,
+            certificate_revocation_checker: None,
+            jwt_vc_issuer_key_resolver: None,
+        }
+    }
+
+    /// Enable checking the revocation status of the certificates of the issuers of disclosed credentials using their
+    /// CRLs and/or OCSP responders, as configured in the policy of `checker`.
+    pub fn with_certificate_revocation_checker(mut self, checker: RevocationChecker) -> Self {
+        self.certificate_revocation_checker = Some(checker);
+        self
+    }
+
+    /// Enable verification of SD-JWTs that do not contain an `x5c` header, by resolving the issuer key from the JWT VC
+    /// Issuer Metadata of the issuer. Only the issuers that are allowed by the policy of `key_resolver` are accepted,
+    /// while SD-JWTs that do contain an `x5c` header are still verified against the trust anchors.
+    pub fn with_jwt_vc_issuer_key_resolver(
+        mut self,
+        key_resolver: JwtVcIssuerKeyResolver<HttpJwtVcIssuerMetadataClient>,
+    ) -> Self {
+        self.jwt_vc_issuer_key_resolver = Some(key_resolver);
+        self
+    }
+
+    /// Enable webhook notifications for use cases that have a webhook configured, using `client` to deliver them.
+    /// This starts a background task that periodically delivers the notifications that are due, retrying failed
+    /// deliveries. As the delivery state is part of the session, pending deliveries survive a restart of the verifier.
+    pub fn with_webhook_client(mut self, client: Arc<dyn WebhookClient + Send + Sync>) -> Self
+    where
+        S: Sync + 'static,
+    {
+        let task_sessions = Arc::clone(&self.sessions);
+        let webhook_task = start_recurring_task(WEBHOOK_DELIVERY_INTERVAL, move || {
+            let task_sessions = Arc::clone(&task_sessions);
+            let task_client = Arc::clone(&client);
+
+            async move {
+                if let Err(error) =
+                    deliver_pending_webhooks(task_sessions.as_ref(), task_client.as_ref(), &TimeGenerator).await
+                {
+                    warn!("error during webhook delivery: {error}");
+                }
+            }
+        });
+
+        if let Some(previous_task) = self.webhook_task.replace(webhook_task) {
+            previous_task.abort();
         }
+
+        self
     }
 
     /// Start a new disclosure session. Returns a [`SessionToken`] that can be used to retrieve the
@@ -962,6 +1090,7 @@ where
         let session_type = url_params.session_type;
         let session: Session<Created> = self.use_cases.session(session_id, url_params).await?;
         let session_token = &session.state.token;
+        let usecase_id = session.state().usecase_id.clone();
 
         let response_uri = response_uri_base.join_base_url(&format!("/{session_token}/response_uri"));
 
@@ -978,6 +1107,7 @@ where
             ),
             Err((err, next)) => {
                 let redirect_uri = err.redirect_uri.clone();
+                let next = self.with_webhook_delivery(&usecase_id, next).await;
                 (Err(err), redirect_uri, next.into())
             }
         };
@@ -999,6 +1129,7 @@ where
         let session: Session<WaitingForResponse> = session_in_state(self.sessions.as_ref(), session_token)
             .await
             .map_err(PostAuthResponseError::Session)?;
+        let usecase_id = session.state().usecase_id.clone();
 
         let (result, next) = session
             .process_authorization_response(
@@ -1009,8 +1140,11 @@ where
                 self.result_handler.as_deref(),
                 self,
                 &self.revocation_verifier,
+                self.certificate_revocation_checker.as_ref(),
+                self.jwt_vc_issuer_key_resolver.as_ref(),
             )
             .await;
+        let next = self.with_webhook_delivery(&usecase_id, next).await;
 
         self.sessions.write(next.into(), false).await.map_err(|err| {
             WithRedirectUri::new(
@@ -1059,38 +1193,56 @@ where
             DisclosureData::WaitingForResponse(_) => StatusResponse::WaitingForResponse,
             DisclosureData::Done(Done {
                 session_result: SessionResult::Done { .. },
+                ..
             }) => StatusResponse::Done,
             DisclosureData::Done(Done {
                 session_result: SessionResult::Failed { .. },
+                ..
             }) => StatusResponse::Failed,
             DisclosureData::Done(Done {
                 session_result: SessionResult::Cancelled,
+                ..
             }) => StatusResponse::Cancelled,
             DisclosureData::Done(Done {
                 session_result: SessionResult::Expired,
+                ..
             }) => StatusResponse::Expired,
         };
 
         Ok(response)
     }
 
+    /// Subscribe to changes of the sessions of this verifier, as described in [`SessionStore::subscribe()`].
+    /// The receiver yields the token of each session that changed, after which [`Verifier::status_response()`]
+    /// can be used to retrieve its new status.
+    pub async fn subscribe(&self) -> Result<broadcast::Receiver<SessionToken>, SessionError> {
+        let changes = self.sessions.subscribe().await?;
+
+        Ok(changes)
+    }
+
     pub async fn cancel(&self, session_token: &SessionToken) -> Result<(), CancelSessionError> {
         let SessionState { data, token, .. } = session_or_error(self.sessions.as_ref(), session_token).await?;
 
-        // Create a new `SessionState<DisclosureData>` if the session
-        // is in the `CREATED` or `WAITING_FOR_RESPONSE` state.
-        let cancelled_session_state = match data {
-            DisclosureData::Created(_) | DisclosureData::WaitingForResponse(_) => SessionState::new(
+        // Create a new `Session<Done>` if the session is in the `CREATED` or `WAITING_FOR_RESPONSE` state.
+        let usecase_id = match data {
+            DisclosureData::Created(Created { usecase_id, .. }) => usecase_id,
+            DisclosureData::WaitingForResponse(waiting) => waiting.usecase_id,
+            DisclosureData::Done(_) => return Err(SessionError::UnexpectedState(data.into()).into()),
+        };
+        let cancelled_session = Session::<Done> {
+            state: SessionState::new(
                 token,
-                DisclosureData::Done(Done {
+                Done {
                     session_result: SessionResult::Cancelled,
-                }),
+                    webhook: None,
+                },
             ),
-            DisclosureData::Done(_) => return Err(SessionError::UnexpectedState(data.into()).into()),
         };
+        let cancelled_session = self.with_webhook_delivery(&usecase_id, cancelled_session).await;
 
         self.sessions
-            .write(cancelled_session_state, false)
+            .write(cancelled_session.into(), false)
             .await
             .map_err(SessionError::SessionStore)?;
 
@@ -1112,6 +1264,7 @@ where
                         redirect_uri_nonce: expected_nonce,
                         disclosed_attributes,
                     },
+                ..
             }) => match (redirect_uri_nonce, expected_nonce) {
                 (_, None) => Ok(disclosed_attributes),
                 (None, Some(_)) => Err(DisclosedAttributesError::RedirectUriNonceMissing),
@@ -1132,6 +1285,54 @@ where
         };
         self.use_cases.get(usecase_id)?.disclosure_base_deep_link().cloned()
     }
+
+    /// Attach a signed webhook notification to a finished session, if webhook delivery is enabled and the use case of
+    /// the session has a webhook configured. The notification is sent by the task started in
+    /// [`Verifier::with_webhook_client()`].
+    async fn with_webhook_delivery(&self, usecase_id: &str, mut session: Session<Done>) -> Session<Done> {
+        if self.webhook_task.is_none() {
+            return session;
+        }
+
+        let Some((use_case, webhook)) = self
+            .use_cases
+            .get(usecase_id)
+            .and_then(|use_case| use_case.webhook().map(|webhook| (use_case, webhook)))
+        else {
+            return session;
+        };
+
+        let (status, disclosed_attributes) = match &session.state().session_result {
+            SessionResult::Done {
+                disclosed_attributes, ..
+            } => (
+                WebhookSessionStatus::Done,
+                webhook
+                    .include_disclosed_attributes
+                    .then(|| disclosed_attributes.clone()),
+            ),
+            SessionResult::Failed { .. } => (WebhookSessionStatus::Failed, None),
+            SessionResult::Cancelled => (WebhookSessionStatus::Cancelled, None),
+            SessionResult::Expired => return session,
+        };
+
+        let notification = WebhookNotification {
+            session_token: session.state.token.clone(),
+            status,
+            disclosed_attributes,
+            iat: Utc::now(),
+        };
+
+        match WebhookDelivery::sign(webhook, &notification, &use_case.data().key_pair).await {
+            Ok(delivery) => session.state.data.webhook = Some(delivery),
+            Err(error) => warn!(
+                "Session({}): error signing webhook notification: {error}",
+                session.state.token
+            ),
+        }
+
+        session
+    }
 }
 
 impl<S, US, C> Verifier<S, US, C> {
@@ -1180,6 +1381,33 @@ where
         .ok_or_else(|| SessionError::UnknownSession(session_token.clone()))
 }
 
+/// Try to deliver all webhook notifications that are due, storing the updated delivery state in the session. The due
+/// sessions are claimed first, so that verifiers sharing the same session store do not deliver a notification twice.
+async fn deliver_pending_webhooks<S>(
+    sessions: &S,
+    client: &(dyn WebhookClient + Send + Sync),
+    time: &impl Generator<DateTime<Utc>>,
+) -> Result<(), SessionStoreError>
+where
+    S: SessionStore<DisclosureData>,
+{
+    for mut session in sessions.claim_pending_notifications(WEBHOOK_CLAIM_DURATION).await? {
+        let DisclosureData::Done(Done {
+            webhook: Some(ref mut webhook),
+            ..
+        }) = session.data
+        else {
+            continue;
+        };
+
+        webhook.attempt(client, time.generate()).await;
+
+        sessions.write(session, false).await?;
+    }
+
+    Ok(())
+}
+
 async fn session_in_state<T, S>(sessions: &S, session_token: &SessionToken) -> Result<Session<T>, SessionError>
 where
     T: DisclosureState,
@@ -1216,6 +1444,7 @@ impl<T: DisclosureState> Session<T> {
             session_result: SessionResult::Failed {
                 error: error.to_string(),
             },
+            webhook: None,
         })
     }
 
@@ -1426,6 +1655,8 @@ impl Session<WaitingForResponse> {
         result_handler: Option<&(dyn DisclosureResultHandler + Send + Sync)>,
         extending_vct_values: &impl ExtendingVctRetriever,
         revocation_verifier: &RevocationVerifier<C>,
+        certificate_revocation_checker: Option<&RevocationChecker>,
+        jwt_vc_issuer_key_resolver: Option<&JwtVcIssuerKeyResolver<HttpJwtVcIssuerMetadataClient>>,
     ) -> (
         Result<VpResponse, WithRedirectUri<PostAuthResponseError>>,
         Session<Done>,
@@ -1472,6 +1703,8 @@ impl Session<WaitingForResponse> {
             trust_anchors,
             extending_vct_values,
             revocation_verifier,
+            certificate_revocation_checker,
+            jwt_vc_issuer_key_resolver,
             self.state().accept_undetermined_revocation_status,
         )
         .await
@@ -1550,12 +1783,14 @@ impl Session<WaitingForResponse> {
                 disclosed_attributes,
                 redirect_uri_nonce: nonce,
             },
+            webhook: None,
         })
     }
 
     fn transition_abort(self) -> Session<Done> {
         self.transition(Done {
             session_result: SessionResult::Cancelled,
+            webhook: None,
         })
     }
 }
@@ -1580,6 +1815,8 @@ mod tests {
     use dcql::unique_id_vec::UniqueIdVec;
     use http_utils::urls::BaseUrl;
     use itertools::Itertools;
+    use jwt::UnverifiedJwt;
+    use jwt::headers::HeaderWithX5c;
     use p256::ecdsa::SigningKey;
     use ring::hmac;
     use ring::rand;
@@ -1609,6 +1846,7 @@ mod tests {
     use super::SessionType;
     use super::SessionTypeReturnUrl;
     use super::StatusResponse;
+    use super::UseCaseClientIdScheme;
     use super::UseCaseData;
     use super::Verifier;
     use super::VerifierUrlParameters;
@@ -1620,21 +1858,31 @@ mod tests {
     use super::WalletAuthResponse;
     use super::WalletInitiatedUseCase;
     use super::WalletInitiatedUseCases;
+    use super::deliver_pending_webhooks;
     use super::must_use_return_url;
     use crate::errors::AuthorizationErrorResponse;
     use crate::errors::ErrorResponse;
     use crate::errors::RemoteAuthorizationErrorResponse;
     use crate::errors::RemoteErrorCode;
     use crate::mock::MOCK_WALLET_CLIENT_ID;
+    use crate::server_state::HasProgress;
     use crate::server_state::MemorySessionStore;
     use crate::server_state::SessionStore;
     use crate::server_state::SessionToken;
     use crate::server_state::test::memory_session_store_with_mock_time;
     use crate::server_state::test::test_memory_store_with_cleanup_task;
     use crate::verifier::EphemeralIdParameters;
+    use crate::webhook::MockWebhookClient;
+    use crate::webhook::WEBHOOK_CLAIM_DURATION;
+    use crate::webhook::Webhook;
+    use crate::webhook::WebhookDeliveryStatus;
+    use crate::webhook::WebhookNotification;
+    use crate::webhook::WebhookSessionStatus;
 
     const DISCLOSURE_USECASE: &str = "example_usecase";
     const DISCLOSURE_USECASE_ALL_REDIRECT_URI: &str = "example_usecase_all_redirect_uri";
+    const DISCLOSURE_USECASE_WEBHOOK: &str = "example_usecase_webhook";
+    const WEBHOOK_URL: &str = "https://example.com/webhook";
 
     type TestVerifier<G> = Verifier<
         MemorySessionStore<DisclosureData, G>,
@@ -1675,6 +1923,7 @@ mod tests {
                     None,
                     None,
                     false,
+                    None,
                 ),
             ),
             (
@@ -1685,6 +1934,21 @@ mod tests {
                     None,
                     None,
                     false,
+                    None,
+                ),
+            ),
+            (
+                DISCLOSURE_USECASE_WEBHOOK.to_string(),
+                RpInitiatedUseCase::new(
+                    UseCaseData::new(ca.generate_wrpac_verifier_mock().unwrap(), session_type_return_url),
+                    None,
+                    None,
+                    None,
+                    false,
+                    Some(Webhook {
+                        url: WEBHOOK_URL.parse().unwrap(),
+                        include_disclosed_attributes: false,
+                    }),
                 ),
             ),
         ]);
@@ -1930,7 +2194,8 @@ mod tests {
                 attributes: DisclosedAttributes::MsoMdoc(Default::default()),
                 issuer_uri: "https://issuer.example.com".parse().unwrap(),
                 attestation_qualification: AttestationQualification::default(),
-                ca: "ca".to_string(),
+                ca: Some("ca".to_string()),
+                jwt_vc_issuer: None,
                 issuance_validity: IssuanceValidity::new(Utc::now(), Some(Utc::now()), Some(Utc::now())),
                 revocation_status: Some(RevocationStatus::Valid),
                 aki: vec![],
@@ -1954,6 +2219,7 @@ mod tests {
                     disclosed_attributes: mock_disclosed_attestations(),
                     redirect_uri_nonce: None,
                 },
+                webhook: None,
             }),
         );
         let session2 = SessionState::new(
@@ -1963,12 +2229,14 @@ mod tests {
                     disclosed_attributes: mock_disclosed_attestations(),
                     redirect_uri_nonce: "this-is-the-nonce".to_string().into(),
                 },
+                webhook: None,
             }),
         );
         let session3 = SessionState::new(
             "token3".into(),
             DisclosureData::Done(Done {
                 session_result: SessionResult::Expired,
+                webhook: None,
             }),
         );
 
@@ -2239,6 +2507,127 @@ mod tests {
         test_memory_store_with_cleanup_task(sessions, token, &mock_time, CLEANUP_INTERVAL).await;
     }
 
+    #[tokio::test]
+    async fn test_verifier_webhook_delivery() {
+        let sessions = Arc::new(MemorySessionStore::default());
+        let verifier = create_verifier(Arc::clone(&sessions), SessionTypeReturnUrl::SameDevice)
+            .with_webhook_client(Arc::new(MockWebhookClient::new()));
+
+        // Stop the delivery task before it runs, so that we can deliver the notification ourselves below.
+        verifier.webhook_task.as_ref().unwrap().abort();
+
+        let token = verifier
+            .new_session(
+                DISCLOSURE_USECASE_WEBHOOK.to_string(),
+                Some(Query::new_mock_mdoc_pid_example()),
+                Some("https://example.com/{session_token}".parse().unwrap()),
+            )
+            .await
+            .unwrap();
+
+        verifier
+            .cancel(&token)
+            .await
+            .expect("cancelling session should succeed");
+
+        // Cancelling the session should have resulted in a pending webhook notification.
+        let session = sessions.get(&token).await.unwrap().unwrap();
+        assert!(session.data.notification_due().is_some());
+
+        let mut client = MockWebhookClient::new();
+        let expected_token = token.clone();
+        client
+            .expect_send()
+            .withf(move |url, notification| {
+                let (_, notification) = notification
+                    .parse::<UnverifiedJwt<WebhookNotification, HeaderWithX5c>>()
+                    .unwrap()
+                    .dangerous_parse_unverified()
+                    .unwrap();
+
+                url.as_ref().as_str() == WEBHOOK_URL
+                    && notification.session_token == expected_token
+                    && notification.status == WebhookSessionStatus::Cancelled
+                    && notification.disclosed_attributes.is_none()
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        deliver_pending_webhooks(sessions.as_ref(), &client, &TimeGenerator)
+            .await
+            .unwrap();
+
+        // After successful delivery there should be nothing left to deliver.
+        assert!(
+            sessions
+                .claim_pending_notifications(WEBHOOK_CLAIM_DURATION)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        let session = sessions.get(&token).await.unwrap().unwrap();
+        assert_matches!(
+            session.data,
+            DisclosureData::Done(Done {
+                session_result: SessionResult::Cancelled,
+                webhook: Some(delivery),
+            }) if delivery.status() == WebhookDeliveryStatus::Delivered
+        );
+    }
+
+    #[tokio::test]
+    async fn test_verifier_subscribe() {
+        let verifier = create_verifier(Default::default(), SessionTypeReturnUrl::SameDevice);
+        let mut changes = verifier.subscribe().await.unwrap();
+
+        let token = verifier
+            .new_session(
+                DISCLOSURE_USECASE.to_string(),
+                Some(Query::new_mock_mdoc_pid_example()),
+                Some("https://example.com/{session_token}".parse().unwrap()),
+            )
+            .await
+            .unwrap();
+
+        // Both creating and cancelling the session should be reported.
+        assert_eq!(changes.try_recv().unwrap(), token);
+
+        verifier.cancel(&token).await.unwrap();
+
+        assert_eq!(changes.try_recv().unwrap(), token);
+        assert!(changes.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_verifier_webhook_disabled() {
+        let sessions = Arc::new(MemorySessionStore::default());
+        let verifier = create_verifier(Arc::clone(&sessions), SessionTypeReturnUrl::SameDevice);
+
+        let token = verifier
+            .new_session(
+                DISCLOSURE_USECASE_WEBHOOK.to_string(),
+                Some(Query::new_mock_mdoc_pid_example()),
+                Some("https://example.com/{session_token}".parse().unwrap()),
+            )
+            .await
+            .unwrap();
+
+        verifier
+            .cancel(&token)
+            .await
+            .expect("cancelling session should succeed");
+
+        // Without a webhook client, no notification should be scheduled.
+        assert!(
+            sessions
+                .claim_pending_notifications(WEBHOOK_CLAIM_DURATION)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
     #[rstest]
     #[case(SessionTypeReturnUrl::Both, SessionType::SameDevice, true)]
     #[case(SessionTypeReturnUrl::Both, SessionType::CrossDevice, true)]
@@ -2251,4 +2640,34 @@ mod tests {
     ) {
         assert_eq!(must_use_return_url(session_type_return_url, session_type), expected);
     }
+
+    #[test]
+    fn test_use_case_data_client_id_scheme() {
+        let ca = Ca::generate_wrpac_mock_ca().unwrap();
+        let key_pair = ca.generate_wrpac_verifier_san_dns_mock("cert.rp.example.com").unwrap();
+        let certificate_client_id = ClientId::x509_hash_from_certificate(key_pair.certificate());
+
+        let data = UseCaseData::try_new_with_client_id_scheme(
+            key_pair.clone(),
+            SessionTypeReturnUrl::SameDevice,
+            UseCaseClientIdScheme::X509Hash,
+        )
+        .unwrap();
+        assert_eq!(data.client_id, certificate_client_id);
+
+        let data = UseCaseData::try_new_with_client_id_scheme(
+            key_pair,
+            SessionTypeReturnUrl::SameDevice,
+            UseCaseClientIdScheme::X509SanDns,
+        )
+        .unwrap();
+        assert_eq!(data.client_id, ClientId::x509_san_dns("cert.rp.example.com"));
+
+        let _ = UseCaseData::try_new_with_client_id_scheme(
+            ca.generate_wrpac_verifier_mock().unwrap(),
+            SessionTypeReturnUrl::SameDevice,
+            UseCaseClientIdScheme::X509SanDns,
+        )
+        .expect_err("x509_san_dns client_id scheme should require a SAN DNS name in the certificate");
+    }
 }
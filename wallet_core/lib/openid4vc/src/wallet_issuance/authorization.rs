@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::num::NonZeroU8;
+use std::sync::Arc;
 
 use base64::Engine;
 use base64::prelude::BASE64_URL_SAFE_NO_PAD;
@@ -8,10 +9,14 @@ use error_category::ErrorCategory;
 use http_utils::reqwest::HttpClient;
 use itertools::Either;
 use itertools::Itertools;
+use jwe::algorithm::EncryptionAlgorithm;
 use jwt::wia::WIA_HEADER_NAME;
 use jwt::wia::WIA_POP_HEADER_NAME;
+use sd_jwt_vc_metadata::resolver::TypeMetadataResolver;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_with::DisplayFromStr;
+use serde_with::serde_as;
 use url::Url;
 use wscd::wscd::WiaClient;
 
@@ -81,10 +86,12 @@ pub struct HttpAuthorizationSession<P = S256PkcePair> {
     credential_issuer: IssuerIdentifier,
     issuer_endpoints: IssuerEndpoints,
     batch_size: NonZeroU8,
+    response_encryption: Option<EncryptionAlgorithm>,
     token_endpoint: Url,
     client_attestation_challenge: ClientAttestationChallengeMechanism,
     authorization_server: IssuerIdentifier,
     http_client: HttpClient,
+    type_metadata_resolver: Option<Arc<TypeMetadataResolver>>,
 
     auth_url: Url,
     redirect_uri: Url,
@@ -92,12 +99,16 @@ pub struct HttpAuthorizationSession<P = S256PkcePair> {
     state: String,
 }
 
+#[serde_as]
 #[derive(Clone, Serialize, Deserialize)]
 pub struct HttpAuthorizationSessionData {
     credential_configurations: HashMap<CredentialConfigurationId, CredentialConfiguration>,
     credential_issuer: IssuerIdentifier,
     issuer_endpoints: IssuerEndpoints,
     batch_size: NonZeroU8,
+    #[serde(default)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    response_encryption: Option<EncryptionAlgorithm>,
     token_endpoint: Url,
     client_attestation_challenge: ClientAttestationChallengeMechanism,
     authorization_server: IssuerIdentifier,
@@ -114,10 +125,12 @@ impl<P: PkcePair> HttpAuthorizationSession<P> {
     #[expect(clippy::too_many_arguments, reason = "internal constructor")]
     pub(super) async fn create(
         http_client: HttpClient,
+        type_metadata_resolver: Option<Arc<TypeMetadataResolver>>,
         credential_configurations: HashMap<CredentialConfigurationId, CredentialConfiguration>,
         credential_issuer: IssuerIdentifier,
         issuer_endpoints: IssuerEndpoints,
         batch_size: NonZeroU8,
+        response_encryption: Option<EncryptionAlgorithm>,
         auth_endpoints: AuthorizationEndpoints,
         client_id: String,
         redirect_uri: Url,
@@ -209,10 +222,12 @@ impl<P: PkcePair> HttpAuthorizationSession<P> {
             credential_issuer,
             issuer_endpoints,
             batch_size,
+            response_encryption,
             token_endpoint: auth_endpoints.token_endpoint,
             client_attestation_challenge: client_auth_challenge,
             authorization_server,
             http_client,
+            type_metadata_resolver,
             auth_url,
             redirect_uri,
             pkce_pair,
@@ -259,16 +274,22 @@ impl<P: PkcePair> HttpAuthorizationSession<P> {
 }
 
 impl HttpAuthorizationSession {
-    pub fn restore(http_client: HttpClient, data: HttpAuthorizationSessionData) -> Self {
+    pub fn restore(
+        http_client: HttpClient,
+        type_metadata_resolver: Option<Arc<TypeMetadataResolver>>,
+        data: HttpAuthorizationSessionData,
+    ) -> Self {
         Self {
             credential_configurations: data.credential_configurations,
             credential_issuer: data.credential_issuer,
             issuer_endpoints: data.issuer_endpoints,
             batch_size: data.batch_size,
+            response_encryption: data.response_encryption,
             token_endpoint: data.token_endpoint,
             client_attestation_challenge: data.client_attestation_challenge,
             authorization_server: data.authorization_server,
             http_client,
+            type_metadata_resolver,
             auth_url: data.auth_url,
             redirect_uri: data.redirect_uri,
             pkce_pair: S256PkcePair::from_code_verifier(data.code_verifier),
@@ -295,6 +316,7 @@ impl AuthorizationSession for HttpAuthorizationSession {
             credential_issuer: self.credential_issuer.clone(),
             issuer_endpoints: self.issuer_endpoints.clone(),
             batch_size: self.batch_size,
+            response_encryption: self.response_encryption,
             token_endpoint: self.token_endpoint.clone(),
             client_attestation_challenge: self.client_attestation_challenge.clone(),
             authorization_server: self.authorization_server.clone(),
@@ -312,7 +334,8 @@ impl AuthorizationSession for HttpAuthorizationSession {
         wia_client: &impl WiaClient,
     ) -> Result<Self::Issuance, WalletIssuanceError> {
         let authorization_code = self.authorization_code(received_redirect_uri)?;
-        let message_client = HttpVcMessageClient::new(self.http_client);
+        let message_client =
+            HttpVcMessageClient::new(self.http_client).with_type_metadata_resolver(self.type_metadata_resolver);
 
         // Create the Token Request to be sent to the issuer with the minimal amount of information required. This does
         // not include either a `scope` or `authorization_details` field, as we have no need to further restrict the
@@ -329,6 +352,7 @@ impl AuthorizationSession for HttpAuthorizationSession {
             self.credential_issuer,
             self.issuer_endpoints,
             self.batch_size,
+            self.response_encryption,
             &self.token_endpoint,
             self.client_attestation_challenge,
             token_request,
@@ -415,12 +439,14 @@ mod tests {
             credential_issuer: issuer_metadata.credential_issuer,
             issuer_endpoints: issuer_metadata.endpoints,
             batch_size,
+            response_encryption: None,
             token_endpoint: ISSUER_URL.parse::<BaseUrl>().unwrap().join(TOKEN_ENDPOINT),
             client_attestation_challenge: ClientAttestationChallengeMechanism::ChallengeEndpoint(
                 ISSUER_URL.parse::<BaseUrl>().unwrap().join(CHALLENGE_ENDPOINT),
             ),
             authorization_server: ISSUER_URL.parse().unwrap(),
             http_client: HttpClient::try_new(default_reqwest_client_builder()).unwrap(),
+            type_metadata_resolver: None,
             auth_url: ISSUER_URL.parse().unwrap(),
             redirect_uri: REDIRECT_URI.parse().unwrap(),
             pkce_pair,
@@ -533,10 +559,12 @@ mod tests {
         let batch_size = issuer_metadata.batch_size().try_into().unwrap();
         let session = HttpAuthorizationSession::<MockPkcePair>::create(
             HttpClient::try_new(httpmock_reqwest_client_builder()).unwrap(),
+            None,
             issuer_metadata.credential_configurations_supported,
             issuer_metadata.credential_issuer.clone(),
             issuer_metadata.endpoints,
             batch_size,
+            None,
             auth_endpoints,
             MOCK_WALLET_CLIENT_ID.to_string(),
             REDIRECT_URI.parse().unwrap(),
@@ -637,10 +665,12 @@ mod tests {
         let wia_client = RecordingWiaClient::default();
         let result = HttpAuthorizationSession::<MockPkcePair>::create(
             HttpClient::try_new(httpmock_reqwest_client_builder()).unwrap(),
+            None,
             issuer_metadata.credential_configurations_supported,
             issuer_metadata.credential_issuer.clone(),
             issuer_metadata.endpoints,
             batch_size,
+            None,
             auth_endpoints,
             MOCK_WALLET_CLIENT_ID.to_string(),
             REDIRECT_URI.parse().unwrap(),
@@ -710,10 +740,12 @@ mod tests {
         let batch_size = issuer_metadata.batch_size().try_into().unwrap();
         let error = HttpAuthorizationSession::<MockPkcePair>::create(
             HttpClient::try_new(httpmock_reqwest_client_builder()).unwrap(),
+            None,
             issuer_metadata.credential_configurations_supported,
             issuer_metadata.credential_issuer.clone(),
             issuer_metadata.endpoints,
             batch_size,
+            None,
             auth_endpoints,
             MOCK_WALLET_CLIENT_ID.to_string(),
             REDIRECT_URI.parse().unwrap(),
@@ -825,6 +857,7 @@ mod tests {
             credential_issuer: issuer_metadata.credential_issuer,
             issuer_endpoints: issuer_metadata.endpoints,
             batch_size,
+            response_encryption: None,
             token_endpoint: ISSUER_URL.parse::<BaseUrl>().unwrap().join(TOKEN_ENDPOINT),
             client_attestation_challenge: ClientAttestationChallengeMechanism::ChallengeEndpoint(
                 ISSUER_URL.parse::<BaseUrl>().unwrap().join(CHALLENGE_ENDPOINT),
@@ -842,8 +875,10 @@ mod tests {
             issuer_endpoints: persisted.issuer_endpoints,
             token_endpoint: persisted.token_endpoint,
             batch_size: persisted.batch_size,
+            response_encryption: persisted.response_encryption,
             client_attestation_challenge: persisted.client_attestation_challenge,
             http_client: HttpClient::try_new(default_reqwest_client_builder()).unwrap(),
+            type_metadata_resolver: None,
             auth_url: persisted.auth_url.clone(),
             redirect_uri: persisted.redirect_uri.clone(),
             pkce_pair: S256PkcePair::from_code_verifier(persisted.code_verifier.clone()),
@@ -853,6 +888,7 @@ mod tests {
 
         let restored = HttpAuthorizationSession::restore(
             HttpClient::try_new(default_reqwest_client_builder()).unwrap(),
+            None,
             session.persist(),
         );
         let restored_persisted = restored.persist();
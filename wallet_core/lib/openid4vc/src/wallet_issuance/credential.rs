@@ -5,6 +5,22 @@ use sd_jwt_vc_metadata::VerifiedTypeMetadataDocuments;
 use utils::date_time_seconds::DateTimeSeconds;
 use utils::vec_at_least::VecNonEmpty;
 
+use super::deferred::DeferredIssuance;
+use super::notification::CredentialNotification;
+use super::refresh::CredentialRefresh;
+
+/// The result of accepting issuance or of polling the issuer for deferred credentials: either the issued credentials
+/// or the state needed to (again) poll the issuer at a later moment.
+#[derive(Debug)]
+#[expect(
+    clippy::large_enum_variant,
+    reason = "an outcome is only returned once per issuance and not stored in bulk"
+)]
+pub enum IssuanceOutcome {
+    Issued(Vec<CredentialWithMetadata>),
+    Deferred(DeferredIssuance),
+}
+
 #[derive(Clone, Debug)]
 pub struct CredentialWithMetadata {
     pub copies: IssuedCredentialCopies,
@@ -13,6 +29,10 @@ pub struct CredentialWithMetadata {
     pub not_before: Option<DateTimeSeconds>,
     pub extended_attestation_types: Vec<String>,
     pub metadata_documents: VerifiedTypeMetadataDocuments,
+    /// Present if the issuer supports notifications about the status of the issued credential.
+    pub notification: Option<CredentialNotification>,
+    /// Present if the issuer handed out a refresh token, with which the credential can be reissued later on.
+    pub refresh: Option<CredentialRefresh>,
 }
 
 impl CredentialWithMetadata {
@@ -31,8 +51,18 @@ impl CredentialWithMetadata {
             not_before,
             extended_attestation_types: extended_attestation_types.into_iter().map(Into::into).collect(),
             metadata_documents,
+            notification: None,
+            refresh: None,
         }
     }
+
+    pub fn with_notification(self, notification: Option<CredentialNotification>) -> Self {
+        Self { notification, ..self }
+    }
+
+    pub fn with_refresh(self, refresh: Option<CredentialRefresh>) -> Self {
+        Self { refresh, ..self }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -54,4 +84,12 @@ impl IssuedCredentialCopies {
             IssuedCredentialCopies::SdJwt(_) => Format::SdJwt,
         }
     }
+
+    /// The identifier of the private key the first copy of the credential is bound to.
+    pub fn first_key_identifier(&self) -> &str {
+        match self {
+            IssuedCredentialCopies::Mdoc(mdocs) => mdocs.first().private_key_id(),
+            IssuedCredentialCopies::SdJwt(sd_jwts) => &sd_jwts.first().key_identifier,
+        }
+    }
 }
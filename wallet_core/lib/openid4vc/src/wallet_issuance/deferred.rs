@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+use std::num::NonZeroU8;
+use std::time::Duration;
+
+use attestation_data::auth::issuer_auth::IssuerRegistration;
+use crypto::PublicKey;
+use crypto::trust_anchor::TrustAnchors;
+use derive_more::Debug;
+use jwe::algorithm::EncryptionAlgorithm;
+use reqwest::Method;
+use sd_jwt_vc_metadata::TypeMetadataDocuments;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_with::DisplayFromStr;
+use serde_with::DurationSeconds;
+use serde_with::serde_as;
+use url::Url;
+use utils::vec_at_least::VecNonEmpty;
+
+use super::WalletIssuanceError;
+use super::credential::IssuanceOutcome;
+use super::dpop_key::PersistentDpopKey;
+use super::issuance_session::CredentialResponseDecrypter;
+use super::issuance_session::IssuanceTypeMetadata;
+use super::issuance_session::VcMessageClient;
+use super::issuance_session::process_credential_responses;
+use super::issuance_session::retry_on_type_metadata_integrity;
+use super::notification::NotificationContext;
+use crate::credential::CredentialResponses;
+use crate::credential::DeferredCredentialRequest;
+use crate::credential::DeferredCredentialResponse;
+use crate::dpop::Dpop;
+use crate::token::AccessToken;
+use crate::token::CredentialPreview;
+
+/// The state of an issuance session for which the issuer has deferred issuance. This contains everything needed to
+/// poll the Deferred Credential Endpoint at a later moment, possibly after the app has been restarted, and to verify
+/// the credentials once they are issued.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeferredIssuance {
+    pub transaction_id: String,
+    #[serde_as(as = "DurationSeconds<u64>")]
+    pub interval: Duration,
+    pub(super) deferred_credential_endpoint: Url,
+    pub(super) access_token: AccessToken,
+    pub(super) dpop_key: PersistentDpopKey,
+    pub(super) dpop_nonce: Option<String>,
+    pub(super) batch_size: NonZeroU8,
+    /// The JWE content encryption algorithm with which the issuer is requested to encrypt its responses, if any. A new
+    /// ephemeral key is generated for every poll.
+    #[serde(default)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub(super) response_encryption: Option<EncryptionAlgorithm>,
+    pub credential_previews: VecNonEmpty<CredentialPreview>,
+    #[debug(skip)]
+    pub(super) type_metadata: HashMap<String, TypeMetadataDocuments>,
+    pub(super) holder_keys: Vec<(PublicKey, String)>,
+    pub issuer_registration: IssuerRegistration,
+    #[serde(default)]
+    pub(super) notification_context: Option<NotificationContext>,
+}
+
+impl DeferredIssuance {
+    pub fn dpop_key(&self) -> &PersistentDpopKey {
+        &self.dpop_key
+    }
+
+    /// Restore the DPoP private key after deserializing, which is needed before the issuer can be polled. The
+    /// notification context shares this key, so it is restored as well.
+    pub fn restore_dpop_key(&mut self, bytes: &[u8]) -> Result<(), WalletIssuanceError> {
+        self.dpop_key.restore(bytes)?;
+
+        if let Some(notification_context) = self.notification_context.as_mut() {
+            notification_context.dpop_key.restore(bytes)?;
+        }
+
+        Ok(())
+    }
+
+    /// The key identifiers of the private keys that the credentials will be bound to once issued.
+    pub fn key_identifiers(&self) -> impl Iterator<Item = &str> {
+        self.holder_keys
+            .iter()
+            .map(|(_, key_identifier)| key_identifier.as_str())
+    }
+
+    /// Poll the Deferred Credential Endpoint of the issuer. If the credentials are still pending, this returns an
+    /// updated [`DeferredIssuance`], which should be used for the next poll.
+    pub async fn poll(
+        self,
+        message_client: &impl VcMessageClient,
+        trust_anchors: &TrustAnchors,
+    ) -> Result<IssuanceOutcome, WalletIssuanceError> {
+        let dpop_header = Dpop::new(
+            self.dpop_key.signing_key()?,
+            self.deferred_credential_endpoint.clone(),
+            &Method::POST,
+            Some(&self.access_token),
+            self.dpop_nonce.clone(),
+        )?;
+        let access_token_header = "DPoP ".to_string() + self.access_token.as_ref();
+
+        let decrypter = self.response_encryption.map(CredentialResponseDecrypter::new_random);
+        let request = DeferredCredentialRequest {
+            transaction_id: self.transaction_id.clone(),
+            credential_response_encryption: decrypter.as_ref().map(CredentialResponseDecrypter::to_parameters),
+        };
+
+        let response = message_client
+            .request_deferred_credential(
+                &self.deferred_credential_endpoint,
+                &request,
+                &dpop_header.to_string(),
+                &access_token_header,
+            )
+            .await?;
+        let response = CredentialResponseDecrypter::decrypt(decrypter.as_ref(), response)?;
+
+        match response {
+            DeferredCredentialResponse::Pending {
+                transaction_id,
+                interval,
+            } => Ok(IssuanceOutcome::Deferred(Self {
+                transaction_id,
+                interval,
+                ..self
+            })),
+            DeferredCredentialResponse::Issued(CredentialResponses { credential_responses }) => {
+                if credential_responses.len() != self.holder_keys.len() {
+                    return Err(WalletIssuanceError::UnexpectedCredentialResponseCount {
+                        found: credential_responses.len(),
+                        expected: self.holder_keys.len(),
+                    });
+                }
+
+                // Normalize the type metadata documents again, as this is not stored in its normalized form.
+                let type_metadata = self
+                    .type_metadata
+                    .into_iter()
+                    .map(|(attestation_type, documents)| {
+                        let (normalized_metadata, raw_metadata) = documents.into_normalized(&attestation_type)?;
+
+                        let metadata = IssuanceTypeMetadata {
+                            normalized_metadata,
+                            raw_metadata,
+                        };
+
+                        Ok((attestation_type, metadata))
+                    })
+                    .collect::<Result<HashMap<_, _>, WalletIssuanceError>>()?;
+
+                let credentials = retry_on_type_metadata_integrity(&type_metadata, message_client, |type_metadata| {
+                    process_credential_responses(
+                        credential_responses.clone(),
+                        self.holder_keys.clone(),
+                        &self.credential_previews,
+                        self.batch_size,
+                        type_metadata,
+                        self.notification_context.as_ref(),
+                        None,
+                        trust_anchors,
+                    )
+                })
+                .await?;
+
+                Ok(IssuanceOutcome::Issued(credentials))
+            }
+        }
+    }
+}
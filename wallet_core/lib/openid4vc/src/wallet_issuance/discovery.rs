@@ -1,14 +1,17 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::num::NonZeroU8;
+use std::sync::Arc;
 
 use crypto::trust_anchor::TrustAnchors;
 use http_utils::reqwest::HttpClient;
 use itertools::Either;
 use itertools::Itertools;
+use jwe::algorithm::EncryptionAlgorithm;
 use jwt::DEFAULT_VALIDATION;
 use jwt::UnverifiedJwt;
 use jwt::headers::HeaderWithX5c;
+use sd_jwt_vc_metadata::resolver::TypeMetadataResolver;
 use url::Url;
 use utils::generator::TimeGenerator;
 use utils::vec_at_least::NonEmptyIterator;
@@ -21,13 +24,18 @@ use super::IssuanceFlow;
 use super::WalletIssuanceError;
 use super::authorization::HttpAuthorizationSession;
 use super::authorization_endpoints::AuthorizationEndpoints;
+use super::credential::IssuanceOutcome;
+use super::deferred::DeferredIssuance;
 use super::issuance_session::HttpIssuanceSession;
 use super::issuance_session::HttpVcMessageClient;
+use super::notification::CredentialNotification;
+use super::refresh::CredentialRefresh;
 use crate::client_auth::ClientAttestationChallengeMechanism;
 use crate::client_auth::check_client_attestation_metadata;
 use crate::credential_offer::CredentialOffer;
 use crate::credential_offer::CredentialOfferContainer;
 use crate::credential_offer::Grants;
+use crate::credential_offer::PreAuthTransactionCode;
 use crate::issuer_identifier::IssuerIdentifier;
 use crate::metadata::issuer_metadata::CredentialConfiguration;
 use crate::metadata::issuer_metadata::CredentialConfigurationId;
@@ -37,6 +45,7 @@ use crate::metadata::issuer_metadata::SignedIssuerMetadataPayload;
 use crate::metadata::oauth_metadata::AuthorizationServerMetadata;
 use crate::metadata::well_known;
 use crate::metadata::well_known::WellKnownPath;
+use crate::notification::NotificationEvent;
 use crate::token::AuthorizationCode;
 use crate::token::TokenRequest;
 
@@ -44,11 +53,29 @@ const BATCH_SIZE_MAX: NonZeroU8 = NonZeroU8::MAX;
 
 pub struct HttpIssuanceDiscovery {
     http_client: HttpClient,
+    type_metadata_resolver: Option<Arc<TypeMetadataResolver>>,
 }
 
 impl HttpIssuanceDiscovery {
     pub fn new(http_client: HttpClient) -> Self {
-        Self { http_client }
+        Self {
+            http_client,
+            type_metadata_resolver: None,
+        }
+    }
+
+    /// Use `type_metadata_resolver` to retrieve type metadata from the `vct` URL for credential configurations that do
+    /// not specify a type metadata URI.
+    pub fn with_type_metadata_resolver(self, type_metadata_resolver: TypeMetadataResolver) -> Self {
+        Self {
+            type_metadata_resolver: Some(Arc::new(type_metadata_resolver)),
+            ..self
+        }
+    }
+
+    fn message_client(&self) -> HttpVcMessageClient {
+        HttpVcMessageClient::new(self.http_client.clone())
+            .with_type_metadata_resolver(self.type_metadata_resolver.clone())
     }
 }
 
@@ -59,15 +86,16 @@ impl IssuanceDiscovery for HttpIssuanceDiscovery {
     async fn start(
         &self,
         offer_uri: &Url,
+        tx_code: Option<String>,
         client_id: String,
         redirect_uri: Url,
         issuer_trust_anchors: &TrustAnchors,
         wia_client: &impl WiaClient,
         wrpac_trust_anchors: &TrustAnchors,
     ) -> Result<IssuanceFlow<Self::Authorization, Self::Issuance>, WalletIssuanceError> {
-        let (credential_configurations, credential_issuer, issuer_endpoints, batch_size, flow) = self
-            .resolve_credential_offer_flow(offer_uri, wrpac_trust_anchors)
-            .await?;
+        let (credential_configurations, credential_issuer, issuer_endpoints, batch_size, response_encryption, flow) =
+            self.resolve_credential_offer_flow(offer_uri, wrpac_trust_anchors)
+                .await?;
 
         let issuance_flow = match flow {
             CredentialOfferFlow::AuthorizationCode {
@@ -77,10 +105,12 @@ impl IssuanceDiscovery for HttpIssuanceDiscovery {
             } => {
                 let authorization_session = HttpAuthorizationSession::create(
                     self.http_client.clone(),
+                    self.type_metadata_resolver.clone(),
                     credential_configurations,
                     credential_issuer,
                     issuer_endpoints,
                     batch_size,
+                    response_encryption,
                     auth_endpoints,
                     client_id,
                     redirect_uri,
@@ -94,17 +124,31 @@ impl IssuanceDiscovery for HttpIssuanceDiscovery {
             }
             CredentialOfferFlow::PreAuthorizedCode {
                 pre_authorized_code,
+                tx_code: tx_code_requirements,
                 token_endpoint,
                 authorization_server,
                 challenge_endpoint,
             } => {
+                // Only send the Transaction Code if the Credential Offer actually requires one.
+                let tx_code = match (tx_code_requirements, tx_code) {
+                    (Some(requirements), None) => {
+                        return Err(WalletIssuanceError::CredentialOfferTxCodeRequired(Box::new(
+                            requirements,
+                        )));
+                    }
+                    (Some(_), tx_code) => tx_code,
+                    (None, _) => None,
+                };
+
                 let issuance_session = self
                     .create_issuance_session(
                         pre_authorized_code,
+                        tx_code,
                         credential_configurations,
                         credential_issuer,
                         issuer_endpoints,
                         batch_size,
+                        response_encryption,
                         &token_endpoint,
                         challenge_endpoint,
                         wia_client,
@@ -128,9 +172,9 @@ impl IssuanceDiscovery for HttpIssuanceDiscovery {
         wia_client: &impl WiaClient,
         wrpac_trust_anchors: &TrustAnchors,
     ) -> Result<Self::Authorization, WalletIssuanceError> {
-        let (credential_configurations, credential_identifier, issuer_endpoints, batch_size, flow) = self
-            .resolve_credential_offer_flow(offer_uri, wrpac_trust_anchors)
-            .await?;
+        let (credential_configurations, credential_identifier, issuer_endpoints, batch_size, response_encryption, flow) =
+            self.resolve_credential_offer_flow(offer_uri, wrpac_trust_anchors)
+                .await?;
 
         let CredentialOfferFlow::AuthorizationCode {
             issuer_state,
@@ -143,10 +187,12 @@ impl IssuanceDiscovery for HttpIssuanceDiscovery {
 
         HttpAuthorizationSession::create(
             self.http_client.clone(),
+            self.type_metadata_resolver.clone(),
             credential_configurations,
             credential_identifier,
             issuer_endpoints,
             batch_size,
+            response_encryption,
             auth_endpoints,
             client_id,
             redirect_uri,
@@ -164,12 +210,13 @@ impl IssuanceDiscovery for HttpIssuanceDiscovery {
         wia_client: &impl WiaClient,
         wrpac_trust_anchors: &TrustAnchors,
     ) -> Result<Self::Issuance, WalletIssuanceError> {
-        let (credential_configurations, credential_identifier, issuer_endpoints, batch_size, flow) = self
-            .resolve_credential_offer_flow(offer_uri, wrpac_trust_anchors)
-            .await?;
+        let (credential_configurations, credential_identifier, issuer_endpoints, batch_size, response_encryption, flow) =
+            self.resolve_credential_offer_flow(offer_uri, wrpac_trust_anchors)
+                .await?;
 
         let CredentialOfferFlow::PreAuthorizedCode {
             pre_authorized_code,
+            tx_code,
             token_endpoint,
             authorization_server,
             challenge_endpoint,
@@ -178,12 +225,19 @@ impl IssuanceDiscovery for HttpIssuanceDiscovery {
             return Err(WalletIssuanceError::CredentialOfferNoPreAuthorizedCode);
         };
 
+        // This flow is started without any user interaction, so a Transaction Code cannot be provided.
+        if tx_code.is_some() {
+            return Err(WalletIssuanceError::CredentialOfferTxCodeUnsupported);
+        }
+
         self.create_issuance_session(
             pre_authorized_code,
+            None,
             credential_configurations,
             credential_identifier,
             issuer_endpoints,
             batch_size,
+            response_encryption,
             &token_endpoint,
             challenge_endpoint,
             wia_client,
@@ -197,7 +251,77 @@ impl IssuanceDiscovery for HttpIssuanceDiscovery {
         &self,
         data: <Self::Authorization as AuthorizationSession>::Persisted,
     ) -> Self::Authorization {
-        HttpAuthorizationSession::restore(self.http_client.clone(), data)
+        HttpAuthorizationSession::restore(self.http_client.clone(), self.type_metadata_resolver.clone(), data)
+    }
+
+    async fn poll_deferred_issuance(
+        &self,
+        deferred_issuance: DeferredIssuance,
+        trust_anchors: &TrustAnchors,
+    ) -> Result<IssuanceOutcome, WalletIssuanceError> {
+        let message_client = self.message_client();
+
+        deferred_issuance.poll(&message_client, trust_anchors).await
+    }
+
+    async fn send_notification(
+        &self,
+        notification: &CredentialNotification,
+        event: NotificationEvent,
+    ) -> Result<(), WalletIssuanceError> {
+        let message_client = self.message_client();
+
+        notification.send(&message_client, event, None).await
+    }
+
+    async fn start_refresh(
+        &self,
+        refresh: &CredentialRefresh,
+        issuer_trust_anchors: &TrustAnchors,
+        wia_client: &impl WiaClient,
+        wrpac_trust_anchors: &TrustAnchors,
+    ) -> Result<Self::Issuance, WalletIssuanceError> {
+        let (
+            credential_configurations,
+            credential_issuer,
+            issuer_endpoints,
+            batch_size,
+            response_encryption,
+            oauth_metadata,
+        ) = self
+            .resolve_credential_configurations(
+                refresh.credential_issuer(),
+                Some(&refresh.authorization_server),
+                refresh.credential_configuration_ids.iter().cloned(),
+                wrpac_trust_anchors,
+            )
+            .await?;
+
+        let message_client = self.message_client();
+
+        let token_request = TokenRequest::new_refresh_token(refresh.refresh_token.clone());
+
+        // Just like in the pre-authorized code flow, no PAR request precedes redeeming a refresh token.
+        let client_auth_challenge =
+            ClientAttestationChallengeMechanism::new_pre_authorized(oauth_metadata.challenge_endpoint);
+
+        // The issuer binds the refresh token to the DPoP key that was used to obtain it, so this key is used again.
+        HttpIssuanceSession::create_with_dpop_signing_key(
+            message_client,
+            credential_configurations,
+            credential_issuer,
+            issuer_endpoints,
+            batch_size,
+            response_encryption,
+            &oauth_metadata.token_endpoint,
+            client_auth_challenge,
+            token_request,
+            wia_client,
+            &oauth_metadata.issuer,
+            issuer_trust_anchors,
+            refresh.dpop_signing_key()?,
+        )
+        .await
     }
 }
 
@@ -211,8 +335,13 @@ struct NormalizedCredentialOffer {
 
 #[derive(Debug)]
 enum CredentialOfferGrant {
-    AuthorizationCode { issuer_state: Option<String> },
-    PreAuthorizedCode { pre_authorized_code: AuthorizationCode },
+    AuthorizationCode {
+        issuer_state: Option<String>,
+    },
+    PreAuthorizedCode {
+        pre_authorized_code: AuthorizationCode,
+        tx_code: Option<PreAuthTransactionCode>,
+    },
     NoKnownGrant,
 }
 
@@ -225,6 +354,7 @@ enum CredentialOfferFlow {
     },
     PreAuthorizedCode {
         pre_authorized_code: AuthorizationCode,
+        tx_code: Option<PreAuthTransactionCode>,
         authorization_server: IssuerIdentifier,
         token_endpoint: Url,
         challenge_endpoint: Option<Url>,
@@ -245,12 +375,9 @@ impl NormalizedCredentialOffer {
                 pre_authorized_code: Some(pre_authorized_code),
                 ..
             }) => {
-                if pre_authorized_code.tx_code.is_some() {
-                    return Err(WalletIssuanceError::CredentialOfferTxCodeUnsupported);
-                }
-
                 let grant = CredentialOfferGrant::PreAuthorizedCode {
                     pre_authorized_code: pre_authorized_code.pre_authorized_code,
+                    tx_code: pre_authorized_code.tx_code,
                 };
 
                 (grant, pre_authorized_code.authorization_server)
@@ -310,8 +437,12 @@ impl CredentialOfferFlow {
                     authorization_server,
                 }
             }
-            CredentialOfferGrant::PreAuthorizedCode { pre_authorized_code } => Self::PreAuthorizedCode {
+            CredentialOfferGrant::PreAuthorizedCode {
+                pre_authorized_code,
+                tx_code,
+            } => Self::PreAuthorizedCode {
                 pre_authorized_code,
+                tx_code,
                 authorization_server: oauth_metadata.issuer,
                 token_endpoint: oauth_metadata.token_endpoint,
                 challenge_endpoint: oauth_metadata.challenge_endpoint,
@@ -384,15 +515,17 @@ impl HttpIssuanceDiscovery {
         Ok(normalized)
     }
 
-    /// Fetch the Issuer Metadata, select an Authorization Server and fetch the OAuth server metadata from that.
+    /// Fetch the Issuer Metadata, select an Authorization Server and fetch the OAuth server metadata from that. If an
+    /// Authorization Server is provided, it must be one of those listed in the Issuer Metadata.
     async fn fetch_metadata(
         &self,
-        credential_offer: &NormalizedCredentialOffer,
+        credential_issuer: &IssuerIdentifier,
+        authorization_server: Option<&IssuerIdentifier>,
         wrpac_trust_anchors: &TrustAnchors,
     ) -> Result<(IssuerMetadata, AuthorizationServerMetadata), WalletIssuanceError> {
         let issuer_metadata_jwt: UnverifiedJwt<SignedIssuerMetadataPayload, HeaderWithX5c> = self
             .http_client
-            .get_jwt(WellKnownPath::CredentialIssuer.url(&credential_offer.credential_issuer))
+            .get_jwt(WellKnownPath::CredentialIssuer.url(credential_issuer))
             .await
             .map_err(WalletIssuanceError::CredentialIssuerMetadataHttp)?
             .parse()?;
@@ -406,26 +539,26 @@ impl HttpIssuanceDiscovery {
             )
             .map_err(WalletIssuanceError::CredentialIssuerMetadataVerify)?
             .into_payload();
-        if *issuer_metadata_payload.sub != credential_offer.credential_issuer {
+        if *issuer_metadata_payload.sub != *credential_issuer {
             return Err(WalletIssuanceError::CredentialIssuerMetadataIdentifierMismatch {
-                expected: Box::new(credential_offer.credential_issuer.clone()),
+                expected: Box::new(credential_issuer.clone()),
                 received: Box::new(issuer_metadata_payload.sub.into_owned()),
             });
         }
 
         let issuer_metadata = issuer_metadata_payload.metadata.into_owned();
-        if issuer_metadata.credential_issuer != credential_offer.credential_issuer {
+        if issuer_metadata.credential_issuer != *credential_issuer {
             return Err(WalletIssuanceError::CredentialIssuerMetadataIdentifierMismatch {
-                expected: Box::new(credential_offer.credential_issuer.clone()),
+                expected: Box::new(credential_issuer.clone()),
                 received: Box::new(issuer_metadata.credential_issuer),
             });
         }
 
         let metadata_auth_servers = issuer_metadata.authorization_servers();
-        let authorization_server = match credential_offer.authorization_server.as_ref() {
+        let authorization_server = match authorization_server {
             Some(authorization_server) => {
                 // If the Credential Offer contains an Authorization Server, it must match one of the entries in the
-                // Issuer Metadata.
+                // Issuer Metadata. The same goes for the Authorization Server that handed out a refresh token.
                 if !metadata_auth_servers.as_ref().contains(&authorization_server) {
                     return Err(WalletIssuanceError::AuthorizationServerMismatch(
                         Box::new(authorization_server.clone()),
@@ -464,13 +597,55 @@ impl HttpIssuanceDiscovery {
             IssuerIdentifier,
             IssuerEndpoints,
             NonZeroU8,
+            Option<EncryptionAlgorithm>,
             CredentialOfferFlow,
         ),
         WalletIssuanceError,
     > {
         let credential_offer = self.process_credential_offer(offer_uri).await?;
 
-        let (issuer_metadata, oauth_metadata) = self.fetch_metadata(&credential_offer, wrpac_trust_anchors).await?;
+        let (credential_configs, credential_issuer, issuer_endpoints, batch_size, response_encryption, oauth_metadata) =
+            self.resolve_credential_configurations(
+                &credential_offer.credential_issuer,
+                credential_offer.authorization_server.as_ref(),
+                credential_offer.credential_configuration_ids,
+                wrpac_trust_anchors,
+            )
+            .await?;
+
+        let flow = CredentialOfferFlow::try_from_offer_grant(credential_offer.grant, oauth_metadata)?;
+
+        Ok((
+            credential_configs,
+            credential_issuer,
+            issuer_endpoints,
+            batch_size,
+            response_encryption,
+            flow,
+        ))
+    }
+
+    /// Fetch both the issuer and OAuth metadata and select the requested Credential Configurations from the former.
+    async fn resolve_credential_configurations(
+        &self,
+        credential_issuer: &IssuerIdentifier,
+        authorization_server: Option<&IssuerIdentifier>,
+        credential_configuration_ids: impl IntoIterator<Item = CredentialConfigurationId>,
+        wrpac_trust_anchors: &TrustAnchors,
+    ) -> Result<
+        (
+            HashMap<CredentialConfigurationId, CredentialConfiguration>,
+            IssuerIdentifier,
+            IssuerEndpoints,
+            NonZeroU8,
+            Option<EncryptionAlgorithm>,
+            AuthorizationServerMetadata,
+        ),
+        WalletIssuanceError,
+    > {
+        let (issuer_metadata, oauth_metadata) = self
+            .fetch_metadata(credential_issuer, authorization_server, wrpac_trust_anchors)
+            .await?;
 
         check_client_attestation_metadata(&oauth_metadata).map_err(WalletIssuanceError::ClientAttestationMetadata)?;
 
@@ -479,6 +654,17 @@ impl HttpIssuanceDiscovery {
             .try_into()
             .expect("IssuerMetadata batch size is capped to BATCH_SIZE_MAX, which is u8");
 
+        // Have the Credential Responses encrypted whenever the issuer supports this using an algorithm we support.
+        let response_encryption = match issuer_metadata.credential_response_encryption.as_ref() {
+            None => None,
+            Some(response_encryption) => match response_encryption.select_encryption_algorithm() {
+                None if response_encryption.encryption_required => {
+                    return Err(WalletIssuanceError::CredentialResponseEncryptionUnsupported);
+                }
+                encryption_algorithm => encryption_algorithm,
+            },
+        };
+
         let IssuerMetadata {
             credential_issuer,
             endpoints: issuer_endpoints,
@@ -489,8 +675,7 @@ impl HttpIssuanceDiscovery {
         // Collect the indices of all Credential Configuration IDs that appear in the Credential Offer, but not in the
         // Issuer Metadata. If any are missing we can use these indices to collect the owned values for returning the
         // error.
-        let (credential_configs, missing_ids): (HashMap<_, _>, HashSet<_>) = credential_offer
-            .credential_configuration_ids
+        let (credential_configs, missing_ids): (HashMap<_, _>, HashSet<_>) = credential_configuration_ids
             .into_iter()
             .enumerate()
             .partition_map(
@@ -517,14 +702,13 @@ impl HttpIssuanceDiscovery {
             return Err(WalletIssuanceError::NoNonceEndpoint);
         }
 
-        let flow = CredentialOfferFlow::try_from_offer_grant(credential_offer.grant, oauth_metadata)?;
-
         Ok((
             credential_configs,
             credential_issuer,
             issuer_endpoints,
             batch_size,
-            flow,
+            response_encryption,
+            oauth_metadata,
         ))
     }
 
@@ -532,19 +716,21 @@ impl HttpIssuanceDiscovery {
     async fn create_issuance_session(
         &self,
         pre_authorized_code: AuthorizationCode,
+        tx_code: Option<String>,
         credential_configurations: HashMap<CredentialConfigurationId, CredentialConfiguration>,
         credential_issuer: IssuerIdentifier,
         issuer_endpoints: IssuerEndpoints,
         batch_size: NonZeroU8,
+        response_encryption: Option<EncryptionAlgorithm>,
         token_endpoint: &Url,
         challenge_endpoint: Option<Url>,
         wia_client: &impl WiaClient,
         authorization_server: &IssuerIdentifier,
         issuer_trust_anchors: &TrustAnchors,
     ) -> Result<HttpIssuanceSession, WalletIssuanceError> {
-        let message_client = HttpVcMessageClient::new(self.http_client.clone());
+        let message_client = self.message_client();
 
-        let token_request = TokenRequest::new_pre_authorized(pre_authorized_code);
+        let token_request = TokenRequest::new_pre_authorized_with_tx_code(pre_authorized_code, tx_code);
 
         // In the pre-authorized code flow, no PAR request was sent whose response might have included a
         // challenge for Attestation-Based Client Authentication. So we can either use the challenge_endpoint,
@@ -557,6 +743,7 @@ impl HttpIssuanceDiscovery {
             credential_issuer,
             issuer_endpoints,
             batch_size,
+            response_encryption,
             token_endpoint,
             client_auth_challenge,
             token_request,
@@ -959,6 +1146,7 @@ mod test {
         let flow = discovery
             .start(
                 &offer_url,
+                None,
                 MOCK_WALLET_CLIENT_ID.to_string(),
                 REDIRECT_URI.clone(),
                 &issuer_trust_anchors,
@@ -1101,6 +1289,7 @@ mod test {
         let result = discovery
             .start(
                 &offer_url,
+                None,
                 MOCK_WALLET_CLIENT_ID.to_string(),
                 REDIRECT_URI.clone(),
                 &TrustAnchors::empty(),
@@ -1120,6 +1309,7 @@ mod test {
         let result = discovery
             .start(
                 &offer_url,
+                None,
                 MOCK_WALLET_CLIENT_ID.to_string(),
                 REDIRECT_URI.clone(),
                 &TrustAnchors::empty(),
@@ -1144,6 +1334,7 @@ mod test {
         let result = discovery
             .start(
                 &offer_url,
+                None,
                 MOCK_WALLET_CLIENT_ID.to_string(),
                 REDIRECT_URI.clone(),
                 &TrustAnchors::empty(),
@@ -1183,6 +1374,7 @@ mod test {
         let result = discovery
             .start(
                 &offer_url,
+                None,
                 MOCK_WALLET_CLIENT_ID.to_string(),
                 REDIRECT_URI.clone(),
                 &TrustAnchors::empty(),
@@ -1226,6 +1418,7 @@ mod test {
         let result = discovery
             .start(
                 &offer_url,
+                None,
                 MOCK_WALLET_CLIENT_ID.to_string(),
                 REDIRECT_URI.clone(),
                 &TrustAnchors::empty(),
@@ -1238,18 +1431,23 @@ mod test {
     }
 
     #[tokio::test]
-    async fn start_credential_offer_tx_code_unsupported_error() {
-        let server = MockServer::start_async().await;
-        let credential_issuer = server.base_url().parse::<IssuerIdentifier>().unwrap();
+    async fn start_credential_offer_tx_code_required_error() {
+        let (_server, issuer_identifier, issuer_trust_anchors, wrpac_trust_anchors) =
+            start_httpmock_issuer(IssuerMetadataOptions::default()).await;
 
         // Construct a Pre-Authorized Code Credential Offer with a Transaction Code.
+        let tx_code = PreAuthTransactionCode {
+            length: Some(4),
+            description: Some("Please enter the code sent to you by email".to_string()),
+            ..PreAuthTransactionCode::default()
+        };
         let credential_offer = CredentialOffer {
-            credential_issuer,
+            credential_issuer: issuer_identifier,
             credential_configuration_ids: vec_nonempty![CONFIG_ID.clone()].into(),
             grants: Some(Grants {
                 pre_authorized_code: Some(GrantPreAuthorizedCode {
                     pre_authorized_code: "code".to_string().into(),
-                    tx_code: Some(PreAuthTransactionCode::default()),
+                    tx_code: Some(tx_code.clone()),
                     authorization_server: None,
                 }),
                 ..Grants::default()
@@ -1259,14 +1457,31 @@ mod test {
 
         let discovery = HttpIssuanceDiscovery::new(HttpClient::try_new(httpmock_reqwest_client_builder()).unwrap());
 
+        // Starting issuance without a Transaction Code should return the requirements for it.
         let result = discovery
             .start(
                 &offer_url,
+                None,
                 MOCK_WALLET_CLIENT_ID.to_string(),
                 REDIRECT_URI.clone(),
-                &TrustAnchors::empty(),
+                &issuer_trust_anchors,
                 &MockWiaClient::new(),
-                &TrustAnchors::empty(),
+                &wrpac_trust_anchors,
+            )
+            .await;
+
+        assert_matches!(
+            result,
+            Err(WalletIssuanceError::CredentialOfferTxCodeRequired(requirements)) if *requirements == tx_code
+        );
+
+        // Directly starting the Pre-Authorized Code flow does not support a Transaction Code at all.
+        let result = discovery
+            .start_pre_authorized_code_flow(
+                &offer_url,
+                &issuer_trust_anchors,
+                &MockWiaClient::new(),
+                &wrpac_trust_anchors,
             )
             .await;
 
@@ -1299,6 +1514,7 @@ mod test {
         let result = discovery
             .start(
                 &offer_url,
+                None,
                 MOCK_WALLET_CLIENT_ID.to_string(),
                 REDIRECT_URI.clone(),
                 &TrustAnchors::empty(),
@@ -1396,6 +1612,7 @@ mod test {
         let result = discovery
             .start(
                 &offer_url,
+                None,
                 MOCK_WALLET_CLIENT_ID.to_string(),
                 REDIRECT_URI.clone(),
                 &issuer_trust_anchors,
@@ -1436,6 +1653,7 @@ mod test {
         let result = discovery
             .start(
                 &offer_url,
+                None,
                 MOCK_WALLET_CLIENT_ID.to_string(),
                 REDIRECT_URI.clone(),
                 &issuer_trust_anchors,
@@ -1474,6 +1692,7 @@ mod test {
         let error = discovery
             .start(
                 &offer_url,
+                None,
                 MOCK_WALLET_CLIENT_ID.to_string(),
                 REDIRECT_URI.clone(),
                 &issuer_trust_anchors,
@@ -1504,6 +1723,7 @@ mod test {
         let _flow = discovery
             .start(
                 &offer_url,
+                None,
                 MOCK_WALLET_CLIENT_ID.to_string(),
                 REDIRECT_URI.clone(),
                 &issuer_trust_anchors,
@@ -1537,6 +1757,7 @@ mod test {
         let error = discovery
             .start(
                 &offer_url,
+                None,
                 MOCK_WALLET_CLIENT_ID.to_string(),
                 REDIRECT_URI.clone(),
                 &trust_anchor,
@@ -0,0 +1,99 @@
+use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+use base64::prelude::Engine;
+use crypto::utils::sha256;
+use derive_more::Debug;
+use p256::ecdsa::SigningKey;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::WalletIssuanceError;
+
+/// The DPoP private key of an issuance session, as used after the session itself has ended, e.g. to poll for deferred
+/// credentials or to send notifications. Only the identifier of the key is serialized, so that the private key itself
+/// never ends up in persisted state. The caller is responsible for storing the private key separately, using
+/// [`PersistentDpopKey::to_bytes`], and for restoring it after deserializing, using [`PersistentDpopKey::restore`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersistentDpopKey {
+    identifier: String,
+    #[debug(skip)]
+    #[serde(skip)]
+    signing_key: Option<SigningKey>,
+}
+
+impl PersistentDpopKey {
+    pub(super) fn new(signing_key: &SigningKey) -> Self {
+        Self {
+            identifier: Self::identifier_for(signing_key),
+            signing_key: Some(signing_key.clone()),
+        }
+    }
+
+    /// The identifier is derived from the public key, so that every [`PersistentDpopKey`] created for the same session
+    /// refers to the same private key.
+    fn identifier_for(signing_key: &SigningKey) -> String {
+        BASE64_URL_SAFE_NO_PAD.encode(sha256(&signing_key.verifying_key().to_sec1_bytes()))
+    }
+
+    pub fn identifier(&self) -> &str {
+        &self.identifier
+    }
+
+    /// The bytes of the private key, if it is available, i.e. if it was just created or has been restored.
+    pub fn to_bytes(&self) -> Option<Vec<u8>> {
+        self.signing_key
+            .as_ref()
+            .map(|signing_key| signing_key.to_bytes().to_vec())
+    }
+
+    /// Restore the private key from its bytes, checking that these actually belong to the identifier.
+    pub fn restore(&mut self, bytes: &[u8]) -> Result<(), WalletIssuanceError> {
+        let signing_key = SigningKey::from_slice(bytes).map_err(WalletIssuanceError::DpopKeyRestore)?;
+
+        if Self::identifier_for(&signing_key) != self.identifier {
+            return Err(WalletIssuanceError::DpopKeyMismatch);
+        }
+
+        self.signing_key = Some(signing_key);
+
+        Ok(())
+    }
+
+    pub(super) fn signing_key(&self) -> Result<&SigningKey, WalletIssuanceError> {
+        self.signing_key.as_ref().ok_or(WalletIssuanceError::DpopKeyMissing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use p256::elliptic_curve::Generate;
+
+    use super::*;
+
+    #[test]
+    fn test_persistent_dpop_key() {
+        let signing_key = SigningKey::generate();
+        let dpop_key = PersistentDpopKey::new(&signing_key);
+        let bytes = dpop_key.to_bytes().unwrap();
+
+        // The private key should not survive serialization, only its identifier.
+        let json = serde_json::to_value(&dpop_key).unwrap();
+        let mut dpop_key: PersistentDpopKey = serde_json::from_value(json).unwrap();
+
+        assert_eq!(dpop_key.identifier(), PersistentDpopKey::new(&signing_key).identifier());
+        assert!(dpop_key.to_bytes().is_none());
+        assert_matches!(dpop_key.signing_key(), Err(WalletIssuanceError::DpopKeyMissing));
+
+        // A different private key should not be accepted when restoring.
+        let other_bytes = SigningKey::generate().to_bytes().to_vec();
+        assert_matches!(
+            dpop_key.restore(&other_bytes),
+            Err(WalletIssuanceError::DpopKeyMismatch)
+        );
+
+        dpop_key.restore(&bytes).expect("restoring DPoP key should succeed");
+
+        assert_eq!(dpop_key.signing_key().unwrap(), &signing_key);
+    }
+}
@@ -1,8 +1,11 @@
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::convert::identity;
 use std::num::NonZeroU8;
+use std::sync::Arc;
+use std::time::Duration;
 
 use attestation_data::attributes::AttributesTraversalBehaviour;
 use attestation_data::auth::issuer_auth::IssuerRegistration;
@@ -16,9 +19,16 @@ use derive_more::Debug;
 use futures::TryFutureExt;
 use futures::future::try_join_all;
 use futures::try_join;
+use http_utils::reqwest::APPLICATION_JWT;
 use http_utils::reqwest::HttpClient;
+use http_utils::reqwest::parse_content_type;
 use itertools::Either;
 use itertools::Itertools;
+use jwe::algorithm::EcdhAlgorithm;
+use jwe::algorithm::EncryptionAlgorithm;
+use jwe::decryption::ExpectedEncryptionAlgorithm;
+use jwe::decryption::JweDecrypter;
+use jwe::decryption::JweEcdhSecretKey;
 use jwt::nonce::Nonce;
 use jwt::wia::WIA_HEADER_NAME;
 use jwt::wia::WIA_POP_HEADER_NAME;
@@ -38,8 +48,10 @@ use sd_jwt_vc_metadata::ClaimSelectiveDisclosureMetadata;
 use sd_jwt_vc_metadata::NormalizedTypeMetadata;
 use sd_jwt_vc_metadata::SortedTypeMetadataDocuments;
 use sd_jwt_vc_metadata::TypeMetadataDocuments;
+use sd_jwt_vc_metadata::resolver::TypeMetadataResolver;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
+use ssri::Integrity;
 use url::Url;
 use utils::generator::TimeGenerator;
 use utils::single_unique::SingleUnique;
@@ -52,8 +64,14 @@ use wscd::wscd::WiaClient;
 use super::IssuanceSession;
 use super::WalletIssuanceError;
 use super::credential::CredentialWithMetadata;
+use super::credential::IssuanceOutcome;
 use super::credential::IssuedCredentialCopies;
 use super::credential::SdJwtCopy;
+use super::deferred::DeferredIssuance;
+use super::dpop_key::PersistentDpopKey;
+use super::notification::CredentialNotification;
+use super::notification::NotificationContext;
+use super::refresh::CredentialRefresh;
 use crate::authorization_details::IssuerAuthorizationDetails;
 use crate::client_auth::ClientAttestationChallengeMechanism;
 use crate::client_auth::fetch_client_auth_challenge;
@@ -63,12 +81,17 @@ use crate::credential::CredentialRequestProof;
 use crate::credential::CredentialRequestType;
 use crate::credential::CredentialRequests;
 use crate::credential::CredentialResponse;
+use crate::credential::CredentialResponseEncryptionParameters;
 use crate::credential::CredentialResponses;
+use crate::credential::DeferredCredentialRequest;
+use crate::credential::DeferredCredentialResponse;
+use crate::credential::MaybeEncrypted;
 use crate::dpop::DPOP_HEADER_NAME;
 use crate::dpop::DPOP_NONCE_HEADER_NAME;
 use crate::dpop::Dpop;
 use crate::errors::CredentialErrorCode;
 use crate::errors::CredentialPreviewErrorCode;
+use crate::errors::NotificationErrorCode;
 use crate::errors::RemoteErrorCode;
 use crate::errors::RemoteErrorResponse;
 use crate::errors::TokenErrorCode;
@@ -77,6 +100,7 @@ use crate::metadata::issuer_metadata::CredentialConfiguration;
 use crate::metadata::issuer_metadata::CredentialConfigurationId;
 use crate::metadata::issuer_metadata::IssuerEndpoints;
 use crate::nonce::response::NonceResponse;
+use crate::notification::NotificationRequest;
 use crate::preview::CredentialPreviewResponse;
 use crate::scope::Scope;
 use crate::token::AccessToken;
@@ -112,6 +136,14 @@ pub trait VcMessageClient {
 
     async fn request_type_metadata(&self, url: Url) -> Result<TypeMetadataDocuments, WalletIssuanceError>;
 
+    /// Resolve the type metadata for `vct` by retrieving it from the `vct` URL itself, returning `None` if the client
+    /// does not support this. If `vct_integrity` is provided, the leaf document is checked against it.
+    async fn resolve_type_metadata(
+        &self,
+        vct: &str,
+        vct_integrity: Option<Integrity>,
+    ) -> Result<Option<TypeMetadataDocuments>, WalletIssuanceError>;
+
     async fn request_nonce(&self, url: Url) -> Result<(NonceResponse, Option<String>), WalletIssuanceError>;
 
     async fn request_credential(
@@ -120,7 +152,7 @@ pub trait VcMessageClient {
         credential_request: &CredentialRequest,
         dpop_header: &str,
         access_token_header: &str,
-    ) -> Result<CredentialResponse, WalletIssuanceError>;
+    ) -> Result<MaybeEncrypted<CredentialResponse>, WalletIssuanceError>;
 
     async fn request_credentials(
         &self,
@@ -128,19 +160,46 @@ pub trait VcMessageClient {
         credential_requests: &CredentialRequests,
         dpop_header: &str,
         access_token_header: &str,
-    ) -> Result<CredentialResponses, WalletIssuanceError>;
+    ) -> Result<MaybeEncrypted<CredentialResponses>, WalletIssuanceError>;
+
+    async fn request_deferred_credential(
+        &self,
+        url: &Url,
+        deferred_credential_request: &DeferredCredentialRequest,
+        dpop_header: &str,
+        access_token_header: &str,
+    ) -> Result<MaybeEncrypted<DeferredCredentialResponse>, WalletIssuanceError>;
 
     async fn reject(&self, url: &Url, dpop_header: &str, access_token_header: &str) -> Result<(), WalletIssuanceError>;
+
+    async fn send_notification(
+        &self,
+        url: &Url,
+        notification_request: &NotificationRequest,
+        dpop_header: &str,
+        access_token_header: &str,
+    ) -> Result<(), WalletIssuanceError>;
 }
 
 #[derive(Debug)]
 pub struct HttpVcMessageClient {
     http_client: HttpClient,
+    type_metadata_resolver: Option<Arc<TypeMetadataResolver>>,
 }
 
 impl HttpVcMessageClient {
     pub fn new(http_client: HttpClient) -> Self {
-        Self { http_client }
+        Self {
+            http_client,
+            type_metadata_resolver: None,
+        }
+    }
+
+    pub fn with_type_metadata_resolver(self, type_metadata_resolver: Option<Arc<TypeMetadataResolver>>) -> Self {
+        Self {
+            type_metadata_resolver,
+            ..self
+        }
     }
 
     fn dpop_nonce(response: &Response) -> Result<Option<String>, ToStrError> {
@@ -240,6 +299,23 @@ impl VcMessageClient for HttpVcMessageClient {
             .map_err(WalletIssuanceError::TypeMetadataHttp)
     }
 
+    async fn resolve_type_metadata(
+        &self,
+        vct: &str,
+        vct_integrity: Option<Integrity>,
+    ) -> Result<Option<TypeMetadataDocuments>, WalletIssuanceError> {
+        let Some(resolver) = self.type_metadata_resolver.as_ref() else {
+            return Ok(None);
+        };
+
+        let documents = resolver
+            .resolve(vct, vct_integrity.as_ref())
+            .await
+            .map_err(WalletIssuanceError::TypeMetadataResolution)?;
+
+        Ok(Some(documents))
+    }
+
     async fn request_nonce(&self, url: Url) -> Result<(NonceResponse, Option<String>), WalletIssuanceError> {
         let response = self
             .http_client
@@ -261,8 +337,8 @@ impl VcMessageClient for HttpVcMessageClient {
         credential_request: &CredentialRequest,
         dpop_header: &str,
         access_token_header: &str,
-    ) -> Result<CredentialResponse, WalletIssuanceError> {
-        self.request(url, credential_request, dpop_header, access_token_header)
+    ) -> Result<MaybeEncrypted<CredentialResponse>, WalletIssuanceError> {
+        self.request_maybe_encrypted(url, credential_request, dpop_header, access_token_header)
             .await
     }
 
@@ -272,8 +348,21 @@ impl VcMessageClient for HttpVcMessageClient {
         credential_requests: &CredentialRequests,
         dpop_header: &str,
         access_token_header: &str,
-    ) -> Result<CredentialResponses, WalletIssuanceError> {
-        self.request(url, credential_requests, dpop_header, access_token_header)
+    ) -> Result<MaybeEncrypted<CredentialResponses>, WalletIssuanceError> {
+        self.request_maybe_encrypted(url, credential_requests, dpop_header, access_token_header)
+            .await
+    }
+
+    async fn request_deferred_credential(
+        &self,
+        url: &Url,
+        deferred_credential_request: &DeferredCredentialRequest,
+        dpop_header: &str,
+        access_token_header: &str,
+    ) -> Result<MaybeEncrypted<DeferredCredentialResponse>, WalletIssuanceError> {
+        // Note that the issuer responds with HTTP status code 202 while the credentials are still pending,
+        // the response body of which is handled by the untagged `DeferredCredentialResponse`.
+        self.request_maybe_encrypted(url, deferred_credential_request, dpop_header, access_token_header)
             .await
     }
 
@@ -303,16 +392,84 @@ impl VcMessageClient for HttpVcMessageClient {
             .await?;
         Ok(())
     }
+
+    async fn send_notification(
+        &self,
+        url: &Url,
+        notification_request: &NotificationRequest,
+        dpop_header: &str,
+        access_token_header: &str,
+    ) -> Result<(), WalletIssuanceError> {
+        self.http_client
+            .post(url.as_ref(), |builder| {
+                builder
+                    .header(DPOP_HEADER_NAME, dpop_header)
+                    .header(AUTHORIZATION, access_token_header)
+                    .json(notification_request)
+            })
+            .map_err(WalletIssuanceError::NotificationHttp)
+            .and_then(|response| async {
+                // If the HTTP response code is 4xx or 5xx, parse the JSON as an error
+                let status = response.status();
+
+                if status.is_client_error() || status.is_server_error() {
+                    let error = response
+                        .json::<RemoteErrorResponse<NotificationErrorCode>>()
+                        .await
+                        .map_err(WalletIssuanceError::NotificationHttp)?;
+
+                    Err(WalletIssuanceError::Notification(Box::new(error)))
+                } else {
+                    Ok(())
+                }
+            })
+            .await
+    }
 }
 
 impl HttpVcMessageClient {
-    async fn request<T: Serialize, S: DeserializeOwned>(
+    /// Send a request whose response may be encrypted by the issuer, in which case it is returned as a compact JWE
+    /// with the `application/jwt` content type.
+    async fn request_maybe_encrypted<T: Serialize, S: DeserializeOwned>(
         &self,
         url: &Url,
         request: &T,
         dpop_header: &str,
         access_token_header: &str,
-    ) -> Result<S, WalletIssuanceError> {
+    ) -> Result<MaybeEncrypted<S>, WalletIssuanceError> {
+        let response = self
+            .send_request(url, request, dpop_header, access_token_header)
+            .await?;
+
+        let is_encrypted =
+            parse_content_type(&response).is_some_and(|content_type| content_type.essence_str() == APPLICATION_JWT);
+
+        let response = if is_encrypted {
+            MaybeEncrypted::Encrypted(
+                response
+                    .text()
+                    .await
+                    .map_err(WalletIssuanceError::CredentialRequestHttp)?,
+            )
+        } else {
+            MaybeEncrypted::Plain(
+                response
+                    .json()
+                    .await
+                    .map_err(WalletIssuanceError::CredentialRequestHttp)?,
+            )
+        };
+
+        Ok(response)
+    }
+
+    async fn send_request<T: Serialize>(
+        &self,
+        url: &Url,
+        request: &T,
+        dpop_header: &str,
+        access_token_header: &str,
+    ) -> Result<Response, WalletIssuanceError> {
         self.http_client
             .post(url.as_ref(), |builder| {
                 builder
@@ -333,11 +490,6 @@ impl HttpVcMessageClient {
 
                     Err(WalletIssuanceError::CredentialRequest(Box::new(error)))
                 } else {
-                    let response = response
-                        .json()
-                        .await
-                        .map_err(WalletIssuanceError::CredentialRequestHttp)?;
-
                     Ok(response)
                 }
             })
@@ -352,6 +504,7 @@ struct IssuanceState {
     credential_issuer: IssuerIdentifier,
     issuer_endpoints: IssuerEndpoints,
     batch_size: NonZeroU8,
+    response_encryption: Option<EncryptionAlgorithm>,
     credential_previews: VecNonEmpty<CredentialPreview>,
     credential_request_types: VecNonEmpty<CredentialRequestType>,
     type_metadata: HashMap<String, IssuanceTypeMetadata>,
@@ -359,6 +512,7 @@ struct IssuanceState {
     #[debug(skip)]
     dpop_signing_key: SigningKey,
     dpop_nonce: Option<String>,
+    refresh: Option<CredentialRefresh>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -440,17 +594,24 @@ fn credential_request_types_from_preview(
 /// valid" signal. And the genuine "no longer valid" case — the session expiring or being consumed between the
 /// authorization callback and the subsequent token request — is practically unreachable in the current implementation.
 /// So the generic error handling is used.
+///
+/// When a Transaction Code was sent along with the pre-authorized code, `invalid_grant` is also returned for an
+/// incorrect Transaction Code. As the user may then retry with another code, this is translated to a separate error.
 fn map_pre_authorized_token_error(error: WalletIssuanceError, token_request: &TokenRequest) -> WalletIssuanceError {
-    let is_pre_authorized = matches!(
-        token_request.grant_type,
-        TokenRequestGrantType::PreAuthorizedCode { .. }
-    );
+    let (is_pre_authorized, has_tx_code) = match &token_request.grant_type {
+        TokenRequestGrantType::PreAuthorizedCode { tx_code, .. } => (true, tx_code.is_some()),
+        _ => (false, false),
+    };
 
     match &error {
         WalletIssuanceError::TokenRequest(response)
             if is_pre_authorized && response.error == RemoteErrorCode::Known(TokenErrorCode::InvalidGrant) =>
         {
-            WalletIssuanceError::PreAuthorizedCodeExpired
+            if has_tx_code {
+                WalletIssuanceError::PreAuthorizedTxCodeRejected
+            } else {
+                WalletIssuanceError::PreAuthorizedCodeExpired
+            }
         }
         _ => error,
     }
@@ -464,19 +625,56 @@ impl<H: VcMessageClient> HttpIssuanceSession<H> {
         credential_issuer: IssuerIdentifier,
         issuer_endpoints: IssuerEndpoints,
         batch_size: NonZeroU8,
+        response_encryption: Option<EncryptionAlgorithm>,
         token_endpoint: &Url,
         client_auth_challenge: ClientAttestationChallengeMechanism,
         token_request: TokenRequest,
         wia_client: &impl WiaClient,
         auth_server_identifier: &IssuerIdentifier,
         trust_anchors: &TrustAnchors,
+    ) -> Result<Self, WalletIssuanceError> {
+        Self::create_with_dpop_signing_key(
+            message_client,
+            credential_configurations,
+            credential_issuer,
+            issuer_endpoints,
+            batch_size,
+            response_encryption,
+            token_endpoint,
+            client_auth_challenge,
+            token_request,
+            wia_client,
+            auth_server_identifier,
+            trust_anchors,
+            SigningKey::generate(),
+        )
+        .await
+    }
+
+    /// Same as [`HttpIssuanceSession::create`], but using an existing DPoP key instead of generating a new one. This is
+    /// necessary when redeeming a refresh token, as the issuer binds these to the DPoP key that was used to obtain
+    /// them.
+    #[expect(clippy::too_many_arguments, reason = "constructor method")]
+    pub(crate) async fn create_with_dpop_signing_key(
+        message_client: H,
+        credential_configurations: HashMap<CredentialConfigurationId, CredentialConfiguration>,
+        credential_issuer: IssuerIdentifier,
+        issuer_endpoints: IssuerEndpoints,
+        batch_size: NonZeroU8,
+        response_encryption: Option<EncryptionAlgorithm>,
+        token_endpoint: &Url,
+        client_auth_challenge: ClientAttestationChallengeMechanism,
+        token_request: TokenRequest,
+        wia_client: &impl WiaClient,
+        auth_server_identifier: &IssuerIdentifier,
+        trust_anchors: &TrustAnchors,
+        dpop_signing_key: SigningKey,
     ) -> Result<Self, WalletIssuanceError> {
         let credential_preview_endpoint = issuer_endpoints
             .credential_preview_endpoint
             .as_ref()
             .ok_or(WalletIssuanceError::NoCredentialPreviewEndpoint)?; // TODO (PVW-5559): skip preview when no credential preview endpoint
 
-        let dpop_signing_key = SigningKey::generate();
         let dpop_header = Dpop::new(&dpop_signing_key, token_endpoint.clone(), &Method::POST, None, None)?;
 
         let challenge = match client_auth_challenge {
@@ -506,6 +704,18 @@ impl<H: VcMessageClient> HttpIssuanceSession<H> {
         // TODO (PVW-5554): Store the offered credential configurations in the `IssuanceState` in order to request these
         //                  from the new credential endpoint.
 
+        // If the issuer handed out a refresh token, retain it together with everything else needed to redeem it later.
+        let refresh = token_response.refresh_token.map(|refresh_token| CredentialRefresh {
+            refresh_token,
+            credential_issuer: credential_issuer.clone(),
+            authorization_server: auth_server_identifier.clone(),
+            credential_configuration_ids: offered_credential_configs
+                .iter()
+                .map(|OfferedCredentialConfig { config_id, .. }| config_id.clone())
+                .collect(),
+            dpop_signing_key: dpop_signing_key.to_bytes().to_vec(),
+        });
+
         let credential_config_iter = offered_credential_configs
             .iter()
             .map(|OfferedCredentialConfig { config_id, config, .. }| (config_id, config));
@@ -545,12 +755,14 @@ impl<H: VcMessageClient> HttpIssuanceSession<H> {
             credential_issuer,
             issuer_endpoints,
             batch_size,
+            response_encryption,
             credential_previews,
             credential_request_types,
             type_metadata,
             issuer_registration,
             dpop_signing_key,
             dpop_nonce,
+            refresh,
         };
 
         let issuance_client = Self {
@@ -681,22 +893,39 @@ impl<H: VcMessageClient> HttpIssuanceSession<H> {
         message_client: &H,
     ) -> Result<HashMap<String, IssuanceTypeMetadata>, WalletIssuanceError> {
         // Get the metadata URI and attestation_type for each credential configuration, while collecting any Credential
-        // Configuration IDs for which no type metadata URI is given.
-        let (configs_data, missing_uri_config_ids): (Vec<_>, Vec<_>) = credential_configurations
+        // Configurations for which no type metadata URI is given.
+        let (configs_data, missing_uri_configs): (Vec<_>, Vec<_>) = credential_configurations
             .into_iter()
-            .partition_map(|(config_id, config)| match config.type_metadata_uri.as_ref() {
-                Some(uri) => {
-                    let attestation_type = config
-                        .format
-                        .attestation_type()
-                        // TODO (PVW-6161): Handle unsupported formats earlier and more consistently.
-                        .expect("unsupported format");
-
-                    Either::Left((uri, attestation_type))
+            .partition_map(|(config_id, config)| {
+                let attestation_type = config
+                    .format
+                    .attestation_type()
+                    // TODO (PVW-6161): Handle unsupported formats earlier and more consistently.
+                    .expect("unsupported format");
+
+                match config.type_metadata_uri.as_ref() {
+                    Some(uri) => Either::Left((uri, attestation_type)),
+                    None => Either::Right((config_id, attestation_type)),
                 }
-                None => Either::Right(config_id.clone()),
             });
 
+        // If no type metadata URI is given, the type metadata may still be retrieved from the attestation type itself,
+        // provided that it is an HTTPS URL and the message client supports this.
+        let mut resolved_documents = Vec::new();
+        let mut missing_uri_config_ids = Vec::new();
+        for (config_id, attestation_type) in missing_uri_configs {
+            let documents = match TypeMetadataResolver::vct_url(attestation_type) {
+                // The integrity of the leaf document is not known yet, this is checked when the credential is received.
+                Some(_) => message_client.resolve_type_metadata(attestation_type, None).await?,
+                None => None,
+            };
+
+            match documents {
+                Some(documents) => resolved_documents.push((attestation_type, documents)),
+                None => missing_uri_config_ids.push(config_id.clone()),
+            }
+        }
+
         // TODO (PVW-5547): Use Credential Metadata from Issuer Metadata if type metadata URI is not present.
         if !missing_uri_config_ids.is_empty() {
             return Err(WalletIssuanceError::TypeMetadataUriMissing(missing_uri_config_ids));
@@ -741,11 +970,21 @@ impl<H: VcMessageClient> HttpIssuanceSession<H> {
             )));
         }
 
-        // Fetch type metadata documents from URIs, then normalize the chain of documents.
-        let metadata_per_attestation_type = try_join_all(attestation_types_and_uris.into_iter().map(
+        // Fetch type metadata documents from URIs, then normalize the chain of documents, including those resolved
+        // above.
+        let fetched_documents = try_join_all(attestation_types_and_uris.into_iter().map(
             async |(attestation_type, uri)| -> Result<_, WalletIssuanceError> {
                 let documents = message_client.request_type_metadata(uri.as_url().clone()).await?;
 
+                Ok((attestation_type, documents))
+            },
+        ))
+        .await?;
+
+        let metadata_per_attestation_type = fetched_documents
+            .into_iter()
+            .chain(resolved_documents)
+            .map(|(attestation_type, documents)| -> Result<_, WalletIssuanceError> {
                 let (normalized_metadata, raw_metadata) = documents
                     .into_normalized(attestation_type)
                     .map_err(WalletIssuanceError::TypeMetadataVerification)?;
@@ -756,11 +995,8 @@ impl<H: VcMessageClient> HttpIssuanceSession<H> {
                 };
 
                 Ok((attestation_type.to_string(), metadata))
-            },
-        ))
-        .await?
-        .into_iter()
-        .collect();
+            })
+            .try_collect()?;
 
         Ok(metadata_per_attestation_type)
     }
@@ -771,7 +1007,7 @@ impl<H: VcMessageClient> IssuanceSession for HttpIssuanceSession<H> {
         &mut self,
         trust_anchors: &TrustAnchors,
         wscd: &W,
-    ) -> Result<Vec<CredentialWithMetadata>, WalletIssuanceError>
+    ) -> Result<IssuanceOutcome, WalletIssuanceError>
     where
         W: IssuanceWscd,
     {
@@ -840,6 +1076,7 @@ impl<H: VcMessageClient> IssuanceSession for HttpIssuanceSession<H> {
                     let cred_request = CredentialRequest {
                         credential_type: credential_request_type.into(),
                         proof: Some(proof),
+                        credential_response_encryption: None,
                     };
 
                     Ok::<_, WalletIssuanceError>(((pubkey, id), cred_request))
@@ -854,7 +1091,7 @@ impl<H: VcMessageClient> IssuanceSession for HttpIssuanceSession<H> {
             1 => {
                 let credential_request = credential_requests.pop().unwrap();
                 vec![
-                    self.request_credential(credential_endpoint_url, &credential_request)
+                    self.request_credential(credential_endpoint_url, credential_request)
                         .await?,
                 ]
             }
@@ -864,110 +1101,39 @@ impl<H: VcMessageClient> IssuanceSession for HttpIssuanceSession<H> {
                     .await?
             }
         };
-        let mut responses_and_pubkeys: VecDeque<_> = responses.into_iter().zip(pubkeys).collect();
-
-        let docs = self
-            .session_state
-            .credential_previews
-            .iter()
-            // TODO (PVW-5554): reduce code duplication in the format arms
-            .map(|preview| {
-                let copy_count = usize::from(self.session_state.batch_size.get());
-
-                // Get type metadata of attestation type
-                let Some(type_metadata) = self
-                    .session_state
-                    .type_metadata
-                    .get(&preview.credential_payload.attestation_type)
-                else {
-                    Err(WalletIssuanceError::TypeMetadataNotFound(
-                        preview.credential_payload.attestation_type.clone(),
-                    ))?
-                };
-
-                // Consume the amount of copies from the front of `responses_and_keys`.
-                let copies = match preview.format {
-                    Format::MsoMdoc => IssuedCredentialCopies::Mdoc(
-                        responses_and_pubkeys
-                            .drain(..copy_count)
-                            .map(|(cred_response, (pubkey, key_id))| {
-                                let credential = cred_response
-                                    .into_immediate_credential()
-                                    .ok_or(WalletIssuanceError::DeferredIssuanceUnsupported)?;
-
-                                credential.into_issued_mdoc(
-                                    key_id,
-                                    &pubkey,
-                                    preview,
-                                    &type_metadata.normalized_metadata,
-                                    trust_anchors,
-                                )
-                            })
-                            .collect::<Result<Vec<_>, _>>()?
-                            .try_into()
-                            .expect("the resulting vector is never empty since 'copies' is nonzero"),
-                    ),
-                    Format::SdJwt => IssuedCredentialCopies::SdJwt(
-                        responses_and_pubkeys
-                            .drain(..copy_count)
-                            .map(|(cred_response, (pubkey, key_id))| {
-                                let credential = cred_response
-                                    .into_immediate_credential()
-                                    .ok_or(WalletIssuanceError::DeferredIssuanceUnsupported)?;
-
-                                credential.into_issued_sd_jwt(
-                                    key_id,
-                                    &pubkey,
-                                    preview,
-                                    &type_metadata.normalized_metadata,
-                                    trust_anchors,
-                                )
-                            })
-                            .collect::<Result<Vec<_>, _>>()?
-                            .try_into()
-                            .expect("the resulting vector is never empty since 'copy_count' is nonzero"),
-                    ),
-                };
-
-                // Verify that each of the resulting credentials contain exactly the same metadata integrity digest.
-                let unique_integrities: HashSet<_> = match &copies {
-                    IssuedCredentialCopies::Mdoc(mdocs) => mdocs
-                        .iter()
-                        .map(|mdoc| mdoc.type_metadata_integrity().map_err(WalletIssuanceError::Metadata))
-                        .try_collect()?,
-                    IssuedCredentialCopies::SdJwt(sd_jwts) => sd_jwts
-                        .iter()
-                        .map(|sd_jwt_copy| {
-                            sd_jwt_copy
-                                .sd_jwt
-                                .claims()
-                                .vct_integrity
-                                .as_ref()
-                                .ok_or(WalletIssuanceError::MetadataIntegrityMissing)
-                        })
-                        .try_collect()?,
-                };
-                let integrity = unique_integrities
-                    .into_iter()
-                    .exactly_one()
-                    .map_err(|_| WalletIssuanceError::MetadataIntegrityInconsistent)?;
+        // If the issuer defers issuance, it responds with a single deferred response for all of the credentials.
+        if let Some(CredentialResponse::Deferred {
+            transaction_id,
+            interval,
+        }) = responses.first()
+        {
+            let deferred_issuance =
+                self.session_state
+                    .to_deferred_issuance(transaction_id.clone(), *interval, pubkeys)?;
 
-                // Check that the integrity hash received in the credential matches
-                // that of encoded JSON of the first metadata document.
-                let verified_metadata = type_metadata.raw_metadata.clone().into_verified(integrity.clone())?;
+            return Ok(IssuanceOutcome::Deferred(deferred_issuance));
+        }
 
-                Ok::<_, WalletIssuanceError>(CredentialWithMetadata::new(
-                    copies,
-                    preview.credential_payload.attestation_type.clone(),
-                    preview.credential_payload.expires,
-                    preview.credential_payload.not_before,
-                    type_metadata.normalized_metadata.extended_vcts(),
-                    verified_metadata,
-                ))
-            })
-            .try_collect()?;
+        let notification_context = self.session_state.notification_context();
+        let credentials = retry_on_type_metadata_integrity(
+            &self.session_state.type_metadata,
+            &self.message_client,
+            |type_metadata| {
+                process_credential_responses(
+                    responses.clone(),
+                    pubkeys.clone(),
+                    &self.session_state.credential_previews,
+                    self.session_state.batch_size,
+                    type_metadata,
+                    notification_context.as_ref(),
+                    self.session_state.refresh.as_ref(),
+                    trust_anchors,
+                )
+            },
+        )
+        .await?;
 
-        Ok(docs)
+        Ok(IssuanceOutcome::Issued(credentials))
     }
 
     async fn reject_issuance(&self) -> Result<(), WalletIssuanceError> {
@@ -1005,16 +1171,23 @@ impl<H: VcMessageClient> HttpIssuanceSession<H> {
     async fn request_credential(
         &self,
         url: &Url,
-        credential_request: &CredentialRequest,
+        mut credential_request: CredentialRequest,
     ) -> Result<CredentialResponse, WalletIssuanceError> {
         let (dpop_header, access_token_header) = self.session_state.auth_headers(url.clone(), &Method::POST)?;
 
+        let decrypter = self
+            .session_state
+            .response_encryption
+            .map(CredentialResponseDecrypter::new_random);
+        credential_request.credential_response_encryption =
+            decrypter.as_ref().map(CredentialResponseDecrypter::to_parameters);
+
         let response = self
             .message_client
-            .request_credential(url, credential_request, &dpop_header, &access_token_header)
+            .request_credential(url, &credential_request, &dpop_header, &access_token_header)
             .await?;
 
-        Ok(response)
+        CredentialResponseDecrypter::decrypt(decrypter.as_ref(), response)
     }
 
     async fn request_batch_credentials(
@@ -1024,20 +1197,31 @@ impl<H: VcMessageClient> HttpIssuanceSession<H> {
     ) -> Result<Vec<CredentialResponse>, WalletIssuanceError> {
         let (dpop_header, access_token_header) = self.session_state.auth_headers(url.clone(), &Method::POST)?;
 
-        let expected_response_count = credential_requests.len().get();
+        let decrypter = self
+            .session_state
+            .response_encryption
+            .map(CredentialResponseDecrypter::new_random);
+        let credential_requests = CredentialRequests {
+            credential_requests,
+            credential_response_encryption: decrypter.as_ref().map(CredentialResponseDecrypter::to_parameters),
+        };
+
+        let expected_response_count = credential_requests.credential_requests.len().get();
         let responses = self
             .message_client
-            .request_credentials(
-                url,
-                &CredentialRequests { credential_requests },
-                &dpop_header,
-                &access_token_header,
-            )
+            .request_credentials(url, &credential_requests, &dpop_header, &access_token_header)
             .await?;
+        let responses = CredentialResponseDecrypter::decrypt(decrypter.as_ref(), responses)?;
 
         // The server must have responded with enough credential responses, N, so that the caller has exactly enough
-        // responses for all copies of all credentials constructed.
-        if responses.credential_responses.len() != expected_response_count {
+        // responses for all copies of all credentials constructed, unless it deferred issuance of all credentials.
+        let is_deferred = responses.credential_responses.len() == 1
+            && responses
+                .credential_responses
+                .first()
+                .is_some_and(CredentialResponse::is_deferred);
+
+        if !is_deferred && responses.credential_responses.len() != expected_response_count {
             return Err(WalletIssuanceError::UnexpectedCredentialResponseCount {
                 found: responses.credential_responses.len(),
                 expected: expected_response_count,
@@ -1048,6 +1232,238 @@ impl<H: VcMessageClient> HttpIssuanceSession<H> {
     }
 }
 
+/// Ephemeral key with which the wallet requests the issuer to encrypt a Credential Response, generated per request.
+pub(super) struct CredentialResponseDecrypter {
+    secret_key: JweEcdhSecretKey,
+    encryption_algorithm: EncryptionAlgorithm,
+}
+
+impl CredentialResponseDecrypter {
+    pub(super) fn new_random(encryption_algorithm: EncryptionAlgorithm) -> Self {
+        Self {
+            secret_key: JweEcdhSecretKey::new_random(None, EcdhAlgorithm::EcdhEs),
+            encryption_algorithm,
+        }
+    }
+
+    pub(super) fn to_parameters(&self) -> CredentialResponseEncryptionParameters {
+        CredentialResponseEncryptionParameters {
+            jwk: self.secret_key.to_jwe_public_key().into(),
+            enc: self.encryption_algorithm.into(),
+            zip: None,
+        }
+    }
+
+    /// Decrypt the response if encryption was requested, making sure that the issuer actually did encrypt it.
+    pub(super) fn decrypt<T: DeserializeOwned>(
+        decrypter: Option<&Self>,
+        response: MaybeEncrypted<T>,
+    ) -> Result<T, WalletIssuanceError> {
+        match (decrypter, response) {
+            (None, MaybeEncrypted::Plain(response)) => Ok(response),
+            (Some(decrypter), MaybeEncrypted::Encrypted(jwe)) => {
+                JweDecrypter::from_ecdh_secret_key(&decrypter.secret_key)
+                    .decrypt_json(
+                        &jwe,
+                        ExpectedEncryptionAlgorithm::Algorithms(std::slice::from_ref(&decrypter.encryption_algorithm)),
+                    )
+                    .map_err(WalletIssuanceError::CredentialResponseDecryption)
+            }
+            (Some(_), MaybeEncrypted::Plain(_)) => Err(WalletIssuanceError::MissingCredentialResponseEncryption),
+            (None, MaybeEncrypted::Encrypted(_)) => Err(WalletIssuanceError::UnexpectedEncryptedCredentialResponse),
+        }
+    }
+}
+
+/// Call `process`, which verifies issued credentials against `type_metadata`. If this fails because the type metadata
+/// of an attestation type does not match the integrity digest in its credentials, that type metadata may have been
+/// resolved from a stale cache or have changed at its `vct` URL in the meantime. In that case it is resolved once more,
+/// this time checking it against the integrity digest from the credentials, after which `process` is called again.
+pub(super) async fn retry_on_type_metadata_integrity<T>(
+    type_metadata: &HashMap<String, IssuanceTypeMetadata>,
+    message_client: &impl VcMessageClient,
+    process: impl Fn(&HashMap<String, IssuanceTypeMetadata>) -> Result<T, WalletIssuanceError>,
+) -> Result<T, WalletIssuanceError> {
+    let mut type_metadata = Cow::Borrowed(type_metadata);
+    let mut resolved_attestation_types = HashSet::new();
+
+    loop {
+        match process(&type_metadata) {
+            // Resolve the type metadata for every attestation type at most once, to prevent an endless loop.
+            Err(WalletIssuanceError::TypeMetadataIntegrity {
+                attestation_type,
+                integrity,
+                error,
+            }) if TypeMetadataResolver::vct_url(&attestation_type).is_some()
+                && resolved_attestation_types.insert(attestation_type.clone()) =>
+            {
+                let Some(documents) = message_client
+                    .resolve_type_metadata(&attestation_type, Some(integrity.clone()))
+                    .await?
+                else {
+                    return Err(WalletIssuanceError::TypeMetadataIntegrity {
+                        attestation_type,
+                        integrity,
+                        error,
+                    });
+                };
+
+                let (normalized_metadata, raw_metadata) = documents
+                    .into_normalized(&attestation_type)
+                    .map_err(WalletIssuanceError::TypeMetadataVerification)?;
+
+                type_metadata.to_mut().insert(
+                    attestation_type,
+                    IssuanceTypeMetadata {
+                        normalized_metadata,
+                        raw_metadata,
+                    },
+                );
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Process the Credential Responses received from the issuer into credentials, by verifying these against both the
+/// previews and the type metadata. The responses and holder public keys are expected to be ordered by preview, with
+/// `batch_size` copies for each preview. If the issuer supports notifications, these are attached to the credentials
+/// using the `notification_id` of the first copy of each credential. Likewise, if the issuer handed out a refresh
+/// token, this is attached to every credential.
+#[expect(clippy::too_many_arguments, reason = "Shared between regular and deferred issuance")]
+pub(super) fn process_credential_responses(
+    responses: Vec<CredentialResponse>,
+    pubkeys: Vec<(PublicKey, String)>,
+    credential_previews: &VecNonEmpty<CredentialPreview>,
+    batch_size: NonZeroU8,
+    type_metadata: &HashMap<String, IssuanceTypeMetadata>,
+    notification_context: Option<&NotificationContext>,
+    refresh: Option<&CredentialRefresh>,
+    trust_anchors: &TrustAnchors,
+) -> Result<Vec<CredentialWithMetadata>, WalletIssuanceError> {
+    let mut responses_and_pubkeys: VecDeque<_> = responses.into_iter().zip(pubkeys).collect();
+
+    let docs = credential_previews
+        .iter()
+        // TODO (PVW-5554): reduce code duplication in the format arms
+        .map(|preview| {
+            let copy_count = usize::from(batch_size.get());
+
+            // Get type metadata of attestation type
+            let Some(type_metadata) = type_metadata.get(&preview.credential_payload.attestation_type) else {
+                Err(WalletIssuanceError::TypeMetadataNotFound(
+                    preview.credential_payload.attestation_type.clone(),
+                ))?
+            };
+
+            let notification = notification_context
+                .zip(
+                    responses_and_pubkeys
+                        .front()
+                        .and_then(|(cred_response, _)| cred_response.notification_id()),
+                )
+                .map(|(context, notification_id)| CredentialNotification {
+                    notification_id: notification_id.to_string(),
+                    context: context.clone(),
+                });
+
+            // Consume the amount of copies from the front of `responses_and_keys`.
+            let copies = match preview.format {
+                Format::MsoMdoc => IssuedCredentialCopies::Mdoc(
+                    responses_and_pubkeys
+                        .drain(..copy_count)
+                        .map(|(cred_response, (pubkey, key_id))| {
+                            let credential = cred_response
+                                .into_immediate_credential()
+                                .ok_or(WalletIssuanceError::DeferredIssuanceUnsupported)?;
+
+                            credential.into_issued_mdoc(
+                                key_id,
+                                &pubkey,
+                                preview,
+                                &type_metadata.normalized_metadata,
+                                trust_anchors,
+                            )
+                        })
+                        .collect::<Result<Vec<_>, _>>()?
+                        .try_into()
+                        .expect("the resulting vector is never empty since 'copies' is nonzero"),
+                ),
+                Format::SdJwt => IssuedCredentialCopies::SdJwt(
+                    responses_and_pubkeys
+                        .drain(..copy_count)
+                        .map(|(cred_response, (pubkey, key_id))| {
+                            let credential = cred_response
+                                .into_immediate_credential()
+                                .ok_or(WalletIssuanceError::DeferredIssuanceUnsupported)?;
+
+                            credential.into_issued_sd_jwt(
+                                key_id,
+                                &pubkey,
+                                preview,
+                                &type_metadata.normalized_metadata,
+                                trust_anchors,
+                            )
+                        })
+                        .collect::<Result<Vec<_>, _>>()?
+                        .try_into()
+                        .expect("the resulting vector is never empty since 'copy_count' is nonzero"),
+                ),
+            };
+
+            // Verify that each of the resulting credentials contain exactly the same metadata integrity digest.
+            let unique_integrities: HashSet<_> = match &copies {
+                IssuedCredentialCopies::Mdoc(mdocs) => mdocs
+                    .iter()
+                    .map(|mdoc| mdoc.type_metadata_integrity().map_err(WalletIssuanceError::Metadata))
+                    .try_collect()?,
+                IssuedCredentialCopies::SdJwt(sd_jwts) => sd_jwts
+                    .iter()
+                    .map(|sd_jwt_copy| {
+                        sd_jwt_copy
+                            .sd_jwt
+                            .claims()
+                            .vct_integrity
+                            .as_ref()
+                            .ok_or(WalletIssuanceError::MetadataIntegrityMissing)
+                    })
+                    .try_collect()?,
+            };
+            let integrity = unique_integrities
+                .into_iter()
+                .exactly_one()
+                .map_err(|_| WalletIssuanceError::MetadataIntegrityInconsistent)?;
+
+            // Check that the integrity hash received in the credential matches
+            // that of encoded JSON of the first metadata document.
+            let verified_metadata = type_metadata
+                .raw_metadata
+                .clone()
+                .into_verified(integrity.clone())
+                .map_err(|error| WalletIssuanceError::TypeMetadataIntegrity {
+                    attestation_type: preview.credential_payload.attestation_type.clone(),
+                    integrity: integrity.clone(),
+                    error,
+                })?;
+
+            let credential = CredentialWithMetadata::new(
+                copies,
+                preview.credential_payload.attestation_type.clone(),
+                preview.credential_payload.expires,
+                preview.credential_payload.not_before,
+                type_metadata.normalized_metadata.extended_vcts(),
+                verified_metadata,
+            )
+            .with_notification(notification)
+            .with_refresh(refresh.cloned());
+
+            Ok::<_, WalletIssuanceError>(credential)
+        })
+        .try_collect()?;
+
+    Ok(docs)
+}
+
 impl Credential {
     /// Create an mdoc out of the credential response. Also verifies the credential.
     fn into_issued_mdoc(
@@ -1226,6 +1642,61 @@ impl IssuanceState {
 
         Ok((dpop_header.to_string(), access_token_header))
     }
+
+    /// Capture everything needed to later notify the issuer about the status of the issued credentials, if the issuer
+    /// supports this.
+    fn notification_context(&self) -> Option<NotificationContext> {
+        self.issuer_endpoints
+            .notification_endpoint
+            .as_ref()
+            .map(|notification_endpoint| NotificationContext {
+                notification_endpoint: notification_endpoint.as_url().clone(),
+                access_token: self.access_token.clone(),
+                dpop_key: PersistentDpopKey::new(&self.dpop_signing_key),
+                dpop_nonce: self.dpop_nonce.clone(),
+            })
+    }
+
+    /// Capture everything needed to later poll the issuer for the credentials and process these, after the issuer has
+    /// deferred issuance.
+    fn to_deferred_issuance(
+        &self,
+        transaction_id: String,
+        interval: Duration,
+        holder_keys: Vec<(PublicKey, String)>,
+    ) -> Result<DeferredIssuance, WalletIssuanceError> {
+        let deferred_credential_endpoint = self
+            .issuer_endpoints
+            .deferred_credential_endpoint
+            .as_ref()
+            .ok_or(WalletIssuanceError::NoDeferredCredentialEndpoint)?
+            .as_url()
+            .clone();
+
+        let type_metadata = self
+            .type_metadata
+            .iter()
+            .map(|(attestation_type, metadata)| (attestation_type.clone(), metadata.raw_metadata.clone().into()))
+            .collect();
+
+        let deferred_issuance = DeferredIssuance {
+            transaction_id,
+            interval,
+            deferred_credential_endpoint,
+            access_token: self.access_token.clone(),
+            dpop_key: PersistentDpopKey::new(&self.dpop_signing_key),
+            dpop_nonce: self.dpop_nonce.clone(),
+            batch_size: self.batch_size,
+            response_encryption: self.response_encryption,
+            credential_previews: self.credential_previews.clone(),
+            type_metadata,
+            holder_keys,
+            issuer_registration: self.issuer_registration.clone(),
+            notification_context: self.notification_context(),
+        };
+
+        Ok(deferred_issuance)
+    }
 }
 
 #[cfg(test)]
@@ -1258,6 +1729,9 @@ mod tests {
     use crypto::x509::CertificateError;
     use derive_more::Debug;
     use futures::FutureExt;
+    use jwe::encryption::JweCompression;
+    use jwe::encryption::JweEncrypter;
+    use jwe::encryption::JwePublicKey;
     use jwt::jwk::jwk_to_public_key;
     use jwt::nonce::Nonce;
     use mdoc::utils::serialization::TaggedBytes;
@@ -1269,9 +1743,9 @@ mod tests {
     use sd_jwt::test::conceal_and_sign;
     use sd_jwt_vc_metadata::TypeMetadata;
     use sd_jwt_vc_metadata::TypeMetadataDocuments;
+    use sd_jwt_vc_metadata::UncheckedTypeMetadata;
     use serde_bytes::ByteBuf;
     use serde_json::json;
-    use ssri::Integrity;
     use utils::generator::mock::MockTimeGenerator;
     use utils::vec_nonempty;
     use wscd::mock_remote::MockRemoteWscd;
@@ -1315,6 +1789,10 @@ mod tests {
         use crate::token::AuthorizationCode;
 
         let pre_authorized = TokenRequest::new_pre_authorized(AuthorizationCode::from("the-code".to_string()));
+        let pre_authorized_with_tx_code = TokenRequest::new_pre_authorized_with_tx_code(
+            AuthorizationCode::from("the-code".to_string()),
+            Some("1234".to_string()),
+        );
         let authorization_code = TokenRequest::new_authorization_code(
             AuthorizationCode::from("the-code".to_string()),
             "https://example.com/redirect".parse().unwrap(),
@@ -1327,6 +1805,12 @@ mod tests {
             WalletIssuanceError::PreAuthorizedCodeExpired
         );
 
+        // With a Transaction Code, invalid_grant may also mean that the Transaction Code was incorrect.
+        assert_matches!(
+            map_pre_authorized_token_error(invalid_grant_error(), &pre_authorized_with_tx_code),
+            WalletIssuanceError::PreAuthorizedTxCodeRejected
+        );
+
         // Authorization-code flow: invalid_grant is shared with PKCE / client_id failures, so it must
         // not be translated.
         assert_matches!(
@@ -1401,6 +1885,7 @@ mod tests {
             issuer_metadata.credential_issuer,
             issuer_metadata.endpoints,
             batch_size,
+            None,
             &oauth_metadata.token_endpoint,
             mechanism,
             TokenRequest::new_mock(),
@@ -1482,9 +1967,17 @@ mod tests {
         mock_msg_client
             .expect_request_challenge()
             .return_once(move |_url| Ok("challenge".to_string().into()));
+        let resolved_type_metadata = type_metadata.clone();
         mock_msg_client
             .expect_request_type_metadata()
             .return_once(move |_url| Ok(TypeMetadataDocuments::from_single_example(type_metadata).2));
+        mock_msg_client
+            .expect_resolve_type_metadata()
+            .return_once(move |_vct, _integrity| {
+                Ok(Some(
+                    TypeMetadataDocuments::from_single_example(resolved_type_metadata).2,
+                ))
+            });
 
         mock_msg_client
             .expect_request_credential_preview()
@@ -1515,6 +2008,7 @@ mod tests {
             issuer_metadata.credential_issuer,
             issuer_metadata.endpoints,
             batch_size,
+            None,
             &oauth_metadata.token_endpoint,
             ClientAttestationChallengeMechanism::ChallengeEndpoint(oauth_metadata.challenge_endpoint.unwrap()),
             TokenRequest::new_mock(),
@@ -1773,6 +2267,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_start_issuance_type_metadata_uri_missing_resolved_from_vct() {
+        let ca = Ca::generate_issuer_mock_ca().unwrap();
+
+        // Create issuer metadata with missing type_metadata_uri, but with a `vct` that is an HTTPS URL.
+        let vct = "https://example.com/vct/pid";
+        let config_id = CredentialConfigurationId::from("config_id".to_string());
+        let mut issuer_metadata = IssuerMetadata::new_mock(
+            "https://example.com".parse().unwrap(),
+            vec![(config_id.clone(), CredentialKind::new(Format::SdJwt, vct.to_string()))],
+        );
+        issuer_metadata
+            .credential_configurations_supported
+            .values_mut()
+            .for_each(|config| config.type_metadata_uri = None);
+
+        let mut preview_payload = PreviewableCredentialPayload::nl_pid_example(&MockTimeGenerator::default());
+        preview_payload.attestation_type = vct.to_string();
+
+        let type_metadata = TypeMetadata::try_new(UncheckedTypeMetadata {
+            vct: vct.to_string(),
+            ..UncheckedTypeMetadata::pid_example()
+        })
+        .unwrap();
+
+        let session = test_start_issuance(
+            &ca,
+            &TrustAnchors::from(&ca),
+            issuer_metadata,
+            vec![(config_id, Format::SdJwt, preview_payload)],
+            type_metadata,
+            TokenResponseFields::Neither,
+        )
+        .expect("starting issuance session should succeed");
+
+        assert_eq!(session.type_metadata().keys().collect_vec(), vec![vct]);
+    }
+
     #[test]
     fn test_start_issuance_type_metadata_host_mismatch() {
         let ca = Ca::generate_issuer_mock_ca().unwrap();
@@ -1971,6 +2503,7 @@ mod tests {
             issuer_metadata.credential_issuer,
             issuer_metadata.endpoints,
             batch_size,
+            None,
             &oauth_metadata.token_endpoint,
             ClientAttestationChallengeMechanism::ChallengeEndpoint(oauth_metadata.challenge_endpoint.unwrap()),
             TokenRequest::new_mock(),
@@ -2013,12 +2546,14 @@ mod tests {
             credential_issuer: issuer_metadata.credential_issuer,
             issuer_endpoints: issuer_metadata.endpoints,
             batch_size: NonZeroU8::MIN,
+            response_encryption: None,
             credential_previews,
             credential_request_types,
             type_metadata: [(attestation_type.to_string(), issuance_type_metadata)].into(),
             issuer_registration: IssuerRegistration::new_mock(),
             dpop_signing_key: SigningKey::generate(),
             dpop_nonce: Some("dpop_nonce".to_string()),
+            refresh: None,
         }
     }
 
@@ -2162,6 +2697,7 @@ mod tests {
             TestNonceEndpoint::PresentWithDpopNonce
         )]
         nonce_endpoint: TestNonceEndpoint,
+        #[values(true, false)] has_refresh_token: bool,
     ) {
         let (signer, preview_data, attestation_type, type_metadata) =
             MockCredentialSigner::new_with_preview_and_type_metadata_state();
@@ -2174,7 +2710,7 @@ mod tests {
             TestNonceEndpoint::PresentWithDpopNonce => (mock_openid_message_client_nonce(true), true, "new_dpop_nonce"),
         };
 
-        let session_state = new_session_state(
+        let mut session_state = new_session_state(
             if multiple_creds {
                 vec_nonempty![preview_data.clone(), preview_data]
             } else {
@@ -2184,6 +2720,13 @@ mod tests {
             type_metadata,
             has_nonce_endpoint,
         );
+        session_state.refresh = has_refresh_token.then(|| CredentialRefresh {
+            refresh_token: "refresh_token".to_string().into(),
+            credential_issuer: session_state.credential_issuer.clone(),
+            authorization_server: session_state.credential_issuer.clone(),
+            credential_configuration_ids: vec![session_state.credential_previews.first().config_id.clone()],
+            dpop_signing_key: session_state.dpop_signing_key.to_bytes().to_vec(),
+        });
 
         // The client must use `request_credentials()` (which uses `/batch_credentials`) iff more than one credential
         // is being issued, and `request_credential()` instead (which uses `/credential`).
@@ -2209,7 +2752,7 @@ mod tests {
                         .map(|(request, signer)| signer.into_response_from_request(request))
                         .collect();
 
-                    Ok(CredentialResponses { credential_responses })
+                    Ok(MaybeEncrypted::Plain(CredentialResponses { credential_responses }))
                 }
             });
         } else {
@@ -2226,12 +2769,12 @@ mod tests {
 
                     let response = signer.into_response_from_request(credential_request);
 
-                    Ok(response)
+                    Ok(MaybeEncrypted::Plain(response))
                 }
             });
         }
 
-        let credential_copies = HttpIssuanceSession {
+        let outcome = HttpIssuanceSession {
             message_client: mock_msg_client,
             session_state,
         }
@@ -2240,8 +2783,19 @@ mod tests {
         .unwrap()
         .expect("accepting issuance should succeed");
 
+        let IssuanceOutcome::Issued(credential_copies) = outcome else {
+            panic!("issuance should not be deferred");
+        };
+
         let expected_credential_count = if multiple_creds { 2 } else { 1 };
         assert_eq!(credential_copies.len(), expected_credential_count);
+
+        // If the issuer handed out a refresh token, it should be attached to every credential.
+        assert!(
+            credential_copies
+                .iter()
+                .all(|credential| credential.refresh.is_some() == has_refresh_token)
+        );
     }
 
     #[test]
@@ -2260,7 +2814,7 @@ mod tests {
                     credential_responses: vec![response],
                 };
 
-                Ok(responses)
+                Ok(MaybeEncrypted::Plain(responses))
             },
         );
 
@@ -2284,6 +2838,69 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn test_accept_issuance_response_encryption(
+        #[values(None, Some(EncryptionAlgorithm::A256Gcm))] response_encryption: Option<EncryptionAlgorithm>,
+        #[values(false, true)] encrypt_response: bool,
+    ) {
+        let (signer, preview_data, attestation_type, type_metadata) =
+            MockCredentialSigner::new_with_preview_and_type_metadata_state();
+        let trust_anchor = TrustAnchors::try_from(vec![signer.trust_anchor.clone()]).unwrap();
+
+        let mut mock_msg_client = mock_openid_message_client_nonce(false);
+
+        mock_msg_client.expect_request_credential().return_once(
+            move |_url, credential_request, _dpop_header, _access_token_header| {
+                // The wallet should only include encryption parameters if it wants the response to be encrypted.
+                assert_eq!(
+                    credential_request.credential_response_encryption.is_some(),
+                    response_encryption.is_some()
+                );
+
+                let response = signer.into_response_from_request(credential_request);
+
+                if !encrypt_response {
+                    return Ok(MaybeEncrypted::Plain(response));
+                }
+
+                // Encrypt to the key provided by the wallet, or to a random key if it did not provide one.
+                let public_key = match credential_request.credential_response_encryption.as_ref() {
+                    Some(parameters) => JwePublicKey::try_from_jwk(&parameters.jwk).unwrap(),
+                    None => JweEcdhSecretKey::new_random(None, EcdhAlgorithm::EcdhEs).to_jwe_public_key(),
+                };
+                let jwe = JweEncrypter::from(public_key)
+                    .encrypt_json(
+                        &response,
+                        EncryptionAlgorithm::A256Gcm,
+                        None,
+                        None,
+                        JweCompression::Deflate,
+                    )
+                    .unwrap();
+
+                Ok(MaybeEncrypted::Encrypted(jwe))
+            },
+        );
+
+        let mut session_state = new_session_state(vec_nonempty![preview_data], &attestation_type, type_metadata, true);
+        session_state.response_encryption = response_encryption;
+
+        let result = HttpIssuanceSession {
+            message_client: mock_msg_client,
+            session_state,
+        }
+        .accept_issuance(&trust_anchor, &MockRemoteWscd::default())
+        .now_or_never()
+        .unwrap();
+
+        // The response should be encrypted if and only if the wallet requested this.
+        match (response_encryption.is_some(), encrypt_response) {
+            (true, true) | (false, false) => assert_matches!(result, Ok(IssuanceOutcome::Issued(_))),
+            (true, false) => assert_matches!(result, Err(WalletIssuanceError::MissingCredentialResponseEncryption)),
+            (false, true) => assert_matches!(result, Err(WalletIssuanceError::UnexpectedEncryptedCredentialResponse)),
+        }
+    }
+
     #[test]
     fn test_accept_issuance_incorrect_resource_integrity() {
         let (mut signer, preview_data, attestation_type, type_metadata) =
@@ -2299,7 +2916,7 @@ mod tests {
             |_url, credential_request, _dpop_header, _access_token_header| {
                 let response = signer.into_response_from_request(credential_request);
 
-                Ok(response)
+                Ok(MaybeEncrypted::Plain(response))
             },
         );
 
@@ -2314,10 +2931,73 @@ mod tests {
 
         assert_matches!(
             error,
-            WalletIssuanceError::TypeMetadataVerification(TypeMetadataChainError::ResourceIntegrity(_))
+            WalletIssuanceError::TypeMetadataIntegrity {
+                error: TypeMetadataChainError::ResourceIntegrity(_),
+                ..
+            }
         );
     }
 
+    #[rstest]
+    fn test_accept_issuance_resolve_type_metadata_on_integrity_mismatch(#[values(true, false)] resolve_updated: bool) {
+        let vct = "https://example.com/vct";
+        let mut preview_payload = PreviewableCredentialPayload::example_family_name(&MockTimeGenerator::default());
+        preview_payload.attestation_type = vct.to_string();
+
+        let (mut signer, preview_data, attestation_type, type_metadata) =
+            MockCredentialSigner::from_metadata_and_payload_with_preview_data(
+                TypeMetadata::example_with_claim_name(vct, "family_name"),
+                preview_payload,
+            );
+        let trust_anchor = TrustAnchors::try_from(vec![signer.trust_anchor.clone()]).unwrap();
+
+        // The type metadata at the `vct` URL has been updated since the wallet resolved it.
+        let (_, updated_integrity, updated_documents) =
+            TypeMetadataDocuments::from_single_example(TypeMetadata::example_with_claim_name(vct, "family_name"));
+        signer.metadata_integrity = updated_integrity.clone();
+
+        let resolved_documents = if resolve_updated {
+            updated_documents
+        } else {
+            TypeMetadataDocuments::from_single_example(TypeMetadata::example_with_claim_name(vct, "family_name")).2
+        };
+
+        let mut mock_msg_client = mock_openid_message_client_nonce(false);
+
+        mock_msg_client.expect_request_credential().return_once(
+            |_url, credential_request, _dpop_header, _access_token_header| {
+                let response = signer.into_response_from_request(credential_request);
+
+                Ok(MaybeEncrypted::Plain(response))
+            },
+        );
+
+        // The type metadata should be resolved only once, using the integrity from the credential.
+        mock_msg_client
+            .expect_resolve_type_metadata()
+            .times(1)
+            .return_once(move |resolve_vct, integrity| {
+                assert_eq!(resolve_vct, vct);
+                assert_eq!(integrity, Some(updated_integrity));
+
+                Ok(Some(resolved_documents))
+            });
+
+        let result = HttpIssuanceSession {
+            message_client: mock_msg_client,
+            session_state: new_session_state(vec_nonempty![preview_data], &attestation_type, type_metadata, true),
+        }
+        .accept_issuance(&trust_anchor, &MockRemoteWscd::default())
+        .now_or_never()
+        .unwrap();
+
+        if resolve_updated {
+            assert_matches!(result, Ok(IssuanceOutcome::Issued(_)));
+        } else {
+            assert_matches!(result, Err(WalletIssuanceError::TypeMetadataIntegrity { .. }));
+        }
+    }
+
     #[rstest]
     fn test_accept_issuance_deferred_issuance(#[values(false, true)] is_batch: bool) {
         let (signer, preview_data, attestation_type, type_metadata) =
@@ -2332,35 +3012,179 @@ mod tests {
         };
 
         let previews = if is_batch {
+            // The issuer responds with a single deferred response for all of the requested credentials.
             mock_msg_client.expect_request_credentials().return_once(
-                |_url, credential_requests, _dpop_header, _access_token_header| {
+                |_url, _credential_requests, _dpop_header, _access_token_header| {
                     let responses = CredentialResponses {
-                        credential_responses: vec![response; credential_requests.credential_requests.len().get()],
+                        credential_responses: vec![response],
                     };
 
-                    Ok(responses)
+                    Ok(MaybeEncrypted::Plain(responses))
                 },
             );
 
             vec_nonempty![preview_data.clone(), preview_data]
         } else {
-            mock_msg_client
-                .expect_request_credential()
-                .return_once(|_url, _credential_request, _dpop_header, _access_token_header| Ok(response));
+            mock_msg_client.expect_request_credential().return_once(
+                |_url, _credential_request, _dpop_header, _access_token_header| Ok(MaybeEncrypted::Plain(response)),
+            );
 
             vec_nonempty![preview_data]
         };
+        let expected_key_count = previews.len().get();
 
-        let error = HttpIssuanceSession {
+        let outcome = HttpIssuanceSession {
             message_client: mock_msg_client,
             session_state: new_session_state(previews, &attestation_type, type_metadata, true),
         }
         .accept_issuance(&trust_anchor, &MockRemoteWscd::default())
         .now_or_never()
         .unwrap()
-        .expect_err("accepting issuance should not succeed");
+        .expect("accepting issuance should succeed");
+
+        let IssuanceOutcome::Deferred(deferred_issuance) = outcome else {
+            panic!("issuance should be deferred");
+        };
+
+        assert_eq!(deferred_issuance.transaction_id, "12345");
+        assert_eq!(deferred_issuance.interval, Duration::from_hours(24));
+        assert_eq!(deferred_issuance.key_identifiers().count(), expected_key_count);
+    }
+
+    #[test]
+    fn test_poll_deferred_issuance() {
+        let (signer, preview_data, attestation_type, type_metadata) =
+            MockCredentialSigner::new_with_preview_and_type_metadata_state();
+        let trust_anchor = TrustAnchors::try_from(vec![signer.trust_anchor.clone()]).unwrap();
 
-        assert_matches!(error, WalletIssuanceError::DeferredIssuanceUnsupported);
+        let mut mock_msg_client = mock_openid_message_client_nonce(false);
+        mock_msg_client.expect_request_credential().return_once(
+            |_url, _credential_request, _dpop_header, _access_token_header| {
+                Ok(MaybeEncrypted::Plain(CredentialResponse::Deferred {
+                    transaction_id: "12345".to_string(),
+                    interval: Duration::from_mins(5),
+                }))
+            },
+        );
+
+        let outcome = HttpIssuanceSession {
+            message_client: mock_msg_client,
+            session_state: new_session_state(vec_nonempty![preview_data], &attestation_type, type_metadata, true),
+        }
+        .accept_issuance(&trust_anchor, &MockRemoteWscd::default())
+        .now_or_never()
+        .unwrap()
+        .expect("accepting issuance should succeed");
+
+        let IssuanceOutcome::Deferred(deferred_issuance) = outcome else {
+            panic!("issuance should be deferred");
+        };
+
+        // The deferred issuance state should survive a round trip through JSON, as the wallet persists it. As the
+        // DPoP private key is not part of this, it should be restored separately.
+        let dpop_key_bytes = deferred_issuance.dpop_key().to_bytes().unwrap();
+        let mut deferred_issuance: DeferredIssuance =
+            serde_json::from_value(serde_json::to_value(&deferred_issuance).unwrap()).unwrap();
+        deferred_issuance
+            .restore_dpop_key(&dpop_key_bytes)
+            .expect("restoring DPoP key should succeed");
+        let holder_pubkey = deferred_issuance.holder_keys.first().unwrap().0.clone();
+
+        // While pending, polling should result in an updated deferred issuance.
+        let mut mock_msg_client = MockVcMessageClient::new();
+        mock_msg_client
+            .expect_request_deferred_credential()
+            .times(1)
+            .return_once(|url, request, _dpop_header, _access_token_header| {
+                assert_eq!(url.as_str(), "https://issuer.example.com/issuance/deferred_credential");
+                assert_eq!(request.transaction_id, "12345");
+
+                Ok(MaybeEncrypted::Plain(DeferredCredentialResponse::Pending {
+                    transaction_id: "67890".to_string(),
+                    interval: Duration::from_mins(10),
+                }))
+            });
+
+        let outcome = deferred_issuance
+            .poll(&mock_msg_client, &trust_anchor)
+            .now_or_never()
+            .unwrap()
+            .expect("polling deferred issuance should succeed");
+
+        let IssuanceOutcome::Deferred(deferred_issuance) = outcome else {
+            panic!("issuance should still be deferred");
+        };
+        assert_eq!(deferred_issuance.transaction_id, "67890");
+        assert_eq!(deferred_issuance.interval, Duration::from_mins(10));
+
+        // Once issued, polling should result in the verified credentials.
+        let mut mock_msg_client = MockVcMessageClient::new();
+        mock_msg_client
+            .expect_request_deferred_credential()
+            .times(1)
+            .return_once(move |_url, request, _dpop_header, _access_token_header| {
+                assert_eq!(request.transaction_id, "67890");
+
+                Ok(MaybeEncrypted::Plain(DeferredCredentialResponse::Issued(
+                    CredentialResponses {
+                        credential_responses: vec![signer.into_response_from_holder_pubkey(&holder_pubkey)],
+                    },
+                )))
+            });
+
+        let outcome = deferred_issuance
+            .poll(&mock_msg_client, &trust_anchor)
+            .now_or_never()
+            .unwrap()
+            .expect("polling deferred issuance should succeed");
+
+        assert_matches!(outcome, IssuanceOutcome::Issued(credentials) if credentials.len() == 1);
+    }
+
+    #[test]
+    fn test_poll_deferred_issuance_response_encryption() {
+        let (signer, preview_data, _, _) = MockCredentialSigner::new_with_preview_and_type_metadata_state();
+        let trust_anchor = TrustAnchors::try_from(vec![signer.trust_anchor.clone()]).unwrap();
+
+        let mut deferred_issuance = DeferredIssuance::new_mock(vec_nonempty![preview_data]);
+        deferred_issuance.response_encryption = Some(EncryptionAlgorithm::A256Gcm);
+
+        let mut mock_msg_client = MockVcMessageClient::new();
+        mock_msg_client.expect_request_deferred_credential().return_once(
+            |_url, request, _dpop_header, _access_token_header| {
+                // The wallet should include a key to encrypt the response to with every poll.
+                let parameters = request
+                    .credential_response_encryption
+                    .as_ref()
+                    .expect("deferred credential request should contain encryption parameters");
+
+                let jwe = JweEncrypter::from(JwePublicKey::try_from_jwk(&parameters.jwk).unwrap())
+                    .encrypt_json(
+                        &DeferredCredentialResponse::Pending {
+                            transaction_id: "67890".to_string(),
+                            interval: Duration::from_mins(10),
+                        },
+                        EncryptionAlgorithm::A256Gcm,
+                        None,
+                        None,
+                        JweCompression::None,
+                    )
+                    .unwrap();
+
+                Ok(MaybeEncrypted::Encrypted(jwe))
+            },
+        );
+
+        let outcome = deferred_issuance
+            .poll(&mock_msg_client, &trust_anchor)
+            .now_or_never()
+            .unwrap()
+            .expect("polling deferred issuance should succeed");
+
+        assert_matches!(
+            outcome,
+            IssuanceOutcome::Deferred(deferred_issuance) if deferred_issuance.transaction_id == "67890"
+        );
     }
 
     fn mock_credential_response_credential() -> (
@@ -1,10 +1,14 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::num::NonZeroU8;
+use std::time::Duration;
 
 use attestation_data::auth::issuer_auth::IssuerRegistration;
 use crypto::trust_anchor::TrustAnchors;
 use jwt::nonce::Nonce;
 use jwt::wia::WiaDisclosure;
+use p256::ecdsa::SigningKey;
+use p256::elliptic_curve::Generate;
 use serde::Deserialize;
 use serde::Serialize;
 use url::Url;
@@ -18,9 +22,30 @@ use super::IssuanceFlow;
 use super::IssuanceSession;
 use super::IssuanceTypeMetadata;
 use super::WalletIssuanceError;
-use super::credential::CredentialWithMetadata;
+use super::credential::IssuanceOutcome;
+use super::deferred::DeferredIssuance;
+use super::dpop_key::PersistentDpopKey;
+use super::notification::CredentialNotification;
+use super::notification::NotificationContext;
+use super::refresh::CredentialRefresh;
+use crate::notification::NotificationEvent;
 use crate::token::CredentialPreview;
 
+impl CredentialNotification {
+    /// Create a [`CredentialNotification`] with the provided `notification_id`, which can only be sent using a mock.
+    pub fn new_mock(notification_id: &str) -> Self {
+        Self {
+            notification_id: notification_id.to_string(),
+            context: NotificationContext {
+                notification_endpoint: "https://issuer.example.com/issuance/notification".parse().unwrap(),
+                access_token: "access_token".to_string().into(),
+                dpop_key: PersistentDpopKey::new(&SigningKey::generate()),
+                dpop_nonce: None,
+            },
+        }
+    }
+}
+
 /// A [`WiaClient`] that records the challenge it was given, delegating the actual WIA issuance to a
 /// [`MockWiaClient`].
 #[derive(Default)]
@@ -37,6 +62,42 @@ impl WiaClient for RecordingWiaClient {
     }
 }
 
+impl DeferredIssuance {
+    /// Create a [`DeferredIssuance`] for the provided previews, which can only be polled using a mock.
+    pub fn new_mock(credential_previews: VecNonEmpty<CredentialPreview>) -> Self {
+        Self {
+            transaction_id: "transaction_id".to_string(),
+            interval: Duration::from_mins(5),
+            deferred_credential_endpoint: "https://issuer.example.com/issuance/deferred_credential"
+                .parse()
+                .unwrap(),
+            access_token: "access_token".to_string().into(),
+            dpop_key: PersistentDpopKey::new(&SigningKey::generate()),
+            dpop_nonce: None,
+            batch_size: NonZeroU8::MIN,
+            response_encryption: None,
+            credential_previews,
+            type_metadata: HashMap::new(),
+            holder_keys: Vec::new(),
+            issuer_registration: IssuerRegistration::new_mock(),
+            notification_context: None,
+        }
+    }
+}
+
+impl CredentialRefresh {
+    /// Create a [`CredentialRefresh`] with the provided `refresh_token`, which can only be redeemed using a mock.
+    pub fn new_mock(refresh_token: &str) -> Self {
+        Self {
+            refresh_token: refresh_token.to_string().into(),
+            credential_issuer: "https://issuer.example.com".parse().unwrap(),
+            authorization_server: "https://issuer.example.com".parse().unwrap(),
+            credential_configuration_ids: Vec::new(),
+            dpop_signing_key: SigningKey::generate().to_bytes().to_vec(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MockAuthorizationSessionData {
     pub auth_url: Url,
@@ -55,6 +116,19 @@ mockall::mock! {
         pub fn start_pre_authorized_code_flow_sync(&self) -> Result<MockIssuanceSession, WalletIssuanceError>;
 
         pub fn restore_authorization_session_sync(&self, data: MockAuthorizationSessionData) -> MockAuthorizationSession;
+
+        pub fn poll_deferred_issuance_sync(
+            &self,
+            deferred_issuance: DeferredIssuance,
+        ) -> Result<IssuanceOutcome, WalletIssuanceError>;
+
+        pub fn send_notification_sync(
+            &self,
+            notification: &CredentialNotification,
+            event: NotificationEvent,
+        ) -> Result<(), WalletIssuanceError>;
+
+        pub fn start_refresh_sync(&self, refresh: &CredentialRefresh) -> Result<MockIssuanceSession, WalletIssuanceError>;
     }
 }
 
@@ -65,6 +139,7 @@ impl IssuanceDiscovery for MockIssuanceDiscovery {
     async fn start(
         &self,
         _offer_uri: &Url,
+        _tx_code: Option<String>,
         _client_id: String,
         _redirect_uri: Url,
         _issuer_trust_anchors: &TrustAnchors,
@@ -101,6 +176,32 @@ impl IssuanceDiscovery for MockIssuanceDiscovery {
     ) -> Self::Authorization {
         self.restore_authorization_session_sync(data)
     }
+
+    async fn poll_deferred_issuance(
+        &self,
+        deferred_issuance: DeferredIssuance,
+        _trust_anchors: &TrustAnchors,
+    ) -> Result<IssuanceOutcome, WalletIssuanceError> {
+        self.poll_deferred_issuance_sync(deferred_issuance)
+    }
+
+    async fn send_notification(
+        &self,
+        notification: &CredentialNotification,
+        event: NotificationEvent,
+    ) -> Result<(), WalletIssuanceError> {
+        self.send_notification_sync(notification, event)
+    }
+
+    async fn start_refresh(
+        &self,
+        refresh: &CredentialRefresh,
+        _issuer_trust_anchors: &TrustAnchors,
+        _wia_client: &impl WiaClient,
+        _wrpac_trust_anchors: &TrustAnchors,
+    ) -> Result<Self::Issuance, WalletIssuanceError> {
+        self.start_refresh_sync(refresh)
+    }
 }
 
 mockall::mock! {
@@ -146,7 +247,7 @@ mockall::mock! {
     pub IssuanceSession {
         pub fn accept(
             &self,
-        ) -> Result<Vec<CredentialWithMetadata>, WalletIssuanceError>;
+        ) -> Result<IssuanceOutcome, WalletIssuanceError>;
 
         pub fn reject(&self) -> Result<(), WalletIssuanceError>;
 
@@ -159,11 +260,7 @@ mockall::mock! {
 }
 
 impl IssuanceSession for MockIssuanceSession {
-    async fn accept_issuance<W>(
-        &mut self,
-        _: &TrustAnchors,
-        _: &W,
-    ) -> Result<Vec<CredentialWithMetadata>, WalletIssuanceError> {
+    async fn accept_issuance<W>(&mut self, _: &TrustAnchors, _: &W) -> Result<IssuanceOutcome, WalletIssuanceError> {
         self.accept()
     }
 
@@ -1,8 +1,12 @@
 pub mod authorization;
 mod authorization_endpoints;
 pub mod credential;
+pub mod deferred;
 pub mod discovery;
+pub mod dpop_key;
 pub mod issuance_session;
+pub mod notification;
+pub mod refresh;
 
 #[cfg(any(test, feature = "mock"))]
 pub mod mock;
@@ -18,6 +22,7 @@ use attestation_data::credential_payload::PreviewableCredentialPayload;
 use crypto::trust_anchor::TrustAnchors;
 use error_category::ErrorCategory;
 use itertools::Itertools;
+use jwe::error::JweJsonDecryptionError;
 use jwt::error::JwkConversionError;
 use jwt::error::JwtParseError;
 use jwt::error::JwtX5cVerifyError;
@@ -25,8 +30,10 @@ use mdoc::utils::cose::CoseError;
 use reqwest::header::ToStrError;
 use sd_jwt::error::DecoderError;
 use sd_jwt_vc_metadata::TypeMetadataChainError;
+use sd_jwt_vc_metadata::resolver::TypeMetadataResolverError;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
+use ssri::Integrity;
 use url::Url;
 use utils::single_unique::MultipleItemsFound;
 use utils::vec_at_least::VecNonEmpty;
@@ -35,21 +42,27 @@ use wscd::wscd::WiaClient;
 
 use self::authorization::OAuthError;
 use self::authorization_endpoints::AuthorizationEndpointsError;
-use self::credential::CredentialWithMetadata;
+use self::credential::IssuanceOutcome;
+use self::deferred::DeferredIssuance;
 use self::issuance_session::IssuanceTypeMetadata;
+use self::notification::CredentialNotification;
+use self::refresh::CredentialRefresh;
 use crate::client_auth::ClientAttestationChallengeError;
 use crate::client_auth::ClientAttestationChallengeMechanismError;
 use crate::client_auth::ClientAttestationMetadataError;
 use crate::credential::Credential;
+use crate::credential_offer::PreAuthTransactionCode;
 use crate::dpop::DpopError;
 use crate::errors::CredentialErrorCode;
 use crate::errors::CredentialPreviewErrorCode;
+use crate::errors::NotificationErrorCode;
 use crate::errors::RemoteErrorResponse;
 use crate::errors::TokenErrorCode;
 use crate::issuer_identifier::IssuerIdentifier;
 use crate::issuer_identifier::IssuerUrl;
 use crate::metadata::issuer_metadata::CredentialConfigurationId;
 use crate::metadata::well_known::WellKnownError;
+use crate::notification::NotificationEvent;
 use crate::scope::Scope;
 use crate::token::CredentialPreview;
 use crate::token::CredentialPreviewError;
@@ -92,6 +105,17 @@ pub enum WalletIssuanceError {
     #[category(critical)]
     TypeMetadataVerification(#[from] TypeMetadataChainError),
 
+    #[error(
+        "type metadata for attestation type \"{attestation_type}\" does not match integrity in credential: {error}"
+    )]
+    #[category(critical)]
+    TypeMetadataIntegrity {
+        attestation_type: String,
+        integrity: Integrity,
+        #[source]
+        error: TypeMetadataChainError,
+    },
+
     #[error("attributes do not match type metadata: {0}")]
     #[category(pd)]
     AttributesVerification(#[from] AttributesError),
@@ -119,6 +143,10 @@ pub enum WalletIssuanceError {
     #[category(expected)]
     PreAuthorizedCodeExpired,
 
+    #[error("pre-authorized code was rejected: the transaction code is incorrect, or the code is no longer valid")]
+    #[category(expected)]
+    PreAuthorizedTxCodeRejected,
+
     #[error("could not retrieve credential preview from issuer: {0:?}")]
     #[category(expected)]
     CredentialPreviewHttp(#[source] reqwest::Error),
@@ -131,6 +159,10 @@ pub enum WalletIssuanceError {
     #[category(expected)]
     TypeMetadataHttp(#[source] reqwest::Error),
 
+    #[error("could not resolve type metadata using its \"vct\" URL: {0}")]
+    #[category(expected)]
+    TypeMetadataResolution(#[source] TypeMetadataResolverError),
+
     #[error("could not retrieve nonce from issuer: {0:?}")]
     #[category(expected)]
     NonceHttp(#[source] reqwest::Error),
@@ -151,6 +183,30 @@ pub enum WalletIssuanceError {
     #[category(pd)]
     CredentialRejection(Box<RemoteErrorResponse<CredentialErrorCode>>),
 
+    #[error("could not send notification to issuer: {0:?}")]
+    #[category(expected)]
+    NotificationHttp(#[source] reqwest::Error),
+
+    #[error("sending notification to issuer reported an error: {0:?}")]
+    #[category(pd)]
+    Notification(Box<RemoteErrorResponse<NotificationErrorCode>>),
+
+    #[error("could not restore DPoP private key: {0}")]
+    #[category(pd)]
+    DpopKeyRestore(#[source] p256::ecdsa::Error),
+
+    #[error("restored DPoP private key does not match its identifier")]
+    #[category(critical)]
+    DpopKeyMismatch,
+
+    #[error("DPoP private key has not been restored")]
+    #[category(critical)]
+    DpopKeyMissing,
+
+    #[error("could not restore DPoP private key for refresh: {0}")]
+    #[category(pd)]
+    RefreshDpopKey(#[source] p256::ecdsa::Error),
+
     #[error("generating credential private keys failed: {0}")]
     #[category(pd)]
     PrivateKeyGeneration(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
@@ -167,7 +223,23 @@ pub enum WalletIssuanceError {
     #[category(critical)]
     UnexpectedCredentialResponseCount { found: usize, expected: usize },
 
-    #[error("deferred issuance is not supported")]
+    #[error("issuer requires credential response encryption, but supports no suitable algorithm")]
+    #[category(critical)]
+    CredentialResponseEncryptionUnsupported,
+
+    #[error("could not decrypt credential response: {0}")]
+    #[category(pd)]
+    CredentialResponseDecryption(#[source] JweJsonDecryptionError),
+
+    #[error("received encrypted credential response, while encryption was not requested")]
+    #[category(critical)]
+    UnexpectedEncryptedCredentialResponse,
+
+    #[error("received unencrypted credential response, while encryption was requested")]
+    #[category(critical)]
+    MissingCredentialResponseEncryption,
+
+    #[error("deferred issuance is only supported for all credentials at once")]
     #[category(expected)]
     DeferredIssuanceUnsupported,
 
@@ -284,6 +356,10 @@ pub enum WalletIssuanceError {
     #[category(critical)]
     NoBatchCredentialEndpoint,
 
+    #[error("issuer deferred issuance, but has no deferred credential endpoint")]
+    #[category(critical)]
+    NoDeferredCredentialEndpoint,
+
     #[error("issuer has no credential preview endpoint")]
     #[category(critical)]
     NoCredentialPreviewEndpoint, // TODO (PVW-5559): skip preview when no credential preview endpoint
@@ -333,6 +409,10 @@ pub enum WalletIssuanceError {
     #[category(expected)]
     CredentialOfferTxCodeUnsupported,
 
+    #[error("the Credential Offer requires a Transaction Code, which was not provided")]
+    #[category(expected)]
+    CredentialOfferTxCodeRequired(Box<PreAuthTransactionCode>),
+
     #[error("the Credential Offer did not resolve to using the Authorization Code flow")]
     #[category(expected)]
     CredentialOfferNoAuthorizationCode,
@@ -366,6 +446,10 @@ pub trait IssuanceDiscovery {
     /// [`AuthorizationSession`] the caller can use to redirect the user into a web-based OAuth flow (if the Credential
     /// Offer resolves to an Authorization Code flow) or immediately returns an [`IssuanceSession`] that the caller can
     /// use to request issued credentials (if the Credential Offer contains a Pre-Authorized Code).
+    ///
+    /// If the Pre-Authorized Code in the Credential Offer requires a Transaction Code, this should be provided as
+    /// `tx_code`. When it is absent, [`WalletIssuanceError::CredentialOfferTxCodeRequired`] is returned, which contains
+    /// the input mode, length and description that can be used to ask the user for the code.
     #[expect(
         clippy::too_many_arguments,
         reason = "helper method that calls either of two functions"
@@ -373,6 +457,7 @@ pub trait IssuanceDiscovery {
     async fn start(
         &self,
         offer_uri: &Url,
+        tx_code: Option<String>,
         client_id: String,
         redirect_uri: Url,
         issuer_trust_anchors: &TrustAnchors,
@@ -408,6 +493,33 @@ pub trait IssuanceDiscovery {
         &self,
         data: <Self::Authorization as AuthorizationSession>::Persisted,
     ) -> Self::Authorization;
+
+    /// Polls the issuer for the credentials of an issuance session for which issuance was deferred, which results in
+    /// either the issued credentials or an updated [`DeferredIssuance`] if these are still pending.
+    async fn poll_deferred_issuance(
+        &self,
+        deferred_issuance: DeferredIssuance,
+        trust_anchors: &TrustAnchors,
+    ) -> Result<IssuanceOutcome, WalletIssuanceError>;
+
+    /// Notifies the issuer about the status of a credential that was issued to the wallet, e.g. that it was accepted
+    /// or deleted by the user.
+    async fn send_notification(
+        &self,
+        notification: &CredentialNotification,
+        event: NotificationEvent,
+    ) -> Result<(), WalletIssuanceError>;
+
+    /// Redeems the refresh token that the issuer handed out alongside a credential, which results in an
+    /// [`IssuanceSession`] that can be used to have the credential reissued without involving the user. The issuer
+    /// and OAuth metadata are fetched again, as these may have changed since the credential was issued.
+    async fn start_refresh(
+        &self,
+        refresh: &CredentialRefresh,
+        issuer_trust_anchors: &TrustAnchors,
+        wia_client: &impl WiaClient,
+        wrpac_trust_anchors: &TrustAnchors,
+    ) -> Result<Self::Issuance, WalletIssuanceError>;
 }
 
 /// Represents an in-progress OAuth authorization code flow.
@@ -440,11 +552,13 @@ pub trait AuthorizationSession {
 
 /// Represents an active credential issuance session for which previews are available.
 pub trait IssuanceSession {
+    /// Requests the credentials from the issuer, which either issues these immediately or defers issuance, in which
+    /// case the returned [`DeferredIssuance`] can be used to poll for the credentials later.
     async fn accept_issuance<W>(
         &mut self,
         trust_anchors: &TrustAnchors,
         wscd: &W,
-    ) -> Result<Vec<CredentialWithMetadata>, WalletIssuanceError>
+    ) -> Result<IssuanceOutcome, WalletIssuanceError>
     where
         W: IssuanceWscd;
 
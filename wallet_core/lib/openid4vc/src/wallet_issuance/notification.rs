@@ -0,0 +1,76 @@
+use reqwest::Method;
+use serde::Deserialize;
+use serde::Serialize;
+use url::Url;
+
+use super::WalletIssuanceError;
+use super::dpop_key::PersistentDpopKey;
+use super::issuance_session::VcMessageClient;
+use crate::dpop::Dpop;
+use crate::notification::NotificationEvent;
+use crate::notification::NotificationRequest;
+use crate::token::AccessToken;
+
+/// Everything needed to notify the issuer about the status of an issued credential, possibly after the app has been
+/// restarted. The same access token and DPoP key that were used to obtain the credential are needed for this. The
+/// issuer accepts these notifications until the credential expires or the wallet reports it as deleted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CredentialNotification {
+    pub notification_id: String,
+    #[serde(flatten)]
+    pub(super) context: NotificationContext,
+}
+
+/// The part of a [`CredentialNotification`] that is shared by all credentials issued in the same session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(super) struct NotificationContext {
+    pub notification_endpoint: Url,
+    pub access_token: AccessToken,
+    pub dpop_key: PersistentDpopKey,
+    pub dpop_nonce: Option<String>,
+}
+
+impl CredentialNotification {
+    pub fn dpop_key(&self) -> &PersistentDpopKey {
+        &self.context.dpop_key
+    }
+
+    /// Restore the DPoP private key after deserializing, which is needed before the notification can be sent.
+    pub fn restore_dpop_key(&mut self, bytes: &[u8]) -> Result<(), WalletIssuanceError> {
+        self.context.dpop_key.restore(bytes)
+    }
+
+    /// Send a Notification Request for the credential to the Notification Endpoint of the issuer.
+    pub async fn send(
+        &self,
+        message_client: &impl VcMessageClient,
+        event: NotificationEvent,
+        event_description: Option<String>,
+    ) -> Result<(), WalletIssuanceError> {
+        let context = &self.context;
+
+        let dpop_header = Dpop::new(
+            context.dpop_key.signing_key()?,
+            context.notification_endpoint.clone(),
+            &Method::POST,
+            Some(&context.access_token),
+            context.dpop_nonce.clone(),
+        )?;
+        let access_token_header = "DPoP ".to_string() + context.access_token.as_ref();
+
+        let request = NotificationRequest {
+            notification_id: self.notification_id.clone(),
+            event,
+            event_description,
+        };
+
+        message_client
+            .send_notification(
+                &context.notification_endpoint,
+                &request,
+                &dpop_header.to_string(),
+                &access_token_header,
+            )
+            .await
+    }
+}
@@ -0,0 +1,38 @@
+use derive_more::Debug;
+use p256::ecdsa::SigningKey;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_with::base64::Base64;
+use serde_with::serde_as;
+
+use super::WalletIssuanceError;
+use crate::issuer_identifier::IssuerIdentifier;
+use crate::metadata::issuer_metadata::CredentialConfigurationId;
+use crate::token::RefreshToken;
+
+/// Everything needed to have the issuer reissue a credential at a later moment without involving the user, possibly
+/// after the app has been restarted. The refresh token is bound to the DPoP key that was used to obtain it, so that key
+/// is retained here as well. As the issuer only accepts a refresh token once, this is replaced by the one returned
+/// in the issuance session that uses it.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CredentialRefresh {
+    #[debug(skip)]
+    pub refresh_token: RefreshToken,
+    pub(super) credential_issuer: IssuerIdentifier,
+    pub(super) authorization_server: IssuerIdentifier,
+    pub(super) credential_configuration_ids: Vec<CredentialConfigurationId>,
+    #[debug(skip)]
+    #[serde_as(as = "Base64")]
+    pub(super) dpop_signing_key: Vec<u8>,
+}
+
+impl CredentialRefresh {
+    pub fn credential_issuer(&self) -> &IssuerIdentifier {
+        &self.credential_issuer
+    }
+
+    pub(super) fn dpop_signing_key(&self) -> Result<SigningKey, WalletIssuanceError> {
+        SigningKey::from_slice(&self.dpop_signing_key).map_err(WalletIssuanceError::RefreshDpopKey)
+    }
+}
@@ -0,0 +1,332 @@
+use async_trait::async_trait;
+use attestation_data::disclosure::DisclosedAttestations;
+use chrono::DateTime;
+use chrono::TimeDelta;
+use chrono::Utc;
+use chrono::serde::ts_seconds;
+use crypto::keys::EcdsaKey;
+use crypto::server_keys::KeyPair;
+use dcql::unique_id_vec::UniqueIdVec;
+use http::StatusCode;
+use http::header::CONTENT_TYPE;
+use http_utils::reqwest::APPLICATION_JWT;
+use http_utils::reqwest::HttpClient;
+use http_utils::urls::BaseUrl;
+use jwt::JwtTyp;
+use jwt::SignedJwt;
+use jwt::error::JwtSignError;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+use tracing::info;
+use tracing::warn;
+
+use crate::server_state::SessionToken;
+
+/// The interval at which the verifier checks for webhook notifications that are due.
+pub const WEBHOOK_DELIVERY_INTERVAL: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// The time for which a verifier instance claims a due webhook notification, during which other instances sharing the
+/// same session store will not try to deliver it. This should comfortably exceed the time needed for a delivery.
+pub const WEBHOOK_CLAIM_DURATION: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// The maximum number of times the verifier tries to deliver a webhook notification before giving up.
+pub const WEBHOOK_MAX_ATTEMPTS: u32 = 8;
+
+/// The delay before the first retry of a failed delivery. This is doubled after each subsequent failure.
+const WEBHOOK_RETRY_BASE_DELAY_SECONDS: i64 = 10;
+
+/// Webhook configuration of a use case. When present, the verifier POSTs a signed [`WebhookNotification`] to `url`
+/// when a disclosure session of that use case is done, has failed or has been cancelled.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Webhook {
+    pub url: BaseUrl,
+    /// Whether to include the disclosed attributes in the notification of a successful session.
+    #[serde(default)]
+    pub include_disclosed_attributes: bool,
+}
+
+/// The final status of a session as reported in a [`WebhookNotification`]. Expired sessions are not reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum WebhookSessionStatus {
+    Done,
+    Failed,
+    Cancelled,
+}
+
+/// The payload of the JWT that is POSTed to the webhook URL of a use case. The JWT is signed with the private key of
+/// the use case and contains its certificate in the `x5c` header, so that the requester can authenticate it.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookNotification {
+    pub session_token: SessionToken,
+    pub status: WebhookSessionStatus,
+    pub disclosed_attributes: Option<UniqueIdVec<DisclosedAttestations>>,
+    #[serde(with = "ts_seconds")]
+    pub iat: DateTime<Utc>,
+}
+
+impl JwtTyp for WebhookNotification {
+    const TYP: &'static str = "verifier-webhook+jwt";
+}
+
+/// A signed webhook notification along with its delivery state, stored in the session so that deliveries are
+/// retried across restarts of the verifier.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookDelivery {
+    url: BaseUrl,
+    notification: String,
+    attempts: u32,
+    status: WebhookDeliveryStatus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "status")]
+pub enum WebhookDeliveryStatus {
+    Pending { next_attempt: DateTime<Utc> },
+    Delivered,
+    Abandoned,
+}
+
+impl WebhookDelivery {
+    /// Sign `notification` using `key_pair`, returning a delivery that is due immediately.
+    pub async fn sign(
+        webhook: &Webhook,
+        notification: &WebhookNotification,
+        key_pair: &KeyPair<impl EcdsaKey>,
+    ) -> Result<Self, JwtSignError> {
+        let jwt = SignedJwt::sign_with_certificate(notification, key_pair).await?;
+
+        let delivery = Self {
+            url: webhook.url.clone(),
+            notification: jwt.into_unverified().serialization().to_string(),
+            attempts: 0,
+            status: WebhookDeliveryStatus::Pending {
+                next_attempt: notification.iat,
+            },
+        };
+
+        Ok(delivery)
+    }
+
+    pub fn status(&self) -> WebhookDeliveryStatus {
+        self.status
+    }
+
+    /// The time at which the next delivery attempt is due, if the notification has not been delivered yet.
+    pub fn next_attempt(&self) -> Option<DateTime<Utc>> {
+        match self.status {
+            WebhookDeliveryStatus::Pending { next_attempt } => Some(next_attempt),
+            WebhookDeliveryStatus::Delivered | WebhookDeliveryStatus::Abandoned => None,
+        }
+    }
+
+    /// Try to deliver the notification using `client`. On failure, the next attempt is scheduled using exponential
+    /// backoff, until [`WEBHOOK_MAX_ATTEMPTS`] is reached.
+    pub async fn attempt(&mut self, client: &(impl WebhookClient + ?Sized), now: DateTime<Utc>) {
+        if self.next_attempt().is_none() {
+            return;
+        }
+
+        self.attempts += 1;
+        self.status = match client.send(&self.url, &self.notification).await {
+            Ok(()) => {
+                info!(
+                    "delivered webhook notification to {} after {} attempt(s)",
+                    self.url, self.attempts
+                );
+
+                WebhookDeliveryStatus::Delivered
+            }
+            Err(error) if self.attempts >= WEBHOOK_MAX_ATTEMPTS => {
+                warn!(
+                    "giving up delivering webhook notification to {} after {} attempts: {error}",
+                    self.url, self.attempts
+                );
+
+                WebhookDeliveryStatus::Abandoned
+            }
+            Err(error) => {
+                warn!(
+                    "error delivering webhook notification to {}, attempt {}: {error}",
+                    self.url, self.attempts
+                );
+
+                WebhookDeliveryStatus::Pending {
+                    next_attempt: now + Self::retry_delay(self.attempts),
+                }
+            }
+        };
+    }
+
+    fn retry_delay(attempts: u32) -> TimeDelta {
+        TimeDelta::seconds(WEBHOOK_RETRY_BASE_DELAY_SECONDS << attempts.saturating_sub(1))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookError {
+    #[error("error sending webhook notification: {0}")]
+    Http(#[from] reqwest::Error),
+    #[error("webhook endpoint responded with unexpected status: {0}")]
+    UnexpectedStatus(StatusCode),
+}
+
+/// Sends webhook notifications to requesters.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait] // This makes the trait object safe so we can use `dyn WebhookClient` in the verifier.
+pub trait WebhookClient {
+    async fn send(&self, url: &BaseUrl, notification: &str) -> Result<(), WebhookError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpWebhookClient {
+    http_client: HttpClient,
+}
+
+impl HttpWebhookClient {
+    pub fn new(http_client: HttpClient) -> Self {
+        Self { http_client }
+    }
+}
+
+#[async_trait]
+impl WebhookClient for HttpWebhookClient {
+    async fn send(&self, url: &BaseUrl, notification: &str) -> Result<(), WebhookError> {
+        let response = self
+            .http_client
+            .post(url.as_ref().clone(), |builder| {
+                builder
+                    .header(CONTENT_TYPE, APPLICATION_JWT)
+                    .body(notification.to_string())
+            })
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(WebhookError::UnexpectedStatus(status));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeDelta;
+    use chrono::Utc;
+    use crypto::server_keys::generate::Ca;
+    use http::StatusCode;
+    use jwt::UnverifiedJwt;
+    use jwt::headers::HeaderWithX5c;
+    use rstest::rstest;
+
+    use super::MockWebhookClient;
+    use super::WEBHOOK_MAX_ATTEMPTS;
+    use super::Webhook;
+    use super::WebhookDelivery;
+    use super::WebhookDeliveryStatus;
+    use super::WebhookError;
+    use super::WebhookNotification;
+    use super::WebhookSessionStatus;
+    use crate::server_state::SessionToken;
+
+    async fn pending_delivery() -> WebhookDelivery {
+        let ca = Ca::generate_wrpac_mock_ca().unwrap();
+        let key_pair = ca.generate_wrpac_verifier_mock().unwrap();
+
+        let webhook = Webhook {
+            url: "https://example.com/webhook".parse().unwrap(),
+            include_disclosed_attributes: false,
+        };
+        let notification = WebhookNotification {
+            session_token: SessionToken::new_random(),
+            status: WebhookSessionStatus::Cancelled,
+            disclosed_attributes: None,
+            iat: Utc::now(),
+        };
+
+        WebhookDelivery::sign(&webhook, &notification, &key_pair).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_webhook_delivery_sign() {
+        let delivery = pending_delivery().await;
+
+        assert_eq!(delivery.attempts, 0);
+        assert!(delivery.next_attempt().is_some());
+
+        let jwt = delivery
+            .notification
+            .parse::<UnverifiedJwt<WebhookNotification, HeaderWithX5c>>()
+            .unwrap();
+        let (_, notification) = jwt.dangerous_parse_unverified().unwrap();
+        assert_eq!(notification.status, WebhookSessionStatus::Cancelled);
+        assert!(notification.disclosed_attributes.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_webhook_delivery_attempt_success() {
+        let mut delivery = pending_delivery().await;
+
+        let mut client = MockWebhookClient::new();
+        let expected_notification = delivery.notification.clone();
+        client
+            .expect_send()
+            .withf(move |url, notification| {
+                url.as_ref().as_str() == "https://example.com/webhook" && notification == expected_notification
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        delivery.attempt(&client, Utc::now()).await;
+
+        assert_eq!(delivery.status(), WebhookDeliveryStatus::Delivered);
+        assert!(delivery.next_attempt().is_none());
+
+        // Attempting a delivered notification again should not contact the client.
+        delivery.attempt(&client, Utc::now()).await;
+        assert_eq!(delivery.attempts, 1);
+    }
+
+    #[rstest]
+    #[case(1, TimeDelta::seconds(10))]
+    #[case(2, TimeDelta::seconds(20))]
+    #[case(3, TimeDelta::seconds(40))]
+    #[tokio::test]
+    async fn test_webhook_delivery_attempt_backoff(#[case] failures: u32, #[case] expected_delay: TimeDelta) {
+        let mut delivery = pending_delivery().await;
+
+        let mut client = MockWebhookClient::new();
+        client
+            .expect_send()
+            .times(failures as usize)
+            .returning(|_, _| Err(WebhookError::UnexpectedStatus(StatusCode::INTERNAL_SERVER_ERROR)));
+
+        let now = Utc::now();
+        for _ in 0..failures {
+            delivery.attempt(&client, now).await;
+        }
+
+        assert_eq!(delivery.next_attempt(), Some(now + expected_delay));
+    }
+
+    #[tokio::test]
+    async fn test_webhook_delivery_attempt_abandoned() {
+        let mut delivery = pending_delivery().await;
+
+        let mut client = MockWebhookClient::new();
+        client
+            .expect_send()
+            .times(WEBHOOK_MAX_ATTEMPTS as usize)
+            .returning(|_, _| Err(WebhookError::UnexpectedStatus(StatusCode::SERVICE_UNAVAILABLE)));
+
+        for _ in 0..WEBHOOK_MAX_ATTEMPTS + 1 {
+            delivery.attempt(&client, Utc::now()).await;
+        }
+
+        assert_eq!(delivery.status(), WebhookDeliveryStatus::Abandoned);
+        assert!(delivery.next_attempt().is_none());
+    }
+}
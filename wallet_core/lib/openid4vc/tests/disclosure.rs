@@ -187,6 +187,8 @@ fn disclosure_direct() {
         &RevocationVerifier::new_without_caching(Arc::new(StatusListClientStub::new(
             issuer_ca.generate_issuer_status_list_mock().unwrap(),
         ))),
+        None,
+        None,
         false,
     )
     .now_or_never()
@@ -394,6 +396,8 @@ impl VpMessageClient for DirectMockVpMessageClient {
             &RevocationVerifier::new_without_caching(Arc::new(StatusListClientStub::new(
                 self.status_list_keypair.clone(),
             ))),
+            None,
+            None,
             false,
         )
         .await
@@ -1102,6 +1106,7 @@ fn setup_verifier(
                 None,
                 None,
                 false,
+                None,
             ),
         ),
         (
@@ -1115,6 +1120,7 @@ fn setup_verifier(
                 None,
                 None,
                 false,
+                None,
             ),
         ),
     ]);
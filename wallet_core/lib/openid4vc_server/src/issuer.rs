@@ -47,6 +47,9 @@ use openid4vc::credential::CredentialRequest;
 use openid4vc::credential::CredentialRequests;
 use openid4vc::credential::CredentialResponse;
 use openid4vc::credential::CredentialResponses;
+use openid4vc::credential::DeferredCredentialRequest;
+use openid4vc::credential::DeferredCredentialResponse;
+use openid4vc::credential::MaybeEncrypted;
 use openid4vc::credential_offer::CredentialOffer;
 use openid4vc::dpop::DPOP_HEADER_NAME;
 use openid4vc::dpop::DPOP_NONCE_HEADER_NAME;
@@ -56,6 +59,7 @@ use openid4vc::errors::BodyOrRedirectErrorResponse;
 use openid4vc::errors::CredentialErrorCode;
 use openid4vc::errors::CredentialPreviewErrorCode;
 use openid4vc::errors::ErrorResponse;
+use openid4vc::errors::NotificationErrorCode;
 use openid4vc::errors::ParErrorCode;
 use openid4vc::errors::TokenErrorCode;
 use openid4vc::issuer::IssuanceData;
@@ -64,6 +68,7 @@ use openid4vc::metadata::issuer_metadata::CredentialConfigurationId;
 use openid4vc::metadata::oauth_metadata::AuthorizationServerMetadata;
 use openid4vc::nonce::response::NonceResponse;
 use openid4vc::nonce::store::NonceStore;
+use openid4vc::notification::NotificationRequest;
 use openid4vc::preview::CredentialPreviewResponse;
 use openid4vc::server_state::SessionStore;
 use openid4vc::store::Store;
@@ -153,6 +158,8 @@ where
         .route("/issuance/credential", delete(reject_credential))
         .route("/issuance/batch_credential", post(batch_credential))
         .route("/issuance/batch_credential", delete(reject_batch_credential))
+        .route("/issuance/deferred_credential", post(deferred_credential))
+        .route("/issuance/notification", post(notification))
         .route("/issuance/client_auth_challenge", post(client_auth_challenge))
         .with_state(IssuanceState { issuer })
 }
@@ -268,7 +275,7 @@ async fn credential<K, L, S, N>(
     TypedHeader(Authorization(authorization_header)): TypedHeader<Authorization<DpopBearer>>,
     TypedHeader(DpopHeader(dpop)): TypedHeader<DpopHeader>,
     Json(credential_request): Json<CredentialRequest>,
-) -> Result<Json<CredentialResponse>, ErrorResponse<CredentialErrorCode>>
+) -> Result<MaybeEncrypted<CredentialResponse>, ErrorResponse<CredentialErrorCode>>
 where
     K: EcdsaKeySend,
     L: StatusListService,
@@ -282,7 +289,7 @@ where
         .await
         .inspect_err(|error| warn!("processing credential failed: {}", error))?;
 
-    Ok(Json(response))
+    Ok(response)
 }
 
 async fn batch_credential<K, L, S, N>(
@@ -290,7 +297,7 @@ async fn batch_credential<K, L, S, N>(
     TypedHeader(Authorization(authorization_header)): TypedHeader<Authorization<DpopBearer>>,
     TypedHeader(DpopHeader(dpop)): TypedHeader<DpopHeader>,
     Json(credential_requests): Json<CredentialRequests>,
-) -> Result<Json<CredentialResponses>, ErrorResponse<CredentialErrorCode>>
+) -> Result<MaybeEncrypted<CredentialResponses>, ErrorResponse<CredentialErrorCode>>
 where
     K: EcdsaKeySend,
     L: StatusListService,
@@ -304,7 +311,36 @@ where
         .await
         .inspect_err(|error| warn!("processing batch credential failed: {}", error))?;
 
-    Ok(Json(response))
+    Ok(response)
+}
+
+async fn deferred_credential<K, L, S, N>(
+    State(state): State<IssuanceState<K, L, S, N>>,
+    TypedHeader(Authorization(authorization_header)): TypedHeader<Authorization<DpopBearer>>,
+    TypedHeader(DpopHeader(dpop)): TypedHeader<DpopHeader>,
+    Json(deferred_credential_request): Json<DeferredCredentialRequest>,
+) -> Result<(StatusCode, MaybeEncrypted<DeferredCredentialResponse>), ErrorResponse<CredentialErrorCode>>
+where
+    K: EcdsaKeySend,
+    L: StatusListService,
+    S: SessionStore<IssuanceData>,
+    N: NonceStore,
+{
+    let access_token = authorization_header.into();
+    let (response, is_pending) = state
+        .issuer
+        .process_deferred_credential(access_token, dpop, deferred_credential_request)
+        .await
+        .inspect_err(|error| warn!("processing deferred credential failed: {}", error))?;
+
+    // As per the spec, a 202 status code signals that the credentials are not yet available.
+    let status_code = if is_pending {
+        StatusCode::ACCEPTED
+    } else {
+        StatusCode::OK
+    };
+
+    Ok((status_code, response))
 }
 
 async fn reject_credential<K, L, S, N>(
@@ -343,6 +379,25 @@ where
     Ok(StatusCode::NO_CONTENT)
 }
 
+async fn notification<K, L, S, N>(
+    State(state): State<IssuanceState<K, L, S, N>>,
+    TypedHeader(Authorization(authorization_header)): TypedHeader<Authorization<DpopBearer>>,
+    TypedHeader(DpopHeader(dpop)): TypedHeader<DpopHeader>,
+    Json(notification_request): Json<NotificationRequest>,
+) -> Result<StatusCode, ErrorResponse<NotificationErrorCode>>
+where
+    S: SessionStore<IssuanceData>,
+{
+    let access_token = authorization_header.into();
+    state
+        .issuer
+        .process_notification(access_token, dpop, notification_request)
+        .await
+        .inspect_err(|error| warn!("processing notification failed: {}", error))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
 async fn token<K, L, S, N>(
     State(state): State<IssuanceState<K, L, S, N>>,
     wia_headers: WiaHeaders<TokenErrorCode>,
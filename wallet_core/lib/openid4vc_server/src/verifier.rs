@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use attestation_data::disclosure::DisclosedAttestations;
 use axum::Form;
@@ -9,12 +10,20 @@ use axum::extract::Path;
 use axum::extract::Query;
 use axum::extract::State;
 use axum::response::IntoResponse;
+use axum::response::sse::Event;
+use axum::response::sse::KeepAlive;
+use axum::response::sse::Sse;
 use axum::routing::delete;
 use axum::routing::get;
 use axum::routing::post;
 use crypto::keys::EcdsaKeySend;
 use crypto::trust_anchor::TrustAnchors;
+use crypto::x509::revocation::RevocationChecker;
 use dcql::unique_id_vec::UniqueIdVec;
+use futures::Stream;
+use futures::future;
+use futures::stream;
+use futures::stream::StreamExt;
 use http::HeaderMap;
 use http::HeaderValue;
 use http::Method;
@@ -40,22 +49,34 @@ use openid4vc::server_state::SessionStore;
 use openid4vc::server_state::SessionToken;
 use openid4vc::verifier::DisclosureData;
 use openid4vc::verifier::DisclosureResultHandler;
+use openid4vc::verifier::SessionStatusError;
 use openid4vc::verifier::SessionType;
 use openid4vc::verifier::StatusResponse;
 use openid4vc::verifier::UseCase;
 use openid4vc::verifier::UseCases;
 use openid4vc::verifier::Verifier;
 use openid4vc::verifier::WalletAuthResponse;
+use openid4vc::webhook::WebhookClient;
+use sd_jwt::jwt_vc_issuer::HttpJwtVcIssuerMetadataClient;
+use sd_jwt::jwt_vc_issuer::JwtVcIssuerKeyResolver;
 use serde::Deserialize;
 use serde::Serialize;
 use token_status_list::verification::client::StatusListClient;
 use token_status_list::verification::verifier::RevocationVerifier;
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::time;
+use tokio::time::Instant;
 use tower_http::cors::CorsLayer;
 use tracing::info;
 use tracing::warn;
 use utils::generator::TimeGenerator;
 use utils::vec_at_least::VecNonEmpty;
 
+/// While a session has not finished, its status is also checked at this interval when no change has been notified.
+/// This keeps the universal link of a created session fresh and makes sure no missed change goes unnoticed.
+const STATUS_EVENTS_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
 struct ApplicationState<S, US, C> {
     verifier: Verifier<S, US, C>,
     public_url: BaseUrl,
@@ -69,6 +90,9 @@ pub struct VerifierFactory<US> {
     issuer_trust_anchors: TrustAnchors,
     accepted_wallet_client_ids: Vec<String>,
     extending_vct_values_store: HashMap<String, VecNonEmpty<String>>,
+    webhook_client: Option<Arc<dyn WebhookClient + Send + Sync>>,
+    certificate_revocation_checker: Option<RevocationChecker>,
+    jwt_vc_issuer_key_resolver: Option<JwtVcIssuerKeyResolver<HttpJwtVcIssuerMetadataClient>>,
 }
 
 struct WalletRouterAndState<S, US, C> {
@@ -97,9 +121,33 @@ where
             issuer_trust_anchors,
             accepted_wallet_client_ids,
             extending_vct_values_store,
+            webhook_client: None,
+            certificate_revocation_checker: None,
+            jwt_vc_issuer_key_resolver: None,
         }
     }
 
+    /// Deliver webhook notifications for use cases that have a webhook configured, using `webhook_client`.
+    pub fn with_webhook_client(mut self, webhook_client: Arc<dyn WebhookClient + Send + Sync>) -> Self {
+        self.webhook_client = Some(webhook_client);
+        self
+    }
+
+    /// Check the revocation status of the certificates of the issuers of disclosed credentials using `checker`.
+    pub fn with_certificate_revocation_checker(mut self, checker: RevocationChecker) -> Self {
+        self.certificate_revocation_checker = Some(checker);
+        self
+    }
+
+    /// Verify SD-JWTs without an `x5c` header by resolving the issuer key using `key_resolver`.
+    pub fn with_jwt_vc_issuer_key_resolver(
+        mut self,
+        key_resolver: JwtVcIssuerKeyResolver<HttpJwtVcIssuerMetadataClient>,
+    ) -> Self {
+        self.jwt_vc_issuer_key_resolver = Some(key_resolver);
+        self
+    }
+
     fn wallet_router_and_state<S, C>(
         self,
         sessions: Arc<S>,
@@ -110,16 +158,30 @@ where
         S: SessionStore<DisclosureData> + Sync + 'static,
         C: StatusListClient + Sync + 'static,
     {
+        let verifier = Verifier::new(
+            self.use_cases,
+            sessions,
+            self.issuer_trust_anchors,
+            result_handler,
+            self.accepted_wallet_client_ids,
+            self.extending_vct_values_store,
+            revocation_verifier,
+        );
+        let verifier = match self.webhook_client {
+            Some(webhook_client) => verifier.with_webhook_client(webhook_client),
+            None => verifier,
+        };
+        let verifier = match self.certificate_revocation_checker {
+            Some(checker) => verifier.with_certificate_revocation_checker(checker),
+            None => verifier,
+        };
+        let verifier = match self.jwt_vc_issuer_key_resolver {
+            Some(key_resolver) => verifier.with_jwt_vc_issuer_key_resolver(key_resolver),
+            None => verifier,
+        };
+
         let application_state = Arc::new(ApplicationState {
-            verifier: Verifier::new(
-                self.use_cases,
-                sessions,
-                self.issuer_trust_anchors,
-                result_handler,
-                self.accepted_wallet_client_ids,
-                self.extending_vct_values_store,
-                revocation_verifier,
-            ),
+            verifier,
             public_url: self.public_url,
             disclosure_base_deep_link: self.disclosure_base_deep_link,
         });
@@ -174,6 +236,7 @@ where
 
         let mut wallet_web = Router::new()
             .route("/{session_token}", get(status::<S, US, UC, K, C>))
+            .route("/{session_token}/events", get(status_events::<S, US, UC, K, C>))
             .route("/{session_token}", delete(cancel::<S, US, UC, K, C>));
 
         if let Some(cors_origin) = allow_origins {
@@ -280,6 +343,25 @@ async fn status<S, US, UC, K, C>(
     Path(session_token): Path<SessionToken>,
     Query(query): Query<StatusParams>,
 ) -> Result<Json<StatusResponse>, HttpJsonError<VerificationErrorCode>>
+where
+    S: SessionStore<DisclosureData>,
+    US: UseCases<Key = K, UseCase = UC>,
+    UC: UseCase<Key = K>,
+    K: EcdsaKeySend,
+    C: StatusListClient,
+{
+    let response = session_status(&state, &session_token, query.session_type)
+        .await
+        .inspect_err(|error| warn!("querying session status failed: {error}"))?;
+
+    Ok(Json(response))
+}
+
+async fn session_status<S, US, UC, K, C>(
+    state: &ApplicationState<S, US, C>,
+    session_token: &SessionToken,
+    session_type: Option<SessionType>,
+) -> Result<StatusResponse, SessionStatusError>
 where
     S: SessionStore<DisclosureData>,
     US: UseCases<Key = K, UseCase = UC>,
@@ -289,22 +371,115 @@ where
 {
     let disclosure_base_deep_link = state
         .verifier
-        .disclosure_usecase_base_deep_link(&session_token)
+        .disclosure_usecase_base_deep_link(session_token)
         .await
         .unwrap_or_else(|| state.disclosure_base_deep_link.clone());
-    let response = state
+
+    state
         .verifier
         .status_response(
-            &session_token,
-            query.session_type,
+            session_token,
+            session_type,
             &urls::disclosure_base_uri(&disclosure_base_deep_link),
             state.public_url.join_base_url(&format!("{session_token}/request_uri")),
             &TimeGenerator,
         )
         .await
+}
+
+/// Streams the status of a session as server-sent events, starting with its current status and followed by each
+/// change of the status, until the session has finished.
+async fn status_events<S, US, UC, K, C>(
+    State(state): State<Arc<ApplicationState<S, US, C>>>,
+    Path(session_token): Path<SessionToken>,
+    Query(query): Query<StatusParams>,
+) -> Result<Sse<impl Stream<Item = Result<Event, axum::Error>>>, HttpJsonError<VerificationErrorCode>>
+where
+    S: SessionStore<DisclosureData> + Sync + 'static,
+    US: UseCases<Key = K, UseCase = UC> + Sync + 'static,
+    UC: UseCase<Key = K>,
+    K: EcdsaKeySend,
+    C: StatusListClient + Sync + 'static,
+{
+    // Subscribe before fetching the current status, so that no change in between is missed.
+    let changes = state
+        .verifier
+        .subscribe()
+        .await
+        .map_err(SessionStatusError::from)
+        .inspect_err(|error| warn!("subscribing to session changes failed: {error}"))?;
+    let status = session_status(&state, &session_token, query.session_type)
+        .await
         .inspect_err(|error| warn!("querying session status failed: {error}"))?;
 
-    Ok(Json(response))
+    let first_event = Event::default().json_data(&status);
+    let events = StatusEvents {
+        state,
+        session_token,
+        session_type: query.session_type,
+        changes,
+        previous: status,
+    };
+
+    let stream = stream::once(future::ready(first_event)).chain(stream::unfold(events, |mut events| async move {
+        let status = events.next_status().await?;
+
+        Some((Event::default().json_data(&status), events))
+    }));
+
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+struct StatusEvents<S, US, C> {
+    state: Arc<ApplicationState<S, US, C>>,
+    session_token: SessionToken,
+    session_type: Option<SessionType>,
+    changes: broadcast::Receiver<SessionToken>,
+    previous: StatusResponse,
+}
+
+impl<S, US, UC, K, C> StatusEvents<S, US, C>
+where
+    S: SessionStore<DisclosureData>,
+    US: UseCases<Key = K, UseCase = UC>,
+    UC: UseCase<Key = K>,
+    K: EcdsaKeySend,
+    C: StatusListClient,
+{
+    /// Wait for the status of the session to change and return it. Returns `None` when the session has finished,
+    /// or when its status can no longer be determined.
+    async fn next_status(&mut self) -> Option<StatusResponse> {
+        if !matches!(
+            self.previous,
+            StatusResponse::Created { .. } | StatusResponse::WaitingForResponse
+        ) {
+            return None;
+        }
+
+        // Use a fixed deadline, so that changes of other sessions do not postpone refreshing the status.
+        let mut deadline = Instant::now() + STATUS_EVENTS_REFRESH_INTERVAL;
+        loop {
+            match time::timeout_at(deadline, self.changes.recv()).await {
+                // Ignore changes of other sessions.
+                Ok(Ok(session_token)) if session_token != self.session_token => continue,
+                Ok(Err(RecvError::Closed)) => return None,
+                // Either the session changed, some changes were missed or it is time to refresh.
+                Ok(Ok(_)) | Ok(Err(RecvError::Lagged(_))) | Err(_) => {}
+            }
+            deadline = Instant::now() + STATUS_EVENTS_REFRESH_INTERVAL;
+
+            let status = session_status(&self.state, &self.session_token, self.session_type)
+                .await
+                .inspect_err(|error| warn!("querying session status failed: {error}"))
+                .ok()?;
+
+            if status != self.previous {
+                self.previous = status.clone();
+
+                return Some(status);
+            }
+        }
+    }
 }
 
 async fn cancel<S, US, UC, K, C>(
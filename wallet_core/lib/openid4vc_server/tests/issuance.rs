@@ -28,13 +28,16 @@ use openid4vc::credential_offer::CredentialOfferContainer;
 use openid4vc::dpop::DPOP_HEADER_NAME;
 use openid4vc::dpop::Dpop;
 use openid4vc::errors::AuthorizationErrorCode;
+use openid4vc::errors::NotificationErrorCode;
 use openid4vc::errors::RemoteErrorCode;
 use openid4vc::errors::TokenErrorCode;
 use openid4vc::issuable_document::IssuableDocument;
 use openid4vc::issuer::AuthRequestValues;
+use openid4vc::issuer::DeferredIssuanceDecisionError;
 use openid4vc::issuer_identifier::IssuerIdentifier;
 use openid4vc::metadata::issuer_metadata::SignedIssuerMetadataPayload;
 use openid4vc::mock::MOCK_WALLET_CLIENT_ID;
+use openid4vc::notification::NotificationEvent;
 use openid4vc::pkce::PkcePair;
 use openid4vc::pkce::S256PkcePair;
 use openid4vc::server_state::MemorySessionStore;
@@ -60,7 +63,7 @@ use openid4vc::wallet_issuance::IssuanceDiscovery;
 use openid4vc::wallet_issuance::IssuanceFlow;
 use openid4vc::wallet_issuance::IssuanceSession;
 use openid4vc::wallet_issuance::WalletIssuanceError;
-use openid4vc::wallet_issuance::credential::CredentialWithMetadata;
+use openid4vc::wallet_issuance::credential::IssuanceOutcome;
 use openid4vc::wallet_issuance::credential::IssuedCredentialCopies;
 use openid4vc::wallet_issuance::discovery::HttpIssuanceDiscovery;
 use openid4vc::wallet_issuance::issuance_session::HttpIssuanceSession;
@@ -204,11 +207,15 @@ async fn start_pre_authorized_code_flow_server(attestation_count: NonZeroUsize)
 }
 
 fn verify_issued_credentials(
-    issued_creds: Vec<CredentialWithMetadata>,
+    outcome: IssuanceOutcome,
     credential_previews: Iter<CredentialPreview>,
     expected_attestations: usize,
     expected_copies: usize,
 ) {
+    let IssuanceOutcome::Issued(issued_creds) = outcome else {
+        panic!("issuance should not be deferred");
+    };
+
     assert_eq!(issued_creds.len(), expected_attestations);
     assert_eq!(
         match &issued_creds.first().unwrap().copies {
@@ -253,6 +260,7 @@ async fn start_issuance_session(server: &AuthCodeFlowServer) -> HttpIssuanceSess
     let flow = discovery
         .start(
             &credential_offer_url,
+            None,
             MOCK_WALLET_CLIENT_ID.to_string(),
             redirect_uri.clone(),
             &server.trust_anchors,
@@ -398,6 +406,7 @@ async fn pre_authorized_code_flow(
     let flow = discovery
         .start(
             &credential_offer_url,
+            None,
             MOCK_WALLET_CLIENT_ID.to_string(),
             REDIRECT_URI.parse().unwrap(),
             &trust_anchors,
@@ -448,6 +457,7 @@ async fn reject_issuance() {
     let flow = discovery
         .start(
             &credential_offer_url,
+            None,
             MOCK_WALLET_CLIENT_ID.to_string(),
             REDIRECT_URI.parse().unwrap(),
             &trust_anchors,
@@ -467,6 +477,175 @@ async fn reject_issuance() {
     session.reject_issuance().await.unwrap();
 }
 
+#[tokio::test]
+async fn pre_authorized_code_flow_notification() {
+    let attestation_count = NonZeroUsize::new(2).unwrap();
+    let PreAuthCodeFlowServer {
+        issuer,
+        trust_anchors,
+        tls_trust_anchor,
+        wia_keypair,
+        ..
+    } = start_pre_authorized_code_flow_server(attestation_count).await;
+
+    let documents = mock_issuable_documents(attestation_count);
+    let credential_offer = issuer.new_preauthorized_session(documents).await.unwrap();
+    let credential_offer_url = CredentialOfferContainer::new_offer(credential_offer).to_credential_offer_url();
+
+    let discovery = HttpIssuanceDiscovery::new(
+        HttpClient::try_new(tls_reqwest_client_builder([tls_trust_anchor.into_certificate()])).unwrap(),
+    );
+
+    let flow = discovery
+        .start(
+            &credential_offer_url,
+            None,
+            MOCK_WALLET_CLIENT_ID.to_string(),
+            REDIRECT_URI.parse().unwrap(),
+            &trust_anchors,
+            &MockWiaClient::new_with_wia_keypair(wia_keypair),
+            &trust_anchors,
+        )
+        .await
+        .unwrap();
+
+    let IssuanceFlow::PreAuthorizedCode {
+        issuance_session: mut session,
+    } = flow
+    else {
+        panic!("should have received Pre-Authorized Code flow");
+    };
+
+    let wscd = MockRemoteWscd::new(vec![]);
+    let IssuanceOutcome::Issued(credentials) = session.accept_issuance(&trust_anchors, &wscd).await.unwrap() else {
+        panic!("issuance should not be deferred");
+    };
+
+    // Every credential should have its own notification, which should be accepted by the issuer, even repeatedly,
+    // up until the wallet reports that it deleted the credential.
+    let notifications = credentials
+        .into_iter()
+        .map(|credential| credential.notification.expect("credential should contain notification"))
+        .collect_vec();
+    assert_eq!(
+        notifications
+            .iter()
+            .map(|notification| &notification.notification_id)
+            .unique()
+            .count(),
+        attestation_count.get()
+    );
+
+    for notification in &notifications {
+        for event in [
+            NotificationEvent::CredentialAccepted,
+            NotificationEvent::CredentialAccepted,
+            NotificationEvent::CredentialDeleted,
+        ] {
+            discovery.send_notification(notification, event).await.unwrap();
+        }
+
+        // After deletion, the issuer no longer knows about the credential.
+        let error = discovery
+            .send_notification(notification, NotificationEvent::CredentialAccepted)
+            .await
+            .expect_err("sending notification about deleted credential should fail");
+        assert_matches!(
+            error,
+            WalletIssuanceError::Notification(err)
+                if matches!(err.error, RemoteErrorCode::Known(NotificationErrorCode::InvalidNotificationId))
+        );
+    }
+
+    // A notification with an unknown notification_id should be refused.
+    let mut unknown_notification = notifications.into_iter().next().unwrap();
+    unknown_notification.notification_id = "unknown".to_string();
+
+    let error = discovery
+        .send_notification(&unknown_notification, NotificationEvent::CredentialAccepted)
+        .await
+        .expect_err("sending notification with unknown notification_id should fail");
+    assert_matches!(
+        error,
+        WalletIssuanceError::Notification(err)
+            if matches!(err.error, RemoteErrorCode::Known(NotificationErrorCode::InvalidNotificationId))
+    );
+}
+
+#[tokio::test]
+async fn pre_authorized_code_flow_deferred() {
+    let attestation_count = NonZeroUsize::MIN;
+    let PreAuthCodeFlowServer {
+        issuer,
+        trust_anchors,
+        wia_keypair,
+        tls_trust_anchor,
+    } = start_pre_authorized_code_flow_server(attestation_count).await;
+
+    let documents = mock_issuable_documents(attestation_count);
+    let (credential_offer, session_token) = issuer.new_deferred_preauthorized_session(documents).await.unwrap();
+    let credential_offer_url = CredentialOfferContainer::new_offer(credential_offer).to_credential_offer_url();
+
+    let http_client = HttpClient::try_new(tls_reqwest_client_builder([tls_trust_anchor.into_certificate()])).unwrap();
+    let discovery = HttpIssuanceDiscovery::new(http_client);
+
+    let flow = discovery
+        .start(
+            &credential_offer_url,
+            None,
+            MOCK_WALLET_CLIENT_ID.to_string(),
+            REDIRECT_URI.parse().unwrap(),
+            &trust_anchors,
+            &MockWiaClient::new_with_wia_keypair(wia_keypair),
+            &trust_anchors,
+        )
+        .await
+        .unwrap();
+
+    let IssuanceFlow::PreAuthorizedCode {
+        issuance_session: mut session,
+    } = flow
+    else {
+        panic!("should have received Pre-Authorized Code flow");
+    };
+
+    let wscd = MockRemoteWscd::new(vec![]);
+    let outcome = session.accept_issuance(&trust_anchors, &wscd).await.unwrap();
+    let IssuanceOutcome::Deferred(deferred_issuance) = outcome else {
+        panic!("issuance should be deferred");
+    };
+
+    // As long as the issuer has not yet approved issuance, polling should result in a deferred issuance again.
+    let outcome = discovery
+        .poll_deferred_issuance(deferred_issuance, &trust_anchors)
+        .await
+        .unwrap();
+    let IssuanceOutcome::Deferred(deferred_issuance) = outcome else {
+        panic!("issuance should still be deferred");
+    };
+
+    issuer.approve_deferred_issuance(&session_token).await.unwrap();
+
+    // Deciding on the same session twice should not be possible.
+    let error = issuer
+        .reject_deferred_issuance(&session_token)
+        .await
+        .expect_err("rejecting approved deferred issuance should fail");
+    assert_matches!(error, DeferredIssuanceDecisionError::AlreadyDecided);
+
+    let outcome = discovery
+        .poll_deferred_issuance(deferred_issuance, &trust_anchors)
+        .await
+        .unwrap();
+
+    verify_issued_credentials(
+        outcome,
+        session.credential_previews().iter(),
+        attestation_count.get(),
+        4,
+    );
+}
+
 #[tokio::test]
 async fn pre_authorized_code_flow_rejects_unknown_client_id() {
     let attestation_count = NonZeroUsize::MIN;
@@ -491,6 +670,7 @@ async fn pre_authorized_code_flow_rejects_unknown_client_id() {
     let error = discovery
         .start(
             &credential_offer_url,
+            None,
             MOCK_WALLET_CLIENT_ID.to_string(),
             REDIRECT_URI.parse().unwrap(),
             &trust_anchors,
@@ -1119,6 +1299,7 @@ async fn token_rejects_grant_type_mismatch() {
     let token_request = TokenRequest {
         grant_type: TokenRequestGrantType::PreAuthorizedCode {
             pre_authorized_code: code,
+            tx_code: None,
         },
         client_id: Some(MOCK_WALLET_CLIENT_ID.to_string()),
         redirect_uri: Some(REDIRECT_URI.parse().unwrap()),
@@ -1335,7 +1516,10 @@ async fn pre_authorized_code_flow_rejects_token_request_scope() {
         .pre_authorized_code;
 
     let token_request = TokenRequest {
-        grant_type: TokenRequestGrantType::PreAuthorizedCode { pre_authorized_code },
+        grant_type: TokenRequestGrantType::PreAuthorizedCode {
+            pre_authorized_code,
+            tx_code: None,
+        },
         client_id: Some(MOCK_WALLET_CLIENT_ID.to_string()),
         redirect_uri: None,
         scope: Some(HashSet::from([
@@ -146,7 +146,7 @@ impl ObjectClaims {
         has_next: bool,
     ) -> Result<Vec<&'a str>, ClaimError>
     where
-        I: ExactSizeIterator<Item = &'a ClaimPath>,
+        I: ExactSizeIterator<Item = &'a ClaimPath> + Clone,
     {
         // Holds all digests that should be disclosed based on the `path`
         let mut digests = vec![];
@@ -173,7 +173,7 @@ impl ObjectClaims {
                     }
                 };
 
-                digests.append(&mut next_object.digests_to_disclose(path, disclosures, false)?);
+                digests.append(&mut next_object.digests_to_disclose(path, disclosures)?);
                 Ok(digests)
             }
             // We reached the the value we want to disclose, so add it to the list of digests
@@ -417,58 +417,44 @@ impl ClaimValue {
         &'a self,
         path: &mut Peekable<I>,
         disclosures: &'a IndexMap<String, Disclosure>,
-        traversing_array: bool,
     ) -> Result<Vec<&'a str>, ClaimError>
     where
-        I: ExactSizeIterator<Item = &'a ClaimPath>,
+        I: ExactSizeIterator<Item = &'a ClaimPath> + Clone,
     {
         // Holds all digests that should be disclosed based on the `path`
         let mut digests = vec![];
 
-        // If we are traversing an array, peekable shouldn't consume the next value
-        let (element_key, has_next) = if traversing_array {
-            (*path.peek().ok_or(ClaimError::EmptyPath)?, path.len() > 1)
-        } else {
-            (path.next().ok_or(ClaimError::EmptyPath)?, path.peek().is_some())
-        };
+        let element_key = path.next().ok_or(ClaimError::EmptyPath)?;
+        let has_next = path.peek().is_some();
 
         match (self, element_key) {
             (ClaimValue::Object(object_claims), _) => {
                 object_claims.digests_to_disclose(path, disclosures, element_key, has_next)
             }
-            (ClaimValue::Array(array_claims), ClaimPath::SelectByIndex(index)) if has_next => {
-                let entry = array_claims
-                    .get(*index)
-                    .ok_or_else(|| ClaimError::ElementNotFoundInArray(element_key.clone()))?;
-
-                if let Some(next_object) = entry.process_digests_to_disclose(disclosures, &mut digests)? {
-                    digests.append(&mut next_object.digests_to_disclose(path, disclosures, false)?);
-                } else {
-                    return Err(ClaimError::ElementNotFoundInArray(element_key.clone()));
-                }
-
-                Ok(digests)
-            }
             (ClaimValue::Array(array_claims), ClaimPath::SelectByIndex(index)) => {
-                let entry = array_claims
-                    .get(*index)
-                    .ok_or_else(|| ClaimError::ElementNotFoundInArray(element_key.clone()))?;
+                // If the array entry is selectively disclosable, its digest is added to the list of digests to
+                // disclose. An entry without a disclosure is a decoy, which cannot be selected.
+                let next_value = match array_claims.get(*index) {
+                    Some(entry) => entry.process_digests_to_disclose(disclosures, &mut digests)?,
+                    None => None,
+                }
+                .ok_or_else(|| ClaimError::ElementNotFoundInArray(element_key.clone()))?;
 
-                // If the array entry is an array-selective-disclosure object, then we'll add the digest to the
-                // list of digests to disclose.
-                if let ArrayClaim::Hash { digest } = entry {
-                    digests.push(digest.as_ref());
+                if has_next {
+                    digests.append(&mut next_value.digests_to_disclose(path, disclosures)?);
                 }
                 Ok(digests)
             }
             (ClaimValue::Array(array_claims), ClaimPath::SelectAll) => {
                 for entry in array_claims {
-                    let next_object = entry
-                        .process_digests_to_disclose(disclosures, &mut digests)?
-                        .ok_or_else(|| ClaimError::ElementNotFoundInArray(element_key.clone()))?;
+                    // Skip any entries without a disclosure, as these are decoys.
+                    let Some(next_value) = entry.process_digests_to_disclose(disclosures, &mut digests)? else {
+                        continue;
+                    };
 
+                    // Every array entry is traversed using its own copy of the remainder of the path.
                     if has_next {
-                        digests.append(&mut next_object.digests_to_disclose(path, disclosures, true)?);
+                        digests.append(&mut next_value.digests_to_disclose(&mut path.clone(), disclosures)?);
                     }
                 }
                 Ok(digests)
@@ -500,9 +486,10 @@ impl ClaimValue {
         let path_item = &claim_path[claim_path_index];
 
         match (path_item, self) {
-            (ClaimPath::SelectByIndex(index), _) => {
-                Err(ClaimError::UnsupportedTraversalPath(ClaimPath::SelectByIndex(*index)))
-            }
+            (ClaimPath::SelectByIndex(index), ClaimValue::Array(array_claims)) => array_claims
+                .get(*index)
+                .ok_or_else(|| ClaimError::ElementNotFoundInArray(path_item.clone()))?
+                .verify_selective_disclosability(claim_path, claim_path_index, disclosures, sd_metadata),
             (ClaimPath::SelectByKey(key), ClaimValue::Object(object_claims)) => object_claims
                 .verify_selective_disclosability(claim_path, claim_path_index, disclosures, sd_metadata, key),
             (ClaimPath::SelectAll, ClaimValue::Array(array_claims)) => {
@@ -587,19 +574,18 @@ impl ArrayClaim {
         digests: &mut Vec<&'a str>,
     ) -> Result<Option<&'a ClaimValue>, ClaimError> {
         match self {
-            ArrayClaim::Hash { digest } => {
-                // We're disclosing something within a selectively disclosable array entry.
-                // For the verifier to be able to verify that, we'll also have to disclose that entry.
-                digests.push(digest.as_ref());
-
-                match disclosures.get(digest) {
-                    Some(disclosure) => {
-                        let (_, value) = disclosure.content.try_as_array_element(digest.as_ref())?;
-                        value.process_digests_to_disclose(disclosures, digests)
-                    }
-                    None => Ok(None),
+            ArrayClaim::Hash { digest } => match disclosures.get(digest) {
+                Some(disclosure) => {
+                    // We're disclosing (something within) a selectively disclosable array entry.
+                    // For the verifier to be able to verify that, we'll also have to disclose that entry.
+                    digests.push(digest.as_ref());
+
+                    let (_, value) = disclosure.content.try_as_array_element(digest.as_ref())?;
+                    value.process_digests_to_disclose(disclosures, digests)
                 }
-            }
+                // There is no disclosure for this digest, which means that it is a decoy.
+                None => Ok(None),
+            },
             ArrayClaim::Value(entry) => {
                 // This array entry is not selectively disclosable, so we just return it verbatim.
                 Ok(Some(entry))
@@ -20,6 +20,7 @@ use crate::claims::ClaimNameError;
 use crate::claims::ClaimType;
 use crate::claims::ClaimValue;
 use crate::claims::ObjectClaims;
+use crate::jwt_vc_issuer::JwtVcIssuerError;
 use crate::sd_alg::SdAlg;
 
 #[derive(Debug, thiserror::Error)]
@@ -96,6 +97,18 @@ pub enum DecoderError {
     #[error("SD-JWT format is invalid, no segments found")]
     MissingSegments,
 
+    #[error("SD-JWT JWS JSON serialization is invalid, unprotected header doesn't contain disclosures")]
+    MissingJwsJsonDisclosures,
+
+    #[error("SD-JWT JWS JSON serialization is invalid, unprotected header doesn't contain a KB-JWT")]
+    MissingJwsJsonKeyBindingJwt,
+
+    #[error("SD-JWT JWS JSON serialization is invalid, unprotected header contains an unexpected KB-JWT")]
+    UnexpectedJwsJsonKeyBindingJwt,
+
+    #[error("JSON deserialization of SD-JWT JWS JSON serialization failed: {0}")]
+    JwsJsonDeserialization(#[source] serde_json::Error),
+
     #[error("hash occurs multiple times in SD-JWT: {0}")]
     DuplicateHash(String),
 
@@ -129,6 +142,12 @@ pub enum DecoderError {
     #[error("unable to extract DN from issuer certificate: {0}")]
     IssuerDnExtraction(#[from] CertificateError),
 
+    #[error("unable to resolve issuer key from JWT VC Issuer Metadata: {0}")]
+    JwtVcIssuer(#[from] JwtVcIssuerError),
+
+    #[error("revocation status cannot be verified without issuer certificate chain")]
+    UnverifiableRevocationStatus,
+
     #[error("invalid KB-JWT: {0}")]
     KeyBinding(#[from] KeyBindingError),
 }
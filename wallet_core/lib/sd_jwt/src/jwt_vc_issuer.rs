@@ -0,0 +1,415 @@
+//! Resolution of SD-JWT VC issuer keys using JWT VC Issuer Metadata, as an alternative to an `x5c` certificate chain in
+//! the header of the issuer-signed JWT.
+//!
+//! The metadata is retrieved from the well-known location derived from the `iss` claim, after which the public key is
+//! looked up in its (embedded or referenced) JWKS by the `kid` in the header. Only issuers that are explicitly allowed
+//! by the [`JwtVcIssuerPolicy`] are resolved, which serves the same purpose as the trust anchors do for `x5c`.
+//!
+//! <https://www.ietf.org/archive/id/draft-ietf-oauth-sd-jwt-vc-10.html#name-jwt-vc-issuer-metadata>
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crypto::PublicKey;
+use http_utils::urls::HttpsUri;
+use jwt::error::JwkConversionError;
+use jwt::jwk::JwkSet;
+use jwt::jwk::jwk_to_public_key;
+use moka::future::Cache;
+use reqwest::ClientBuilder;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+use url::Url;
+
+pub const JWT_VC_ISSUER_METADATA_PATH: &str = ".well-known/jwt-vc-issuer";
+
+/// The minimum amount of time between fetching the JWKS of an issuer again because of an unknown `kid`.
+const UNKNOWN_KID_REFETCH_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, thiserror::Error)]
+pub enum JwtVcIssuerError {
+    #[error("issuer is not allowed by policy: {0}")]
+    IssuerNotAllowed(HttpsUri),
+
+    #[error("networking error: {0}")]
+    Networking(#[from] reqwest::Error),
+
+    #[error("issuer in metadata does not match, expected: {expected}, found: {found}")]
+    IssuerMismatch { expected: HttpsUri, found: HttpsUri },
+
+    #[error("issuer metadata should contain exactly one of `jwks` and `jwks_uri`")]
+    InvalidJwksConfiguration,
+
+    #[error("no key found in issuer JWKS with kid: {0}")]
+    KeyNotFound(String),
+
+    #[error("error converting JWK to public key: {0}")]
+    Jwk(#[from] JwkConversionError),
+}
+
+/// JWT VC Issuer Metadata, containing either the JWKS of the issuer by value in `jwks`, or a reference to it in
+/// `jwks_uri`.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JwtVcIssuerMetadata {
+    pub issuer: HttpsUri,
+    pub jwks_uri: Option<Url>,
+    pub jwks: Option<JwkSet>,
+}
+
+impl JwtVcIssuerMetadata {
+    /// Returns the URL at which the JWT VC Issuer Metadata of `issuer` is located, which is constructed by inserting
+    /// the well-known path between the host and the path component of the issuer identifier.
+    pub fn metadata_url(issuer: &HttpsUri) -> Url {
+        let mut url: Url = issuer
+            .to_string()
+            .parse()
+            .expect("HttpsUri should always be a valid URL");
+        let path = format!("/{JWT_VC_ISSUER_METADATA_PATH}{}", url.path().trim_end_matches('/'));
+        url.set_path(&path);
+        url.set_query(None);
+        url.set_fragment(None);
+
+        url
+    }
+}
+
+/// Policy that determines for which issuers the signing key may be resolved using JWT VC Issuer Metadata.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JwtVcIssuerPolicy {
+    allowed_issuers: Vec<HttpsUri>,
+}
+
+impl JwtVcIssuerPolicy {
+    pub fn new(allowed_issuers: Vec<HttpsUri>) -> Self {
+        Self { allowed_issuers }
+    }
+
+    pub fn is_allowed(&self, issuer: &HttpsUri) -> bool {
+        self.allowed_issuers.contains(issuer)
+    }
+}
+
+#[trait_variant::make(Send)]
+pub trait JwtVcIssuerMetadataClient {
+    async fn fetch_metadata(&self, url: Url) -> Result<JwtVcIssuerMetadata, reqwest::Error>;
+
+    async fn fetch_jwks(&self, url: Url) -> Result<JwkSet, reqwest::Error>;
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpJwtVcIssuerMetadataClient {
+    client: reqwest::Client,
+}
+
+impl HttpJwtVcIssuerMetadataClient {
+    pub fn new(client_builder: ClientBuilder) -> Result<Self, reqwest::Error> {
+        let client = client_builder.build()?;
+
+        Ok(Self { client })
+    }
+}
+
+impl JwtVcIssuerMetadataClient for HttpJwtVcIssuerMetadataClient {
+    async fn fetch_metadata(&self, url: Url) -> Result<JwtVcIssuerMetadata, reqwest::Error> {
+        self.client.get(url).send().await?.error_for_status()?.json().await
+    }
+
+    async fn fetch_jwks(&self, url: Url) -> Result<JwkSet, reqwest::Error> {
+        self.client.get(url).send().await?.error_for_status()?.json().await
+    }
+}
+
+/// Resolves the public keys of issuers allowed by the [`JwtVcIssuerPolicy`], caching the JWKS of each issuer.
+#[derive(Debug)]
+pub struct JwtVcIssuerKeyResolver<C> {
+    cache: Cache<String, JwkSet>,
+    refetched_issuers: Cache<String, ()>,
+    client: Arc<C>,
+    policy: JwtVcIssuerPolicy,
+}
+
+impl<C> JwtVcIssuerKeyResolver<C>
+where
+    C: JwtVcIssuerMetadataClient,
+{
+    pub fn new(client: Arc<C>, policy: JwtVcIssuerPolicy, cache_capacity: u64, ttl: Duration) -> Self {
+        let cache = Cache::builder().max_capacity(cache_capacity).time_to_live(ttl).build();
+        let refetched_issuers = Cache::builder()
+            .max_capacity(cache_capacity)
+            .time_to_live(UNKNOWN_KID_REFETCH_INTERVAL)
+            .build();
+
+        Self {
+            cache,
+            refetched_issuers,
+            client,
+            policy,
+        }
+    }
+
+    pub fn policy(&self) -> &JwtVcIssuerPolicy {
+        &self.policy
+    }
+
+    /// Resolve the public key identified by `kid` of `issuer`. If the key cannot be found in the cached JWKS, the
+    /// JWKS is fetched again, as the issuer may have rotated its keys in the meantime. This happens at most once a
+    /// minute for each issuer, so that presenting SD-JWTs with unknown `kid` values cannot
+    /// be used to make the verifier flood the issuer with requests.
+    pub async fn resolve(&self, issuer: &HttpsUri, kid: &str) -> Result<PublicKey, JwtVcIssuerError> {
+        if !self.policy.is_allowed(issuer) {
+            return Err(JwtVcIssuerError::IssuerNotAllowed(issuer.clone()));
+        }
+
+        let cache_key = issuer.to_string();
+        if let Some(jwks) = self.cache.get(&cache_key).await {
+            if let Some(jwk) = jwks.find(kid) {
+                return Ok(jwk_to_public_key(jwk)?);
+            }
+
+            if self.refetched_issuers.contains_key(&cache_key) {
+                return Err(JwtVcIssuerError::KeyNotFound(kid.to_string()));
+            }
+
+            self.refetched_issuers.insert(cache_key.clone(), ()).await;
+        }
+
+        let jwks = self.fetch_jwks(issuer).await?;
+        let public_key = jwks.find(kid).map(jwk_to_public_key).transpose()?;
+        self.cache.insert(cache_key, jwks).await;
+
+        public_key.ok_or_else(|| JwtVcIssuerError::KeyNotFound(kid.to_string()))
+    }
+
+    async fn fetch_jwks(&self, issuer: &HttpsUri) -> Result<JwkSet, JwtVcIssuerError> {
+        let metadata = self
+            .client
+            .fetch_metadata(JwtVcIssuerMetadata::metadata_url(issuer))
+            .await?;
+
+        if metadata.issuer != *issuer {
+            return Err(JwtVcIssuerError::IssuerMismatch {
+                expected: issuer.clone(),
+                found: metadata.issuer,
+            });
+        }
+
+        match (metadata.jwks, metadata.jwks_uri) {
+            (Some(jwks), None) => Ok(jwks),
+            (None, Some(jwks_uri)) => Ok(self.client.fetch_jwks(jwks_uri).await?),
+            _ => Err(JwtVcIssuerError::InvalidJwksConfiguration),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use http_utils::httpmock::httpmock_reqwest_client_builder;
+    use httpmock::Method::GET;
+    use httpmock::MockServer;
+    use jwt::jwk::jwk_from_public_key;
+    use p256::ecdsa::SigningKey;
+    use p256::elliptic_curve::Generate;
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::*;
+
+    fn jwks(keys: &[(&str, &SigningKey)]) -> JwkSet {
+        let keys = keys
+            .iter()
+            .map(|(kid, key)| {
+                let mut jwk = jwk_from_public_key(&PublicKey::from(*key.verifying_key())).unwrap();
+                jwk.common.key_id = Some(kid.to_string());
+                jwk
+            })
+            .collect();
+
+        JwkSet { keys }
+    }
+
+    fn resolver(issuer: &HttpsUri) -> JwtVcIssuerKeyResolver<HttpJwtVcIssuerMetadataClient> {
+        JwtVcIssuerKeyResolver::new(
+            Arc::new(HttpJwtVcIssuerMetadataClient::new(httpmock_reqwest_client_builder()).unwrap()),
+            JwtVcIssuerPolicy::new(vec![issuer.clone()]),
+            10,
+            Duration::from_secs(60),
+        )
+    }
+
+    #[rstest]
+    #[case("https://example.com", "https://example.com/.well-known/jwt-vc-issuer")]
+    #[case("https://example.com/", "https://example.com/.well-known/jwt-vc-issuer")]
+    #[case(
+        "https://example.com/tenant/1234",
+        "https://example.com/.well-known/jwt-vc-issuer/tenant/1234"
+    )]
+    #[case(
+        "https://example.com:8443/tenant/",
+        "https://example.com:8443/.well-known/jwt-vc-issuer/tenant"
+    )]
+    fn test_metadata_url(#[case] issuer: &str, #[case] expected: &str) {
+        let url = JwtVcIssuerMetadata::metadata_url(&issuer.parse().unwrap());
+
+        assert_eq!(url.as_str(), expected);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_embedded_jwks() {
+        let server = MockServer::start_async().await;
+        let issuer: HttpsUri = server.url("/issuer").parse().unwrap();
+        let signing_key = SigningKey::generate();
+
+        let metadata_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path("/.well-known/jwt-vc-issuer/issuer");
+                then.status(200).json_body(json!({
+                    "issuer": issuer,
+                    "jwks": jwks(&[("key-1", &signing_key)]),
+                }));
+            })
+            .await;
+
+        let resolver = resolver(&issuer);
+        let public_key = resolver.resolve(&issuer, "key-1").await.unwrap();
+        assert_eq!(public_key, PublicKey::from(*signing_key.verifying_key()));
+
+        // The second resolution should be served from the cache
+        resolver.resolve(&issuer, "key-1").await.unwrap();
+        metadata_mock.assert_calls_async(1).await;
+    }
+
+    #[tokio::test]
+    async fn test_resolve_jwks_uri() {
+        let server = MockServer::start_async().await;
+        let issuer: HttpsUri = server.url("/issuer").parse().unwrap();
+        let signing_key = SigningKey::generate();
+
+        let _metadata_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path("/.well-known/jwt-vc-issuer/issuer");
+                then.status(200).json_body(json!({
+                    "issuer": issuer,
+                    "jwks_uri": server.url("/jwks"),
+                }));
+            })
+            .await;
+        let jwks_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path("/jwks");
+                then.status(200).json_body(json!(jwks(&[("key-1", &signing_key)])));
+            })
+            .await;
+
+        let public_key = resolver(&issuer).resolve(&issuer, "key-1").await.unwrap();
+
+        assert_eq!(public_key, PublicKey::from(*signing_key.verifying_key()));
+        jwks_mock.assert_calls_async(1).await;
+    }
+
+    #[tokio::test]
+    async fn test_resolve_refetches_on_unknown_kid() {
+        let server = MockServer::start_async().await;
+        let issuer: HttpsUri = server.url("/issuer").parse().unwrap();
+        let old_key = SigningKey::generate();
+        let new_key = SigningKey::generate();
+
+        let old_metadata_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path("/.well-known/jwt-vc-issuer/issuer");
+                then.status(200).json_body(json!({
+                    "issuer": issuer,
+                    "jwks": jwks(&[("key-1", &old_key)]),
+                }));
+            })
+            .await;
+
+        let resolver = resolver(&issuer);
+        resolver.resolve(&issuer, "key-1").await.unwrap();
+
+        // Rotate the issuer key, which should be picked up despite the JWKS being cached
+        old_metadata_mock.delete_async().await;
+        let new_metadata_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path("/.well-known/jwt-vc-issuer/issuer");
+                then.status(200).json_body(json!({
+                    "issuer": issuer,
+                    "jwks": jwks(&[("key-2", &new_key)]),
+                }));
+            })
+            .await;
+
+        let public_key = resolver.resolve(&issuer, "key-2").await.unwrap();
+        assert_eq!(public_key, PublicKey::from(*new_key.verifying_key()));
+
+        // Another unknown `kid` should not cause the JWKS to be fetched again that soon after the previous time
+        let error = resolver.resolve(&issuer, "key-3").await.unwrap_err();
+        assert_matches!(error, JwtVcIssuerError::KeyNotFound(kid) if kid == "key-3");
+
+        new_metadata_mock.assert_calls_async(1).await;
+    }
+
+    #[tokio::test]
+    async fn test_resolve_issuer_not_allowed() {
+        let server = MockServer::start_async().await;
+        let issuer: HttpsUri = server.url("/issuer").parse().unwrap();
+        let other_issuer: HttpsUri = server.url("/other_issuer").parse().unwrap();
+
+        let metadata_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path("/.well-known/jwt-vc-issuer/other_issuer");
+                then.status(200).json_body(json!({
+                    "issuer": other_issuer,
+                    "jwks": jwks(&[("key-1", &SigningKey::generate())]),
+                }));
+            })
+            .await;
+
+        let error = resolver(&issuer).resolve(&other_issuer, "key-1").await.unwrap_err();
+
+        assert_matches!(error, JwtVcIssuerError::IssuerNotAllowed(_));
+        metadata_mock.assert_calls_async(0).await;
+    }
+
+    #[tokio::test]
+    async fn test_resolve_issuer_mismatch() {
+        let server = MockServer::start_async().await;
+        let issuer: HttpsUri = server.url("/issuer").parse().unwrap();
+
+        let _metadata_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path("/.well-known/jwt-vc-issuer/issuer");
+                then.status(200).json_body(json!({
+                    "issuer": "https://other.example.com",
+                    "jwks": jwks(&[("key-1", &SigningKey::generate())]),
+                }));
+            })
+            .await;
+
+        let error = resolver(&issuer).resolve(&issuer, "key-1").await.unwrap_err();
+
+        assert_matches!(error, JwtVcIssuerError::IssuerMismatch { .. });
+    }
+
+    #[tokio::test]
+    async fn test_resolve_invalid_jwks_configuration() {
+        let server = MockServer::start_async().await;
+        let issuer: HttpsUri = server.url("/issuer").parse().unwrap();
+
+        let _metadata_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path("/.well-known/jwt-vc-issuer/issuer");
+                then.status(200).json_body(json!({
+                    "issuer": issuer,
+                }));
+            })
+            .await;
+
+        let error = resolver(&issuer).resolve(&issuer, "key-1").await.unwrap_err();
+
+        assert_matches!(error, JwtVcIssuerError::InvalidJwksConfiguration);
+    }
+}
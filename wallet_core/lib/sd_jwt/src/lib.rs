@@ -19,6 +19,8 @@
 //!     only `Sha256` is implemented via `Sha256Hasher`.
 //! - JWT. The underlying JWT handling (sign/verify) is provided by the `jwt` crate. This crate uses:
 //!   - `HeaderWithX5c` when issuing SD-JWTs (issuer certificate chain embedded in `x5c`),
+//!   - `HeaderWithKid` when verifying SD-JWTs of issuers that publish their keys in JWT VC Issuer Metadata instead (see
+//!     [`jwt_vc_issuer`]),
 //!   - the holder key binding lives in the `cnf` claim as a JWK (`ConfirmationClaim`).
 //! - SD-JWTs. Note: to be able to parse the examples from the spec, generics `<C, H>` are provided, but limited to
 //!   what's needed in the examples/tests:
@@ -39,6 +41,10 @@
 //!     holder key from the `cnf` claim. If both signatures are verified, the disclosures will be parsed.
 //!   - [`VerifiedSdJwtPresentation`](sd_jwt::VerifiedSdJwtPresentation): verified SD-JWT presentation with verified
 //!     disclosures and verified KB-JWT.
+//! - Serialization. SD-JWTs and presentations are formatted using the compact `~`-separated serialization. Both
+//!   `UnverifiedSdJwt` and `UnverifiedSdJwtPresentation` can also be parsed from and converted to the flattened or
+//!   general syntax of the JWS JSON serialization (see [`SdJwtSerialization`](sd_jwt::SdJwtSerialization)), in which
+//!   the disclosures and the KB-JWT are included in the unprotected header.
 //!
 //! # Usage
 //! - For issuance, issuer side
@@ -58,7 +64,9 @@
 //!   5. Send the `SignedSdJwtPresentation` to the verifier.
 //! - For verification, verifier side
 //!   1. Deserialize with `UnverifiedSdJwtPresentation::parse`.
-//!   2. Verify the SD-JWT and the KB-JWT with `UnverifiedSdJwtPresentation::into_verified_against_trust_anchors`.
+//!   2. Verify the SD-JWT and the KB-JWT with `UnverifiedSdJwtPresentation::into_verified_against_trust_anchors`, or
+//!      with `UnverifiedSdJwtPresentation::into_verified_against_jwt_vc_issuer` if the issuer-signed JWT does not
+//!      contain an `x5c` header.
 //!   3. Obtain decoded claims with `VerifiedSdJwtPresentation::sd_jwt().decoded_claims()`.
 //!
 //! # Example; issuance, presentation, and verification
@@ -167,6 +175,7 @@ pub mod disclosure;
 mod encoder;
 pub mod error;
 pub mod hasher;
+pub mod jwt_vc_issuer;
 pub mod key_binding_jwt;
 mod sd_alg;
 pub mod sd_jwt;
@@ -309,7 +318,11 @@ mod tests {
     )]
     #[case(all_claims(),
            vec![ClaimPath::SelectByIndex(0)],
-           |actual| assert_eq!(actual, ClaimError::UnsupportedTraversalPath(ClaimPath::SelectByIndex(0)))
+           |actual| assert_matches!(actual, ClaimError::UnexpectedElement(_, path) if path == vec![ClaimPath::SelectByIndex(0)])
+    )]
+    #[case(all_claims(),
+           vec![ClaimPath::SelectByKey("root_array".to_string()), ClaimPath::SelectByIndex(3)],
+           |actual| assert_eq!(actual, ClaimError::ElementNotFoundInArray(ClaimPath::SelectByIndex(3)))
     )]
     #[case(all_claims(),
            vec![ClaimPath::SelectByKey("missing_root_value".to_string())],
@@ -25,7 +25,9 @@ use indexmap::IndexMap;
 use indexmap::IndexSet;
 use itertools::Itertools;
 use jsonwebtoken::Algorithm;
-#[cfg(any(test, feature = "examples"))]
+use jwt::JsonJwt;
+use jwt::JsonJwtSignature;
+use jwt::JsonJwtSignatures;
 use jwt::JwtDecodingKey;
 use jwt::JwtTyp;
 use jwt::JwtValidation;
@@ -34,11 +36,14 @@ use jwt::ValidationWrapper;
 use jwt::VerifiedJwt;
 use jwt::confirmation::ConfirmationClaim;
 use jwt::error::JwkConversionError;
+use jwt::error::JwtParseError;
+use jwt::headers::HeaderWithKid;
 use jwt::headers::HeaderWithX5c;
 use sd_jwt_vc_metadata::ClaimSelectiveDisclosureMetadata;
 use serde::Deserialize;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
+use serde_json::Value;
 use serde_with::DeserializeFromStr;
 use serde_with::SerializeDisplay;
 use serde_with::skip_serializing_none;
@@ -51,6 +56,7 @@ use utils::generator::Generator;
 use utils::vec_at_least::IntoNonEmptyIterator;
 use utils::vec_at_least::NonEmptyIterator;
 use utils::vec_at_least::VecNonEmpty;
+use utils::vec_nonempty;
 
 use crate::claims::ClaimType;
 use crate::claims::ClaimValue;
@@ -62,6 +68,8 @@ use crate::error::ClaimError;
 use crate::error::DecoderError;
 use crate::error::SigningError;
 use crate::hasher::Hasher;
+use crate::jwt_vc_issuer::JwtVcIssuerKeyResolver;
+use crate::jwt_vc_issuer::JwtVcIssuerMetadataClient;
 use crate::key_binding_jwt::KbVerificationOptions;
 use crate::key_binding_jwt::KeyBindingJwtBuilder;
 use crate::key_binding_jwt::SignedKeyBindingJwt;
@@ -93,10 +101,44 @@ impl SdJwtClaims for SdJwtVcClaims {
     }
 }
 
+/// Name of the unprotected header member that contains the disclosures in the JWS JSON serialization.
+const JWS_JSON_DISCLOSURES_HEADER: &str = "disclosures";
+
+/// Name of the unprotected header member that contains the KB-JWT in the JWS JSON serialization.
+const JWS_JSON_KB_JWT_HEADER: &str = "kb_jwt";
+
+/// The serializations of an SD-JWT (presentation).
+/// <https://www.ietf.org/archive/id/draft-ietf-oauth-selective-disclosure-jwt-22.html#section-8>
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SdJwtSerialization {
+    /// The `~`-separated compact serialization.
+    #[default]
+    Compact,
+    /// The flattened syntax of the JWS JSON serialization, which contains a single signature.
+    JwsJsonFlattened,
+    /// The general syntax of the JWS JSON serialization, which may contain multiple signatures over the payload.
+    JwsJsonGeneral,
+}
+
+impl<C, H> From<&JsonJwt<C, H>> for SdJwtSerialization {
+    fn from(value: &JsonJwt<C, H>) -> Self {
+        match value.signatures {
+            JsonJwtSignatures::General { .. } => Self::JwsJsonGeneral,
+            JsonJwtSignatures::Flattened { .. } => Self::JwsJsonFlattened,
+        }
+    }
+}
+
+fn is_jws_json(s: &str) -> bool {
+    s.trim_start().starts_with('{')
+}
+
 /// An SD-JWT that has been split into parts but not verified yet.
 ///
 /// There's no need to keep the SD-JWT as serialized form as there is no KB-JWT. Formats as `<Issuer-signed
-/// JWT>~<Disclosure>~...~<Disclosure>~`.
+/// JWT>~<Disclosure>~...~<Disclosure>~`. Parsing also accepts the JWS JSON serialization, which can be produced using
+/// [`UnverifiedSdJwt::to_jws_json_flattened`] and [`UnverifiedSdJwt::to_jws_json_general`].
 ///
 /// Use [`UnverifiedSdJwt::into_verified_against_trust_anchors`] to validate the SD-JWT against provided trust
 /// anchors.
@@ -104,6 +146,10 @@ impl SdJwtClaims for SdJwtVcClaims {
 pub struct UnverifiedSdJwt<C = SdJwtVcClaims, H = HeaderWithX5c> {
     issuer_signed: UnverifiedJwt<C, H>,
     disclosures: Vec<String>,
+    /// Any signatures over the issuer-signed payload other than the one in `issuer_signed`, which are only present
+    /// when parsed from the general syntax of the JWS JSON serialization. These are retained in order to include them
+    /// when converting back to that syntax, but are never verified.
+    additional_signatures: Vec<JsonJwtSignature>,
 }
 
 impl<C, H> UnverifiedSdJwt<C, H> {
@@ -111,7 +157,103 @@ impl<C, H> UnverifiedSdJwt<C, H> {
         Self {
             issuer_signed,
             disclosures,
+            additional_signatures: Vec::new(),
+        }
+    }
+
+    /// Converts the SD-JWT to the flattened syntax of the JWS JSON serialization. Note that this syntax cannot
+    /// contain any additional signatures.
+    pub fn to_jws_json_flattened(&self) -> Result<JsonJwt<C, H>, JwtParseError>
+    where
+        C: Clone,
+        H: Clone,
+    {
+        self.to_jws_json(None, false)
+    }
+
+    /// Converts the SD-JWT to the general syntax of the JWS JSON serialization.
+    pub fn to_jws_json_general(&self) -> Result<JsonJwt<C, H>, JwtParseError>
+    where
+        C: Clone,
+        H: Clone,
+    {
+        self.to_jws_json(None, true)
+    }
+
+    /// Converts the SD-JWT to the JWS JSON serialization, where the disclosures and the optional KB-JWT are included
+    /// in the unprotected header of the first signature.
+    /// <https://www.ietf.org/archive/id/draft-ietf-oauth-selective-disclosure-jwt-22.html#section-8.1>
+    fn to_jws_json(
+        &self,
+        key_binding_jwt: Option<&UnverifiedKeyBindingJwt>,
+        general: bool,
+    ) -> Result<JsonJwt<C, H>, JwtParseError>
+    where
+        C: Clone,
+        H: Clone,
+    {
+        let mut json_jwt = JsonJwt::try_from(vec_nonempty![self.issuer_signed.clone()])?;
+
+        let mut header = HashMap::from([(
+            JWS_JSON_DISCLOSURES_HEADER.to_string(),
+            Value::from(self.disclosures.clone()),
+        )]);
+        if let Some(key_binding_jwt) = key_binding_jwt {
+            header.insert(
+                JWS_JSON_KB_JWT_HEADER.to_string(),
+                Value::from(key_binding_jwt.to_string()),
+            );
         }
+
+        // A `JsonJwt` converted from a single JWT contains exactly one signature.
+        let signature = JsonJwtSignature {
+            header,
+            ..json_jwt.signatures.into_iter().next().unwrap()
+        };
+
+        json_jwt.signatures = if general {
+            let mut signatures = vec_nonempty![signature];
+            signatures.extend(self.additional_signatures.iter().cloned());
+
+            JsonJwtSignatures::General { signatures }
+        } else {
+            JsonJwtSignatures::Flattened { signature }
+        };
+
+        Ok(json_jwt)
+    }
+
+    /// Parses an SD-JWT from the JWS JSON serialization, returning the KB-JWT separately if present.
+    fn from_jws_json(json_jwt: JsonJwt<C, H>) -> Result<(Self, Option<UnverifiedKeyBindingJwt>), DecoderError> {
+        let JsonJwt {
+            payload, signatures, ..
+        } = json_jwt;
+
+        let mut signatures = signatures.into_iter();
+        let mut signature = signatures.next().ok_or(DecoderError::MissingIssuerSignedJwt)?;
+
+        let disclosures = signature
+            .header
+            .remove(JWS_JSON_DISCLOSURES_HEADER)
+            .ok_or(DecoderError::MissingJwsJsonDisclosures)?;
+        let disclosures = serde_json::from_value(disclosures).map_err(DecoderError::JwsJsonDeserialization)?;
+
+        let key_binding_jwt = signature
+            .header
+            .remove(JWS_JSON_KB_JWT_HEADER)
+            .map(serde_json::from_value)
+            .transpose()
+            .map_err(DecoderError::JwsJsonDeserialization)?;
+
+        let issuer_signed = [signature.protected, payload, signature.signature].join(".").parse()?;
+
+        let sd_jwt = Self {
+            issuer_signed,
+            disclosures,
+            additional_signatures: signatures.collect(),
+        };
+
+        Ok((sd_jwt, key_binding_jwt))
     }
 
     fn dangerous_parse_unverified(&self) -> Result<VerifiedSdJwt<C, H>, DecoderError>
@@ -154,16 +296,30 @@ impl<C, H> FromStr for UnverifiedSdJwt<C, H> {
     type Err = DecoderError;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if is_jws_json(s) {
+            return serde_json::from_str::<JsonJwt<C, H>>(s)
+                .map_err(DecoderError::JwsJsonDeserialization)?
+                .try_into();
+        }
+
         let s = s.strip_suffix("~").ok_or(DecoderError::MissingFinalTilde)?;
 
         let mut segments = s.split('~');
         let issuer_signed = segments.next().ok_or(DecoderError::MissingIssuerSignedJwt)?.parse()?;
         let disclosures = segments.map(ToString::to_string).collect_vec();
 
-        Result::Ok(UnverifiedSdJwt {
-            issuer_signed,
-            disclosures,
-        })
+        Result::Ok(UnverifiedSdJwt::new(issuer_signed, disclosures))
+    }
+}
+
+impl<C, H> TryFrom<JsonJwt<C, H>> for UnverifiedSdJwt<C, H> {
+    type Error = DecoderError;
+
+    fn try_from(json_jwt: JsonJwt<C, H>) -> Result<Self, Self::Error> {
+        match Self::from_jws_json(json_jwt)? {
+            (sd_jwt, None) => Ok(sd_jwt),
+            (_, Some(_)) => Err(DecoderError::UnexpectedJwsJsonKeyBindingJwt),
+        }
     }
 }
 
@@ -247,10 +403,7 @@ impl From<VerifiedSdJwt> for UnverifiedSdJwt {
             .map(|disclosure| disclosure.encoded)
             .collect();
 
-        Self {
-            issuer_signed: sd_jwt.issuer_signed.into(),
-            disclosures,
-        }
+        Self::new(sd_jwt.issuer_signed.into(), disclosures)
     }
 }
 
@@ -363,16 +516,18 @@ impl<C> VerifiedSdJwt<C, HeaderWithX5c> {
     }
 }
 
+impl<C> VerifiedSdJwt<C, HeaderWithKid> {
+    pub fn issuer_kid(&self) -> &str {
+        &self.issuer_signed.header().kid
+    }
+}
+
 impl VerifiedSdJwt {
     /// Prepares this SD-JWT for presentation, returning a builder that can be used to select which claims to disclose.
     pub fn into_presentation_builder(self) -> SdJwtPresentationBuilder {
         SdJwtPresentationBuilder::new(self)
     }
 
-    pub fn holder_pubkey(&self) -> Result<PublicKey, JwkConversionError> {
-        self.claims().cnf().try_to_public_key()
-    }
-
     /// Parses an SD-JWT into its components as [`VerifiedSdJwt`] without verifying the signature. Note that this should
     /// only be used when receiving the SD-JWT over a trusted channel (i.e. from the database).
     pub fn dangerous_parse_unverified(s: &str) -> Result<Self, DecoderError> {
@@ -416,6 +571,10 @@ pub fn verify_selective_disclosability(
 }
 
 impl<H> VerifiedSdJwt<SdJwtVcClaims, H> {
+    pub fn holder_pubkey(&self) -> Result<PublicKey, JwkConversionError> {
+        self.claims().cnf().try_to_public_key()
+    }
+
     /// Decodes the SD-protected claims by substituting matched disclosures, returning a plain `ObjectClaims` structure
     /// without `_sd` claims or digests.
     pub fn decoded_claims(&self) -> Result<ObjectClaims, DecoderError> {
@@ -481,7 +640,8 @@ impl UnsignedSdJwtPresentation {
 
 /// Parsed but not yet verified SD-JWT Presentation consisting of an SD-JWT and a Key Binding JWT (KB-JWT).
 ///
-/// Formats as `<SD-JWT>~<KB-JWT>`.
+/// Formats as `<SD-JWT>~<KB-JWT>`. Parsing also accepts the JWS JSON serialization, in which the KB-JWT is included in
+/// the unprotected header.
 #[derive(Debug, Clone, Eq, PartialEq, SerializeDisplay, DeserializeFromStr)]
 pub struct UnverifiedSdJwtPresentation<C = SdJwtVcClaims, H = HeaderWithX5c> {
     sd_jwt: UnverifiedSdJwt<C, H>,
@@ -492,6 +652,12 @@ impl<C, H> FromStr for UnverifiedSdJwtPresentation<C, H> {
     type Err = DecoderError;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if is_jws_json(s) {
+            return serde_json::from_str::<JsonJwt<C, H>>(s)
+                .map_err(DecoderError::JwsJsonDeserialization)?
+                .try_into();
+        }
+
         let sd_jwt_end = s.rfind("~").ok_or(DecoderError::MissingSegments)? + 1;
         // the SD-JWT part includes the trailing '~'
 
@@ -505,12 +671,39 @@ impl<C, H> FromStr for UnverifiedSdJwtPresentation<C, H> {
     }
 }
 
+impl<C, H> TryFrom<JsonJwt<C, H>> for UnverifiedSdJwtPresentation<C, H> {
+    type Error = DecoderError;
+
+    fn try_from(json_jwt: JsonJwt<C, H>) -> Result<Self, Self::Error> {
+        let (sd_jwt, key_binding_jwt) = UnverifiedSdJwt::from_jws_json(json_jwt)?;
+
+        Ok(Self {
+            sd_jwt,
+            key_binding_jwt: key_binding_jwt.ok_or(DecoderError::MissingJwsJsonKeyBindingJwt)?,
+        })
+    }
+}
+
 impl<C, H> Display for UnverifiedSdJwtPresentation<C, H> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}{}", self.sd_jwt, self.key_binding_jwt)
     }
 }
 
+impl<C: Clone, H: Clone> UnverifiedSdJwtPresentation<C, H> {
+    /// Converts the presentation to the flattened syntax of the JWS JSON serialization. Note that the `sd_hash` of the
+    /// KB-JWT is always calculated over the compact serialization of the SD-JWT, so it remains valid.
+    pub fn to_jws_json_flattened(&self) -> Result<JsonJwt<C, H>, JwtParseError> {
+        self.sd_jwt.to_jws_json(Some(&self.key_binding_jwt), false)
+    }
+
+    /// Converts the presentation to the general syntax of the JWS JSON serialization. Note that the `sd_hash` of the
+    /// KB-JWT is always calculated over the compact serialization of the SD-JWT, so it remains valid.
+    pub fn to_jws_json_general(&self) -> Result<JsonJwt<C, H>, JwtParseError> {
+        self.sd_jwt.to_jws_json(Some(&self.key_binding_jwt), true)
+    }
+}
+
 impl UnverifiedSdJwtPresentation {
     /// Parses an SD-JWT into its components as [`VerifiedSdJwtPresentation`] while verifying against a set of trust
     /// anchors.
@@ -574,6 +767,77 @@ impl UnverifiedSdJwtPresentation {
             revocation_status,
         })
     }
+
+    /// Returns whether the header of the issuer-signed JWT contains an `x5c` certificate chain. If it does not, the
+    /// presentation can only be verified using [`UnverifiedSdJwtPresentation::into_verified_against_jwt_vc_issuer`].
+    pub fn has_x5c(&self) -> Result<bool, DecoderError> {
+        let header = self
+            .sd_jwt
+            .issuer_signed
+            .serialization()
+            .parse::<UnverifiedJwt<SdJwtVcClaims, jwt::Header>>()?
+            .dangerous_parse_header_unverified()?;
+
+        Ok(header.x5c.is_some())
+    }
+
+    /// Parses an SD-JWT into its components as [`VerifiedSdJwtPresentation`] while resolving the issuer key from the
+    /// JWT VC Issuer Metadata of the issuer, instead of from an `x5c` certificate chain.
+    ///
+    /// Verifies the presentation by:
+    /// 1) resolving the public key identified by `kid` of the `iss` using `key_resolver`, which only succeeds for
+    ///    issuers that are allowed by its policy,
+    /// 2) validating the issuer-signed JWT against the resolved public key,
+    /// 3) validating the KB-JWT against the public key from the `cnf` claim in the verified issuer-signed JWT,
+    /// 4) parsing/verifying disclosures.
+    ///
+    /// Note that Status List Tokens are verified against the issuer trust anchors, which do not apply here. As the
+    /// revocation status cannot be determined, an SD-JWT that contains a `status` claim is rejected.
+    pub async fn into_verified_against_jwt_vc_issuer<C>(
+        self,
+        key_resolver: &JwtVcIssuerKeyResolver<C>,
+        kb_verification_options: &KbVerificationOptions<'_>,
+        time: &impl Generator<DateTime<Utc>>,
+    ) -> Result<VerifiedSdJwtPresentation<SdJwtVcClaims, HeaderWithKid>, DecoderError>
+    where
+        C: JwtVcIssuerMetadataClient,
+    {
+        let issuer_signed = self
+            .sd_jwt
+            .issuer_signed
+            .serialization()
+            .parse::<UnverifiedJwt<SdJwtVcClaims, HeaderWithKid>>()?;
+
+        // the `iss` and `kid` are needed to resolve the issuer key, so these have to be read before verification
+        let (header, claims) = issuer_signed.dangerous_parse_unverified()?;
+        let issuer_pubkey = key_resolver.resolve(&claims.iss, &header.kid).await?;
+
+        let issuer_signed = issuer_signed.into_verified(JwtDecodingKey::from(&issuer_pubkey), &*SD_JWT_VALIDATION)?;
+
+        if issuer_signed.payload().status.is_some() {
+            return Err(DecoderError::UnverifiableRevocationStatus);
+        }
+
+        let key_binding_jwt = self.key_binding_jwt.into_verified(
+            &(&issuer_signed.payload().cnf().try_to_public_key()?).into(),
+            kb_verification_options,
+            time,
+        )?;
+
+        let disclosures = UnverifiedSdJwt::<SdJwtVcClaims, HeaderWithKid>::parse_and_verify_disclosures(
+            &self.sd_jwt.disclosures,
+            issuer_signed.payload(),
+        )?;
+
+        Ok(VerifiedSdJwtPresentation {
+            sd_jwt: VerifiedSdJwt {
+                issuer_signed,
+                disclosures,
+            },
+            key_binding_jwt,
+            revocation_status: None,
+        })
+    }
 }
 
 /// Verified SD-JWT Presentation combining a verified SD-JWT and a verified KB-JWT.
@@ -657,7 +921,7 @@ impl SdJwtPresentationBuilder {
             let mut path_segments = path.as_ref().iter().peekable();
             self.full_payload
                 .claims
-                .digests_to_disclose(&mut path_segments, &self.nondisclosed, false)?
+                .digests_to_disclose(&mut path_segments, &self.nondisclosed)?
                 .into_iter()
                 .map(String::from)
         });
@@ -721,6 +985,12 @@ impl From<SignedSdJwtPresentation> for UnverifiedSdJwtPresentation {
 }
 
 impl UnsignedSdJwtPresentation {
+    /// Prepares this presentation for further selection of the claims to disclose, returning a builder. Note that
+    /// only claims that are already disclosed in this presentation can be selected.
+    pub fn into_presentation_builder(self) -> SdJwtPresentationBuilder {
+        self.0.into_presentation_builder()
+    }
+
     /// Signs the underlying [`VerifiedSdJwt`] and returns an SD-JWT presentation containing the (verified) issuer
     /// signed SD-JWT and signed KB-JWT.
     pub async fn sign(
@@ -885,12 +1155,16 @@ mod examples {
 mod test {
     use std::assert_matches;
     use std::collections::HashSet;
+    use std::sync::Arc;
     use std::time::Duration;
 
     use chrono::DateTime;
     use crypto::server_keys::generate::Ca;
     use futures::FutureExt;
+    use http_utils::httpmock::httpmock_reqwest_client_builder;
     use http_utils::urls::HttpsUri;
+    use httpmock::Method::GET;
+    use httpmock::MockServer;
     use itertools::Itertools;
     use jsonwebtoken::errors::ErrorKind;
     use jsonwebtoken::jwk::AlgorithmParameters;
@@ -899,8 +1173,10 @@ mod test {
     use jsonwebtoken::jwk::EllipticCurveKeyType;
     use jsonwebtoken::jwk::Jwk;
     use jwt::Header;
+    use jwt::SignedJwt;
     use jwt::confirmation::ConfirmationClaim;
     use jwt::error::JwtVerifyError;
+    use jwt::jwk::jwk_from_public_key;
     use jwt::nonce::Nonce;
     use p256::ecdsa::SigningKey;
     use p256::elliptic_curve::Generate;
@@ -912,11 +1188,15 @@ mod test {
 
     use super::*;
     use crate::builder::SdJwtBuilder;
+    use crate::builder::SignedSdJwt;
     use crate::claims::ArrayClaim;
     use crate::disclosure::Disclosure;
     use crate::disclosure::DisclosureContent;
     use crate::examples::KeyBindingExampleTimeGenerator;
     use crate::examples::*;
+    use crate::jwt_vc_issuer::HttpJwtVcIssuerMetadataClient;
+    use crate::jwt_vc_issuer::JwtVcIssuerError;
+    use crate::jwt_vc_issuer::JwtVcIssuerPolicy;
     use crate::key_binding_jwt::KeyBindingJwtBuilder;
     use crate::sd_alg::SdAlg;
     use crate::sd_jwt::ClaimValue;
@@ -1033,6 +1313,129 @@ mod test {
         assert_matches!(result, Err(DecoderError::JsonDeserialization(_)));
     }
 
+    #[rstest]
+    #[case::flattened(SdJwtSerialization::JwsJsonFlattened)]
+    #[case::general(SdJwtSerialization::JwsJsonGeneral)]
+    fn round_trip_jws_json(#[case] serialization: SdJwtSerialization) {
+        let sd_jwt = SIMPLE_STRUCTURED_SD_JWT
+            .parse::<UnverifiedSdJwt<SdJwtExampleClaims, Header>>()
+            .unwrap();
+
+        let json_jwt = match serialization {
+            SdJwtSerialization::JwsJsonFlattened => sd_jwt.to_jws_json_flattened(),
+            SdJwtSerialization::JwsJsonGeneral => sd_jwt.to_jws_json_general(),
+            SdJwtSerialization::Compact => unreachable!(),
+        }
+        .unwrap();
+        assert_eq!(SdJwtSerialization::from(&json_jwt), serialization);
+
+        let json = serde_json::to_string(&json_jwt).unwrap();
+        let parsed = json.parse::<UnverifiedSdJwt<SdJwtExampleClaims, Header>>().unwrap();
+
+        assert_eq!(parsed, sd_jwt);
+        assert_eq!(parsed.to_string(), SIMPLE_STRUCTURED_SD_JWT);
+
+        let verified = parsed.into_verified(&examples_sd_jwt_public_key().into()).unwrap();
+        assert_eq!(verified.disclosures.len(), 2);
+    }
+
+    #[rstest]
+    #[case::flattened(SdJwtSerialization::JwsJsonFlattened)]
+    #[case::general(SdJwtSerialization::JwsJsonGeneral)]
+    fn round_trip_jws_json_presentation(#[case] serialization: SdJwtSerialization) {
+        let presentation = SD_JWT_VC_WITH_KB
+            .parse::<UnverifiedSdJwtPresentation<SdJwtVcClaims, Header>>()
+            .unwrap();
+
+        let json_jwt = match serialization {
+            SdJwtSerialization::JwsJsonFlattened => presentation.to_jws_json_flattened(),
+            SdJwtSerialization::JwsJsonGeneral => presentation.to_jws_json_general(),
+            SdJwtSerialization::Compact => unreachable!(),
+        }
+        .unwrap();
+
+        let json = serde_json::to_value(&json_jwt).unwrap();
+        let header = match serialization {
+            SdJwtSerialization::JwsJsonFlattened => &json["header"],
+            _ => &json["signatures"][0]["header"],
+        };
+        assert_eq!(header["kb_jwt"], presentation.key_binding_jwt.to_string());
+        assert_eq!(header["disclosures"].as_array().unwrap().len(), 2);
+
+        let parsed = json
+            .to_string()
+            .parse::<UnverifiedSdJwtPresentation<SdJwtVcClaims, Header>>()
+            .unwrap();
+
+        assert_eq!(parsed, presentation);
+        assert_eq!(parsed.to_string(), SD_JWT_VC_WITH_KB);
+
+        // The `sd_hash` in the KB-JWT is calculated over the compact serialization, so it should still verify.
+        parsed
+            .into_verified(
+                &(&examples_sd_jwt_public_key()).into(),
+                WITH_KB_SD_JWT_AUD,
+                &Nonce::from(WITH_KB_SD_JWT_NONCE.to_string()),
+                Duration::from_secs(10 * 60),
+                &KeyBindingExampleTimeGenerator,
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn jws_json_general_additional_signatures() {
+        let sd_jwt = SIMPLE_STRUCTURED_SD_JWT
+            .parse::<UnverifiedSdJwt<SdJwtExampleClaims, Header>>()
+            .unwrap();
+
+        let mut json = serde_json::to_value(sd_jwt.to_jws_json_general().unwrap()).unwrap();
+        let additional_signature = json!({
+            "protected": json["signatures"][0]["protected"],
+            "header": { "kid": "other_issuer" },
+            "signature": "c2lnbmF0dXJl",
+        });
+        json["signatures"].as_array_mut().unwrap().push(additional_signature);
+
+        let parsed = json
+            .to_string()
+            .parse::<UnverifiedSdJwt<SdJwtExampleClaims, Header>>()
+            .unwrap();
+
+        // The additional signature is dropped in the compact serialization and flattened syntax, but retained when
+        // converting back to the general syntax.
+        assert_eq!(parsed.to_string(), SIMPLE_STRUCTURED_SD_JWT);
+        assert_eq!(
+            serde_json::to_value(parsed.to_jws_json_flattened().unwrap()).unwrap(),
+            serde_json::to_value(sd_jwt.to_jws_json_flattened().unwrap()).unwrap()
+        );
+        assert_eq!(
+            serde_json::to_value(parsed.to_jws_json_general().unwrap()).unwrap(),
+            json
+        );
+
+        // Only the first signature is verified.
+        parsed.into_verified(&examples_sd_jwt_public_key().into()).unwrap();
+    }
+
+    #[test]
+    fn parse_jws_json_key_binding_jwt_mismatch() {
+        let presentation = SD_JWT_VC_WITH_KB
+            .parse::<UnverifiedSdJwtPresentation<SdJwtVcClaims, Header>>()
+            .unwrap();
+        let presentation_json = serde_json::to_string(&presentation.to_jws_json_flattened().unwrap()).unwrap();
+        let sd_jwt_json = serde_json::to_string(&presentation.sd_jwt.to_jws_json_flattened().unwrap()).unwrap();
+
+        let error = presentation_json
+            .parse::<UnverifiedSdJwt<SdJwtVcClaims, Header>>()
+            .expect_err("parsing SD-JWT with KB-JWT should fail");
+        assert_matches!(error, DecoderError::UnexpectedJwsJsonKeyBindingJwt);
+
+        let error = sd_jwt_json
+            .parse::<UnverifiedSdJwtPresentation<SdJwtVcClaims, Header>>()
+            .expect_err("parsing SD-JWT presentation without KB-JWT should fail");
+        assert_matches!(error, DecoderError::MissingJwsJsonKeyBindingJwt);
+    }
+
     fn create_presentation(
         object: serde_json::Value,
         conceal_paths: &[Vec<&str>],
@@ -1421,6 +1824,37 @@ mod test {
         &["nationalities", "country"],
         &[],
     )]
+    #[case::array_all_nested_object(
+        json!({
+            "nationalities": [{"country": {"code": "NL"}}, {"country": {"code": "DE"}}]
+        }),
+        &[
+            vec!["nationalities", "0", "country", "code"],
+            vec!["nationalities", "1", "country", "code"],
+            vec!["nationalities", "0", "country"],
+            vec!["nationalities", "1", "country"],
+            vec!["nationalities", "0"],
+            vec!["nationalities", "1"]
+        ],
+        &[vec!["nationalities", "null", "country", "code"]],
+        &["country", "code"],
+        &["/nationalities"],
+    )]
+    #[case::array_all_nested_array(
+        json!({
+            "nationalities": [["NL", "DE"], ["BE"]]
+        }),
+        &[
+            vec!["nationalities", "0", "0"],
+            vec!["nationalities", "0", "1"],
+            vec!["nationalities", "1", "0"],
+            vec!["nationalities", "0"],
+            vec!["nationalities", "1"]
+        ],
+        &[vec!["nationalities", "null", "null"]],
+        &["NL", "DE", "BE"],
+        &["/nationalities"],
+    )]
     fn test_array_selectively_disclosable_attributes_in_presentation(
         #[case] object: serde_json::Value,
         #[case] conceal_paths: &[Vec<&str>],
@@ -1521,6 +1955,111 @@ mod test {
         );
     }
 
+    #[rstest]
+    #[case::all(ClaimPath::SelectAll, Ok(json!(["NL", "DE"])))]
+    #[case::index(ClaimPath::SelectByIndex(1), Ok(json!(["DE"])))]
+    #[case::decoy(
+        ClaimPath::SelectByIndex(2),
+        Err(ClaimError::ElementNotFoundInArray(ClaimPath::SelectByIndex(2)))
+    )]
+    #[case::out_of_bounds(
+        ClaimPath::SelectByIndex(4),
+        Err(ClaimError::ElementNotFoundInArray(ClaimPath::SelectByIndex(4)))
+    )]
+    fn test_disclose_array_elements_with_decoys(
+        #[case] array_path: ClaimPath,
+        #[case] expected_nationalities: Result<Value, ClaimError>,
+    ) {
+        let ca = Ca::generate_issuer_mock_ca().unwrap();
+        let issuer_keypair = ca.generate_issuer_mock().unwrap();
+        let holder_key = SigningKey::generate();
+
+        let nationalities_path = ClaimPath::SelectByKey("nationalities".to_string());
+        let signed_sd_jwt = SdJwtBuilder::new(SdJwtVcClaims::example_from_json(
+            holder_key.verifying_key(),
+            json!({
+                "nationalities": ["NL", "DE"]
+            }),
+            &MockTimeGenerator::default(),
+        ))
+        .make_concealable(
+            vec![nationalities_path.clone(), ClaimPath::SelectByIndex(0)]
+                .try_into()
+                .unwrap(),
+        )
+        .unwrap()
+        .make_concealable(
+            vec![nationalities_path.clone(), ClaimPath::SelectByIndex(1)]
+                .try_into()
+                .unwrap(),
+        )
+        .unwrap()
+        .add_decoys(std::slice::from_ref(&nationalities_path), 2)
+        .unwrap()
+        .finish(&issuer_keypair)
+        .now_or_never()
+        .unwrap()
+        .unwrap();
+
+        // The decoys are appended to the array, so only the first two entries can be disclosed.
+        let nationalities = signed_sd_jwt
+            .into_verified()
+            .into_presentation_builder()
+            .disclose(&vec![nationalities_path, array_path].try_into().unwrap())
+            .map(|builder| {
+                let claims = builder.finish().as_ref().decoded_claims().unwrap();
+
+                serde_json::to_value(claims).unwrap()["nationalities"].clone()
+            });
+
+        assert_eq!(nationalities, expected_nationalities);
+    }
+
+    #[test]
+    fn test_unsigned_presentation_into_presentation_builder() {
+        let ca = Ca::generate_issuer_mock_ca().unwrap();
+        let issuer_keypair = ca.generate_issuer_mock().unwrap();
+        let holder_key = SigningKey::generate();
+
+        let bsn_path = vec_nonempty![ClaimPath::SelectByKey("bsn".to_string())];
+        let family_name_path = vec_nonempty![ClaimPath::SelectByKey("family_name".to_string())];
+
+        let unsigned_presentation =
+            SignedSdJwt::pid_example(&issuer_keypair, &PublicKey::from(*holder_key.verifying_key()))
+                .into_verified()
+                .into_presentation_builder()
+                .disclose(&bsn_path)
+                .unwrap()
+                .disclose(&family_name_path)
+                .unwrap()
+                .finish();
+
+        assert_eq!(unsigned_presentation.as_ref().disclosures().len(), 2);
+
+        // Selecting a subset of the already disclosed claims should only retain the disclosure for that claim.
+        let subset_presentation = unsigned_presentation
+            .clone()
+            .into_presentation_builder()
+            .disclose(&bsn_path)
+            .unwrap()
+            .finish();
+
+        assert_eq!(subset_presentation.as_ref().disclosures().len(), 1);
+
+        let claims = serde_json::to_value(subset_presentation.as_ref().decoded_claims().unwrap()).unwrap();
+        assert_eq!(claims["bsn"], json!("999999999"));
+        assert_eq!(claims.get("family_name"), None);
+
+        // A claim that is not disclosed in the presentation cannot be selected again.
+        let error = subset_presentation
+            .into_presentation_builder()
+            .disclose(&family_name_path)
+            .map(|_| ())
+            .expect_err("selecting a claim that was not disclosed should fail");
+
+        assert_matches!(error, ClaimError::ElementNotFound(key) if key == "family_name");
+    }
+
     #[rstest]
     #[case(json!({
         "vct": "com:example:pid:1",
@@ -2127,4 +2666,110 @@ mod test {
             expected, actual, digest, }) if
             expected == ClaimType::Object && actual == ClaimType::Array && digest == object_claim_digest );
     }
+
+    /// Create a presentation of an SD-JWT that is signed with a `kid` instead of an `x5c` header.
+    async fn jwt_vc_issuer_presentation(
+        claims: &SdJwtVcClaims,
+        issuer_key: &SigningKey,
+        holder_key: &SigningKey,
+        time: &MockTimeGenerator,
+    ) -> UnverifiedSdJwtPresentation {
+        let issuer_signed = SignedJwt::sign_with_kid(claims, String::from("key-1"), issuer_key)
+            .await
+            .unwrap();
+        let sd_jwt = format!("{}~", issuer_signed.as_ref().serialization())
+            .parse::<UnverifiedSdJwt<SdJwtVcClaims, HeaderWithKid>>()
+            .unwrap()
+            .dangerous_parse_unverified()
+            .unwrap();
+        let key_binding_jwt = KeyBindingJwtBuilder::new(
+            String::from("https://example.com"),
+            Nonce::from("abcdefghi".to_string()),
+        )
+        .finish(&sd_jwt, holder_key, time)
+        .await
+        .unwrap();
+
+        format!("{sd_jwt}{}", key_binding_jwt.as_ref().serialization())
+            .parse::<UnverifiedSdJwtPresentation>()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_presentation_into_verified_against_jwt_vc_issuer() {
+        let server = MockServer::start_async().await;
+        let issuer: HttpsUri = server.url("/issuer").parse().unwrap();
+        let issuer_key = SigningKey::generate();
+        let holder_key = SigningKey::generate();
+        let time = MockTimeGenerator::default();
+
+        let mut jwk = jwk_from_public_key(&PublicKey::from(*issuer_key.verifying_key())).unwrap();
+        jwk.common.key_id = Some(String::from("key-1"));
+        let _metadata_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path("/.well-known/jwt-vc-issuer/issuer");
+                then.status(200).json_body(json!({
+                    "issuer": issuer,
+                    "jwks": { "keys": [jwk] },
+                }));
+            })
+            .await;
+
+        let claims = SdJwtVcClaims {
+            iss: issuer.clone(),
+            status: None,
+            ..SdJwtVcClaims::pid_example(&PublicKey::from(*holder_key.verifying_key()), &time)
+        };
+        let presentation = jwt_vc_issuer_presentation(&claims, &issuer_key, &holder_key, &time).await;
+
+        assert!(!presentation.has_x5c().unwrap());
+
+        let kb_verification_options = KbVerificationOptions {
+            expected_aud: "https://example.com",
+            expected_nonce: &Nonce::from("abcdefghi".to_string()),
+            iat_leeway: Duration::ZERO,
+            iat_acceptance_window: Duration::from_secs(60),
+        };
+        let client = Arc::new(HttpJwtVcIssuerMetadataClient::new(httpmock_reqwest_client_builder()).unwrap());
+
+        let key_resolver = JwtVcIssuerKeyResolver::new(
+            Arc::clone(&client),
+            JwtVcIssuerPolicy::new(vec![issuer.clone()]),
+            10,
+            Duration::from_secs(60),
+        );
+        let verified = presentation
+            .clone()
+            .into_verified_against_jwt_vc_issuer(&key_resolver, &kb_verification_options, &time)
+            .await
+            .unwrap();
+
+        assert_eq!(verified.sd_jwt().issuer_kid(), "key-1");
+        assert_eq!(verified.sd_jwt().claims().iss, issuer);
+        assert_eq!(verified.revocation_status(), None);
+
+        // The status claim of the SD-JWT cannot be checked without issuer trust anchors, so it should be rejected.
+        let claims_with_status = SdJwtVcClaims {
+            iss: issuer.clone(),
+            ..SdJwtVcClaims::pid_example(&PublicKey::from(*holder_key.verifying_key()), &time)
+        };
+        assert!(claims_with_status.status.is_some());
+
+        let error = jwt_vc_issuer_presentation(&claims_with_status, &issuer_key, &holder_key, &time)
+            .await
+            .into_verified_against_jwt_vc_issuer(&key_resolver, &kb_verification_options, &time)
+            .await
+            .unwrap_err();
+
+        assert_matches!(error, DecoderError::UnverifiableRevocationStatus);
+
+        let key_resolver =
+            JwtVcIssuerKeyResolver::new(client, JwtVcIssuerPolicy::default(), 10, Duration::from_secs(60));
+        let error = presentation
+            .into_verified_against_jwt_vc_issuer(&key_resolver, &kb_verification_options, &time)
+            .await
+            .unwrap_err();
+
+        assert_matches!(error, DecoderError::JwtVcIssuer(JwtVcIssuerError::IssuerNotAllowed(_)));
+    }
 }
@@ -49,7 +49,7 @@ pub enum TypeMetadataChainError {
     Normalization(#[from] NormalizedTypeMetadataError),
 }
 
-fn check_resource_integrity(json: &[u8], integrity: Integrity) -> Result<(), TypeMetadataChainError> {
+pub(crate) fn check_resource_integrity(json: &[u8], integrity: Integrity) -> Result<(), TypeMetadataChainError> {
     let mut checker = IntegrityChecker::new(integrity);
     checker.input(json);
 
@@ -87,7 +87,7 @@ impl SortedTypeMetadata {
 /// a [`SortedTypeMetadataDocuments`] type, which then can be turned into [`VerifiedTypeMetadataDocuments`] by verifying
 /// its integrity.
 #[serde_as]
-#[derive(Clone, PartialEq, Eq, AsRef, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, AsRef, Serialize, Deserialize)]
 pub struct TypeMetadataDocuments(
     #[serde_as(as = "IfIsHumanReadable<Vec<Base64<UrlSafe, Unpadded>>, Vec<Bytes>>")] VecNonEmpty<Vec<u8>>,
 );
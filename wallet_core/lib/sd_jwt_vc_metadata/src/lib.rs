@@ -7,5 +7,8 @@ mod chain;
 mod metadata;
 mod normalized;
 
+#[cfg(feature = "resolver")]
+pub mod resolver;
+
 #[cfg(any(test, feature = "example_constructors"))]
 pub mod examples;
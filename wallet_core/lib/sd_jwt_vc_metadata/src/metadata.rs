@@ -1,18 +1,22 @@
 use std::collections::HashSet;
 use std::fmt::Debug;
 use std::ops::Deref;
+use std::string::FromUtf8Error;
 
 use attestation_types::claim_path::ClaimPath;
 use attestation_types::data_uri::DataUri;
 use attestation_types::image::Image;
 use itertools::Itertools;
 use nutype::nutype;
+use regex::Captures;
+use regex::Regex;
 use regex::regex;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_with::TryFromInto;
 use serde_with::serde_as;
 use serde_with::skip_serializing_none;
+use ssri::Algorithm;
 use ssri::Integrity;
 use utils::spec::SpecOptional;
 use utils::vec_at_least::VecNonEmpty;
@@ -40,6 +44,15 @@ pub enum TypeMetadataError {
 
     #[error("internal attributes found in claim: {}", .0.join(", "))]
     InternalAttributeInClaim(Vec<String>),
+
+    #[error("missing properties for multiple SVG templates for locale: {0}")]
+    MissingSvgTemplateProperties(String),
+
+    #[error("SVG template integrity did not validate for locale {0}: {1}")]
+    SvgTemplateIntegrity(String, #[source] ssri::Error),
+
+    #[error("insecure SVG template integrity algorithm used for locale {0}: {1}")]
+    SvgTemplateIntegrityAlgorithmInsecure(String, Algorithm),
 }
 
 /// SD-JWT VC type metadata document.
@@ -81,18 +94,28 @@ pub struct UncheckedTypeMetadata {
     pub claims: Vec<ClaimMetadata>,
 }
 
+/// Matches `{{svg_id}}` placeholders, as used in both the summary and SVG templates.
+fn svg_id_placeholder_regex() -> &'static Regex {
+    regex!(r"\{\{([A-Za-z_][0-9A-Za-z_]*)}}")
+}
+
 pub(crate) fn find_missing_svg_ids(display: &[DisplayMetadata], claims: &[ClaimMetadata]) -> Vec<String> {
     let svg_ids = claims
         .iter()
         .filter_map(|claim| claim.svg_id.as_deref())
         .collect::<HashSet<_>>();
 
-    display
+    let summaries = display.iter().filter_map(|display| display.summary.as_deref());
+    let templates = display
         .iter()
-        .filter_map(|display| display.summary.as_deref())
-        .flat_map(|summary| {
-            regex!(r"\{\{([A-Za-z_][0-9A-Za-z_]*)}}")
-                .captures_iter(summary)
+        .flat_map(DisplayMetadata::svg_templates)
+        .map(|template| template.template.as_str());
+
+    summaries
+        .chain(templates)
+        .flat_map(|text| {
+            svg_id_placeholder_regex()
+                .captures_iter(text)
                 .flat_map(|captures| captures.extract::<1>().1)
         })
         .unique()
@@ -126,6 +149,7 @@ impl UncheckedTypeMetadata {
         unchecked_metadata.detect_path_collisions()?;
         unchecked_metadata.detect_duplicate_languages()?;
         unchecked_metadata.validate_svg_ids()?;
+        unchecked_metadata.validate_svg_templates()?;
 
         Ok(())
     }
@@ -217,6 +241,36 @@ impl UncheckedTypeMetadata {
 
         Ok(())
     }
+
+    fn validate_svg_templates(&self) -> Result<(), TypeMetadataError> {
+        for display in &self.display {
+            let templates = display.svg_templates();
+
+            // The `properties` field is required when more than one SVG template is present.
+            if templates.len() > 1 && templates.iter().any(|template| template.properties.is_none()) {
+                return Err(TypeMetadataError::MissingSvgTemplateProperties(display.locale.clone()));
+            }
+
+            for template in templates {
+                let Some(integrity) = &template.integrity else {
+                    continue;
+                };
+
+                let algorithm = integrity
+                    .check(template.template.as_str())
+                    .map_err(|error| TypeMetadataError::SvgTemplateIntegrity(display.locale.clone(), error))?;
+
+                if algorithm > Algorithm::Sha256 {
+                    return Err(TypeMetadataError::SvgTemplateIntegrityAlgorithmInsecure(
+                        display.locale.clone(),
+                        algorithm,
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -255,6 +309,16 @@ pub struct DisplayMetadata {
     pub rendering: Option<RenderingMetadata>,
 }
 
+impl DisplayMetadata {
+    /// Returns the SVG templates for this display metadata, which is empty if it does not use SVG template rendering.
+    pub fn svg_templates(&self) -> &[SvgTemplateMetadata] {
+        match &self.rendering {
+            Some(RenderingMetadata::SvgTemplates(templates)) => templates.as_slice(),
+            Some(RenderingMetadata::Simple { .. }) | None => &[],
+        }
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -272,7 +336,9 @@ pub enum RenderingMetadata {
         /// An RGB color value for the text of the credential.
         text_color: Option<String>,
     },
-    SvgTemplates,
+    /// An array of objects containing SVG templates for the type.
+    #[serde(rename = "svg_templates")]
+    SvgTemplates(VecNonEmpty<SvgTemplateMetadata>),
 }
 
 #[serde_as]
@@ -299,6 +365,136 @@ pub struct BackgroundImageMetadata {
     pub image: Image,
 }
 
+#[serde_as]
+#[skip_serializing_none]
+#[derive(derive_more::Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SvgTemplateMetadata {
+    /// Explicitly reject non-embedded templates and mime types other than `image/svg+xml`.
+    #[debug(skip)]
+    #[serde(rename = "uri")]
+    #[serde_as(as = "TryFromInto<DataUri>")]
+    pub template: SvgTemplate,
+
+    /// Validating the integrity of the template, which is checked against the decoded contents of the `uri`.
+    #[serde(rename = "uri#integrity")]
+    pub integrity: Option<Integrity>,
+
+    /// An object containing properties for the SVG template, used to select the appropriate template for display.
+    /// Note that this is mandatory if more than one SVG template is present.
+    pub properties: Option<SvgTemplateProperties>,
+}
+
+/// The contents of an SVG template, which may contain `{{svg_id}}` placeholders for claim values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SvgTemplate(String);
+
+#[derive(Debug, thiserror::Error)]
+pub enum SvgTemplateError {
+    #[error("utf8 decode error: {0}")]
+    Utf8Decode(#[from] FromUtf8Error),
+    #[error("unsupported mime type: {0}")]
+    UnsupportedMimeType(String),
+}
+
+impl SvgTemplate {
+    const MIME_TYPE: &str = "image/svg+xml";
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Render the template by replacing every `{{svg_id}}` placeholder with the XML escaped value returned by
+    /// `claim_value`. Placeholders for which no value is available are replaced with an empty string. Note that the
+    /// result is not sanitized in any way, this is left to the consumer.
+    pub fn render<F, S>(&self, claim_value: F) -> String
+    where
+        F: Fn(&str) -> Option<S>,
+        S: AsRef<str>,
+    {
+        svg_id_placeholder_regex()
+            .replace_all(&self.0, |captures: &Captures| {
+                claim_value(&captures[1])
+                    .map(|value| escape_xml(value.as_ref()))
+                    .unwrap_or_default()
+            })
+            .into_owned()
+    }
+}
+
+impl TryFrom<DataUri> for SvgTemplate {
+    type Error = SvgTemplateError;
+
+    fn try_from(value: DataUri) -> Result<Self, Self::Error> {
+        if value.mime_type != Self::MIME_TYPE {
+            return Err(SvgTemplateError::UnsupportedMimeType(value.mime_type));
+        }
+
+        let template = String::from_utf8(value.data)?;
+
+        Ok(Self(template))
+    }
+}
+
+impl From<SvgTemplate> for DataUri {
+    fn from(value: SvgTemplate) -> Self {
+        DataUri {
+            mime_type: String::from(SvgTemplate::MIME_TYPE),
+            data: value.0.into_bytes(),
+        }
+    }
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .chars()
+        .fold(String::with_capacity(value.len()), |mut escaped, c| {
+            match c {
+                '&' => escaped.push_str("&amp;"),
+                '<' => escaped.push_str("&lt;"),
+                '>' => escaped.push_str("&gt;"),
+                '"' => escaped.push_str("&quot;"),
+                '\'' => escaped.push_str("&apos;"),
+                c => escaped.push(c),
+            }
+
+            escaped
+        })
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SvgTemplateProperties {
+    /// The orientation for which the SVG template is optimized.
+    pub orientation: Option<SvgTemplateOrientation>,
+
+    /// The color scheme for which the SVG template is optimized.
+    pub color_scheme: Option<SvgTemplateColorScheme>,
+
+    /// The contrast for which the SVG template is optimized.
+    pub contrast: Option<SvgTemplateContrast>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SvgTemplateOrientation {
+    Portrait,
+    Landscape,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SvgTemplateColorScheme {
+    Light,
+    Dark,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SvgTemplateContrast {
+    Normal,
+    High,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ClaimMetadata {
@@ -858,6 +1054,144 @@ mod test {
         assert_type_metadata_error(metadata.validate_svg_ids(), expected);
     }
 
+    const EXAMPLE_SVG_TEMPLATE: &str =
+        r#"<svg xmlns="http://www.w3.org/2000/svg"><text>{{address_street}}</text></svg>"#;
+
+    fn svg_template_json(svg: &str, integrity: Option<Integrity>, properties: serde_json::Value) -> serde_json::Value {
+        let uri = DataUri {
+            mime_type: String::from("image/svg+xml"),
+            data: svg.as_bytes().to_vec(),
+        };
+
+        json!({
+            "uri": uri.to_string(),
+            "uri#integrity": integrity.map(|integrity| integrity.to_string()),
+            "properties": properties,
+        })
+    }
+
+    fn svg_templates_metadata_json(templates: Vec<serde_json::Value>) -> serde_json::Value {
+        json!({
+            "vct": VCT_EXAMPLE_CREDENTIAL,
+            "display": [{
+                "locale": "en",
+                "name": "Example Credential",
+                "rendering": {
+                    "svg_templates": templates,
+                },
+            }],
+            "claims": [
+                { "path": vec!["address"], "svg_id": "address" },
+                { "path": vec!["address", "street"], "svg_id": "address_street" },
+            ]
+        })
+    }
+
+    #[test]
+    fn test_deserialize_svg_templates() {
+        let json = svg_templates_metadata_json(vec![svg_template_json(
+            EXAMPLE_SVG_TEMPLATE,
+            Some(Integrity::from(EXAMPLE_SVG_TEMPLATE)),
+            json!({ "orientation": "landscape", "color_scheme": "dark", "contrast": "high" }),
+        )]);
+
+        let metadata = serde_json::from_value::<TypeMetadata>(json).unwrap();
+        let templates = metadata.as_ref().display[0].svg_templates();
+
+        assert_eq!(templates.len(), 1);
+        assert_eq!(templates[0].template.as_str(), EXAMPLE_SVG_TEMPLATE);
+        assert_eq!(
+            templates[0].properties,
+            Some(SvgTemplateProperties {
+                orientation: Some(SvgTemplateOrientation::Landscape),
+                color_scheme: Some(SvgTemplateColorScheme::Dark),
+                contrast: Some(SvgTemplateContrast::High),
+            })
+        );
+
+        // Serializing and deserializing again should result in the same metadata.
+        let reserialized = serde_json::to_value(&metadata).unwrap();
+        assert_eq!(serde_json::from_value::<TypeMetadata>(reserialized).unwrap(), metadata);
+    }
+
+    #[test]
+    fn test_deserialize_svg_templates_unsupported_mime_type() {
+        let mut json = svg_templates_metadata_json(vec![svg_template_json(EXAMPLE_SVG_TEMPLATE, None, json!(null))]);
+        json["display"][0]["rendering"]["svg_templates"][0]["uri"] = json!("data:image/png;base64,q80=");
+
+        let error = serde_json::from_value::<UncheckedTypeMetadata>(json).expect_err("should fail to deserialize");
+        assert!(error.to_string().contains("unsupported mime type: image/png"));
+    }
+
+    #[rstest]
+    #[case(vec![svg_template_json(EXAMPLE_SVG_TEMPLATE, None, json!(null))], Ok(()))]
+    #[case(
+        vec![
+            svg_template_json(EXAMPLE_SVG_TEMPLATE, None, json!({ "color_scheme": "light" })),
+            svg_template_json(EXAMPLE_SVG_TEMPLATE, None, json!({ "color_scheme": "dark" })),
+        ],
+        Ok(())
+    )]
+    #[case(
+        vec![
+            svg_template_json(EXAMPLE_SVG_TEMPLATE, None, json!({ "color_scheme": "light" })),
+            svg_template_json(EXAMPLE_SVG_TEMPLATE, None, json!(null)),
+        ],
+        Err(TypeMetadataError::MissingSvgTemplateProperties("en".to_string()))
+    )]
+    #[case(
+        vec![svg_template_json(EXAMPLE_SVG_TEMPLATE, Some(Integrity::from(EXAMPLE_SVG_TEMPLATE)), json!(null))],
+        Ok(())
+    )]
+    #[case(
+        vec![svg_template_json(EXAMPLE_SVG_TEMPLATE, Some(Integrity::from("<svg></svg>")), json!(null))],
+        Err(TypeMetadataError::SvgTemplateIntegrity(
+            "en".to_string(),
+            Integrity::from("<svg></svg>").check(EXAMPLE_SVG_TEMPLATE).unwrap_err(),
+        ))
+    )]
+    fn test_validate_svg_templates(
+        #[case] templates: Vec<serde_json::Value>,
+        #[case] expected: Result<(), TypeMetadataError>,
+    ) {
+        let metadata = serde_json::from_value::<UncheckedTypeMetadata>(svg_templates_metadata_json(templates)).unwrap();
+
+        assert_type_metadata_error(metadata.validate_svg_templates(), expected);
+    }
+
+    #[test]
+    fn test_validate_svg_templates_missing_svg_ids() {
+        let metadata =
+            serde_json::from_value::<UncheckedTypeMetadata>(svg_templates_metadata_json(vec![svg_template_json(
+                "<svg><text>{{address_city}}</text></svg>",
+                None,
+                json!(null),
+            )]))
+            .unwrap();
+
+        assert_type_metadata_error(
+            metadata.validate_svg_ids(),
+            Err(TypeMetadataError::MissingSvgIds(vec!["address_city".to_string()])),
+        );
+    }
+
+    #[test]
+    fn test_svg_template_render() {
+        let template = SvgTemplate::try_from(DataUri {
+            mime_type: String::from("image/svg+xml"),
+            data: b"<svg><text>{{street}} {{number}}</text><text>{{city}}</text></svg>".to_vec(),
+        })
+        .unwrap();
+
+        let values = HashMap::from([("street", "<Main> & \"Co's\""), ("number", "42")]);
+        let rendered = template.render(|svg_id| values.get(svg_id));
+
+        assert_eq!(
+            rendered,
+            "<svg><text>&lt;Main&gt; &amp; &quot;Co&apos;s&quot; 42</text><text></text></svg>"
+        );
+    }
+
     #[rstest]
     #[case(json![["nbf"]], Err(TypeMetadataError::InternalAttributeInClaim(vec!["nbf".to_string()])))]
     #[case(json![["nested", "nbf"]], Ok(()))]
@@ -0,0 +1,508 @@
+//! Resolution of SD-JWT VC Type Metadata documents by retrieving them from the URL in the `vct` or `extends` field, for
+//! when these documents are not provided by other means, e.g. in the `vctm` header of an SD-JWT.
+//!
+//! Retrieved documents are cached, either in memory (for servers) or on disk (for the wallet). The latter allows
+//! documents to be available when the device is offline. How the cache is used relative to the network is determined
+//! by the [`TypeMetadataResolverMode`].
+//!
+//! Resolution is used by the wallet and by issuers only. The verifier does not process type metadata when verifying
+//! disclosed attestations, so it has no need for a resolver.
+//!
+//! <https://www.ietf.org/archive/id/draft-ietf-oauth-sd-jwt-vc-10.html#name-retrieving-type-metadata>
+
+use std::collections::HashSet;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use moka::future::Cache;
+use reqwest::ClientBuilder;
+use serde::Deserialize;
+use ssri::Integrity;
+use url::Url;
+
+use crate::chain::TypeMetadataChainError;
+use crate::chain::TypeMetadataDocuments;
+use crate::chain::check_resource_integrity;
+use crate::metadata::UncheckedTypeMetadata;
+
+const MAX_CHAIN_LENGTH: usize = 100;
+
+#[derive(Debug, thiserror::Error)]
+pub enum TypeMetadataResolverError {
+    #[error("\"vct\" is not an HTTPS URL: {0}")]
+    VctNotHttpsUrl(String),
+
+    #[error("networking error: {0}")]
+    Networking(#[from] reqwest::Error),
+
+    #[error("type metadata document is not cached and may not be fetched: {0}")]
+    NotCached(Url),
+
+    #[error("could not access type metadata cache: {0}")]
+    Cache(#[from] io::Error),
+
+    #[error("JSON deserialization failed: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("\"vct\" in type metadata document does not match, expected: {expected}, found: {found}")]
+    VctMismatch { expected: String, found: String },
+
+    #[error("maximum chain length exceeded")]
+    MaximumLengthExceeded,
+
+    #[error("type metadata chain error: {0}")]
+    Chain(#[from] TypeMetadataChainError),
+}
+
+/// Determines how the [`TypeMetadataResolver`] uses its cache and the network.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TypeMetadataResolverMode {
+    /// Use cached documents when available and only fetch documents that are not cached.
+    #[default]
+    CacheFirst,
+    /// Always fetch documents, but fall back to cached documents if this fails, e.g. when offline.
+    NetworkFirst,
+    /// Never fetch documents and only use cached documents.
+    CacheOnly,
+}
+
+/// Storage for the JSON of retrieved type metadata documents, keyed by their URL.
+#[derive(Debug)]
+pub enum TypeMetadataCache {
+    /// Cache documents in memory for a limited amount of time, which is suitable for servers.
+    Memory(Cache<Url, Vec<u8>>),
+    /// Cache documents as files in a directory, so that they persist across restarts. Documents older than `ttl` are
+    /// considered stale, but are still used when they cannot be fetched, e.g. when the device is offline.
+    Disk { directory: PathBuf, ttl: Duration },
+}
+
+impl TypeMetadataCache {
+    pub fn new_memory(capacity: u64, ttl: Duration) -> Self {
+        Self::Memory(Cache::builder().max_capacity(capacity).time_to_live(ttl).build())
+    }
+
+    pub fn new_disk(directory: PathBuf, ttl: Duration) -> Self {
+        Self::Disk { directory, ttl }
+    }
+
+    /// The file name is derived from a digest of the URL, as the URL itself may contain characters that are not
+    /// allowed in file names.
+    fn file_path(directory: &Path, url: &Url) -> PathBuf {
+        let (_, digest) = Integrity::from(url.as_str()).to_hex();
+
+        directory.join(format!("{digest}.json"))
+    }
+
+    /// Get a cached document, where stale documents are only returned if `include_stale` is set. Note that the memory
+    /// cache evicts documents once they expire, so that it never returns stale documents.
+    async fn get(&self, url: &Url, include_stale: bool) -> Result<Option<Vec<u8>>, io::Error> {
+        match self {
+            Self::Memory(cache) => Ok(cache.get(url).await),
+            Self::Disk { directory, ttl } => {
+                let path = Self::file_path(directory, url);
+
+                let modified = match tokio::fs::metadata(&path).await {
+                    Ok(metadata) => metadata.modified()?,
+                    Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+                    Err(error) => return Err(error),
+                };
+
+                // A modification time in the future is treated as stale, so that the document will be refreshed.
+                let is_fresh = modified.elapsed().is_ok_and(|age| age < *ttl);
+                if !is_fresh && !include_stale {
+                    return Ok(None);
+                }
+
+                match tokio::fs::read(path).await {
+                    Ok(json) => Ok(Some(json)),
+                    Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+                    Err(error) => Err(error),
+                }
+            }
+        }
+    }
+
+    async fn insert(&self, url: &Url, json: Vec<u8>) -> Result<(), io::Error> {
+        match self {
+            Self::Memory(cache) => {
+                cache.insert(url.clone(), json).await;
+
+                Ok(())
+            }
+            Self::Disk { directory, .. } => {
+                tokio::fs::create_dir_all(directory).await?;
+
+                // Write to a temporary file first, so that a partially written document is never read from the cache.
+                let path = Self::file_path(directory, url);
+                let temp_path = path.with_extension("tmp");
+                tokio::fs::write(&temp_path, json).await?;
+                tokio::fs::rename(temp_path, path).await
+            }
+        }
+    }
+}
+
+/// Resolves chains of SD-JWT VC Type Metadata documents by fetching each document from its `vct` URL, while checking
+/// the resource integrity of every document for which a digest is known. Only documents that pass this check are
+/// cached.
+#[derive(Debug)]
+pub struct TypeMetadataResolver {
+    client: reqwest::Client,
+    cache: TypeMetadataCache,
+    mode: TypeMetadataResolverMode,
+}
+
+impl TypeMetadataResolver {
+    pub fn new(
+        client_builder: ClientBuilder,
+        cache: TypeMetadataCache,
+        mode: TypeMetadataResolverMode,
+    ) -> Result<Self, reqwest::Error> {
+        let client = client_builder.build()?;
+
+        Ok(Self { client, cache, mode })
+    }
+
+    /// Returns the URL from which the type metadata for `vct` can be retrieved, if `vct` is an HTTPS URL.
+    pub fn vct_url(vct: &str) -> Option<Url> {
+        Url::parse(vct).ok().filter(|url| url.scheme() == "https")
+    }
+
+    /// Resolve the chain of type metadata documents for `vct`, by following the `extends` field of each document. The
+    /// resource integrity of the leaf document is checked against `vct_integrity` if provided, while that of every
+    /// extended document is checked against the `extends#integrity` field of the document that extends it.
+    ///
+    /// The returned documents are sorted from leaf to root and can be normalized using
+    /// [`TypeMetadataDocuments::into_normalized`].
+    pub async fn resolve(
+        &self,
+        vct: &str,
+        vct_integrity: Option<&Integrity>,
+    ) -> Result<TypeMetadataDocuments, TypeMetadataResolverError> {
+        let mut documents = Vec::with_capacity(1);
+        let mut seen_vcts = HashSet::new();
+        let mut next_extends = Some((vct.to_string(), vct_integrity.cloned()));
+
+        while let Some((vct, integrity)) = next_extends {
+            if documents.len() == MAX_CHAIN_LENGTH {
+                return Err(TypeMetadataResolverError::MaximumLengthExceeded);
+            }
+
+            // Fetching the same `vct` twice would result in an infinite loop, which the specification explicitly
+            // mandates should be detected and prevented in section 10.3.
+            if !seen_vcts.insert(vct.clone()) {
+                return Err(TypeMetadataChainError::CircularChain(vct).into());
+            }
+
+            let url = Self::vct_url(&vct).ok_or_else(|| TypeMetadataResolverError::VctNotHttpsUrl(vct.clone()))?;
+            let json = self.document(&url, integrity.as_ref()).await?;
+
+            // Only decode the fields needed to follow the chain, full validation happens when normalizing.
+            let metadata = serde_json::from_slice::<UncheckedTypeMetadata>(&json)?;
+            if metadata.vct != vct {
+                return Err(TypeMetadataResolverError::VctMismatch {
+                    expected: vct,
+                    found: metadata.vct,
+                });
+            }
+
+            next_extends = metadata
+                .extends
+                .map(|extends| (extends.extends, Some(extends.extends_integrity.into_inner())));
+
+            documents.push(json);
+        }
+
+        // This `.unwrap()` is guaranteed to succeed as the `while` loop above runs at least once.
+        Ok(TypeMetadataDocuments::new(documents.try_into().unwrap()))
+    }
+
+    /// Get a single document from either the cache or the network, depending on the mode. Cached documents are
+    /// ignored if they do not match `integrity`, which may happen when the document at the URL has changed. Stale
+    /// cached documents are only used when the document cannot be fetched, or when fetching is not allowed at all.
+    async fn document(&self, url: &Url, integrity: Option<&Integrity>) -> Result<Vec<u8>, TypeMetadataResolverError> {
+        if self.mode != TypeMetadataResolverMode::NetworkFirst
+            && let Some(json) = self
+                .cached_document(url, integrity, self.mode == TypeMetadataResolverMode::CacheOnly)
+                .await?
+        {
+            return Ok(json);
+        }
+
+        if self.mode == TypeMetadataResolverMode::CacheOnly {
+            return Err(TypeMetadataResolverError::NotCached(url.clone()));
+        }
+
+        match self.fetch_document(url).await {
+            Ok(json) => {
+                if let Some(integrity) = integrity {
+                    check_resource_integrity(&json, integrity.clone())?;
+                }
+                self.cache.insert(url, json.clone()).await?;
+
+                Ok(json)
+            }
+            Err(error) => self
+                .cached_document(url, integrity, true)
+                .await?
+                .ok_or_else(|| TypeMetadataResolverError::Networking(error)),
+        }
+    }
+
+    async fn cached_document(
+        &self,
+        url: &Url,
+        integrity: Option<&Integrity>,
+        include_stale: bool,
+    ) -> Result<Option<Vec<u8>>, TypeMetadataResolverError> {
+        let json =
+            self.cache.get(url, include_stale).await?.filter(|json| {
+                integrity.is_none_or(|integrity| check_resource_integrity(json, integrity.clone()).is_ok())
+            });
+
+        Ok(json)
+    }
+
+    async fn fetch_document(&self, url: &Url) -> Result<Vec<u8>, reqwest::Error> {
+        let response = self.client.get(url.clone()).send().await?.error_for_status()?;
+        let json = response.bytes().await?;
+
+        Ok(json.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches;
+
+    use http_utils::httpmock::httpmock_reqwest_client_builder;
+    use httpmock::Method::GET;
+    use httpmock::Mock;
+    use httpmock::MockServer;
+    use rstest::rstest;
+    use utils::vec_nonempty;
+
+    use super::*;
+    use crate::metadata::TypeMetadata;
+
+    const LEAF_PATH: &str = "/leaf";
+    const ROOT_PATH: &str = "/root";
+
+    struct ServedChain<'a> {
+        vct: String,
+        integrity: Integrity,
+        documents: TypeMetadataDocuments,
+        leaf_mock: Mock<'a>,
+        root_mock: Mock<'a>,
+    }
+
+    /// Serve a chain of a leaf and a root type metadata document from `server`.
+    async fn serve_chain(server: &MockServer) -> ServedChain<'_> {
+        let (vct, integrity, documents) = TypeMetadataDocuments::new_metadata_chain(vec_nonempty![
+            TypeMetadata::empty_example_with_attestation_type(&server.url(LEAF_PATH)),
+            TypeMetadata::empty_example_with_attestation_type(&server.url(ROOT_PATH)),
+        ])
+        .unwrap();
+
+        let [leaf_json, root_json] = documents.as_ref().as_slice() else {
+            panic!("chain should contain two documents");
+        };
+
+        let leaf_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path(LEAF_PATH);
+                then.status(200).body(leaf_json);
+            })
+            .await;
+        let root_mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path(ROOT_PATH);
+                then.status(200).body(root_json);
+            })
+            .await;
+
+        ServedChain {
+            vct,
+            integrity,
+            documents,
+            leaf_mock,
+            root_mock,
+        }
+    }
+
+    fn resolver(cache: TypeMetadataCache, mode: TypeMetadataResolverMode) -> TypeMetadataResolver {
+        TypeMetadataResolver::new(httpmock_reqwest_client_builder(), cache, mode).unwrap()
+    }
+
+    fn memory_cache() -> TypeMetadataCache {
+        TypeMetadataCache::new_memory(10, Duration::from_secs(60))
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_resolve(#[values(true, false)] with_integrity: bool) {
+        let server = MockServer::start_async().await;
+        let chain = serve_chain(&server).await;
+
+        let resolver = resolver(memory_cache(), TypeMetadataResolverMode::CacheFirst);
+        let integrity = with_integrity.then_some(&chain.integrity);
+
+        let documents = resolver.resolve(&chain.vct, integrity).await.unwrap();
+        assert_eq!(documents, chain.documents);
+
+        // The second resolution should be served from the cache
+        let documents = resolver.resolve(&chain.vct, integrity).await.unwrap();
+        assert_eq!(documents, chain.documents);
+
+        chain.leaf_mock.assert_calls_async(1).await;
+        chain.root_mock.assert_calls_async(1).await;
+
+        documents
+            .into_normalized(&chain.vct)
+            .expect("resolved documents should normalize");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_integrity_mismatch() {
+        let server = MockServer::start_async().await;
+        let chain = serve_chain(&server).await;
+
+        let resolver = resolver(memory_cache(), TypeMetadataResolverMode::CacheFirst);
+        let error = resolver
+            .resolve(&chain.vct, Some(&Integrity::from(b"other")))
+            .await
+            .expect_err("resolving should fail");
+
+        assert_matches!(
+            error,
+            TypeMetadataResolverError::Chain(TypeMetadataChainError::ResourceIntegrity(_))
+        );
+
+        // The document that failed the integrity check should not have been cached.
+        let url = TypeMetadataResolver::vct_url(&chain.vct).unwrap();
+        assert!(resolver.cache.get(&url, true).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_resolve_vct_mismatch() {
+        let server = MockServer::start_async().await;
+        let json = serde_json::to_vec(&TypeMetadata::empty_example_with_attestation_type(
+            "https://example.com",
+        ))
+        .unwrap();
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path(LEAF_PATH);
+                then.status(200).body(json);
+            })
+            .await;
+
+        let resolver = resolver(memory_cache(), TypeMetadataResolverMode::CacheFirst);
+        let error = resolver
+            .resolve(&server.url(LEAF_PATH), None)
+            .await
+            .expect_err("resolving should fail");
+
+        assert_matches!(error, TypeMetadataResolverError::VctMismatch { .. });
+    }
+
+    #[tokio::test]
+    async fn test_resolve_vct_not_https_url() {
+        let resolver = resolver(memory_cache(), TypeMetadataResolverMode::CacheFirst);
+        let error = resolver
+            .resolve("urn:eudi:pid:1", None)
+            .await
+            .expect_err("resolving should fail");
+
+        assert_matches!(error, TypeMetadataResolverError::VctNotHttpsUrl(_));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_disk_cache_offline() {
+        let directory = tempfile::tempdir().unwrap();
+        let server = MockServer::start_async().await;
+        let chain = serve_chain(&server).await;
+
+        // Nothing has been cached yet, so resolving should fail without contacting the server.
+        let offline_resolver = resolver(
+            TypeMetadataCache::new_disk(directory.path().to_path_buf(), Duration::from_secs(60)),
+            TypeMetadataResolverMode::CacheOnly,
+        );
+        let error = offline_resolver
+            .resolve(&chain.vct, Some(&chain.integrity))
+            .await
+            .expect_err("resolving should fail");
+
+        assert_matches!(error, TypeMetadataResolverError::NotCached(_));
+        chain.leaf_mock.assert_calls_async(0).await;
+
+        let online_resolver = resolver(
+            TypeMetadataCache::new_disk(directory.path().to_path_buf(), Duration::from_secs(60)),
+            TypeMetadataResolverMode::CacheFirst,
+        );
+        online_resolver
+            .resolve(&chain.vct, Some(&chain.integrity))
+            .await
+            .unwrap();
+
+        // Now that the documents are cached on disk, they should be available without contacting the server.
+        let documents = offline_resolver
+            .resolve(&chain.vct, Some(&chain.integrity))
+            .await
+            .unwrap();
+        assert_eq!(documents, chain.documents);
+
+        chain.leaf_mock.assert_calls_async(1).await;
+        chain.root_mock.assert_calls_async(1).await;
+    }
+
+    #[tokio::test]
+    async fn test_resolve_network_first() {
+        let server = MockServer::start_async().await;
+        let chain = serve_chain(&server).await;
+
+        let resolver = resolver(memory_cache(), TypeMetadataResolverMode::NetworkFirst);
+        resolver.resolve(&chain.vct, Some(&chain.integrity)).await.unwrap();
+        resolver.resolve(&chain.vct, Some(&chain.integrity)).await.unwrap();
+
+        // The documents should be fetched every time while the server is reachable...
+        chain.leaf_mock.assert_calls_async(2).await;
+        chain.root_mock.assert_calls_async(2).await;
+
+        // ...and the cached documents should be used as soon as it is not.
+        chain.leaf_mock.delete_async().await;
+        chain.root_mock.delete_async().await;
+
+        let documents = resolver.resolve(&chain.vct, Some(&chain.integrity)).await.unwrap();
+        assert_eq!(documents, chain.documents);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_disk_cache_stale() {
+        let directory = tempfile::tempdir().unwrap();
+        let server = MockServer::start_async().await;
+        let chain = serve_chain(&server).await;
+
+        // With a TTL of zero, every cached document is immediately stale.
+        let resolver = resolver(
+            TypeMetadataCache::new_disk(directory.path().to_path_buf(), Duration::ZERO),
+            TypeMetadataResolverMode::CacheFirst,
+        );
+        resolver.resolve(&chain.vct, Some(&chain.integrity)).await.unwrap();
+        resolver.resolve(&chain.vct, Some(&chain.integrity)).await.unwrap();
+
+        // Stale documents should be fetched again while the server is reachable...
+        chain.leaf_mock.assert_calls_async(2).await;
+        chain.root_mock.assert_calls_async(2).await;
+
+        // ...but should still be used as soon as it is not.
+        chain.leaf_mock.delete_async().await;
+        chain.root_mock.delete_async().await;
+
+        let documents = resolver.resolve(&chain.vct, Some(&chain.integrity)).await.unwrap();
+        assert_eq!(documents, chain.documents);
+    }
+}
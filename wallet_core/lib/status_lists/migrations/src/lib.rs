@@ -5,6 +5,9 @@ mod m20250925_000002_create_attestation_batch;
 mod m20250925_000003_create_status_list;
 mod m20250925_000004_create_status_list_item;
 mod m20250925_000005_create_attestation_batch_list_indices;
+mod m20261018_000001_add_attestation_batch_is_suspended;
+mod m20261018_000002_create_attestation_batch_status_history;
+mod m20261018_000003_add_attestation_batch_subject_lookup_hash;
 
 pub struct Migrator;
 
@@ -17,6 +20,9 @@ impl MigratorTrait for Migrator {
             Box::new(m20250925_000003_create_status_list::Migration),
             Box::new(m20250925_000004_create_status_list_item::Migration),
             Box::new(m20250925_000005_create_attestation_batch_list_indices::Migration),
+            Box::new(m20261018_000001_add_attestation_batch_is_suspended::Migration),
+            Box::new(m20261018_000002_create_attestation_batch_status_history::Migration),
+            Box::new(m20261018_000003_add_attestation_batch_subject_lookup_hash::Migration),
         ]
     }
 }
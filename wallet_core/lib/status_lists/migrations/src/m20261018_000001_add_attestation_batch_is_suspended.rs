@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::schema::*;
+
+use crate::m20250925_000002_create_attestation_batch::AttestationBatch;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AttestationBatch::Table)
+                    .add_column(boolean(AttestationBatchSuspension::IsSuspended).default(false))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(AttestationBatch::Table)
+                    .name("attestation_batch_suspended")
+                    .col(AttestationBatchSuspension::IsSuspended)
+                    .and_where(Expr::col(AttestationBatchSuspension::IsSuspended).eq(true))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum AttestationBatchSuspension {
+    IsSuspended,
+}
@@ -0,0 +1,76 @@
+use async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::schema::*;
+
+use crate::m20250925_000002_create_attestation_batch::AttestationBatch;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AttestationBatchStatusHistory::Table)
+                    .if_not_exists()
+                    .col(
+                        big_integer(AttestationBatchStatusHistory::Id)
+                            .primary_key()
+                            .auto_increment(),
+                    )
+                    .col(big_integer(AttestationBatchStatusHistory::AttestationBatchId))
+                    .col(string(AttestationBatchStatusHistory::Status))
+                    .col(
+                        timestamp_with_time_zone(AttestationBatchStatusHistory::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_attestation_batch_status_history_attestation_batch")
+                            .from(
+                                AttestationBatchStatusHistory::Table,
+                                AttestationBatchStatusHistory::AttestationBatchId,
+                            )
+                            .to(AttestationBatch::Table, AttestationBatch::Id)
+                            .on_delete(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(AttestationBatchStatusHistory::Table)
+                    .name("attestation_batch_status_history_attestation_batch_id")
+                    .col(AttestationBatchStatusHistory::AttestationBatchId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Record the batches that were revoked before the history was kept, as the number of status changes of the
+        // batches in a status list is used to version the published status list.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                INSERT INTO attestation_batch_status_history (attestation_batch_id, status)
+                SELECT id, 'revoked' FROM attestation_batch WHERE is_revoked
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum AttestationBatchStatusHistory {
+    Table,
+    Id,
+    AttestationBatchId,
+    Status,
+    CreatedAt,
+}
@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::schema::*;
+
+use crate::m20250925_000002_create_attestation_batch::AttestationBatch;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AttestationBatch::Table)
+                    .add_column(string_null(AttestationBatchSubject::SubjectLookupHash))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(AttestationBatch::Table)
+                    .name("attestation_batch_subject_lookup_hash")
+                    .col(AttestationBatchSubject::SubjectLookupHash)
+                    .and_where(Expr::col(AttestationBatchSubject::SubjectLookupHash).is_not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum AttestationBatchSubject {
+    SubjectLookupHash,
+}
@@ -11,12 +11,16 @@ pub struct Model {
     pub batch_id: Uuid,
     pub expiration_date: Option<Date>,
     pub is_revoked: bool,
+    pub is_suspended: bool,
+    pub subject_lookup_hash: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
     #[sea_orm(has_many = "super::attestation_batch_list_indices::Entity")]
     AttestationBatchListIndices,
+    #[sea_orm(has_many = "super::attestation_batch_status_history::Entity")]
+    AttestationBatchStatusHistory,
 }
 
 impl Related<super::attestation_batch_list_indices::Entity> for Entity {
@@ -25,6 +29,12 @@ impl Related<super::attestation_batch_list_indices::Entity> for Entity {
     }
 }
 
+impl Related<super::attestation_batch_status_history::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AttestationBatchStatusHistory.def()
+    }
+}
+
 impl Related<super::status_list::Entity> for Entity {
     fn to() -> RelationDef {
         super::attestation_batch_list_indices::Relation::StatusList.def()
@@ -4,6 +4,7 @@ pub mod prelude;
 
 pub mod attestation_batch;
 pub mod attestation_batch_list_indices;
+pub mod attestation_batch_status_history;
 pub mod attestation_group;
 pub mod status_list;
 pub mod status_list_item;
@@ -2,6 +2,7 @@
 
 pub use super::attestation_batch::Entity as AttestationBatch;
 pub use super::attestation_batch_list_indices::Entity as AttestationBatchListIndices;
+pub use super::attestation_batch_status_history::Entity as AttestationBatchStatusHistory;
 pub use super::attestation_group::Entity as AttestationGroup;
 pub use super::status_list::Entity as StatusList;
 pub use super::status_list_item::Entity as StatusListItem;
@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::convert::Infallible;
 use std::num::NonZeroUsize;
 use std::path::Path;
@@ -9,6 +10,7 @@ use attestation_types::status_claim::StatusClaim;
 use attestation_types::status_claim::StatusListClaim;
 use chrono::DateTime;
 use chrono::Utc;
+use cose::CoseError;
 use crypto::EcdsaKeySend;
 use futures::StreamExt;
 use futures::future::join_all;
@@ -19,14 +21,18 @@ use jwt::error::JwtParseError;
 use jwt::error::JwtSignError;
 use measure::measure;
 use rand::seq::SliceRandom;
+use sea_orm::AccessMode;
 use sea_orm::ColumnTrait;
+use sea_orm::Condition;
 use sea_orm::DatabaseConnection;
 use sea_orm::DatabaseTransaction;
 use sea_orm::DbErr;
 use sea_orm::EntityTrait;
 use sea_orm::IntoActiveModel;
+use sea_orm::IsolationLevel;
 use sea_orm::JoinType;
 use sea_orm::NotSet;
+use sea_orm::PaginatorTrait;
 use sea_orm::QueryFilter;
 use sea_orm::QueryOrder;
 use sea_orm::QuerySelect;
@@ -36,6 +42,7 @@ use sea_orm::Set;
 use sea_orm::TransactionTrait;
 use sea_orm::TryInsertResult;
 use sea_orm::sea_query::Expr;
+use sea_orm::sea_query::IntoCondition;
 use sea_orm::sea_query::LockBehavior;
 use sea_orm::sea_query::LockType;
 use sea_orm::sea_query::OnConflict;
@@ -44,8 +51,11 @@ use sea_orm::sqlx::types::chrono::NaiveDate;
 use token_status_list::status_list::PackedStatusList;
 use token_status_list::status_list::StatusList;
 use token_status_list::status_list::StatusType;
+use token_status_list::status_list_aggregation::StatusListAggregation;
+use token_status_list::status_list_cwt::StatusListCwtError;
 use token_status_list::status_list_service::RevocationError;
 use token_status_list::status_list_service::StatusListService;
+use token_status_list::status_list_service::SubjectLookupHash;
 use token_status_list::status_list_token::StatusListToken;
 use token_status_list::status_list_token::StatusListTokenBuilder;
 use tokio::task::AbortHandle;
@@ -59,9 +69,11 @@ use uuid::Uuid;
 use crate::config::StatusListConfig;
 use crate::entity::attestation_batch;
 use crate::entity::attestation_batch_list_indices;
+use crate::entity::attestation_batch_status_history;
 use crate::entity::attestation_group;
 use crate::entity::status_list;
 use crate::entity::status_list_item;
+use crate::publish::AGGREGATION_NAME;
 use crate::publish::LockVersion;
 use crate::publish::PublishLockError;
 use crate::refresh::RefreshControl;
@@ -86,6 +98,36 @@ pub trait RevokeAll {
     async fn is_revoked_all(&self) -> Result<bool, Self::Error>;
 }
 
+/// Status of an attestation batch, as recorded in the status history of the batch.
+///
+/// Revocation is final, while a suspended batch can be reinstated, i.e. become valid again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchStatus {
+    Valid,
+    Revoked,
+    Suspended,
+}
+
+impl BatchStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Valid => "valid",
+            Self::Revoked => "revoked",
+            Self::Suspended => "suspended",
+        }
+    }
+
+    /// Condition on the attestation batches that are allowed to transition to this status.
+    fn transition_condition(self) -> Condition {
+        let condition = Condition::all().add(attestation_batch::Column::IsRevoked.eq(false));
+        match self {
+            Self::Valid => condition.add(attestation_batch::Column::IsSuspended.eq(true)),
+            Self::Revoked => condition,
+            Self::Suspended => condition.add(attestation_batch::Column::IsSuspended.eq(false)),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct NoRevokeAll;
 
@@ -113,6 +155,9 @@ impl RevokeAll for NoRevokeAll {
 /// is the exclusive end of the sequence numbers used for that status list and the start of a new
 /// status list. This next sequence number is also stored on the attestation group to detect a
 /// concurrent creation of the list by a separate instance.
+///
+/// Status lists are published using one bit per status, unless any of the attestations in the list
+/// is suspended. In that case two bits are used, as required for the suspended status.
 #[derive(Debug)]
 pub struct PostgresStatusListService<K, R> {
     connection: DatabaseConnection,
@@ -162,6 +207,15 @@ pub enum StatusListServiceError {
     #[error("could sign JWT: {0}")]
     JwtSign(#[from] JwtSignError),
 
+    #[error("could not sign CWT: {0}")]
+    CwtSign(#[from] CoseError),
+
+    #[error("could not encode CWT: {0}")]
+    CwtEncode(#[from] StatusListCwtError),
+
+    #[error("could not encode status list aggregation: {0}")]
+    AggregationEncode(#[from] serde_json::Error),
+
     #[error("no status list available and could not create one")]
     NoStatusListAvailable,
 
@@ -214,11 +268,12 @@ where
     async fn obtain_status_claims(
         &self,
         batch_id: Uuid,
+        subject_lookup_hash: Option<SubjectLookupHash>,
         expires: Option<DateTimeSeconds>,
         copies: NonZeroUsize,
     ) -> Result<VecNonEmpty<StatusClaim>, Self::Error> {
         tracing::debug!("Obtaining status claims with {} copies", copies);
-        self.obtain_status_claims_and_scheduled_tasks(batch_id, expires, copies)
+        self.obtain_status_claims_and_scheduled_tasks(batch_id, subject_lookup_hash, expires, copies)
             .await
             .map(|(claims, _)| claims)
     }
@@ -274,52 +329,23 @@ where
 
     #[measure(name = "nlwallet_status_list_operations", "service" => "status_lists")]
     async fn revoke_attestation_batches(&self, batch_ids: Vec<Uuid>) -> Result<(), RevocationError> {
-        // Find batches and status_lists for this service by batch_ids
-        let batches: Vec<(i64, i64, String, i32)> = attestation_batch::Entity::find()
-            .select_only()
-            .column(attestation_batch::Column::Id)
-            .select_column(status_list::Column::Id)
-            .select_column(status_list::Column::ExternalId)
-            .select_column(status_list::Column::Size)
-            .inner_join(status_list::Entity)
-            .filter(
-                attestation_batch::Column::BatchId
-                    .is_in(batch_ids)
-                    .and(status_list::Column::AttestationGroupId.eq(self.attestation_group_id)),
-            )
-            .into_tuple()
-            .all(&self.connection)
+        self.update_attestation_batches(batch_ids, BatchStatus::Revoked)
             .await
-            .map_err(|e| RevocationError::InternalError(Box::new(e)))?;
+            .map_err(|e| RevocationError::InternalError(Box::new(e)))
+    }
 
-        let (attestation_batch_ids, status_list_info): (Vec<_>, Vec<_>) = batches
-            .into_iter()
-            .map(|(batch_id, status_list_id, external_id, size)| (batch_id, (status_list_id, external_id, size)))
-            .unzip();
-
-        // Update revocation for all batches
-        attestation_batch::Entity::update_many()
-            .col_expr(attestation_batch::Column::IsRevoked, Expr::value(true))
-            .filter(attestation_batch::Column::Id.is_in(attestation_batch_ids))
-            .exec(&self.connection)
+    #[measure(name = "nlwallet_status_list_operations", "service" => "status_lists")]
+    async fn suspend_attestation_batches(&self, batch_ids: Vec<Uuid>) -> Result<(), RevocationError> {
+        self.update_attestation_batches(batch_ids, BatchStatus::Suspended)
             .await
-            .map_err(|e| RevocationError::InternalError(Box::new(e)))?;
+            .map_err(|e| RevocationError::InternalError(Box::new(e)))
+    }
 
-        // Publish new status list
-        let publisher = self
-            .to_publisher()
+    #[measure(name = "nlwallet_status_list_operations", "service" => "status_lists")]
+    async fn reinstate_attestation_batches(&self, batch_ids: Vec<Uuid>) -> Result<(), RevocationError> {
+        self.update_attestation_batches(batch_ids, BatchStatus::Valid)
             .await
-            .map_err(|e| RevocationError::InternalError(Box::new(e)))?;
-        try_join_all(status_list_info.into_iter().unique_by(|(id, _, _)| *id).map(
-            |(list_id, external_id, size)| async move {
-                let size = size.try_into().expect("size should be non-zero");
-                publisher.publish(list_id, external_id.as_str(), size).await
-            },
-        ))
-        .await
-        .map_err(|e| RevocationError::InternalError(Box::new(e)))?;
-
-        Ok(())
+            .map_err(|e| RevocationError::InternalError(Box::new(e)))
     }
 }
 
@@ -350,6 +376,13 @@ impl<K, R> PostgresStatusListService<K, R> {
             .join_base_url(&self.config.context_path)
             .join(external_id)
     }
+
+    fn aggregation_url(&self) -> Url {
+        self.config
+            .base_url
+            .join_base_url(&self.config.context_path)
+            .join(AGGREGATION_NAME)
+    }
 }
 
 impl<K, R> PostgresStatusListService<K, R>
@@ -361,6 +394,7 @@ where
     pub async fn obtain_status_claims_and_scheduled_tasks(
         &self,
         batch_id: Uuid,
+        subject_lookup_hash: Option<SubjectLookupHash>,
         expires: Option<DateTimeSeconds>,
         copies: NonZeroUsize,
     ) -> Result<(VecNonEmpty<StatusClaim>, Vec<JoinHandle<()>>), StatusListServiceError> {
@@ -380,6 +414,7 @@ where
         Self::create_attestation_batch(
             &tx,
             batch_id,
+            subject_lookup_hash,
             expires.map(|d| DateTime::from(d).date_naive()),
             lists_with_items.iter().map(|(list, items)| {
                 let indices = items.iter().map(|item| item.index).collect();
@@ -409,6 +444,114 @@ where
         Ok((claims.try_into().unwrap(), tasks))
     }
 
+    /// Change the status of the attestation batches with `batch_ids` to `status`, recording the change in the status
+    /// history and republishing the affected status lists. Batches for which the transition is not allowed, such as
+    /// suspending a revoked batch, are left unchanged.
+    #[measure(name = "nlwallet_status_list_operations", "service" => "status_lists")]
+    pub async fn update_attestation_batches(
+        &self,
+        batch_ids: Vec<Uuid>,
+        status: BatchStatus,
+    ) -> Result<(), StatusListServiceError> {
+        self.update_attestation_batches_matching(attestation_batch::Column::BatchId.is_in(batch_ids), status)
+            .await
+            .map(|_| ())
+    }
+
+    /// Revoke all attestation batches issued to the subject with `subject_lookup_hash`, returning the IDs of the
+    /// batches that were revoked. Batches that were already revoked are not included.
+    #[measure(name = "nlwallet_status_list_operations", "service" => "status_lists")]
+    pub async fn revoke_attestation_batches_for_subject(
+        &self,
+        subject_lookup_hash: &SubjectLookupHash,
+    ) -> Result<Vec<Uuid>, StatusListServiceError> {
+        self.update_attestation_batches_matching(
+            attestation_batch::Column::SubjectLookupHash.eq(subject_lookup_hash.as_str()),
+            BatchStatus::Revoked,
+        )
+        .await
+    }
+
+    /// Change the status of the attestation batches matching `condition` to `status`, returning the IDs of the batches
+    /// that were updated.
+    async fn update_attestation_batches_matching(
+        &self,
+        condition: impl IntoCondition,
+        status: BatchStatus,
+    ) -> Result<Vec<Uuid>, StatusListServiceError> {
+        // Find batches and status_lists for this service matching the condition
+        let batches: Vec<(i64, i64, String, i32)> = attestation_batch::Entity::find()
+            .select_only()
+            .column(attestation_batch::Column::Id)
+            .select_column(status_list::Column::Id)
+            .select_column(status_list::Column::ExternalId)
+            .select_column(status_list::Column::Size)
+            .inner_join(status_list::Entity)
+            .filter(condition)
+            .filter(status_list::Column::AttestationGroupId.eq(self.attestation_group_id))
+            .into_tuple()
+            .all(&self.connection)
+            .await?;
+
+        let tx = self.connection.begin().await?;
+
+        // Update status of all batches for which the transition is allowed
+        let update = attestation_batch::Entity::update_many();
+        let update = match status {
+            BatchStatus::Valid => update.col_expr(attestation_batch::Column::IsSuspended, Expr::value(false)),
+            BatchStatus::Revoked => update
+                .col_expr(attestation_batch::Column::IsRevoked, Expr::value(true))
+                .col_expr(attestation_batch::Column::IsSuspended, Expr::value(false)),
+            BatchStatus::Suspended => update.col_expr(attestation_batch::Column::IsSuspended, Expr::value(true)),
+        };
+        let updated_batches = update
+            .filter(attestation_batch::Column::Id.is_in(batches.iter().map(|(batch_id, ..)| *batch_id).unique()))
+            .filter(status.transition_condition())
+            .exec_with_returning(&tx)
+            .await?;
+
+        if updated_batches.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let updated_batch_ids = updated_batches.iter().map(|batch| batch.id).collect::<HashSet<_>>();
+
+        // Record the status change of the updated batches
+        let history = updated_batch_ids
+            .iter()
+            .map(|batch_id| attestation_batch_status_history::ActiveModel {
+                id: NotSet,
+                attestation_batch_id: Set(*batch_id),
+                status: Set(status.as_str().to_string()),
+                created_at: NotSet,
+            });
+        attestation_batch_status_history::Entity::insert_many(history)
+            .exec(&tx)
+            .await?;
+
+        tx.commit().await?;
+
+        // Publish new status lists
+        let publisher = self
+            .to_publisher()
+            .await
+            .map_err(|e| StatusListServiceError::RevokeAll(Box::new(e)))?;
+        try_join_all(
+            batches
+                .into_iter()
+                .filter(|(batch_id, ..)| updated_batch_ids.contains(batch_id))
+                .map(|(_, list_id, external_id, size)| (list_id, external_id, size))
+                .unique_by(|(id, _, _)| *id)
+                .map(|(list_id, external_id, size)| async move {
+                    let size = size.try_into().expect("size should be non-zero");
+                    publisher.publish(list_id, external_id.as_str(), size).await
+                }),
+        )
+        .await?;
+
+        Ok(updated_batches.into_iter().map(|batch| batch.batch_id).collect())
+    }
+
     #[measure(name = "nlwallet_status_list_operations", "service" => "status_lists")]
     async fn fetch_exclusive_available_status_lists_or_create(
         &self,
@@ -537,6 +680,7 @@ where
     async fn create_attestation_batch(
         tx: &DatabaseTransaction,
         batch_id: Uuid,
+        subject_lookup_hash: Option<SubjectLookupHash>,
         expiration_date: Option<NaiveDate>,
         list_indices: impl Iterator<Item = (i64, Vec<i32>)>,
     ) -> Result<(), StatusListServiceError> {
@@ -545,6 +689,8 @@ where
             batch_id: Set(batch_id),
             expiration_date: Set(expiration_date),
             is_revoked: Set(false),
+            is_suspended: Set(false),
+            subject_lookup_hash: Set(subject_lookup_hash.map(|hash| hash.as_str().to_string())),
         };
         let attestation_batch_id = attestation_batch::Entity::insert(model).exec(tx).await?.last_insert_id;
 
@@ -657,6 +803,16 @@ where
         publish.await??;
 
         tx.commit().await?;
+
+        // Publish the aggregation after committing, so that it contains the new list
+        if let Err(err) = self.publish_aggregation().await {
+            tracing::warn!(
+                "Failed to publish status list aggregation for attestation group ID {}: {}",
+                self.attestation_group_id,
+                err,
+            );
+        }
+
         Ok(true)
     }
 
@@ -825,6 +981,15 @@ where
             }
         };
 
+        // Always republish the aggregation, as another instance could have overwritten it with an outdated one
+        if let Err(err) = self.publish_aggregation().await {
+            tracing::warn!(
+                "Failed to publish status list aggregation for attestation group ID {}: {}",
+                self.attestation_group_id,
+                err
+            );
+        }
+
         // Get publisher
         let publisher = match self.to_publisher().await {
             Ok(publisher) => publisher,
@@ -844,7 +1009,12 @@ where
                     // Ignore error is ok because it is just logged with WARN
                     .ok();
 
-                if expiry.is_none_or(|exp| refresh_control.should_refresh(exp)) {
+                // Lists that were published before the CWT format was supported only exist as JWT
+                let cwt_exists = tokio::fs::try_exists(self.config.publish_dir.cwt_path(&list.external_id))
+                    .await
+                    .unwrap_or_default();
+
+                if !cwt_exists || expiry.is_none_or(|exp| refresh_control.should_refresh(exp)) {
                     tracing::info!("Republishing status list for ID {}", list.id);
                     let size = list.size.try_into().expect("size should be non-zero");
 
@@ -876,6 +1046,29 @@ where
         refresh_control.next_refresh_delay(expiries.into_iter().collect::<Option<Vec<_>>>().unwrap_or_default())
     }
 
+    async fn publish_aggregation(&self) -> Result<(), StatusListServiceError> {
+        let external_ids: Vec<String> = status_list::Entity::find()
+            .select_only()
+            .select_column(status_list::Column::ExternalId)
+            .filter(status_list::Column::AttestationGroupId.eq(self.attestation_group_id))
+            .order_by_asc(status_list::Column::Id)
+            .into_tuple()
+            .all(&self.connection)
+            .await?;
+
+        let aggregation = StatusListAggregation {
+            status_lists: external_ids
+                .iter()
+                .map(|external_id| self.external_id_url(external_id))
+                .collect(),
+        };
+        let json = serde_json::to_vec(&aggregation)?;
+
+        let path = self.config.publish_dir.aggregation_path();
+        let tmp_path = self.config.publish_dir.tmp_path(AGGREGATION_NAME);
+        tokio::task::spawn_blocking(move || write_via_tmp_path(&tmp_path, path, &json)).await?
+    }
+
     async fn publish_new_status_list(&self, external_id: &str) -> Result<(), StatusListServiceError> {
         let is_revoked_all = self
             .revoke_all
@@ -891,18 +1084,21 @@ where
         } else {
             PackedStatusList::new(self.config.list_size.as_usize())
         };
-        let token = StatusListToken::builder(sub, packed)
+        let builder = StatusListToken::builder(sub, packed)
             .exp(Some(expires))
             .ttl(self.config.ttl)
-            .sign(&self.config.key_pair)
-            .await?;
+            .aggregation_uri(Some(self.aggregation_url()));
+        let token = builder.clone().sign(&self.config.key_pair).await?;
+        let cwt = builder.sign_cwt(&self.config.key_pair).await?.to_vec()?;
 
         // Write to disk
         let publish_lock = self.config.publish_dir.lock_for(external_id);
         let jwt_path = self.config.publish_dir.jwt_path(external_id);
+        let cwt_path = self.config.publish_dir.cwt_path(external_id);
         tokio::task::spawn_blocking(move || {
             // create because a new status list external id can be reused if the transaction fails
             publish_lock.create(expires)?;
+            std::fs::write(&cwt_path, cwt).map_err(|err| StatusListServiceError::IOWithPath(cwt_path, err))?;
             std::fs::write(&jwt_path, token.as_ref().serialization())
                 .map_err(|err| StatusListServiceError::IOWithPath(jwt_path, err))
         })
@@ -915,22 +1111,50 @@ where
         external_id: &str,
         size: usize,
     ) -> Result<bool, StatusListServiceError> {
-        // Fetch all revoked attestation for this status list
-        let result: Vec<Vec<i32>> = attestation_batch_list_indices::Entity::find()
+        // Read from a single snapshot, so that the version matches the statuses that are published
+        let tx = self
+            .connection
+            .begin_with_config(Some(IsolationLevel::RepeatableRead), Some(AccessMode::ReadOnly))
+            .await?;
+
+        // Fetch all revoked and suspended attestations for this status list
+        let result: Vec<(Vec<i32>, bool)> = attestation_batch_list_indices::Entity::find()
             .join(
                 JoinType::InnerJoin,
                 attestation_batch_list_indices::Relation::AttestationBatch.def(),
             )
             .select_only()
             .select_column(attestation_batch_list_indices::Column::Indices)
+            .select_column(attestation_batch::Column::IsRevoked)
             .filter(attestation_batch_list_indices::Column::StatusListId.eq(list_id))
-            .filter(attestation_batch::Column::IsRevoked.eq(true))
+            .filter(
+                attestation_batch::Column::IsRevoked
+                    .eq(true)
+                    .or(attestation_batch::Column::IsSuspended.eq(true)),
+            )
             .into_tuple()
-            .all(&self.connection)
+            .all(&tx)
+            .await?;
+
+        // As suspensions can be lifted, the number of status changes is used as version instead of the number of
+        // revoked and suspended attestations, since the former only ever increases.
+        let number_of_status_changes = attestation_batch_status_history::Entity::find()
+            .filter(
+                attestation_batch_status_history::Column::AttestationBatchId.in_subquery(
+                    Query::select()
+                        .column(attestation_batch_list_indices::Column::AttestationBatchId)
+                        .from(attestation_batch_list_indices::Entity)
+                        .and_where(attestation_batch_list_indices::Column::StatusListId.eq(list_id))
+                        .to_owned(),
+                ),
+            )
+            .count(&tx)
             .await?;
 
+        tx.commit().await?;
+
         let expires = Utc::now() + self.config.expiry;
-        let version = LockVersion::from(result.len(), expires);
+        let version = LockVersion::from(number_of_status_changes as usize, expires);
         self.config
             .publish_dir
             .lock_for(external_id)
@@ -939,8 +1163,15 @@ where
                 let sub = self.external_id_url(external_id);
                 let builder = tokio::task::spawn_blocking(move || {
                     let mut status_list = StatusList::new(size);
-                    for index in result.into_iter().flatten() {
-                        status_list.insert(index as usize, StatusType::Invalid);
+                    for (indices, is_revoked) in result {
+                        let status = if is_revoked {
+                            StatusType::Invalid
+                        } else {
+                            StatusType::Suspended
+                        };
+                        for index in indices {
+                            status_list.insert(index as usize, status);
+                        }
                     }
                     StatusListToken::builder(sub, status_list.pack())
                 })
@@ -975,20 +1206,21 @@ where
         expires: DateTime<Utc>,
         external_id: &str,
     ) -> Result<(), StatusListServiceError> {
-        // Sign
-        let token = builder
+        // Sign in both formats, so that either can be served
+        let builder = builder
             .exp(Some(expires))
             .ttl(self.config.ttl)
-            .sign(&self.config.key_pair)
-            .await?;
+            .aggregation_uri(Some(self.aggregation_url()));
+        let token = builder.clone().sign(&self.config.key_pair).await?;
+        let cwt = builder.sign_cwt(&self.config.key_pair).await?.to_vec()?;
 
-        // Write to a tempfile and atomically move via rename
+        // Write to a tempfile and atomically move via rename, the JWT last as its expiry is used for refreshing
         let jwt_path = self.config.publish_dir.jwt_path(external_id);
+        let cwt_path = self.config.publish_dir.cwt_path(external_id);
         let tmp_path = self.config.publish_dir.tmp_path(external_id);
         tokio::task::spawn_blocking(move || {
-            let buf = token.as_ref().serialization();
-            std::fs::write(&tmp_path, buf).map_err(|err| StatusListServiceError::IOWithPath(tmp_path.clone(), err))?;
-            std::fs::rename(&tmp_path, &jwt_path).map_err(|err| StatusListServiceError::IOWithPath(jwt_path, err))
+            write_via_tmp_path(&tmp_path, cwt_path, &cwt)?;
+            write_via_tmp_path(&tmp_path, jwt_path, token.as_ref().serialization().as_bytes())
         })
         .await??;
 
@@ -996,6 +1228,11 @@ where
     }
 }
 
+fn write_via_tmp_path(tmp_path: &Path, path: PathBuf, buf: &[u8]) -> Result<(), StatusListServiceError> {
+    std::fs::write(tmp_path, buf).map_err(|err| StatusListServiceError::IOWithPath(tmp_path.to_path_buf(), err))?;
+    std::fs::rename(tmp_path, &path).map_err(|err| StatusListServiceError::IOWithPath(path, err))
+}
+
 async fn initialize_attestation_group_id(
     connection: &DatabaseConnection,
     attestation_group: &str,
@@ -1097,7 +1334,7 @@ mod tests {
 
         let batch_id = Uuid::new_v4();
         let result = service
-            .obtain_status_claims_and_scheduled_tasks(batch_id, None, 3.try_into().unwrap())
+            .obtain_status_claims_and_scheduled_tasks(batch_id, None, None, 3.try_into().unwrap())
             .await;
         assert_matches!(result, Err(StatusListServiceError::TooManyClaimsRequested(size)) if size == 3);
     }
@@ -16,6 +16,7 @@ use crate::entity::attestation_batch;
 pub struct BatchIsRevoked {
     pub batch_id: Uuid,
     pub is_revoked: bool,
+    pub is_suspended: bool,
 }
 
 #[derive(Debug, Clone, Constructor)]
@@ -36,11 +37,16 @@ impl PostgresRevocationHelper {
             .select_only()
             .select_column(attestation_batch::Column::BatchId)
             .select_column(attestation_batch::Column::IsRevoked)
+            .select_column(attestation_batch::Column::IsSuspended)
             .into_tuple()
             .one(&self.connection)
             .await
             .map_err(|error| RevocationError::InternalError(Box::new(error)))?
-            .map(|(batch_id, is_revoked)| BatchIsRevoked { batch_id, is_revoked })
+            .map(|(batch_id, is_revoked, is_suspended)| BatchIsRevoked {
+                batch_id,
+                is_revoked,
+                is_suspended,
+            })
             .ok_or_else(|| RevocationError::BatchIdNotFound(batch_id))
     }
 
@@ -49,12 +55,17 @@ impl PostgresRevocationHelper {
             .select_only()
             .select_column(attestation_batch::Column::BatchId)
             .select_column(attestation_batch::Column::IsRevoked)
+            .select_column(attestation_batch::Column::IsSuspended)
             .into_tuple()
             .all(&self.connection)
             .await
             .map_err(|error| RevocationError::InternalError(Box::new(error)))?
             .into_iter()
-            .map(|(batch_id, is_revoked)| BatchIsRevoked { batch_id, is_revoked })
+            .map(|(batch_id, is_revoked, is_suspended)| BatchIsRevoked {
+                batch_id,
+                is_revoked,
+                is_suspended,
+            })
             .collect())
     }
 }
@@ -40,10 +40,15 @@ pub enum PublishDirError {
     NotADirectory,
 }
 
+/// Name of the published Status List Aggregation, which cannot clash with the external ids of status lists.
+pub const AGGREGATION_NAME: &str = "aggregation";
+
 impl PublishDir {
     const TMP_EXTENSION: &'static str = "tmp";
     const JWT_EXTENSION: &'static str = "jwt";
+    const CWT_EXTENSION: &'static str = "cwt";
     const LOCK_EXTENSION: &'static str = "lock";
+    const JSON_EXTENSION: &'static str = "json";
 
     fn sanitize(path: PathBuf) -> PathBuf {
         prefix_local_path(path).into_owned()
@@ -71,6 +76,14 @@ impl PublishDir {
         self.path_with_extension(external_id, Self::JWT_EXTENSION)
     }
 
+    pub fn cwt_path(&self, external_id: &str) -> PathBuf {
+        self.path_with_extension(external_id, Self::CWT_EXTENSION)
+    }
+
+    pub fn aggregation_path(&self) -> PathBuf {
+        self.path_with_extension(AGGREGATION_NAME, Self::JSON_EXTENSION)
+    }
+
     pub fn lock_for(&self, external_id: &str) -> PublishLock {
         PublishLock(self.path_with_extension(external_id, Self::LOCK_EXTENSION))
     }
@@ -115,20 +128,20 @@ pub struct PublishLock(PathBuf);
 /// A writer that writes later, can have an older view of the status list from
 /// the database.
 ///
-/// We can use the number of revocations as major version because revocations
-/// are irreversible and the database will always return committed rows, so no
+/// We can use the number of status changes as major version because the status
+/// history only grows and the database will always return committed rows, so no
 /// interleaving can happen. The expiration is used as minor version to update
-/// a list with the same revocations for a newer one.
+/// a list with the same status changes for a newer one.
 #[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct LockVersion {
-    number_of_revocations: usize,
+    number_of_status_changes: usize,
     expiration: i64,
 }
 
 impl LockVersion {
-    pub fn from(number_of_revocations: usize, expiration: DateTime<Utc>) -> Self {
+    pub fn from(number_of_status_changes: usize, expiration: DateTime<Utc>) -> Self {
         Self {
-            number_of_revocations,
+            number_of_status_changes,
             expiration: expiration.timestamp(),
         }
     }
@@ -138,18 +151,18 @@ impl LockVersion {
         reader.read_exact(&mut buf)?;
 
         // Unwrap is safe as the buf is the length of both combined
-        let number_of_revocations = usize::from_le_bytes(buf[..size_of::<usize>()].try_into().unwrap());
+        let number_of_status_changes = usize::from_le_bytes(buf[..size_of::<usize>()].try_into().unwrap());
         let expiration = i64::from_le_bytes(buf[size_of::<usize>()..].try_into().unwrap());
 
         Ok(Self {
-            number_of_revocations,
+            number_of_status_changes,
             expiration,
         })
     }
 
     fn write_to_io(&self, writer: &mut impl Write) -> Result<(), std::io::Error> {
         let mut buf = [0; { size_of::<usize>() + size_of::<i64>() }];
-        buf[..size_of::<usize>()].copy_from_slice(&self.number_of_revocations.to_le_bytes());
+        buf[..size_of::<usize>()].copy_from_slice(&self.number_of_status_changes.to_le_bytes());
         buf[size_of::<usize>()..].copy_from_slice(&self.expiration.to_le_bytes());
         writer.write_all(&buf)
     }
@@ -235,14 +248,14 @@ mod tests {
     #[test]
     fn default_lock_version() {
         let version = LockVersion::default();
-        assert_eq!(version.number_of_revocations, 0);
+        assert_eq!(version.number_of_status_changes, 0);
         assert_eq!(version.expiration, 0);
     }
 
     #[test]
     fn lock_version_serialize_deserialize() {
         let version = LockVersion {
-            number_of_revocations: 1337,
+            number_of_status_changes: 1337,
             expiration: Utc::now().timestamp(),
         };
 
@@ -307,16 +320,16 @@ mod tests {
     }
 
     #[rstest]
-    #[case(LockVersion { number_of_revocations: 1, expiration: 3 }, false)]
-    #[case(LockVersion { number_of_revocations: 2, expiration: 1 }, false)]
-    #[case(LockVersion { number_of_revocations: 2, expiration: 2 }, false)]
-    #[case(LockVersion { number_of_revocations: 2, expiration: 3 }, true)]
-    #[case(LockVersion { number_of_revocations: 3, expiration: 1 }, true)]
+    #[case(LockVersion { number_of_status_changes: 1, expiration: 3 }, false)]
+    #[case(LockVersion { number_of_status_changes: 2, expiration: 1 }, false)]
+    #[case(LockVersion { number_of_status_changes: 2, expiration: 2 }, false)]
+    #[case(LockVersion { number_of_status_changes: 2, expiration: 3 }, true)]
+    #[case(LockVersion { number_of_status_changes: 3, expiration: 1 }, true)]
     #[tokio::test]
     async fn publish_with_lock_should_only_called_when_newer(#[case] version: LockVersion, #[case] publish: bool) {
         let mut file = NamedTempFile::new().unwrap();
         LockVersion {
-            number_of_revocations: 2,
+            number_of_status_changes: 2,
             expiration: 2,
         }
         .write_to_io(file.as_file_mut())
@@ -331,7 +344,7 @@ mod tests {
         let mut lock_contents = Vec::new();
         file.read_to_end(&mut lock_contents).unwrap();
         if publish {
-            assert_eq!(lock_contents[0], version.number_of_revocations as u8);
+            assert_eq!(lock_contents[0], version.number_of_status_changes as u8);
             assert_eq!(lock_contents[size_of::<usize>()], version.expiration as u8);
         } else {
             assert_eq!(lock_contents[0], 2);
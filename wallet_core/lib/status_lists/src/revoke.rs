@@ -9,13 +9,14 @@ use crypto::EcdsaKeySend;
 use futures::future::try_join_all;
 use itertools::Itertools;
 use token_status_list::status_list_service::RevocationError;
-use token_status_list::status_list_service::StatusListService;
+use token_status_list::status_list_service::SubjectLookupHash;
 use utils::vec_at_least::VecNonEmpty;
 use utoipa::OpenApi;
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_axum::routes;
 use uuid::Uuid;
 
+use crate::postgres::BatchStatus;
 use crate::postgres::PostgresStatusListService;
 use crate::postgres::RevokeAll;
 #[cfg(feature = "test_api")]
@@ -49,6 +50,90 @@ async fn revoke_batch<K, R>(
     State(state): State<Arc<RevocationRouterState<K, R>>>,
     Json(batch_ids): Json<Vec<Uuid>>,
 ) -> Result<(), RevocationError>
+where
+    K: EcdsaKeySend + Sync + 'static,
+    R: RevokeAll + Clone + Sync + 'static,
+{
+    update_batches(&state, batch_ids, BatchStatus::Revoked).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/suspend/",
+    request_body(
+        content = Vec<Uuid>,
+        example = json!(["67e55044-10b1-426f-9247-bb680e5fe0c8"]),
+    ),
+    responses(
+        (status = OK, description = "Successfully suspended the provided batch IDs, except for revoked batches.")
+    )
+)]
+async fn suspend_batch<K, R>(
+    State(state): State<Arc<RevocationRouterState<K, R>>>,
+    Json(batch_ids): Json<Vec<Uuid>>,
+) -> Result<(), RevocationError>
+where
+    K: EcdsaKeySend + Sync + 'static,
+    R: RevokeAll + Clone + Sync + 'static,
+{
+    update_batches(&state, batch_ids, BatchStatus::Suspended).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/reinstate/",
+    request_body(
+        content = Vec<Uuid>,
+        example = json!(["67e55044-10b1-426f-9247-bb680e5fe0c8"]),
+    ),
+    responses(
+        (status = OK, description = "Successfully reinstated the provided suspended batch IDs.")
+    )
+)]
+async fn reinstate_batch<K, R>(
+    State(state): State<Arc<RevocationRouterState<K, R>>>,
+    Json(batch_ids): Json<Vec<Uuid>>,
+) -> Result<(), RevocationError>
+where
+    K: EcdsaKeySend + Sync + 'static,
+    R: RevokeAll + Clone + Sync + 'static,
+{
+    update_batches(&state, batch_ids, BatchStatus::Valid).await
+}
+
+/// Revoke all attestation batches issued to the subject with `subject_lookup_hash` in every status list service,
+/// returning the IDs of the revoked batches.
+///
+/// This is deliberately not exposed as an endpoint of the revocation router, as operators cannot compute subject
+/// lookup hashes themselves. Instead, issuers that record these hashes expose an internal endpoint that derives the
+/// hash from the subject's identifier, such as the `/revoke/bsn/` endpoint of the PID issuer.
+pub async fn revoke_subject_batches<K, R>(
+    status_list_services: &[PostgresStatusListService<K, R>],
+    subject_lookup_hash: &SubjectLookupHash,
+) -> Result<Vec<Uuid>, RevocationError>
+where
+    K: EcdsaKeySend + Sync + 'static,
+    R: RevokeAll + Clone + Sync + 'static,
+{
+    let revoked_batch_ids = try_join_all(
+        status_list_services
+            .iter()
+            .map(|service| service.revoke_attestation_batches_for_subject(subject_lookup_hash)),
+    )
+    .await
+    .map_err(|e| RevocationError::InternalError(Box::new(e)))?
+    .into_iter()
+    .flatten()
+    .collect();
+
+    Ok(revoked_batch_ids)
+}
+
+async fn update_batches<K, R>(
+    state: &RevocationRouterState<K, R>,
+    batch_ids: Vec<Uuid>,
+    status: BatchStatus,
+) -> Result<(), RevocationError>
 where
     K: EcdsaKeySend + Sync + 'static,
     R: RevokeAll + Clone + Sync + 'static,
@@ -60,10 +145,11 @@ where
             .status_list_services
             .iter()
             .zip_eq(std::iter::repeat_n(batch_ids, service_count))
-            .map(|(service, batch_ids)| service.revoke_attestation_batches(batch_ids)),
+            .map(|(service, batch_ids)| service.update_attestation_batches(batch_ids, status)),
     )
     .await
     .map(|_| ())
+    .map_err(|e| RevocationError::InternalError(Box::new(e)))
 }
 
 #[cfg(feature = "test_api")]
@@ -106,7 +192,10 @@ where
     K: EcdsaKeySend + Sync + 'static,
     R: RevokeAll + Clone + Sync + 'static,
 {
-    let router = OpenApiRouter::with_openapi(ApiDoc::openapi()).routes(routes!(revoke_batch));
+    let router = OpenApiRouter::with_openapi(ApiDoc::openapi())
+        .routes(routes!(revoke_batch))
+        .routes(routes!(suspend_batch))
+        .routes(routes!(reinstate_batch));
 
     #[cfg(feature = "test_api")]
     let router = router.routes(routes!(get_batch)).routes(routes!(list_batch));
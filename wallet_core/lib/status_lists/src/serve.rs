@@ -21,13 +21,14 @@ use etag::EntityTag;
 use http::Method;
 use http_utils::mediatype::MediaType;
 use http_utils::mediatype::find_content_type_from_accept;
-use itertools::Itertools;
 use mediatype::Name;
+use token_status_list::status_list_token::StatusListTokenFormat;
 use tower_http::compression::CompressionLayer;
 use tower_http::cors::Any;
 use tower_http::cors::CorsLayer;
 
 use crate::config::StatusListConfig;
+use crate::publish::AGGREGATION_NAME;
 use crate::publish::PublishDir;
 
 const STATUSLIST_JWT_MEDIA_TYPE: MediaType = MediaType::new(
@@ -36,11 +37,18 @@ const STATUSLIST_JWT_MEDIA_TYPE: MediaType = MediaType::new(
     Some(Name::new_unchecked("jwt")),
 );
 
+const STATUSLIST_CWT_MEDIA_TYPE: MediaType = MediaType::new(
+    mediatype::names::APPLICATION,
+    Name::new_unchecked("statuslist"),
+    Some(Name::new_unchecked("cwt")),
+);
+
+const AGGREGATION_MEDIA_TYPE: &str = "application/json";
+
 #[derive(Debug, Clone)]
 struct RouterState {
     publish_dir: Arc<PublishDir>,
     cache_control: HeaderValue,
-    content_type: HeaderValue,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -109,25 +117,22 @@ fn check_serve_directories<'a>(
 pub fn create_serve_router<'a>(
     route_sources: impl IntoIterator<Item = StatusListRouteSource<'a>>,
 ) -> Result<Router, RouterError> {
-    // Convert to HeaderValue, unwrap is safe since string is ASCII.
-    let content_type = HeaderValue::from_str(&STATUSLIST_JWT_MEDIA_TYPE.to_string()).unwrap();
-
     let route_sources = check_serve_directories(route_sources)?;
-    let route_count = route_sources.len();
 
     let router = route_sources
         .into_iter()
-        .zip_eq(std::iter::repeat_n(content_type, route_count))
-        .fold(Router::new(), |router, ((path, route_source), content_type)| {
+        .fold(Router::new(), |router, (path, route_source)| {
             let state = RouterState {
                 publish_dir: Arc::new(route_source.publish_dir),
                 cache_control: cache_control_header_for_ttl(route_source.ttl),
-                content_type,
             };
 
             router.nest(
                 path,
-                Router::new().route("/{id}", get(serve_status_list)).with_state(state),
+                Router::new()
+                    .route(&format!("/{AGGREGATION_NAME}"), get(serve_aggregation))
+                    .route("/{id}", get(serve_status_list))
+                    .with_state(state),
             )
         })
         .layer(middleware::from_fn(add_vary_header))
@@ -167,10 +172,30 @@ async fn serve_status_list(
     Path(id): Path<String>,
     State(state): State<RouterState>,
 ) -> Result<Response, StatusCode> {
-    check_accept(headers.get(header::ACCEPT))?;
+    let format = check_accept(headers.get(header::ACCEPT))?;
+
+    let path = match format {
+        StatusListTokenFormat::Jwt => state.publish_dir.jwt_path(id.as_str()),
+        StatusListTokenFormat::Cwt => state.publish_dir.cwt_path(id.as_str()),
+    };
+
+    serve_file(&headers, &path, format.media_type(), state.cache_control).await
+}
+
+/// Serve the Status List Aggregation, which lists the URIs of all published status lists.
+async fn serve_aggregation(headers: HeaderMap, State(state): State<RouterState>) -> Result<Response, StatusCode> {
+    let path = state.publish_dir.aggregation_path();
 
-    let path = state.publish_dir.jwt_path(id.as_str());
-    let bytes = tokio::fs::read(&path).await.map_err(|err| map_io_error(&path, &err))?;
+    serve_file(&headers, &path, AGGREGATION_MEDIA_TYPE, state.cache_control).await
+}
+
+async fn serve_file(
+    headers: &HeaderMap,
+    path: &std::path::Path,
+    content_type: &'static str,
+    cache_control: HeaderValue,
+) -> Result<Response, StatusCode> {
+    let bytes = tokio::fs::read(path).await.map_err(|err| map_io_error(path, &err))?;
 
     let etag = EntityTag::from_data(&bytes);
     if let Some(request_etag) = headers.get(header::IF_NONE_MATCH)
@@ -181,8 +206,8 @@ async fn serve_status_list(
 
     Ok((
         [
-            (header::CONTENT_TYPE, state.content_type),
-            (header::CACHE_CONTROL, state.cache_control),
+            (header::CONTENT_TYPE, HeaderValue::from_static(content_type)),
+            (header::CACHE_CONTROL, cache_control),
             // Unwrap is safe since etag is ASCII
             (header::ETAG, HeaderValue::from_str(&etag.to_string()).unwrap()),
         ],
@@ -198,18 +223,26 @@ fn ascii_header<'a>(header: &'a HeaderValue, name: &str) -> Result<&'a str, Stat
     })
 }
 
-/// Check accept header for valid content types
+/// Check accept header for valid content types and return the format to serve
 ///
 /// The spec says that a verifier SHOULD send a request with an Accept header
 /// unless the Content-Type is known in the ecosystem or the verifier supports
-/// both. For the moment only the JWT format is supported in this code base.
-fn check_accept(header: Option<&HeaderValue>) -> Result<(), StatusCode> {
+/// both. Both the JWT and CWT format are supported, defaulting to JWT.
+fn check_accept(header: Option<&HeaderValue>) -> Result<StatusListTokenFormat, StatusCode> {
     match find_content_type_from_accept(
         header,
-        |media_type| (media_type == STATUSLIST_JWT_MEDIA_TYPE).then_some(()),
-        (),
+        |media_type| {
+            if media_type == STATUSLIST_JWT_MEDIA_TYPE {
+                Some(StatusListTokenFormat::Jwt)
+            } else if media_type == STATUSLIST_CWT_MEDIA_TYPE {
+                Some(StatusListTokenFormat::Cwt)
+            } else {
+                None
+            }
+        },
+        StatusListTokenFormat::default(),
     ) {
-        Ok(Some(_)) => Ok(()),
+        Ok(Some(format)) => Ok(format),
         Ok(None) => Err(StatusCode::NOT_ACCEPTABLE),
         Err(err) => {
             tracing::info!("invalid accept header: {err}");
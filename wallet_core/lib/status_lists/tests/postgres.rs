@@ -41,6 +41,7 @@ use sea_orm::sea_query::Expr;
 use status_lists::config::StatusListConfig;
 use status_lists::entity::attestation_batch;
 use status_lists::entity::attestation_batch_list_indices;
+use status_lists::entity::attestation_batch_status_history;
 use status_lists::entity::attestation_group;
 use status_lists::entity::status_list;
 use status_lists::entity::status_list_item;
@@ -51,6 +52,8 @@ use tempfile::TempDir;
 use token_status_list::status_list::Bits;
 use token_status_list::status_list::StatusList;
 use token_status_list::status_list::StatusType;
+use token_status_list::status_list_aggregation::StatusListAggregation;
+use token_status_list::status_list_cwt::StatusListCwt;
 use token_status_list::status_list_service::StatusListService;
 use token_status_list::status_list_token::StatusListToken;
 use token_status_list::status_list_token::TOKEN_STATUS_LIST_JWT_TYP;
@@ -198,6 +201,20 @@ async fn assert_published_list(
     list: &status_list::Model,
     revoked: impl IntoIterator<Item = usize>,
 ) {
+    assert_published_list_statuses(
+        config,
+        list,
+        revoked.into_iter().map(|index| (index, StatusType::Invalid)),
+    )
+    .await
+}
+
+async fn assert_published_list_statuses(
+    config: &StatusListConfig<SigningKey>,
+    list: &status_list::Model,
+    statuses: impl IntoIterator<Item = (usize, StatusType)>,
+) {
+    let statuses = statuses.into_iter().collect_vec();
     let path = config.publish_dir.jwt_path(&list.external_id);
     let status_list_token = tokio::fs::read_to_string(path)
         .await
@@ -212,9 +229,19 @@ async fn assert_published_list(
         .unwrap();
     assert_eq!(header.inner().typ, TOKEN_STATUS_LIST_JWT_TYP);
 
+    // Two bits should only be used when an attestation is suspended
     let bits = *claims.status_list.bits();
-    assert_eq!(bits, Bits::One);
+    let expected_bits = if statuses.iter().any(|(_, status)| *status == StatusType::Suspended) {
+        Bits::Two
+    } else {
+        Bits::One
+    };
+    assert_eq!(bits, expected_bits);
     assert_eq!(claims.ttl, config.ttl);
+    assert_eq!(
+        claims.status_list.aggregation_uri(),
+        Some(&config.base_url.join_base_url(&config.context_path).join("aggregation"))
+    );
 
     // Expiry should be less than configured in since time has increased
     let expiry_from_now = claims.exp.expect("expiry should be set") - Utc::now();
@@ -228,10 +255,34 @@ async fn assert_published_list(
 
     let published = claims.status_list.unpack();
     let mut expected = StatusList::new_aligned(list.size as usize, bits);
-    for index in revoked {
-        expected.insert(index, StatusType::Invalid);
+    for (index, status) in statuses {
+        expected.insert(index, status);
     }
     assert_eq!(published, expected);
+
+    // The same status list should be published as CWT
+    let cwt_path = config.publish_dir.cwt_path(&list.external_id);
+    let status_list_cwt = StatusListCwt::from_slice(&tokio::fs::read(cwt_path).await.unwrap()).unwrap();
+    let cwt_claims = status_list_cwt.dangerous_parse_unverified().unwrap();
+    assert_eq!(cwt_claims.sub, claims.sub);
+    assert_eq!(cwt_claims.exp, claims.exp);
+    assert_eq!(cwt_claims.status_list.unpack(), published);
+}
+
+async fn assert_published_aggregation(config: &StatusListConfig<SigningKey>, lists: &[status_list::Model]) {
+    let aggregation: StatusListAggregation =
+        serde_json::from_slice(&tokio::fs::read(config.publish_dir.aggregation_path()).await.unwrap()).unwrap();
+
+    let expected = lists
+        .iter()
+        .map(|list| {
+            config
+                .base_url
+                .join_base_url(&config.context_path)
+                .join(&list.external_id)
+        })
+        .collect_vec();
+    assert_eq!(aggregation.status_lists, expected);
 }
 
 async fn modified_timestamp(path: &Path) -> Option<SystemTime> {
@@ -345,6 +396,7 @@ async fn test_service_initializes_and_republishes_status_lists() {
     let db_lists = fetch_status_list(&connection, attestation_group.id).await;
     assert_eq!(db_lists.len(), 1);
     assert_empty_published_list(&config, &db_lists[0]).await;
+    assert_published_aggregation(&config, &db_lists).await;
 
     // Check republish
     service.republish_all(true).await.unwrap();
@@ -371,13 +423,16 @@ async fn test_service_initializes_schedule_housekeeping_empty() {
         list_size: 6.try_into().unwrap(),
         ..config
     };
-    let _ = recreate_status_list_service(&connection, &attestation_group, config, revoke_all).await;
+    let _ = recreate_status_list_service(&connection, &attestation_group, config.clone(), revoke_all).await;
 
     // Old list should be empty and new list should be created
     let db_lists = fetch_status_list(&connection, type_id).await;
     assert_eq!(db_lists.len(), 2);
     assert_status_list_items(&connection, &db_lists[0], 0, 5, 5, true).await;
     assert_status_list_items(&connection, &db_lists[1], 6, 6, 11, false).await;
+
+    // Aggregation should contain both lists
+    assert_published_aggregation(&config, &db_lists).await;
 }
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -450,7 +505,7 @@ async fn test_service_create_status_claims() {
     let batch_id = Uuid::new_v4();
     let expiration_date: DateTimeSeconds = Utc::now().into();
     let (claims, tasks) = service
-        .obtain_status_claims_and_scheduled_tasks(batch_id, Some(expiration_date), 2.try_into().unwrap())
+        .obtain_status_claims_and_scheduled_tasks(batch_id, None, Some(expiration_date), 2.try_into().unwrap())
         .await
         .unwrap();
     assert_eq!(tasks.len(), 0);
@@ -514,7 +569,7 @@ async fn test_service_create_status_claims_creates_in_flight_if_needed() {
     // Obtain claims for attestation batch
     let batch_id = Uuid::new_v4();
     let (claims, tasks) = service
-        .obtain_status_claims_and_scheduled_tasks(batch_id, None, 2.try_into().unwrap())
+        .obtain_status_claims_and_scheduled_tasks(batch_id, None, None, 2.try_into().unwrap())
         .await
         .unwrap();
     assert_eq!(tasks.len(), 2);
@@ -572,7 +627,7 @@ async fn test_service_create_status_claims_concurrently() {
     let concurrent = 7;
     let num_copies = 3.try_into().unwrap();
     let claims_per_batch =
-        try_join_all((0..concurrent).map(|_| service.obtain_status_claims(Uuid::new_v4(), None, num_copies)))
+        try_join_all((0..concurrent).map(|_| service.obtain_status_claims(Uuid::new_v4(), None, None, num_copies)))
             .await
             .unwrap();
 
@@ -626,7 +681,7 @@ async fn test_service_revoke_attestation_batches_multiple_lists() {
     // Create status claims for attestation
     let batch_id = Uuid::new_v4();
     let claims = service
-        .obtain_status_claims(batch_id, None, 3.try_into().unwrap())
+        .obtain_status_claims(batch_id, None, None, 3.try_into().unwrap())
         .await
         .unwrap();
 
@@ -686,7 +741,7 @@ async fn test_service_revoke_attestation_batches_concurrently() {
         batch_ids
             .iter()
             .copied()
-            .map(|batch_id| service.obtain_status_claims(batch_id, None, NonZeroUsize::MIN)),
+            .map(|batch_id| service.obtain_status_claims(batch_id, None, None, NonZeroUsize::MIN)),
     )
     .await
     .unwrap();
@@ -716,6 +771,97 @@ async fn test_service_revoke_attestation_batches_concurrently() {
     .await;
 }
 
+async fn fetch_attestation_batch_status_history(connection: &DatabaseConnection, batch_id: Uuid) -> Vec<String> {
+    attestation_batch_status_history::Entity::find()
+        .inner_join(attestation_batch::Entity)
+        .filter(attestation_batch::Column::BatchId.eq(batch_id))
+        .order_by_asc(attestation_batch_status_history::Column::Id)
+        .all(connection)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|history| history.status)
+        .collect()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_service_suspend_and_reinstate_attestation_batches() {
+    let ca = Ca::generate_issuer_mock_ca().unwrap();
+    let db_setup = DbSetup::create().await;
+    let connection = connection_from_url(db_setup.status_lists_url()).await;
+    let publish_dir = tempfile::tempdir().unwrap();
+    let (attestation_group, config, _, service) =
+        create_status_list_service(&ca, &connection, 9, 1, None, &publish_dir)
+            .await
+            .unwrap();
+
+    let type_id = attestation_group_id(&connection, &attestation_group).await;
+
+    // Obtain claims for two attestation batches
+    let batch_ids = [Uuid::new_v4(), Uuid::new_v4()];
+    let claims_per_batch = try_join_all(
+        batch_ids
+            .iter()
+            .copied()
+            .map(|batch_id| service.obtain_status_claims(batch_id, None, None, NonZeroUsize::MIN)),
+    )
+    .await
+    .unwrap();
+    let indices = claims_per_batch
+        .into_iter()
+        .map(|claims| match claims.into_first() {
+            StatusClaim::StatusList(list) => list.idx as usize,
+        })
+        .collect_vec();
+
+    let db_lists = fetch_status_list(&connection, type_id).await;
+    assert_eq!(db_lists.len(), 1);
+
+    // Suspend both attestation batches, which should publish a 2-bit status list
+    service.suspend_attestation_batches(batch_ids.to_vec()).await.unwrap();
+    assert_published_list_statuses(
+        &config,
+        &db_lists[0],
+        indices.iter().map(|index| (*index, StatusType::Suspended)),
+    )
+    .await;
+
+    // Revoke the first batch, which should take precedence over the suspension
+    service.revoke_attestation_batches(vec![batch_ids[0]]).await.unwrap();
+    assert_published_list_statuses(
+        &config,
+        &db_lists[0],
+        [(indices[0], StatusType::Invalid), (indices[1], StatusType::Suspended)],
+    )
+    .await;
+
+    // Reinstate both batches, which should only affect the second batch, as revocation is final
+    service.reinstate_attestation_batches(batch_ids.to_vec()).await.unwrap();
+    assert_published_list(&config, &db_lists[0], [indices[0]]).await;
+
+    // Suspending the revoked batch again should have no effect
+    service.suspend_attestation_batches(vec![batch_ids[0]]).await.unwrap();
+    assert_published_list(&config, &db_lists[0], [indices[0]]).await;
+
+    // Check the status of the attestation batches and their history in the database
+    let db_attestations = fetch_attestation_batches(&connection, &db_lists).await;
+    let (revoked, reinstated): (Vec<_>, Vec<_>) = db_attestations
+        .into_iter()
+        .map(|(batch, _)| batch)
+        .partition(|batch| batch.batch_id == batch_ids[0]);
+    assert!(revoked[0].is_revoked && !revoked[0].is_suspended);
+    assert!(!reinstated[0].is_revoked && !reinstated[0].is_suspended);
+
+    assert_eq!(
+        fetch_attestation_batch_status_history(&connection, batch_ids[0]).await,
+        vec!["suspended", "revoked"]
+    );
+    assert_eq!(
+        fetch_attestation_batch_status_history(&connection, batch_ids[1]).await,
+        vec!["suspended", "valid"]
+    );
+}
+
 async fn republish_list_with_expiry(path: &Path, key_pair: &KeyPair<impl EcdsaKey>, expiry: Option<DateTime<Utc>>) {
     let token: StatusListToken = tokio::fs::read_to_string(path).await.unwrap().parse().unwrap();
     let (_, mut claims) = token.as_ref().dangerous_parse_unverified().unwrap();
@@ -773,7 +919,7 @@ async fn test_service_republish_status_list_with_revoke_all_set() {
     // Create status claims for attestation
     let batch_id = Uuid::new_v4();
     service
-        .obtain_status_claims(batch_id, None, NonZeroUsize::MIN)
+        .obtain_status_claims(batch_id, None, None, NonZeroUsize::MIN)
         .await
         .unwrap();
 
@@ -803,7 +949,7 @@ async fn test_service_new_status_list_with_revoke_all_set() {
 
     // Create status claims for attestation to create new list
     let (_, tasks) = service
-        .obtain_status_claims_and_scheduled_tasks(Uuid::new_v4(), None, 2.try_into().unwrap())
+        .obtain_status_claims_and_scheduled_tasks(Uuid::new_v4(), None, None, 2.try_into().unwrap())
         .await
         .unwrap();
     assert_eq!(tasks.len(), 2);
@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::num::NonZeroUsize;
 use std::time::Duration;
 
@@ -19,6 +20,8 @@ use status_lists::postgres::NoRevokeAll;
 use status_lists::postgres::PostgresStatusListService;
 use status_lists::publish::PublishDir;
 use status_lists::revoke::create_revocation_router;
+use status_lists::revoke::revoke_subject_batches;
+use token_status_list::status_list_service::SubjectLookupHash;
 use tokio::net::TcpListener;
 use url::Url;
 use utils::num::NonZeroU31;
@@ -32,7 +35,7 @@ async fn setup_revocation_server(service: PostgresStatusListService<SigningKey,
     let port = listener.local_addr()?.port();
     tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
 
-    Ok(format!("http://127.0.0.1:{}/revoke/", port).parse()?)
+    Ok(format!("http://127.0.0.1:{}/", port).parse()?)
 }
 
 pub async fn fetch_attestation_batch(
@@ -72,9 +75,15 @@ async fn setup_revocation_test(
         .unwrap();
     try_join_all(service.initialize_lists().await.unwrap()).await.unwrap();
 
-    let revoke_endpoint = setup_revocation_server(service.clone()).await.unwrap();
+    let revocation_url = setup_revocation_server(service.clone()).await.unwrap();
 
-    (service, revoke_endpoint)
+    (service, revocation_url)
+}
+
+async fn post_batch_ids(url: Url, batch_ids: &[Uuid]) {
+    let response = reqwest::Client::new().post(url).json(&batch_ids).send().await.unwrap();
+
+    assert_eq!(response.status(), 200);
 }
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -85,15 +94,16 @@ async fn test_revoke_batch(#[case] batch: &[Uuid]) {
     let db_setup = DbSetup::create().await;
     let publish_dir = tempfile::tempdir().unwrap();
 
-    let (service, revocation_endpoint) = setup_revocation_test(
+    let (service, revocation_url) = setup_revocation_test(
         &db_setup,
         PublishDir::try_new(publish_dir.path().to_path_buf()).unwrap(),
     )
     .await;
+    let revocation_endpoint = revocation_url.join("revoke/").unwrap();
 
     join_all(batch.iter().map(async |id| {
         let tasks = service
-            .obtain_status_claims_and_scheduled_tasks(*id, None, NonZeroUsize::MIN)
+            .obtain_status_claims_and_scheduled_tasks(*id, None, None, NonZeroUsize::MIN)
             .await
             .unwrap()
             .1;
@@ -139,11 +149,12 @@ async fn test_revoke_non_existing_batch_should_return_success() {
     let db_setup = DbSetup::create().await;
     let publish_dir = tempfile::tempdir().unwrap();
 
-    let (_, revocation_endpoint) = setup_revocation_test(
+    let (_, revocation_url) = setup_revocation_test(
         &db_setup,
         PublishDir::try_new(publish_dir.path().to_path_buf()).unwrap(),
     )
     .await;
+    let revocation_endpoint = revocation_url.join("revoke/").unwrap();
 
     let uuid = Uuid::new_v4();
 
@@ -156,3 +167,104 @@ async fn test_revoke_non_existing_batch_should_return_success() {
 
     assert_eq!(response.status(), 200);
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_suspend_and_reinstate_batch() {
+    let db_setup = DbSetup::create().await;
+    let publish_dir = tempfile::tempdir().unwrap();
+
+    let (service, revocation_url) = setup_revocation_test(
+        &db_setup,
+        PublishDir::try_new(publish_dir.path().to_path_buf()).unwrap(),
+    )
+    .await;
+
+    let batch_id = Uuid::new_v4();
+    let tasks = service
+        .obtain_status_claims_and_scheduled_tasks(batch_id, None, None, NonZeroUsize::MIN)
+        .await
+        .unwrap()
+        .1;
+    join_all(tasks).await;
+
+    let connection = connection_from_url(db_setup.status_lists_url()).await;
+
+    // suspend the batch
+    post_batch_ids(revocation_url.join("suspend/").unwrap(), &[batch_id]).await;
+
+    let batch = fetch_attestation_batch(&connection, batch_id).await.unwrap();
+    assert!(batch.is_suspended);
+    assert!(!batch.is_revoked);
+
+    // reinstate the batch
+    post_batch_ids(revocation_url.join("reinstate/").unwrap(), &[batch_id]).await;
+
+    let batch = fetch_attestation_batch(&connection, batch_id).await.unwrap();
+    assert!(!batch.is_suspended);
+    assert!(!batch.is_revoked);
+
+    // suspend and revoke the batch, after which it cannot be reinstated anymore
+    post_batch_ids(revocation_url.join("suspend/").unwrap(), &[batch_id]).await;
+    post_batch_ids(revocation_url.join("revoke/").unwrap(), &[batch_id]).await;
+    post_batch_ids(revocation_url.join("reinstate/").unwrap(), &[batch_id]).await;
+
+    let batch = fetch_attestation_batch(&connection, batch_id).await.unwrap();
+    assert!(!batch.is_suspended);
+    assert!(batch.is_revoked);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_revoke_subject() {
+    let db_setup = DbSetup::create().await;
+    let publish_dir = tempfile::tempdir().unwrap();
+
+    let (service, _) = setup_revocation_test(
+        &db_setup,
+        PublishDir::try_new(publish_dir.path().to_path_buf()).unwrap(),
+    )
+    .await;
+
+    let subject = SubjectLookupHash::from(random_string(32));
+    let other_subject = SubjectLookupHash::from(random_string(32));
+    let subject_batch_ids = [Uuid::new_v4(), Uuid::new_v4()];
+    let other_batch_id = Uuid::new_v4();
+    let anonymous_batch_id = Uuid::new_v4();
+
+    for (batch_id, subject_lookup_hash) in subject_batch_ids
+        .iter()
+        .map(|batch_id| (*batch_id, Some(subject.clone())))
+        .chain([(other_batch_id, Some(other_subject)), (anonymous_batch_id, None)])
+    {
+        let tasks = service
+            .obtain_status_claims_and_scheduled_tasks(batch_id, subject_lookup_hash, None, NonZeroUsize::MIN)
+            .await
+            .unwrap()
+            .1;
+        join_all(tasks).await;
+    }
+
+    let revoked_batch_ids = revoke_subject_batches(std::slice::from_ref(&service), &subject)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        revoked_batch_ids.into_iter().collect::<HashSet<_>>(),
+        HashSet::from(subject_batch_ids)
+    );
+
+    // assert that only the batches of the subject are revoked
+    let connection = connection_from_url(db_setup.status_lists_url()).await;
+    for batch_id in subject_batch_ids {
+        assert!(fetch_attestation_batch(&connection, batch_id).await.unwrap().is_revoked);
+    }
+    for batch_id in [other_batch_id, anonymous_batch_id] {
+        assert!(!fetch_attestation_batch(&connection, batch_id).await.unwrap().is_revoked);
+    }
+
+    // revoking again should not return the already revoked batches
+    let revoked_batch_ids = revoke_subject_batches(std::slice::from_ref(&service), &subject)
+        .await
+        .unwrap();
+
+    assert!(revoked_batch_ids.is_empty());
+}
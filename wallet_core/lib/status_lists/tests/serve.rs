@@ -2,6 +2,7 @@ use std::time::Duration;
 
 use reqwest::header::ACCEPT;
 use reqwest::header::CACHE_CONTROL;
+use reqwest::header::CONTENT_TYPE;
 use reqwest::header::ETAG;
 use reqwest::header::IF_NONE_MATCH;
 use reqwest::header::VARY;
@@ -62,17 +63,30 @@ async fn test_router_not_found() {
 
 #[tokio::test]
 #[rstest]
-#[case("application/statuslist+jwt", 200)]
-#[case("application/statuslist+cwt", 406)]
-#[case("application/statuslist+cwt, application/statuslist+jwt", 200)]
-#[case("text/plain", 406)]
-#[case("*/*", 200)]
-async fn test_router_media_type(#[case] accept: &str, #[case] status_code: u16) {
+#[case("application/statuslist+jwt", 200, Some("application/statuslist+jwt"))]
+#[case("application/statuslist+cwt", 200, Some("application/statuslist+cwt"))]
+#[case(
+    "application/statuslist+cwt, application/statuslist+jwt",
+    200,
+    Some("application/statuslist+cwt")
+)]
+#[case(
+    "application/statuslist+jwt, application/statuslist+cwt",
+    200,
+    Some("application/statuslist+jwt")
+)]
+#[case("text/plain", 406, None)]
+#[case("*/*", 200, Some("application/statuslist+jwt"))]
+async fn test_router_media_type(#[case] accept: &str, #[case] status_code: u16, #[case] content_type: Option<&str>) {
     let publish_dir = TempDir::new().unwrap();
     let url = setup_server(&publish_dir, None).await.unwrap();
 
-    let path = publish_dir.path().join("test.jwt");
-    tokio::fs::write(&path, "test123").await.unwrap();
+    tokio::fs::write(publish_dir.path().join("test.jwt"), "test123")
+        .await
+        .unwrap();
+    tokio::fs::write(publish_dir.path().join("test.cwt"), [0xd2, 0x84])
+        .await
+        .unwrap();
 
     let client = reqwest::Client::new();
     let response = client
@@ -84,6 +98,48 @@ async fn test_router_media_type(#[case] accept: &str, #[case] status_code: u16)
 
     assert_eq!(response.headers().get(VARY).unwrap(), "accept");
     assert_eq!(response.status(), status_code);
+    assert_eq!(
+        response
+            .headers()
+            .get(CONTENT_TYPE)
+            .map(|content_type| content_type.to_str().unwrap()),
+        content_type
+    );
+}
+
+#[tokio::test]
+async fn test_router_cwt_not_found() {
+    let publish_dir = TempDir::new().unwrap();
+    let url = setup_server(&publish_dir, None).await.unwrap();
+
+    let path = publish_dir.path().join("test.jwt");
+    tokio::fs::write(&path, "test123").await.unwrap();
+
+    let client = reqwest::Client::new();
+    let response = client
+        .request(reqwest::Method::GET, url.join("test").unwrap())
+        .header(ACCEPT, "application/statuslist+cwt")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn test_router_serve_aggregation() {
+    let publish_dir = TempDir::new().unwrap();
+    let url = setup_server(&publish_dir, None).await.unwrap();
+
+    let aggregation = r#"{"status_lists":["https://example.com/tsl/test"]}"#;
+    tokio::fs::write(publish_dir.path().join("aggregation.json"), aggregation)
+        .await
+        .unwrap();
+
+    let response = reqwest::get(url.join("aggregation").unwrap()).await.unwrap();
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers().get(CONTENT_TYPE).unwrap(), "application/json");
+    assert_eq!(response.bytes().await.unwrap(), aggregation.as_bytes());
 }
 
 #[tokio::test]
@@ -8,6 +8,8 @@ use quick_xml::XmlVersion;
 use quick_xml::events::BytesStart;
 use quick_xml::events::BytesText;
 use quick_xml::events::Event;
+use serde::Deserialize;
+use serde::Serialize;
 
 use crate::allow::LowerCaseString;
 use crate::allow::XmlNormalizedString;
@@ -41,9 +43,20 @@ pub enum Error {
 /// - CDATA sections are converted to escaped text nodes.
 /// - `<use>`, `<animate>`, `<set>`, `<script>`, `<style>`, and `<foreignObject>` are blocked.
 /// - The `style` and `class` attributes are stripped; the renderer does not support CSS.
-#[derive(Clone, Debug, AsRef, Into, PartialEq, Eq, Hash)]
+///
+/// Note that deserializing sanitizes the input again, so that this type can never contain an unsanitized SVG.
+#[derive(Clone, Debug, AsRef, Into, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
 pub struct SanitizedSvg(String);
 
+impl TryFrom<String> for SanitizedSvg {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_new(&value)
+    }
+}
+
 impl SanitizedSvg {
     pub fn try_new(input: &str) -> Result<Self, Error> {
         if input.len() > MAX_SIZE {
@@ -585,4 +598,23 @@ mod tests {
     fn valid_svg_passes_through(#[case] input: &str) {
         assert_eq!(sanitize_panicking(input), input);
     }
+
+    // ── Serde ──────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn serde_roundtrip() {
+        let svg = SanitizedSvg::try_new(r#"<svg><circle r="5"/></svg>"#).unwrap();
+
+        let json = serde_json::to_string(&svg).unwrap();
+        assert_eq!(json, r#""<svg><circle r=\"5\"/></svg>""#);
+        assert_eq!(serde_json::from_str::<SanitizedSvg>(&json).unwrap(), svg);
+    }
+
+    #[test]
+    fn deserialize_sanitizes() {
+        let svg: SanitizedSvg =
+            serde_json::from_str(r#""<svg><script>alert(1)</script><circle r=\"5\"/></svg>""#).unwrap();
+
+        assert_eq!(svg.0, r#"<svg><circle r="5"/></svg>"#);
+    }
 }
@@ -1,4 +1,6 @@
 pub mod status_list;
+pub mod status_list_aggregation;
+pub mod status_list_cwt;
 pub mod status_list_service;
 pub mod status_list_token;
 
@@ -6,6 +6,7 @@ use serde::Deserialize;
 use serde::Deserializer;
 use serde::Serialize;
 use serde::Serializer;
+use url::Url;
 
 #[derive(Debug, Default)]
 struct IdentityHasher(u64);
@@ -48,6 +49,55 @@ pub struct PackedStatusList {
 
     #[serde(with = "zlib_base64")]
     lst: Vec<u8>,
+
+    /// URI to retrieve the Status List Aggregation, which lists all Status Lists of the same issuer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    aggregation_uri: Option<Url>,
+}
+
+/// The CBOR representation of a [`PackedStatusList`], in which `lst` contains the compressed bytes as a byte string
+/// instead of a base64url encoded string.
+///
+/// <https://www.ietf.org/archive/id/draft-ietf-oauth-status-list-12.html#name-status-list-in-cbor-format>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CborPackedStatusList {
+    bits: Bits,
+
+    #[serde(with = "zlib_bytes")]
+    lst: Vec<u8>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    aggregation_uri: Option<Url>,
+}
+
+impl From<PackedStatusList> for CborPackedStatusList {
+    fn from(value: PackedStatusList) -> Self {
+        let PackedStatusList {
+            bits,
+            lst,
+            aggregation_uri,
+        } = value;
+        Self {
+            bits,
+            lst,
+            aggregation_uri,
+        }
+    }
+}
+
+impl From<CborPackedStatusList> for PackedStatusList {
+    fn from(value: CborPackedStatusList) -> Self {
+        let CborPackedStatusList {
+            bits,
+            lst,
+            aggregation_uri,
+        } = value;
+        Self {
+            bits,
+            lst,
+            aggregation_uri,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -101,6 +151,41 @@ mod zlib_base64 {
     }
 }
 
+mod zlib_bytes {
+    use std::io::prelude::*;
+
+    use flate2::Compression;
+    use flate2::read::ZlibDecoder;
+    use flate2::write::ZlibEncoder;
+    use serde::Deserialize;
+    use serde_bytes::ByteBuf;
+
+    pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        // Implementations are RECOMMENDED to use the highest compression level available.
+        let mut e = ZlibEncoder::new(Vec::with_capacity(bytes.len() / 1024), Compression::best());
+        e.write_all(bytes).map_err(serde::ser::Error::custom)?;
+        let compressed = e.finish().map_err(serde::ser::Error::custom)?;
+
+        serializer.serialize_bytes(&compressed)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let compressed = ByteBuf::deserialize(deserializer)?;
+
+        let mut decompressed = Vec::with_capacity(compressed.len());
+        let mut d = ZlibDecoder::new(compressed.as_slice());
+        d.read_to_end(&mut decompressed).map_err(serde::de::Error::custom)?;
+
+        Ok(decompressed)
+    }
+}
+
 impl StatusList {
     pub fn new(len: usize) -> Self {
         StatusList {
@@ -137,7 +222,11 @@ impl StatusList {
             acc
         });
 
-        PackedStatusList { bits, lst }
+        PackedStatusList {
+            bits,
+            lst,
+            aggregation_uri: None,
+        }
     }
 }
 
@@ -147,6 +236,7 @@ impl PackedStatusList {
         PackedStatusList {
             bits,
             lst: vec![0; bits.packed_len(len)],
+            aggregation_uri: None,
         }
     }
 
@@ -155,6 +245,7 @@ impl PackedStatusList {
         PackedStatusList {
             bits,
             lst: vec![0xff; bits.packed_len(len)],
+            aggregation_uri: None,
         }
     }
 
@@ -162,6 +253,15 @@ impl PackedStatusList {
         &self.bits
     }
 
+    pub fn aggregation_uri(&self) -> Option<&Url> {
+        self.aggregation_uri.as_ref()
+    }
+
+    pub(crate) fn with_aggregation_uri(mut self, aggregation_uri: Option<Url>) -> Self {
+        self.aggregation_uri = aggregation_uri;
+        self
+    }
+
     pub fn single_unpack(&self, index: usize) -> StatusType {
         let byte = self.lst[self.bits.packed_index(index)];
         let status = (byte >> self.bits.shift_for_index(index)) & self.bits.mask();
@@ -338,6 +438,7 @@ impl StatusType {
 pub mod test {
     use std::sync::LazyLock;
 
+    use hex_literal::hex;
     use regex::regex;
     use rstest::rstest;
     use serde_json::json;
@@ -393,6 +494,40 @@ pub mod test {
         assert_eq!(compressed, expected);
     }
 
+    #[test]
+    fn test_status_list_serialization_aggregation_uri() {
+        let aggregation_uri: Url = "https://example.com/statuslists/aggregation".parse().unwrap();
+        let packed = EXAMPLE_STATUS_LIST_ONE
+            .to_owned()
+            .pack()
+            .with_aggregation_uri(Some(aggregation_uri.clone()));
+
+        let value = serde_json::to_value(&packed).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "bits": 1,
+                "lst": "eNrbuRgAAhcBXQ",
+                "aggregation_uri": "https://example.com/statuslists/aggregation",
+            })
+        );
+
+        let deserialized: PackedStatusList = serde_json::from_value(value).unwrap();
+        assert_eq!(deserialized.aggregation_uri(), Some(&aggregation_uri));
+    }
+
+    #[rstest]
+    #[case(EXAMPLE_STATUS_LIST_ONE.to_owned(), hex!("a2646269747301636c73744a78dadbb918000217015d").to_vec())]
+    #[case(EXAMPLE_STATUS_LIST_TWO.to_owned(), hex!("a2646269747302636c73744b78da3be9f2130003df0207").to_vec())]
+    fn test_status_list_cbor_serialization(#[case] list: StatusList, #[case] expected: Vec<u8>) {
+        let mut serialized = Vec::new();
+        ciborium::into_writer(&CborPackedStatusList::from(list.clone().pack()), &mut serialized).unwrap();
+        assert_eq!(serialized, expected);
+
+        let deserialized: CborPackedStatusList = ciborium::from_reader(expected.as_slice()).unwrap();
+        assert_eq!(PackedStatusList::from(deserialized).unpack().sparse, list.sparse);
+    }
+
     #[rstest]
     #[case(ONE_BIT_STATUS_LIST.to_owned(), Bits::One)]
     #[case(TWO_BIT_STATUS_LIST.to_owned(), Bits::Two)]
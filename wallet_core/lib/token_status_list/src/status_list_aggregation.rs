@@ -0,0 +1,33 @@
+use serde::Deserialize;
+use serde::Serialize;
+use url::Url;
+
+/// A Status List Aggregation lists the URIs of all Status List Tokens of an issuer, so that a consumer can fetch them
+/// all at once, e.g. for caching or offline use.
+///
+/// <https://www.ietf.org/archive/id/draft-ietf-oauth-status-list-12.html#name-status-list-aggregation>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusListAggregation {
+    pub status_lists: Vec<Url>,
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_status_list_aggregation_serialization() {
+        let value = json!({
+            "status_lists": [
+                "https://example.com/statuslists/1",
+                "https://example.com/statuslists/2",
+            ]
+        });
+
+        let aggregation: StatusListAggregation = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(aggregation.status_lists.len(), 2);
+        assert_eq!(serde_json::to_value(&aggregation).unwrap(), value);
+    }
+}
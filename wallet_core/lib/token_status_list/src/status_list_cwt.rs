@@ -0,0 +1,315 @@
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use ciborium::value::Value;
+use cose::COSE_TYPE_HEADER_LABEL;
+use cose::CoseError;
+use cose::TypedCose;
+use cose::header_with_x5chain;
+use coset::CborSerializable;
+use coset::CoseSign1;
+use coset::HeaderBuilder;
+use coset::Label;
+use coset::TaggedCborSerializable;
+use coset::iana;
+use crypto::EcdsaKey;
+use crypto::server_keys::KeyPair;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+use serde::de;
+use serde::ser;
+use url::Url;
+use utils::vec_nonempty;
+
+use crate::status_list::CborPackedStatusList;
+use crate::status_list_token::StatusListClaims;
+
+pub static TOKEN_STATUS_LIST_CWT_TYP: &str = "application/statuslist+cwt";
+
+// CWT claim keys as registered in RFC 8392 and by the Token Status List specification.
+const CWT_CLAIM_SUB: i64 = 2;
+const CWT_CLAIM_EXP: i64 = 4;
+const CWT_CLAIM_IAT: i64 = 6;
+const CWT_CLAIM_STATUS_LIST: i64 = 65533;
+const CWT_CLAIM_TTL: i64 = 65534;
+
+#[derive(Debug, thiserror::Error)]
+pub enum StatusListCwtError {
+    #[error("could not decode or encode COSE_Sign1: {0}")]
+    CoseSerialization(#[source] coset::CoseError),
+
+    #[error("unexpected protected CWT type: {0:?}")]
+    UnexpectedType(Option<Value>),
+}
+
+/// A Status List Token in CWT format, which embeds a Status List in a COSE_Sign1 structure. The signing certificate is
+/// included in the unprotected `x5chain` header, like the issuer certificate of an mdoc.
+///
+/// <https://www.ietf.org/archive/id/draft-ietf-oauth-status-list-12.html#name-status-list-token-in-cwt-fo>
+#[derive(Debug, Clone)]
+pub struct StatusListCwt(TypedCose<CoseSign1, CwtStatusListClaims>);
+
+impl StatusListCwt {
+    pub(crate) async fn sign(
+        claims: StatusListClaims,
+        keypair: &KeyPair<impl EcdsaKey>,
+    ) -> Result<StatusListCwt, CoseError> {
+        let protected_header = HeaderBuilder::new()
+            .algorithm(iana::Algorithm::ES256)
+            .value(
+                COSE_TYPE_HEADER_LABEL,
+                Value::Text(TOKEN_STATUS_LIST_CWT_TYP.to_owned()),
+            )
+            .build();
+        let unprotected_header = header_with_x5chain(&vec_nonempty![keypair.certificate()]);
+
+        let cose = TypedCose::sign_with_protected_header(
+            &CwtStatusListClaims(claims),
+            protected_header,
+            unprotected_header,
+            keypair,
+            true,
+        )
+        .await?;
+
+        Ok(StatusListCwt(cose))
+    }
+
+    /// Parse a COSE_Sign1 object that is either untagged or tagged as COSE_Sign1.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, StatusListCwtError> {
+        let cose = CoseSign1::from_tagged_slice(bytes)
+            .or_else(|_| CoseSign1::from_slice(bytes))
+            .map_err(StatusListCwtError::CoseSerialization)?;
+
+        let cwt_type = cose
+            .protected
+            .header
+            .rest
+            .iter()
+            .find(|(label, _)| label == &Label::Int(COSE_TYPE_HEADER_LABEL))
+            .map(|(_, value)| value);
+        match cwt_type {
+            Some(Value::Text(cwt_type)) if cwt_type == TOKEN_STATUS_LIST_CWT_TYP => {}
+            cwt_type => return Err(StatusListCwtError::UnexpectedType(cwt_type.cloned())),
+        }
+
+        Ok(Self(cose.into()))
+    }
+
+    /// Serialize as a tagged COSE_Sign1 object.
+    pub fn to_vec(&self) -> Result<Vec<u8>, StatusListCwtError> {
+        self.0
+            .as_ref()
+            .clone()
+            .to_tagged_vec()
+            .map_err(StatusListCwtError::CoseSerialization)
+    }
+
+    /// Parse the claims without verifying the signature.
+    pub fn dangerous_parse_unverified(&self) -> Result<StatusListClaims, CoseError> {
+        let CwtStatusListClaims(claims) = self.0.dangerous_parse_unverified()?;
+        Ok(claims)
+    }
+}
+
+/// Wrapper around [`StatusListClaims`] that (de)serializes them as a CWT Claims Set, which uses integer keys.
+#[derive(Debug, Clone)]
+struct CwtStatusListClaims(StatusListClaims);
+
+impl Serialize for CwtStatusListClaims {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let claims = &self.0;
+        let status_list =
+            Value::serialized(&CborPackedStatusList::from(claims.status_list.clone())).map_err(ser::Error::custom)?;
+
+        let mut entries = vec![
+            (Value::from(CWT_CLAIM_SUB), Value::Text(claims.sub.to_string())),
+            (Value::from(CWT_CLAIM_IAT), Value::from(claims.iat.timestamp())),
+        ];
+        if let Some(exp) = claims.exp {
+            entries.push((Value::from(CWT_CLAIM_EXP), Value::from(exp.timestamp())));
+        }
+        if let Some(ttl) = claims.ttl {
+            entries.push((Value::from(CWT_CLAIM_TTL), Value::from(ttl.as_secs())));
+        }
+        entries.push((Value::from(CWT_CLAIM_STATUS_LIST), status_list));
+
+        Value::Map(entries).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CwtStatusListClaims {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let Value::Map(entries) = Value::deserialize(deserializer)? else {
+            return Err(de::Error::custom("CWT claims set is not a map"));
+        };
+
+        let mut sub = None;
+        let mut iat = None;
+        let mut exp = None;
+        let mut ttl = None;
+        let mut status_list = None;
+
+        // Claims with other keys, including text keys, are ignored.
+        for (key, value) in entries {
+            match key.as_integer().and_then(|key| i64::try_from(key).ok()) {
+                Some(CWT_CLAIM_SUB) => {
+                    let url = value
+                        .into_text()
+                        .ok()
+                        .and_then(|sub| sub.parse::<Url>().ok())
+                        .ok_or_else(|| de::Error::custom("invalid sub claim"))?;
+                    sub = Some(url);
+                }
+                Some(CWT_CLAIM_IAT) => iat = Some(timestamp_claim(&value, "iat")?),
+                Some(CWT_CLAIM_EXP) => exp = Some(timestamp_claim(&value, "exp")?),
+                Some(CWT_CLAIM_TTL) => {
+                    let seconds = value
+                        .as_integer()
+                        .and_then(|seconds| u64::try_from(seconds).ok())
+                        .ok_or_else(|| de::Error::custom("invalid ttl claim"))?;
+                    ttl = Some(Duration::from_secs(seconds));
+                }
+                Some(CWT_CLAIM_STATUS_LIST) => {
+                    let packed = value
+                        .deserialized::<CborPackedStatusList>()
+                        .map_err(de::Error::custom)?;
+                    status_list = Some(packed.into());
+                }
+                _ => {}
+            }
+        }
+
+        Ok(Self(StatusListClaims {
+            iat: iat.ok_or_else(|| de::Error::missing_field("iat"))?,
+            exp,
+            sub: sub.ok_or_else(|| de::Error::missing_field("sub"))?,
+            ttl,
+            status_list: status_list.ok_or_else(|| de::Error::missing_field("status_list"))?,
+        }))
+    }
+}
+
+fn timestamp_claim<E>(value: &Value, name: &str) -> Result<DateTime<Utc>, E>
+where
+    E: de::Error,
+{
+    value
+        .as_integer()
+        .and_then(|timestamp| i64::try_from(timestamp).ok())
+        .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0))
+        .ok_or_else(|| E::custom(format!("invalid {name} claim")))
+}
+
+#[cfg(feature = "verification")]
+mod verification {
+    use chrono::DateTime;
+    use chrono::Utc;
+    use crypto::trust_anchor::TrustAnchors;
+    use crypto::x509::CanonicalDistinguishedName;
+    use crypto::x509::CertificateUsage;
+    use url::Url;
+    use utils::generator::Generator;
+
+    use crate::status_list_cwt::CwtStatusListClaims;
+    use crate::status_list_cwt::StatusListCwt;
+    use crate::status_list_token::StatusListClaims;
+    use crate::status_list_token::verification::StatusListTokenVerificationError;
+    use crate::status_list_token::verification::verify_claims;
+
+    impl StatusListCwt {
+        pub fn parse_and_verify(
+            &self,
+            issuer_trust_anchors: &TrustAnchors,
+            attestation_signing_certificate_dn: CanonicalDistinguishedName,
+            url: &Url,
+            time: &impl Generator<DateTime<Utc>>,
+        ) -> Result<StatusListClaims, StatusListTokenVerificationError> {
+            let CwtStatusListClaims(claims) = self.0.verify_against_trust_anchors(
+                issuer_trust_anchors,
+                time,
+                Some(CertificateUsage::OAuthStatusSigning),
+            )?;
+
+            let slt_dn = self
+                .0
+                .x5chain()?
+                .first()
+                .to_canonical_distinguished_name()
+                .map_err(StatusListTokenVerificationError::MissingDN)?;
+
+            verify_claims(claims, slt_dn, attestation_signing_certificate_dn, url, time)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::assert_matches;
+    use std::ops::Add;
+
+    use chrono::Days;
+    use crypto::server_keys::generate::Ca;
+
+    use super::*;
+    use crate::status_list::StatusList;
+    use crate::status_list::StatusType;
+    use crate::status_list_token::StatusListToken;
+
+    #[tokio::test]
+    async fn test_status_list_cwt() {
+        let ca = Ca::generate_mock();
+        let keypair = ca.generate_issuer_status_list_mock().unwrap();
+
+        let mut status_list = StatusList::new(16);
+        status_list.insert(3, StatusType::Invalid);
+        status_list.insert(5, StatusType::Suspended);
+
+        let sub: Url = "https://example.com/statuslists/1".parse().unwrap();
+        let exp = DateTime::from_timestamp(Utc::now().add(Days::new(1)).timestamp(), 0).unwrap();
+        let cwt = StatusListToken::builder(sub.clone(), status_list.clone().pack())
+            .exp(Some(exp))
+            .ttl(Some(Duration::from_secs(3600)))
+            .sign_cwt(&keypair)
+            .await
+            .unwrap();
+
+        let parsed = StatusListCwt::from_slice(&cwt.to_vec().unwrap()).unwrap();
+        let claims = parsed.dangerous_parse_unverified().unwrap();
+
+        assert_eq!(claims.sub, sub);
+        assert_eq!(claims.exp, Some(exp));
+        assert_eq!(claims.ttl, Some(Duration::from_secs(3600)));
+        assert_eq!(claims.status_list.unpack(), status_list);
+    }
+
+    #[tokio::test]
+    async fn test_status_list_cwt_unexpected_type() {
+        let ca = Ca::generate_mock();
+        let keypair = ca.generate_issuer_status_list_mock().unwrap();
+
+        let claims = CwtStatusListClaims(StatusListClaims {
+            iat: Utc::now(),
+            exp: None,
+            sub: "https://example.com/statuslists/1".parse().unwrap(),
+            ttl: None,
+            status_list: StatusList::new(8).pack(),
+        });
+        let cose = TypedCose::<CoseSign1, _>::sign(&claims, Default::default(), &keypair, true)
+            .await
+            .unwrap();
+        let bytes = cose.into_inner().to_tagged_vec().unwrap();
+
+        let error = StatusListCwt::from_slice(&bytes).expect_err("should fail without type header");
+        assert_matches!(error, StatusListCwtError::UnexpectedType(None));
+    }
+}
@@ -1,11 +1,27 @@
 use std::num::NonZeroUsize;
 
 use attestation_types::status_claim::StatusClaim;
+use derive_more::From;
+use serde::Deserialize;
+use serde::Serialize;
 use tokio::task::AbortHandle;
 use utils::date_time_seconds::DateTimeSeconds;
 use utils::vec_at_least::VecNonEmpty;
 use uuid::Uuid;
 
+/// Salted hash of an identifier of the subject to which an attestation is issued, such as a BSN. It is stored with the
+/// attestation batch, so that all batches issued to a subject can be revoked without storing the identifier itself.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, From, Serialize, Deserialize)]
+#[cfg_attr(feature = "axum", derive(utoipa::ToSchema))]
+#[serde(transparent)]
+pub struct SubjectLookupHash(String);
+
+impl SubjectLookupHash {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
 #[trait_variant::make(Send)]
 pub trait StatusListService {
     type Error: std::error::Error + Send + Sync + 'static;
@@ -13,6 +29,7 @@ pub trait StatusListService {
     async fn obtain_status_claims(
         &self,
         batch_id: Uuid,
+        subject_lookup_hash: Option<SubjectLookupHash>,
         expires: Option<DateTimeSeconds>,
         copies: NonZeroUsize,
     ) -> Result<VecNonEmpty<StatusClaim>, Self::Error>;
@@ -21,6 +38,8 @@ pub trait StatusListService {
 
     async fn republish_all(&self, force: bool) -> Result<(), RevocationError>;
     async fn revoke_attestation_batches(&self, batch_ids: Vec<Uuid>) -> Result<(), RevocationError>;
+    async fn suspend_attestation_batches(&self, batch_ids: Vec<Uuid>) -> Result<(), RevocationError>;
+    async fn reinstate_attestation_batches(&self, batch_ids: Vec<Uuid>) -> Result<(), RevocationError>;
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -82,6 +101,7 @@ pub mod mock {
             async fn obtain_status_claims(
                 &self,
                 batch_id: Uuid,
+                subject_lookup_hash: Option<SubjectLookupHash>,
                 expires: Option<DateTimeSeconds>,
                 copies: NonZeroUsize,
             ) -> Result<VecNonEmpty<StatusClaim>, Infallible>;
@@ -90,6 +110,8 @@ pub mod mock {
 
             async fn republish_all(&self, force: bool) -> Result<(), RevocationError>;
             async fn revoke_attestation_batches(&self, batch_ids: Vec<Uuid>) -> Result<(), RevocationError>;
+            async fn suspend_attestation_batches(&self, batch_ids: Vec<Uuid>) -> Result<(), RevocationError>;
+            async fn reinstate_attestation_batches(&self, batch_ids: Vec<Uuid>) -> Result<(), RevocationError>;
         }
     }
 }
@@ -4,9 +4,11 @@ use chrono::DateTime;
 use chrono::Utc;
 use chrono::serde::ts_seconds;
 use chrono::serde::ts_seconds_option;
+use cose::CoseError;
 use crypto::EcdsaKey;
 use crypto::server_keys::KeyPair;
 use derive_more::AsRef;
+use derive_more::From;
 use derive_more::FromStr;
 use jwt::JwtTyp;
 use jwt::SignedJwt;
@@ -20,9 +22,28 @@ use serde_with::serde_as;
 use url::Url;
 
 use crate::status_list::PackedStatusList;
+use crate::status_list_cwt::StatusListCwt;
 
 pub static TOKEN_STATUS_LIST_JWT_TYP: &str = "statuslist+jwt";
 
+/// The formats in which a Status List Token can be represented and served.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatusListTokenFormat {
+    #[default]
+    Jwt,
+    Cwt,
+}
+
+impl StatusListTokenFormat {
+    pub fn media_type(&self) -> &'static str {
+        match self {
+            Self::Jwt => "application/statuslist+jwt",
+            Self::Cwt => "application/statuslist+cwt",
+        }
+    }
+}
+
 /// A Status List Token embeds a Status List into a token that is cryptographically signed and protects the integrity of
 /// the Status List.
 ///
@@ -31,21 +52,31 @@ pub static TOKEN_STATUS_LIST_JWT_TYP: &str = "statuslist+jwt";
 #[cfg_attr(test, derive(PartialEq, Eq))]
 pub struct StatusListToken(UnverifiedJwt<StatusListClaims, HeaderWithX5c>);
 
+/// A Status List Token in either the JWT or the CWT format.
+#[derive(Debug, Clone, From)]
+pub enum AnyStatusListToken {
+    Jwt(StatusListToken),
+    Cwt(Box<StatusListCwt>),
+}
+
 impl StatusListToken {
     pub fn builder(sub: Url, status_list: PackedStatusList) -> StatusListTokenBuilder {
         StatusListTokenBuilder {
             exp: None,
             sub,
             ttl: None,
+            aggregation_uri: None,
             status_list,
         }
     }
 }
 
+#[derive(Clone)]
 pub struct StatusListTokenBuilder {
     exp: Option<DateTime<Utc>>,
     sub: Url,
     ttl: Option<Duration>,
+    aggregation_uri: Option<Url>,
     status_list: PackedStatusList,
 }
 
@@ -60,18 +91,29 @@ impl StatusListTokenBuilder {
         self
     }
 
-    pub async fn sign(self, keypair: &KeyPair<impl EcdsaKey>) -> Result<StatusListToken, JwtSignError> {
-        let claims = StatusListClaims {
+    pub fn aggregation_uri(mut self, aggregation_uri: Option<Url>) -> Self {
+        self.aggregation_uri = aggregation_uri;
+        self
+    }
+
+    fn into_claims(self) -> StatusListClaims {
+        StatusListClaims {
             iat: Utc::now(),
             exp: self.exp,
             sub: self.sub,
             ttl: self.ttl,
-            status_list: self.status_list,
-        };
+            status_list: self.status_list.with_aggregation_uri(self.aggregation_uri),
+        }
+    }
 
-        let jwt = SignedJwt::sign_with_certificate(&claims, keypair).await?;
+    pub async fn sign(self, keypair: &KeyPair<impl EcdsaKey>) -> Result<StatusListToken, JwtSignError> {
+        let jwt = SignedJwt::sign_with_certificate(&self.into_claims(), keypair).await?;
         Ok(StatusListToken(jwt.into_unverified()))
     }
+
+    pub async fn sign_cwt(self, keypair: &KeyPair<impl EcdsaKey>) -> Result<StatusListCwt, CoseError> {
+        StatusListCwt::sign(self.into_claims(), keypair).await
+    }
 }
 
 #[serde_as]
@@ -110,6 +152,7 @@ pub mod verification {
     use chrono::DateTime;
     use chrono::Duration;
     use chrono::Utc;
+    use cose::CoseError;
     use crypto::trust_anchor::TrustAnchors;
     use crypto::x509::CanonicalDistinguishedName;
     use crypto::x509::CertificateError;
@@ -119,6 +162,7 @@ pub mod verification {
     use url::Url;
     use utils::generator::Generator;
 
+    use crate::status_list_token::AnyStatusListToken;
     use crate::status_list_token::StatusListClaims;
     use crate::status_list_token::StatusListToken;
 
@@ -129,10 +173,13 @@ pub mod verification {
         #[error("JWT verification failed: {0}")]
         JwtVerification(#[from] JwtX5cVerifyError),
 
-        #[error("JWT is expired")]
+        #[error("CWT verification failed: {0}")]
+        CwtVerification(#[from] CoseError),
+
+        #[error("token is expired")]
         Expired,
 
-        #[error("JWT subject claim ('{sub}') does not match url claim of Reference Token ('{url}')")]
+        #[error("token subject claim ('{sub}') does not match url claim of Reference Token ('{url}')")]
         UnexpectedSubject { sub: String, url: String },
 
         #[error("DN is missing in certificate")]
@@ -165,26 +212,57 @@ pub mod verification {
                 .first()
                 .to_canonical_distinguished_name()
                 .map_err(StatusListTokenVerificationError::MissingDN)?;
-            if slt_dn != attestation_signing_certificate_dn {
-                return Err(StatusListTokenVerificationError::DifferentDN {
-                    slt: slt_dn,
-                    attestation: attestation_signing_certificate_dn,
-                });
-            }
 
-            if *url != claims.sub {
-                return Err(StatusListTokenVerificationError::UnexpectedSubject {
-                    sub: claims.sub.to_string(),
-                    url: url.to_string(),
-                });
-            }
+            verify_claims(claims, slt_dn, attestation_signing_certificate_dn, url, time)
+        }
+    }
 
-            if claims.exp.is_some_and(|exp| exp.add(EXP_LEEWAY) < time.generate()) {
-                return Err(StatusListTokenVerificationError::Expired);
+    impl AnyStatusListToken {
+        pub fn parse_and_verify(
+            &self,
+            issuer_trust_anchors: &TrustAnchors,
+            attestation_signing_certificate_dn: CanonicalDistinguishedName,
+            url: &Url,
+            time: &impl Generator<DateTime<Utc>>,
+        ) -> Result<StatusListClaims, StatusListTokenVerificationError> {
+            match self {
+                Self::Jwt(token) => {
+                    token.parse_and_verify(issuer_trust_anchors, attestation_signing_certificate_dn, url, time)
+                }
+                Self::Cwt(token) => {
+                    token.parse_and_verify(issuer_trust_anchors, attestation_signing_certificate_dn, url, time)
+                }
             }
+        }
+    }
 
-            Ok(claims)
+    /// Verify the claims of an authenticated Status List Token, regardless of its format.
+    pub(crate) fn verify_claims(
+        claims: StatusListClaims,
+        slt_dn: CanonicalDistinguishedName,
+        attestation_signing_certificate_dn: CanonicalDistinguishedName,
+        url: &Url,
+        time: &impl Generator<DateTime<Utc>>,
+    ) -> Result<StatusListClaims, StatusListTokenVerificationError> {
+        if slt_dn != attestation_signing_certificate_dn {
+            return Err(StatusListTokenVerificationError::DifferentDN {
+                slt: slt_dn,
+                attestation: attestation_signing_certificate_dn,
+            });
         }
+
+        if *url != claims.sub {
+            return Err(StatusListTokenVerificationError::UnexpectedSubject {
+                sub: claims.sub.to_string(),
+                url: url.to_string(),
+            });
+        }
+
+        if claims.exp.is_some_and(|exp| exp.add(EXP_LEEWAY) < time.generate()) {
+            return Err(StatusListTokenVerificationError::Expired);
+        }
+
+        Ok(claims)
     }
 }
 
@@ -1,7 +1,9 @@
 use jwt::error::JwtParseError;
 use url::Url;
 
-use crate::status_list_token::StatusListToken;
+use crate::status_list_aggregation::StatusListAggregation;
+use crate::status_list_cwt::StatusListCwtError;
+use crate::status_list_token::AnyStatusListToken;
 
 #[derive(Debug, thiserror::Error)]
 pub enum StatusListClientError {
@@ -10,11 +12,16 @@ pub enum StatusListClientError {
 
     #[error("jwt parsing error: {0}")]
     JwtParsing(#[from] JwtParseError),
+
+    #[error("cwt parsing error: {0}")]
+    CwtParsing(#[from] StatusListCwtError),
 }
 
 #[trait_variant::make(Send)]
 pub trait StatusListClient {
-    async fn fetch(&self, url: Url) -> Result<StatusListToken, StatusListClientError>;
+    async fn fetch(&self, url: Url) -> Result<AnyStatusListToken, StatusListClientError>;
+
+    async fn fetch_aggregation(&self, url: Url) -> Result<StatusListAggregation, StatusListClientError>;
 }
 
 #[cfg(any(test, feature = "mock"))]
@@ -31,6 +38,8 @@ pub mod mock {
     use url::Url;
 
     use crate::status_list::StatusList;
+    use crate::status_list_aggregation::StatusListAggregation;
+    use crate::status_list_token::AnyStatusListToken;
     use crate::status_list_token::StatusListToken;
     use crate::status_list_token::mock::create_status_list_token;
     use crate::verification::client::StatusListClient;
@@ -40,7 +49,9 @@ pub mod mock {
         pub StatusListClient {}
 
         impl StatusListClient for StatusListClient {
-            async fn fetch(&self, url: Url) -> Result<StatusListToken, StatusListClientError>;
+            async fn fetch(&self, url: Url) -> Result<AnyStatusListToken, StatusListClientError>;
+
+            async fn fetch_aggregation(&self, url: Url) -> Result<StatusListAggregation, StatusListClientError>;
         }
     }
 
@@ -51,11 +62,15 @@ pub mod mock {
     where
         S: EcdsaKeySend + Sync,
     {
-        async fn fetch(&self, _url: Url) -> Result<StatusListToken, StatusListClientError> {
+        async fn fetch(&self, _url: Url) -> Result<AnyStatusListToken, StatusListClientError> {
             let (_, _, status_list_token) =
                 create_status_list_token(&self.0, Some(Utc::now().add(Days::new(1)).timestamp()), None).await;
 
-            Ok(status_list_token)
+            Ok(status_list_token.into())
+        }
+
+        async fn fetch_aggregation(&self, _url: Url) -> Result<StatusListAggregation, StatusListClientError> {
+            Ok(StatusListAggregation::default())
         }
     }
 
@@ -70,7 +85,7 @@ pub mod mock {
     where
         S: EcdsaKeySend + Sync,
     {
-        async fn fetch(&self, url: Url) -> Result<StatusListToken, StatusListClientError> {
+        async fn fetch(&self, url: Url) -> Result<AnyStatusListToken, StatusListClientError> {
             let url_vct = url
                 .as_str()
                 .rsplit("/")
@@ -89,7 +104,11 @@ pub mod mock {
                 .await
                 .unwrap();
 
-            Ok(status_list_token)
+            Ok(status_list_token.into())
+        }
+
+        async fn fetch_aggregation(&self, _url: Url) -> Result<StatusListAggregation, StatusListClientError> {
+            Ok(StatusListAggregation::default())
         }
     }
 }
@@ -3,34 +3,60 @@ use http::header;
 use reqwest::ClientBuilder;
 use url::Url;
 
-use crate::status_list_token::StatusListToken;
+use crate::status_list_aggregation::StatusListAggregation;
+use crate::status_list_cwt::StatusListCwt;
+use crate::status_list_token::AnyStatusListToken;
+use crate::status_list_token::StatusListTokenFormat;
 use crate::verification::client::StatusListClient;
 use crate::verification::client::StatusListClientError;
 
-const STATUS_LIST_JWT_ACCEPT: &str = "application/statuslist+jwt";
-
 #[derive(Debug, Clone, AsRef)]
-pub struct HttpStatusListClient(reqwest::Client);
+pub struct HttpStatusListClient {
+    #[as_ref]
+    client: reqwest::Client,
+    format: StatusListTokenFormat,
+}
 
 impl HttpStatusListClient {
     pub fn new(client_builder: ClientBuilder) -> Result<Self, reqwest::Error> {
         let client = client_builder.build()?;
 
-        Ok(Self(client))
+        Ok(Self {
+            client,
+            format: StatusListTokenFormat::default(),
+        })
+    }
+
+    /// Request Status List Tokens in the specified format instead of the default JWT format.
+    pub fn with_format(mut self, format: StatusListTokenFormat) -> Self {
+        self.format = format;
+        self
     }
 }
 
 impl StatusListClient for HttpStatusListClient {
-    async fn fetch(&self, url: Url) -> Result<StatusListToken, StatusListClientError> {
+    async fn fetch(&self, url: Url) -> Result<AnyStatusListToken, StatusListClientError> {
         let response = self
             .as_ref()
             .get(url)
-            .header(header::ACCEPT, STATUS_LIST_JWT_ACCEPT)
+            .header(header::ACCEPT, self.format.media_type())
             .send()
-            .await?;
+            .await?
+            .error_for_status()?;
 
-        let status_list_token = response.error_for_status()?.text().await?.parse()?;
+        let status_list_token = match self.format {
+            StatusListTokenFormat::Jwt => AnyStatusListToken::Jwt(response.text().await?.parse()?),
+            StatusListTokenFormat::Cwt => {
+                AnyStatusListToken::Cwt(Box::new(StatusListCwt::from_slice(&response.bytes().await?)?))
+            }
+        };
 
         Ok(status_list_token)
     }
+
+    async fn fetch_aggregation(&self, url: Url) -> Result<StatusListAggregation, StatusListClientError> {
+        let aggregation = self.as_ref().get(url).send().await?.error_for_status()?.json().await?;
+
+        Ok(aggregation)
+    }
 }
@@ -1,4 +1,5 @@
 use std::cmp::min;
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
@@ -10,6 +11,7 @@ use chrono::DateTime;
 use chrono::Utc;
 use crypto::trust_anchor::TrustAnchors;
 use crypto::x509::CanonicalDistinguishedName;
+use futures::StreamExt;
 use moka::Expiry;
 use moka::future::Cache;
 use serde::Deserialize;
@@ -29,6 +31,8 @@ use crate::verification::client::StatusListClientError;
 pub enum RevocationStatus {
     Valid,
     Revoked,
+    /// The attestation is temporarily invalid, but may become valid again.
+    Suspended,
     Undetermined,
     Corrupted,
 }
@@ -46,6 +50,12 @@ type CachedResult = Result<StatusListClaims, StatusListVerificationError>;
 
 const ZERO_DURATION: Duration = Duration::from_secs(0);
 
+/// Maximum number of Status List Tokens that are fetched concurrently while prefetching
+pub const PREFETCH_CONCURRENCY: usize = 10;
+
+/// Maximum number of Status List Tokens listed in a Status List Aggregation that are fetched while prefetching
+pub const MAX_AGGREGATED_STATUS_LISTS: usize = 100;
+
 struct TokenExpiry<G> {
     /// TTL when Status List Token has no `ttl` specified
     default_ttl: Duration,
@@ -114,6 +124,7 @@ where
         match result {
             Ok(claims) => match claims.status_list.single_unpack(idx.try_into().unwrap()) {
                 StatusType::Valid => RevocationStatus::Valid,
+                StatusType::Suspended => RevocationStatus::Suspended,
                 _ => RevocationStatus::Revoked,
             },
             Err(err) => match err {
@@ -129,6 +140,106 @@ where
         }
     }
 
+    /// Prefetch the Status List Tokens at the given URIs, which should all belong to the same issuer, into the cache.
+    ///
+    /// If the Status List Tokens refer to a Status List Aggregation, all Status List Tokens listed in it are fetched as
+    /// well, so that the issuer does not learn which of its Status Lists are of interest. Only Status List Tokens from
+    /// the aggregation that pass verification are cached, as these are not necessarily signed by the same issuer. At
+    /// most [`MAX_AGGREGATED_STATUS_LISTS`] Status List Tokens are fetched from the aggregation.
+    pub async fn prefetch(
+        &self,
+        issuer_trust_anchors: &TrustAnchors,
+        attestation_signing_certificate_dn: CanonicalDistinguishedName,
+        uris: impl IntoIterator<Item = Url>,
+        time: &impl Generator<DateTime<Utc>>,
+    ) {
+        let uris = uris.into_iter().collect::<HashSet<_>>();
+        let Some(first_uri) = uris.iter().next().cloned() else {
+            return;
+        };
+
+        // All Status List Tokens of an issuer are expected to refer to the same aggregation, so checking one suffices
+        let aggregation_uri = self
+            .cache
+            .get_with(
+                first_uri.clone(),
+                self.fetch_status_list_claims(
+                    first_uri,
+                    issuer_trust_anchors,
+                    attestation_signing_certificate_dn.clone(),
+                    time,
+                ),
+            )
+            .await
+            .ok()
+            .and_then(|claims| claims.status_list.aggregation_uri().cloned());
+
+        let aggregated_uris = match aggregation_uri {
+            Some(aggregation_uri) => match self.client.fetch_aggregation(aggregation_uri).await {
+                Ok(aggregation) => aggregation.status_lists,
+                Err(e) => {
+                    warn!("Status list aggregation fetching fails: {e}");
+                    Vec::new()
+                }
+            },
+            None => Vec::new(),
+        };
+        let aggregated_uris = aggregated_uris
+            .into_iter()
+            .filter(|uri| !uris.contains(uri))
+            .collect::<HashSet<_>>();
+        if aggregated_uris.len() > MAX_AGGREGATED_STATUS_LISTS {
+            warn!(
+                "Status list aggregation lists {} status lists, only prefetching {MAX_AGGREGATED_STATUS_LISTS}",
+                aggregated_uris.len()
+            );
+        }
+
+        futures::stream::iter(
+            uris.into_iter().map(|uri| (uri, false)).chain(
+                aggregated_uris
+                    .into_iter()
+                    .take(MAX_AGGREGATED_STATUS_LISTS)
+                    .map(|uri| (uri, true)),
+            ),
+        )
+        .for_each_concurrent(PREFETCH_CONCURRENCY, |(uri, from_aggregation)| {
+            self.prefetch_status_list(
+                uri,
+                from_aggregation,
+                issuer_trust_anchors,
+                &attestation_signing_certificate_dn,
+                time,
+            )
+        })
+        .await;
+    }
+
+    async fn prefetch_status_list(
+        &self,
+        url: Url,
+        from_aggregation: bool,
+        issuer_trust_anchors: &TrustAnchors,
+        attestation_signing_certificate_dn: &CanonicalDistinguishedName,
+        time: &impl Generator<DateTime<Utc>>,
+    ) {
+        let init = self.fetch_status_list_claims(
+            url.clone(),
+            issuer_trust_anchors,
+            attestation_signing_certificate_dn.clone(),
+            time,
+        );
+
+        if from_aggregation {
+            self.cache
+                .entry(url)
+                .or_optionally_insert_with(async { init.await.ok().map(Ok) })
+                .await;
+        } else {
+            _ = self.cache.get_with(url, init).await;
+        }
+    }
+
     async fn fetch_status_list_claims(
         &self,
         url: Url,
@@ -191,11 +302,16 @@ mod test {
     use utils::generator::mock::MockTimeGenerator;
 
     use crate::status_list::PackedStatusList;
+    use crate::status_list::StatusList as TokenStatusList;
+    use crate::status_list::StatusType;
+    use crate::status_list_aggregation::StatusListAggregation;
     use crate::status_list_token::StatusListClaims;
+    use crate::status_list_token::StatusListToken;
     use crate::status_list_token::verification::StatusListTokenVerificationError;
     use crate::verification::client::StatusListClientError;
     use crate::verification::client::mock::MockStatusListClient;
     use crate::verification::client::mock::StatusListClientStub;
+    use crate::verification::verifier::MAX_AGGREGATED_STATUS_LISTS;
     use crate::verification::verifier::RevocationStatus;
     use crate::verification::verifier::RevocationVerifier;
     use crate::verification::verifier::StatusListVerificationError;
@@ -328,6 +444,90 @@ mod test {
         assert_eq!(RevocationStatus::Undetermined, status);
     }
 
+    #[test]
+    fn test_verify_suspended() {
+        let ca = Ca::generate_mock();
+        let keypair = ca.generate_issuer_status_list_mock().unwrap();
+        let iss_keypair = ca.generate_issuer_mock().unwrap();
+
+        let mut status_list = TokenStatusList::new(8);
+        status_list.insert(2, StatusType::Suspended);
+        status_list.insert(3, StatusType::Invalid);
+        let token = StatusListToken::builder("https://example.com/statuslists/1".parse().unwrap(), status_list.pack())
+            .exp(Some(Utc::now().add(Days::new(1))))
+            .sign(&keypair)
+            .now_or_never()
+            .unwrap()
+            .unwrap();
+
+        let mut client = MockStatusListClient::new();
+        client.expect_fetch().returning(move |_| Ok(token.clone().into()));
+        let verifier = RevocationVerifier::new_without_caching(Arc::new(client));
+
+        for (idx, expected) in [
+            (1, RevocationStatus::Valid),
+            (2, RevocationStatus::Suspended),
+            (3, RevocationStatus::Revoked),
+        ] {
+            let status = verifier
+                .verify(
+                    &TrustAnchors::from(&ca),
+                    iss_keypair.certificate().to_canonical_distinguished_name().unwrap(),
+                    StatusList(StatusListClaim {
+                        uri: "https://example.com/statuslists/1".parse().unwrap(),
+                        idx,
+                    }),
+                    &MockTimeGenerator::default(),
+                )
+                .now_or_never()
+                .unwrap();
+            assert_eq!(expected, status);
+        }
+    }
+
+    #[test]
+    fn test_verify_cwt() {
+        let ca = Ca::generate_mock();
+        let keypair = ca.generate_issuer_status_list_mock().unwrap();
+        let iss_keypair = ca.generate_issuer_mock().unwrap();
+
+        let mut status_list = TokenStatusList::new(8);
+        status_list.insert(3, StatusType::Invalid);
+        let cwt = StatusListToken::builder("https://example.com/statuslists/1".parse().unwrap(), status_list.pack())
+            .exp(Some(Utc::now().add(Days::new(1))))
+            .sign_cwt(&keypair)
+            .now_or_never()
+            .unwrap()
+            .unwrap();
+
+        let mut client = MockStatusListClient::new();
+        client
+            .expect_fetch()
+            .returning(move |_| Ok(Box::new(cwt.clone()).into()));
+        let verifier = RevocationVerifier::new_without_caching(Arc::new(client));
+
+        for (trust_anchors, idx, expected) in [
+            (TrustAnchors::from(&ca), 1, RevocationStatus::Valid),
+            (TrustAnchors::from(&ca), 3, RevocationStatus::Revoked),
+            // Corrupted when the CWT doesn't validate
+            (TrustAnchors::empty(), 1, RevocationStatus::Corrupted),
+        ] {
+            let status = verifier
+                .verify(
+                    &trust_anchors,
+                    iss_keypair.certificate().to_canonical_distinguished_name().unwrap(),
+                    StatusList(StatusListClaim {
+                        uri: "https://example.com/statuslists/1".parse().unwrap(),
+                        idx,
+                    }),
+                    &MockTimeGenerator::default(),
+                )
+                .now_or_never()
+                .unwrap();
+            assert_eq!(expected, status);
+        }
+    }
+
     #[test]
     fn test_verify_cached() {
         let ca = Ca::generate_mock();
@@ -373,6 +573,153 @@ mod test {
         assert_eq!(RevocationStatus::Valid, status);
     }
 
+    #[test]
+    fn test_prefetch_aggregation() {
+        let ca = Ca::generate_mock();
+        let keypair = ca.generate_issuer_status_list_mock().unwrap();
+        let iss_keypair = ca.generate_issuer_mock().unwrap();
+        let other_keypair = Ca::generate_mock().generate_issuer_status_list_mock().unwrap();
+
+        let aggregation_uri: Url = "https://example.com/statuslists/aggregation".parse().unwrap();
+        let uris: [Url; 3] = [
+            "https://example.com/statuslists/1".parse().unwrap(),
+            "https://example.com/statuslists/2".parse().unwrap(),
+            "https://example.com/statuslists/3".parse().unwrap(),
+        ];
+
+        let mut status_list = TokenStatusList::new(8);
+        status_list.insert(3, StatusType::Invalid);
+        let tokens = uris
+            .iter()
+            .map(|uri| {
+                // The last Status List is signed by an untrusted issuer
+                let keypair = if *uri == uris[2] { &other_keypair } else { &keypair };
+                StatusListToken::builder(uri.clone(), status_list.clone().pack())
+                    .exp(Some(Utc::now().add(Days::new(1))))
+                    .aggregation_uri(Some(aggregation_uri.clone()))
+                    .sign(keypair)
+                    .now_or_never()
+                    .unwrap()
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let mut client = MockStatusListClient::new();
+        for (index, (uri, token)) in uris.iter().cloned().zip(tokens).enumerate() {
+            // The untrusted Status List is fetched again on verification, as it should not have been cached
+            let times = if index == 2 { 2 } else { 1 };
+            client
+                .expect_fetch()
+                .withf(move |url| *url == uri)
+                .times(times)
+                .returning(move |_| Ok(token.clone().into()));
+        }
+        let aggregation = StatusListAggregation {
+            status_lists: uris.to_vec(),
+        };
+        client
+            .expect_fetch_aggregation()
+            .withf(move |url| *url == aggregation_uri)
+            .times(1)
+            .returning(move |_| Ok(aggregation.clone()));
+
+        let verifier = RevocationVerifier::new(
+            Arc::new(client),
+            10,
+            TEN_MINUTES,
+            TEN_MINUTES,
+            MockTimeGenerator::default(),
+        );
+        let dn = iss_keypair.certificate().to_canonical_distinguished_name().unwrap();
+
+        verifier
+            .prefetch(
+                &TrustAnchors::from(&ca),
+                dn.clone(),
+                [uris[0].clone()],
+                &MockTimeGenerator::default(),
+            )
+            .now_or_never()
+            .unwrap();
+
+        for (uri, expected) in [
+            (&uris[0], RevocationStatus::Revoked),
+            (&uris[1], RevocationStatus::Revoked),
+            (&uris[2], RevocationStatus::Corrupted),
+        ] {
+            let status = verifier
+                .verify(
+                    &TrustAnchors::from(&ca),
+                    dn.clone(),
+                    StatusList(StatusListClaim {
+                        uri: uri.clone(),
+                        idx: 3,
+                    }),
+                    &MockTimeGenerator::default(),
+                )
+                .now_or_never()
+                .unwrap();
+            assert_eq!(expected, status);
+        }
+    }
+
+    #[test]
+    fn test_prefetch_aggregation_max_status_lists() {
+        let ca = Ca::generate_mock();
+        let keypair = ca.generate_issuer_status_list_mock().unwrap();
+        let iss_keypair = ca.generate_issuer_mock().unwrap();
+
+        let aggregation_uri: Url = "https://example.com/statuslists/aggregation".parse().unwrap();
+        let uri: Url = "https://example.com/statuslists/0".parse().unwrap();
+
+        let token = StatusListToken::builder(uri.clone(), TokenStatusList::new(8).pack())
+            .exp(Some(Utc::now().add(Days::new(1))))
+            .aggregation_uri(Some(aggregation_uri.clone()))
+            .sign(&keypair)
+            .now_or_never()
+            .unwrap()
+            .unwrap();
+
+        let mut client = MockStatusListClient::new();
+        let expected_uri = uri.clone();
+        client
+            .expect_fetch()
+            .withf(move |url| *url == expected_uri)
+            .times(1)
+            .returning(move |_| Ok(token.clone().into()));
+        // Only the maximum number of Status Lists from the aggregation should be fetched
+        let expected_uri = uri.clone();
+        client
+            .expect_fetch()
+            .withf(move |url| *url != expected_uri)
+            .times(MAX_AGGREGATED_STATUS_LISTS)
+            .returning(|_| Err(StatusListClientError::JwtParsing(JwtParseError::MissingKid)));
+        let aggregation = StatusListAggregation {
+            status_lists: (1..=MAX_AGGREGATED_STATUS_LISTS * 2)
+                .map(|index| format!("https://example.com/statuslists/{index}").parse().unwrap())
+                .collect(),
+        };
+        client
+            .expect_fetch_aggregation()
+            .withf(move |url| *url == aggregation_uri)
+            .times(1)
+            .returning(move |_| Ok(aggregation.clone()));
+
+        let verifier = RevocationVerifier::new(
+            Arc::new(client),
+            10,
+            TEN_MINUTES,
+            TEN_MINUTES,
+            MockTimeGenerator::default(),
+        );
+        let dn = iss_keypair.certificate().to_canonical_distinguished_name().unwrap();
+
+        verifier
+            .prefetch(&TrustAnchors::from(&ca), dn, [uri], &MockTimeGenerator::default())
+            .now_or_never()
+            .unwrap();
+    }
+
     fn cached_result(
         exp: Option<DateTime<Utc>>,
         ttl: Option<Duration>,
@@ -6,12 +6,14 @@ use axum::extract::State;
 use axum::response::IntoResponse;
 use axum::response::Response;
 use axum::routing::get;
+use crypto::trusted_list::TrustedListFetcher;
 use etag::EntityTag;
 use http::HeaderMap;
 use http::HeaderValue;
 use http::StatusCode;
 use http::header;
 use http_utils::health::create_health_router;
+use http_utils::reqwest::default_reqwest_client_builder;
 use jwt::VerifiedJwt;
 use status_lists::serve::StatusListRouteSource;
 use status_lists::serve::create_serve_router;
@@ -19,6 +21,7 @@ use tokio::net::TcpListener;
 use tracing::debug;
 use tracing::info;
 use utils::built_info::version_string;
+use utils::generator::TimeGenerator;
 use wallet_configuration::wallet_config::WalletConfiguration;
 
 use super::settings::Settings;
@@ -33,10 +36,20 @@ pub async fn serve_with_listener(listener: TcpListener, settings: Settings) -> R
     info!("listening on {}", listener.local_addr()?);
     let listener = listener.into_std()?;
 
-    let config_entity_tag = EntityTag::from_data(settings.wallet_config_jwt.jwt().serialization().as_bytes());
+    let wallet_config_jwt = match settings.wallet_config_trusted_lists {
+        Some(trusted_lists) => {
+            let fetcher = TrustedListFetcher::new(default_reqwest_client_builder().build()?);
+            trusted_lists
+                .extend_wallet_config(&settings.wallet_config_jwt, &fetcher, &TimeGenerator)
+                .await?
+        }
+        None => settings.wallet_config_jwt,
+    };
+
+    let config_entity_tag = EntityTag::from_data(wallet_config_jwt.jwt().serialization().as_bytes());
     let config_router = Router::new()
         .route("/wallet-config", get(configuration))
-        .with_state((settings.wallet_config_jwt, config_entity_tag));
+        .with_state((wallet_config_jwt, config_entity_tag));
 
     let status_list_router = create_serve_router([StatusListRouteSource {
         path: "/wia",
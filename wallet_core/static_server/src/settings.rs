@@ -1,14 +1,25 @@
 use std::net::IpAddr;
 use std::path::Path;
 
+use chrono::DateTime;
+use chrono::Utc;
 use config::Config;
 use config::ConfigError;
 use config::Environment;
 use config::File;
+use crypto::p256_der::DerSigningKey;
+use crypto::trusted_list::TrustedListFetcher;
 use http_utils::server::TlsServerConfig;
+use jwt::SignedJwt;
 use jwt::VerifiedJwt;
+use jwt::error::JwtSignError;
 use serde::Deserialize;
+use serde_with::base64::Base64;
+use serde_with::serde_as;
+use server_utils::settings::TrustedListSettingsError;
+use server_utils::trusted_list_settings::TrustedListSettings;
 use status_lists::publish::PublishDir;
+use utils::generator::Generator;
 use utils::path::prefix_local_path;
 use wallet_configuration::wallet_config::WalletConfiguration;
 
@@ -21,6 +32,28 @@ pub struct Settings {
     #[serde(deserialize_with = "VerifiedJwt::dangerous_deserialize")] // we trust our own config file
     pub wallet_config_jwt: VerifiedJwt<WalletConfiguration>,
     pub wua_publish_dir: PublishDir,
+
+    pub wallet_config_trusted_lists: Option<WalletConfigTrustedLists>,
+}
+
+/// ETSI TS 119 612 trusted lists from which the trust anchors of the served wallet configuration are extended on
+/// startup. As this changes the wallet configuration, it is signed again using `signing_key`, which should be the same
+/// key the wallet configuration JWT was signed with.
+#[serde_as]
+#[derive(Clone, Deserialize)]
+pub struct WalletConfigTrustedLists {
+    pub trusted_lists: Vec<TrustedListSettings>,
+
+    #[serde_as(as = "Base64")]
+    pub signing_key: DerSigningKey,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum WalletConfigTrustedListsError {
+    #[error(transparent)]
+    TrustedList(#[from] TrustedListSettingsError),
+    #[error("could not sign wallet configuration: {0}")]
+    Signing(#[from] JwtSignError),
 }
 
 impl Settings {
@@ -39,3 +72,48 @@ impl Settings {
             .try_deserialize()
     }
 }
+
+impl WalletConfigTrustedLists {
+    /// Extend the issuer, WRPAC and WRPRC trust anchors of `wallet_config_jwt` with those loaded from the configured
+    /// trusted lists and sign the resulting wallet configuration.
+    pub async fn extend_wallet_config(
+        &self,
+        wallet_config_jwt: &VerifiedJwt<WalletConfiguration>,
+        fetcher: &TrustedListFetcher,
+        time: &impl Generator<DateTime<Utc>>,
+    ) -> Result<VerifiedJwt<WalletConfiguration>, WalletConfigTrustedListsError> {
+        let mut wallet_config = wallet_config_jwt.payload().clone();
+
+        for trusted_list in &self.trusted_lists {
+            let anchors = trusted_list
+                .load(fetcher, time)
+                .await
+                .map_err(|error| TrustedListSettingsError::TrustedList(error, trusted_list.url.clone()))?;
+
+            tracing::info!(
+                "adding {} issuer, {} WRPAC and {} WRPRC trust anchors from trusted list {} to wallet configuration",
+                anchors.issuer.len(),
+                anchors.wrpac.len(),
+                anchors.wrprc.len(),
+                trusted_list.url
+            );
+
+            wallet_config.issuer_trust_anchors = wallet_config
+                .issuer_trust_anchors
+                .extended(anchors.issuer)
+                .map_err(TrustedListSettingsError::TrustAnchor)?;
+            wallet_config.wrpac_trust_anchors = wallet_config
+                .wrpac_trust_anchors
+                .extended(anchors.wrpac)
+                .map_err(TrustedListSettingsError::TrustAnchor)?;
+            wallet_config.wrprc_trust_anchors = wallet_config
+                .wrprc_trust_anchors
+                .extended(anchors.wrprc)
+                .map_err(TrustedListSettingsError::TrustAnchor)?;
+        }
+
+        let signed = SignedJwt::sign(&wallet_config, self.signing_key.as_inner()).await?;
+
+        Ok(signed.into())
+    }
+}
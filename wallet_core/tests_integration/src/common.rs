@@ -935,7 +935,7 @@ pub async fn start_issuance_server(mut settings: IssuanceServerSettings, hsm: Op
     let serve_status_lists = settings.issuer_settings.status_lists.serve;
 
     let (issuer, _, store_connection, server_settings) =
-        settings.issuer_settings.into_issuer(hsm.clone()).await.unwrap();
+        settings.issuer_settings.into_issuer(hsm.clone(), None).await.unwrap();
 
     let issuer = Arc::new(issuer);
 
@@ -994,7 +994,7 @@ pub async fn start_pacf_issuance_server(mut settings: PacfIssuanceServerSettings
 
     let serve_status_lists = settings.0.status_lists.serve;
 
-    let (issuer, _, _, server_settings) = settings.0.into_issuer(None).await.unwrap();
+    let (issuer, _, _, server_settings) = settings.0.into_issuer(None, None).await.unwrap();
 
     let issuer = Arc::new(issuer);
 
@@ -1082,7 +1082,7 @@ pub async fn start_acf_demo_issuer_server(mut settings: AcfDemoIssuerSettings) -
 
     let (issuer, _, _, server_settings) = settings
         .authorizing_issuer_settings
-        .into_authorizing_issuer(None, |store_connection| {
+        .into_authorizing_issuer(None, None, |store_connection| {
             Ok::<_, Infallible>(DemoAuthorizationCodeFlow::new(
                 store_connection,
                 consent_base_url,
@@ -1153,7 +1153,7 @@ where
 
     let (issuer, _, _, server_settings) = pid_settings
         .authorizing_issuer_settings
-        .into_authorizing_issuer(hsm, |_| Ok::<_, Infallible>(flow))
+        .into_authorizing_issuer(hsm, None, |_| Ok::<_, Infallible>(flow))
         .await
         .unwrap();
 
@@ -1169,6 +1169,7 @@ where
                 serve_status_lists,
                 [],
                 None,
+                None,
             )
             .await
             {
@@ -129,6 +129,7 @@ async fn ltc1_test_pid_issuance_digid_bridge() {
     let issuance_flow = credential_issuer_discovery
         .start(
             &credential_offer,
+            None,
             String::from(MOCK_WALLET_CLIENT_ID),
             redirect_uri,
             wallet_config.issuer_trust_anchors(),
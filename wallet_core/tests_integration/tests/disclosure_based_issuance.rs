@@ -65,9 +65,13 @@ async fn ltc5_test_disclosure_based_issuance_and_disclosure(
     let credential_request = NormalizedCredentialRequest::SdJwt {
         id: "degree".parse().unwrap(),
         vct_values: vec_nonempty!["com.example.degree".to_string()],
+        multiple: false,
         claims: vec_nonempty![SdJwtAttributeRequest {
+            id: None,
             path: vec_nonempty![ClaimPath::SelectByKey("education".to_string())],
+            values: vec![],
         }],
+        claim_sets: vec![],
         aki: vec![],
     };
 
@@ -1,6 +1,9 @@
+use std::assert_matches;
+
 use db_test::DbSetup;
 use hsm::test::HsmSetup;
 use http_utils::reqwest::default_reqwest_client_builder;
+use openid4vc::credential_offer::PreAuthTransactionCode;
 use openid4vc::issuable_document::IssuableDocument;
 use pacf_issuance_server::offer::OfferRequest;
 use pacf_issuance_server::offer::OfferResponse;
@@ -9,6 +12,8 @@ use tests_integration::common::*;
 use utils::vec_nonempty;
 use wallet::IssuanceStartResult;
 use wallet::Pin;
+use wallet::errors::IssuanceError;
+use wallet::errors::openid4vc::WalletIssuanceError;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 #[serial(hsm)]
@@ -29,7 +34,11 @@ async fn test_pre_authorized_code_issuance() {
         .build()
         .unwrap()
         .post(pacf_issuance_server.internal.join("offer"))
-        .json(&OfferRequest { documents })
+        .json(&OfferRequest {
+            documents,
+            deferred: false,
+            tx_code: None,
+        })
         .send()
         .await
         .unwrap()
@@ -69,3 +78,87 @@ async fn test_pre_authorized_code_issuance() {
         "com.example.museum_maandkaart".to_string()
     );
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+#[serial(hsm)]
+async fn test_pre_authorized_code_issuance_with_tx_code() {
+    let db_setup = DbSetup::create_clean().await;
+    let hsm_setup = HsmSetup::new();
+
+    let pin: Pin = "112233".into();
+
+    let wallet = setup_wallet_env(&db_setup, &hsm_setup, WalletDeviceVendor::Apple).await;
+    let pacf_issuance_server = setup_pre_auth_env(&db_setup).await;
+    let mut wallet = do_wallet_registration(wallet, pin.clone()).await;
+
+    // Create a pre-authorized issuance session that requires a Transaction Code on the issuance server.
+    let tx_code_requirements = PreAuthTransactionCode {
+        description: Some("The code has been sent to you by e-mail".to_string()),
+        ..PreAuthTransactionCode::default()
+    };
+
+    let offer_response = default_reqwest_client_builder()
+        .build()
+        .unwrap()
+        .post(pacf_issuance_server.internal.join("offer"))
+        .json(&OfferRequest {
+            documents: vec_nonempty![IssuableDocument::new_mock_museum_maandkaart()],
+            deferred: false,
+            tx_code: Some(tx_code_requirements.clone()),
+        })
+        .send()
+        .await
+        .unwrap()
+        .json::<OfferResponse>()
+        .await
+        .unwrap();
+
+    let tx_code = offer_response
+        .tx_code
+        .expect("offer response should contain a Transaction Code");
+
+    // Starting issuance without a Transaction Code should return its requirements, so the user can be asked for it.
+    let error = wallet
+        .start_issuance_from_offer(offer_response.credential_offer_url.clone())
+        .await
+        .expect_err("starting issuance without Transaction Code should fail");
+
+    assert_matches!(
+        error,
+        IssuanceError::IssuanceSession(WalletIssuanceError::CredentialOfferTxCodeRequired(requirements))
+            if requirements.description == tx_code_requirements.description
+                && requirements.length == Some(tx_code.len().try_into().unwrap())
+    );
+
+    // An incorrect Transaction Code is rejected, after which the user may try again.
+    let error = wallet
+        .start_issuance_from_offer_with_transaction_code(
+            offer_response.credential_offer_url.clone(),
+            "incorrect".to_string(),
+        )
+        .await
+        .expect_err("starting issuance with incorrect Transaction Code should fail");
+
+    assert_matches!(
+        error,
+        IssuanceError::IssuanceSession(WalletIssuanceError::PreAuthorizedTxCodeRejected)
+    );
+
+    let IssuanceStartResult::Previews(previews) = wallet
+        .start_issuance_from_offer_with_transaction_code(offer_response.credential_offer_url, tx_code)
+        .await
+        .expect("should start issuance from offer with Transaction Code")
+    else {
+        panic!("expected Previews, got authorization redirect");
+    };
+
+    assert_eq!(previews.len(), 1);
+
+    wallet.accept_issuance(pin).await.expect("should accept issuance");
+
+    let attestations = wallet_attestations(&mut wallet).await;
+    assert_eq!(
+        attestations.first().unwrap().attestation_type,
+        "com.example.museum_maandkaart".to_string()
+    );
+}
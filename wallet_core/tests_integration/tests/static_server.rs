@@ -1,6 +1,12 @@
 use std::assert_matches;
 
+use chrono::Days;
+use chrono::Utc;
 use crypto::PublicKey;
+use crypto::server_keys::generate::Ca;
+use crypto::trusted_list::SERVICE_TYPE_CA_QC;
+use crypto::trusted_list::mock::signed_trusted_list_xml;
+use crypto::x509::BorrowingCertificate;
 use http_utils::client::TlsPinningConfig;
 use jwt::SignedJwt;
 use jwt::error::JwtVerifyError;
@@ -10,8 +16,12 @@ use p256::pkcs8::DecodePrivateKey;
 use p256::pkcs8::EncodePrivateKey;
 use regex::regex;
 use reqwest::header::HeaderValue;
+use server_utils::trusted_list_settings::TrustedListSettings;
+use static_server::settings::WalletConfigTrustedLists;
 use tests_integration::common::*;
+use tests_integration::utils::read_file;
 use tokio::fs;
+use url::Url;
 use utils::vec_at_least::VecNonEmpty;
 use utils::vec_nonempty;
 use wallet::errors::ConfigurationError;
@@ -70,6 +80,69 @@ async fn test_wallet_config() {
     assert_matches!(result, RepositoryUpdateState::Unmodified(_));
 }
 
+#[tokio::test]
+async fn test_wallet_config_trusted_lists() {
+    let operator_ca = Ca::generate_mock();
+    let operator = operator_ca.generate_wrpac_verifier_mock().unwrap();
+    let issuer_ca = Ca::generate_issuer_mock_ca().unwrap();
+
+    let trusted_list_xml = signed_trusted_list_xml(
+        &[(SERVICE_TYPE_CA_QC, issuer_ca.certificate().as_ref())],
+        &[],
+        Utc::now().checked_add_days(Days::new(1)).unwrap(),
+        &operator,
+    );
+    let trusted_list_dir = tempfile::tempdir().unwrap();
+    let trusted_list_path = trusted_list_dir.path().join("trusted_list.xml");
+    fs::write(&trusted_list_path, trusted_list_xml).await.unwrap();
+
+    let mut served_wallet_config = default_wallet_config();
+    served_wallet_config.version = 2;
+
+    let signing_key = SigningKey::from_pkcs8_pem(&String::from_utf8_lossy(&read_file("config_signing.pem"))).unwrap();
+
+    let (mut static_settings, static_root_ca) = static_server_settings();
+    static_settings.wallet_config_jwt = config_jwt(&served_wallet_config).await.into();
+    static_settings.wallet_config_trusted_lists = Some(WalletConfigTrustedLists {
+        trusted_lists: vec![TrustedListSettings {
+            url: Url::from_file_path(&trusted_list_path).unwrap(),
+            signing_certificates: vec![operator.certificate().to_vec()],
+            issuer_service_types: vec![SERVICE_TYPE_CA_QC.to_string()],
+            wrpac_service_types: vec![],
+            wrprc_service_types: vec![],
+            allow_failing_pointers: false,
+        }],
+        signing_key: signing_key.into(),
+    });
+    let port = start_static_server(static_settings, static_root_ca.clone()).await;
+
+    let config_server_config = ConfigServerConfiguration {
+        http_config: TlsPinningConfig::try_new(local_config_base_url(port), vec_nonempty![static_root_ca]).unwrap(),
+        ..default_config_server_config()
+    };
+
+    let storage_dir = tempfile::tempdir().unwrap();
+    let http_config = HttpConfigurationRepository::new(
+        PublicKey::from(*config_server_config.signing_public_key.as_inner()).into(),
+        storage_dir.path().to_path_buf(),
+        default_wallet_config(),
+    )
+    .await
+    .unwrap();
+
+    let result = http_config.fetch(&config_server_config.http_config).await.unwrap();
+    assert_matches!(result, RepositoryUpdateState::Updated { .. });
+
+    // The served configuration should be signed again and contain the issuer CA from the trusted list.
+    let issuer_certificate = BorrowingCertificate::from_der(issuer_ca.certificate().as_ref()).unwrap();
+    assert!(http_config.get().issuer_trust_anchors.contains(&issuer_certificate));
+    assert!(
+        !default_wallet_config()
+            .issuer_trust_anchors
+            .contains(&issuer_certificate)
+    );
+}
+
 #[tokio::test]
 async fn test_wallet_config_stale() {
     let settings = WpSettings::new().expect("Could not read settings");
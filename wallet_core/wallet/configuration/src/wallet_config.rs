@@ -10,6 +10,7 @@ use chrono::DateTime;
 use chrono::Utc;
 use crypto::p256_der::DerVerifyingKey;
 use crypto::trust_anchor::TrustAnchors;
+use crypto::x509::revocation::RevocationPolicy;
 use derive_more::Debug;
 use error_category::ErrorCategory;
 use http_utils::client::TlsPinningConfig;
@@ -38,6 +39,10 @@ pub struct WalletConfiguration {
     pub wrpac_trust_anchors: TrustAnchors,
     #[debug(skip)]
     pub wrprc_trust_anchors: TrustAnchors,
+    /// If set, the revocation status of the certificates of relying parties and issuers is checked using their CRLs
+    /// and/or OCSP responders, in accordance with this policy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub certificate_revocation: Option<RevocationPolicy>,
     pub update_policy_server: UpdatePolicyServerConfiguration,
     pub google_cloud_project_number: u64,
     pub static_assets_base_url: BaseUrl,
@@ -24,6 +24,7 @@ pub struct Model {
     pub attestation_format: AttestationFormat,
     pub extended_types: ExtendedTypesModel,
     pub type_metadata: TypeMetadataModel,
+    pub credential_refresh: Option<Json>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
@@ -3,6 +3,7 @@ pub use sea_orm_migration::prelude::*;
 mod m20230425_140221_create_keyed_data_table;
 mod m20230922_095234_create_attestation_tables;
 mod m20231115_100948_create_event_tables;
+mod m20261017_000001_add_attestation_credential_refresh;
 
 pub struct Migrator;
 
@@ -12,6 +13,7 @@ impl MigratorTrait for Migrator {
             Box::new(m20230425_140221_create_keyed_data_table::Migration),
             Box::new(m20230922_095234_create_attestation_tables::Migration),
             Box::new(m20231115_100948_create_event_tables::Migration),
+            Box::new(m20261017_000001_add_attestation_credential_refresh::Migration),
         ]
     }
 }
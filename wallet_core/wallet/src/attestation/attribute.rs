@@ -1,5 +1,7 @@
+use std::collections::HashMap;
 use std::collections::HashSet;
 
+use attestation_data::attributes::AttributeValue;
 use attestation_data::attributes::Attributes;
 use attestation_data::auth::Organization;
 use attestation_types::claim_path::ClaimPath;
@@ -8,7 +10,10 @@ use indexmap::IndexMap;
 use mdoc::iso::mdocs::Entry;
 use mdoc::iso::mdocs::NameSpace;
 use sd_jwt::claims::ObjectClaims;
+use sd_jwt_vc_metadata::DisplayMetadata;
 use sd_jwt_vc_metadata::NormalizedTypeMetadata;
+use svg_sanitize::SanitizedSvg;
+use tracing::warn;
 use utils::vec_at_least::NonEmptyIterator;
 use utils::vec_at_least::VecNonEmpty;
 
@@ -18,6 +23,7 @@ use super::AttestationIdentity;
 use super::AttestationPresentation;
 use super::AttestationPresentationConfig;
 use super::AttestationValidity;
+use super::RenderedSvgTemplate;
 
 impl AttestationPresentation {
     pub(crate) fn create_from_mdoc(
@@ -128,6 +134,8 @@ impl AttestationPresentation {
             None => attributes,
         };
 
+        let svg_templates = Self::render_svg_templates(display_metadata.as_slice(), &attributes);
+
         // Finally, construct the `AttestationPresentation` type.
         Ok(AttestationPresentation {
             identity,
@@ -137,8 +145,44 @@ impl AttestationPresentation {
             issuer,
             attributes,
             validity,
+            svg_templates,
         })
     }
+
+    /// Render all of the SVG templates in the display metadata by substituting the `svg_id` placeholders with the
+    /// values of the corresponding attributes. Any template that cannot be sanitized after rendering is not included.
+    fn render_svg_templates(
+        display_metadata: &[DisplayMetadata],
+        attributes: &[AttestationAttribute],
+    ) -> Vec<RenderedSvgTemplate> {
+        let svg_values = attributes
+            .iter()
+            .filter_map(|attribute| attribute.svg_id.as_deref().map(|svg_id| (svg_id, &attribute.value)))
+            .collect::<HashMap<_, _>>();
+
+        display_metadata
+            .iter()
+            .flat_map(|display| display.svg_templates().iter().map(move |template| (display, template)))
+            .filter_map(|(display, template)| {
+                let svg = template.template.render(|svg_id| {
+                    svg_values.get(svg_id).map(|value| match value {
+                        AttributeValue::Null => String::new(),
+                        value => value.to_string(),
+                    })
+                });
+
+                let svg = SanitizedSvg::try_new(&svg)
+                    .inspect_err(|e| warn!("error sanitizing rendered SVG template, not showing: {e}"))
+                    .ok()?;
+
+                Some(RenderedSvgTemplate {
+                    locale: display.locale.clone(),
+                    properties: template.properties.clone(),
+                    svg,
+                })
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -153,6 +197,7 @@ pub mod test {
     use attestation_data::validity::ValidityWindow;
     use attestation_types::claim_path::ClaimPath;
     use attestation_types::credential_format::Format;
+    use attestation_types::data_uri::DataUri;
     use attestation_types::pid_constants::PID_ATTESTATION_TYPE;
     use attestation_types::pid_constants::PID_BSN;
     use attestation_types::pid_constants::PID_RECOVERY_CODE;
@@ -163,8 +208,11 @@ pub mod test {
     use sd_jwt_vc_metadata::ClaimMetadata;
     use sd_jwt_vc_metadata::ClaimSelectiveDisclosureMetadata;
     use sd_jwt_vc_metadata::NormalizedTypeMetadata;
+    use sd_jwt_vc_metadata::SvgTemplateOrientation;
+    use sd_jwt_vc_metadata::SvgTemplateProperties;
     use sd_jwt_vc_metadata::UncheckedTypeMetadata;
     use serde_json::json;
+    use svg_sanitize::SanitizedSvg;
     use utils::vec_nonempty;
 
     use super::super::AttestationAttribute;
@@ -172,6 +220,7 @@ pub mod test {
     use super::super::AttestationIdentity;
     use super::super::AttestationPresentation;
     use super::super::AttributesError;
+    use super::super::RenderedSvgTemplate;
     use super::super::mock::EmptyPresentationConfig;
     use crate::attestation::AttestationValidity;
     use crate::config::test::test_wallet_config;
@@ -476,6 +525,63 @@ pub mod test {
         HashSet::from_iter(vec![vec!["address".to_string(), "street".to_string()], vec!["address".to_string(), "number".to_string()]]));
     }
 
+    #[test]
+    fn test_create_from_attributes_svg_templates() {
+        let attributes = example_attributes();
+        let template = DataUri {
+            mime_type: "image/svg+xml".to_string(),
+            data: br#"<svg><text>{{name}}</text><text>{{street}} {{number}}</text><script>alert(1)</script></svg>"#
+                .to_vec(),
+        };
+        let metadata_json = json!({
+            "vct": "com.example.pid",
+            "display": [{
+                "locale": "en",
+                "name": "example",
+                "rendering": {
+                    "svg_templates": [{
+                        "uri": template.to_string(),
+                        "properties": { "orientation": "landscape" },
+                    }]
+                }
+            }],
+            "claims": [
+                { "path": ["name"], "svg_id": "name" },
+                { "path": ["birth_date"] },
+                { "path": ["address", "street"], "svg_id": "street" },
+                { "path": ["address", "number"], "svg_id": "number" },
+            ]
+        });
+        let type_metadata = NormalizedTypeMetadata::from_single_example(serde_json::from_value(metadata_json).unwrap());
+
+        let attestation_presentation = AttestationPresentation::create_from_attributes(
+            AttestationIdentity::Ephemeral,
+            Format::SdJwt,
+            type_metadata,
+            Organization::new_mock().into(),
+            AttestationValidity {
+                revocation_status: None,
+                validity_window: ValidityWindow::new_valid_mock(),
+            },
+            &attributes,
+            &EmptyPresentationConfig,
+        )
+        .expect("creating AttestationPresentation should succeed");
+
+        assert_eq!(
+            attestation_presentation.svg_templates,
+            vec![RenderedSvgTemplate {
+                locale: "en".to_string(),
+                properties: Some(SvgTemplateProperties {
+                    orientation: Some(SvgTemplateOrientation::Landscape),
+                    color_scheme: None,
+                    contrast: None,
+                }),
+                svg: SanitizedSvg::try_new("<svg><text>Wallet</text><text>Gracht 123</text></svg>").unwrap(),
+            }]
+        );
+    }
+
     #[test]
     fn test_filter_recovery_code() {
         let config = test_wallet_config();
@@ -13,8 +13,10 @@ use chrono::Utc;
 use error_category::ErrorCategory;
 use sd_jwt_vc_metadata::ClaimDisplayMetadata;
 use sd_jwt_vc_metadata::DisplayMetadata;
+use sd_jwt_vc_metadata::SvgTemplateProperties;
 use serde::Deserialize;
 use serde::Serialize;
+use svg_sanitize::SanitizedSvg;
 use token_status_list::verification::verifier::RevocationStatus;
 use utils::vec_at_least::VecNonEmpty;
 use uuid::Uuid;
@@ -68,6 +70,8 @@ pub struct AttestationPresentation {
     pub issuer: Box<Organization>,
     pub validity: AttestationValidity,
     pub attributes: Vec<AttestationAttribute>,
+    #[serde(default)]
+    pub svg_templates: Vec<RenderedSvgTemplate>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -85,6 +89,14 @@ pub struct AttestationAttribute {
     pub svg_id: Option<String>,
 }
 
+/// An SVG template from the display metadata, rendered using the attribute values of the attestation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RenderedSvgTemplate {
+    pub locale: String,
+    pub properties: Option<SvgTemplateProperties>,
+    pub svg: SanitizedSvg,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AttestationValidity {
     pub revocation_status: Option<RevocationStatus>,
@@ -189,6 +201,7 @@ pub mod mock {
                     validity_window: ValidityWindow::new_valid_mock(),
                 },
                 attributes: vec![],
+                svg_templates: vec![],
             }
         }
     }
@@ -4,6 +4,7 @@ pub mod reqwest {
 }
 
 pub mod openid4vc {
+    pub use openid4vc::credential_offer::PreAuthTransactionCode;
     pub use openid4vc::disclosure_session::VpClientError;
     pub use openid4vc::disclosure_session::VpMessageClientError;
     pub use openid4vc::disclosure_session::VpMessageClientErrorType;
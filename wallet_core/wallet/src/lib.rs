@@ -23,6 +23,7 @@ pub use wallet_account::messages::transfer::TransferSessionState;
 pub use crate::attestation::AttestationAttribute;
 pub use crate::attestation::AttestationIdentity;
 pub use crate::attestation::AttestationPresentation;
+pub use crate::attestation::RenderedSvgTemplate;
 pub use crate::attestation::ValidityStatus;
 pub use crate::notification::DisplayTarget;
 pub use crate::notification::Notification;
@@ -34,7 +35,11 @@ pub use crate::storage::WalletEvent;
 pub use crate::wallet::AttributesNotAvailable;
 pub use crate::wallet::BlockedReason;
 pub use crate::wallet::CloseProximityDisclosureUpdate;
+pub use crate::wallet::DeferredIssuancePoll;
+pub use crate::wallet::DeferredIssuancePollResults;
 pub use crate::wallet::DisclosureAttestationOptions;
+pub use crate::wallet::DisclosureAttributeIdentifier;
+pub use crate::wallet::DisclosureCredentialSet;
 pub use crate::wallet::DisclosureProposalPresentation;
 pub use crate::wallet::DisclosureUriSource;
 pub use crate::wallet::InvocationUri;
@@ -85,12 +90,17 @@ pub mod sd_jwt_vc_metadata {
     pub use sd_jwt_vc_metadata::DisplayMetadata;
     pub use sd_jwt_vc_metadata::LogoMetadata;
     pub use sd_jwt_vc_metadata::RenderingMetadata;
+    pub use sd_jwt_vc_metadata::SvgTemplateColorScheme;
+    pub use sd_jwt_vc_metadata::SvgTemplateContrast;
+    pub use sd_jwt_vc_metadata::SvgTemplateOrientation;
+    pub use sd_jwt_vc_metadata::SvgTemplateProperties;
 }
 
 pub mod utils {
     pub use http_utils::urls::BaseUrl;
     pub use update_policy_model::update_policy::VersionState;
     pub use utils::built_info::version_string;
+    pub use utils::vec_at_least::VecNonEmpty;
 }
 
 #[cfg(feature = "test")]
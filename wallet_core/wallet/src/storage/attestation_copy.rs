@@ -1,3 +1,6 @@
+use std::collections::HashSet;
+
+use attestation_data::attributes::AttributeValue;
 use attestation_data::attributes::Attributes;
 use attestation_data::auth::Organization;
 use attestation_data::auth::issuer_auth::IssuerRegistration;
@@ -7,6 +10,9 @@ use attestation_types::claim_path::ClaimPath;
 use attestation_types::credential_format::Format;
 use crypto::x509::BorrowingCertificateExtension;
 use crypto::x509::KeyIdentifier;
+use dcql::normalized::ClaimRequest;
+use indexmap::IndexMap;
+use itertools::Itertools;
 use mdoc::IssuerSigned;
 use mdoc::holder::Mdoc;
 use mdoc::holder::disclosure::MissingAttributesError;
@@ -76,16 +82,40 @@ pub enum PartialAttestation {
     },
 }
 
+/// A partial attestation from which a further subset of its attributes can be selected, which can also be converted to
+/// an [`AttestationPresentation`] that can be shown to the user.
+pub trait PartialAttestationSubset: Sized {
+    fn try_into_subset<'a>(
+        self,
+        claim_paths: impl IntoIterator<Item = &'a VecNonEmpty<ClaimPath>>,
+    ) -> Result<Self, PartialAttestationError>;
+
+    fn to_presentation(
+        &self,
+        attestation_id: Uuid,
+        normalized_metadata: NormalizedTypeMetadata,
+        issuer_organization: Box<Organization>,
+        validity: AttestationValidity,
+        config: &impl AttestationPresentationConfig,
+    ) -> AttestationPresentation;
+}
+
 /// A version of an attestation in the wallet database which contains a subset of its original attributes and whose
 /// intended purpose is disclosure. It contains the column id for the copy in the database that is its source, the
 /// partial attestation itself and an [`AttestationPresentation`] of the partial attestation that can be shown to the
 /// user for approval. This type is always derived from [`StoredAttestationCopy`].
+///
+/// Additionally, it retains the claim paths that were used to create the partial attestation and the type metadata,
+/// so that the user may choose not to disclose those attributes that are marked as optional.
 #[derive(Debug, Clone)]
-#[cfg_attr(test, derive(derive_more::Constructor))]
 pub struct DisclosableAttestation<P> {
+    attestation_id: Uuid,
     attestation_copy_id: Uuid,
     partial_attestation: P,
     presentation: AttestationPresentation,
+    normalized_metadata: NormalizedTypeMetadata,
+    claim_paths: Vec<VecNonEmpty<ClaimPath>>,
+    optional_attributes: IndexMap<VecNonEmpty<String>, VecNonEmpty<ClaimPath>>,
 }
 
 fn attestation_presentation_from_issuer_signed(
@@ -182,15 +212,21 @@ impl StoredAttestationCopy {
             })
     }
 
-    /// Checks if the stored attestation matches a list of claim paths.
-    pub fn matches_requested_attributes<'a, 'b>(
-        &'a self,
-        claim_paths: impl IntoIterator<Item = &'b VecNonEmpty<ClaimPath>>,
-    ) -> bool {
+    /// Checks if the stored attestation contains all of the requested claims. For claims that specify values, the
+    /// value of the attribute should also match any of these.
+    pub fn matches_requested_claims(&self, claims: &[ClaimRequest]) -> bool {
+        fn value_matches(attribute_value: &AttributeValue, claim: &ClaimRequest) -> bool {
+            claim.values.is_empty() || claim.values.iter().any(|value| attribute_value == value)
+        }
+
         match &self.attestation {
-            StoredAttestation::MsoMdoc { mdoc } => {
-                mdoc.issuer_signed().matches_requested_attributes(claim_paths).is_ok()
-            }
+            StoredAttestation::MsoMdoc { mdoc } => claims.iter().all(|claim| {
+                mdoc.issuer_signed().attribute_value(&claim.path).is_some_and(|value| {
+                    claim.values.is_empty()
+                        || AttributeValue::try_from(value.clone())
+                            .is_ok_and(|attribute_value| value_matches(&attribute_value, claim))
+                })
+            }),
             StoredAttestation::SdJwt { sd_jwt, .. } => {
                 // TODO VerifiedSdJwt should have a way to directly check if paths are present (PVW-4998)
                 // Convert to Attributes to check if the paths are all present.
@@ -200,7 +236,13 @@ impl StoredAttestationCopy {
                     .try_into()
                     .expect("a stored SD-JWT attestation should have decoded claims");
 
-                attributes.has_claim_paths(claim_paths)
+                claims.iter().all(|claim| {
+                    attributes
+                        .get(&claim.path)
+                        .ok()
+                        .flatten()
+                        .is_some_and(|attribute_value| value_matches(attribute_value, claim))
+                })
             }
         }
     }
@@ -302,6 +344,90 @@ impl PartialAttestation {
     }
 }
 
+impl PartialAttestationSubset for PartialMdoc {
+    fn try_into_subset<'a>(
+        self,
+        claim_paths: impl IntoIterator<Item = &'a VecNonEmpty<ClaimPath>>,
+    ) -> Result<Self, PartialAttestationError> {
+        let partial_mdoc = self.try_into_attribute_subset(claim_paths)?;
+
+        Ok(partial_mdoc)
+    }
+
+    fn to_presentation(
+        &self,
+        attestation_id: Uuid,
+        normalized_metadata: NormalizedTypeMetadata,
+        issuer_organization: Box<Organization>,
+        validity: AttestationValidity,
+        config: &impl AttestationPresentationConfig,
+    ) -> AttestationPresentation {
+        attestation_presentation_from_issuer_signed(
+            self.issuer_signed().clone(),
+            attestation_id,
+            normalized_metadata,
+            issuer_organization,
+            validity,
+            config,
+        )
+    }
+}
+
+impl PartialAttestationSubset for PartialAttestation {
+    fn try_into_subset<'a>(
+        self,
+        claim_paths: impl IntoIterator<Item = &'a VecNonEmpty<ClaimPath>>,
+    ) -> Result<Self, PartialAttestationError> {
+        let partial_attestation = match self {
+            PartialAttestation::MsoMdoc { partial_mdoc } => PartialAttestation::MsoMdoc {
+                partial_mdoc: Box::new((*partial_mdoc).try_into_subset(claim_paths)?),
+            },
+            PartialAttestation::SdJwt { key_identifier, sd_jwt } => {
+                let unsigned_presentation = claim_paths
+                    .into_iter()
+                    .try_fold((*sd_jwt).into_presentation_builder(), |builder, claim_path| {
+                        builder.disclose(claim_path)
+                    })?
+                    .finish();
+
+                PartialAttestation::SdJwt {
+                    key_identifier,
+                    sd_jwt: Box::new(unsigned_presentation),
+                }
+            }
+        };
+
+        Ok(partial_attestation)
+    }
+
+    fn to_presentation(
+        &self,
+        attestation_id: Uuid,
+        normalized_metadata: NormalizedTypeMetadata,
+        issuer_organization: Box<Organization>,
+        validity: AttestationValidity,
+        config: &impl AttestationPresentationConfig,
+    ) -> AttestationPresentation {
+        match self {
+            PartialAttestation::MsoMdoc { partial_mdoc } => partial_mdoc.to_presentation(
+                attestation_id,
+                normalized_metadata,
+                issuer_organization,
+                validity,
+                config,
+            ),
+            PartialAttestation::SdJwt { sd_jwt, .. } => attestation_presentation_from_sd_jwt(
+                sd_jwt.as_ref().as_ref(),
+                attestation_id,
+                normalized_metadata,
+                issuer_organization,
+                validity,
+                config,
+            ),
+        }
+    }
+}
+
 impl DisclosableAttestation<PartialAttestation> {
     pub fn try_new<'a>(
         attestation_copy: StoredAttestationCopy,
@@ -318,38 +444,122 @@ impl DisclosableAttestation<PartialAttestation> {
             ..
         } = attestation_copy;
 
+        let claim_paths = claim_paths.into_iter().cloned().collect_vec();
         let issuer_registration = attestation.issuer_registration();
-        let partial_attestation = PartialAttestation::try_new(attestation, claim_paths)?;
+        let partial_attestation = PartialAttestation::try_new(attestation, &claim_paths)?;
 
-        let presentation = match &partial_attestation {
-            PartialAttestation::MsoMdoc { partial_mdoc } => attestation_presentation_from_issuer_signed(
-                partial_mdoc.issuer_signed().clone(),
-                attestation_id,
-                normalized_metadata,
-                issuer_registration.organization,
-                AttestationValidity {
-                    revocation_status,
-                    validity_window,
-                },
-                presentation_config,
-            ),
-            PartialAttestation::SdJwt { sd_jwt, .. } => attestation_presentation_from_sd_jwt(
-                sd_jwt.as_ref().as_ref(),
-                attestation_id,
-                normalized_metadata,
-                issuer_registration.organization,
-                AttestationValidity {
-                    revocation_status,
-                    validity_window,
-                },
-                presentation_config,
-            ),
-        };
+        let presentation = partial_attestation.to_presentation(
+            attestation_id,
+            normalized_metadata.clone(),
+            issuer_registration.organization,
+            AttestationValidity {
+                revocation_status,
+                validity_window,
+            },
+            presentation_config,
+        );
 
         let disclosable_attestation = Self {
+            attestation_id,
             attestation_copy_id,
             partial_attestation,
             presentation,
+            normalized_metadata,
+            claim_paths,
+            optional_attributes: IndexMap::new(),
+        };
+
+        Ok(disclosable_attestation)
+    }
+}
+
+impl<P> DisclosableAttestation<P>
+where
+    P: PartialAttestationSubset + Clone,
+{
+    /// Marks the provided claim paths as optional, which means that the user may choose not to disclose them. Only
+    /// those claim paths that correspond to exactly one attribute in the [`AttestationPresentation`] are retained, as
+    /// this allows the user to de-select them on a per-attribute basis. Any other claim paths will always be disclosed.
+    pub fn with_optional_claim_paths<'a>(
+        mut self,
+        optional_claim_paths: impl IntoIterator<Item = &'a VecNonEmpty<ClaimPath>>,
+        presentation_config: &impl AttestationPresentationConfig,
+    ) -> Self {
+        let optional_attributes = optional_claim_paths
+            .into_iter()
+            .filter_map(|claim_path| {
+                // Determine which attributes are no longer present when leaving out the claim path. Since the claim
+                // path is present in the partial attestation, this should not result in an error.
+                let attestation = self
+                    .try_without_claim_paths([claim_path], presentation_config)
+                    .expect("optional claim path should be present in partial attestation");
+
+                let remaining_keys = attestation
+                    .presentation
+                    .attributes
+                    .iter()
+                    .map(|attribute| &attribute.key)
+                    .collect::<HashSet<_>>();
+
+                let attribute_key = self
+                    .presentation
+                    .attributes
+                    .iter()
+                    .map(|attribute| &attribute.key)
+                    .filter(|key| !remaining_keys.contains(key))
+                    .exactly_one()
+                    .ok()?;
+
+                Some((attribute_key.clone(), claim_path.clone()))
+            })
+            .collect();
+
+        self.optional_attributes = optional_attributes;
+
+        self
+    }
+
+    /// Returns a new [`DisclosableAttestation`] from which the attributes covered by the provided claim paths have
+    /// been removed, including an updated [`AttestationPresentation`].
+    pub fn try_without_claim_paths<'a>(
+        &self,
+        removed_claim_paths: impl IntoIterator<Item = &'a VecNonEmpty<ClaimPath>>,
+        presentation_config: &impl AttestationPresentationConfig,
+    ) -> Result<Self, PartialAttestationError> {
+        let removed_claim_paths = removed_claim_paths.into_iter().collect::<HashSet<_>>();
+
+        let claim_paths = self
+            .claim_paths
+            .iter()
+            .filter(|claim_path| !removed_claim_paths.contains(claim_path))
+            .cloned()
+            .collect_vec();
+
+        let partial_attestation = self.partial_attestation.clone().try_into_subset(&claim_paths)?;
+
+        let presentation = partial_attestation.to_presentation(
+            self.attestation_id,
+            self.normalized_metadata.clone(),
+            self.presentation.issuer.clone(),
+            self.presentation.validity.clone(),
+            presentation_config,
+        );
+
+        let optional_attributes = self
+            .optional_attributes
+            .iter()
+            .filter(|(_, claim_path)| !removed_claim_paths.contains(claim_path))
+            .map(|(key, claim_path)| (key.clone(), claim_path.clone()))
+            .collect();
+
+        let disclosable_attestation = Self {
+            attestation_id: self.attestation_id,
+            attestation_copy_id: self.attestation_copy_id,
+            partial_attestation,
+            presentation,
+            normalized_metadata: self.normalized_metadata.clone(),
+            claim_paths,
+            optional_attributes,
         };
 
         Ok(disclosable_attestation)
@@ -374,9 +584,13 @@ impl<P> DisclosableAttestation<P> {
         map: impl FnOnce(P) -> Result<T, E>,
     ) -> Result<DisclosableAttestation<T>, E> {
         Ok(DisclosableAttestation {
+            attestation_id: self.attestation_id,
             attestation_copy_id: self.attestation_copy_id,
             partial_attestation: map(self.partial_attestation)?,
             presentation: self.presentation,
+            normalized_metadata: self.normalized_metadata,
+            claim_paths: self.claim_paths,
+            optional_attributes: self.optional_attributes,
         })
     }
 
@@ -387,6 +601,19 @@ impl<P> DisclosableAttestation<P> {
     pub fn into_presentation(self) -> AttestationPresentation {
         self.presentation
     }
+
+    /// Returns the keys of the attributes in the [`AttestationPresentation`] that the user may choose not to disclose.
+    pub fn optional_attribute_keys(&self) -> impl Iterator<Item = &VecNonEmpty<String>> {
+        self.optional_attributes.keys()
+    }
+
+    /// Returns the claim path that corresponds to an optional attribute, if that attribute is indeed optional.
+    pub fn optional_attribute_claim_path(
+        &self,
+        attribute_key: &VecNonEmpty<String>,
+    ) -> Option<&VecNonEmpty<ClaimPath>> {
+        self.optional_attributes.get(attribute_key)
+    }
 }
 
 #[cfg(test)]
@@ -410,6 +637,20 @@ mod test {
             }
         }
     }
+
+    impl<P> DisclosableAttestation<P> {
+        pub fn new(attestation_copy_id: Uuid, partial_attestation: P, presentation: AttestationPresentation) -> Self {
+            Self {
+                attestation_id: Uuid::new_v4(),
+                attestation_copy_id,
+                partial_attestation,
+                presentation,
+                normalized_metadata: NormalizedTypeMetadata::empty_example(),
+                claim_paths: Vec::new(),
+                optional_attributes: IndexMap::new(),
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -425,11 +666,14 @@ mod tests {
     use attestation_types::credential_format::Format;
     use attestation_types::pid_constants::PID_ATTESTATION_TYPE;
     use attestation_types::pid_constants::PID_BSN;
+    use attestation_types::pid_constants::PID_GIVEN_NAME;
     use attestation_types::status_claim::StatusClaim;
     use chrono::Utc;
     use crypto::PublicKey;
     use crypto::server_keys::KeyPair;
     use crypto::server_keys::generate::Ca;
+    use dcql::ClaimsQueryValue;
+    use dcql::normalized::ClaimRequest;
     use futures::FutureExt;
     use itertools::Itertools;
     use mdoc::holder::Mdoc;
@@ -528,11 +772,35 @@ mod tests {
             assert_eq!(full_issuer_registration, issuer_registration);
 
             // The attestation should contain the BSN attribute path.
-            assert!(attestation_copy.matches_requested_attributes([&bsn_path]));
+            let bsn_claim = ClaimRequest {
+                path: bsn_path.clone(),
+                values: vec![],
+            };
+            assert!(attestation_copy.matches_requested_claims(std::slice::from_ref(&bsn_claim)));
 
             // The attestation should not contain some incorrect path.
             let missing_path = vec_nonempty![ClaimPath::SelectByKey("missing".to_string())];
-            assert!(!attestation_copy.matches_requested_attributes([&missing_path]));
+            let missing_claim = ClaimRequest {
+                path: missing_path.clone(),
+                values: vec![],
+            };
+            assert!(!attestation_copy.matches_requested_claims(&[bsn_claim.clone(), missing_claim]));
+
+            // The BSN attribute should only match if its value is one of the requested values.
+            let matching_bsn_claim = ClaimRequest {
+                values: vec![
+                    ClaimsQueryValue::String("123456789".to_string()),
+                    ClaimsQueryValue::String("999991772".to_string()),
+                ],
+                ..bsn_claim.clone()
+            };
+            assert!(attestation_copy.matches_requested_claims(&[matching_bsn_claim]));
+
+            let mismatching_bsn_claim = ClaimRequest {
+                values: vec![ClaimsQueryValue::String("123456789".to_string())],
+                ..bsn_claim
+            };
+            assert!(!attestation_copy.matches_requested_claims(&[mismatching_bsn_claim]));
 
             // The converted `AttestationPresentation` contains multiple attributes.
             let full_presentation = attestation_copy
@@ -577,4 +845,83 @@ mod tests {
             assert_eq!(mdoc_presentation.attributes, sd_jwt_presentation.attributes);
         }
     }
+
+    #[test]
+    fn test_disclosable_attestation_optional_claim_paths() {
+        let wallet_config = test_wallet_config();
+        let ca = Ca::generate_issuer_mock_ca().unwrap();
+        let issuer_keypair = generate_issuer_mock_with_registration(&ca, &IssuerRegistration::new_mock()).unwrap();
+
+        for (attestation_copy, bsn_path) in [
+            mdoc_stored_attestation_copy(&issuer_keypair),
+            sd_jwt_stored_attestation_copy(&issuer_keypair),
+        ] {
+            let given_name_path: VecNonEmpty<ClaimPath> = bsn_path
+                .clone()
+                .into_inner()
+                .into_iter()
+                .map(|claim_path| match claim_path {
+                    ClaimPath::SelectByKey(key) if key == PID_BSN => ClaimPath::SelectByKey(PID_GIVEN_NAME.to_string()),
+                    claim_path => claim_path,
+                })
+                .collect_vec()
+                .try_into()
+                .unwrap();
+
+            let disclosable_attestation = DisclosableAttestation::try_new(
+                attestation_copy,
+                [&bsn_path, &given_name_path],
+                &wallet_config.pid_attributes,
+            )
+            .expect("converting the full attestation copy to a partial one should succeed")
+            .with_optional_claim_paths([&given_name_path], &wallet_config.pid_attributes);
+
+            // The given name should be the only optional attribute, which is linked to its claim path.
+            let given_name_key = vec_nonempty![PID_GIVEN_NAME.to_string()];
+            assert_eq!(
+                disclosable_attestation.optional_attribute_keys().collect_vec(),
+                vec![&given_name_key]
+            );
+            assert_eq!(
+                disclosable_attestation.optional_attribute_claim_path(&given_name_key),
+                Some(&given_name_path)
+            );
+            assert_eq!(
+                disclosable_attestation.optional_attribute_claim_path(&vec_nonempty![PID_BSN.to_string()]),
+                None
+            );
+            assert_eq!(disclosable_attestation.presentation().attributes.len(), 2);
+
+            // Removing the optional claim path should result in both the partial attestation and the presentation
+            // containing only the BSN, with no optional attributes remaining.
+            let reduced_attestation = disclosable_attestation
+                .try_without_claim_paths([&given_name_path], &wallet_config.pid_attributes)
+                .expect("removing an optional claim path should succeed");
+
+            assert_eq!(
+                reduced_attestation.attestation_copy_id(),
+                disclosable_attestation.attestation_copy_id()
+            );
+            assert_eq!(
+                reduced_attestation
+                    .presentation()
+                    .attributes
+                    .iter()
+                    .map(|attribute| &attribute.key)
+                    .collect_vec(),
+                vec![&vec_nonempty![PID_BSN.to_string()]]
+            );
+            assert_eq!(reduced_attestation.optional_attribute_keys().count(), 0);
+
+            match reduced_attestation.partial_attestation() {
+                PartialAttestation::MsoMdoc { partial_mdoc } => {
+                    assert!(partial_mdoc.issuer_signed().attribute_value(&bsn_path).is_some());
+                    assert!(partial_mdoc.issuer_signed().attribute_value(&given_name_path).is_none());
+                }
+                PartialAttestation::SdJwt { sd_jwt, .. } => {
+                    assert_eq!(sd_jwt.as_ref().as_ref().disclosures().len(), 1);
+                }
+            }
+        }
+    }
 }
@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::io;
 use std::path::PathBuf;
 
 use attestation_data::auth::Organization;
@@ -33,6 +34,7 @@ use mdoc::utils::serialization::cbor_serialize;
 use openid4vc::wallet_issuance::credential::CredentialWithMetadata;
 use openid4vc::wallet_issuance::credential::IssuedCredentialCopies;
 use openid4vc::wallet_issuance::credential::SdJwtCopy;
+use openid4vc::wallet_issuance::refresh::CredentialRefresh;
 use platform_support::hw_keystore::PlatformEncryptionKey;
 use sd_jwt::sd_jwt::VerifiedSdJwt;
 use sea_orm::ActiveModelTrait;
@@ -85,6 +87,7 @@ use super::database::Database;
 use super::database::SqliteUrl;
 use super::event_log::WalletEvent;
 use super::key_file;
+use super::key_file::KeyFileError;
 use super::sql_cipher_key::SqlCipherKey;
 use crate::AttestationIdentity;
 use crate::AttestationPresentation;
@@ -94,12 +97,18 @@ use crate::storage::revocation_info::RevocationInfo;
 const KEY_FILE_SUFFIX: &str = "_db";
 const DATABASE_FILE_EXT: &str = "db";
 const KEY_IDENTIFIER_PREFIX: &str = "keyfile_";
+const DPOP_KEY_FILE_INFIX: &str = "_dpop_";
 
 fn key_file_alias_for_name(database_name: &str) -> String {
     // Append suffix to database name to get key file alias
     format!("{database_name}{KEY_FILE_SUFFIX}")
 }
 
+fn dpop_key_file_alias(database_name: &str, identifier: &str) -> String {
+    // Combine database name and DPoP key identifier to get key file alias
+    format!("{database_name}{DPOP_KEY_FILE_INFIX}{identifier}")
+}
+
 fn key_identifier_for_key_file(alias: &str) -> String {
     format!("{KEY_IDENTIFIER_PREFIX}{alias}")
 }
@@ -211,6 +220,12 @@ impl<K> DatabaseStorage<K> {
         Ok(database)
     }
 
+    fn key_file_key(&self) -> StorageResult<&K> {
+        let key_file_key = &self.open_database.as_ref().ok_or(StorageError::NotOpened)?.key_file_key;
+
+        Ok(key_file_key)
+    }
+
     fn database_path_for_name(&self) -> PathBuf {
         let name = &self.database_name;
 
@@ -648,6 +663,13 @@ where
                 warn!("Could not delete database key file: {}", error);
             }
 
+            // The DPoP key files are encrypted using the same key as the database key file, so remove these as well.
+            let dpop_key_file_prefix = dpop_key_file_alias(&self.database_name, "");
+            if let Err(error) = key_file::delete_key_files_with_prefix(&self.storage_path, &dpop_key_file_prefix).await
+            {
+                warn!("Could not delete DPoP key files: {}", error);
+            }
+
             if let Err(error) = open_database.key_file_key.delete().await {
                 warn!("Could not delete database key file key: {}", error);
             }
@@ -712,6 +734,32 @@ where
         Ok(())
     }
 
+    /// Store a DPoP private key in a key file, encrypted using the same platform key as the database key file.
+    async fn insert_dpop_key(&mut self, identifier: &str, key: &[u8]) -> StorageResult<()> {
+        let alias = dpop_key_file_alias(&self.database_name, identifier);
+        key_file::write_key_file(&self.storage_path, &alias, self.key_file_key()?, key).await?;
+
+        Ok(())
+    }
+
+    /// Read and decrypt a DPoP private key from its key file, if present.
+    async fn fetch_dpop_key(&self, identifier: &str) -> StorageResult<Option<Vec<u8>>> {
+        let alias = dpop_key_file_alias(&self.database_name, identifier);
+        let key = key_file::read_key_file(&self.storage_path, &alias, self.key_file_key()?).await?;
+
+        Ok(key)
+    }
+
+    /// Remove the key file of a DPoP private key, if present.
+    async fn delete_dpop_key(&mut self, identifier: &str) -> StorageResult<()> {
+        let alias = dpop_key_file_alias(&self.database_name, identifier);
+
+        match key_file::delete_key_file(&self.storage_path, &alias).await {
+            Err(KeyFileError::Io(error)) if error.kind() == io::ErrorKind::NotFound => Ok(()),
+            result => Ok(result?),
+        }
+    }
+
     async fn insert_credentials(
         &mut self,
         timestamp: DateTime<Utc>,
@@ -737,6 +785,8 @@ where
                         not_before,
                         extended_attestation_types,
                         metadata_documents,
+                        refresh,
+                        ..
                     },
                     attestation_presentation,
                 )| {
@@ -750,6 +800,7 @@ where
                         not_before: Set(not_before.map(Into::into)),
                         extended_types: Set(ExtendedTypesModel::new(extended_attestation_types)),
                         type_metadata: Set(TypeMetadataModel::new(metadata_documents)),
+                        credential_refresh: Set(refresh.map(serde_json::to_value).transpose()?),
                     };
 
                     let copy_models = create_attestation_copy_models(attestation_id, copies)?;
@@ -794,7 +845,11 @@ where
     async fn update_credentials(
         &mut self,
         timestamp: DateTime<Utc>,
-        credentials: Vec<(IssuedCredentialCopies, AttestationPresentation)>,
+        credentials: Vec<(
+            IssuedCredentialCopies,
+            Option<CredentialRefresh>,
+            AttestationPresentation,
+        )>,
     ) -> StorageResult<()> {
         let issuance_event_id = Uuid::now_v7();
 
@@ -807,7 +862,7 @@ where
 
         let mut issuance_event_attestations = Vec::with_capacity(credentials.len());
 
-        for (copies, attestation_presentation) in credentials {
+        for (copies, refresh, attestation_presentation) in credentials {
             let AttestationIdentity::Fixed { id: attestation_id } = attestation_presentation.identity else {
                 return Err(StorageError::EventEphemeralIdentity);
             };
@@ -829,6 +884,16 @@ where
             attestation_copy::Entity::insert_many(create_attestation_copy_models(attestation_id, copies)?)
                 .exec(&transaction)
                 .await?;
+
+            // Any previous refresh token is replaced, as the issuer does not accept these more than once.
+            attestation::Entity::update_many()
+                .col_expr(
+                    attestation::Column::CredentialRefresh,
+                    Expr::value(refresh.map(serde_json::to_value).transpose()?),
+                )
+                .filter(attestation::Column::Id.eq(attestation_id))
+                .exec(&transaction)
+                .await?;
         }
 
         issuance_event::Entity::insert(issuance_event)
@@ -856,6 +921,55 @@ where
         Ok(())
     }
 
+    async fn fetch_attestation_refreshes_due(
+        &self,
+        expires_before: DateTime<Utc>,
+        max_unused_copies_ratio: f64,
+    ) -> StorageResult<Vec<(Uuid, CredentialRefresh)>> {
+        let unused_copy_count = Expr::expr(Func::sum(
+            Expr::col((attestation_copy::Entity, attestation_copy::Column::DisclosureCount)).eq(0),
+        ));
+        let copy_count = Expr::expr(Func::count(Expr::col((
+            attestation_copy::Entity,
+            attestation_copy::Column::Id,
+        ))));
+
+        let refreshes = attestation::Entity::find()
+            .select_only()
+            .inner_join(attestation_copy::Entity)
+            .column(attestation::Column::Id)
+            .column(attestation::Column::CredentialRefresh)
+            .filter(attestation::Column::CredentialRefresh.is_not_null())
+            .group_by(attestation::Column::Id)
+            .having(
+                Condition::any()
+                    .add(unused_copy_count.lte(copy_count.mul(max_unused_copies_ratio)))
+                    .add(attestation::Column::Expiration.lt(expires_before)),
+            )
+            .order_by(attestation::Column::Id, Order::Asc)
+            .into_tuple::<(Uuid, Json)>()
+            .all(self.database()?.connection())
+            .await?
+            .into_iter()
+            .map(|(attestation_id, refresh)| Ok((attestation_id, serde_json::from_value(refresh)?)))
+            .collect::<Result<_, StorageError>>()?;
+
+        Ok(refreshes)
+    }
+
+    async fn delete_attestation_refreshes(&mut self, attestation_ids: Vec<Uuid>) -> StorageResult<()> {
+        attestation::Entity::update_many()
+            .col_expr(
+                attestation::Column::CredentialRefresh,
+                Expr::value(Option::<Json>::None),
+            )
+            .filter(attestation::Column::Id.is_in(attestation_ids))
+            .exec(self.database()?.connection())
+            .await?;
+
+        Ok(())
+    }
+
     async fn has_any_attestations_with_credential_kinds(
         &self,
         credential_kinds: &HashSet<CredentialKind>,
@@ -1340,6 +1454,7 @@ fn create_attestation_copy_models(
 fn determine_revocation_status(revocation_statuses: &[Option<RevocationStatus>]) -> Option<RevocationStatus> {
     let mut has_valid = false;
     let mut has_invalid = false;
+    let mut has_suspended = false;
     let mut has_undetermined = false;
     let mut has_any_checked = false;
 
@@ -1353,6 +1468,10 @@ fn determine_revocation_status(revocation_statuses: &[Option<RevocationStatus>])
                 has_invalid = true;
                 has_any_checked = true;
             }
+            Some(RevocationStatus::Suspended) => {
+                has_suspended = true;
+                has_any_checked = true;
+            }
             Some(RevocationStatus::Undetermined) => {
                 has_undetermined = true;
                 has_any_checked = true;
@@ -1384,12 +1503,17 @@ fn determine_revocation_status(revocation_statuses: &[Option<RevocationStatus>])
         return Some(RevocationStatus::Revoked);
     }
 
-    // 3. Undetermined if one copy is Undetermined and no copy is Valid | Invalid
+    // 3. Suspended if one copy is Suspended and no copy is Valid | Invalid
+    if has_suspended {
+        return Some(RevocationStatus::Suspended);
+    }
+
+    // 4. Undetermined if one copy is Undetermined and no copy is Valid | Invalid | Suspended
     if has_undetermined {
         return Some(RevocationStatus::Undetermined);
     }
 
-    // 4. Corrupted if all checked copies are Corrupted
+    // 5. Corrupted if all checked copies are Corrupted
     Some(RevocationStatus::Corrupted)
 }
 
@@ -1641,6 +1765,38 @@ pub(crate) mod tests {
         assert_eq!(String::from("wallet123"), imported_registration_data.unwrap().wallet_id);
     }
 
+    #[tokio::test]
+    async fn test_dpop_keys() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let mut storage = DatabaseStorage::open_file(tempdir.path().to_path_buf()).await;
+
+        let key = random_bytes(32);
+
+        assert!(storage.fetch_dpop_key("dpop_key").await.unwrap().is_none());
+
+        storage.insert_dpop_key("dpop_key", &key).await.unwrap();
+
+        assert_eq!(storage.fetch_dpop_key("dpop_key").await.unwrap(), Some(key.clone()));
+
+        // Deleting a DPoP key should be idempotent.
+        storage.delete_dpop_key("dpop_key").await.unwrap();
+        storage.delete_dpop_key("dpop_key").await.unwrap();
+
+        assert!(storage.fetch_dpop_key("dpop_key").await.unwrap().is_none());
+
+        // Clearing the storage should remove any remaining DPoP keys.
+        storage.insert_dpop_key("dpop_key", &key).await.unwrap();
+        storage.clear().await;
+
+        assert!(!std::fs::read_dir(tempdir.path()).unwrap().any(|entry| {
+            entry
+                .unwrap()
+                .file_name()
+                .to_string_lossy()
+                .contains(DPOP_KEY_FILE_INFIX)
+        }));
+    }
+
     // Regression test for the in-memory storage teardown bug seen in the integration tests
     // (`no such table: keyed_data` during registration). In that flow the pool discarded its only
     // connection — e.g. when the background revocation task was aborted mid-query — which, for a
@@ -2699,7 +2855,7 @@ pub(crate) mod tests {
 
         // Update sd_jwt
         storage
-            .update_credentials(Utc::now(), vec![(issued_copies, attestation_presentation)])
+            .update_credentials(Utc::now(), vec![(issued_copies, None, attestation_presentation)])
             .await
             .expect("Could not update sd-jwts");
 
@@ -3225,6 +3381,84 @@ pub(crate) mod tests {
         assert_eq!(key_identifiers, vec!["test_key_id".to_string()]);
     }
 
+    #[tokio::test]
+    async fn test_fetch_and_delete_attestation_refreshes() {
+        let mut storage = MockHardwareDatabaseStorage::open_in_memory().await;
+
+        let sd_jwt = SignedSdJwt::pid_example(&ISSUER_KEY, &PublicKey::from(*SigningKey::generate().verifying_key()))
+            .into_verified();
+        let expiration: DateTime<Utc> = sd_jwt.claims().exp.unwrap().into();
+
+        // Insert two attestations with four copies each, of which only the first has a refresh token.
+        for refresh in [Some(CredentialRefresh::new_mock("refresh_token")), None] {
+            let issued_copies = IssuedCredentialCopies::SdJwt(vec_nonempty![
+                SdJwtCopy { key_identifier: random_string(16), sd_jwt: sd_jwt.clone() }; 4
+            ]);
+            let credential = CredentialWithMetadata::new(
+                issued_copies,
+                sd_jwt.claims().vct.clone(),
+                sd_jwt.claims().exp,
+                sd_jwt.claims().nbf,
+                NormalizedTypeMetadata::nl_pid_example().extended_vcts(),
+                VerifiedTypeMetadataDocuments::nl_pid_example(),
+            )
+            .with_refresh(refresh);
+
+            storage
+                .insert_credentials(Utc::now(), vec![(credential, AttestationPresentation::new_mock())])
+                .await
+                .expect("Could not insert credential");
+        }
+
+        let fetch_refreshes = async |storage: &MockHardwareDatabaseStorage, expires_before| {
+            storage
+                .fetch_attestation_refreshes_due(expires_before, 0.5)
+                .await
+                .expect("Could not fetch attestation refreshes")
+        };
+
+        // No attestation is due while all copies are unused and the attestation does not expire soon.
+        assert!(fetch_refreshes(&storage, Utc::now()).await.is_empty());
+
+        // The attestation with the refresh token is due when it expires soon.
+        let refreshes = fetch_refreshes(&storage, expiration + Duration::days(1)).await;
+        assert_eq!(refreshes.len(), 1);
+        let (attestation_id, refresh) = refreshes.into_iter().next().unwrap();
+        assert_eq!(refresh.refresh_token.as_ref(), "refresh_token");
+
+        // It is also due once half of its copies have been used.
+        for _ in 0..2 {
+            let copy_id = storage
+                .fetch_unique_attestations()
+                .await
+                .expect("Could not fetch unique attestations")
+                .into_iter()
+                .find(|copy| copy.attestation_id == attestation_id)
+                .unwrap()
+                .attestation_copy_id;
+
+            storage
+                .increment_attestation_copies_usage_count(vec![copy_id])
+                .await
+                .expect("Could not increment usage count");
+        }
+
+        let refreshes = fetch_refreshes(&storage, Utc::now()).await;
+        assert_eq!(refreshes.iter().map(|(id, _)| *id).collect_vec(), vec![attestation_id]);
+
+        // After deleting the refresh token, the attestation is no longer returned.
+        storage
+            .delete_attestation_refreshes(vec![attestation_id])
+            .await
+            .expect("Could not delete attestation refreshes");
+
+        assert!(
+            fetch_refreshes(&storage, expiration + Duration::days(1))
+                .await
+                .is_empty()
+        );
+    }
+
     #[tokio::test]
     async fn test_delete_attestation() {
         let mut storage = MockHardwareDatabaseStorage::open_in_memory().await;
@@ -3325,7 +3559,7 @@ pub(crate) mod tests {
         }]);
 
         storage
-            .update_credentials(Utc::now(), vec![(issued_copies, renewed_presentation.clone())])
+            .update_credentials(Utc::now(), vec![(issued_copies, None, renewed_presentation.clone())])
             .await
             .expect("Could not update credentials");
 
@@ -3368,7 +3602,13 @@ pub(crate) mod tests {
     )]
     #[case(vec![Some(RevocationStatus::Undetermined), Some(RevocationStatus::Revoked), Some(RevocationStatus::Corrupted)], Some(RevocationStatus::Revoked)
     )]
-    // Rule 3: Undetermined if one copy is Undetermined and no copy is Valid | Invalid
+    #[case(vec![Some(RevocationStatus::Suspended), Some(RevocationStatus::Revoked)], Some(RevocationStatus::Revoked))]
+    // Rule 3: Suspended if one copy is Suspended and no copy is Valid | Invalid
+    #[case(vec![Some(RevocationStatus::Suspended)], Some(RevocationStatus::Suspended))]
+    #[case(vec![Some(RevocationStatus::Suspended), Some(RevocationStatus::Valid)], Some(RevocationStatus::Valid))]
+    #[case(vec![Some(RevocationStatus::Undetermined), Some(RevocationStatus::Suspended), None], Some(RevocationStatus::Suspended)
+    )]
+    // Rule 4: Undetermined if one copy is Undetermined and no copy is Valid | Invalid | Suspended
     #[case(vec![Some(RevocationStatus::Undetermined)], Some(RevocationStatus::Undetermined))]
     #[case(vec![Some(RevocationStatus::Undetermined), Some(RevocationStatus::Corrupted)], Some(RevocationStatus::Undetermined)
     )]
@@ -3376,7 +3616,7 @@ pub(crate) mod tests {
     )]
     #[case(vec![Some(RevocationStatus::Undetermined), Some(RevocationStatus::Undetermined)], Some(RevocationStatus::Undetermined)
     )]
-    // Rule 4: Corrupted if all copies are Corrupted
+    // Rule 5: Corrupted if all copies are Corrupted
     #[case(vec![Some(RevocationStatus::Corrupted)], Some(RevocationStatus::Corrupted))]
     #[case(vec![Some(RevocationStatus::Corrupted), Some(RevocationStatus::Corrupted)], Some(RevocationStatus::Corrupted)
     )]
@@ -31,6 +31,33 @@ pub async fn get_or_create_key_file(
     .await
 }
 
+pub async fn write_key_file(
+    storage_path: &Path,
+    alias: &str,
+    encryption_key: &impl SecureEncryptionKey,
+    contents: &[u8],
+) -> Result<(), KeyFileError> {
+    let path = path_for_key_file(storage_path, alias);
+
+    write_encrypted_file(path.as_path(), contents, encryption_key).await
+}
+
+pub async fn read_key_file(
+    storage_path: &Path,
+    alias: &str,
+    encryption_key: &impl SecureEncryptionKey,
+) -> Result<Option<Vec<u8>>, KeyFileError> {
+    let path = path_for_key_file(storage_path, alias);
+
+    if !fs::try_exists(&path).await? {
+        return Ok(None);
+    }
+
+    let contents = read_encrypted_file(path.as_path(), encryption_key).await?;
+
+    Ok(Some(contents))
+}
+
 pub async fn delete_key_file(storage_path: &Path, alias: &str) -> Result<(), KeyFileError> {
     let path = path_for_key_file(storage_path, alias);
     fs::remove_file(&path).await?;
@@ -38,6 +65,24 @@ pub async fn delete_key_file(storage_path: &Path, alias: &str) -> Result<(), Key
     Ok(())
 }
 
+/// Delete all key files of which the alias starts with `prefix`.
+pub async fn delete_key_files_with_prefix(storage_path: &Path, prefix: &str) -> Result<(), KeyFileError> {
+    let mut entries = fs::read_dir(storage_path).await?;
+
+    while let Some(entry) = entries.next_entry().await? {
+        let file_name = entry.file_name();
+
+        if file_name
+            .to_str()
+            .is_some_and(|name| name.starts_with(prefix) && name.ends_with(".key"))
+        {
+            fs::remove_file(entry.path()).await?;
+        }
+    }
+
+    Ok(())
+}
+
 fn path_for_key_file(storage_path: &Path, alias: &str) -> PathBuf {
     // Get path to key file as "<storage_path>/<alias>.key"
     storage_path.join(format!("{alias}.key"))
@@ -212,4 +257,34 @@ mod tests {
         assert!(!fs::try_exists(&path1).await.unwrap());
         assert!(!fs::try_exists(&path2).await.unwrap());
     }
+
+    #[tokio::test]
+    async fn test_write_and_read_key_file() {
+        let alias = "test_write_and_read_key_file".to_string();
+        let storage_path = env::temp_dir();
+        let encryption_key = Aes256Gcm::new(&Aes256Gcm::generate_key(&mut OsRng));
+        let contents = crypto::utils::random_bytes(32);
+
+        // Make sure we start with a clean slate.
+        _ = delete_key_file(&storage_path, &alias).await;
+
+        // Reading a key file that does not exist should not result in an error.
+        let read_contents = read_key_file(&storage_path, &alias, &encryption_key)
+            .await
+            .expect("Could not read key file");
+
+        assert!(read_contents.is_none());
+
+        write_key_file(&storage_path, &alias, &encryption_key, &contents)
+            .await
+            .expect("Could not write key file");
+        let read_contents = read_key_file(&storage_path, &alias, &encryption_key)
+            .await
+            .expect("Could not read key file");
+
+        assert_eq!(read_contents, Some(contents));
+
+        // Cleanup after ourselves.
+        delete_key_file(&storage_path, &alias).await.unwrap();
+    }
 }
@@ -24,6 +24,7 @@ use mdoc::utils::cose::CoseError;
 use mdoc::utils::serialization::CborError;
 use openid4vc::wallet_issuance::credential::CredentialWithMetadata;
 use openid4vc::wallet_issuance::credential::IssuedCredentialCopies;
+use openid4vc::wallet_issuance::refresh::CredentialRefresh;
 use sd_jwt_vc_metadata::TypeMetadataChainError;
 use sea_orm::DbErr;
 use serde::Deserialize;
@@ -35,6 +36,7 @@ use uuid::Uuid;
 
 pub use self::attestation_copy::DisclosableAttestation;
 pub use self::attestation_copy::PartialAttestation;
+pub use self::attestation_copy::PartialAttestationSubset;
 pub use self::attestation_copy::StoredAttestation;
 pub use self::attestation_copy::StoredAttestationCopy;
 pub use self::data::ChangePinData;
@@ -168,6 +170,13 @@ pub trait Storage: Send {
     async fn upsert_data<D: KeyedData + Sync + 'static>(&mut self, data: &D) -> StorageResult<()>;
     async fn delete_data<D: KeyedData + Sync + 'static>(&mut self) -> StorageResult<()>;
 
+    /// Store the DPoP private key of an issuance session that is still needed after the session has ended, e.g. to
+    /// poll for deferred credentials or to send notifications. This key is not stored in the database itself, but in
+    /// a separate file that is encrypted using the platform key store.
+    async fn insert_dpop_key(&mut self, identifier: &str, key: &[u8]) -> StorageResult<()>;
+    async fn fetch_dpop_key(&self, identifier: &str) -> StorageResult<Option<Vec<u8>>>;
+    async fn delete_dpop_key(&mut self, identifier: &str) -> StorageResult<()>;
+
     async fn insert_credentials(
         &mut self,
         timestamp: DateTime<Utc>,
@@ -177,11 +186,28 @@ pub trait Storage: Send {
     async fn update_credentials(
         &mut self,
         timestamp: DateTime<Utc>,
-        credentials: Vec<(IssuedCredentialCopies, AttestationPresentation)>,
+        credentials: Vec<(
+            IssuedCredentialCopies,
+            Option<CredentialRefresh>,
+            AttestationPresentation,
+        )>,
     ) -> StorageResult<()>;
 
     async fn increment_attestation_copies_usage_count(&mut self, attestation_copy_ids: Vec<Uuid>) -> StorageResult<()>;
 
+    /// Returns the id and refresh data of every attestation that the issuer handed out a refresh token for and that is
+    /// due to be reissued, either because at most `max_unused_copies_ratio` of its copies have not been disclosed yet
+    /// or because it expires before `expires_before`.
+    async fn fetch_attestation_refreshes_due(
+        &self,
+        expires_before: DateTime<Utc>,
+        max_unused_copies_ratio: f64,
+    ) -> StorageResult<Vec<(Uuid, CredentialRefresh)>>;
+
+    /// Removes the refresh data of the attestations with the given ids, which is necessary once the issuer has
+    /// consumed their refresh token.
+    async fn delete_attestation_refreshes(&mut self, attestation_ids: Vec<Uuid>) -> StorageResult<()>;
+
     async fn has_any_attestations(&self) -> StorageResult<bool>;
     async fn has_any_attestations_with_credential_kinds(
         &self,
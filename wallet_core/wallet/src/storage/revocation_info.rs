@@ -8,6 +8,7 @@ use entity::revocation_info;
 use token_status_list::verification::client::StatusListClient;
 use token_status_list::verification::verifier::RevocationStatus;
 use token_status_list::verification::verifier::RevocationVerifier;
+use url::Url;
 use utils::generator::Generator;
 use uuid::Uuid;
 
@@ -25,6 +26,15 @@ impl RevocationInfo {
         self.attestation_copy_id
     }
 
+    pub fn issuer_cert_distinguished_name(&self) -> &CanonicalDistinguishedName {
+        &self.issuer_cert_distinguished_name
+    }
+
+    pub fn status_list_uri(&self) -> &Url {
+        let StatusClaim::StatusList(StatusListClaim { uri, .. }) = &self.status_claim;
+        uri
+    }
+
     pub async fn verify_revocation(
         &self,
         issuer_trust_anchors: &TrustAnchors,
@@ -0,0 +1,41 @@
+use std::slice;
+
+use crypto::trust_anchor::TrustAnchors;
+use crypto::x509::BorrowingCertificate;
+use crypto::x509::CertificateUsage;
+use crypto::x509::revocation::RevocationError;
+use crypto::x509::revocation::RevocationPolicy;
+use openid4vc::disclosure_session::DisclosureClient;
+use openid4vc::wallet_issuance::IssuanceDiscovery;
+use platform_support::attested_key::AttestedKeyHolder;
+use tracing::info;
+use utils::generator::TimeGenerator;
+
+use crate::Wallet;
+
+impl<CR, UR, S, AKH, APC, CID, DCC, CPC, SLC> Wallet<CR, UR, S, AKH, APC, CID, DCC, CPC, SLC>
+where
+    AKH: AttestedKeyHolder,
+    CID: IssuanceDiscovery,
+    DCC: DisclosureClient,
+{
+    /// Check the revocation status of a relying party or issuer certificate in accordance with `policy`, which is taken
+    /// from the wallet configuration. Nothing is checked if no policy is configured.
+    pub(super) async fn check_certificate_revocation(
+        &self,
+        certificate: &BorrowingCertificate,
+        trust_anchors: &TrustAnchors,
+        usage: Option<CertificateUsage>,
+        policy: Option<RevocationPolicy>,
+    ) -> Result<(), RevocationError> {
+        let (Some(policy), Some(checker)) = (policy, self.certificate_revocation_checker.as_ref()) else {
+            return Ok(());
+        };
+
+        info!("Checking revocation status of certificate");
+        checker
+            .with_policy(policy)
+            .verify_chain(slice::from_ref(certificate), trust_anchors, usage, &[], &TimeGenerator)
+            .await
+    }
+}
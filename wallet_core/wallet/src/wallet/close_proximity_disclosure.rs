@@ -1,4 +1,6 @@
+use std::collections::HashSet;
 use std::future::Future;
+use std::ops::Deref;
 use std::pin::Pin;
 use std::sync::Arc;
 
@@ -11,6 +13,7 @@ use crypto::CredentialEcdsaKey;
 use crypto::trust_anchor::TrustAnchors;
 use crypto::wscd::DisclosureWscd;
 use crypto::x509::BorrowingCertificate;
+use crypto::x509::revocation::RevocationError;
 use derive_more::IsVariant;
 use entity::disclosure_event::EventStatus;
 use error_category::ErrorCategory;
@@ -51,6 +54,7 @@ use utils::generator::TimeGenerator;
 use utils::vec_at_least::IntoNonEmptyIterator;
 use utils::vec_at_least::NonEmptyIterator;
 use utils::vec_at_least::VecNonEmpty;
+use utils::vec_nonempty;
 use wallet_configuration::wallet_config::WalletConfiguration;
 use wscd::Poa;
 use wscd::poa::JwtPoaInput;
@@ -71,6 +75,8 @@ use crate::storage::PartialAttestation;
 use crate::storage::Storage;
 use crate::wallet::DisclosureError;
 use crate::wallet::Session;
+use crate::wallet::disclosure::DisclosureAttributeIdentifier;
+use crate::wallet::disclosure::DisclosureCredentialSet;
 use crate::wallet::disclosure::RedirectUriPurpose;
 use crate::wallet::disclosure::VpDisclosableAttestation;
 use crate::wallet::disclosure::WalletDisclosureAttestations;
@@ -119,6 +125,7 @@ impl TryFrom<VpDisclosableAttestation> for CloseProximityDisclosableAttestation
 
 fn close_proximity_disclosure_proposal(
     attestations: IndexMap<usize, VecNonEmpty<VpDisclosableAttestation>>,
+    credential_sets: VecNonEmpty<DisclosureCredentialSet>,
 ) -> Result<CloseProximityDisclosureAttestations, DisclosureError> {
     let attestations = attestations
         .into_iter()
@@ -132,7 +139,12 @@ fn close_proximity_disclosure_proposal(
         })
         .collect::<Result<IndexMap<_, _>, DisclosureError>>()?;
 
-    Ok(WalletDisclosureAttestations::Proposal(attestations))
+    // ISO/IEC 18013-5 does not allow for disclosing more than one mdoc per requested document type.
+    Ok(WalletDisclosureAttestations::Proposal {
+        attestations,
+        credential_sets,
+        multiple_selection_indices: HashSet::new(),
+    })
 }
 
 #[derive(Debug, Clone, IsVariant)]
@@ -258,6 +270,10 @@ pub enum CloseProximityDisclosureError {
     #[error("failed creating device response: {0}")]
     #[category(pd)]
     DeviceResponse(#[source] mdoc::Error),
+
+    #[error("could not check revocation status of reader certificate: {0}")]
+    #[category(critical)]
+    CertificateRevocation(#[source] RevocationError),
 }
 
 fn parse_device_request(bytes: &[u8]) -> Result<DeviceRequest, CloseProximityDisclosureError> {
@@ -274,7 +290,8 @@ fn error_device_response_status(error: &CloseProximityDisclosureError) -> Option
         CloseProximityDisclosureError::UnsupportedDocFormat { .. } => Some(DeviceResponseStatus::InvalidRequest),
         CloseProximityDisclosureError::MissingReaderAuth
         | CloseProximityDisclosureError::InconsistentReaderAuths
-        | CloseProximityDisclosureError::InvalidDocRequest(_) => Some(DeviceResponseStatus::GeneralError),
+        | CloseProximityDisclosureError::InvalidDocRequest(_)
+        | CloseProximityDisclosureError::CertificateRevocation(_) => Some(DeviceResponseStatus::GeneralError),
         // These are either internal wallet errors or failures already handled by platform support,
         // so we do not expect to send a protocol-level error DeviceResponse for them.
         CloseProximityDisclosureError::DeviceResponseEncoding(_)
@@ -417,6 +434,20 @@ where
             }
         };
 
+        if let Err(error) = self
+            .check_certificate_revocation(
+                &verifier_certificate,
+                wallet_config.wrpac_trust_anchors(),
+                None,
+                wallet_config.certificate_revocation,
+            )
+            .await
+        {
+            let error = CloseProximityDisclosureError::CertificateRevocation(error);
+            self.send_close_proximity_error_response_and_stop(&error).await?;
+            return Err(error.into());
+        }
+
         let (candidate_attestations, shared_data_with_relying_party_before) = self
             .prepare_disclosure(
                 &device_request.items_requests().collect_vec(),
@@ -442,8 +473,15 @@ where
                 "All attributes in the disclosure request are present in the database, return a proposal to the user"
             );
 
+            // Close proximity disclosure does not support credential sets, so all of the requested attestations are
+            // required to be disclosed.
+            let credential_sets =
+                vec_nonempty![DisclosureCredentialSet::new_all_required(candidate_attestations.len())];
+
             let proposal = DisclosureProposalPresentation::from_candidates(
                 candidate_attestations.clone(),
+                credential_sets.clone(),
+                HashSet::new(),
                 organization.clone(),
                 shared_data_with_relying_party_before,
                 session_type,
@@ -456,6 +494,7 @@ where
                 verifier_certificate: Box::new(verifier_certificate),
                 attestations: close_proximity_disclosure_proposal(
                     candidate_attestations.into_iter().enumerate().collect(),
+                    credential_sets,
                 )?,
             };
 
@@ -484,10 +523,13 @@ where
     }
 
     #[instrument(skip_all)]
+    #[expect(clippy::too_many_arguments, reason = "Arguments needed for disclosure")]
     pub(super) async fn perform_close_proximity_disclosure(
         &mut self,
         close_proximity_session: CloseProximityDisclosureSession,
-        selected_indices: &[usize],
+        selected_indices: &[VecNonEmpty<usize>],
+        selected_credential_set_options: Option<&[Option<usize>]>,
+        deselected_attributes: &HashSet<DisclosureAttributeIdentifier>,
         pin: WalletPin,
         attested_key_registration_data_and_config: AttestedKeyRegistrationDataAndConfig<AKH>,
     ) -> Result<(), DisclosureError>
@@ -509,6 +551,12 @@ where
             return Err(DisclosureError::SessionState);
         };
 
+        if let Err(error) = attestations.check_deselected_attributes(deselected_attributes) {
+            self.session
+                .replace(Session::CloseProximityDisclosure(close_proximity_session));
+            return Err(error);
+        }
+
         let organization =
             Organization::try_from(verifier_certificate.as_ref()).map_err(DisclosureError::Organization)?;
 
@@ -526,10 +574,17 @@ where
         };
 
         // Note that this will panic if any of the indices are out of bounds.
-        let attestations = attestations.select_proposal(selected_indices);
+        let wallet_config = self.config_repository.get();
+        let attestations = attestations.select_proposal(
+            selected_indices,
+            selected_credential_set_options,
+            deselected_attributes,
+            &wallet_config.pid_attributes,
+        );
 
         // There is guaranteed to be at least one attestation because of the logic in `start_disclosure()`.
-        let attestation_values = VecNonEmpty::try_from(attestations.into_values().collect_vec()).unwrap();
+        let attestation_values =
+            VecNonEmpty::try_from(attestations.values().flatten().map(Deref::deref).collect_vec()).unwrap();
 
         // NOTE: If the disclosure fails and is retried, the disclosure count will jump by
         //       more than 1, since the same copies are shared with the verifier again.
@@ -825,6 +880,7 @@ fn verify_requested_doc_formats(device_request: &DeviceRequest) -> Result<(), Cl
 mod tests {
     use std::assert_matches;
     use std::collections::HashSet;
+    use std::num::NonZeroUsize;
     use std::sync::Arc;
     use std::sync::LazyLock;
 
@@ -911,6 +967,7 @@ mod tests {
     use crate::storage::StoredAttestationCopy;
     use crate::wallet::DisclosureError;
     use crate::wallet::Session;
+    use crate::wallet::disclosure::DisclosureCredentialSet;
     use crate::wallet::disclosure::VpDisclosableAttestation;
     use crate::wallet::test::TestWalletMockStorage;
     use crate::wallet::test::WRPAC_CA;
@@ -1759,10 +1816,10 @@ mod tests {
             session_state: Arc::new(Mutex::new(CloseProximityDisclosureSessionState::DisclosureProposed {
                 session_transcript: Box::new(session_transcript.clone()),
                 verifier_certificate: Box::new(verifier_certificate.clone()),
-                attestations: close_proximity_disclosure_proposal(IndexMap::from([(
-                    0,
-                    vec_nonempty![disclosable_attestation],
-                )]))
+                attestations: close_proximity_disclosure_proposal(
+                    IndexMap::from([(0, vec_nonempty![disclosable_attestation])]),
+                    vec_nonempty![DisclosureCredentialSet::new_all_required(NonZeroUsize::MIN)],
+                )
                 .unwrap(),
             })),
         }));
@@ -2110,9 +2167,11 @@ mod tests {
         let sd_jwt_disclosable_attestation =
             disclosable_attestation_from_credential_requests(sd_jwt_attestation, &credential_requests);
 
-        let error =
-            close_proximity_disclosure_proposal(IndexMap::from([(0, vec_nonempty![sd_jwt_disclosable_attestation])]))
-                .expect_err("a close proximity disclosure proposal should not contain SD-JWT attestations");
+        let error = close_proximity_disclosure_proposal(
+            IndexMap::from([(0, vec_nonempty![sd_jwt_disclosable_attestation])]),
+            vec_nonempty![DisclosureCredentialSet::new_all_required(NonZeroUsize::MIN)],
+        )
+        .expect_err("a close proximity disclosure proposal should not contain SD-JWT attestations");
 
         assert_matches!(error, DisclosureError::UnexpectedAttestationFormat);
     }
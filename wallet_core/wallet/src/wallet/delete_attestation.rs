@@ -144,7 +144,7 @@ where
         self.check_result_for_wallet_revocation(
             instruction_client
                 .send(DeleteKeys {
-                    identifiers: key_identifiers,
+                    identifiers: key_identifiers.clone(),
                 })
                 .await,
         )
@@ -157,6 +157,9 @@ where
             .delete_attestation(Utc::now(), attestation_id)
             .await?;
 
+        info!("Notifying issuer of attestation deletion");
+        self.send_deleted_notification(key_identifiers.as_slice()).await;
+
         self.emit_attestations().await?;
         self.emit_recent_history().await?;
 
@@ -167,19 +170,26 @@ where
 #[cfg(test)]
 mod tests {
     use std::assert_matches;
+    use std::collections::HashMap;
     use std::sync::Arc;
     use std::sync::LazyLock;
 
     use attestation_types::credential_format::Format;
     use attestation_types::credential_kind::CredentialKind;
     use attestation_types::pid_constants::PID_ATTESTATION_TYPE;
+    use itertools::Itertools;
     use mockall::predicate::always;
     use mockall::predicate::eq;
+    use openid4vc::notification::NotificationEvent;
+    use openid4vc::wallet_issuance::WalletIssuanceError;
+    use openid4vc::wallet_issuance::notification::CredentialNotification;
     use update_policy_model::update_policy::VersionState;
     use uuid::Uuid;
     use wallet_account::messages::instructions::DeleteKeys;
     use wallet_account::messages::instructions::Instruction;
 
+    use super::super::IssuedCredentialNotifications;
+    use super::super::PendingDeferredIssuances;
     use super::super::test::TestWalletMockStorage;
     use super::super::test::WalletDeviceVendor;
     use super::super::test::create_wp_result;
@@ -197,6 +207,7 @@ mod tests {
         wallet: &mut TestWalletMockStorage,
         attestation_id: Uuid,
         delete_result: Result<(), StorageError>,
+        stored_notifications: Option<IssuedCredentialNotifications>,
     ) {
         wallet
             .mut_storage()
@@ -244,6 +255,32 @@ mod tests {
             .expect_delete_attestation()
             .with(always(), eq(attestation_id))
             .return_once(move |_, _| delete_result);
+
+        // The DPoP keys of the notifications are stored separately.
+        let dpop_keys = stored_notifications
+            .iter()
+            .flat_map(|IssuedCredentialNotifications(notifications)| notifications.values())
+            .map(|notification| {
+                let dpop_key = notification.dpop_key();
+
+                (dpop_key.identifier().to_string(), dpop_key.to_bytes().unwrap())
+            })
+            .collect::<HashMap<_, _>>();
+
+        wallet
+            .mut_storage()
+            .expect_fetch_data::<IssuedCredentialNotifications>()
+            .return_once(|| Ok(stored_notifications));
+
+        wallet
+            .mut_storage()
+            .expect_fetch_dpop_key()
+            .returning(move |identifier| Ok(dpop_keys.get(identifier).cloned()));
+
+        wallet
+            .mut_storage()
+            .expect_fetch_data::<PendingDeferredIssuances>()
+            .returning(|| Ok(None));
     }
 
     #[tokio::test]
@@ -334,7 +371,7 @@ mod tests {
 
         let attestation_id = Uuid::new_v4();
 
-        setup_delete_attestation_mocks(&mut wallet, attestation_id, Ok(()));
+        setup_delete_attestation_mocks(&mut wallet, attestation_id, Ok(()), None);
 
         wallet
             .delete_attestation(PIN.clone(), attestation_id.to_string())
@@ -377,7 +414,7 @@ mod tests {
         assert_eq!(history.lock().len(), 1);
 
         let attestation_id = Uuid::new_v4();
-        setup_delete_attestation_mocks(&mut wallet, attestation_id, Ok(()));
+        setup_delete_attestation_mocks(&mut wallet, attestation_id, Ok(()), None);
 
         wallet
             .delete_attestation(PIN.clone(), attestation_id.to_string())
@@ -388,12 +425,108 @@ mod tests {
         assert_eq!(history.lock().len(), 2);
     }
 
+    #[tokio::test]
+    async fn test_delete_attestation_success_sends_notification() {
+        let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
+
+        wallet
+            .mut_storage()
+            .expect_fetch_unique_attestations()
+            .returning(|| Ok(vec![]));
+
+        let attestation_id = Uuid::new_v4();
+        let notification = CredentialNotification::new_mock("notification_1");
+        let dpop_key_identifier = notification.dpop_key().identifier().to_string();
+        let stored_notifications = IssuedCredentialNotifications(HashMap::from([
+            ("test_key_id".to_string(), notification),
+            (
+                "other_key_id".to_string(),
+                CredentialNotification::new_mock("notification_2"),
+            ),
+        ]));
+        setup_delete_attestation_mocks(&mut wallet, attestation_id, Ok(()), Some(stored_notifications));
+
+        // Only the notification for the other attestation should remain in storage.
+        wallet
+            .mut_storage()
+            .expect_upsert_data::<IssuedCredentialNotifications>()
+            .times(1)
+            .return_once(|IssuedCredentialNotifications(notifications)| {
+                assert_eq!(notifications.keys().collect_vec(), vec![&"other_key_id".to_string()]);
+
+                Ok(())
+            });
+
+        // The DPoP key of the removed notification is no longer used by anything else.
+        wallet
+            .mut_storage()
+            .expect_delete_dpop_key()
+            .with(eq(dpop_key_identifier))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        wallet
+            .mut_issuance_discovery()
+            .expect_send_notification_sync()
+            .withf(|notification, event| {
+                notification.notification_id == "notification_1" && *event == NotificationEvent::CredentialDeleted
+            })
+            .times(1)
+            .return_once(|_, _| Ok(()));
+
+        wallet
+            .delete_attestation(PIN.clone(), attestation_id.to_string())
+            .await
+            .expect("delete_attestation should succeed");
+    }
+
+    #[tokio::test]
+    async fn test_delete_attestation_success_notification_error() {
+        let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
+
+        wallet
+            .mut_storage()
+            .expect_fetch_unique_attestations()
+            .returning(|| Ok(vec![]));
+
+        let attestation_id = Uuid::new_v4();
+        let stored_notifications = IssuedCredentialNotifications(HashMap::from([(
+            "test_key_id".to_string(),
+            CredentialNotification::new_mock("notification_1"),
+        )]));
+        setup_delete_attestation_mocks(&mut wallet, attestation_id, Ok(()), Some(stored_notifications));
+
+        // The last stored notification is removed, so there is nothing left to store.
+        wallet
+            .mut_storage()
+            .expect_delete_data::<IssuedCredentialNotifications>()
+            .times(1)
+            .return_once(|| Ok(()));
+        wallet
+            .mut_storage()
+            .expect_delete_dpop_key()
+            .times(1)
+            .returning(|_| Ok(()));
+
+        wallet
+            .mut_issuance_discovery()
+            .expect_send_notification_sync()
+            .times(1)
+            .return_once(|_, _| Err(WalletIssuanceError::IssuerMismatch));
+
+        // Failing to notify the issuer should not result in an error.
+        wallet
+            .delete_attestation(PIN.clone(), attestation_id.to_string())
+            .await
+            .expect("delete_attestation should succeed");
+    }
+
     #[tokio::test]
     async fn test_delete_attestation_error_storage() {
         let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
         let attestation_id = Uuid::new_v4();
 
-        setup_delete_attestation_mocks(&mut wallet, attestation_id, Err(StorageError::AlreadyOpened));
+        setup_delete_attestation_mocks(&mut wallet, attestation_id, Err(StorageError::AlreadyOpened), None);
 
         let error = wallet
             .delete_attestation(PIN.clone(), attestation_id.to_string())
@@ -1,7 +1,10 @@
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::fmt::Debug;
 use std::hash::Hash;
 use std::num::NonZeroUsize;
+use std::ops::Deref;
 use std::sync::Arc;
 
 use attestation_data::auth::Organization;
@@ -12,8 +15,10 @@ use attestation_types::claim_path::ClaimPath;
 use attestation_types::credential_format::Format;
 use chrono::Utc;
 use crypto::x509::BorrowingCertificate;
+use crypto::x509::revocation::RevocationError;
 use dcql::CredentialQueryIdentifier;
 use dcql::normalized::NormalizedCredentialRequest;
+use dcql::normalized::SatisfiableCredentialSet;
 use entity::disclosure_event::EventStatus;
 use error_category::ErrorCategory;
 use error_category::sentry_capture_error;
@@ -75,6 +80,7 @@ use crate::repository::Repository;
 use crate::repository::UpdateableRepository;
 use crate::storage::DisclosableAttestation;
 use crate::storage::PartialAttestation;
+use crate::storage::PartialAttestationSubset;
 use crate::storage::Storage;
 use crate::storage::StorageError;
 use crate::wallet::HistoryError;
@@ -86,6 +92,12 @@ use crate::wallet::state::CheckPreconditionsError;
 #[derive(Debug, Clone)]
 pub struct DisclosureProposalPresentation {
     pub attestation_options: VecNonEmpty<DisclosureAttestationOptions>,
+    /// The indices of the [`DisclosureAttestationOptions`] for which the user may select more than one attestation.
+    pub multiple_selection_indices: HashSet<usize>,
+    pub credential_sets: VecNonEmpty<DisclosureCredentialSet>,
+    /// The attributes of the attestations in the proposal that are not required by the verifier, which the user may
+    /// choose not to disclose.
+    pub optional_attributes: HashSet<DisclosureAttributeIdentifier>,
     pub organization: Organization,
     pub shared_data_with_relying_party_before: bool,
     pub session_type: SessionType,
@@ -99,6 +111,126 @@ pub enum DisclosureAttestationOptions {
     Multiple(VecAtLeastTwo<AttestationPresentation>),
 }
 
+/// Identifies a single attribute of one of the attestations in a [`DisclosureProposalPresentation`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DisclosureAttributeIdentifier {
+    /// The index of the [`DisclosureAttestationOptions`] in the proposal.
+    pub attestation_option_index: usize,
+    /// The index of the attestation within its [`DisclosureAttestationOptions`].
+    pub attestation_index: usize,
+    /// The key of the [`AttestationAttribute`](crate::AttestationAttribute) in the [`AttestationPresentation`].
+    pub attribute_key: VecNonEmpty<String>,
+}
+
+/// A set of alternatives, of which the user can choose one to disclose. Each option consists of the indices of the
+/// [`DisclosureAttestationOptions`] in the proposal that will be disclosed when that option is selected. If the set
+/// is not required, the user may also choose not to disclose any of its options.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisclosureCredentialSet {
+    pub required: bool,
+    pub options: VecNonEmpty<VecNonEmpty<usize>>,
+}
+
+impl DisclosureCredentialSet {
+    /// Creates a required credential set with a single option, which consists of all of the attestation options.
+    pub(super) fn new_all_required(attestation_option_count: NonZeroUsize) -> Self {
+        Self {
+            required: true,
+            options: vec_nonempty![VecNonEmpty::try_from((0..attestation_option_count.get()).collect_vec()).unwrap()],
+        }
+    }
+
+    /// Converts the credential sets that can be satisfied by the candidate attestations to a [`VecNonEmpty`] of
+    /// [`DisclosureCredentialSet`], which reference the candidates by index. Any candidates that are not part of the
+    /// options of any of the credential sets are removed.
+    fn from_satisfiable_credential_sets<T>(
+        credential_sets: VecNonEmpty<SatisfiableCredentialSet<'_>>,
+        mut candidates: IndexMap<CredentialQueryIdentifier, T>,
+    ) -> (VecNonEmpty<Self>, IndexMap<CredentialQueryIdentifier, T>) {
+        let option_ids = credential_sets
+            .iter()
+            .flat_map(|credential_set| credential_set.options.iter().flatten())
+            .copied()
+            .collect::<HashSet<_>>();
+
+        candidates.retain(|id, _| option_ids.contains(id));
+
+        let credential_sets = credential_sets
+            .into_nonempty_iter()
+            .map(|credential_set| Self {
+                required: credential_set.required,
+                options: credential_set
+                    .options
+                    .into_nonempty_iter()
+                    .map(|option| {
+                        option
+                            .into_nonempty_iter()
+                            .map(|id| {
+                                candidates
+                                    .get_index_of(id)
+                                    .expect("credential set option should only contain candidate identifiers")
+                            })
+                            .collect()
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        (credential_sets, candidates)
+    }
+
+    /// Returns the indices of the attestation options that are part of the selected credential set options. When no
+    /// selection is provided, the first option of every required credential set is selected. Note that this panics
+    /// when the selection does not match the credential sets, as this is considered programmer error.
+    fn selected_attestation_options(
+        credential_sets: &VecNonEmpty<Self>,
+        selected_options: Option<&[Option<usize>]>,
+    ) -> HashSet<usize> {
+        let selected_options = match selected_options {
+            Some(selected_options) => {
+                if selected_options.len() != credential_sets.len().get() {
+                    panic!(
+                        "credential set option count does not match proposal, expected {}, found {}",
+                        credential_sets.len(),
+                        selected_options.len()
+                    );
+                }
+
+                selected_options.to_vec()
+            }
+            None => credential_sets
+                .iter()
+                .map(|credential_set| credential_set.required.then_some(0))
+                .collect(),
+        };
+
+        credential_sets
+            .iter()
+            .zip(selected_options)
+            .enumerate()
+            .flat_map(|(set_index, (credential_set, selected_option))| match selected_option {
+                Some(selected_option) => {
+                    let Some(option) = credential_set.options.as_ref().get(selected_option) else {
+                        panic!(
+                            "selected credential set option out of bounds for set index {} with count {}: {}",
+                            set_index,
+                            credential_set.options.len(),
+                            selected_option,
+                        );
+                    };
+
+                    option.as_slice()
+                }
+                None if credential_set.required => {
+                    panic!("no option selected for required credential set index {set_index}")
+                }
+                None => &[],
+            })
+            .copied()
+            .collect()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct AttributesNotAvailable {
     pub organization: Box<Organization>,
@@ -126,6 +258,10 @@ pub enum DisclosureError {
     #[category(pd)]
     DisclosureUriQuery(Url),
 
+    #[error("could not check revocation status of relying party certificate: {0}")]
+    #[category(critical)]
+    CertificateRevocation(#[source] RevocationError),
+
     #[error("could not get organization from certificate: {0}")]
     #[category(critical)]
     Organization(OrganizationError),
@@ -194,6 +330,10 @@ pub enum DisclosureError {
     #[error("Close Proximity disclosure session error: {0}")]
     #[category(defer)]
     CloseProximityDisclosureSessionError(#[from] CloseProximityDisclosureError),
+
+    #[error("de-selected disclosure attribute is not optional: {0:?}")]
+    #[category(critical)]
+    DeselectedAttributeNotOptional(DisclosureAttributeIdentifier),
 }
 
 impl DisclosureError {
@@ -289,17 +429,81 @@ pub enum RedirectUriPurpose {
 #[derive(Debug, Clone)]
 pub(super) enum WalletDisclosureAttestations<T, P> {
     Missing,
-    Proposal(IndexMap<T, VecNonEmpty<DisclosableAttestation<P>>>),
+    Proposal {
+        attestations: IndexMap<T, VecNonEmpty<DisclosableAttestation<P>>>,
+        credential_sets: VecNonEmpty<DisclosureCredentialSet>,
+        multiple_selection_indices: HashSet<usize>,
+    },
+}
+
+/// Converts a selection of a single attestation per DCQL query to the selection accepted by
+/// [`WalletDisclosureAttestations::select_proposal`].
+pub(super) fn single_attestation_selection(selected_indices: &[usize]) -> Vec<VecNonEmpty<usize>> {
+    selected_indices.iter().map(|index| vec_nonempty![*index]).collect()
 }
 
-impl<T: Hash + Eq, P> WalletDisclosureAttestations<T, P> {
-    /// Returns an [`IndexMap`] selecting one attestation per DCQL query from the proposal. Note that this panics when
-    /// [`WalletDisclosureAttestations`] is not a proposal or any of the indices is out of bounds, as this is considered
-    /// programmer error.
-    pub fn select_proposal(&self, selected_indices: &[usize]) -> IndexMap<&T, &DisclosableAttestation<P>> {
+/// Groups values by their DCQL query identifier, retaining the order of the values for each identifier.
+fn group_by_query_identifier<T: Debug>(
+    values: Vec<(CredentialQueryIdentifier, T)>,
+) -> HashMap<CredentialQueryIdentifier, VecNonEmpty<T>> {
+    values
+        .into_iter()
+        .into_group_map()
+        .into_iter()
+        // This unwrap is safe, as each group contains at least one value.
+        .map(|(id, values)| (id, VecNonEmpty::try_from(values).unwrap()))
+        .collect()
+}
+
+impl<T: Hash + Eq, P> WalletDisclosureAttestations<T, P>
+where
+    P: PartialAttestationSubset + Clone,
+{
+    /// Checks that every attribute in `deselected_attributes` is an optional attribute of one of the attestations in
+    /// the proposal, i.e. that it is contained in [`DisclosureProposalPresentation::optional_attributes`].
+    pub fn check_deselected_attributes(
+        &self,
+        deselected_attributes: &HashSet<DisclosureAttributeIdentifier>,
+    ) -> Result<(), DisclosureError> {
+        let Self::Proposal { attestations, .. } = self else {
+            return Err(DisclosureError::SessionState);
+        };
+
+        match deselected_attributes.iter().find(|identifier| {
+            !attestations
+                .get_index(identifier.attestation_option_index)
+                .and_then(|(_, candidates)| candidates.as_ref().get(identifier.attestation_index))
+                .is_some_and(|attestation| {
+                    attestation
+                        .optional_attribute_claim_path(&identifier.attribute_key)
+                        .is_some()
+                })
+        }) {
+            Some(identifier) => Err(DisclosureError::DeselectedAttributeNotOptional(identifier.clone())),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns an [`IndexMap`] selecting one or more attestations per DCQL query from the proposal, for those DCQL
+    /// queries that are part of the selected credential set options. Selecting more than one attestation is only
+    /// allowed for the queries in `multiple_selection_indices`. Any optional attributes of the selected attestations
+    /// that are contained in `deselected_attributes` are removed from those attestations, which should be checked using
+    /// [`Self::check_deselected_attributes`] first. Note that this panics when [`WalletDisclosureAttestations`] is not
+    /// a proposal or the selection is otherwise invalid, as this is considered programmer error.
+    pub fn select_proposal(
+        &self,
+        selected_indices: &[VecNonEmpty<usize>],
+        selected_credential_set_options: Option<&[Option<usize>]>,
+        deselected_attributes: &HashSet<DisclosureAttributeIdentifier>,
+        presentation_config: &impl AttestationPresentationConfig,
+    ) -> IndexMap<&T, VecNonEmpty<Cow<'_, DisclosableAttestation<P>>>> {
         match self {
             Self::Missing => panic!("disclosure proposal selected when missing attributes"),
-            Self::Proposal(attestations) => {
+            Self::Proposal {
+                attestations,
+                credential_sets,
+                multiple_selection_indices,
+            } => {
                 if selected_indices.len() != attestations.len() {
                     panic!(
                         "disclosure attestation count does not match query, expected {}, found {}",
@@ -308,21 +512,78 @@ impl<T: Hash + Eq, P> WalletDisclosureAttestations<T, P> {
                     );
                 }
 
+                let selected_queries = DisclosureCredentialSet::selected_attestation_options(
+                    credential_sets,
+                    selected_credential_set_options,
+                );
+
                 attestations
                     .iter()
-                    .zip(selected_indices.iter().copied())
+                    .zip(selected_indices)
                     .enumerate()
-                    .map(|(query_index, ((id, candidates), selected_index))| {
-                        let Some(attestation) = candidates.as_ref().get(selected_index) else {
-                            panic!(
-                                "selected disclosure attestation out of bounds for query index {} with count {}: {}",
-                                query_index,
-                                candidates.len(),
-                                selected_index,
-                            );
-                        };
+                    .filter(|(query_index, _)| selected_queries.contains(query_index))
+                    .map(|(query_index, ((id, candidates), query_selected_indices))| {
+                        if query_selected_indices.len().get() > 1 {
+                            if !multiple_selection_indices.contains(&query_index) {
+                                panic!(
+                                    "multiple disclosure attestations selected for single query index {query_index}"
+                                );
+                            }
+
+                            if !query_selected_indices.iter().all_unique() {
+                                panic!("duplicate disclosure attestations selected for query index {query_index}");
+                            }
+                        }
+
+                        let selected_attestations = query_selected_indices
+                            .nonempty_iter()
+                            .map(|selected_index| {
+                                let Some(attestation) = candidates.as_ref().get(*selected_index) else {
+                                    panic!(
+                                        "selected disclosure attestation out of bounds for query index {} with count \
+                                         {}: {}",
+                                        query_index,
+                                        candidates.len(),
+                                        selected_index,
+                                    );
+                                };
+
+                                let deselected_claim_paths = deselected_attributes
+                                    .iter()
+                                    .filter(|identifier| {
+                                        identifier.attestation_option_index == query_index
+                                            && identifier.attestation_index == *selected_index
+                                    })
+                                    .map(|identifier| {
+                                        attestation
+                                            .optional_attribute_claim_path(&identifier.attribute_key)
+                                            .unwrap_or_else(|| {
+                                                panic!(
+                                                    "de-selected disclosure attribute is not optional for query index \
+                                                     {} and attestation index {}: {}",
+                                                    query_index,
+                                                    selected_index,
+                                                    identifier.attribute_key.iter().join("."),
+                                                )
+                                            })
+                                    })
+                                    .collect_vec();
+
+                                if deselected_claim_paths.is_empty() {
+                                    return Cow::Borrowed(attestation);
+                                }
 
-                        (id, attestation)
+                                // Since only optional claim paths are removed, which are present
+                                // in the partial attestation, this should not result in an error.
+                                let attestation = attestation
+                                    .try_without_claim_paths(deselected_claim_paths, presentation_config)
+                                    .expect("optional claim paths should be present in partial attestation");
+
+                                Cow::Owned(attestation)
+                            })
+                            .collect();
+
+                        (id, selected_attestations)
                     })
                     .collect()
             }
@@ -346,12 +607,18 @@ impl<DCS> WalletDisclosureSession<DCS> {
         redirect_uri_purpose: RedirectUriPurpose,
         disclosure_type: DisclosureType,
         attestations: IndexMap<CredentialQueryIdentifier, VecNonEmpty<VpDisclosableAttestation>>,
+        credential_sets: VecNonEmpty<DisclosureCredentialSet>,
+        multiple_selection_indices: HashSet<usize>,
         protocol_state: DCS,
     ) -> Self {
         Self {
             redirect_uri_purpose,
             disclosure_type,
-            attestations: WalletDisclosureAttestations::Proposal(attestations),
+            attestations: WalletDisclosureAttestations::Proposal {
+                attestations,
+                credential_sets,
+                multiple_selection_indices,
+            },
             protocol_state,
         }
     }
@@ -408,14 +675,37 @@ pub(super) fn is_request_for_recovery_code(
 
 impl DisclosureProposalPresentation {
     /// Converts a collection of candidate attestations into a [`DisclosureProposalPresentation`].
+    #[expect(clippy::too_many_arguments, reason = "Constructor")]
     pub(super) fn from_candidates(
         candidate_attestations: VecNonEmpty<VecNonEmpty<VpDisclosableAttestation>>,
+        credential_sets: VecNonEmpty<DisclosureCredentialSet>,
+        multiple_selection_indices: HashSet<usize>,
         organization: Organization,
         shared_data_with_relying_party_before: bool,
         session_type: SessionType,
         disclosure_type: DisclosureType,
         purpose: RedirectUriPurpose,
     ) -> Self {
+        // Collect the attributes of all candidates that the user may choose not to disclose.
+        let optional_attributes = candidate_attestations
+            .iter()
+            .enumerate()
+            .flat_map(|(attestation_option_index, candidates)| {
+                candidates
+                    .iter()
+                    .enumerate()
+                    .flat_map(move |(attestation_index, candidate)| {
+                        candidate
+                            .optional_attribute_keys()
+                            .map(move |attribute_key| DisclosureAttributeIdentifier {
+                                attestation_option_index,
+                                attestation_index,
+                                attribute_key: attribute_key.clone(),
+                            })
+                    })
+            })
+            .collect();
+
         // Place the proposed attestations in a `DisclosureProposalPresentation`,
         let attestation_options = candidate_attestations
             .into_nonempty_iter()
@@ -434,6 +724,9 @@ impl DisclosureProposalPresentation {
 
         DisclosureProposalPresentation {
             attestation_options,
+            multiple_selection_indices,
+            credential_sets,
+            optional_attributes,
             organization,
             shared_data_with_relying_party_before,
             session_type,
@@ -498,7 +791,10 @@ where
 
     /// Helper method that fetches attestation from the database based on their attestation type, filters out any of
     /// them that do not match the request and convert the remaining ones to a [`DisclosableAttestation`], which
-    /// contains an [`AttestationPresentation`] to show to the user.
+    /// contains an [`AttestationPresentation`] to show to the user. For each attestation, the first combination of
+    /// requested claims it satisfies determines which attributes will be disclosed. Of these, the claims that are not
+    /// part of the smallest combination of requested claims that is contained within it and is also satisfied by the
+    /// attestation are marked as optional, as the user may choose not to disclose them.
     pub(super) async fn fetch_candidate_attestations(
         storage: &S,
         request: &impl AttestationRequest,
@@ -508,27 +804,58 @@ where
             .fetch_valid_unique_attestations_by_credential_kinds(&request.credential_kinds(), TimeGenerator)
             .await?;
 
+        let claim_sets = request.claim_sets();
+
         let candidate_attestations = stored_attestations
             .into_iter()
             .filter_map(|attestation_copy| {
-                // Only select those attestations that contain all of the requested attributes, as well as any
-                // AKI, if specified.
+                // Only select those attestations that satisfy any of the combinations of requested attributes,
+                // including their values, as well as any AKI, if specified.
                 // TODO (PVW-4537): Have this (or at least the attributes matching) be part of the database query
                 // using some index.
-                (attestation_copy.matches_requested_attributes(request.claim_paths().collect_vec().iter())
-                    && attestation_copy.matches_any_aki(request.aki()))
-                .then(|| {
-                    // Create a disclosure proposal by removing any attributes that were not requested from the
-                    // presentation attributes. Since the filtering above should remove any attestation in which the
-                    // requested claim paths are not present and this is the only error condition, no error should
-                    // occur.
-                    DisclosableAttestation::try_new(
-                        attestation_copy,
-                        request.claim_paths().collect_vec().iter(),
-                        presentation_config,
-                    )
-                    .expect("all claim paths should be present in attestation")
-                })
+                if !attestation_copy.matches_any_aki(request.aki()) {
+                    return None;
+                }
+
+                let claims = claim_sets
+                    .iter()
+                    .find(|claims| attestation_copy.matches_requested_claims(claims))?;
+
+                // Note that the combination of claims found above is itself always a candidate here.
+                let required_claims = claim_sets
+                    .iter()
+                    .filter(|required_claims| {
+                        required_claims
+                            .iter()
+                            .all(|required_claim| claims.iter().any(|claim| claim.path == required_claim.path))
+                            && attestation_copy.matches_requested_claims(required_claims)
+                    })
+                    .min_by_key(|required_claims| required_claims.len())
+                    .unwrap_or(claims);
+
+                let optional_claim_paths = claims
+                    .iter()
+                    .map(|claim| &claim.path)
+                    .filter(|path| {
+                        !required_claims
+                            .iter()
+                            .any(|required_claim| required_claim.path == **path)
+                    })
+                    .collect_vec();
+
+                // Create a disclosure proposal by removing any attributes that were not requested from the
+                // presentation attributes. Since the filtering above should remove any attestation in which the
+                // requested claim paths are not present and this is the only error condition, no error should
+                // occur.
+                let disclosable_attestation = DisclosableAttestation::try_new(
+                    attestation_copy,
+                    claims.iter().map(|claim| &claim.path),
+                    presentation_config,
+                )
+                .expect("all claim paths should be present in attestation")
+                .with_optional_claim_paths(optional_claim_paths, presentation_config);
+
+                Some(disclosable_attestation)
             })
             .collect_vec();
 
@@ -600,6 +927,15 @@ where
             .start(disclosure_uri_query, source, wallet_config.wrpac_trust_anchors())
             .await?;
 
+        self.check_certificate_revocation(
+            session.certificate(),
+            wallet_config.wrpac_trust_anchors(),
+            None,
+            wallet_config.certificate_revocation,
+        )
+        .await
+        .map_err(DisclosureError::CertificateRevocation)?;
+
         let (candidate_attestations, shared_data_with_relying_party_before) = self
             .prepare_disclosure(
                 &session.credential_requests().as_ref().iter().collect_vec(),
@@ -627,16 +963,45 @@ where
             &wallet_config.pid_attributes,
         );
 
-        if let Ok(disclosable_attestations) =
-            VecNonEmpty::try_from(candidate_attestations.values().cloned().collect_vec())
-            && disclosable_attestations.len().get() == session.credential_requests().as_ref().len()
+        // Evaluate which of the credential sets in the request, if any, can be satisfied by the candidates. If no
+        // credential sets are present, all of the requests need to be satisfied.
+        let candidate_ids = candidate_attestations.keys().collect();
+
+        if let Ok(satisfiable_credential_sets) = session
+            .credential_requests()
+            .satisfiable_credential_sets(&candidate_ids)
         {
             info!(
                 "All attributes in the disclosure request are present in the database, return a proposal to the user"
             );
 
+            let (credential_sets, candidate_attestations) = DisclosureCredentialSet::from_satisfiable_credential_sets(
+                satisfiable_credential_sets,
+                candidate_attestations,
+            );
+
+            // There is guaranteed to be at least one candidate, as there is at least one required credential set.
+            let disclosable_attestations =
+                VecNonEmpty::try_from(candidate_attestations.values().cloned().collect_vec()).unwrap();
+
+            // The user may select more than one of the candidates for those requests that allow for this.
+            let multiple_selection_indices = candidate_attestations
+                .keys()
+                .enumerate()
+                .filter(|(_, id)| {
+                    session
+                        .credential_requests()
+                        .as_ref()
+                        .iter()
+                        .any(|request| request.id() == *id && request.multiple())
+                })
+                .map(|(index, _)| index)
+                .collect::<HashSet<_>>();
+
             let proposal = DisclosureProposalPresentation::from_candidates(
                 disclosable_attestations,
+                credential_sets.clone(),
+                multiple_selection_indices.clone(),
                 organization,
                 shared_data_with_relying_party_before,
                 session.session_type(),
@@ -650,6 +1015,8 @@ where
                     purpose,
                     disclosure_type,
                     candidate_attestations,
+                    credential_sets,
+                    multiple_selection_indices,
                     session,
                 )));
 
@@ -745,6 +1112,7 @@ where
         Ok(return_url)
     }
 
+    /// Accept the disclosure proposal, disclosing the first option of every required credential set.
     #[instrument(skip_all)]
     #[sentry_capture_error]
     pub async fn accept_disclosure(
@@ -752,6 +1120,109 @@ where
         selected_indices: &[usize],
         pin: Pin,
     ) -> Result<Option<Url>, DisclosureError>
+    where
+        S: Storage,
+        UR: UpdateableRepository<VersionState, TlsPinningConfig, Error = UpdatePolicyError>,
+        CPC: CloseProximityDisclosureClient,
+        APC: AccountProviderClient,
+    {
+        self.accept_disclosure_with_options(
+            &single_attestation_selection(selected_indices),
+            None,
+            &HashSet::new(),
+            pin,
+        )
+        .await
+    }
+
+    /// Accept the disclosure proposal, disclosing the selected option for every credential set in the proposal. For
+    /// credential sets that are not required, `None` may be selected in order not to disclose any of its options.
+    #[instrument(skip_all)]
+    #[sentry_capture_error]
+    pub async fn accept_disclosure_with_credential_set_options(
+        &mut self,
+        selected_indices: &[usize],
+        selected_credential_set_options: &[Option<usize>],
+        pin: Pin,
+    ) -> Result<Option<Url>, DisclosureError>
+    where
+        S: Storage,
+        UR: UpdateableRepository<VersionState, TlsPinningConfig, Error = UpdatePolicyError>,
+        CPC: CloseProximityDisclosureClient,
+        APC: AccountProviderClient,
+    {
+        self.accept_disclosure_with_options(
+            &single_attestation_selection(selected_indices),
+            Some(selected_credential_set_options),
+            &HashSet::new(),
+            pin,
+        )
+        .await
+    }
+
+    /// Accept the disclosure proposal, disclosing all of the selected attestations for every attestation option and
+    /// the selected option for every credential set in the proposal. More than one attestation may only be selected
+    /// for the attestation options in [`DisclosureProposalPresentation::multiple_selection_indices`].
+    #[instrument(skip_all)]
+    #[sentry_capture_error]
+    pub async fn accept_disclosure_with_multiple_selection(
+        &mut self,
+        selected_indices: &[VecNonEmpty<usize>],
+        selected_credential_set_options: &[Option<usize>],
+        pin: Pin,
+    ) -> Result<Option<Url>, DisclosureError>
+    where
+        S: Storage,
+        UR: UpdateableRepository<VersionState, TlsPinningConfig, Error = UpdatePolicyError>,
+        CPC: CloseProximityDisclosureClient,
+        APC: AccountProviderClient,
+    {
+        self.accept_disclosure_with_options(
+            selected_indices,
+            Some(selected_credential_set_options),
+            &HashSet::new(),
+            pin,
+        )
+        .await
+    }
+
+    /// Accept the disclosure proposal in the same way as [`Self::accept_disclosure_with_multiple_selection`], without
+    /// disclosing the de-selected attributes. Only attributes that are listed in
+    /// [`DisclosureProposalPresentation::optional_attributes`] may be de-selected, otherwise
+    /// [`DisclosureError::DeselectedAttributeNotOptional`] is returned. Any de-selected attributes of attestations that
+    /// are not selected are ignored. The disclosure history will only contain those attributes that were actually
+    /// disclosed.
+    #[instrument(skip_all)]
+    #[sentry_capture_error]
+    pub async fn accept_disclosure_with_deselected_attributes(
+        &mut self,
+        selected_indices: &[VecNonEmpty<usize>],
+        selected_credential_set_options: &[Option<usize>],
+        deselected_attributes: &HashSet<DisclosureAttributeIdentifier>,
+        pin: Pin,
+    ) -> Result<Option<Url>, DisclosureError>
+    where
+        S: Storage,
+        UR: UpdateableRepository<VersionState, TlsPinningConfig, Error = UpdatePolicyError>,
+        CPC: CloseProximityDisclosureClient,
+        APC: AccountProviderClient,
+    {
+        self.accept_disclosure_with_options(
+            selected_indices,
+            Some(selected_credential_set_options),
+            deselected_attributes,
+            pin,
+        )
+        .await
+    }
+
+    async fn accept_disclosure_with_options(
+        &mut self,
+        selected_indices: &[VecNonEmpty<usize>],
+        selected_credential_set_options: Option<&[Option<usize>]>,
+        deselected_attributes: &HashSet<DisclosureAttributeIdentifier>,
+        pin: Pin,
+    ) -> Result<Option<Url>, DisclosureError>
     where
         S: Storage,
         UR: UpdateableRepository<VersionState, TlsPinningConfig, Error = UpdatePolicyError>,
@@ -769,6 +1240,8 @@ where
                 self.perform_disclosure(
                     session,
                     selected_indices,
+                    selected_credential_set_options,
+                    deselected_attributes,
                     pin,
                     RedirectUriPurpose::Browser,
                     attested_key_registration_data_and_config,
@@ -779,6 +1252,8 @@ where
                 self.perform_close_proximity_disclosure(
                     session,
                     selected_indices,
+                    selected_credential_set_options,
+                    deselected_attributes,
                     pin,
                     attested_key_registration_data_and_config,
                 )
@@ -794,10 +1269,13 @@ where
     }
 
     #[instrument(skip_all)]
+    #[expect(clippy::too_many_arguments, reason = "Arguments needed for disclosure")]
     pub(super) async fn perform_disclosure(
         &mut self,
         mut session: WalletDisclosureSession<DCC::Session>,
-        selected_indices: &[usize],
+        selected_indices: &[VecNonEmpty<usize>],
+        selected_credential_set_options: Option<&[Option<usize>]>,
+        deselected_attributes: &HashSet<DisclosureAttributeIdentifier>,
         pin: Pin,
         redirect_uri_purpose: RedirectUriPurpose,
         attested_key_registration_data_and_config: AttestedKeyRegistrationDataAndConfig<AKH>,
@@ -808,7 +1286,7 @@ where
         APC: AccountProviderClient,
     {
         // If we do not have a proposal, this method should not have been called, so return an error.
-        if !matches!(session.attestations, WalletDisclosureAttestations::Proposal(_)) {
+        if !matches!(session.attestations, WalletDisclosureAttestations::Proposal { .. }) {
             self.session.replace(Session::Disclosure(session)); // Put back the session
             return Err(DisclosureError::SessionState);
         }
@@ -822,6 +1300,11 @@ where
             });
         }
 
+        if let Err(error) = session.attestations.check_deselected_attributes(deselected_attributes) {
+            self.session.replace(Session::Disclosure(session)); // Put back the session
+            return Err(error);
+        }
+
         // Prepare the `RemoteEcdsaWscd` for signing using the provided PIN.
         let remote_wscd = match self
             .prepare_remote_instruction_client(pin, attested_key_registration_data_and_config)
@@ -835,10 +1318,17 @@ where
         };
 
         // Note that this will panic if any of the indices are out of bounds.
-        let attestations = session.attestations.select_proposal(selected_indices);
+        let wallet_config = self.config_repository.get();
+        let attestations = session.attestations.select_proposal(
+            selected_indices,
+            selected_credential_set_options,
+            deselected_attributes,
+            &wallet_config.pid_attributes,
+        );
 
         // There is guaranteed to be at least one attestation because of the logic in `start_disclosure()`.
-        let attestation_values = VecNonEmpty::try_from(attestations.values().copied().collect_vec()).unwrap();
+        let attestation_values =
+            VecNonEmpty::try_from(attestations.values().flatten().map(Deref::deref).collect_vec()).unwrap();
 
         // NOTE: If the disclosure fails and is retried, the disclosure count will jump by
         //       more than 1, since the same copies are shared with the verifier again.
@@ -875,17 +1365,17 @@ where
 
         // Gather both partial mdocs or SD-JWT presentations by cloning the attestations
         // held in the session, as disclosing attestations needs to be retryable.
-        let (partial_mdocs, sd_jwt_presentations): (HashMap<_, _>, HashMap<_, _>) =
-            attestations
-                .iter()
-                .partition_map(|(id, attestation)| match attestation.partial_attestation() {
-                    PartialAttestation::MsoMdoc { partial_mdoc } => {
-                        Either::Left(((*id).clone(), vec_nonempty![partial_mdoc.as_ref().clone()]))
-                    }
-                    PartialAttestation::SdJwt { key_identifier, sd_jwt } => {
-                        Either::Right(((*id).clone(), vec_nonempty![(*sd_jwt.clone(), key_identifier.clone())]))
-                    }
-                });
+        let (partial_mdocs, sd_jwt_presentations): (Vec<_>, Vec<_>) = attestations
+            .iter()
+            .flat_map(|(id, attestations)| attestations.iter().map(move |attestation| ((*id).clone(), attestation)))
+            .partition_map(|(id, attestation)| match attestation.partial_attestation() {
+                PartialAttestation::MsoMdoc { partial_mdoc } => Either::Left((id, partial_mdoc.as_ref().clone())),
+                PartialAttestation::SdJwt { key_identifier, sd_jwt } => {
+                    Either::Right((id, (*sd_jwt.clone(), key_identifier.clone())))
+                }
+            });
+        let partial_mdocs = group_by_query_identifier(partial_mdocs);
+        let sd_jwt_presentations = group_by_query_identifier(sd_jwt_presentations);
 
         // This should result in either all partial mdocs or all SD-JWT presentations, which is guaranteed by the logic
         // in `VpDisclosureSession`, which rejects DCQL requests with a mix of formats. Additionally, there will be at
@@ -976,6 +1466,10 @@ where
         .await
         .map_err(DisclosureError::EventStorage)?;
 
+        // Now that the user has entered their PIN, use the opportunity to reissue any attestations that are running low
+        // on unused copies or are about to expire.
+        self.refresh_attestations(&remote_wscd).await;
+
         Ok(return_url)
     }
 }
@@ -985,6 +1479,7 @@ mod tests {
     use std::assert_matches;
     use std::collections::HashMap;
     use std::collections::HashSet;
+    use std::num::NonZeroUsize;
     use std::str::FromStr;
     use std::sync::Arc;
     use std::sync::LazyLock;
@@ -1013,6 +1508,13 @@ mod tests {
     use crypto::server_keys::generate::Ca;
     use crypto::x509::BorrowingCertificate;
     use crypto::x509::KeyIdentifier;
+    use crypto::x509::crl::CrlProviderError;
+    use crypto::x509::revocation::RevocationError;
+    use crypto::x509::revocation::RevocationFailureMode;
+    use crypto::x509::revocation::RevocationMethod;
+    use crypto::x509::revocation::RevocationPolicy;
+    use dcql::ClaimsQueryValue;
+    use dcql::CredentialQueryIdentifier;
     use dcql::normalized::MdocAttributeRequest;
     use dcql::normalized::NormalizedCredentialRequest;
     use dcql::normalized::NormalizedCredentialRequests;
@@ -1066,6 +1568,8 @@ mod tests {
     use super::super::test::setup_mock_recent_history_callback;
     use super::AttributesNotAvailable;
     use super::DisclosureAttestationOptions;
+    use super::DisclosureAttributeIdentifier;
+    use super::DisclosureCredentialSet;
     use super::DisclosureError;
     use super::DisclosureProposalPresentation;
     use super::RedirectUriPurpose;
@@ -1200,6 +1704,8 @@ mod tests {
             RedirectUriPurpose::Browser,
             DisclosureType::Regular,
             IndexMap::from([("id".try_into().unwrap(), vec_nonempty![disclosable_attestation])]),
+            vec_nonempty![DisclosureCredentialSet::new_all_required(NonZeroUsize::MIN)],
+            HashSet::new(),
             disclosure_session,
         ));
 
@@ -1443,6 +1949,13 @@ mod tests {
             .times(1)
             .returning(|_| Ok(()));
 
+        // After successful disclosure, the wallet will check if any attestations are due to be reissued.
+        wallet
+            .mut_storage()
+            .expect_fetch_attestation_refreshes_due()
+            .times(1)
+            .return_once(|_, _| Ok(vec![]));
+
         // The wallet will use the OpenID4VP disclosure client to disclose the actual attributes.
         let Some(Session::Disclosure(session)) = wallet.session.as_mut() else {
             panic!("wallet should contain disclosure session");
@@ -1765,6 +2278,36 @@ mod tests {
         assert!(wallet.session.is_none());
     }
 
+    #[tokio::test]
+    async fn test_wallet_start_disclosure_error_certificate_revocation() {
+        let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
+        wallet
+            .set_certificate_revocation(RevocationPolicy {
+                method: RevocationMethod::Crl,
+                failure_mode: RevocationFailureMode::HardFail,
+            })
+            .await;
+
+        let _verifier_certificate = setup_disclosure_client_start(
+            &mut wallet.disclosure_client,
+            default_pid_credential_requests(Format::MsoMdoc),
+        );
+
+        // The mock verifier certificate has no CRL distribution points, so its revocation status cannot be determined,
+        // which should result in an error when failing hard.
+        let error = wallet
+            .start_disclosure(&DISCLOSURE_URI, DisclosureUriSource::QrCode)
+            .await
+            .expect_err("starting disclosure should not succeed");
+
+        assert_matches!(
+            error,
+            DisclosureError::CertificateRevocation(RevocationError::Crl(CrlProviderError::NoCrlDistributionPoint))
+        );
+        assert!(error.return_url().is_none());
+        assert!(wallet.session.is_none());
+    }
+
     #[tokio::test]
     async fn test_wallet_start_disclosure_error_attestation_retrieval() {
         let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
@@ -1948,54 +2491,373 @@ mod tests {
         assert!(wallet.session.is_some());
     }
 
-    fn wallet_expectations_for_aki_tests(
-        wallet: &mut TestWalletMockStorage,
-        stored_attestation: StoredAttestationCopy,
-    ) {
+    #[tokio::test]
+    async fn test_wallet_start_disclosure_credential_sets() {
+        let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
+
+        // Request either a passport or a PID, optionally followed by the passport again. As only the PID is present
+        // in the wallet, the proposal should only contain the PID and the optional credential set should be left out.
+        let credential_requests = NormalizedCredentialRequests::new_mock_sd_jwt_from_slices(&[
+            (&[PID_ATTESTATION_TYPE], &[&[PID_FAMILY_NAME]]),
+            (&["com.example.passport"], &[&["document_number"]]),
+        ])
+        .with_credential_sets(&[(&[&["sd_jwt_1"], &["sd_jwt_0"]], true), (&[&["sd_jwt_1"]], false)]);
+
+        setup_disclosure_client_start(&mut wallet.disclosure_client, credential_requests);
+
+        let (stored_attestation, _) = example_pid_stored_attestation_copy(Format::SdJwt);
         wallet
             .mut_storage()
             .expect_fetch_valid_unique_attestations_by_credential_kinds()
-            .times(1)
+            .times(2)
             .returning(move |_, _| Ok(vec![stored_attestation.clone()]));
         wallet
             .mut_storage()
             .expect_did_share_data_with_relying_party()
             .return_once(|_| Ok(false));
+
+        let proposal = wallet
+            .start_disclosure(&DISCLOSURE_URI, DisclosureUriSource::QrCode)
+            .await
+            .expect("starting disclosure should succeed");
+
+        assert_eq!(proposal.attestation_options.len().get(), 1);
+        assert_eq!(
+            proposal.credential_sets,
+            vec_nonempty![DisclosureCredentialSet {
+                required: true,
+                options: vec_nonempty![vec_nonempty![0]],
+            }]
+        );
+
+        let Some(Session::Disclosure(session)) = &wallet.session else {
+            panic!("wallet should have an active disclosure session");
+        };
+        let WalletDisclosureAttestations::Proposal { attestations, .. } = &session.attestations else {
+            panic!("disclosure session should contain a proposal");
+        };
+
+        assert_eq!(
+            attestations.keys().cloned().collect_vec(),
+            vec![CredentialQueryIdentifier::try_from("sd_jwt_0").unwrap()]
+        );
     }
 
-    fn default_pid_credential_requests_with_aki(
-        requested_format: Format,
-        aki: &KeyIdentifier,
-    ) -> NormalizedCredentialRequests {
-        default_pid_credential_requests(requested_format)
+    /// Request the PID, optionally allowing for multiple PID attestations to be disclosed.
+    fn pid_credential_requests_with_multiple(multiple: bool) -> NormalizedCredentialRequests {
+        default_pid_credential_requests(Format::SdJwt)
             .into_iter()
-            .map(|r| r.with_aki(vec![aki.clone()]))
-            .collect::<Vec<_>>()
+            .map(|request| if multiple { request.with_multiple() } else { request })
+            .collect_vec()
             .try_into()
             .unwrap()
     }
 
     #[rstest]
+    #[case::single(false, HashSet::new())]
+    #[case::multiple(true, HashSet::from([0]))]
     #[tokio::test]
-    async fn test_wallet_start_disclosure_matching_aki(
-        #[values(Format::MsoMdoc, Format::SdJwt)] requested_format: Format,
+    async fn test_wallet_start_disclosure_multiple(
+        #[case] multiple: bool,
+        #[case] expected_multiple_selection_indices: HashSet<usize>,
     ) {
         let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
 
-        // Build credential requests containing the AKI of the ISSUER_KEY's certificate,
-        // which matches the stored attestation that will be returned from storage.
-        let aki = ISSUER_KEY
-            .issuance_key
-            .certificate()
-            .authority_key_id()
-            .expect("issuer certificate should have AKI");
-        let credential_requests = default_pid_credential_requests_with_aki(requested_format, &aki);
-
-        setup_disclosure_client_start(&mut wallet.disclosure_client, credential_requests);
-
-        wallet_expectations_for_aki_tests(&mut wallet, example_pid_stored_attestation_copy(requested_format).0);
+        setup_disclosure_client_start(
+            &mut wallet.disclosure_client,
+            pid_credential_requests_with_multiple(multiple),
+        );
 
-        // The AKI matches, so a disclosure proposal should be returned.
+        let stored_attestations = vec![
+            example_pid_stored_attestation_copy(Format::SdJwt).0,
+            example_pid_stored_attestation_copy(Format::SdJwt).0,
+        ];
+        wallet
+            .mut_storage()
+            .expect_fetch_valid_unique_attestations_by_credential_kinds()
+            .times(1)
+            .return_once(move |_, _| Ok(stored_attestations));
+        wallet
+            .mut_storage()
+            .expect_did_share_data_with_relying_party()
+            .return_once(|_| Ok(false));
+
+        let proposal = wallet
+            .start_disclosure(&DISCLOSURE_URI, DisclosureUriSource::QrCode)
+            .await
+            .expect("starting disclosure should succeed");
+
+        // Both PID attestations should be offered, while selecting both of them is only allowed for `multiple`.
+        assert_matches!(
+            proposal.attestation_options.first(),
+            DisclosureAttestationOptions::Multiple(options) if options.len().get() == 2
+        );
+        assert_eq!(proposal.multiple_selection_indices, expected_multiple_selection_indices);
+
+        let Some(Session::Disclosure(session)) = &wallet.session else {
+            panic!("wallet should have an active disclosure session");
+        };
+        let WalletDisclosureAttestations::Proposal {
+            multiple_selection_indices,
+            ..
+        } = &session.attestations
+        else {
+            panic!("disclosure session should contain a proposal");
+        };
+
+        assert_eq!(*multiple_selection_indices, expected_multiple_selection_indices);
+    }
+
+    /// Request either the family name of the PID, constrained to the provided values, or otherwise its given name.
+    fn pid_credential_requests_with_claim_sets(
+        requested_format: Format,
+        family_name_values: &[&str],
+    ) -> NormalizedCredentialRequests {
+        let credential_requests = match requested_format {
+            Format::MsoMdoc => NormalizedCredentialRequests::new_mock_mdoc_from_slices(
+                &[(
+                    PID_ATTESTATION_TYPE,
+                    &[
+                        &[PID_ATTESTATION_TYPE, PID_FAMILY_NAME],
+                        &[PID_ATTESTATION_TYPE, PID_GIVEN_NAME],
+                    ],
+                )],
+                None,
+            ),
+            Format::SdJwt => NormalizedCredentialRequests::new_mock_sd_jwt_from_slices(&[(
+                &[PID_ATTESTATION_TYPE],
+                &[&[PID_FAMILY_NAME], &[PID_GIVEN_NAME]],
+            )]),
+        };
+
+        let request = credential_requests
+            .into_iter()
+            .next()
+            .unwrap()
+            .with_claim_sets(&["family_name", "given_name"], &[&["family_name"], &["given_name"]])
+            .with_claim_values(
+                0,
+                family_name_values
+                    .iter()
+                    .map(|value| ClaimsQueryValue::String(value.to_string()))
+                    .collect(),
+            );
+
+        vec![request].try_into().unwrap()
+    }
+
+    #[rstest]
+    #[case(&["Smith", "De Bruijn"], PID_FAMILY_NAME)]
+    #[case(&["Smith"], PID_GIVEN_NAME)]
+    #[tokio::test]
+    async fn test_wallet_start_disclosure_claim_sets_and_values(
+        #[values(Format::MsoMdoc, Format::SdJwt)] requested_format: Format,
+        #[case] family_name_values: &[&str],
+        #[case] expected_attribute: &str,
+    ) {
+        let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
+
+        let credential_requests = pid_credential_requests_with_claim_sets(requested_format, family_name_values);
+        setup_disclosure_client_start(&mut wallet.disclosure_client, credential_requests);
+
+        let (stored_attestation, _) = example_pid_stored_attestation_copy(requested_format);
+        wallet
+            .mut_storage()
+            .expect_fetch_valid_unique_attestations_by_credential_kinds()
+            .times(1)
+            .returning(move |_, _| Ok(vec![stored_attestation.clone()]));
+        wallet
+            .mut_storage()
+            .expect_did_share_data_with_relying_party()
+            .return_once(|_| Ok(false));
+
+        let proposal = wallet
+            .start_disclosure(&DISCLOSURE_URI, DisclosureUriSource::QrCode)
+            .await
+            .expect("starting disclosure should succeed");
+
+        // Only the attribute of the first claim set that is satisfied by the PID should be proposed.
+        let DisclosureAttestationOptions::Single(presentation) = proposal.attestation_options.first() else {
+            panic!("proposal should contain a single attestation");
+        };
+
+        assert_eq!(
+            presentation
+                .attributes
+                .iter()
+                .map(|attribute| attribute.key.clone())
+                .collect_vec(),
+            vec![vec_nonempty![expected_attribute.to_string()]]
+        );
+    }
+
+    /// Request both the family name and the given name of the PID, of which only the family name is required.
+    fn pid_credential_requests_with_optional_given_name(requested_format: Format) -> NormalizedCredentialRequests {
+        let credential_requests = match requested_format {
+            Format::MsoMdoc => NormalizedCredentialRequests::new_mock_mdoc_from_slices(
+                &[(
+                    PID_ATTESTATION_TYPE,
+                    &[
+                        &[PID_ATTESTATION_TYPE, PID_FAMILY_NAME],
+                        &[PID_ATTESTATION_TYPE, PID_GIVEN_NAME],
+                    ],
+                )],
+                None,
+            ),
+            Format::SdJwt => NormalizedCredentialRequests::new_mock_sd_jwt_from_slices(&[(
+                &[PID_ATTESTATION_TYPE],
+                &[&[PID_FAMILY_NAME], &[PID_GIVEN_NAME]],
+            )]),
+        };
+
+        let request = credential_requests.into_iter().next().unwrap().with_claim_sets(
+            &["family_name", "given_name"],
+            &[&["family_name", "given_name"], &["family_name"]],
+        );
+
+        vec![request].try_into().unwrap()
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_wallet_start_disclosure_optional_attributes(
+        #[values(Format::MsoMdoc, Format::SdJwt)] requested_format: Format,
+    ) {
+        let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
+
+        let credential_requests = pid_credential_requests_with_optional_given_name(requested_format);
+        setup_disclosure_client_start(&mut wallet.disclosure_client, credential_requests);
+
+        let (stored_attestation, _) = example_pid_stored_attestation_copy(requested_format);
+        wallet
+            .mut_storage()
+            .expect_fetch_valid_unique_attestations_by_credential_kinds()
+            .times(1)
+            .returning(move |_, _| Ok(vec![stored_attestation.clone()]));
+        wallet
+            .mut_storage()
+            .expect_did_share_data_with_relying_party()
+            .return_once(|_| Ok(false));
+
+        let proposal = wallet
+            .start_disclosure(&DISCLOSURE_URI, DisclosureUriSource::QrCode)
+            .await
+            .expect("starting disclosure should succeed");
+
+        // Both attributes of the preferred claim set should be proposed.
+        let DisclosureAttestationOptions::Single(presentation) = proposal.attestation_options.first() else {
+            panic!("proposal should contain a single attestation");
+        };
+
+        assert_eq!(
+            presentation
+                .attributes
+                .iter()
+                .map(|attribute| attribute.key.clone())
+                .collect_vec(),
+            vec![
+                vec_nonempty![PID_FAMILY_NAME.to_string()],
+                vec_nonempty![PID_GIVEN_NAME.to_string()]
+            ]
+        );
+
+        // As the family name by itself also satisfies the request, the given name should be optional.
+        assert_eq!(
+            proposal.optional_attributes,
+            HashSet::from([DisclosureAttributeIdentifier {
+                attestation_option_index: 0,
+                attestation_index: 0,
+                attribute_key: vec_nonempty![PID_GIVEN_NAME.to_string()],
+            }])
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_wallet_start_disclosure_error_attributes_not_available_non_matching_values(
+        #[values(Format::MsoMdoc, Format::SdJwt)] requested_format: Format,
+    ) {
+        let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
+
+        // Only request the family name of the PID, with a value that does not match the stored PID.
+        let credential_requests = default_pid_credential_requests(requested_format)
+            .into_iter()
+            .map(|request| request.with_claim_values(0, vec![ClaimsQueryValue::String("Smith".to_string())]))
+            .collect_vec()
+            .try_into()
+            .unwrap();
+        setup_disclosure_client_start(&mut wallet.disclosure_client, credential_requests);
+
+        let (stored_attestation, _) = example_pid_stored_attestation_copy(requested_format);
+        wallet
+            .mut_storage()
+            .expect_fetch_valid_unique_attestations_by_credential_kinds()
+            .times(1)
+            .returning(move |_, _| Ok(vec![stored_attestation.clone()]));
+        wallet
+            .mut_storage()
+            .expect_did_share_data_with_relying_party()
+            .return_once(|_| Ok(false));
+
+        let error = wallet
+            .start_disclosure(&DISCLOSURE_URI, DisclosureUriSource::QrCode)
+            .await
+            .expect_err("starting disclosure should not succeed");
+
+        assert_matches!(
+            error,
+            DisclosureError::AttributesNotAvailable(AttributesNotAvailable { .. })
+        );
+    }
+
+    fn wallet_expectations_for_aki_tests(
+        wallet: &mut TestWalletMockStorage,
+        stored_attestation: StoredAttestationCopy,
+    ) {
+        wallet
+            .mut_storage()
+            .expect_fetch_valid_unique_attestations_by_credential_kinds()
+            .times(1)
+            .returning(move |_, _| Ok(vec![stored_attestation.clone()]));
+        wallet
+            .mut_storage()
+            .expect_did_share_data_with_relying_party()
+            .return_once(|_| Ok(false));
+    }
+
+    fn default_pid_credential_requests_with_aki(
+        requested_format: Format,
+        aki: &KeyIdentifier,
+    ) -> NormalizedCredentialRequests {
+        default_pid_credential_requests(requested_format)
+            .into_iter()
+            .map(|r| r.with_aki(vec![aki.clone()]))
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap()
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_wallet_start_disclosure_matching_aki(
+        #[values(Format::MsoMdoc, Format::SdJwt)] requested_format: Format,
+    ) {
+        let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
+
+        // Build credential requests containing the AKI of the ISSUER_KEY's certificate,
+        // which matches the stored attestation that will be returned from storage.
+        let aki = ISSUER_KEY
+            .issuance_key
+            .certificate()
+            .authority_key_id()
+            .expect("issuer certificate should have AKI");
+        let credential_requests = default_pid_credential_requests_with_aki(requested_format, &aki);
+
+        setup_disclosure_client_start(&mut wallet.disclosure_client, credential_requests);
+
+        wallet_expectations_for_aki_tests(&mut wallet, example_pid_stored_attestation_copy(requested_format).0);
+
+        // The AKI matches, so a disclosure proposal should be returned.
         wallet
             .start_disclosure(&DISCLOSURE_URI, DisclosureUriSource::QrCode)
             .await
@@ -2409,6 +3271,13 @@ mod tests {
             .times(1)
             .return_once(|_| Ok(()));
 
+        // After successful disclosure, the wallet will check if any attestations are due to be reissued.
+        wallet
+            .mut_storage()
+            .expect_fetch_attestation_refreshes_due()
+            .times(1)
+            .return_once(|_, _| Ok(vec![]));
+
         let organization = Organization::try_from(&verifier_certificate).unwrap();
         wallet
             .mut_storage()
@@ -2437,6 +3306,270 @@ mod tests {
         assert_eq!(event_count.load(Ordering::Relaxed), 2);
     }
 
+    #[rstest]
+    #[tokio::test]
+    async fn test_wallet_accept_disclosure_multiple_selection(
+        #[values(Format::MsoMdoc, Format::SdJwt)] requested_format: Format,
+    ) {
+        // Prepare a registered and unlocked wallet with an active disclosure session,
+        // which contains two PID attestations that may both be disclosed.
+        let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
+        let credential_requests = default_pid_credential_requests(requested_format);
+        let [first_attestation, second_attestation] = [(); 2].map(|_| {
+            let (stored_attestation, _) = example_pid_stored_attestation_copy(requested_format);
+
+            DisclosableAttestation::try_new(
+                stored_attestation,
+                credential_requests.as_ref().first().unwrap().claim_paths(),
+                &EmptyPresentationConfig,
+            )
+            .unwrap()
+        });
+        let (mut disclosure_session, _verifier_certificate) = setup_disclosure_session(credential_requests);
+
+        // Both of the attestations should be disclosed for the single query identifier.
+        disclosure_session
+            .expect_disclose()
+            .times(1)
+            .withf(|disclosable_attestations| match disclosable_attestations.as_ref() {
+                DisclosableAttestations::MsoMdoc(partial_mdocs) => {
+                    partial_mdocs
+                        .values()
+                        .map(|partial_mdocs| partial_mdocs.len().get())
+                        .collect_vec()
+                        == vec![2]
+                }
+                DisclosableAttestations::SdJwt(presentations) => {
+                    presentations
+                        .values()
+                        .map(|presentations| presentations.len().get())
+                        .collect_vec()
+                        == vec![2]
+                }
+            })
+            .return_once(|_disclosable_attestations| Ok(None));
+
+        wallet.session = Some(Session::Disclosure(WalletDisclosureSession::new_proposal(
+            RedirectUriPurpose::Browser,
+            DisclosureType::Regular,
+            IndexMap::from([(
+                "id".try_into().unwrap(),
+                vec_nonempty![first_attestation, second_attestation],
+            )]),
+            vec_nonempty![DisclosureCredentialSet::new_all_required(NonZeroUsize::MIN)],
+            HashSet::from([0]),
+            disclosure_session,
+        )));
+
+        wallet
+            .mut_storage()
+            .expect_fetch_data::<ChangePinData>()
+            .returning(|| Ok(None));
+
+        // The usage count of both attestation copies should be incremented.
+        wallet
+            .mut_storage()
+            .expect_increment_attestation_copies_usage_count()
+            .withf(|attestation_copy_ids| attestation_copy_ids.len() == 2)
+            .times(1)
+            .return_once(|_| Ok(()));
+        wallet
+            .mut_storage()
+            .expect_fetch_attestation_refreshes_due()
+            .times(1)
+            .return_once(|_, _| Ok(vec![]));
+        wallet
+            .mut_storage()
+            .expect_log_disclosure_event()
+            .withf(|_, attestations, _, _, _| attestations.len() == 2)
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(()));
+
+        wallet
+            .accept_disclosure_with_multiple_selection(&[vec_nonempty![1, 0]], &[Some(0)], PIN.clone())
+            .await
+            .expect("accepting disclosure should succeed");
+
+        assert!(wallet.session.is_none());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_wallet_accept_disclosure_deselected_attributes(
+        #[values(Format::MsoMdoc, Format::SdJwt)] requested_format: Format,
+    ) {
+        // Prepare a registered and unlocked wallet with an active disclosure session,
+        // for which the given name of the PID is optional.
+        let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
+        let credential_requests = pid_credential_requests_with_optional_given_name(requested_format);
+        let credential_request = credential_requests.as_ref().first().unwrap();
+        let given_name_path = credential_request
+            .claim_paths()
+            .find(|path| path.last() == &ClaimPath::SelectByKey(PID_GIVEN_NAME.to_string()))
+            .unwrap()
+            .clone();
+
+        let (stored_attestation, _) = example_pid_stored_attestation_copy(requested_format);
+        let disclosable_attestation = DisclosableAttestation::try_new(
+            stored_attestation,
+            credential_request.claim_paths(),
+            &EmptyPresentationConfig,
+        )
+        .unwrap()
+        .with_optional_claim_paths([&given_name_path], &EmptyPresentationConfig);
+        let (mut disclosure_session, _verifier_certificate) = setup_disclosure_session(credential_requests);
+
+        // Only the family name should be disclosed to the verifier.
+        disclosure_session
+            .expect_disclose()
+            .times(1)
+            .withf(|disclosable_attestations| match disclosable_attestations.as_ref() {
+                DisclosableAttestations::MsoMdoc(partial_mdocs) => {
+                    partial_mdocs.values().flatten().all(|partial_mdoc| {
+                        let names = partial_mdoc
+                            .issuer_signed()
+                            .clone()
+                            .into_entries_by_namespace()
+                            .into_values()
+                            .flatten()
+                            .map(|entry| entry.name)
+                            .collect_vec();
+
+                        names == vec![PID_FAMILY_NAME.to_string()]
+                    })
+                }
+                DisclosableAttestations::SdJwt(presentations) => {
+                    presentations.values().flatten().all(|(presentation, _)| {
+                        let attributes = Attributes::try_from(presentation.as_ref().decoded_claims().unwrap()).unwrap();
+                        let keys = attributes
+                            .flattened()
+                            .into_keys()
+                            .map(|path| path.into_iter().map(str::to_string).collect_vec())
+                            .collect_vec();
+
+                        keys.contains(&vec![PID_FAMILY_NAME.to_string()])
+                            && !keys.contains(&vec![PID_GIVEN_NAME.to_string()])
+                    })
+                }
+            })
+            .return_once(|_disclosable_attestations| Ok(None));
+
+        wallet.session = Some(Session::Disclosure(WalletDisclosureSession::new_proposal(
+            RedirectUriPurpose::Browser,
+            DisclosureType::Regular,
+            IndexMap::from([("id".try_into().unwrap(), vec_nonempty![disclosable_attestation])]),
+            vec_nonempty![DisclosureCredentialSet::new_all_required(NonZeroUsize::MIN)],
+            HashSet::new(),
+            disclosure_session,
+        )));
+
+        wallet
+            .mut_storage()
+            .expect_fetch_data::<ChangePinData>()
+            .returning(|| Ok(None));
+        wallet
+            .mut_storage()
+            .expect_increment_attestation_copies_usage_count()
+            .times(1)
+            .return_once(|_| Ok(()));
+        wallet
+            .mut_storage()
+            .expect_fetch_attestation_refreshes_due()
+            .times(1)
+            .return_once(|_, _| Ok(vec![]));
+
+        // The disclosure event should only contain the family name.
+        wallet
+            .mut_storage()
+            .expect_log_disclosure_event()
+            .withf(|_, attestations, _, _, _| {
+                attestations
+                    .iter()
+                    .flat_map(|attestation| &attestation.attributes)
+                    .map(|attribute| attribute.key.clone().into_inner())
+                    .collect_vec()
+                    == vec![vec![PID_FAMILY_NAME.to_string()]]
+            })
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(()));
+
+        wallet
+            .accept_disclosure_with_deselected_attributes(
+                &[vec_nonempty![0]],
+                &[Some(0)],
+                &HashSet::from([DisclosureAttributeIdentifier {
+                    attestation_option_index: 0,
+                    attestation_index: 0,
+                    attribute_key: vec_nonempty![PID_GIVEN_NAME.to_string()],
+                }]),
+                PIN.clone(),
+            )
+            .await
+            .expect("accepting disclosure should succeed");
+
+        assert!(wallet.session.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_wallet_accept_disclosure_error_deselected_attribute_not_optional() {
+        // Prepare a registered and unlocked wallet with an active disclosure session,
+        // for which only the given name of the PID is optional.
+        let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
+        let credential_requests = pid_credential_requests_with_optional_given_name(Format::SdJwt);
+        let credential_request = credential_requests.as_ref().first().unwrap();
+        let given_name_path = credential_request
+            .claim_paths()
+            .find(|path| path.last() == &ClaimPath::SelectByKey(PID_GIVEN_NAME.to_string()))
+            .unwrap()
+            .clone();
+
+        let (stored_attestation, _) = example_pid_stored_attestation_copy(Format::SdJwt);
+        let disclosable_attestation = DisclosableAttestation::try_new(
+            stored_attestation,
+            credential_request.claim_paths(),
+            &EmptyPresentationConfig,
+        )
+        .unwrap()
+        .with_optional_claim_paths([&given_name_path], &EmptyPresentationConfig);
+        let (mut disclosure_session, _verifier_certificate) = setup_disclosure_session(credential_requests);
+        disclosure_session.expect_disclose().never();
+
+        wallet.session = Some(Session::Disclosure(WalletDisclosureSession::new_proposal(
+            RedirectUriPurpose::Browser,
+            DisclosureType::Regular,
+            IndexMap::from([("id".try_into().unwrap(), vec_nonempty![disclosable_attestation])]),
+            vec_nonempty![DisclosureCredentialSet::new_all_required(NonZeroUsize::MIN)],
+            HashSet::new(),
+            disclosure_session,
+        )));
+
+        wallet.mut_storage().expect_log_disclosure_event().never();
+
+        // De-selecting the family name, which is required by the verifier, should result in an error.
+        let error = wallet
+            .accept_disclosure_with_deselected_attributes(
+                &[vec_nonempty![0]],
+                &[Some(0)],
+                &HashSet::from([DisclosureAttributeIdentifier {
+                    attestation_option_index: 0,
+                    attestation_index: 0,
+                    attribute_key: vec_nonempty![PID_FAMILY_NAME.to_string()],
+                }]),
+                PIN.clone(),
+            )
+            .await
+            .expect_err("accepting disclosure should not succeed");
+
+        assert_matches!(
+            error,
+            DisclosureError::DeselectedAttributeNotOptional(identifier)
+                if identifier.attribute_key == vec_nonempty![PID_FAMILY_NAME.to_string()]
+        );
+
+        // The session should remain active.
+        assert_matches!(wallet.session, Some(Session::Disclosure(_)));
+    }
+
     #[tokio::test]
     async fn test_wallet_accept_disclosure_error_blocked() {
         // Prepare a registered and unlocked wallet with an active disclosure session that is blocked.
@@ -2617,6 +3750,69 @@ mod tests {
         let _ = wallet.accept_disclosure(&[1], PIN.clone()).await;
     }
 
+    #[tokio::test]
+    #[should_panic(expected = "selected credential set option out of bounds for set index 0 with count 1: 1")]
+    async fn test_wallet_accept_disclosure_panic_credential_set_option_out_of_bounds() {
+        // Prepare a registered and unlocked wallet with an active disclosure session.
+        let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
+        let (session, _verifier_certificate) = setup_wallet_disclosure_session(Format::SdJwt);
+        wallet.session = Some(session);
+
+        wallet
+            .mut_storage()
+            .expect_fetch_data::<ChangePinData>()
+            .return_once(|| Ok(None));
+        wallet.mut_storage().expect_log_disclosure_event().never();
+
+        // Accepting disclosure on a wallet while selecting a non-existant
+        // credential set option should result in a panic.
+        let _ = wallet
+            .accept_disclosure_with_credential_set_options(&[0], &[Some(1)], PIN.clone())
+            .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "no option selected for required credential set index 0")]
+    async fn test_wallet_accept_disclosure_panic_required_credential_set_not_selected() {
+        // Prepare a registered and unlocked wallet with an active disclosure session.
+        let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
+        let (session, _verifier_certificate) = setup_wallet_disclosure_session(Format::SdJwt);
+        wallet.session = Some(session);
+
+        wallet
+            .mut_storage()
+            .expect_fetch_data::<ChangePinData>()
+            .return_once(|| Ok(None));
+        wallet.mut_storage().expect_log_disclosure_event().never();
+
+        // Accepting disclosure on a wallet without selecting an option for a required credential set should result in
+        // a panic.
+        let _ = wallet
+            .accept_disclosure_with_credential_set_options(&[0], &[None], PIN.clone())
+            .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "multiple disclosure attestations selected for single query index 0")]
+    async fn test_wallet_accept_disclosure_panic_multiple_selection_not_allowed() {
+        // Prepare a registered and unlocked wallet with an active disclosure session.
+        let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
+        let (session, _verifier_certificate) = setup_wallet_disclosure_session(Format::SdJwt);
+        wallet.session = Some(session);
+
+        wallet
+            .mut_storage()
+            .expect_fetch_data::<ChangePinData>()
+            .return_once(|| Ok(None));
+        wallet.mut_storage().expect_log_disclosure_event().never();
+
+        // Accepting disclosure on a wallet while selecting more than one attestation
+        // for a query that does not allow for this should result in a panic.
+        let _ = wallet
+            .accept_disclosure_with_multiple_selection(&[vec_nonempty![0, 0]], &[Some(0)], PIN.clone())
+            .await;
+    }
+
     // TODO (PVW-3844): Add tests for continuing a PIN change when accepting disclosure.
 
     #[tokio::test]
@@ -2735,8 +3931,9 @@ mod tests {
 
         let copy_ids = session
             .attestations
-            .select_proposal(&[0])
+            .select_proposal(&[vec_nonempty![0]], None, &HashSet::new(), &EmptyPresentationConfig)
             .values()
+            .flatten()
             .map(|attestation| attestation.attestation_copy_id())
             .collect_vec();
 
@@ -2916,8 +4113,9 @@ mod tests {
 
         let copy_ids = session
             .attestations
-            .select_proposal(&[0])
+            .select_proposal(&[vec_nonempty![0]], None, &HashSet::new(), &EmptyPresentationConfig)
             .values()
+            .flatten()
             .map(|attestation| attestation.attestation_copy_id())
             .collect_vec();
 
@@ -3342,17 +4540,27 @@ mod tests {
             Format::MsoMdoc => NormalizedCredentialRequest::MsoMdoc {
                 id: "identifier".try_into().unwrap(),
                 doctype_value: credential_type.to_owned(),
+                multiple: false,
                 aki: vec![],
                 claims: vec_nonempty![MdocAttributeRequest {
+                    id: None,
                     path,
+                    values: vec![],
                     intent_to_retain: Some(false),
                 }],
+                claim_sets: vec![],
             },
             Format::SdJwt => NormalizedCredentialRequest::SdJwt {
                 id: "identifier".try_into().unwrap(),
                 vct_values: vec_nonempty![credential_type.to_owned()],
+                multiple: false,
                 aki: vec![],
-                claims: vec_nonempty![SdJwtAttributeRequest { path }],
+                claims: vec_nonempty![SdJwtAttributeRequest {
+                    id: None,
+                    path,
+                    values: vec![],
+                }],
+                claim_sets: vec![],
             },
         };
 
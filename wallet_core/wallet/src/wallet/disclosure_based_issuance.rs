@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use attestation_data::auth::Organization;
@@ -23,6 +24,7 @@ use super::DisclosureError;
 use super::IssuanceError;
 use super::Wallet;
 use super::disclosure::RedirectUriPurpose;
+use super::disclosure::single_attestation_selection;
 use crate::account_provider::AccountProviderClient;
 use crate::attestation::AttestationPresentation;
 use crate::errors::UpdatePolicyError;
@@ -93,7 +95,9 @@ where
         let redirect_uri = match self
             .perform_disclosure(
                 session,
-                selected_indices,
+                &single_attestation_selection(selected_indices),
+                None,
+                &HashSet::new(),
                 pin,
                 RedirectUriPurpose::Issuance,
                 (
@@ -166,6 +170,8 @@ fn convert_and_enrich_error(error: WalletIssuanceError, organization: &Organizat
 #[cfg(test)]
 mod tests {
     use std::assert_matches;
+    use std::collections::HashSet;
+    use std::num::NonZeroUsize;
     use std::sync::LazyLock;
 
     use attestation_data::auth::issuer_auth::IssuerRegistration;
@@ -199,6 +205,7 @@ mod tests {
 
     use super::super::DisclosureBasedIssuanceError;
     use super::super::Session;
+    use super::super::disclosure::DisclosureCredentialSet;
     use super::super::disclosure::DisclosureError;
     use super::super::disclosure::RedirectUriPurpose;
     use super::super::disclosure::WalletDisclosureSession;
@@ -256,6 +263,8 @@ mod tests {
             RedirectUriPurpose::Issuance,
             DisclosureType::Regular,
             IndexMap::from([("id".try_into().unwrap(), vec_nonempty![disclosable_attestation])]),
+            vec_nonempty![DisclosureCredentialSet::new_all_required(NonZeroUsize::MIN)],
+            HashSet::new(),
             disclosure_session,
         )
     }
@@ -287,7 +296,7 @@ mod tests {
         let (credential_preview, type_metadata) =
             create_example_pid_preview_data(&MockTimeGenerator::default(), Format::MsoMdoc);
         wallet
-            .issuance_discovery
+            .mut_issuance_discovery()
             .expect_start_pre_authorized_code_flow_sync()
             .return_once(move || {
                 let mut issuance_session = MockIssuanceSession::new();
@@ -322,6 +331,13 @@ mod tests {
             .times(1)
             .return_once(|_| Ok(()));
 
+        // After successful disclosure, the wallet will check if any attestations are due to be reissued.
+        wallet
+            .mut_storage()
+            .expect_fetch_attestation_refreshes_due()
+            .times(1)
+            .return_once(|_, _| Ok(vec![]));
+
         wallet
             .mut_storage()
             .expect_log_disclosure_event()
@@ -3,6 +3,9 @@ use std::marker::PhantomData;
 use std::sync::Arc;
 use std::time::Duration;
 
+use crypto::x509::revocation::RevocationChecker;
+use crypto::x509::revocation::RevocationPolicy;
+use derive_more::Debug;
 use error_category::ErrorCategory;
 use error_category::sentry_capture_error;
 use futures::try_join;
@@ -20,6 +23,9 @@ use platform_support::utils::PlatformUtilities;
 use platform_support::utils::UtilitiesError;
 use platform_support::utils::hardware::HardwareUtilities;
 use reqwest::ClientBuilder;
+use sd_jwt_vc_metadata::resolver::TypeMetadataCache;
+use sd_jwt_vc_metadata::resolver::TypeMetadataResolver;
+use sd_jwt_vc_metadata::resolver::TypeMetadataResolverMode;
 use token_status_list::verification::client::StatusListClient;
 use token_status_list::verification::reqwest::HttpStatusListClient;
 use tokio::sync::RwLock;
@@ -53,6 +59,9 @@ use crate::storage::StorageState;
 use crate::update_policy::UpdatePolicyRepository;
 
 const DATABASE_NAME: &str = "wallet";
+const TYPE_METADATA_CACHE_DIRECTORY: &str = "type_metadata";
+const TYPE_METADATA_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+const CERTIFICATE_REVOCATION_CACHE_CAPACITY: u64 = 100;
 
 const REVOCATION_CHECK_FREQUENCY: Duration = Duration::from_secs(24 * 60 * 60);
 
@@ -149,7 +158,21 @@ where
             update_policy_repository,
         };
 
-        let wallet_clients = WalletClients::new()?;
+        // Type metadata that is retrieved using its `vct` URL is cached on disk, so that it remains available offline.
+        // Cached documents are refreshed once they expire, but are still used while the device is offline.
+        let type_metadata_resolver = TypeMetadataResolver::new(
+            reqwest_client_builder(),
+            TypeMetadataCache::new_disk(
+                storage_path.join(TYPE_METADATA_CACHE_DIRECTORY),
+                TYPE_METADATA_CACHE_TTL,
+            ),
+            TypeMetadataResolverMode::CacheFirst,
+        )?;
+
+        let mut wallet_clients = WalletClients::new()?;
+        wallet_clients.credential_issuer_discovery = wallet_clients
+            .credential_issuer_discovery
+            .with_type_metadata_resolver(type_metadata_resolver);
 
         Self::init_registration(storage, key_holder, repositories, wallet_clients).await
     }
@@ -167,6 +190,8 @@ pub struct WalletClients<APC, CID, DCC, SLC> {
     pub credential_issuer_discovery: CID,
     pub disclosure_client: DCC,
     pub status_list_client: SLC,
+    #[debug(skip)]
+    pub certificate_revocation_checker: Option<RevocationChecker>,
 }
 
 fn reqwest_client_builder() -> ClientBuilder {
@@ -185,12 +210,20 @@ where
         let disclosure_client = VpDisclosureClient::new_with_client(HttpClient::try_new(reqwest_client_builder())?);
         // Note that HTTP is explicitly allowed for the retrieval of status lists.
         let status_list_client = HttpStatusListClient::new(default_reqwest_client_builder())?;
+        // The policy is taken from the wallet configuration each time a certificate is checked. Note that HTTP is
+        // explicitly allowed for the retrieval of CRLs and OCSP responses, as these are signed.
+        let certificate_revocation_checker = RevocationChecker::new_with_client(
+            RevocationPolicy::default(),
+            default_reqwest_client_builder().build()?,
+            CERTIFICATE_REVOCATION_CACHE_CAPACITY,
+        );
 
         let clients = Self {
             account_provider_client: APC::default(),
             credential_issuer_discovery,
             disclosure_client,
             status_list_client,
+            certificate_revocation_checker: Some(certificate_revocation_checker),
         };
 
         Ok(clients)
@@ -240,10 +273,11 @@ where
             key_holder,
             registration,
             account_provider_client: Arc::new(wallet_clients.account_provider_client),
-            issuance_discovery: wallet_clients.credential_issuer_discovery,
+            issuance_discovery: Arc::new(wallet_clients.credential_issuer_discovery),
             disclosure_client: wallet_clients.disclosure_client,
             close_proximity_disclosure: PhantomData,
             status_list_client: Arc::new(wallet_clients.status_list_client),
+            certificate_revocation_checker: wallet_clients.certificate_revocation_checker,
             session,
             lock: WalletLock::new(true),
             attestations_callback: Arc::default(),
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use attestation_data::auth::Organization;
@@ -9,6 +10,8 @@ use attestation_types::credential_kind::CredentialKind;
 use chrono::DateTime;
 use chrono::Utc;
 use crypto::x509::CertificateError;
+use crypto::x509::CertificateUsage;
+use crypto::x509::revocation::RevocationError;
 use derive_more::IsVariant;
 use error_category::ErrorCategory;
 use error_category::sentry_capture_error;
@@ -18,6 +21,7 @@ use itertools::Itertools;
 use jwt::error::JwtVerifyError;
 use openid4vc::disclosure_session::DisclosureClient;
 use openid4vc::metadata::issuer_metadata::CredentialConfigurationId;
+use openid4vc::notification::NotificationEvent;
 use openid4vc::token::CredentialPreview;
 use openid4vc::token::CredentialPreviewError;
 use openid4vc::wallet_issuance::AuthorizationSession;
@@ -27,8 +31,11 @@ use openid4vc::wallet_issuance::IssuanceSession;
 use openid4vc::wallet_issuance::WalletIssuanceError;
 use openid4vc::wallet_issuance::authorization::OAuthError;
 use openid4vc::wallet_issuance::credential::CredentialWithMetadata;
+use openid4vc::wallet_issuance::credential::IssuanceOutcome;
 use openid4vc::wallet_issuance::credential::IssuedCredentialCopies;
 use openid4vc::wallet_issuance::credential::SdJwtCopy;
+use openid4vc::wallet_issuance::deferred::DeferredIssuance;
+use openid4vc::wallet_issuance::notification::CredentialNotification;
 use p256::ecdsa::signature;
 use platform_support::attested_key::AppleAttestedKey;
 use platform_support::attested_key::AttestedKeyHolder;
@@ -37,6 +44,7 @@ use serde::Deserialize;
 use serde::Serialize;
 use tracing::info;
 use tracing::instrument;
+use tracing::warn;
 use update_policy_model::update_policy::VersionState;
 use url::Url;
 use utils::built_info::version;
@@ -50,6 +58,9 @@ use wallet_account::messages::instructions::DiscloseRecoveryCode;
 use wallet_configuration::wallet_config::PidAttributesConfiguration;
 use wallet_configuration::wallet_config::WalletConfiguration;
 
+use super::IssuedCredentialNotifications;
+use super::PendingDeferredIssuance;
+use super::PendingDeferredIssuances;
 use super::PersistedIssuanceSessionData;
 use super::Wallet;
 use crate::account_provider::AccountProviderClient;
@@ -94,6 +105,10 @@ pub enum IssuanceError {
     #[category(critical)]
     PidAlreadyPresent,
 
+    #[error("PID issuance was deferred by issuer")]
+    #[category(critical)]
+    DeferredPidIssuance,
+
     #[error("cannot renew PID: wallet has no PID")]
     #[category(critical)]
     NoPidPresent,
@@ -133,6 +148,9 @@ pub enum IssuanceError {
     #[error("could not update issuance OAuth session state in database: {0}")]
     SessionStorage(#[source] StorageError),
 
+    #[error("could not update deferred issuance state in database: {0}")]
+    DeferredIssuanceStorage(#[source] StorageError),
+
     #[error("key '{0}' not found in Wallet Provider")]
     #[category(pd)]
     KeyNotFound(String),
@@ -170,6 +188,10 @@ pub enum IssuanceError {
     #[error("certificate error: {0}")]
     Certificate(#[from] CertificateError),
 
+    #[error("could not check revocation status of issuer certificate: {0}")]
+    #[category(critical)]
+    CertificateRevocation(#[source] RevocationError),
+
     #[error("PID attestation in SD JWT format is missing")]
     #[category(critical)]
     MissingPidSdJwt,
@@ -250,6 +272,8 @@ impl<AS, IS> WalletIssuanceSession<AS, IS> {
 #[derive(Debug, Clone)]
 pub struct IssuanceResult {
     pub transfer_session_id: Option<TransferSessionId>,
+    /// Whether the issuer has deferred issuance, in which case the attestations will be received at a later moment.
+    pub is_deferred: bool,
 }
 
 #[derive(Debug)]
@@ -270,6 +294,55 @@ pub enum PidAttestationFormat {
     Either,
 }
 
+/// The pending deferred issuance sessions that are due to be polled, as collected by
+/// [`Wallet::prepare_deferred_issuance_poll()`].
+pub struct DeferredIssuancePoll<CID> {
+    issuance_discovery: Arc<CID>,
+    config: Arc<WalletConfiguration>,
+    due_issuances: Vec<PendingDeferredIssuance>,
+    discarded_dpop_key_identifiers: Vec<String>,
+}
+
+/// The results of polling the issuers of deferred issuance sessions, to be stored using
+/// [`Wallet::finish_deferred_issuance_poll()`].
+pub struct DeferredIssuancePollResults {
+    outcomes: Vec<(PendingDeferredIssuance, Result<IssuanceOutcome, WalletIssuanceError>)>,
+    discarded_dpop_key_identifiers: Vec<String>,
+}
+
+impl<CID> DeferredIssuancePoll<CID>
+where
+    CID: IssuanceDiscovery,
+{
+    /// Poll the issuer of each of the due sessions, which does not require access to the wallet.
+    pub async fn execute(self) -> DeferredIssuancePollResults {
+        let Self {
+            issuance_discovery,
+            config,
+            due_issuances,
+            discarded_dpop_key_identifiers,
+        } = self;
+
+        let mut outcomes = Vec::with_capacity(due_issuances.len());
+        for pending_issuance in due_issuances {
+            info!("Polling issuer for deferred attestations");
+            let result = issuance_discovery
+                .poll_deferred_issuance(
+                    pending_issuance.deferred_issuance.clone(),
+                    config.issuer_trust_anchors(),
+                )
+                .await;
+
+            outcomes.push((pending_issuance, result));
+        }
+
+        DeferredIssuancePollResults {
+            outcomes,
+            discarded_dpop_key_identifiers,
+        }
+    }
+}
+
 impl<CR, UR, S, AKH, APC, CID, DCC, CPC, SLC> Wallet<CR, UR, S, AKH, APC, CID, DCC, CPC, SLC>
 where
     S: Storage,
@@ -293,6 +366,164 @@ where
             .has_any_attestations_with_credential_kinds(&pid_credential_kinds)
             .await
     }
+
+    /// Notify the issuers of the provided credentials of `event`. This is done on a best-effort basis, which means
+    /// that any errors are logged but otherwise ignored.
+    async fn send_issuer_notifications(&self, notifications: &[&CredentialNotification], event: NotificationEvent) {
+        for notification in notifications {
+            if let Err(error) = self.issuance_discovery.send_notification(notification, event).await {
+                warn!("could not send {event} notification to issuer: {error}");
+            }
+        }
+    }
+
+    /// Persist the notifications of newly stored credentials, so that their issuers can be notified when the user
+    /// deletes any of these later on. Failing to do so is not considered fatal, so any errors are only logged.
+    async fn store_issuer_notifications(&self, notifications: Vec<(String, CredentialNotification)>) {
+        if notifications.is_empty() {
+            return;
+        }
+
+        let mut storage = self.storage.write().await;
+
+        let result = async {
+            // The DPoP private keys are not persisted along with the notifications, but separately.
+            let dpop_keys = notifications
+                .iter()
+                .map(|(_, notification)| notification.dpop_key())
+                .unique_by(|dpop_key| dpop_key.identifier())
+                .collect_vec();
+
+            for dpop_key in dpop_keys {
+                if let Some(key) = dpop_key.to_bytes() {
+                    storage.insert_dpop_key(dpop_key.identifier(), &key).await?;
+                }
+            }
+
+            let IssuedCredentialNotifications(mut stored_notifications) = storage
+                .fetch_data::<IssuedCredentialNotifications>()
+                .await?
+                .unwrap_or_default();
+            stored_notifications.extend(notifications);
+
+            storage
+                .upsert_data(&IssuedCredentialNotifications(stored_notifications))
+                .await
+        }
+        .await;
+
+        if let Err(error) = result {
+            warn!("could not store issuer notifications: {error}");
+        }
+    }
+
+    /// Delete the DPoP private keys with the provided identifiers, unless these are still in use by any of the
+    /// remaining notifications or pending deferred issuance sessions. Note that these can share a DPoP key, as
+    /// attestations are reissued using the DPoP key of the original issuance session. Like notifications, this is done
+    /// on a best-effort basis.
+    async fn delete_unused_dpop_keys(
+        storage: &mut S,
+        identifiers: &[&str],
+        notifications: &HashMap<String, CredentialNotification>,
+        pending_issuances: &[PendingDeferredIssuance],
+    ) {
+        let used_identifiers = notifications
+            .values()
+            .map(|notification| notification.dpop_key().identifier())
+            .chain(
+                pending_issuances
+                    .iter()
+                    .map(|pending_issuance| pending_issuance.deferred_issuance.dpop_key().identifier()),
+            )
+            .collect::<HashSet<_>>();
+
+        for &identifier in identifiers {
+            if used_identifiers.contains(identifier) {
+                continue;
+            }
+
+            if let Err(error) = storage.delete_dpop_key(identifier).await {
+                warn!("could not delete DPoP key: {error}");
+            }
+        }
+    }
+
+    /// Notify the issuer of a deleted attestation, identified by the key identifiers of its copies, if a notification
+    /// was stored for it. Like all notifications, this is done on a best-effort basis.
+    pub(super) async fn send_deleted_notification(&self, key_identifiers: &[String]) {
+        let (mut notification, dpop_key) = {
+            let mut storage = self.storage.write().await;
+
+            let stored = match storage.fetch_data::<IssuedCredentialNotifications>().await {
+                Ok(stored) => stored,
+                Err(error) => {
+                    warn!("could not fetch issuer notifications: {error}");
+                    return;
+                }
+            };
+
+            let Some(IssuedCredentialNotifications(mut stored_notifications)) = stored else {
+                return;
+            };
+
+            let Some(notification) = key_identifiers
+                .iter()
+                .find_map(|key_identifier| stored_notifications.remove(key_identifier))
+            else {
+                return;
+            };
+
+            let result = if stored_notifications.is_empty() {
+                storage.delete_data::<IssuedCredentialNotifications>().await
+            } else {
+                storage
+                    .upsert_data(&IssuedCredentialNotifications(stored_notifications.clone()))
+                    .await
+            };
+
+            if let Err(error) = result {
+                warn!("could not update issuer notifications: {error}");
+            }
+
+            // As this is the last notification that can be sent for the attestation, its DPoP private key is no longer
+            // needed afterwards, unless it is shared with other notifications or pending deferred issuance sessions.
+            let dpop_key_identifier = notification.dpop_key().identifier();
+            let dpop_key = match storage.fetch_dpop_key(dpop_key_identifier).await {
+                Ok(dpop_key) => dpop_key,
+                Err(error) => {
+                    warn!("could not fetch DPoP key for issuer notification: {error}");
+                    None
+                }
+            };
+
+            match storage.fetch_data::<PendingDeferredIssuances>().await {
+                Ok(pending_issuances) => {
+                    Self::delete_unused_dpop_keys(
+                        &mut *storage,
+                        &[dpop_key_identifier],
+                        &stored_notifications,
+                        &pending_issuances.unwrap_or_default().0,
+                    )
+                    .await
+                }
+                Err(error) => warn!("could not fetch deferred issuance sessions: {error}"),
+            }
+
+            (notification, dpop_key)
+        };
+
+        let restored = dpop_key
+            .ok_or(WalletIssuanceError::DpopKeyMissing)
+            .and_then(|dpop_key| notification.restore_dpop_key(&dpop_key));
+
+        if let Err(error) = restored {
+            warn!("could not restore DPoP key for issuer notification: {error}");
+            return;
+        }
+
+        self.send_issuer_notifications(&[&notification], NotificationEvent::CredentialDeleted)
+            .await;
+    }
 }
 
 impl<CR, UR, S, AKH, APC, CID, DCC, CPC, SLC> Wallet<CR, UR, S, AKH, APC, CID, DCC, CPC, SLC>
@@ -413,6 +644,38 @@ where
     {
         info!("Starting issuance from credential offer URI");
 
+        self.start_issuance_from_offer_internal(offer_uri, None).await
+    }
+
+    /// Start issuance from a Credential Offer that contains a Pre-Authorized Code requiring a Transaction Code. The
+    /// requirements for this code (i.e. its input mode, length and description) are returned as the
+    /// [`WalletIssuanceError::CredentialOfferTxCodeRequired`] error from [`Self::start_issuance_from_offer`], so that
+    /// the user can be asked to enter it. If the issuer rejects the code, the user may retry by calling this method
+    /// again with the same offer URI.
+    #[instrument(skip_all)]
+    #[sentry_capture_error]
+    pub async fn start_issuance_from_offer_with_transaction_code(
+        &mut self,
+        offer_uri: Url,
+        transaction_code: String,
+    ) -> Result<IssuanceStartResult, IssuanceError>
+    where
+        UR: UpdateableRepository<VersionState, TlsPinningConfig, Error = UpdatePolicyError>,
+    {
+        info!("Starting issuance from credential offer URI with transaction code");
+
+        self.start_issuance_from_offer_internal(offer_uri, Some(transaction_code))
+            .await
+    }
+
+    async fn start_issuance_from_offer_internal(
+        &mut self,
+        offer_uri: Url,
+        transaction_code: Option<String>,
+    ) -> Result<IssuanceStartResult, IssuanceError>
+    where
+        UR: UpdateableRepository<VersionState, TlsPinningConfig, Error = UpdatePolicyError>,
+    {
         self.check_session_preconditions()?;
 
         if self.session.is_some() {
@@ -430,6 +693,7 @@ where
             .issuance_discovery
             .start(
                 &offer_uri,
+                transaction_code,
                 String::from(NL_WALLET_CLIENT_ID),
                 redirect_uri,
                 config.issuer_trust_anchors(),
@@ -529,15 +793,52 @@ where
         &mut self,
         issuance_session: CID::Issuance,
         pid_purpose: Option<PidIssuancePurpose>,
+    ) -> Result<Vec<AttestationPresentation>, IssuanceError> {
+        let attestations = self
+            .issuance_preview_attestations(&issuance_session, pid_purpose)
+            .await?;
+
+        // The IssuanceSession trait guarantees that credential_preview_data()
+        // returns at least one value, so this unwrap() is safe.
+        let event_attestations = attestations.clone().try_into().unwrap();
+        self.session.replace(Session::Issuance(WalletIssuanceSession::new(
+            pid_purpose,
+            SessionState::Issuance {
+                preview_attestations: event_attestations,
+                protocol_state: issuance_session,
+            },
+        )));
+
+        Ok(attestations)
+    }
+
+    /// Converts the previews of the issuance session to [`AttestationPresentation`]s, each of which has the identity of
+    /// the stored attestation it matches, if any.
+    pub(super) async fn issuance_preview_attestations(
+        &self,
+        issuance_session: &CID::Issuance,
+        pid_purpose: Option<PidIssuancePurpose>,
     ) -> Result<Vec<AttestationPresentation>, IssuanceError> {
         let previews = issuance_session.credential_previews();
+        let config = self.config_repository.get();
+
+        for preview in previews.as_ref() {
+            self.check_certificate_revocation(
+                &preview.issuer_certificate,
+                config.issuer_trust_anchors(),
+                Some(CertificateUsage::Mdl),
+                config.certificate_revocation,
+            )
+            .await
+            .map_err(IssuanceError::CertificateRevocation)?;
+        }
+
         let preview_attestation_types_and_formats = previews
             .iter()
             .map(|preview| CredentialKind::new(preview.format, preview.credential_payload.attestation_type.clone()))
             .collect();
         let type_metadata = issuance_session.type_metadata();
 
-        let config = self.config_repository.get();
         if pid_purpose.is_some() {
             self.compare_recovery_code_against_stored(
                 Self::pid_preview(previews.as_ref(), &config.pid_attributes)?,
@@ -599,17 +900,6 @@ where
             })
             .collect::<Result<Vec<_>, IssuanceError>>()?;
 
-        // The IssuanceSession trait guarantees that credential_preview_data()
-        // returns at least one value, so this unwrap() is safe.
-        let event_attestations = attestations.clone().try_into().unwrap();
-        self.session.replace(Session::Issuance(WalletIssuanceSession::new(
-            pid_purpose,
-            SessionState::Issuance {
-                preview_attestations: event_attestations,
-                protocol_state: issuance_session,
-            },
-        )));
-
         Ok(attestations)
     }
 
@@ -643,13 +933,13 @@ where
         };
 
         info!("Signing nonce using Wallet Provider");
-        let issuance_result = protocol_state
+        let issuance_outcome = protocol_state
             .accept_issuance(config.issuer_trust_anchors(), &remote_wscd)
             .await
             .map_err(|error| Self::handle_accept_issuance_error(error, protocol_state));
 
         // In some cases, the contents of the wallet need to be wiped and the wallet returned to its initial state.
-        let issued_credentials_with_metadata = match issuance_result {
+        let issuance_outcome = match issuance_outcome {
             Err(error @ IssuanceError::Instruction(InstructionError::Timeout { .. } | InstructionError::Blocked)) => {
                 if pid_purpose.is_some() {
                     self.reset_to_initial_state().await;
@@ -660,20 +950,21 @@ where
                 self.handle_wallet_revocation(data).await;
                 return Err(error);
             }
-            _ => issuance_result?,
+            _ => issuance_outcome?,
         };
 
-        info!("Issuance succeeded; removing issuance session state");
-        let preview_attestations = match self.session.take() {
-            Some(Session::Issuance(session)) => match session.into_session_state() {
-                SessionState::Issuance {
-                    preview_attestations, ..
-                } => preview_attestations,
-                _ => unreachable!(),
-            },
-            _ => return Err(IssuanceError::SessionState),
+        let issued_credentials_with_metadata = match issuance_outcome {
+            IssuanceOutcome::Issued(credentials) => credentials,
+            // The PID is required to be issued immediately, as the wallet cannot function without it.
+            IssuanceOutcome::Deferred(_) if pid_purpose.is_some() => return Err(IssuanceError::DeferredPidIssuance),
+            IssuanceOutcome::Deferred(deferred_issuance) => {
+                return self.store_deferred_issuance(deferred_issuance).await;
+            }
         };
 
+        info!("Issuance succeeded; removing issuance session state");
+        let preview_attestations = self.take_issuance_preview_attestations()?;
+
         let transfer_session_id = if pid_purpose.is_some() {
             info!("This is a PID issuance session, therefore disclosing recovery code");
             self.disclose_recovery_code(
@@ -700,16 +991,337 @@ where
                 .map_err(IssuanceError::TransferDataStorage)?;
         }
 
-        let all_previews = issued_credentials_with_metadata
-            .into_iter()
-            .zip_eq(preview_attestations)
+        info!("Attestations accepted, storing credentials in database");
+        self.store_issued_credentials(issued_credentials_with_metadata, preview_attestations)
+            .await?;
+
+        self.emit_attestations().await?;
+        self.emit_notifications().await?;
+        self.emit_recent_history().await?;
+
+        Ok(IssuanceResult {
+            transfer_session_id,
+            is_deferred: false,
+        })
+    }
+
+    /// Poll the issuers of all pending deferred issuance sessions for which the polling interval has passed. Any
+    /// attestations that have been issued in the meantime are stored in the database. Note that this holds on to the
+    /// wallet while polling, see [`Self::prepare_deferred_issuance_poll()`] for how to prevent that.
+    pub async fn poll_deferred_issuances(&mut self) -> Result<(), IssuanceError> {
+        let Some(poll) = self.prepare_deferred_issuance_poll().await? else {
+            return Ok(());
+        };
+
+        let results = poll.execute().await;
+
+        self.finish_deferred_issuance_poll(results).await
+    }
+
+    /// Collect the pending deferred issuance sessions for which the polling interval has passed. Polling their issuers
+    /// using [`DeferredIssuancePoll::execute()`] does not require access to the wallet, so that it can be used in the
+    /// meantime. The results should then be passed to [`Self::finish_deferred_issuance_poll()`]. Returns `None` when
+    /// there is nothing to poll.
+    #[instrument(skip_all)]
+    #[sentry_capture_error]
+    pub async fn prepare_deferred_issuance_poll(&self) -> Result<Option<DeferredIssuancePoll<CID>>, IssuanceError> {
+        info!("Preparing to poll deferred issuance sessions");
+
+        self.check_session_preconditions()?;
+
+        let Some(PendingDeferredIssuances(pending_issuances)) = self
+            .storage
+            .read()
+            .await
+            .fetch_data::<PendingDeferredIssuances>()
+            .await
+            .map_err(IssuanceError::DeferredIssuanceStorage)?
+        else {
+            info!("No deferred issuance sessions pending");
+            return Ok(None);
+        };
+
+        let now = Utc::now();
+        let mut due_issuances = Vec::new();
+        let mut discarded_dpop_key_identifiers = Vec::new();
+
+        for mut pending_issuance in pending_issuances {
+            if pending_issuance.next_poll > now {
+                continue;
+            }
+
+            let dpop_key_identifier = pending_issuance.deferred_issuance.dpop_key().identifier().to_string();
+
+            // The DPoP private key is not persisted along with the session, so restore it from storage first.
+            let dpop_key = self
+                .storage
+                .read()
+                .await
+                .fetch_dpop_key(&dpop_key_identifier)
+                .await
+                .map_err(IssuanceError::DeferredIssuanceStorage)?;
+            let restored = dpop_key
+                .ok_or(WalletIssuanceError::DpopKeyMissing)
+                .and_then(|dpop_key| pending_issuance.deferred_issuance.restore_dpop_key(&dpop_key));
+
+            match restored {
+                Ok(()) => due_issuances.push(pending_issuance),
+                Err(error) => {
+                    warn!("Could not restore DPoP key for deferred issuance, discarding session: {error}");
+                    discarded_dpop_key_identifiers.push(dpop_key_identifier);
+                }
+            }
+        }
+
+        if due_issuances.is_empty() && discarded_dpop_key_identifiers.is_empty() {
+            info!("No deferred issuance sessions due to be polled");
+            return Ok(None);
+        }
+
+        let poll = DeferredIssuancePoll {
+            issuance_discovery: Arc::clone(&self.issuance_discovery),
+            config: self.config_repository.get(),
+            due_issuances,
+            discarded_dpop_key_identifiers,
+        };
+
+        Ok(Some(poll))
+    }
+
+    /// Store the results of polling the issuers of deferred issuance sessions. As the wallet may have been used while
+    /// polling, these results are merged with the sessions that are pending at this moment. The result for a session
+    /// that is no longer pending is ignored.
+    #[instrument(skip_all)]
+    #[sentry_capture_error]
+    pub async fn finish_deferred_issuance_poll(
+        &mut self,
+        results: DeferredIssuancePollResults,
+    ) -> Result<(), IssuanceError> {
+        let DeferredIssuancePollResults {
+            outcomes,
+            discarded_dpop_key_identifiers,
+        } = results;
+
+        let mut pending_issuances = self
+            .storage
+            .read()
+            .await
+            .fetch_data::<PendingDeferredIssuances>()
+            .await
+            .map_err(IssuanceError::DeferredIssuanceStorage)?
+            .unwrap_or_default()
+            .0;
+
+        let now = Utc::now();
+        let mut finished_dpop_key_identifiers = discarded_dpop_key_identifiers;
+        let mut has_issued = false;
+
+        for (polled_issuance, result) in outcomes {
+            let PendingDeferredIssuance {
+                deferred_issuance,
+                preview_attestations,
+                ..
+            } = polled_issuance;
+            let dpop_key_identifier = deferred_issuance.dpop_key().identifier().to_string();
+
+            // Every session uses its own DPoP key, so its identifier also identifies the session.
+            let Some(pending_issuance) = pending_issuances.iter_mut().find(|pending_issuance| {
+                pending_issuance.deferred_issuance.dpop_key().identifier() == dpop_key_identifier
+            }) else {
+                warn!("Deferred issuance session is no longer pending, ignoring poll result");
+                continue;
+            };
+
+            match result {
+                Ok(IssuanceOutcome::Issued(credentials)) => {
+                    info!("Deferred attestations received, storing credentials in database");
+                    self.store_issued_credentials(credentials, preview_attestations).await?;
+                    finished_dpop_key_identifiers.push(dpop_key_identifier);
+                    has_issued = true;
+                }
+                Ok(IssuanceOutcome::Deferred(deferred_issuance)) => {
+                    info!("Deferred attestations not yet available");
+                    *pending_issuance = PendingDeferredIssuance {
+                        next_poll: now + deferred_issuance.interval,
+                        deferred_issuance,
+                        preview_attestations,
+                    };
+                }
+                // The issuer explicitly refused the request, e.g. because it has rejected issuance or the transaction
+                // has expired, so polling again will not yield any different result.
+                Err(error @ WalletIssuanceError::CredentialRequest(_)) => {
+                    warn!("Issuer refused deferred issuance, discarding session: {error}");
+                    finished_dpop_key_identifiers.push(dpop_key_identifier);
+                }
+                Err(error) => {
+                    warn!("Could not poll issuer for deferred attestations, retrying later: {error}");
+                    pending_issuance.next_poll = now + deferred_issuance.interval;
+                }
+            }
+        }
+
+        pending_issuances.retain(|pending_issuance| {
+            let dpop_key_identifier = pending_issuance.deferred_issuance.dpop_key().identifier();
+
+            !finished_dpop_key_identifiers
+                .iter()
+                .any(|identifier| identifier == dpop_key_identifier)
+        });
+
+        if !finished_dpop_key_identifiers.is_empty() {
+            let mut storage = self.storage.write().await;
+
+            match storage.fetch_data::<IssuedCredentialNotifications>().await {
+                Ok(notifications) => {
+                    Self::delete_unused_dpop_keys(
+                        &mut *storage,
+                        &finished_dpop_key_identifiers.iter().map(String::as_str).collect_vec(),
+                        &notifications.unwrap_or_default().0,
+                        &pending_issuances,
+                    )
+                    .await
+                }
+                Err(error) => warn!("could not fetch issuer notifications: {error}"),
+            }
+        }
+
+        self.write_pending_deferred_issuances(pending_issuances).await?;
+
+        if has_issued {
+            self.emit_attestations().await?;
+            self.emit_notifications().await?;
+            self.emit_recent_history().await?;
+        }
+
+        Ok(())
+    }
+
+    async fn store_deferred_issuance(
+        &mut self,
+        deferred_issuance: DeferredIssuance,
+    ) -> Result<IssuanceResult, IssuanceError> {
+        info!("Issuance deferred by issuer; removing issuance session state");
+        let preview_attestations = self.take_issuance_preview_attestations()?;
+
+        self.add_pending_deferred_issuance(deferred_issuance, preview_attestations)
+            .await?;
+
+        Ok(IssuanceResult {
+            transfer_session_id: None,
+            is_deferred: true,
+        })
+    }
+
+    pub(super) async fn add_pending_deferred_issuance(
+        &self,
+        deferred_issuance: DeferredIssuance,
+        preview_attestations: VecNonEmpty<AttestationPresentation>,
+    ) -> Result<(), IssuanceError> {
+        info!("Storing deferred issuance session in database");
+
+        // The DPoP private key is not persisted along with the session, but separately.
+        if let Some(dpop_key) = deferred_issuance.dpop_key().to_bytes() {
+            self.storage
+                .write()
+                .await
+                .insert_dpop_key(deferred_issuance.dpop_key().identifier(), &dpop_key)
+                .await
+                .map_err(IssuanceError::DeferredIssuanceStorage)?;
+        }
+
+        let mut pending_issuances = self
+            .storage
+            .read()
+            .await
+            .fetch_data::<PendingDeferredIssuances>()
+            .await
+            .map_err(IssuanceError::DeferredIssuanceStorage)?
+            .unwrap_or_default()
+            .0;
+
+        pending_issuances.push(PendingDeferredIssuance {
+            next_poll: Utc::now() + deferred_issuance.interval,
+            deferred_issuance,
+            preview_attestations,
+        });
+
+        self.write_pending_deferred_issuances(pending_issuances).await
+    }
+
+    async fn write_pending_deferred_issuances(
+        &self,
+        pending_issuances: Vec<PendingDeferredIssuance>,
+    ) -> Result<(), IssuanceError> {
+        let mut storage = self.storage.write().await;
+
+        let result = if pending_issuances.is_empty() {
+            storage.delete_data::<PendingDeferredIssuances>().await
+        } else {
+            storage.upsert_data(&PendingDeferredIssuances(pending_issuances)).await
+        };
+
+        result.map_err(IssuanceError::DeferredIssuanceStorage)
+    }
+
+    fn take_issuance_preview_attestations(&mut self) -> Result<VecNonEmpty<AttestationPresentation>, IssuanceError> {
+        match self.session.take() {
+            Some(Session::Issuance(session)) => match session.into_session_state() {
+                SessionState::Issuance {
+                    preview_attestations, ..
+                } => Ok(preview_attestations),
+                _ => unreachable!(),
+            },
+            _ => Err(IssuanceError::SessionState),
+        }
+    }
+
+    pub(super) async fn store_issued_credentials(
+        &self,
+        credentials: Vec<CredentialWithMetadata>,
+        preview_attestations: VecNonEmpty<AttestationPresentation>,
+    ) -> Result<(), IssuanceError> {
+        let notifications = credentials
+            .iter()
+            .filter_map(|credential| {
+                credential
+                    .notification
+                    .clone()
+                    .map(|notification| (credential.copies.first_key_identifier().to_string(), notification))
+            })
             .collect_vec();
+        let sent_notifications = notifications.iter().map(|(_, notification)| notification).collect_vec();
+
+        match self
+            .insert_or_update_credentials(credentials, preview_attestations)
+            .await
+        {
+            Ok(()) => {
+                self.send_issuer_notifications(&sent_notifications, NotificationEvent::CredentialAccepted)
+                    .await;
+                self.store_issuer_notifications(notifications).await;
+
+                Ok(())
+            }
+            Err(error) => {
+                self.send_issuer_notifications(&sent_notifications, NotificationEvent::CredentialFailure)
+                    .await;
+
+                Err(error)
+            }
+        }
+    }
+
+    async fn insert_or_update_credentials(
+        &self,
+        credentials: Vec<CredentialWithMetadata>,
+        preview_attestations: VecNonEmpty<AttestationPresentation>,
+    ) -> Result<(), IssuanceError> {
+        let all_previews = credentials.into_iter().zip_eq(preview_attestations).collect_vec();
 
         let (existing, new): (Vec<_>, Vec<_>) = all_previews
             .into_iter()
             .partition(|(_, preview)| matches!(preview.identity, AttestationIdentity::Fixed { .. }));
 
-        info!("Attestations accepted, storing credentials in database");
         if !existing.is_empty() {
             self.storage
                 .write()
@@ -718,7 +1330,7 @@ where
                     Utc::now(),
                     existing
                         .into_iter()
-                        .map(|(credential, preview)| (credential.copies, preview))
+                        .map(|(credential, preview)| (credential.copies, credential.refresh, preview))
                         .collect_vec(),
                 )
                 .await
@@ -733,11 +1345,7 @@ where
                 .map_err(IssuanceError::AttestationStorage)?;
         }
 
-        self.emit_attestations().await?;
-        self.emit_notifications().await?;
-        self.emit_recent_history().await?;
-
-        Ok(IssuanceResult { transfer_session_id })
+        Ok(())
     }
 
     pub(super) fn handle_accept_issuance_error(
@@ -895,6 +1503,9 @@ mod tests {
     use futures::FutureExt;
     use itertools::multiunzip;
     use mockall::predicate::*;
+    use openid4vc::errors::CredentialErrorCode;
+    use openid4vc::errors::ErrorResponse;
+    use openid4vc::errors::RemoteErrorCode;
     use openid4vc::wallet_issuance::IssuanceFlow;
     use openid4vc::wallet_issuance::mock::MockAuthorizationSession;
     use openid4vc::wallet_issuance::mock::MockAuthorizationSessionData;
@@ -948,7 +1559,7 @@ mod tests {
 
         // Set up the credential issuer discovery mock
         wallet
-            .issuance_discovery
+            .mut_issuance_discovery()
             .expect_start_authorization_code_flow_sync()
             .return_once(|| {
                 let mut authorization_session = MockAuthorizationSession::new();
@@ -1725,7 +2336,7 @@ mod tests {
             .expect_upsert_data::<PersistedIssuanceSessionData<MockAuthorizationSessionData>>()
             .return_once(move |_| Ok(()));
 
-        wallet.issuance_discovery.expect_start_sync().return_once(|| {
+        wallet.mut_issuance_discovery().expect_start_sync().return_once(|| {
             let mut session = MockAuthorizationSession::new();
             session
                 .expect_get_auth_url()
@@ -1757,19 +2368,22 @@ mod tests {
             "some_config_id".to_string().into(),
         );
 
-        wallet.issuance_discovery.expect_start_sync().return_once(move || {
-            let mut session = MockIssuanceSession::new();
-            session
-                .expect_type_metadata()
-                .return_const([("some_attestation_type".to_string(), type_metadata)].into());
-            session
-                .expect_credential_previews()
-                .return_const(vec_nonempty![preview]);
-            session.expect_issuer().return_const(IssuerRegistration::new_mock());
-            Ok(IssuanceFlow::PreAuthorizedCode {
-                issuance_session: session,
-            })
-        });
+        wallet
+            .mut_issuance_discovery()
+            .expect_start_sync()
+            .return_once(move || {
+                let mut session = MockIssuanceSession::new();
+                session
+                    .expect_type_metadata()
+                    .return_const([("some_attestation_type".to_string(), type_metadata)].into());
+                session
+                    .expect_credential_previews()
+                    .return_const(vec_nonempty![preview]);
+                session.expect_issuer().return_const(IssuerRegistration::new_mock());
+                Ok(IssuanceFlow::PreAuthorizedCode {
+                    issuance_session: session,
+                })
+            });
 
         wallet
             .mut_storage()
@@ -1785,6 +2399,27 @@ mod tests {
         assert!(wallet.session.is_some());
     }
 
+    #[tokio::test]
+    async fn test_start_issuance_from_offer_error_tx_code_required() {
+        let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
+
+        wallet
+            .mut_issuance_discovery()
+            .expect_start_sync()
+            .return_once(|| Err(WalletIssuanceError::CredentialOfferTxCodeRequired(Box::default())));
+
+        let error = wallet
+            .start_issuance_from_offer(Url::parse(OFFER_URI).unwrap())
+            .await
+            .expect_err("Starting issuance from offer should have resulted in an error");
+
+        assert_matches!(
+            error,
+            IssuanceError::IssuanceSession(WalletIssuanceError::CredentialOfferTxCodeRequired(_))
+        );
+        assert!(wallet.session.is_none());
+    }
+
     #[tokio::test]
     async fn test_start_issuance_from_offer_error_locked() {
         let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
@@ -2327,6 +2962,77 @@ mod tests {
         assert!(!wallet.is_locked());
     }
 
+    #[rstest]
+    #[tokio::test]
+    async fn test_store_issued_credentials_sends_notifications(#[values(true, false)] storage_succeeds: bool) {
+        let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
+
+        let (sd_jwt_credential, metadata_documents, _) = sd_jwt_pid();
+        let (issuance_session, attestations) = mock_issuance_session([(sd_jwt_credential, metadata_documents)]);
+        let Ok(IssuanceOutcome::Issued(credentials)) = issuance_session.accept() else {
+            panic!("mock issuance session should issue credentials");
+        };
+        let credentials = credentials
+            .into_iter()
+            .map(|credential| credential.with_notification(Some(CredentialNotification::new_mock("notification_id"))))
+            .collect_vec();
+
+        wallet
+            .mut_storage()
+            .expect_insert_credentials()
+            .times(1)
+            .return_once(move |_, _| {
+                if storage_succeeds {
+                    Ok(())
+                } else {
+                    Err(StorageError::AlreadyOpened)
+                }
+            });
+
+        // The issuer should be notified of either the acceptance or the failure to store the credential.
+        let expected_event = if storage_succeeds {
+            NotificationEvent::CredentialAccepted
+        } else {
+            NotificationEvent::CredentialFailure
+        };
+        wallet
+            .mut_issuance_discovery()
+            .expect_send_notification_sync()
+            .withf(move |notification, event| {
+                notification.notification_id == "notification_id" && *event == expected_event
+            })
+            .times(1)
+            .return_once(|_, _| Ok(()));
+
+        // Only when the credential was stored successfully should its notification and DPoP key be stored.
+        if storage_succeeds {
+            wallet
+                .mut_storage()
+                .expect_insert_dpop_key()
+                .times(1)
+                .returning(|_, _| Ok(()));
+            wallet
+                .mut_storage()
+                .expect_fetch_data::<IssuedCredentialNotifications>()
+                .times(1)
+                .return_once(|| Ok(None));
+            wallet
+                .mut_storage()
+                .expect_upsert_data::<IssuedCredentialNotifications>()
+                .times(1)
+                .return_once(|IssuedCredentialNotifications(notifications)| {
+                    assert_eq!(notifications.len(), 1);
+                    assert_eq!(notifications["key_id"].notification_id, "notification_id");
+
+                    Ok(())
+                });
+        }
+
+        let result = wallet.store_issued_credentials(credentials, attestations).await;
+
+        assert_eq!(result.is_ok(), storage_succeeds);
+    }
+
     #[test]
     fn test_match_preview_and_stored_attestations() {
         let ca = Ca::generate_mock();
@@ -2493,4 +3199,326 @@ mod tests {
         assert!(wallet.has_registration());
         assert!(!wallet.is_locked());
     }
+
+    fn mock_deferred_issuance() -> DeferredIssuance {
+        let (preview, _) = create_example_pid_preview_data(&MockTimeGenerator::default(), Format::SdJwt);
+
+        DeferredIssuance::new_mock(vec_nonempty![preview])
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_accept_issuance_deferred(#[values(true, false)] has_pending: bool) {
+        let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
+
+        wallet
+            .mut_storage()
+            .expect_fetch_data::<ChangePinData>()
+            .returning(|| Ok(None));
+
+        // Any previously pending deferred issuance session should be retained.
+        wallet
+            .mut_storage()
+            .expect_fetch_data::<PendingDeferredIssuances>()
+            .times(1)
+            .return_once(move || {
+                Ok(has_pending.then(|| {
+                    PendingDeferredIssuances(vec![PendingDeferredIssuance {
+                        deferred_issuance: mock_deferred_issuance(),
+                        preview_attestations: vec_nonempty![AttestationPresentation::new_mock()],
+                        next_poll: Utc::now(),
+                    }])
+                }))
+            });
+        wallet
+            .mut_storage()
+            .expect_upsert_data::<PendingDeferredIssuances>()
+            .times(1)
+            .return_once(move |PendingDeferredIssuances(pending_issuances)| {
+                assert_eq!(pending_issuances.len(), if has_pending { 2 } else { 1 });
+
+                let pending_issuance = pending_issuances.last().unwrap();
+                assert_eq!(pending_issuance.deferred_issuance.transaction_id, "transaction_id");
+                assert!(pending_issuance.next_poll > Utc::now());
+
+                Ok(())
+            });
+
+        // The DPoP key of the new session should be stored separately.
+        wallet
+            .mut_storage()
+            .expect_insert_dpop_key()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let issuer = {
+            let mut client = MockIssuanceSession::new();
+            client
+                .expect_accept()
+                .return_once(|| Ok(IssuanceOutcome::Deferred(mock_deferred_issuance())));
+            client.expect_issuer().return_const(IssuerRegistration::new_mock());
+
+            client
+        };
+        wallet.session = Some(Session::Issuance(WalletIssuanceSession::Generic {
+            session_state: SessionState::Issuance {
+                preview_attestations: vec_nonempty![AttestationPresentation::new_mock()],
+                protocol_state: issuer,
+            },
+        }));
+
+        let result = wallet
+            .accept_issuance(PIN.clone())
+            .await
+            .expect("Accepting deferred issuance should succeed");
+
+        assert!(result.is_deferred);
+        assert!(result.transfer_session_id.is_none());
+        assert!(wallet.session.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_accept_pid_issuance_error_deferred() {
+        let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
+
+        wallet
+            .mut_storage()
+            .expect_fetch_data::<ChangePinData>()
+            .returning(|| Ok(None));
+
+        let pid_issuer = {
+            let mut client = MockIssuanceSession::new();
+            client
+                .expect_accept()
+                .return_once(|| Ok(IssuanceOutcome::Deferred(mock_deferred_issuance())));
+            client.expect_issuer().return_const(IssuerRegistration::new_mock());
+
+            client
+        };
+        wallet.session = Some(Session::Issuance(WalletIssuanceSession::Pid {
+            purpose: PidIssuancePurpose::Enrollment,
+            session_state: SessionState::Issuance {
+                preview_attestations: vec_nonempty![AttestationPresentation::new_mock()],
+                protocol_state: pid_issuer,
+            },
+        }));
+
+        let error = wallet
+            .accept_issuance(PIN.clone())
+            .await
+            .expect_err("Accepting deferred PID issuance should have resulted in an error");
+
+        assert_matches!(error, IssuanceError::DeferredPidIssuance);
+        assert!(wallet.has_registration());
+    }
+
+    #[rstest]
+    #[case(Ok(IssuanceOutcome::Deferred(mock_deferred_issuance())))]
+    #[case(Err(WalletIssuanceError::IssuerMismatch))]
+    #[tokio::test]
+    async fn test_poll_deferred_issuances_pending(#[case] poll_result: Result<IssuanceOutcome, WalletIssuanceError>) {
+        let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
+
+        // Of the two pending sessions, only the first one should be polled. The pending sessions are fetched both
+        // before and after polling.
+        let not_yet_due = Utc::now() + Duration::hours(1);
+        let deferred_issuance = mock_deferred_issuance();
+        let dpop_key_identifier = deferred_issuance.dpop_key().identifier().to_string();
+        let dpop_key = deferred_issuance.dpop_key().to_bytes().unwrap();
+        let pending_issuances = PendingDeferredIssuances(vec![
+            PendingDeferredIssuance {
+                deferred_issuance,
+                preview_attestations: vec_nonempty![AttestationPresentation::new_mock()],
+                next_poll: Utc::now() - Duration::minutes(1),
+            },
+            PendingDeferredIssuance {
+                deferred_issuance: mock_deferred_issuance(),
+                preview_attestations: vec_nonempty![AttestationPresentation::new_mock()],
+                next_poll: not_yet_due,
+            },
+        ]);
+        wallet
+            .mut_storage()
+            .expect_fetch_data::<PendingDeferredIssuances>()
+            .times(2)
+            .returning(move || Ok(Some(pending_issuances.clone())));
+
+        // The DPoP key of the polled session should be restored from storage.
+        wallet
+            .mut_storage()
+            .expect_fetch_dpop_key()
+            .with(eq(dpop_key_identifier))
+            .times(1)
+            .return_once(|_| Ok(Some(dpop_key)));
+
+        wallet
+            .mut_issuance_discovery()
+            .expect_poll_deferred_issuance_sync()
+            .times(1)
+            .return_once(|_| poll_result);
+
+        // Both sessions should still be pending afterwards, with the polled one being scheduled for a next poll.
+        wallet
+            .mut_storage()
+            .expect_upsert_data::<PendingDeferredIssuances>()
+            .times(1)
+            .return_once(move |PendingDeferredIssuances(pending_issuances)| {
+                assert_eq!(pending_issuances.len(), 2);
+                assert!(pending_issuances.iter().all(|pending| pending.next_poll > Utc::now()));
+                assert_eq!(pending_issuances[1].next_poll, not_yet_due);
+
+                Ok(())
+            });
+
+        wallet
+            .poll_deferred_issuances()
+            .await
+            .expect("Polling deferred issuances should succeed");
+    }
+
+    #[tokio::test]
+    async fn test_poll_deferred_issuances_refused() {
+        let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
+
+        let deferred_issuance = mock_deferred_issuance();
+        let dpop_key_identifier = deferred_issuance.dpop_key().identifier().to_string();
+        let dpop_key = deferred_issuance.dpop_key().to_bytes().unwrap();
+        let pending_issuances = PendingDeferredIssuances(vec![PendingDeferredIssuance {
+            deferred_issuance,
+            preview_attestations: vec_nonempty![AttestationPresentation::new_mock()],
+            next_poll: Utc::now() - Duration::minutes(1),
+        }]);
+        wallet
+            .mut_storage()
+            .expect_fetch_data::<PendingDeferredIssuances>()
+            .times(2)
+            .returning(move || Ok(Some(pending_issuances.clone())));
+        wallet
+            .mut_storage()
+            .expect_fetch_dpop_key()
+            .times(1)
+            .return_once(|_| Ok(Some(dpop_key)));
+
+        wallet
+            .mut_issuance_discovery()
+            .expect_poll_deferred_issuance_sync()
+            .times(1)
+            .return_once(|_| {
+                Err(WalletIssuanceError::CredentialRequest(Box::new(ErrorResponse {
+                    error: RemoteErrorCode::Known(CredentialErrorCode::InvalidTransactionId),
+                    error_description: None,
+                    error_uri: None,
+                })))
+            });
+
+        // The refused session should be discarded, along with its DPoP key, as nothing else uses it.
+        wallet
+            .mut_storage()
+            .expect_fetch_data::<IssuedCredentialNotifications>()
+            .times(1)
+            .return_once(|| Ok(None));
+        wallet
+            .mut_storage()
+            .expect_delete_dpop_key()
+            .with(eq(dpop_key_identifier))
+            .times(1)
+            .returning(|_| Ok(()));
+        wallet
+            .mut_storage()
+            .expect_delete_data::<PendingDeferredIssuances>()
+            .times(1)
+            .return_once(|| Ok(()));
+
+        wallet
+            .poll_deferred_issuances()
+            .await
+            .expect("Polling deferred issuances should succeed");
+    }
+
+    #[tokio::test]
+    async fn test_poll_deferred_issuances_merge_pending() {
+        let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
+
+        let deferred_issuance = mock_deferred_issuance();
+        let dpop_key = deferred_issuance.dpop_key().to_bytes().unwrap();
+        let polled_issuance = PendingDeferredIssuance {
+            deferred_issuance,
+            preview_attestations: vec_nonempty![AttestationPresentation::new_mock()],
+            next_poll: Utc::now() - Duration::minutes(1),
+        };
+        wallet
+            .mut_storage()
+            .expect_fetch_data::<PendingDeferredIssuances>()
+            .times(1)
+            .return_once({
+                let polled_issuance = polled_issuance.clone();
+                move || Ok(Some(PendingDeferredIssuances(vec![polled_issuance])))
+            });
+        wallet
+            .mut_storage()
+            .expect_fetch_dpop_key()
+            .times(1)
+            .return_once(|_| Ok(Some(dpop_key)));
+        wallet
+            .mut_issuance_discovery()
+            .expect_poll_deferred_issuance_sync()
+            .times(1)
+            .return_once(|_| Ok(IssuanceOutcome::Deferred(mock_deferred_issuance())));
+
+        let poll = wallet
+            .prepare_deferred_issuance_poll()
+            .await
+            .expect("Preparing to poll deferred issuances should succeed")
+            .expect("A deferred issuance session should be due");
+        let results = poll.execute().await;
+
+        // While polling, another session has been added and should be retained along with the polled one.
+        wallet.mut_storage().checkpoint();
+        let added_issuance = PendingDeferredIssuance {
+            deferred_issuance: mock_deferred_issuance(),
+            preview_attestations: vec_nonempty![AttestationPresentation::new_mock()],
+            next_poll: Utc::now() + Duration::hours(1),
+        };
+        let added_dpop_key_identifier = added_issuance.deferred_issuance.dpop_key().identifier().to_string();
+        wallet
+            .mut_storage()
+            .expect_fetch_data::<PendingDeferredIssuances>()
+            .times(1)
+            .return_once(move || Ok(Some(PendingDeferredIssuances(vec![polled_issuance, added_issuance]))));
+        wallet
+            .mut_storage()
+            .expect_upsert_data::<PendingDeferredIssuances>()
+            .times(1)
+            .return_once(move |PendingDeferredIssuances(pending_issuances)| {
+                assert_eq!(pending_issuances.len(), 2);
+                assert!(pending_issuances[0].next_poll > Utc::now());
+                assert_eq!(
+                    pending_issuances[1].deferred_issuance.dpop_key().identifier(),
+                    added_dpop_key_identifier
+                );
+
+                Ok(())
+            });
+
+        wallet
+            .finish_deferred_issuance_poll(results)
+            .await
+            .expect("Finishing polling deferred issuances should succeed");
+    }
+
+    #[tokio::test]
+    async fn test_poll_deferred_issuances_none_pending() {
+        let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
+
+        wallet
+            .mut_storage()
+            .expect_fetch_data::<PendingDeferredIssuances>()
+            .times(1)
+            .return_once(|| Ok(None));
+
+        wallet
+            .poll_deferred_issuances()
+            .await
+            .expect("Polling deferred issuances should succeed");
+    }
 }
@@ -1,4 +1,5 @@
 mod attestations;
+mod certificate_revocation;
 mod change_pin;
 mod close_proximity_disclosure;
 mod config;
@@ -13,6 +14,7 @@ mod lock;
 mod notifications;
 mod pin_recovery;
 mod recovery_code;
+mod refresh;
 mod registration;
 mod reset;
 mod revocation;
@@ -24,13 +26,19 @@ mod uri;
 #[cfg(test)]
 mod test;
 
+use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
+use crypto::x509::revocation::RevocationChecker;
 use openid4vc::disclosure_session::DisclosureClient;
 use openid4vc::disclosure_session::VpDisclosureClient;
 use openid4vc::wallet_issuance::IssuanceDiscovery;
+use openid4vc::wallet_issuance::deferred::DeferredIssuance;
 use openid4vc::wallet_issuance::discovery::HttpIssuanceDiscovery;
+use openid4vc::wallet_issuance::notification::CredentialNotification;
 use parking_lot::Mutex;
 use platform_support::attested_key::AttestedKey;
 use platform_support::attested_key::AttestedKeyHolder;
@@ -42,6 +50,7 @@ use serde::de::DeserializeOwned;
 use token_status_list::verification::reqwest::HttpStatusListClient;
 use tokio::sync::RwLock;
 use tokio::task::AbortHandle;
+use utils::vec_at_least::VecNonEmpty;
 
 use self::attestations::AttestationsCallback;
 pub use self::close_proximity_disclosure::CloseProximityDisclosureError;
@@ -49,6 +58,8 @@ pub use self::close_proximity_disclosure::CloseProximityDisclosureUpdate;
 pub use self::delete_attestation::DeleteAttestationError;
 pub use self::disclosure::AttributesNotAvailable;
 pub use self::disclosure::DisclosureAttestationOptions;
+pub use self::disclosure::DisclosureAttributeIdentifier;
+pub use self::disclosure::DisclosureCredentialSet;
 pub use self::disclosure::DisclosureError;
 pub use self::disclosure::DisclosureProposalPresentation;
 pub use self::disclosure::DisclosureUriSource;
@@ -60,6 +71,8 @@ pub use self::history::RecentHistoryCallback;
 pub use self::init::WalletClients;
 pub use self::init::WalletInitError;
 pub use self::init::WalletRepositories;
+pub use self::issuance::DeferredIssuancePoll;
+pub use self::issuance::DeferredIssuancePollResults;
 pub use self::issuance::IssuanceError;
 pub use self::issuance::IssuanceResult;
 pub use self::issuance::IssuanceStartResult;
@@ -85,6 +98,7 @@ pub use self::uri::RedirectUri;
 pub use self::uri::UriIdentificationError;
 pub use self::uri::UriType;
 use crate::account_provider::HttpAccountProviderClient;
+use crate::attestation::AttestationPresentation;
 use crate::config::WalletConfigurationRepository;
 use crate::lock::WalletLock;
 use crate::storage::DatabaseStorage;
@@ -95,11 +109,10 @@ use crate::wallet::close_proximity_disclosure::CloseProximityDisclosureSession;
 use crate::wallet::notifications::DirectNotificationsCallback;
 use crate::wallet::pin_recovery::PinRecoverySession;
 
-type KeyHolderType =
-    cfg_select! {
-        feature = "fake_attestation" => platform_support::attested_key::mock::PersistentMockAttestedKeyHolder,
-        _ => platform_support::attested_key::hardware::HardwareAttestedKeyHolder,
-    };
+type KeyHolderType = cfg_select! {
+    feature = "fake_attestation" => platform_support::attested_key::mock::PersistentMockAttestedKeyHolder,
+    _ => platform_support::attested_key::hardware::HardwareAttestedKeyHolder,
+};
 
 #[derive(Debug, Default)]
 enum WalletRegistration<A, G> {
@@ -149,6 +162,30 @@ where
     const KEY: &'static str = "issuance_oauth_session";
 }
 
+/// The issuance sessions for which the issuer has deferred issuance, which should be polled periodically.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(super) struct PendingDeferredIssuances(Vec<PendingDeferredIssuance>);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(super) struct PendingDeferredIssuance {
+    deferred_issuance: DeferredIssuance,
+    preview_attestations: VecNonEmpty<AttestationPresentation>,
+    next_poll: DateTime<Utc>,
+}
+
+impl KeyedData for PendingDeferredIssuances {
+    const KEY: &'static str = "pending_deferred_issuances";
+}
+
+/// The notifications that can still be sent to the issuers of stored attestations, e.g. when the user deletes one of
+/// these. These are keyed by the identifier of the private key of the first copy of each attestation.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(super) struct IssuedCredentialNotifications(HashMap<String, CredentialNotification>);
+
+impl KeyedData for IssuedCredentialNotifications {
+    const KEY: &'static str = "issued_credential_notifications";
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(super) struct PersistedPinRecoverySessionData<AD> {
     authorization_session: AD,
@@ -182,10 +219,11 @@ pub struct Wallet<
     key_holder: AKH,
     registration: WalletRegistration<AKH::AppleKey, AKH::GoogleKey>,
     account_provider_client: Arc<APC>,
-    issuance_discovery: CID,
+    issuance_discovery: Arc<CID>,
     disclosure_client: DCC,
     close_proximity_disclosure: PhantomData<CPC>,
     status_list_client: Arc<SLC>,
+    certificate_revocation_checker: Option<RevocationChecker>,
     session: Option<Session<CID::Authorization, CID::Issuance, DCC::Session>>,
     lock: WalletLock,
     attestations_callback: Arc<Mutex<Option<AttestationsCallback>>>,
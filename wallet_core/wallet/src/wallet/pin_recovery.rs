@@ -13,6 +13,7 @@ use openid4vc::wallet_issuance::IssuanceDiscovery;
 use openid4vc::wallet_issuance::IssuanceSession;
 use openid4vc::wallet_issuance::WalletIssuanceError;
 use openid4vc::wallet_issuance::authorization::OAuthError;
+use openid4vc::wallet_issuance::credential::IssuanceOutcome;
 use openid4vc::wallet_issuance::credential::IssuedCredentialCopies;
 use p256::ecdsa::VerifyingKey;
 use platform_support::attested_key::AttestedKeyHolder;
@@ -385,6 +386,10 @@ where
             _ => issuance_result?,
         };
 
+        let IssuanceOutcome::Issued(issued_credentials) = issuance_result else {
+            return Err(IssuanceError::DeferredPidIssuance.into());
+        };
+
         // Store the new wallet certificate and the new salt.
 
         let new_wallet_certificate = pin_recovery_wscd
@@ -406,7 +411,7 @@ where
         };
 
         // Get an SD-JWT copy out of the PID we just received.
-        let pid = issuance_result
+        let pid = issued_credentials
             .into_iter()
             .filter(|attestation| attestation.attestation_type == pid_attestation_type)
             .find_map(|attestation| match attestation.copies {
@@ -539,7 +544,7 @@ mod tests {
 
     fn setup_issuer_metadata_mock(wallet: &mut TestWalletMockStorage) {
         wallet
-            .issuance_discovery
+            .mut_issuance_discovery()
             .expect_start_authorization_code_flow_sync()
             .return_once(|| {
                 let mut authorization_session = MockAuthorizationSession::new();
@@ -0,0 +1,239 @@
+use std::sync::Arc;
+
+use chrono::TimeDelta;
+use chrono::Utc;
+use itertools::Itertools;
+use openid4vc::disclosure_session::DisclosureClient;
+use openid4vc::errors::RemoteErrorCode;
+use openid4vc::errors::TokenErrorCode;
+use openid4vc::wallet_issuance::IssuanceDiscovery;
+use openid4vc::wallet_issuance::IssuanceSession;
+use openid4vc::wallet_issuance::WalletIssuanceError;
+use openid4vc::wallet_issuance::credential::IssuanceOutcome;
+use openid4vc::wallet_issuance::refresh::CredentialRefresh;
+use platform_support::attested_key::AttestedKeyHolder;
+use tracing::info;
+use tracing::instrument;
+use tracing::warn;
+use update_policy_model::update_policy::VersionState;
+use uuid::Uuid;
+use wallet_configuration::wallet_config::WalletConfiguration;
+use wscd::wscd::IssuanceWscd;
+
+use super::IssuanceError;
+use super::Wallet;
+use crate::account_provider::AccountProviderClient;
+use crate::repository::Repository;
+use crate::storage::Storage;
+use crate::wallet::state::CheckPreconditionsError;
+
+/// Attestations are reissued when at most this fraction of their copies has not been disclosed yet.
+const REFRESH_MAX_UNUSED_COPIES_RATIO: f64 = 0.25;
+
+/// Attestations are reissued when they expire within this period.
+const REFRESH_EXPIRY_MARGIN: TimeDelta = TimeDelta::days(7);
+
+impl<CR, UR, S, AKH, APC, CID, DCC, CPC, SLC> Wallet<CR, UR, S, AKH, APC, CID, DCC, CPC, SLC>
+where
+    CR: Repository<Arc<WalletConfiguration>>,
+    UR: Repository<VersionState>,
+    S: Storage,
+    AKH: AttestedKeyHolder,
+    APC: AccountProviderClient,
+    CID: IssuanceDiscovery,
+    DCC: DisclosureClient,
+{
+    /// Reissue all attestations for which the issuer handed out a refresh token and that are either running low on
+    /// unused copies or are about to expire. This does not involve the user, but it does require a WSCD that is able to
+    /// generate new keys, which is why this is called right after the user has entered their PIN. Any errors are
+    /// logged, after which reissuance is simply attempted again on a later occasion.
+    #[instrument(skip_all)]
+    pub(super) async fn refresh_attestations<W>(&mut self, wscd: &W)
+    where
+        W: IssuanceWscd,
+    {
+        let result = self
+            .storage
+            .read()
+            .await
+            .fetch_attestation_refreshes_due(Utc::now() + REFRESH_EXPIRY_MARGIN, REFRESH_MAX_UNUSED_COPIES_RATIO)
+            .await;
+
+        let refreshes = match result {
+            Ok(refreshes) => refreshes,
+            Err(error) => {
+                warn!("Could not fetch attestations to refresh: {error}");
+                return;
+            }
+        };
+
+        if refreshes.is_empty() {
+            return;
+        }
+
+        // Attestations that were issued in the same session share a refresh token, so these are reissued together.
+        let mut has_refreshed = false;
+        for group in refreshes
+            .into_iter()
+            .into_group_map_by(|(_, refresh)| refresh.refresh_token.clone())
+            .into_values()
+        {
+            let (attestation_ids, refreshes): (Vec<_>, Vec<_>) = group.into_iter().unzip();
+
+            // Every group contains at least one entry, all of which are identical.
+            let refresh = refreshes.into_iter().next().unwrap();
+
+            match self.refresh_attestation_group(&refresh, attestation_ids, wscd).await {
+                Ok(()) => has_refreshed = true,
+                Err(error) => warn!(
+                    "Could not refresh attestations from {}: {error}",
+                    refresh.credential_issuer()
+                ),
+            }
+        }
+
+        if has_refreshed {
+            if let Err(error) = self.emit_attestations().await {
+                warn!("Could not emit attestations after refresh: {error}");
+            }
+            if let Err(error) = self.emit_notifications().await {
+                warn!("Could not emit notifications after refresh: {error}");
+            }
+            if let Err(error) = self.emit_recent_history().await {
+                warn!("Could not emit recent history after refresh: {error}");
+            }
+        }
+    }
+
+    async fn refresh_attestation_group<W>(
+        &self,
+        refresh: &CredentialRefresh,
+        attestation_ids: Vec<Uuid>,
+        wscd: &W,
+    ) -> Result<(), IssuanceError>
+    where
+        W: IssuanceWscd,
+    {
+        let config = self.config_repository.get();
+        let (attested_key, registration_data) = self
+            .registration
+            .as_key_and_registration_data()
+            .ok_or_else(|| IssuanceError::CheckPreconditions(CheckPreconditionsError::NotRegistered))?;
+
+        info!("Starting reissuance using refresh token");
+        let result = self
+            .issuance_discovery
+            .start_refresh(
+                refresh,
+                config.issuer_trust_anchors(),
+                &self.new_remote_wia_client(Arc::clone(attested_key), registration_data, &config),
+                config.wrpac_trust_anchors(),
+            )
+            .await;
+
+        // A refresh token can only be used once, so it is discarded when the issuer has either accepted or refused it.
+        // Any new refresh token is stored along with the reissued attestations. On any other error the issuer retains
+        // the refresh token, so that reissuance can be retried later.
+        let is_refused = matches!(
+            &result,
+            Err(WalletIssuanceError::TokenRequest(error))
+                if error.error == RemoteErrorCode::Known(TokenErrorCode::InvalidGrant)
+        );
+        if result.is_ok() || is_refused {
+            self.storage
+                .write()
+                .await
+                .delete_attestation_refreshes(attestation_ids)
+                .await
+                .map_err(IssuanceError::AttestationStorage)?;
+        }
+
+        let mut issuance_session = result?;
+
+        let preview_attestations = self.issuance_preview_attestations(&issuance_session, None).await?;
+
+        // The IssuanceSession trait guarantees that credential_preview_data()
+        // returns at least one value, so this unwrap() is safe.
+        let preview_attestations = preview_attestations.try_into().unwrap();
+
+        info!("Accepting reissuance");
+        match issuance_session
+            .accept_issuance(config.issuer_trust_anchors(), wscd)
+            .await?
+        {
+            IssuanceOutcome::Issued(credentials) => {
+                info!("Attestations reissued, storing credentials in database");
+                self.store_issued_credentials(credentials, preview_attestations).await
+            }
+            IssuanceOutcome::Deferred(deferred_issuance) => {
+                self.add_pending_deferred_issuance(deferred_issuance, preview_attestations)
+                    .await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use openid4vc::errors::ErrorResponse;
+    use openid4vc::errors::RemoteErrorCode;
+    use openid4vc::errors::TokenErrorCode;
+    use openid4vc::wallet_issuance::WalletIssuanceError;
+    use openid4vc::wallet_issuance::refresh::CredentialRefresh;
+    use rstest::rstest;
+    use uuid::Uuid;
+    use wscd::mock_remote::MockRemoteWscd;
+
+    use super::super::test::TestWalletMockStorage;
+    use super::super::test::WalletDeviceVendor;
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_refresh_attestations_error(#[values(true, false)] is_refused: bool) {
+        let mut wallet = TestWalletMockStorage::new_registered_and_unlocked(WalletDeviceVendor::Apple).await;
+
+        let attestation_ids = [Uuid::now_v7(), Uuid::now_v7(), Uuid::now_v7()];
+        wallet
+            .mut_storage()
+            .expect_fetch_attestation_refreshes_due()
+            .times(1)
+            .return_once(move |_, _| {
+                Ok(vec![
+                    (attestation_ids[0], CredentialRefresh::new_mock("refresh_token_1")),
+                    (attestation_ids[1], CredentialRefresh::new_mock("refresh_token_2")),
+                    (attestation_ids[2], CredentialRefresh::new_mock("refresh_token_1")),
+                ])
+            });
+
+        // Attestations that share a refresh token should be reissued in a single session.
+        wallet
+            .mut_issuance_discovery()
+            .expect_start_refresh_sync()
+            .times(2)
+            .returning(move |_| {
+                let error = if is_refused {
+                    WalletIssuanceError::TokenRequest(Box::new(ErrorResponse {
+                        error: RemoteErrorCode::Known(TokenErrorCode::InvalidGrant),
+                        error_description: None,
+                        error_uri: None,
+                    }))
+                } else {
+                    WalletIssuanceError::IssuerMismatch
+                };
+
+                Err(error)
+            });
+
+        // Only when the issuer refuses the refresh token should it be discarded, otherwise it is retried later.
+        wallet
+            .mut_storage()
+            .expect_delete_attestation_refreshes()
+            .withf(move |ids| {
+                ids.as_slice() == [attestation_ids[0], attestation_ids[2]] || ids.as_slice() == [attestation_ids[1]]
+            })
+            .times(if is_refused { 2 } else { 0 })
+            .returning(|_| Ok(()));
+
+        wallet.refresh_attestations(&MockRemoteWscd::default()).await;
+    }
+}
@@ -7,12 +7,14 @@ use chrono::Utc;
 use crypto::trust_anchor::TrustAnchors;
 use error_category::ErrorCategory;
 use futures::StreamExt;
+use itertools::Itertools;
 use openid4vc::disclosure_session::DisclosureClient;
 use openid4vc::wallet_issuance::IssuanceDiscovery;
 use parking_lot::Mutex;
 use platform_support::attested_key::AttestedKeyHolder;
 use rand::random;
 use token_status_list::verification::client::StatusListClient;
+use token_status_list::verification::verifier::PREFETCH_CONCURRENCY;
 use token_status_list::verification::verifier::RevocationStatus;
 use token_status_list::verification::verifier::RevocationVerifier;
 use tokio::sync::RwLock;
@@ -192,6 +194,27 @@ where
 
         let issuer_trust_anchors = config.issuer_trust_anchors();
 
+        // Prefetch all status lists per issuer at once, so that the verifications below are served from the cache
+        let status_list_uris_by_issuer = revocation_info
+            .iter()
+            .map(|revocation_info| {
+                (
+                    revocation_info.issuer_cert_distinguished_name().clone(),
+                    revocation_info.status_list_uri().clone(),
+                )
+            })
+            .into_group_map();
+        futures::stream::iter(status_list_uris_by_issuer)
+            .for_each_concurrent(PREFETCH_CONCURRENCY, |(issuer_cert_distinguished_name, uris)| {
+                revocation_verifier.prefetch(
+                    issuer_trust_anchors,
+                    issuer_cert_distinguished_name,
+                    uris,
+                    &time_generator,
+                )
+            })
+            .await;
+
         // Verify all revocations without holding any locks
         let updates: Vec<(Uuid, RevocationStatus)> = futures::stream::iter(revocation_info)
             .map(|revocation_info| {
@@ -326,7 +349,7 @@ mod tests {
         let mut mock_status_list_client = MockStatusListClient::new();
         mock_status_list_client
             .expect_fetch()
-            .returning(move |_| Ok(status_list_token.clone()));
+            .returning(move |_| Ok(status_list_token.clone().into()));
 
         let mut storage = MockStorage::new();
 
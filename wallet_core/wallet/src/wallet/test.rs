@@ -23,6 +23,8 @@ use crypto::server_keys::generate::Ca;
 use crypto::trust_anchor::BorrowingTrustAnchor;
 use crypto::trust_anchor::TrustAnchors;
 use crypto::x509::BorrowingCertificateExtension;
+use crypto::x509::revocation::RevocationChecker;
+use crypto::x509::revocation::RevocationPolicy;
 use futures::future::FutureExt;
 use itertools::Itertools;
 use jwt::SignedJwt;
@@ -32,6 +34,7 @@ use openid4vc::disclosure_session::mock::MockDisclosureClient;
 use openid4vc::metadata::issuer_metadata::CredentialConfigurationId;
 use openid4vc::token::CredentialPreview;
 use openid4vc::wallet_issuance::credential::CredentialWithMetadata;
+use openid4vc::wallet_issuance::credential::IssuanceOutcome;
 use openid4vc::wallet_issuance::credential::IssuedCredentialCopies;
 use openid4vc::wallet_issuance::credential::SdJwtCopy;
 use openid4vc::wallet_issuance::issuance_session::IssuanceTypeMetadata;
@@ -394,6 +397,26 @@ where
         Arc::get_mut(&mut self.storage).unwrap().get_mut()
     }
 
+    pub fn mut_issuance_discovery(&mut self) -> &mut MockIssuanceDiscovery {
+        Arc::get_mut(&mut self.issuance_discovery).unwrap()
+    }
+
+    /// Configures the `Wallet` to check the revocation status of relying party and issuer certificates.
+    pub async fn set_certificate_revocation(&mut self, policy: RevocationPolicy) {
+        let mut config = create_wallet_configuration();
+        config.certificate_revocation = Some(policy);
+
+        self.config_repository = Arc::new(
+            UpdatingConfigurationRepository::new(
+                LocalConfigurationRepository::new(config),
+                default_config_server_config(),
+            )
+            .await,
+        );
+        self.certificate_revocation_checker =
+            Some(RevocationChecker::new_with_client(policy, reqwest::Client::new(), 1));
+    }
+
     /// Creates an unregistered `Wallet` with mock dependencies.
     pub async fn new_unregistered(vendor: WalletDeviceVendor) -> Self {
         let config_server_config = default_config_server_config();
@@ -670,7 +693,9 @@ pub fn mock_issuance_session(
 
     let mut client = MockIssuanceSession::new();
     client.expect_issuer().return_const(issuer_registration);
-    client.expect_accept().return_once(|| Ok(credentials_with_metadata));
+    client
+        .expect_accept()
+        .return_once(|| Ok(IssuanceOutcome::Issued(credentials_with_metadata)));
 
     (client, attestation_presentations.try_into().unwrap())
 }
@@ -3,13 +3,144 @@ use std::path::Path;
 
 use anyhow::Result;
 use anyhow::anyhow;
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
 use clio::CachedInput;
 use crypto::server_keys::generate::Ca;
+use crypto::utils::random_bytes;
+use crypto::x509::BorrowingCertificate;
 use p256::ecdsa::SigningKey;
 use p256::pkcs8::EncodePrivateKey;
 use pem::EncodeConfig;
 use pem::LineEnding;
 use pem::Pem;
+use rcgen::RevokedCertParams;
+use rcgen::SerialNumber;
+use serde::Deserialize;
+use serde::Serialize;
+use time::OffsetDateTime;
+
+/// A certificate issued by the CA, as recorded in the [`CertificateIndex`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexEntry {
+    /// Hex-encoded serial number of the certificate
+    pub serial: String,
+    pub subject: String,
+    pub not_after: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+/// Index of the certificates issued by a CA, stored as a JSON file next to the CA. It is used to revoke certificates
+/// by their serial number and to generate a CRL containing all revoked certificates.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct CertificateIndex {
+    /// The number of the last CRL generated from this index
+    pub crl_number: u64,
+    pub certificates: Vec<IndexEntry>,
+}
+
+impl CertificateIndex {
+    /// Read the index from `file_path`, returning an empty index if the file does not exist yet.
+    pub fn read(file_path: &Path) -> Result<Self> {
+        if !file_path.exists() {
+            return Ok(Self::default());
+        }
+        let index = serde_json::from_slice(&fs::read(file_path)?)?;
+        Ok(index)
+    }
+
+    pub fn write(&self, file_path: &Path) -> Result<()> {
+        fs::write(file_path, serde_json::to_string_pretty(self)?)?;
+        eprintln!("Certificate index stored in '{}'", file_path.display());
+        Ok(())
+    }
+
+    /// Record a newly issued certificate, which should have a unique serial number, see [`random_serial_number`].
+    pub fn record(&mut self, certificate: &BorrowingCertificate) -> Result<()> {
+        let x509_certificate = certificate.x509_certificate();
+        let entry = IndexEntry {
+            serial: hex::encode(x509_certificate.tbs_certificate.raw_serial()),
+            subject: x509_certificate.subject().to_string(),
+            not_after: DateTime::from_timestamp(x509_certificate.validity().not_after.timestamp(), 0)
+                .ok_or_else(|| anyhow!("certificate has an invalid notAfter"))?,
+            revoked_at: None,
+        };
+
+        if self.certificates.iter().any(|existing| existing.serial == entry.serial) {
+            return Err(anyhow!(
+                "Certificate with serial '{}' has already been recorded in index",
+                entry.serial
+            ));
+        }
+        self.certificates.push(entry);
+
+        Ok(())
+    }
+
+    /// Mark the certificate with the given hex-encoded `serial` as revoked. Revoking an already revoked certificate
+    /// retains the original revocation time.
+    pub fn revoke(&mut self, serial: &str, revoked_at: DateTime<Utc>) -> Result<()> {
+        let serial = normalize_serial(serial)?;
+        let entry = self
+            .certificates
+            .iter_mut()
+            .find(|entry| entry.serial == serial)
+            .ok_or_else(|| anyhow!("No certificate with serial '{serial}' found in index"))?;
+        entry.revoked_at.get_or_insert(revoked_at);
+
+        Ok(())
+    }
+
+    /// Generate a CRL signed by `ca`, containing all revoked certificates in this index that have not expired yet.
+    /// This increments the CRL number of the index.
+    pub fn generate_crl(&mut self, ca: &Ca, now: DateTime<Utc>, valid_for: Duration) -> Result<Vec<u8>> {
+        let revoked_certs = self
+            .certificates
+            .iter()
+            .filter(|entry| entry.not_after > now)
+            .filter_map(|entry| entry.revoked_at.map(|revoked_at| (entry, revoked_at)))
+            .map(|(entry, revoked_at)| {
+                Ok(RevokedCertParams {
+                    serial_number: SerialNumber::from_slice(&hex::decode(&entry.serial)?),
+                    revocation_time: to_offset_date_time(revoked_at)?,
+                    reason_code: None,
+                    invalidity_date: None,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        self.crl_number += 1;
+        let crl = ca.generate_crl_with_number(
+            revoked_certs,
+            self.crl_number,
+            to_offset_date_time(now)?,
+            to_offset_date_time(now + valid_for)?,
+        )?;
+
+        Ok(crl.der().to_vec())
+    }
+}
+
+/// Generate a random serial number of 16 bytes, so that every certificate issued by the CA has a unique serial number,
+/// even when issued for the same public key. The first byte is made non-zero with its most significant bit cleared, as
+/// serial numbers must be positive and minimally encoded.
+pub fn random_serial_number() -> Vec<u8> {
+    let mut serial_number = random_bytes(16);
+    serial_number[0] = (serial_number[0] & 0x7f) | 0x01;
+    serial_number
+}
+
+/// Normalize a hex-encoded serial number, as e.g. printed by `openssl x509 -serial`, allowing colon separators.
+fn normalize_serial(serial: &str) -> Result<String> {
+    let bytes = hex::decode(serial.replace(':', "")).map_err(|_| anyhow!("Invalid serial '{serial}'"))?;
+    Ok(hex::encode(bytes))
+}
+
+fn to_offset_date_time(date_time: DateTime<Utc>) -> Result<OffsetDateTime> {
+    let offset_date_time = OffsetDateTime::from_unix_timestamp(date_time.timestamp())?;
+    Ok(offset_date_time)
+}
 
 pub fn read_public_key(public_key_file: &CachedInput) -> Result<Pem> {
     let pem = Pem::try_from(public_key_file.get_data())?;
@@ -50,6 +181,16 @@ pub fn write_key_pair(certificate: &impl AsRef<[u8]>, key: &SigningKey, file_pre
     Ok(())
 }
 
+pub fn write_crl(crl: &[u8], file_prefix: &str, force: bool) -> Result<()> {
+    let crl_file = format!("{file_prefix}.crl");
+    let crl_path = Path::new(&crl_file);
+    assert_not_exists(crl_path, force)?;
+
+    fs::write(crl_path, crl)?;
+    eprintln!("CRL stored in '{}'", crl_path.display());
+    Ok(())
+}
+
 fn assert_not_exists(file_path: &Path, force: bool) -> Result<()> {
     if file_path.exists() && !force {
         return Err(anyhow!("Target file '{}' already exists", file_path.display()));
@@ -1,4 +1,5 @@
 use std::collections::HashSet;
+use std::path::PathBuf;
 
 use anyhow::Context;
 use anyhow::Result;
@@ -12,6 +13,7 @@ use clap::Subcommand;
 use clap::ValueEnum;
 use clio::CachedInput;
 use crypto::server_keys::generate;
+use crypto::x509::BorrowingCertificate;
 use crypto::x509::BorrowingCertificateExtension;
 use crypto::x509::CertificateConfiguration;
 use crypto::x509::CertificateUsage;
@@ -27,17 +29,21 @@ use mdoc::NameSpaces;
 use mdoc::SessionTranscript;
 use mdoc::holder::disclosure::create_doc_request;
 use mdoc::utils::serialization::cbor_serialize;
+use url::Url;
 use utils::built_info::version_string;
 use utils::vec_at_least::VecNonEmpty;
+use wallet_ca::CertificateIndex;
+use wallet_ca::random_serial_number;
 use wallet_ca::read_public_key;
 use wallet_ca::read_self_signed_ca;
 use wallet_ca::write_certificate;
+use wallet_ca::write_crl;
 use wallet_ca::write_key_pair;
 
 /// Generate private keys and certificates
 ///
-/// NOTE: Do NOT use in production environments, as the certificates lifetime is incredibly large, and no revocation is
-/// implemented.
+/// NOTE: Do NOT use in production environments, as the certificates lifetime is incredibly large, and revocation is
+/// only supported through a local certificate index and CRL file, meant for test environments.
 #[derive(Parser)]
 #[command(author, version=version_string(), about, long_about)]
 struct Cli {
@@ -111,6 +117,12 @@ enum Command {
         /// Path to Issuer Authentication file in JSON format
         #[arg(short, long, value_parser)]
         issuer_auth_file: Option<CachedInput>,
+        /// URL of the CRL distribution point to embed in the new certificate
+        #[arg(long)]
+        crl_url: Option<Url>,
+        /// Path to the certificate index of the CA in JSON format, in which the new certificate is recorded
+        #[arg(long)]
+        index_file: Option<PathBuf>,
         /// Prefix to use for the generated files: <FILE_PREFIX>.key.pem and <FILE_PREFIX>.crt.pem
         #[arg(short, long)]
         file_prefix: String,
@@ -162,6 +174,12 @@ enum Command {
         /// Path to Issuer Authentication file in JSON format
         #[arg(short, long, value_parser)]
         issuer_auth_file: Option<CachedInput>,
+        /// URL of the CRL distribution point to embed in the new certificate
+        #[arg(long)]
+        crl_url: Option<Url>,
+        /// Path to the certificate index of the CA in JSON format, in which the new certificate is recorded
+        #[arg(long)]
+        index_file: Option<PathBuf>,
         /// Prefix to use for the generated files: <FILE_PREFIX>.crt.pem
         #[arg(short, long)]
         file_prefix: String,
@@ -172,6 +190,31 @@ enum Command {
         #[arg(long, default_value = "false")]
         force: bool,
     },
+    /// Revoke a certificate recorded in the certificate index and generate a CRL signed by given Certificate Authority
+    /// (CA), containing all revoked certificates
+    Revoke {
+        /// Path to the CA key file in PEM format
+        #[arg(short = 'k', long, value_parser)]
+        ca_key_file: CachedInput,
+        /// Path to the CA certificate file in PEM format
+        #[arg(short = 'c', long, value_parser)]
+        ca_crt_file: CachedInput,
+        /// Path to the certificate index of the CA in JSON format
+        #[arg(long)]
+        index_file: PathBuf,
+        /// Hex-encoded serial number of the certificate to revoke, when omitted only the CRL is regenerated
+        #[arg(short, long)]
+        serial: Option<String>,
+        /// Prefix to use for the generated file: <FILE_PREFIX>.crl
+        #[arg(short, long)]
+        file_prefix: String,
+        /// Duration for which the CRL will be valid, i.e. the time until its nextUpdate
+        #[arg(short, long, default_value = "7")]
+        days: u32,
+        /// Overwrite existing files
+        #[arg(long, default_value = "false")]
+        force: bool,
+    },
     /// Generate a signed mdoc DeviceRequest for close-proximity disclosure
     ReaderDeviceRequest {
         /// Path to the CA key file in PEM format
@@ -269,6 +312,7 @@ impl Command {
     fn get_certificate_configuration(
         cert_type: CertType,
         issuer_auth_file: Option<CachedInput>,
+        crl_url: Option<Url>,
         days: u32,
     ) -> Result<CertificateConfiguration> {
         let usage = match cert_type {
@@ -285,10 +329,21 @@ impl Command {
         Ok(CertificateConfiguration {
             usage,
             extension,
+            crl_distribution_points: crl_url.into_iter().collect(),
+            serial_number: Some(random_serial_number()),
             ..Self::get_ca_configuration(days)
         })
     }
 
+    fn record_in_index(index_file: Option<PathBuf>, certificate: &BorrowingCertificate) -> Result<()> {
+        if let Some(index_file) = index_file {
+            let mut index = CertificateIndex::read(&index_file)?;
+            index.record(certificate)?;
+            index.write(&index_file)?;
+        }
+        Ok(())
+    }
+
     fn execute(self) -> Result<()> {
         use Command::*;
         match self {
@@ -320,6 +375,8 @@ impl Command {
                 san_uris,
                 cert_type,
                 issuer_auth_file,
+                crl_url,
+                index_file,
                 file_prefix,
                 days,
                 force,
@@ -335,10 +392,11 @@ impl Command {
                     surname,
                     given_name,
                 )?;
-                let config = Self::get_certificate_configuration(cert_type, issuer_auth_file, days)?;
+                let config = Self::get_certificate_configuration(cert_type, issuer_auth_file, crl_url, days)?;
                 let san_uris = Self::get_san_uris(san_uris)?;
                 let key_pair = ca.generate_key_pair(distinguished_name, config, san_uris)?;
                 write_key_pair(key_pair.certificate(), key_pair.private_key(), &file_prefix, force)?;
+                Self::record_in_index(index_file, key_pair.certificate())?;
                 Ok(())
             }
             CertPub {
@@ -355,6 +413,8 @@ impl Command {
                 san_uris,
                 cert_type,
                 issuer_auth_file,
+                crl_url,
+                index_file,
                 file_prefix,
                 days,
                 force,
@@ -371,11 +431,35 @@ impl Command {
                     surname,
                     given_name,
                 )?;
-                let config = Self::get_certificate_configuration(cert_type, issuer_auth_file, days)?;
+                let config = Self::get_certificate_configuration(cert_type, issuer_auth_file, crl_url, days)?;
                 let san_uris = Self::get_san_uris(san_uris)?;
                 let certificate =
                     ca.generate_certificate(public_key.contents(), distinguished_name, config, san_uris)?;
                 write_certificate(&certificate, &file_prefix, force)?;
+                Self::record_in_index(index_file, &certificate)?;
+                Ok(())
+            }
+            Revoke {
+                ca_key_file,
+                ca_crt_file,
+                index_file,
+                serial,
+                file_prefix,
+                days,
+                force,
+            } => {
+                let ca = read_self_signed_ca(&ca_crt_file, &ca_key_file)?;
+                let mut index = CertificateIndex::read(&index_file)?;
+
+                let now = Utc::now();
+                if let Some(serial) = serial {
+                    index.revoke(&serial, now)?;
+                }
+                let crl = index.generate_crl(&ca, now, Duration::days(i64::from(days)))?;
+
+                // Write the index first, so that a CRL is never written that is not reflected by the index.
+                index.write(&index_file)?;
+                write_crl(&crl, &file_prefix, force)?;
                 Ok(())
             }
             ReaderDeviceRequest {
@@ -10,6 +10,7 @@ use assert_fs::TempDir;
 use assert_fs::fixture::ChildPath;
 use assert_fs::prelude::*;
 use attestation_data::auth::issuer_auth::IssuerRegistration;
+use crypto::x509::BorrowingCertificate;
 use crypto::x509::CertificateUsage;
 use crypto::x509::DistinguishedName;
 use crypto::x509::SubjectAltNameUri;
@@ -27,6 +28,7 @@ use predicates::str::RegexPredicate;
 use predicates::str::StartsWithPredicate;
 use time::Duration;
 use time::OffsetDateTime;
+use wallet_ca::CertificateIndex;
 use x509_parser::extensions::GeneralName;
 use x509_parser::oid_registry::OID_KEY_TYPE_EC_PUBLIC_KEY;
 
@@ -942,6 +944,198 @@ fn regenerating_cert() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn revoking_cert() -> Result<()> {
+    let temp = TempDir::new()?;
+    let (ca_prefix, ca_crt, ca_key) = keypair_paths(&temp, "test-ca");
+    let (wrpac_prefix, wrpac_crt, _) = keypair_paths(&temp, "test-wrpac-kp");
+    let index_json = temp.child("test-ca.index.json");
+    let crl_file = temp.child("test-ca.crl");
+
+    // Generate ca and assert success
+    Command::new(assert_cmd::cargo::cargo_bin!())
+        .generate_ca(&ca_prefix)
+        .assert()
+        .success();
+
+    // Generate WRPAC key pair with CRL distribution point, recorded in the index
+    Command::new(assert_cmd::cargo::cargo_bin!())
+        .generate_wrpac_kp(&ca_crt, &ca_key, &wrpac_prefix)
+        .generate_for_legal_person("Test B.V.", "NTRNL-00000002")
+        .arg("--crl-url")
+        .arg("http://localhost:3010/test-ca.crl")
+        .arg("--index-file")
+        .arg(index_json.path())
+        .assert()
+        .success();
+
+    // Assert the CRL distribution point is embedded in the certificate
+    let wrpac_certificate = BorrowingCertificate::from_pem(std::fs::read(&wrpac_crt)?)?;
+    itertools::assert_equal(
+        crypto::x509::crl::extract_crl_distribution_points(&wrpac_certificate)
+            .unwrap()
+            .into_inner(),
+        vec!["http://localhost:3010/test-ca.crl".to_string()],
+    );
+
+    // Assert the certificate is recorded in the index
+    let serial = hex::encode(wrpac_certificate.x509_certificate().tbs_certificate.raw_serial());
+    let index = CertificateIndex::read(&index_json)?;
+    assert_eq!(index.certificates.len(), 1);
+    assert_eq!(index.certificates[0].serial, serial);
+    assert!(index.certificates[0].revoked_at.is_none());
+
+    // Revoking an unknown serial should fail
+    Command::new(assert_cmd::cargo::cargo_bin!())
+        .arg("revoke")
+        .arg("--ca-key-file")
+        .arg(ca_key.path())
+        .arg("--ca-crt-file")
+        .arg(ca_crt.path())
+        .arg("--index-file")
+        .arg(index_json.path())
+        .arg("--serial")
+        .arg("01")
+        .arg("--file-prefix")
+        .arg(ca_prefix.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::is_match(
+            "Error: No certificate with serial '01' found in index\n",
+        )?);
+
+    // Revoke the certificate and assert success and stderr output
+    Command::new(assert_cmd::cargo::cargo_bin!())
+        .arg("revoke")
+        .arg("--ca-key-file")
+        .arg(ca_key.path())
+        .arg("--ca-crt-file")
+        .arg(ca_crt.path())
+        .arg("--index-file")
+        .arg(index_json.path())
+        .arg("--serial")
+        .arg(serial.to_uppercase())
+        .arg("--file-prefix")
+        .arg(ca_prefix.path())
+        .assert()
+        .success()
+        .stderr(predicate::str::is_match(format!(
+            "CRL stored in '{}'",
+            crl_file.display()
+        ))?);
+
+    // Assert the certificate is revoked in the index
+    let index = CertificateIndex::read(&index_json)?;
+    assert_eq!(index.crl_number, 1);
+    assert!(index.certificates[0].revoked_at.is_some());
+
+    // Assert the CRL is signed by the CA and contains the revoked certificate
+    let ca_certificate = BorrowingCertificate::from_pem(std::fs::read(&ca_crt)?)?;
+    let crl_der = std::fs::read(&crl_file)?;
+    let (_, crl) = x509_parser::parse_x509_crl(&crl_der)?;
+    crl.verify_signature(ca_certificate.x509_certificate().public_key())?;
+    itertools::assert_equal(
+        crl.iter_revoked_certificates()
+            .map(|revoked| hex::encode(revoked.raw_serial())),
+        vec![serial],
+    );
+
+    // Regenerating the CRL should fail without force
+    Command::new(assert_cmd::cargo::cargo_bin!())
+        .arg("revoke")
+        .arg("--ca-key-file")
+        .arg(ca_key.path())
+        .arg("--ca-crt-file")
+        .arg(ca_crt.path())
+        .arg("--index-file")
+        .arg(index_json.path())
+        .arg("--file-prefix")
+        .arg(ca_prefix.path())
+        .assert()
+        .failure()
+        .stderr(predicate_file_already_exists(&crl_file)?);
+
+    // Explicitly close the temp folder, for better error reporting
+    temp.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn revoking_cert_for_shared_public_key() -> Result<()> {
+    let temp = TempDir::new()?;
+    let (ca_prefix, ca_crt, ca_key) = keypair_paths(&temp, "test-ca");
+    let (first_prefix, first_crt, _) = keypair_paths(&temp, "test-wrpac-first");
+    let (second_prefix, second_crt, _) = keypair_paths(&temp, "test-wrpac-second");
+    let public_key = public_key_path(&temp, "test-wrpac");
+    let index_json = temp.child("test-ca.index.json");
+    let crl_file = temp.child("test-ca.crl");
+
+    Command::new(assert_cmd::cargo::cargo_bin!())
+        .generate_ca(&ca_prefix)
+        .assert()
+        .success();
+
+    // Generate two certificates for the same public key, both recorded in the index
+    generate_public_key(&public_key);
+    for prefix in [&first_prefix, &second_prefix] {
+        Command::new(assert_cmd::cargo::cargo_bin!())
+            .generate_wrpac_cert(&public_key, &ca_crt, &ca_key, prefix)
+            .generate_for_legal_person("Test B.V.", "NTRNL-00000002")
+            .arg("--index-file")
+            .arg(index_json.path())
+            .assert()
+            .success();
+    }
+
+    // Assert the certificates have distinct serials, which are both recorded in the index
+    let [first_serial, second_serial] = [&first_crt, &second_crt].map(|crt| {
+        let certificate = BorrowingCertificate::from_pem(std::fs::read(crt).unwrap()).unwrap();
+        hex::encode(certificate.x509_certificate().tbs_certificate.raw_serial())
+    });
+    assert_ne!(first_serial, second_serial);
+
+    let index = CertificateIndex::read(&index_json)?;
+    itertools::assert_equal(
+        index.certificates.iter().map(|entry| &entry.serial),
+        [&first_serial, &second_serial],
+    );
+
+    // Revoke only the first certificate
+    Command::new(assert_cmd::cargo::cargo_bin!())
+        .arg("revoke")
+        .arg("--ca-key-file")
+        .arg(ca_key.path())
+        .arg("--ca-crt-file")
+        .arg(ca_crt.path())
+        .arg("--index-file")
+        .arg(index_json.path())
+        .arg("--serial")
+        .arg(&first_serial)
+        .arg("--file-prefix")
+        .arg(ca_prefix.path())
+        .assert()
+        .success();
+
+    // Assert only the first certificate is revoked, both in the index and in the CRL
+    let index = CertificateIndex::read(&index_json)?;
+    assert!(index.certificates[0].revoked_at.is_some());
+    assert!(index.certificates[1].revoked_at.is_none());
+
+    let crl_der = std::fs::read(&crl_file)?;
+    let (_, crl) = x509_parser::parse_x509_crl(&crl_der)?;
+    itertools::assert_equal(
+        crl.iter_revoked_certificates()
+            .map(|revoked| hex::encode(revoked.raw_serial())),
+        vec![first_serial],
+    );
+
+    // Explicitly close the temp folder, for better error reporting
+    temp.close()?;
+
+    Ok(())
+}
+
 // TODO: PVW-5870 Remove when issuer is just like another cert
 fn setup_issuer_files(temp: &TempDir) -> Result<(ChildPath, ChildPath, ChildPath, ChildPath)> {
     let (ca_prefix, ca_crt, ca_key) = keypair_paths(temp, "test-ca");
@@ -1779,7 +1779,7 @@ pub mod mock {
         let mut status_list_service = MockStatusListService::new();
         status_list_service
             .expect_obtain_status_claims()
-            .returning(|_, _, copies| {
+            .returning(|_, _, _, copies| {
                 let uri = "https://example.com/wia".parse().unwrap();
                 Ok(generate_status_claims(&uri, copies))
             });
@@ -522,6 +522,7 @@ where
         .status_list_service
         .obtain_status_claims(
             wia_id,
+            None,
             Some(exp.into()),
             NonZeroUsize::MIN, // only one WIA is issued
         )
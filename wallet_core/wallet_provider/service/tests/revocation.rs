@@ -141,7 +141,7 @@ async fn register_wallets_with_wias(
                 let wia_id = Uuid::new_v4();
                 let claim = user_state
                     .status_list_service
-                    .obtain_status_claims(wia_id, None, NonZeroUsize::MIN)
+                    .obtain_status_claims(wia_id, None, None, NonZeroUsize::MIN)
                     .await
                     .unwrap()
                     .into_iter()
@@ -294,7 +294,7 @@ async fn register_wallets_to_revoke_with_revocation_codes(
                 let wia_id = Uuid::new_v4();
                 let claim = user_state
                     .status_list_service
-                    .obtain_status_claims(wia_id, None, NonZeroUsize::MIN)
+                    .obtain_status_claims(wia_id, None, None, NonZeroUsize::MIN)
                     .await
                     .unwrap()
                     .into_iter()
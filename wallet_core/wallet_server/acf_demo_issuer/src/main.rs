@@ -39,7 +39,7 @@ async fn main_impl(settings: AcfDemoIssuerSettings) -> Result<()> {
 
     let (issuer, database_checkers, _, server_settings) = settings
         .authorizing_issuer_settings
-        .into_authorizing_issuer(hsm, |store_connection| {
+        .into_authorizing_issuer(hsm, None, |store_connection| {
             Ok::<_, Infallible>(DemoAuthorizationCodeFlow::new(
                 store_connection,
                 issuer_identifier.as_base_url(),
@@ -120,4 +120,8 @@ impl ServerSettings for AcfDemoIssuerSettings {
     fn server_settings(&self) -> &Settings {
         &self.authorizing_issuer_settings.issuer_settings.server_settings
     }
+
+    fn server_settings_mut(&mut self) -> &mut Settings {
+        &mut self.authorizing_issuer_settings.issuer_settings.server_settings
+    }
 }
@@ -180,6 +180,7 @@ mod tests {
     use openid4vc::errors::ErrorWithCode;
     use openid4vc::errors::PostAuthResponseErrorCode;
     use openid4vc::issuable_document::IssuableDocument;
+    use openid4vc::issuer::CredentialResponseEncryptionPolicy;
     use openid4vc::issuer::Grant;
     use openid4vc::issuer::IssuanceData;
     use openid4vc::issuer::Issuer;
@@ -208,7 +209,8 @@ mod tests {
                 attributes: DisclosedAttributes::MsoMdoc(IndexMap::new()),
                 issuer_uri: "https://example.com".parse().unwrap(),
                 attestation_qualification: AttestationQualification::default(),
-                ca: "ca".to_string(),
+                ca: Some("ca".to_string()),
+                jwt_vc_issuer: None,
                 issuance_validity: IssuanceValidity::new(Utc::now(), Some(Utc::now()), Some(Utc::now())),
                 revocation_status: Some(RevocationStatus::Valid),
                 aki: vec![],
@@ -290,11 +292,14 @@ mod tests {
             "https://example.com".parse().unwrap(),
             metadata_keypair,
             NonZeroU8::MIN,
+            CredentialResponseEncryptionPolicy::default(),
             HashSet::new(),
             [("credential_config_id".to_string().into(), config_params)].into(),
             wia_trust_anchors,
             sessions,
             MemoryNonceStore::new(),
+            None,
+            None,
         )
         .unwrap()
     }
@@ -17,7 +17,8 @@ async fn main() -> Result<()> {
 
 async fn main_impl(settings: IssuanceServerSettings) -> Result<()> {
     // Note that HTTP is explicitly allowed for the retrieval of status lists.
-    let status_list_client = HttpStatusListClient::new(default_reqwest_client_builder())?;
+    let status_list_client = HttpStatusListClient::new(default_reqwest_client_builder())?
+        .with_format(settings.status_list_token_cache_settings.format);
     let revocation_verifier = settings.to_revocation_verifier(status_list_client);
 
     let serve_status_lists = settings.issuer_settings.status_lists.serve;
@@ -32,7 +33,7 @@ async fn main_impl(settings: IssuanceServerSettings) -> Result<()> {
     let hsm_checker = hsm.as_ref().map(HsmChecker::new);
 
     let (issuer, database_checkers, store_connection, server_settings) =
-        settings.issuer_settings.into_issuer(hsm.clone()).await?;
+        settings.issuer_settings.into_issuer(hsm.clone(), None).await?;
 
     let issuer = Arc::new(issuer);
 
@@ -8,12 +8,14 @@ use config::ConfigError;
 use config::Environment;
 use config::File;
 use crypto::trust_anchor::TrustAnchors;
+use crypto::x509::revocation::RevocationChecker;
 use dcql::Query;
 use dcql::normalized::UnsupportedDcqlFeatures;
 use derive_more::Debug;
 use futures::future::try_join_all;
 use hsm::service::Pkcs11Hsm;
 use http_utils::client::TlsPinningConfig;
+use http_utils::reqwest::default_reqwest_client_builder;
 use http_utils::urls::BaseUrl;
 use http_utils::urls::DEFAULT_UNIVERSAL_LINK_BASE;
 use issuer_common::IssuanceServerIssuer;
@@ -30,6 +32,7 @@ use openid4vc_server::verifier::VerifierFactory;
 use serde::Deserialize;
 use serde_with::serde_as;
 use server_utils::keys::PrivateKeySettingsError;
+use server_utils::settings::CERTIFICATE_REVOCATION_CACHE_CAPACITY;
 use server_utils::settings::KeyPair;
 use server_utils::settings::NL_WALLET_CLIENT_ID;
 use server_utils::settings::ServerSettings;
@@ -173,6 +176,10 @@ impl ServerSettings for IssuanceServerSettings {
     fn server_settings(&self) -> &Settings {
         &self.issuer_settings.server_settings
     }
+
+    fn server_settings_mut(&mut self) -> &mut Settings {
+        &mut self.issuer_settings.server_settings
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -185,6 +192,9 @@ pub enum VerifierSettingsError {
 
     #[error("could not initialize attributes fetcher: {0}")]
     AttributesFetcher(#[source] reqwest::Error),
+
+    #[error("could not initialize certificate revocation checker: {0}")]
+    RevocationChecker(#[source] reqwest::Error),
 }
 
 impl VerifierSettings {
@@ -256,6 +266,17 @@ impl VerifierSettings {
             self.extending_vct_values.unwrap_or_default(),
         );
 
+        let factory = match server_settings.certificate_revocation {
+            Some(policy) => factory.with_certificate_revocation_checker(RevocationChecker::new_with_client(
+                policy,
+                default_reqwest_client_builder()
+                    .build()
+                    .map_err(VerifierSettingsError::RevocationChecker)?,
+                CERTIFICATE_REVOCATION_CACHE_CAPACITY,
+            )),
+            None => factory,
+        };
+
         let result_handler = IssuanceResultHandler {
             issuer,
             attributes_fetcher,
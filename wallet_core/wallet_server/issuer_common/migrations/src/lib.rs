@@ -4,6 +4,8 @@ use sea_orm_migration::prelude::*;
 mod m20220101_000001_create_proof_nonce_table;
 mod m20260507_000001_create_pushed_authorization_request_table;
 mod m20260522_000001_create_state_bridge_table;
+mod m20261017_000001_create_refresh_token_table;
+mod m20261018_000001_create_issued_credential_table;
 
 pub struct Migrator;
 
@@ -16,6 +18,8 @@ impl MigratorTrait for Migrator {
         migrations.extend(status_lists_migrations::Migrator::migrations());
 
         migrations.push(Box::new(m20220101_000001_create_proof_nonce_table::Migration));
+        migrations.push(Box::new(m20261017_000001_create_refresh_token_table::Migration));
+        migrations.push(Box::new(m20261018_000001_create_issued_credential_table::Migration));
 
         migrations
     }
@@ -2,6 +2,8 @@
 
 pub mod prelude;
 
+pub mod issued_credential;
 pub mod proof_nonce;
 pub mod pushed_authorization_request;
+pub mod refresh_token;
 pub mod state_bridge;
@@ -1,5 +1,7 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.19
 
+pub use super::issued_credential::Entity as IssuedCredential;
 pub use super::proof_nonce::Entity as ProofNonce;
 pub use super::pushed_authorization_request::Entity as PushedAuthorizationRequest;
+pub use super::refresh_token::Entity as RefreshToken;
 pub use super::state_bridge::Entity as StateBridge;
@@ -0,0 +1,251 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+use openid4vc::issuer::IssuedCredential;
+use openid4vc::issuer::IssuedCredentialStore;
+use openid4vc::issuer::IssuedCredentials;
+use openid4vc::issuer::MemoryIssuedCredentialStore;
+use openid4vc::issuer::NotificationAuthorization;
+use sea_orm::ColumnTrait;
+use sea_orm::ConnectionTrait;
+use sea_orm::DatabaseConnection;
+use sea_orm::DbBackend;
+use sea_orm::DbErr;
+use sea_orm::EntityTrait;
+use sea_orm::NotSet;
+use sea_orm::QueryFilter;
+use sea_orm::Set;
+use sea_orm::Statement;
+use serde::Deserialize;
+use serde::Serialize;
+use server_utils::store::StoreConnection;
+use tracing::info;
+use utils::generator::Generator;
+use utils::generator::TimeGenerator;
+
+use crate::entity::issued_credential;
+
+/// Maximum rows deleted per statement during cleanup, to bound lock duration and DB load.
+const CLEANUP_BATCH_SIZE: u64 = 1_000;
+
+#[derive(Debug, thiserror::Error)]
+pub enum IssuerIssuedCredentialStoreError {
+    #[error("could not store issued credentials in database: {0}")]
+    DbStore(#[source] DbErr),
+
+    #[error("could not fetch issued credential from database: {0}")]
+    DbFetch(#[source] DbErr),
+
+    #[error("could not remove issued credential from database: {0}")]
+    DbRemove(#[source] DbErr),
+
+    #[error("could not delete expired issued credentials from database: {0}")]
+    DbCleanup(#[source] DbErr),
+
+    #[error("could not serialize issued credential data: {0}")]
+    Serialize(#[source] serde_json::Error),
+
+    #[error("could not deserialize issued credential data: {0}")]
+    Deserialize(#[source] serde_json::Error),
+}
+
+/// The contents of the `data` column of the `issued_credential` table.
+#[derive(Debug, Serialize, Deserialize)]
+struct IssuedCredentialData {
+    authorization: NotificationAuthorization,
+    credential: IssuedCredential,
+}
+
+#[derive(Debug)]
+enum IssuedCredentialStoreBackend {
+    Postgres(DatabaseConnection),
+    Memory(MemoryIssuedCredentialStore),
+}
+
+/// Stores the credentials issued by the issuer, against which the wallet may send notifications until they expire,
+/// either in PostgreSQL or in memory.
+#[derive(Debug)]
+pub struct IssuerIssuedCredentialStore<T = TimeGenerator> {
+    backend: IssuedCredentialStoreBackend,
+    time_generator: T,
+}
+
+impl IssuerIssuedCredentialStore {
+    pub fn new(store_connection: StoreConnection) -> Self {
+        let backend = match store_connection {
+            StoreConnection::Postgres(connection) => IssuedCredentialStoreBackend::Postgres(connection),
+            StoreConnection::Memory => IssuedCredentialStoreBackend::Memory(MemoryIssuedCredentialStore::new()),
+        };
+
+        Self {
+            backend,
+            time_generator: TimeGenerator,
+        }
+    }
+}
+
+#[cfg(feature = "db_test")]
+impl<T> IssuerIssuedCredentialStore<T> {
+    pub fn new_postgres_with_time_generator(database_connection: DatabaseConnection, time_generator: T) -> Self {
+        Self {
+            backend: IssuedCredentialStoreBackend::Postgres(database_connection),
+            time_generator,
+        }
+    }
+}
+
+impl<T> IssuerIssuedCredentialStore<T>
+where
+    T: Generator<DateTime<Utc>>,
+{
+    fn now(&self) -> DateTime<Utc> {
+        self.time_generator.generate()
+    }
+}
+
+#[async_trait]
+impl<T> IssuedCredentialStore for IssuerIssuedCredentialStore<T>
+where
+    T: Generator<DateTime<Utc>> + Send + Sync,
+{
+    async fn store_issued_credentials(
+        &self,
+        issued_credentials: IssuedCredentials,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match &self.backend {
+            IssuedCredentialStoreBackend::Postgres(connection) => {
+                let IssuedCredentials {
+                    authorization,
+                    credentials,
+                } = issued_credentials;
+
+                let models = credentials
+                    .into_iter()
+                    .map(|credential| {
+                        let notification_id = credential.notification_id.clone();
+                        let expires_at = credential.expires_at;
+                        let data = serde_json::to_value(IssuedCredentialData {
+                            authorization: authorization.clone(),
+                            credential,
+                        })
+                        .map_err(IssuerIssuedCredentialStoreError::Serialize)?;
+
+                        Ok(issued_credential::ActiveModel {
+                            id: NotSet,
+                            notification_id: Set(notification_id),
+                            data: Set(data),
+                            expires_at: Set(expires_at.into()),
+                        })
+                    })
+                    .collect::<Result<Vec<_>, IssuerIssuedCredentialStoreError>>()?;
+
+                issued_credential::Entity::insert_many(models)
+                    .exec(connection)
+                    .await
+                    .map_err(IssuerIssuedCredentialStoreError::DbStore)?;
+
+                Ok(())
+            }
+            IssuedCredentialStoreBackend::Memory(memory_store) => {
+                memory_store.store_issued_credentials(issued_credentials).await
+            }
+        }
+    }
+
+    async fn fetch_issued_credential(
+        &self,
+        notification_id: &str,
+    ) -> Result<Option<(NotificationAuthorization, IssuedCredential)>, Box<dyn std::error::Error + Send + Sync + 'static>>
+    {
+        match &self.backend {
+            IssuedCredentialStoreBackend::Postgres(connection) => {
+                let model = issued_credential::Entity::find()
+                    .filter(issued_credential::Column::NotificationId.eq(notification_id))
+                    .filter(issued_credential::Column::ExpiresAt.gt(self.now()))
+                    .one(connection)
+                    .await
+                    .map_err(IssuerIssuedCredentialStoreError::DbFetch)?;
+
+                let Some(model) = model else {
+                    return Ok(None);
+                };
+
+                let IssuedCredentialData {
+                    authorization,
+                    credential,
+                } = serde_json::from_value(model.data).map_err(IssuerIssuedCredentialStoreError::Deserialize)?;
+
+                Ok(Some((authorization, credential)))
+            }
+            IssuedCredentialStoreBackend::Memory(memory_store) => {
+                memory_store.fetch_issued_credential(notification_id).await
+            }
+        }
+    }
+
+    async fn remove_issued_credential(
+        &self,
+        notification_id: &str,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match &self.backend {
+            IssuedCredentialStoreBackend::Postgres(connection) => {
+                issued_credential::Entity::delete_many()
+                    .filter(issued_credential::Column::NotificationId.eq(notification_id))
+                    .exec(connection)
+                    .await
+                    .map_err(IssuerIssuedCredentialStoreError::DbRemove)?;
+
+                Ok(())
+            }
+            IssuedCredentialStoreBackend::Memory(memory_store) => {
+                memory_store.remove_issued_credential(notification_id).await
+            }
+        }
+    }
+
+    async fn cleanup_issued_credentials(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match &self.backend {
+            IssuedCredentialStoreBackend::Postgres(connection) => {
+                let now = self.now();
+                let mut total_deleted: u64 = 0;
+
+                // Delete in bounded batches, skipping rows locked by a concurrent notification, see the cleanup of
+                // the refresh token store.
+                loop {
+                    let result = connection
+                        .execute(Statement::from_sql_and_values(
+                            DbBackend::Postgres,
+                            r#"
+                            WITH rows_to_delete AS (
+                                SELECT id
+                                FROM issued_credential
+                                WHERE expires_at <= $1
+                                ORDER BY expires_at
+                                LIMIT $2
+                                FOR UPDATE SKIP LOCKED
+                            )
+                            DELETE FROM issued_credential ic
+                            USING rows_to_delete
+                            WHERE ic.id = rows_to_delete.id
+                            "#,
+                            [now.into(), (CLEANUP_BATCH_SIZE as i64).into()],
+                        ))
+                        .await
+                        .map_err(IssuerIssuedCredentialStoreError::DbCleanup)?;
+
+                    total_deleted += result.rows_affected();
+                    if result.rows_affected() < CLEANUP_BATCH_SIZE {
+                        break;
+                    }
+                }
+
+                if total_deleted > 0 {
+                    info!("Deleted {total_deleted} expired issued credential(s) from storage");
+                }
+
+                Ok(())
+            }
+            IssuedCredentialStoreBackend::Memory(memory_store) => memory_store.cleanup_issued_credentials().await,
+        }
+    }
+}
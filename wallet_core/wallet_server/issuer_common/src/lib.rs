@@ -8,8 +8,10 @@ use status_lists::postgres::PostgresStatusListService;
 use crate::nonce_store::ProofNonceStore;
 
 mod entity;
+pub mod issued_credential_store;
 pub mod nonce_store;
 pub mod par_store;
+pub mod refresh_token_store;
 pub mod settings;
 pub mod state_bridge_store;
 
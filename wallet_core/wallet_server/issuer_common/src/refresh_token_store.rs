@@ -0,0 +1,242 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
+use openid4vc::issuer::MemoryRefreshTokenStore;
+use openid4vc::issuer::RefreshTokenData;
+use openid4vc::issuer::RefreshTokenStore;
+use openid4vc::store::Consumed;
+use openid4vc::token::RefreshToken;
+use sea_orm::ActiveModelTrait;
+use sea_orm::ColumnTrait;
+use sea_orm::ConnectionTrait;
+use sea_orm::DatabaseConnection;
+use sea_orm::DbBackend;
+use sea_orm::DbErr;
+use sea_orm::EntityTrait;
+use sea_orm::NotSet;
+use sea_orm::QueryFilter;
+use sea_orm::Set;
+use sea_orm::Statement;
+use server_utils::store::StoreConnection;
+use tracing::info;
+use utils::generator::Generator;
+use utils::generator::TimeGenerator;
+
+use crate::entity::refresh_token;
+
+/// Maximum rows deleted per statement during cleanup, to bound lock duration and DB load.
+const CLEANUP_BATCH_SIZE: u64 = 1_000;
+
+#[derive(Debug, thiserror::Error)]
+pub enum IssuerRefreshTokenStoreError {
+    #[error("could not store refresh token in database: {0}")]
+    DbStore(#[source] DbErr),
+
+    #[error("could not fetch refresh token from database: {0}")]
+    DbFetch(#[source] DbErr),
+
+    #[error("could not consume refresh token from database: {0}")]
+    DbConsume(#[source] DbErr),
+
+    #[error("could not delete expired refresh tokens from database: {0}")]
+    DbCleanup(#[source] DbErr),
+
+    #[error("could not serialize refresh token data: {0}")]
+    Serialize(#[source] serde_json::Error),
+
+    #[error("could not deserialize refresh token data: {0}")]
+    Deserialize(#[source] serde_json::Error),
+}
+
+#[derive(Debug)]
+enum RefreshTokenStoreBackend {
+    Postgres(DatabaseConnection),
+    Memory(MemoryRefreshTokenStore),
+}
+
+/// Stores the refresh tokens handed out by the issuer either in PostgreSQL or in memory.
+#[derive(Debug)]
+pub struct IssuerRefreshTokenStore<T = TimeGenerator> {
+    backend: RefreshTokenStoreBackend,
+    ttl: Duration,
+    time_generator: T,
+}
+
+impl IssuerRefreshTokenStore {
+    pub fn new(store_connection: StoreConnection, ttl: Duration) -> Self {
+        let backend = match store_connection {
+            StoreConnection::Postgres(connection) => RefreshTokenStoreBackend::Postgres(connection),
+            StoreConnection::Memory => RefreshTokenStoreBackend::Memory(MemoryRefreshTokenStore::new(ttl)),
+        };
+
+        Self {
+            backend,
+            ttl,
+            time_generator: TimeGenerator,
+        }
+    }
+}
+
+#[cfg(feature = "db_test")]
+impl<T> IssuerRefreshTokenStore<T>
+where
+    T: Clone,
+{
+    pub fn new_postgres_with_time_generator(
+        database_connection: DatabaseConnection,
+        ttl: Duration,
+        time_generator: T,
+    ) -> Self {
+        Self {
+            backend: RefreshTokenStoreBackend::Postgres(database_connection),
+            ttl,
+            time_generator,
+        }
+    }
+}
+
+impl<T> IssuerRefreshTokenStore<T>
+where
+    T: Generator<DateTime<Utc>>,
+{
+    fn now(&self) -> DateTime<Utc> {
+        self.time_generator.generate()
+    }
+}
+
+#[async_trait]
+impl<T> RefreshTokenStore for IssuerRefreshTokenStore<T>
+where
+    T: Generator<DateTime<Utc>> + Send + Sync,
+{
+    async fn store_refresh_token(
+        &self,
+        refresh_token: RefreshToken,
+        data: RefreshTokenData,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match &self.backend {
+            RefreshTokenStoreBackend::Postgres(connection) => {
+                let data = serde_json::to_value(&data).map_err(IssuerRefreshTokenStoreError::Serialize)?;
+                let expires_at = self.now() + self.ttl;
+
+                refresh_token::ActiveModel {
+                    id: NotSet,
+                    token: Set(refresh_token.as_ref().to_string()),
+                    data: Set(data),
+                    expires_at: Set(expires_at.into()),
+                }
+                .insert(connection)
+                .await
+                .map_err(IssuerRefreshTokenStoreError::DbStore)?;
+
+                Ok(())
+            }
+            RefreshTokenStoreBackend::Memory(memory_store) => {
+                memory_store.store_refresh_token(refresh_token, data).await
+            }
+        }
+    }
+
+    async fn fetch_refresh_token(
+        &self,
+        refresh_token: &RefreshToken,
+    ) -> Result<Option<RefreshTokenData>, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match &self.backend {
+            RefreshTokenStoreBackend::Postgres(connection) => {
+                let model = refresh_token::Entity::find()
+                    .filter(refresh_token::Column::Token.eq(refresh_token.as_ref()))
+                    .filter(refresh_token::Column::ExpiresAt.gt(self.now()))
+                    .one(connection)
+                    .await
+                    .map_err(IssuerRefreshTokenStoreError::DbFetch)?;
+
+                let Some(model) = model else {
+                    return Ok(None);
+                };
+
+                let data = serde_json::from_value(model.data).map_err(IssuerRefreshTokenStoreError::Deserialize)?;
+
+                Ok(Some(data))
+            }
+            RefreshTokenStoreBackend::Memory(memory_store) => memory_store.fetch_refresh_token(refresh_token).await,
+        }
+    }
+
+    async fn consume_refresh_token(
+        &self,
+        refresh_token: &RefreshToken,
+    ) -> Result<Consumed<RefreshTokenData>, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match &self.backend {
+            RefreshTokenStoreBackend::Postgres(connection) => {
+                let deleted = refresh_token::Entity::delete_many()
+                    .filter(refresh_token::Column::Token.eq(refresh_token.as_ref()))
+                    .exec_with_returning(connection)
+                    .await
+                    .map_err(IssuerRefreshTokenStoreError::DbConsume)?;
+
+                let Some(model) = deleted.into_iter().next() else {
+                    return Ok(Consumed::Absent);
+                };
+
+                let data = serde_json::from_value(model.data).map_err(IssuerRefreshTokenStoreError::Deserialize)?;
+                Ok(if self.now() >= model.expires_at.to_utc() {
+                    Consumed::Expired(data)
+                } else {
+                    Consumed::Live(data)
+                })
+            }
+            RefreshTokenStoreBackend::Memory(memory_store) => memory_store.consume_refresh_token(refresh_token).await,
+        }
+    }
+
+    async fn cleanup_refresh_tokens(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match &self.backend {
+            RefreshTokenStoreBackend::Postgres(connection) => {
+                let now = self.now();
+                let mut total_deleted: u64 = 0;
+
+                // Delete in bounded batches so a single statement never holds a large lock or scans
+                // the whole backlog. `FOR UPDATE SKIP LOCKED` skips rows currently locked by a
+                // concurrent `consume`; the loop drains the rest, stopping once a batch removes
+                // fewer rows than the limit (nothing left to delete). This also enables running multiple pods that
+                // start concurrent cleanup tasks.
+                //
+                loop {
+                    let result = connection
+                        .execute(Statement::from_sql_and_values(
+                            DbBackend::Postgres,
+                            r#"
+                            WITH rows_to_delete AS (
+                                SELECT id
+                                FROM refresh_token
+                                WHERE expires_at <= $1
+                                ORDER BY expires_at
+                                LIMIT $2
+                                FOR UPDATE SKIP LOCKED
+                            )
+                            DELETE FROM refresh_token rt
+                            USING rows_to_delete
+                            WHERE rt.id = rows_to_delete.id
+                            "#,
+                            [now.into(), (CLEANUP_BATCH_SIZE as i64).into()],
+                        ))
+                        .await
+                        .map_err(IssuerRefreshTokenStoreError::DbCleanup)?;
+
+                    total_deleted += result.rows_affected();
+                    if result.rows_affected() < CLEANUP_BATCH_SIZE {
+                        break;
+                    }
+                }
+
+                if total_deleted > 0 {
+                    info!("Deleted {total_deleted} expired refresh token(s) from storage");
+                }
+
+                Ok(())
+            }
+            RefreshTokenStoreBackend::Memory(memory_store) => memory_store.cleanup_refresh_tokens().await,
+        }
+    }
+}
@@ -8,6 +8,7 @@ use std::sync::Arc;
 use attestation_types::credential_kind::CredentialKind;
 use attestation_types::qualification::AttestationQualification;
 use chrono::Days;
+use chrono::Duration;
 use crypto::trust_anchor::TrustAnchors;
 use crypto::x509::CanonicalDistinguishedName;
 use crypto::x509::CertificateError;
@@ -20,18 +21,26 @@ use futures::future::try_join_all;
 use health_checkers::postgres::DatabaseChecker;
 use hsm::service::HsmError;
 use hsm::service::Pkcs11Hsm;
+use http_utils::reqwest::default_tls_reqwest_client_builder;
 use http_utils::urls::BaseUrl;
 use http_utils::urls::HttpsUri;
 use itertools::Itertools;
 use openid4vc::authorizing_issuer::AuthorizingIssuer;
 use openid4vc::credential_configurations::CredentialConfigurationParameters;
 use openid4vc::credential_configurations::CredentialConfigurationsError;
+use openid4vc::issuer::CredentialRefresh;
+use openid4vc::issuer::CredentialResponseEncryptionPolicy;
+use openid4vc::issuer::IssuableDocumentSource;
 use openid4vc::issuer::IssuanceData;
 use openid4vc::issuer::Issuer;
 use openid4vc::issuer_identifier::IssuerIdentifier;
 use openid4vc::metadata::issuer_metadata::CredentialConfigurationId;
 use sd_jwt_vc_metadata::TypeMetadataDocuments;
 use sd_jwt_vc_metadata::UncheckedTypeMetadata;
+use sd_jwt_vc_metadata::resolver::TypeMetadataCache;
+use sd_jwt_vc_metadata::resolver::TypeMetadataResolver;
+use sd_jwt_vc_metadata::resolver::TypeMetadataResolverError;
+use sd_jwt_vc_metadata::resolver::TypeMetadataResolverMode;
 use sea_orm::DatabaseConnection;
 use sea_orm::DbErr;
 use serde::Deserialize;
@@ -58,8 +67,13 @@ use utils::generator::TimeGenerator;
 use utils::path::prefix_local_path;
 use utils::vec_at_least::VecNonEmpty;
 
+use crate::issued_credential_store::IssuerIssuedCredentialStore;
 use crate::nonce_store::ProofNonceStore;
 use crate::par_store::IssuerParStore;
+use crate::refresh_token_store::IssuerRefreshTokenStore;
+
+const TYPE_METADATA_CACHE_CAPACITY: u64 = 100;
+const TYPE_METADATA_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(60 * 60);
 
 /// Settings for an authorizing (Authorization Phase) issuer: the shared [`IssuerSettings`] plus the
 /// parameters that only the auth-code path needs.
@@ -81,6 +95,7 @@ impl AuthorizingIssuerSettings {
     pub async fn into_authorizing_issuer<AF, E>(
         self,
         hsm: Option<Pkcs11Hsm>,
+        document_source: Option<Box<dyn IssuableDocumentSource + Send + Sync>>,
         flow: impl FnOnce(StoreConnection) -> Result<AF, E>,
     ) -> Result<
         (
@@ -106,7 +121,8 @@ impl AuthorizingIssuerSettings {
             issuer_settings,
         } = self;
 
-        let (issuer, database_checkers, store_connection, server_settings) = issuer_settings.into_issuer(hsm).await?;
+        let (issuer, database_checkers, store_connection, server_settings) =
+            issuer_settings.into_issuer(hsm, document_source).await?;
 
         let par_store = IssuerParStore::new(store_connection.clone());
         let flow =
@@ -134,6 +150,11 @@ pub struct IssuerSettings {
     #[serde_as(as = "TryFromInto<Vec<String>>")]
     pub type_metadata: TypeMetadataByVct,
 
+    /// If set, SD-JWT VC Type Metadata documents that are not present in `type_metadata` are retrieved using their
+    /// `vct` URL and cached in memory, using this mode.
+    #[serde(default)]
+    pub type_metadata_resolver_mode: Option<TypeMetadataResolverMode>,
+
     /// `client_id` values that this server accepts, identifying the wallet implementation (not individual instances,
     /// i.e., the `client_id` value of a wallet implementation will be constant across all wallets of that
     /// implementation).
@@ -144,6 +165,15 @@ pub struct IssuerSettings {
     /// The maximum amount of copies of a credential that the holder can request.
     pub batch_size: NonZeroU8,
 
+    /// If set, the wallet receives a refresh token along with its credentials, which it can redeem within this amount
+    /// of days to have the same credentials reissued without the user having to authorize this again. This is only
+    /// supported by issuers that are able to fetch the documents to reissue from their source.
+    pub refresh_token_valid_days: Option<u64>,
+
+    /// Whether Credential Responses are encrypted to a key provided by the wallet, on top of TLS.
+    #[serde(default)]
+    pub credential_response_encryption: CredentialResponseEncryptionPolicy,
+
     #[serde(flatten)]
     #[debug(skip)]
     pub server_settings: Settings,
@@ -222,35 +252,50 @@ pub enum TypeMetadataDocumentsError {
 
     #[error("missing metadata document for vct: {0}")]
     MissingDocument(String),
+
+    #[error("could not resolve metadata document: {0}")]
+    Resolution(#[source] TypeMetadataResolverError),
 }
 
 impl TypeMetadataByVct {
-    /// Collect a chain of SD-JWT VC type metadata JSON from the configured files.
-    fn to_metadata_documents(&self, vct: &str) -> Result<TypeMetadataDocuments, TypeMetadataDocumentsError> {
+    /// Collect a chain of SD-JWT VC type metadata JSON from the configured files. If a document in the chain is not
+    /// present in these files, the remainder of the chain is retrieved using `resolver`, if provided.
+    async fn to_metadata_documents(
+        &self,
+        vct: &str,
+        resolver: Option<&TypeMetadataResolver>,
+    ) -> Result<TypeMetadataDocuments, TypeMetadataDocumentsError> {
         const MAX_CHAIN_LENGTH: usize = 100;
 
         let Self(metadata_by_vct) = self;
 
         let mut documents = Vec::with_capacity(1);
         let mut chain_length = 0;
-        let mut next_vct = Some(vct);
+        let mut next_extends = Some((vct, None));
 
-        while let Some(vct) = next_vct {
+        while let Some((vct, integrity)) = next_extends {
             chain_length += 1;
             if chain_length == MAX_CHAIN_LENGTH {
                 return Err(TypeMetadataDocumentsError::MaximumLengthExceeded);
             }
 
-            let (metadata_document, metadata_json) = metadata_by_vct
-                .get(vct)
-                .ok_or_else(|| TypeMetadataDocumentsError::MissingDocument(vct.to_string()))?;
+            let Some((metadata_document, metadata_json)) = metadata_by_vct.get(vct) else {
+                let resolver = resolver.ok_or_else(|| TypeMetadataDocumentsError::MissingDocument(vct.to_string()))?;
+                let resolved_documents = resolver
+                    .resolve(vct, integrity)
+                    .await
+                    .map_err(TypeMetadataDocumentsError::Resolution)?;
+
+                documents.extend(resolved_documents.as_ref().iter().cloned());
+                break;
+            };
 
             documents.push(metadata_json.clone());
 
-            next_vct = metadata_document
+            next_extends = metadata_document
                 .extends
                 .as_ref()
-                .map(|extends| extends.extends.as_str());
+                .map(|extends| (extends.extends.as_str(), Some(extends.extends_integrity.as_ref())));
         }
 
         // This `.unwrap()` is guaranteed to succeed as the `while` loop above runs at least once.
@@ -283,6 +328,7 @@ impl CredentialConfigurationsSettings {
         hsm: Option<Pkcs11Hsm>,
         status_list_settings: &StatusListsSettings,
         metadata_by_vct: &TypeMetadataByVct,
+        type_metadata_resolver: Option<&TypeMetadataResolver>,
     ) -> Result<
         HashMap<
             CredentialConfigurationId,
@@ -322,7 +368,8 @@ impl CredentialConfigurationsSettings {
                         };
 
                         let metadata_documents = metadata_by_vct
-                            .to_metadata_documents(&settings.credential_kind.attestation_type)
+                            .to_metadata_documents(&settings.credential_kind.attestation_type, type_metadata_resolver)
+                            .await
                             .map_err(CredentialConfigurationsSettingsError::TypeMetadataChain)?;
 
                         let key_pair = settings
@@ -408,11 +455,17 @@ pub enum IssuerSettingsError {
     #[error("no database configured for status lists")]
     NoStatusListDatabase,
 
+    #[error("could not initialize type metadata resolver: {0}")]
+    TypeMetadataResolver(#[source] reqwest::Error),
+
     #[error("could not initialize credential configurations: {0}")]
     CredentialConfigurations(#[source] CredentialConfigurationsError),
 
     #[error("could not initialize authorization code flow: {0}")]
     AuthorizationCodeFlow(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+
+    #[error("refresh tokens are configured, but this issuer can not fetch documents to reissue")]
+    RefreshTokensUnsupported,
 }
 
 impl IssuerSettings {
@@ -497,9 +550,12 @@ impl IssuerSettings {
         Ok(())
     }
 
+    /// Build an [`Issuer`] from these settings. Refresh tokens are only handed out when `refresh_token_valid_days` is
+    /// configured, in which case a `document_source` is required to fetch the documents to reissue.
     pub async fn into_issuer(
         self,
         hsm: Option<Pkcs11Hsm>,
+        document_source: Option<Box<dyn IssuableDocumentSource + Send + Sync>>,
     ) -> Result<
         (
             Issuer<
@@ -532,6 +588,18 @@ impl IssuerSettings {
 
         let sessions = SessionStoreVariant::new(store_connection.clone(), (&self.server_settings.storage).into());
         let proof_nonce_store = ProofNonceStore::new(store_connection.clone());
+        let issued_credential_store = IssuerIssuedCredentialStore::new(store_connection.clone());
+        let credential_refresh = match (self.refresh_token_valid_days, document_source) {
+            (Some(valid_days), Some(document_source)) => {
+                let ttl = Duration::days(valid_days as i64);
+                Some(CredentialRefresh {
+                    refresh_token_store: Box::new(IssuerRefreshTokenStore::new(store_connection.clone(), ttl)),
+                    document_source,
+                })
+            }
+            (Some(_), None) => return Err(IssuerSettingsError::RefreshTokensUnsupported),
+            (None, _) => None,
+        };
 
         let status_list_connection = match (&store_connection, self.status_lists.storage_url.clone()) {
             (_, Some(url)) => {
@@ -554,6 +622,18 @@ impl IssuerSettings {
             .await
             .map_err(IssuerSettingsError::MetadataPrivateKey)?;
 
+        let type_metadata_resolver = self
+            .type_metadata_resolver_mode
+            .map(|mode| {
+                TypeMetadataResolver::new(
+                    default_tls_reqwest_client_builder(),
+                    TypeMetadataCache::new_memory(TYPE_METADATA_CACHE_CAPACITY, TYPE_METADATA_CACHE_TTL),
+                    mode,
+                )
+            })
+            .transpose()
+            .map_err(IssuerSettingsError::TypeMetadataResolver)?;
+
         let config_params = self
             .credential_configurations
             .into_params(
@@ -562,6 +642,7 @@ impl IssuerSettings {
                 hsm,
                 &self.status_lists,
                 &self.type_metadata,
+                type_metadata_resolver.as_ref(),
             )
             .await
             .map_err(IssuerSettingsError::CredentialConfigurationParameters)?;
@@ -570,11 +651,14 @@ impl IssuerSettings {
             self.public_url,
             metadata_keypair,
             self.batch_size,
+            self.credential_response_encryption,
             self.wallet_client_ids,
             config_params,
             self.wia_trust_anchors,
             Arc::new(sessions),
             proof_nonce_store,
+            Some(Box::new(issued_credential_store)),
+            credential_refresh,
         )
         .map_err(IssuerSettingsError::CredentialConfigurations)?;
 
@@ -732,8 +816,11 @@ mod tests {
                 let metadata_bytes = serde_json::to_vec(&metadata).unwrap();
                 (vct, (metadata, metadata_bytes))
             }])),
+            type_metadata_resolver_mode: None,
             wallet_client_ids: HashSet::from([MOCK_WALLET_CLIENT_ID.to_string()]),
             batch_size: NonZeroU8::MIN,
+            refresh_token_valid_days: None,
+            credential_response_encryption: CredentialResponseEncryptionPolicy::Optional,
             server_settings: Settings {
                 wallet_server: Server {
                     ip: "127.0.0.1".parse().unwrap(),
@@ -754,6 +841,8 @@ mod tests {
                 issuer_trust_anchors: TrustAnchors::from(issuer_ca),
                 wrpac_trust_anchors: TrustAnchors::from(wrpac_ca),
                 wrprc_trust_anchors: TrustAnchors::empty(),
+                trusted_lists: vec![],
+                certificate_revocation: None,
                 hsm: None,
             },
             status_lists: StatusListsSettings {
@@ -0,0 +1,73 @@
+use chrono::DateTime;
+use chrono::Duration;
+use crypto::PublicKey;
+use db_test::DbName;
+use db_test::DbSetup;
+use db_test::connection_from_url;
+use issuer_common::issued_credential_store::IssuerIssuedCredentialStore;
+use openid4vc::issuer::IssuedCredential;
+use openid4vc::issuer::IssuedCredentialStore;
+use openid4vc::issuer::IssuedCredentials;
+use openid4vc::issuer::NotificationAuthorization;
+use openid4vc::token::AccessToken;
+use p256::ecdsa::SigningKey;
+use p256::elliptic_curve::Generate;
+use utils::generator::mock::MockTimeGenerator;
+use uuid::Uuid;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_issuer_issued_credential_store() {
+    let db_setup = DbSetup::create_clean_only([DbName::PidIssuer]).await;
+    let database_connection = connection_from_url(db_setup.pid_issuer_url()).await;
+
+    let now = DateTime::from_timestamp_secs(1_000_000_000).unwrap();
+    let time_generator = MockTimeGenerator::new(now);
+    let store =
+        IssuerIssuedCredentialStore::new_postgres_with_time_generator(database_connection, time_generator.clone());
+
+    let authorization = NotificationAuthorization {
+        access_token: AccessToken::from("access_token".to_string()),
+        dpop_public_key: PublicKey::from(*SigningKey::generate().verifying_key()),
+        dpop_nonce: "nonce".to_string(),
+    };
+    let credentials = ["first", "second"]
+        .into_iter()
+        .map(|notification_id| IssuedCredential {
+            notification_id: notification_id.to_string(),
+            credential_configuration_id: "com.example.degree_dc+sd-jwt".to_string().into(),
+            attestation_type: "com.example.degree".to_string(),
+            batch_id: Uuid::new_v4(),
+            expires_at: now + Duration::days(30),
+        })
+        .collect::<Vec<_>>();
+
+    store
+        .store_issued_credentials(IssuedCredentials {
+            authorization: authorization.clone(),
+            credentials: credentials.clone(),
+        })
+        .await
+        .unwrap();
+
+    // Both credentials can be found until they expire, also after cleanup.
+    *time_generator.time.write() += Duration::days(29);
+    store.cleanup_issued_credentials().await.unwrap();
+
+    for credential in &credentials {
+        let (fetched_authorization, fetched_credential) = store
+            .fetch_issued_credential(&credential.notification_id)
+            .await
+            .unwrap()
+            .expect("issued credential should be present");
+        assert_eq!(fetched_authorization, authorization);
+        assert_eq!(&fetched_credential, credential);
+    }
+
+    // A removed credential can no longer be found.
+    store.remove_issued_credential("first").await.unwrap();
+    assert!(store.fetch_issued_credential("first").await.unwrap().is_none());
+
+    // Neither can an expired credential.
+    *time_generator.time.write() += Duration::days(1);
+    assert!(store.fetch_issued_credential("second").await.unwrap().is_none());
+}
@@ -0,0 +1,139 @@
+use attestation_types::credential_format::Format;
+use attestation_types::credential_kind::CredentialKind;
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
+use crypto::PublicKey;
+use db_test::DbName;
+use db_test::DbSetup;
+use db_test::connection_from_url;
+use issuer_common::refresh_token_store::IssuerRefreshTokenStore;
+use openid4vc::issuer::RefreshTokenData;
+use openid4vc::issuer::RefreshTokenStore;
+use openid4vc::issuer::RefreshableDocument;
+use openid4vc::store::Consumed;
+use openid4vc::token::RefreshToken;
+use p256::ecdsa::SigningKey;
+use p256::elliptic_curve::Generate;
+use sea_orm::ConnectionTrait;
+use sea_orm::DatabaseConnection;
+use sea_orm::DbBackend;
+use sea_orm::Statement;
+use utils::generator::mock::MockTimeGenerator;
+use utils::vec_nonempty;
+
+fn refresh_token_data() -> RefreshTokenData {
+    RefreshTokenData {
+        dpop_public_key: PublicKey::from(*SigningKey::generate().verifying_key()),
+        documents: vec_nonempty![RefreshableDocument {
+            credential_kind: CredentialKind::new(Format::SdJwt, "com.example.degree".to_string()),
+            reference: "reference".to_string().into(),
+        }],
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_issuer_refresh_token_store() {
+    let db_setup = DbSetup::create_clean_only([DbName::PidIssuer]).await;
+    let database_connection = connection_from_url(db_setup.pid_issuer_url()).await;
+
+    let now = DateTime::from_timestamp_secs(1_000_000_000).unwrap();
+    let store = IssuerRefreshTokenStore::new_postgres_with_time_generator(
+        database_connection.clone(),
+        Duration::days(30),
+        MockTimeGenerator::new(now),
+    );
+
+    let refresh_token = RefreshToken::from("refresh_token".to_string());
+    let data = refresh_token_data();
+    store
+        .store_refresh_token(refresh_token.clone(), data.clone())
+        .await
+        .unwrap();
+    assert_eq!(count_rows(&database_connection).await, 1);
+
+    // Fetching the refresh token does not remove it.
+    let fetched = store.fetch_refresh_token(&refresh_token).await.unwrap().unwrap();
+    assert_eq!(fetched.dpop_public_key, data.dpop_public_key);
+    assert_eq!(fetched.documents, data.documents);
+    assert_eq!(count_rows(&database_connection).await, 1);
+
+    // The refresh token can be redeemed exactly once.
+    let Consumed::Live(consumed) = store.consume_refresh_token(&refresh_token).await.unwrap() else {
+        panic!("refresh token should be live");
+    };
+    assert_eq!(consumed.dpop_public_key, data.dpop_public_key);
+
+    assert!(matches!(
+        store.consume_refresh_token(&refresh_token).await.unwrap(),
+        Consumed::Absent
+    ));
+    assert!(store.fetch_refresh_token(&refresh_token).await.unwrap().is_none());
+    assert_eq!(count_rows(&database_connection).await, 0);
+}
+
+/// Verify that `cleanup` removes only expired rows and drains backlogs larger than a single batch
+/// (the `FOR UPDATE SKIP LOCKED` loop), leaving still-valid rows untouched.
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_issuer_refresh_token_store_cleanup_drains_in_batches() {
+    let db_setup = DbSetup::create_clean_only([DbName::PidIssuer]).await;
+    let database_connection = connection_from_url(db_setup.pid_issuer_url()).await;
+
+    let now = DateTime::from_timestamp_secs(1_000_000_000).unwrap();
+    let store = IssuerRefreshTokenStore::new_postgres_with_time_generator(
+        database_connection.clone(),
+        Duration::days(30),
+        MockTimeGenerator::new(now),
+    );
+
+    // More than one batch (CLEANUP_BATCH_SIZE == 1000) worth of expired rows, plus a few valid ones.
+    let expired_count: i64 = 1_005;
+    let valid_count: i64 = 3;
+
+    insert_rows(
+        &database_connection,
+        "expired",
+        expired_count,
+        now - Duration::seconds(1),
+    )
+    .await;
+    insert_rows(&database_connection, "valid", valid_count, now + Duration::hours(1)).await;
+    assert_eq!(
+        count_rows(&database_connection).await,
+        (expired_count + valid_count) as usize
+    );
+
+    store.cleanup_refresh_tokens().await.unwrap();
+
+    assert_eq!(count_rows(&database_connection).await, valid_count as usize);
+}
+
+async fn insert_rows(connection: &DatabaseConnection, prefix: &str, count: i64, expires_at: DateTime<Utc>) {
+    connection
+        .execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"
+            INSERT INTO refresh_token (token, data, expires_at)
+            SELECT $1 || '-' || g, '{}'::jsonb, $2
+            FROM generate_series(1, $3::bigint) AS g
+            "#,
+            [prefix.into(), expires_at.into(), count.into()],
+        ))
+        .await
+        .unwrap();
+}
+
+async fn count_rows(connection: &DatabaseConnection) -> usize {
+    connection
+        .query_one(Statement::from_string(
+            DbBackend::Postgres,
+            r#"SELECT COUNT(*) FROM "refresh_token""#,
+        ))
+        .await
+        .unwrap()
+        .unwrap()
+        .try_get_by_index::<i64>(0)
+        .unwrap()
+        .try_into()
+        .unwrap()
+}
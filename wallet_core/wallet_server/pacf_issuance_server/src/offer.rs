@@ -2,13 +2,18 @@ use std::sync::Arc;
 
 use axum::Json;
 use axum::Router;
+use axum::extract::Path;
 use axum::extract::State;
 use axum::http::StatusCode;
 use axum::routing::post;
 use issuer_common::IssuanceServerIssuer;
 use openid4vc::credential_offer::CredentialOfferContainer;
+use openid4vc::credential_offer::PreAuthTransactionCode;
 use openid4vc::issuable_document::IssuableDocument;
+use openid4vc::issuer::DeferredIssuanceDecisionError;
+use openid4vc::issuer::IssuanceError;
 use openid4vc::issuer::PreAuthorizedSessionError;
+use openid4vc::server_state::SessionToken;
 use serde::Deserialize;
 use serde::Serialize;
 use url::Url;
@@ -18,15 +23,30 @@ use utils::vec_at_least::VecNonEmpty;
 pub enum OfferError {
     #[error("could not create Pre-Authorized credential offer: {0}")]
     PreAuthorizedSession(#[source] PreAuthorizedSessionError),
+
+    #[error("could not approve or reject deferred issuance: {0}")]
+    DeferredIssuanceDecision(#[source] DeferredIssuanceDecisionError),
 }
 
 impl axum::response::IntoResponse for OfferError {
     fn into_response(self) -> axum::response::Response {
         let status = match self {
-            OfferError::PreAuthorizedSession(PreAuthorizedSessionError::IssuableDocument(_)) => StatusCode::BAD_REQUEST,
+            OfferError::PreAuthorizedSession(
+                PreAuthorizedSessionError::IssuableDocument(_)
+                | PreAuthorizedSessionError::TxCodeInputMode(_)
+                | PreAuthorizedSessionError::TxCodeLength(_)
+                | PreAuthorizedSessionError::TxCodeDescriptionLength,
+            ) => StatusCode::BAD_REQUEST,
             OfferError::PreAuthorizedSession(PreAuthorizedSessionError::SessionStore(_)) => {
                 StatusCode::INTERNAL_SERVER_ERROR
             }
+            OfferError::DeferredIssuanceDecision(DeferredIssuanceDecisionError::IssuanceError(
+                IssuanceError::UnknownSession(_),
+            )) => StatusCode::NOT_FOUND,
+            OfferError::DeferredIssuanceDecision(DeferredIssuanceDecisionError::IssuanceError(
+                IssuanceError::SessionStore(_),
+            )) => StatusCode::INTERNAL_SERVER_ERROR,
+            OfferError::DeferredIssuanceDecision(_) => StatusCode::CONFLICT,
         };
         (status, self.to_string()).into_response()
     }
@@ -39,17 +59,33 @@ struct ApplicationState {
 pub fn create_offer_router(issuer: Arc<IssuanceServerIssuer>) -> Router {
     Router::new()
         .route("/offer", post(offer))
+        .route("/deferred/{session_token}/approve", post(approve_deferred))
+        .route("/deferred/{session_token}/reject", post(reject_deferred))
         .with_state(Arc::new(ApplicationState { issuer }))
 }
 
 #[derive(Serialize, Deserialize)]
 pub struct OfferRequest {
     pub documents: VecNonEmpty<IssuableDocument>,
+    /// Only issue the documents after the session has been approved using the `/deferred/{session_token}/approve`
+    /// endpoint.
+    #[serde(default)]
+    pub deferred: bool,
+    /// Require the user to enter a Transaction Code in the wallet, which is generated according to these requirements
+    /// and returned so that it can be delivered to the user out-of-band, e.g. by e-mail or text message.
+    #[serde(default)]
+    pub tx_code: Option<PreAuthTransactionCode>,
 }
 
 #[derive(Serialize, Deserialize)]
 pub struct OfferResponse {
     pub credential_offer_url: Url,
+    /// The token with which a deferred session can be approved or rejected, only present for deferred offers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_token: Option<SessionToken>,
+    /// The Transaction Code the user needs to enter in the wallet, only present if requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tx_code: Option<String>,
 }
 
 /// Accepts a list of issuable documents, creates a pre-authorized issuance session, and returns
@@ -58,12 +94,72 @@ async fn offer(
     State(state): State<Arc<ApplicationState>>,
     Json(request): Json<OfferRequest>,
 ) -> Result<Json<OfferResponse>, OfferError> {
-    let credential_offer = state
+    let (credential_offer, session_token, tx_code) = match request.tx_code {
+        Some(tx_code) => {
+            let (credential_offer, session_token, tx_code) = state
+                .issuer
+                .new_preauthorized_session_with_tx_code(request.documents, request.deferred, tx_code)
+                .await
+                .map_err(OfferError::PreAuthorizedSession)?;
+
+            (
+                credential_offer,
+                request.deferred.then_some(session_token),
+                Some(tx_code),
+            )
+        }
+        None if request.deferred => {
+            let (credential_offer, session_token) = state
+                .issuer
+                .new_deferred_preauthorized_session(request.documents)
+                .await
+                .map_err(OfferError::PreAuthorizedSession)?;
+
+            (credential_offer, Some(session_token), None)
+        }
+        None => {
+            let credential_offer = state
+                .issuer
+                .new_preauthorized_session(request.documents)
+                .await
+                .map_err(OfferError::PreAuthorizedSession)?;
+
+            (credential_offer, None, None)
+        }
+    };
+
+    let credential_offer_url = CredentialOfferContainer::new_offer(credential_offer).to_credential_offer_url();
+    Ok(Json(OfferResponse {
+        credential_offer_url,
+        session_token,
+        tx_code,
+    }))
+}
+
+/// Approves a deferred issuance session, so that the wallet receives its credentials the next time it polls.
+async fn approve_deferred(
+    State(state): State<Arc<ApplicationState>>,
+    Path(session_token): Path<SessionToken>,
+) -> Result<StatusCode, OfferError> {
+    state
         .issuer
-        .new_preauthorized_session(request.documents)
+        .approve_deferred_issuance(&session_token)
         .await
-        .map_err(OfferError::PreAuthorizedSession)?;
+        .map_err(OfferError::DeferredIssuanceDecision)?;
 
-    let credential_offer_url = CredentialOfferContainer::new_offer(credential_offer).to_credential_offer_url();
-    Ok(Json(OfferResponse { credential_offer_url }))
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Rejects a deferred issuance session, which the wallet receives as an error the next time it polls.
+async fn reject_deferred(
+    State(state): State<Arc<ApplicationState>>,
+    Path(session_token): Path<SessionToken>,
+) -> Result<StatusCode, OfferError> {
+    state
+        .issuer
+        .reject_deferred_issuance(&session_token)
+        .await
+        .map_err(OfferError::DeferredIssuanceDecision)?;
+
+    Ok(StatusCode::NO_CONTENT)
 }
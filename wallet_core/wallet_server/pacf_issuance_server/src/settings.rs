@@ -80,4 +80,8 @@ impl ServerSettings for PacfIssuanceServerSettings {
     fn server_settings(&self) -> &Settings {
         &self.0.server_settings
     }
+
+    fn server_settings_mut(&mut self) -> &mut Settings {
+        &mut self.0.server_settings
+    }
 }
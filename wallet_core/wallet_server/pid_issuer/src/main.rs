@@ -10,6 +10,7 @@ use pid_issuer::pid::digid::DigidMetadataClient;
 use pid_issuer::pid::digid_mock::MOCK_LOGIN_PATH;
 use pid_issuer::pid::digid_mock::MockLoginState;
 use pid_issuer::pid::digid_mock::build_mock_login_csp;
+use pid_issuer::pid::document_source::BrpDocumentSource;
 use pid_issuer::server;
 use pid_issuer::settings::PidIssuerSettings;
 use reqwest::redirect::Policy;
@@ -40,8 +41,28 @@ async fn main_impl(settings: PidIssuerSettings) -> Result<()> {
     let mock_subjects = settings.digid.mock_subjects;
 
     let digid_metadata_client = DigidMetadataClient::try_new(settings.digid.client_settings)?;
-    let brp_client = HttpBrpClient::new(settings.brp_server);
-    let recovery_code_secret_key = SecretKeyVariant::from_settings(settings.recovery_code, hsm.clone())?;
+    let brp_client = HttpBrpClient::new(settings.brp_server.clone());
+    let recovery_code_secret_key = SecretKeyVariant::from_settings(settings.recovery_code.clone(), hsm.clone())?;
+    let subject_lookup_secret_key = settings
+        .subject_lookup
+        .clone()
+        .map(|secret_key| SecretKeyVariant::from_settings(secret_key, hsm.clone()))
+        .transpose()?;
+    let revocation_subject_lookup_secret_key = settings
+        .subject_lookup
+        .clone()
+        .map(|secret_key| SecretKeyVariant::from_settings(secret_key, hsm.clone()))
+        .transpose()?;
+
+    // When the wallet redeems a refresh token, the PID is fetched again from the BRP, using its own client and keys.
+    let document_source = BrpDocumentSource::new(
+        HttpBrpClient::new(settings.brp_server),
+        SecretKeyVariant::from_settings(settings.recovery_code, hsm.clone())?,
+        settings
+            .subject_lookup
+            .map(|secret_key| SecretKeyVariant::from_settings(secret_key, hsm.clone()))
+            .transpose()?,
+    );
     let digid_client_id = settings.digid.client_id;
     let bsn_privkey = settings.digid.bsn_privkey;
 
@@ -79,7 +100,7 @@ async fn main_impl(settings: PidIssuerSettings) -> Result<()> {
 
     let (issuer, database_checkers, _, server_settings) = settings
         .authorizing_issuer_settings
-        .into_authorizing_issuer(hsm, |store_connection| {
+        .into_authorizing_issuer(hsm, Some(Box::new(document_source)), |store_connection| {
             UpstreamOidcAuthorizationCodeFlow::try_new(
                 brp_client,
                 &bsn_privkey,
@@ -89,7 +110,10 @@ async fn main_impl(settings: PidIssuerSettings) -> Result<()> {
                 store_connection,
                 &callback_base_url,
             )
-            .map(|flow| flow.with_mock_login_uri(mock_login_uri))
+            .map(|flow| {
+                flow.with_mock_login_uri(mock_login_uri)
+                    .with_subject_lookup_secret_key(subject_lookup_secret_key)
+            })
         })
         .await?;
 
@@ -106,6 +130,7 @@ async fn main_impl(settings: PidIssuerSettings) -> Result<()> {
         serve_status_lists,
         health_checkers,
         mock_login,
+        revocation_subject_lookup_secret_key,
     )
     .await
 }
@@ -32,6 +32,7 @@ use openid4vc::errors::AuthorizationErrorCode;
 use openid4vc::errors::BodyOrRedirectErrorResponse;
 use openid4vc::errors::ErrorWithCode;
 use openid4vc::errors::RedirectError;
+use openid4vc::issuable_document::DocumentReference;
 use openid4vc::issuable_document::IssuableDocument;
 use openid4vc::issuer::AuthRequestValues;
 use openid4vc::issuer::IssuanceData;
@@ -62,6 +63,7 @@ use crate::pid::digid;
 use crate::pid::digid::DigidClient;
 use crate::pid::digid::DigidMetadataClient;
 use crate::pid::digid::HttpDigidClient;
+use crate::pid::revocation::subject_lookup_hash;
 
 const ISSUER_STATE_LENGTH: usize = 32;
 
@@ -162,13 +164,14 @@ struct DigidCallbackQuery {
 /// - upstream OIDC discovery cache + client (for the authorize-endpoint URL and the `/userinfo`-based BSN exchange);
 /// - the state-bridge store linking the issuer-generated `bridge_key` (sent to the upstream as `state`) to the wallet's
 ///   original `redirect_uri`, `state`, PKCE challenge and our upstream PKCE verifier;
-/// - the BRP client (BSN → person attributes) and the recovery-code HMAC key;
+/// - the BRP client (BSN → person attributes), the recovery-code HMAC key and the optional subject lookup HMAC key;
 /// - the issuer's own callback URL, used both as the upstream `redirect_uri` and as the `redirect_uri` parameter of the
 ///   upstream `/token` exchange.
 pub struct UpstreamOidcAuthorizationCodeFlow<B = HttpBrpClient, O = HttpDigidClient> {
     brp_client: B,
     digid_client: O,
     recovery_code_secret_key: SecretKeyVariant,
+    subject_lookup_secret_key: Option<SecretKeyVariant>,
     state_bridge_store: Arc<IssuerStateBridgeStore<StateBridgeEntry>>,
     callback_url: Url,
     client_id: String,
@@ -234,6 +237,7 @@ impl<B, O> UpstreamOidcAuthorizationCodeFlow<B, O> {
             brp_client,
             digid_client,
             recovery_code_secret_key,
+            subject_lookup_secret_key: None,
             state_bridge_store,
             callback_url: callback_base_url.join(DIGID_CALLBACK_PATH),
             client_id,
@@ -248,6 +252,13 @@ impl<B, O> UpstreamOidcAuthorizationCodeFlow<B, O> {
         self
     }
 
+    /// Store a subject lookup hash, computed as an HMAC over the BSN using this key, with every issued attestation
+    /// batch.
+    pub fn with_subject_lookup_secret_key(mut self, subject_lookup_secret_key: Option<SecretKeyVariant>) -> Self {
+        self.subject_lookup_secret_key = subject_lookup_secret_key;
+        self
+    }
+
     /// Mount the `/digid/callback` route owned by this flow on a fresh [`Router`]. The
     /// pid_issuer's `server` module merges this with the `openid4vc` layer's authorization and
     /// issuance routers. The handler reads its flow state via [`AuthorizingIssuer::flow`].
@@ -285,29 +296,65 @@ impl<B, O> UpstreamOidcAuthorizationCodeFlow<B, O> {
             .await
             .map_err(Error::Digid)?;
 
-        let mut persons = self.brp_client.get_person_by_bsn(&bsn).await.map_err(Error::Brp)?;
-        if persons.persons.len() != 1 {
-            return Err(Error::NoAttributesFound);
-        }
-        let person = persons.persons.remove(0);
-        let attributes = insert_recovery_code(person.into_attributes(), &self.recovery_code_secret_key).await?;
-
-        // Create an `IssuableDocument` for each requested format.
-        let format_count = formats.len();
-        let issuable_documents = formats
-            .into_nonempty_iter()
-            .zip(utils::vec_at_least::repeat_n(attributes, format_count))
-            .map(|(format, attributes)| {
-                IssuableDocument::try_new_with_random_id(
-                    CredentialKind::new(format, PID_ATTESTATION_TYPE.to_string()),
-                    attributes,
-                )
-            })
-            .collect::<Result<_, _>>()
-            .map_err(|_| Error::InvalidIssuableDocuments)?;
+        issuable_documents_for_bsn(
+            &self.brp_client,
+            &self.recovery_code_secret_key,
+            self.subject_lookup_secret_key.as_ref(),
+            bsn,
+            formats,
+        )
+        .await
+    }
+}
 
-        Ok(issuable_documents)
+/// Look up the attributes of the person with `bsn` in the BRP and build an [`IssuableDocument`] for each of the
+/// requested formats. The BSN is used as the reference to the source of the documents, so that these can be fetched
+/// again when the wallet redeems a refresh token.
+pub(crate) async fn issuable_documents_for_bsn<B>(
+    brp_client: &B,
+    recovery_code_secret_key: &SecretKeyVariant,
+    subject_lookup_secret_key: Option<&SecretKeyVariant>,
+    bsn: String,
+    formats: VecNonEmpty<Format>,
+) -> Result<VecNonEmpty<IssuableDocument>, Error>
+where
+    B: BrpClient,
+{
+    let mut persons = brp_client.get_person_by_bsn(&bsn).await.map_err(Error::Brp)?;
+    if persons.persons.len() != 1 {
+        return Err(Error::NoAttributesFound);
     }
+    let person = persons.persons.remove(0);
+    let attributes = insert_recovery_code(person.into_attributes(), recovery_code_secret_key).await?;
+
+    let subject_lookup_hash = match subject_lookup_secret_key {
+        Some(secret_key) => Some(subject_lookup_hash(secret_key, &bsn).await.map_err(Error::Hmac)?),
+        None => None,
+    };
+    let source_reference = DocumentReference::from(bsn);
+
+    // Create an `IssuableDocument` for each requested format.
+    let format_count = formats.len();
+    let issuable_documents = formats
+        .into_nonempty_iter()
+        .zip(utils::vec_at_least::repeat_n(attributes, format_count))
+        .map(|(format, attributes)| {
+            IssuableDocument::try_new_with_random_id(
+                CredentialKind::new(format, PID_ATTESTATION_TYPE.to_string()),
+                attributes,
+            )
+            .map(|document| {
+                let document = document.with_source_reference(source_reference.clone());
+                match &subject_lookup_hash {
+                    Some(subject_lookup_hash) => document.with_subject_lookup_hash(subject_lookup_hash.clone()),
+                    None => document,
+                }
+            })
+        })
+        .collect::<Result<_, _>>()
+        .map_err(|_| Error::InvalidIssuableDocuments)?;
+
+    Ok(issuable_documents)
 }
 
 impl<B, O> AuthorizationCodeFlow for UpstreamOidcAuthorizationCodeFlow<B, O>
@@ -577,6 +624,7 @@ mod tests {
     use server_utils::keys::SecretKeyVariant;
     use server_utils::settings::SecretKey;
     use server_utils::store::StoreConnection;
+    use token_status_list::status_list_service::SubjectLookupHash;
     use token_status_list::status_list_service::mock::MockStatusListService;
     use utils::path::prefix_local_path;
     use utils::vec_nonempty;
@@ -762,6 +810,71 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_subject_lookup_hash() {
+        let bsn = "999991772";
+        let key: Vec<_> = (32..64).collect();
+        let formats = vec_nonempty![Format::MsoMdoc, Format::SdJwt];
+
+        // Without a subject lookup key, no hash should be stored with the issued attestations.
+        let flow = flow_with_clients(
+            MockBrpClient::from_fixture("frouke"),
+            MockDigidClient::new(bsn),
+            memory_bridge_store(),
+        );
+        let documents = flow
+            .fetch_issuable_documents(
+                AuthorizationCode::from("upstream-code".to_string()),
+                "upstream-verifier".to_string(),
+                formats.clone(),
+            )
+            .await
+            .unwrap();
+
+        assert!(
+            documents
+                .as_ref()
+                .iter()
+                .all(|document| document.subject_lookup_hash.is_none())
+        );
+
+        // Every document should refer to its source by the BSN, so that it can be fetched again on refresh.
+        assert!(
+            documents
+                .as_ref()
+                .iter()
+                .all(|document| document.source_reference.as_ref().map(AsRef::as_ref) == Some(bsn))
+        );
+
+        // With a subject lookup key, every document should contain the HMAC over the BSN.
+        let secret_key = SecretKeyVariant::from_settings(
+            SecretKey::Software {
+                secret_key: key.clone().try_into().unwrap(),
+            },
+            None,
+        )
+        .unwrap();
+        let flow = flow.with_subject_lookup_secret_key(Some(secret_key));
+        let documents = flow
+            .fetch_issuable_documents(
+                AuthorizationCode::from("upstream-code".to_string()),
+                "upstream-verifier".to_string(),
+                formats,
+            )
+            .await
+            .unwrap();
+
+        let hmac_key = &hmac::Key::new(HMAC_SHA256, &key);
+        let expected_hash = SubjectLookupHash::from(hex::encode(hmac::sign(hmac_key, bsn.as_bytes())));
+
+        assert!(
+            documents
+                .as_ref()
+                .iter()
+                .all(|document| document.subject_lookup_hash.as_ref() == Some(&expected_hash))
+        );
+    }
+
     #[tokio::test]
     async fn authorize_builds_upstream_redirect_and_bridge_entry() {
         let bridge = memory_bridge_store();
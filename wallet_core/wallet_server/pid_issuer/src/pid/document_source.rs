@@ -0,0 +1,91 @@
+use async_trait::async_trait;
+use openid4vc::issuable_document::IssuableDocument;
+use openid4vc::issuer::IssuableDocumentSource;
+use openid4vc::issuer::RefreshableDocument;
+use server_utils::keys::SecretKeyVariant;
+use utils::vec_nonempty;
+
+use crate::pid::auth_code_flow::issuable_documents_for_bsn;
+use crate::pid::brp::client::BrpClient;
+use crate::pid::brp::client::HttpBrpClient;
+
+/// Fetches the PID again from the BRP when the wallet redeems a refresh token, using the BSN that the documents refer
+/// to. This ensures that reissued PIDs contain the current attributes of the person.
+pub struct BrpDocumentSource<B = HttpBrpClient> {
+    brp_client: B,
+    recovery_code_secret_key: SecretKeyVariant,
+    subject_lookup_secret_key: Option<SecretKeyVariant>,
+}
+
+impl<B> BrpDocumentSource<B> {
+    pub fn new(
+        brp_client: B,
+        recovery_code_secret_key: SecretKeyVariant,
+        subject_lookup_secret_key: Option<SecretKeyVariant>,
+    ) -> Self {
+        Self {
+            brp_client,
+            recovery_code_secret_key,
+            subject_lookup_secret_key,
+        }
+    }
+}
+
+#[async_trait]
+impl<B> IssuableDocumentSource for BrpDocumentSource<B>
+where
+    B: BrpClient + Send + Sync,
+{
+    async fn fetch_issuable_document(
+        &self,
+        document: &RefreshableDocument,
+    ) -> Result<IssuableDocument, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let issuable_documents = issuable_documents_for_bsn(
+            &self.brp_client,
+            &self.recovery_code_secret_key,
+            self.subject_lookup_secret_key.as_ref(),
+            document.reference.as_ref().to_string(),
+            vec_nonempty![document.credential_kind.format],
+        )
+        .await?;
+
+        Ok(issuable_documents.into_first())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use attestation_types::credential_format::Format;
+    use attestation_types::credential_kind::CredentialKind;
+    use openid4vc::issuer::IssuableDocumentSource;
+    use openid4vc::issuer::RefreshableDocument;
+    use server_utils::keys::SecretKeyVariant;
+    use server_utils::settings::SecretKey;
+
+    use super::BrpDocumentSource;
+    use crate::pid::constants::PID_ATTESTATION_TYPE;
+    use crate::pid::mock::MockBrpClient;
+
+    #[tokio::test]
+    async fn test_brp_document_source() {
+        let key: Vec<_> = (32..64).collect();
+        let secret_key = SecretKeyVariant::from_settings(
+            SecretKey::Software {
+                secret_key: key.try_into().unwrap(),
+            },
+            None,
+        )
+        .unwrap();
+        let source = BrpDocumentSource::new(MockBrpClient::default(), secret_key, None);
+
+        let document = RefreshableDocument {
+            credential_kind: CredentialKind::new(Format::SdJwt, PID_ATTESTATION_TYPE.to_string()),
+            reference: "999991772".to_string().into(),
+        };
+        let issuable_document = source.fetch_issuable_document(&document).await.unwrap();
+
+        // The document should be of the requested kind and refer to the same source, so that it can be reissued again.
+        assert_eq!(issuable_document.credential_kind, document.credential_kind);
+        assert_eq!(issuable_document.source_reference, Some(document.reference));
+    }
+}
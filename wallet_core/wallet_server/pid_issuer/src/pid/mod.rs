@@ -3,7 +3,9 @@ pub mod brp;
 pub mod constants;
 pub mod digid;
 pub mod digid_mock;
+pub mod document_source;
 pub mod jwks;
+pub mod revocation;
 pub mod userinfo;
 
 #[cfg(any(test, feature = "mock"))]
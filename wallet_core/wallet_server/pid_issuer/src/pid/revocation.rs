@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use axum::Json;
+use axum::Router;
+use axum::extract::State;
+use crypto::EcdsaKeySend;
+use hsm::service::HsmError;
+use nutype::nutype;
+use server_utils::keys::SecretKeyVariant;
+use status_lists::postgres::PostgresStatusListService;
+use status_lists::postgres::RevokeAll;
+use status_lists::revoke::revoke_subject_batches;
+use token_status_list::status_list_service::RevocationError;
+use token_status_list::status_list_service::SubjectLookupHash;
+use utils::vec_at_least::VecNonEmpty;
+use utoipa::OpenApi;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+
+/// Compute the salted hash of `bsn` that is stored with the attestation batches issued to the person with that BSN, so
+/// that these can be revoked at once without storing the BSN itself.
+pub async fn subject_lookup_hash(secret_key: &SecretKeyVariant, bsn: &str) -> Result<SubjectLookupHash, HsmError> {
+    let hmac = secret_key.sign_hmac(bsn.as_bytes()).await?;
+
+    Ok(SubjectLookupHash::from(hex::encode(hmac)))
+}
+
+/// A BSN (burgerservicenummer), which consists of 9 digits that pass the so-called "elfproef".
+#[nutype(
+    derive(Debug, Clone, Deserialize, AsRef),
+    validate(predicate = Bsn::is_valid),
+)]
+pub struct Bsn(String);
+
+impl Bsn {
+    // See section I.2.3 of Logisch Ontwerp BSN 2024 Q1 for the details on the "elfproef"
+    fn is_valid(bsn: &str) -> bool {
+        let Some(digits) = bsn
+            .chars()
+            .map(|c| c.to_digit(10).map(|digit| digit as i32))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return false;
+        };
+
+        if digits.len() != 9 {
+            return false;
+        }
+
+        let weights = [9, 8, 7, 6, 5, 4, 3, 2, -1];
+        let sum: i32 = digits.iter().zip(weights).map(|(digit, weight)| digit * weight).sum();
+
+        sum % 11 == 0
+    }
+}
+
+struct SubjectRevocationRouterState<K, R> {
+    status_list_services: VecNonEmpty<PostgresStatusListService<K, R>>,
+    subject_lookup_secret_key: SecretKeyVariant,
+}
+
+#[derive(OpenApi)]
+#[openapi(info(title = "PID revocation API"))]
+struct ApiDoc;
+
+#[utoipa::path(
+    post,
+    path = "/revoke/bsn/",
+    request_body(
+        content = String,
+        example = json!("999991772"),
+    ),
+    responses(
+        (status = OK, body = Vec<Uuid>, description = "Successfully revoked all batches issued to the person with the \
+            provided BSN, returning the revoked batch IDs."),
+        (status = UNPROCESSABLE_ENTITY, description = "The provided value is not a valid BSN."),
+    )
+)]
+async fn revoke_bsn<K, R>(
+    State(state): State<Arc<SubjectRevocationRouterState<K, R>>>,
+    Json(bsn): Json<Bsn>,
+) -> Result<Json<Vec<Uuid>>, RevocationError>
+where
+    K: EcdsaKeySend + Sync + 'static,
+    R: RevokeAll + Clone + Sync + 'static,
+{
+    let subject_lookup_hash = subject_lookup_hash(&state.subject_lookup_secret_key, bsn.as_ref())
+        .await
+        .map_err(|e| RevocationError::InternalError(Box::new(e)))?;
+
+    let revoked_batch_ids = revoke_subject_batches(state.status_list_services.as_slice(), &subject_lookup_hash).await?;
+
+    Ok(Json(revoked_batch_ids))
+}
+
+/// Create the internal router to revoke all attestation batches issued to a person by their BSN, which is hashed using
+/// `subject_lookup_secret_key` in the same way as during issuance.
+pub fn create_subject_revocation_router<K, R>(
+    status_list_services: VecNonEmpty<PostgresStatusListService<K, R>>,
+    subject_lookup_secret_key: SecretKeyVariant,
+) -> (Router, utoipa::openapi::OpenApi)
+where
+    K: EcdsaKeySend + Sync + 'static,
+    R: RevokeAll + Clone + Sync + 'static,
+{
+    let state = SubjectRevocationRouterState {
+        status_list_services,
+        subject_lookup_secret_key,
+    };
+
+    OpenApiRouter::with_openapi(ApiDoc::openapi())
+        .routes(routes!(revoke_bsn))
+        .with_state(Arc::new(state))
+        .split_for_parts()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::Bsn;
+
+    #[rstest]
+    #[case("999991772")]
+    #[case("900253010")]
+    #[case("900265462")]
+    fn test_bsn_valid(#[case] bsn: &str) {
+        assert!(Bsn::try_new(bsn).is_ok());
+    }
+
+    #[rstest]
+    #[case("999991773")]
+    #[case("9999917720")]
+    #[case("11122146")]
+    #[case("99999177a")]
+    #[case("")]
+    fn test_bsn_invalid(#[case] bsn: &str) {
+        assert!(Bsn::try_new(bsn).is_err());
+    }
+}
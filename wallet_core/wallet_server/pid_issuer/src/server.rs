@@ -13,6 +13,7 @@ use openid4vc::issuer::IssuanceData;
 use openid4vc_server::issuer::create_authorization_router;
 use openid4vc_server::issuer::create_issuance_router;
 use server_utils::keys::PrivateKeyVariant;
+use server_utils::keys::SecretKeyVariant;
 use server_utils::server::add_cache_control_no_store_layer;
 use server_utils::server::create_internal_listener;
 use server_utils::server::create_wallet_listener;
@@ -33,6 +34,7 @@ use crate::pid::brp::client::HttpBrpClient;
 use crate::pid::digid::DigidClient;
 use crate::pid::digid::HttpDigidClient;
 use crate::pid::digid_mock::MockLoginState;
+use crate::pid::revocation::create_subject_revocation_router;
 
 pub type PidIssuer<B = HttpBrpClient, O = HttpDigidClient> = AuthorizingIssuer<
     PrivateKeyVariant,
@@ -49,6 +51,7 @@ pub async fn serve(
     serve_status_lists: bool,
     health_checkers: impl IntoIterator<Item = Box<dyn HealthChecker + Send + Sync>>,
     mock_login: Option<MockLoginState>,
+    subject_lookup_secret_key: Option<SecretKeyVariant>,
 ) -> Result<()> {
     serve_with_listeners(
         create_wallet_listener(&server_settings.wallet_server).await?,
@@ -58,13 +61,14 @@ pub async fn serve(
         serve_status_lists,
         health_checkers,
         mock_login,
+        subject_lookup_secret_key,
     )
     .await
 }
 
 #[expect(
     clippy::too_many_arguments,
-    reason = "server wiring, including the optional mock login page"
+    reason = "server wiring, including the optional mock login page and subject lookup key"
 )]
 pub async fn serve_with_listeners<B, O>(
     wallet_listener: TcpListener,
@@ -74,6 +78,7 @@ pub async fn serve_with_listeners<B, O>(
     serve_status_lists: bool,
     health_checkers: impl IntoIterator<Item = Box<dyn HealthChecker + Send + Sync>>,
     mock_login: Option<MockLoginState>,
+    subject_lookup_secret_key: Option<SecretKeyVariant>,
 ) -> Result<()>
 where
     B: BrpClient + Send + Sync + 'static,
@@ -115,7 +120,16 @@ where
         router = router.merge(status_list_router);
     }
 
-    let (internal_router, internal_openapi) = create_revocation_router(status_list_services);
+    let (mut internal_router, mut internal_openapi) = create_revocation_router(status_list_services.clone());
+
+    // Revoking all attestations of a person by their BSN requires the key their subject lookup hash was computed with.
+    if let Some(subject_lookup_secret_key) = subject_lookup_secret_key {
+        let (subject_router, subject_openapi) =
+            create_subject_revocation_router(status_list_services, subject_lookup_secret_key);
+
+        internal_router = internal_router.merge(subject_router);
+        internal_openapi.merge(subject_openapi);
+    }
 
     #[cfg(feature = "test_internal_ui")]
     let mut internal_router = internal_router.merge(
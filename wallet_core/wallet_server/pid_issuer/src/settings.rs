@@ -35,6 +35,11 @@ pub struct PidIssuerSettings {
     #[debug(skip)]
     pub recovery_code: SecretKey,
 
+    /// Secret key used to compute the subject lookup hash (an HMAC over the BSN) that is stored with every issued
+    /// attestation batch, which allows revoking all attestations issued to a person. No hash is stored when absent.
+    #[debug(skip)]
+    pub subject_lookup: Option<SecretKey>,
+
     #[serde(flatten)]
     pub authorizing_issuer_settings: AuthorizingIssuerSettings,
 }
@@ -131,6 +136,10 @@ impl ServerSettings for PidIssuerSettings {
     fn server_settings(&self) -> &Settings {
         &self.authorizing_issuer_settings.issuer_settings.server_settings
     }
+
+    fn server_settings_mut(&mut self) -> &mut Settings {
+        &mut self.authorizing_issuer_settings.issuer_settings.server_settings
+    }
 }
 
 #[cfg(test)]
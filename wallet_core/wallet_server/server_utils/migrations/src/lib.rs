@@ -2,12 +2,20 @@ use async_trait::async_trait;
 use sea_orm_migration::prelude::*;
 
 mod m20220101_000001_create_session_table;
+mod m20261018_000001_add_session_notification_due;
+mod m20261018_000002_create_session_state_notify_trigger;
+mod m20261018_000003_add_session_expiry;
 
 pub struct Migrator;
 
 #[async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20220101_000001_create_session_table::Migration)]
+        vec![
+            Box::new(m20220101_000001_create_session_table::Migration),
+            Box::new(m20261018_000001_add_session_notification_due::Migration),
+            Box::new(m20261018_000002_create_session_state_notify_trigger::Migration),
+            Box::new(m20261018_000003_add_session_expiry::Migration),
+        ]
     }
 }
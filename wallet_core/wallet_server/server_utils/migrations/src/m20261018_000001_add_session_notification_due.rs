@@ -0,0 +1,54 @@
+use async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SessionState::Table)
+                    .add_column(
+                        ColumnDef::new(SessionState::NotificationDueDateTime)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("session_state_notification_due_idx")
+                    .table(SessionState::Table)
+                    .col(SessionState::Type)
+                    .col(SessionState::NotificationDueDateTime)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SessionState::Table)
+                    .drop_column(SessionState::NotificationDueDateTime)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SessionState {
+    Table,
+    Type,
+    NotificationDueDateTime,
+}
@@ -0,0 +1,49 @@
+use async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// Notify listeners on the `session_state_changed` channel of every insert or update of a session,
+// which includes sessions being expired by the cleanup task.
+#[async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                CREATE OR REPLACE FUNCTION notify_session_state_changed() RETURNS trigger AS $$
+                BEGIN
+                    PERFORM pg_notify(
+                        'session_state_changed',
+                        json_build_object('type', NEW.type, 'token', NEW.token)::text
+                    );
+                    RETURN NEW;
+                END;
+                $$ LANGUAGE plpgsql;
+
+                CREATE TRIGGER session_state_changed
+                    AFTER INSERT OR UPDATE ON session_state
+                    FOR EACH ROW EXECUTE FUNCTION notify_session_state_changed();
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                DROP TRIGGER IF EXISTS session_state_changed ON session_state;
+                DROP FUNCTION IF EXISTS notify_session_state_changed();
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -13,6 +13,8 @@ pub struct Model {
     pub data: Json,
     pub status: String,
     pub last_active_date_time: DateTimeWithTimeZone,
+    pub notification_due_date_time: Option<DateTimeWithTimeZone>,
+    pub expiry_date_time: Option<DateTimeWithTimeZone>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -4,6 +4,7 @@ pub mod server;
 pub mod settings;
 pub mod status_list_token_cache_settings;
 pub mod store;
+pub mod trusted_list_settings;
 
 #[cfg(feature = "postgres")]
 pub mod entity;
@@ -3,8 +3,10 @@ use std::io;
 
 use anyhow::Result;
 use axum::Router;
+use crypto::trusted_list::TrustedListFetcher;
 use http::HeaderValue;
 use http::header;
+use http_utils::reqwest::default_reqwest_client_builder;
 use tokio::net::TcpListener;
 use tower_http::set_header::SetResponseHeaderLayer;
 use tower_http::trace::TraceLayer;
@@ -14,6 +16,7 @@ use tracing::info;
 use tracing::level_filters::LevelFilter;
 use tracing_subscriber::EnvFilter;
 use utils::built_info::version_string;
+use utils::generator::TimeGenerator;
 
 use crate::log_requests::log_request_response;
 use crate::settings::Authentication;
@@ -133,7 +136,7 @@ pub async fn wallet_server_main<S: ServerSettings, Fut: Future<Output = Result<(
     env_prefix: &str,
     app: impl FnOnce(S) -> Fut,
 ) -> Result<()> {
-    let settings = S::new(config_file, env_prefix)?;
+    let mut settings = S::new(config_file, env_prefix)?;
 
     // Initialize tracing.
     let builder = tracing_subscriber::fmt().with_env_filter(
@@ -147,6 +150,15 @@ pub async fn wallet_server_main<S: ServerSettings, Fut: Future<Output = Result<(
         builder.init();
     }
 
+    // Load the trust anchors from trusted lists first, as these may be needed to verify the settings.
+    if !settings.server_settings().trusted_lists.is_empty() {
+        let fetcher = TrustedListFetcher::new(default_reqwest_client_builder().build()?);
+        settings
+            .server_settings_mut()
+            .load_trusted_lists(&fetcher, &TimeGenerator)
+            .await?;
+    }
+
     // Verify the settings here, now that we've setup tracing.
     if let Err(error) = settings.validate() {
         error!("invalid configuration: {error}");
@@ -11,9 +11,12 @@ use config::ConfigError;
 use crypto::p256_der::DerSigningKey;
 use crypto::server_keys::KeyPair as ParsedKeyPair;
 use crypto::trust_anchor::TrustAnchors;
+use crypto::trusted_list::TrustedListError;
+use crypto::trusted_list::TrustedListFetcher;
 use crypto::x509::BorrowingCertificate;
 use crypto::x509::CertificateError;
 use crypto::x509::CertificateUsage;
+use crypto::x509::revocation::RevocationPolicy;
 use hsm::service::Pkcs11Hsm;
 use hsm::settings::Hsm;
 use nutype::nutype;
@@ -27,10 +30,14 @@ use utils::generator::Generator;
 
 use crate::keys::PrivateKeySettingsError;
 use crate::keys::PrivateKeyVariant;
+use crate::trusted_list_settings::TrustedListSettings;
 
 /// Used as the `iss` field by the wallet in various JWTs.
 pub const NL_WALLET_CLIENT_ID: &str = "https://wallet.edi.rijksoverheid.nl";
 
+/// The maximum number of CRLs and OCSP responses that are cached when checking certificate revocation.
+pub const CERTIFICATE_REVOCATION_CACHE_CAPACITY: u64 = 1_000;
+
 /// Settings shared by all variants of issuer/verifier servers.
 #[derive(Clone, Deserialize)]
 pub struct Settings {
@@ -57,6 +64,14 @@ pub struct Settings {
     /// Trust anchors for Wallet Relying Party Registration Certificates, used by both issuers and verifiers.
     pub wrprc_trust_anchors: TrustAnchors,
 
+    /// Trusted lists from which additional issuer, WRPAC and WRPRC trust anchors are loaded on application startup.
+    #[serde(default)]
+    pub trusted_lists: Vec<TrustedListSettings>,
+
+    /// If set, the revocation status of the certificates of the issuers of disclosed attributes is checked during
+    /// disclosure sessions using their CRLs and/or OCSP responders, in accordance with this policy.
+    pub certificate_revocation: Option<RevocationPolicy>,
+
     /// Optional HSM settings in which private keys can be stored
     pub hsm: Option<Hsm>,
 }
@@ -151,6 +166,36 @@ impl From<&Storage> for SessionStoreTimeouts {
     }
 }
 
+impl Settings {
+    /// Extend the issuer, WRPAC and WRPRC trust anchors with those loaded from the configured trusted lists.
+    pub async fn load_trusted_lists(
+        &mut self,
+        fetcher: &TrustedListFetcher,
+        time: &impl Generator<DateTime<Utc>>,
+    ) -> Result<(), TrustedListSettingsError> {
+        for trusted_list in &self.trusted_lists {
+            let anchors = trusted_list
+                .load(fetcher, time)
+                .await
+                .map_err(|error| TrustedListSettingsError::TrustedList(error, trusted_list.url.clone()))?;
+
+            tracing::info!(
+                "loaded {} issuer, {} WRPAC and {} WRPRC trust anchors from trusted list {}",
+                anchors.issuer.len(),
+                anchors.wrpac.len(),
+                anchors.wrprc.len(),
+                trusted_list.url
+            );
+
+            self.issuer_trust_anchors = self.issuer_trust_anchors.clone().extended(anchors.issuer)?;
+            self.wrpac_trust_anchors = self.wrpac_trust_anchors.clone().extended(anchors.wrpac)?;
+            self.wrprc_trust_anchors = self.wrprc_trust_anchors.clone().extended(anchors.wrprc)?;
+        }
+
+        Ok(())
+    }
+}
+
 impl KeyPair {
     pub async fn parse(
         self,
@@ -186,12 +231,21 @@ pub enum CertificateVerificationError {
     NoCertificateType(#[source] CertificateTypeError, String),
 }
 
+#[derive(Debug, thiserror::Error)]
+pub enum TrustedListSettingsError {
+    #[error("could not load trusted list `{1}`: {0}")]
+    TrustedList(#[source] TrustedListError, Url),
+    #[error("invalid trust anchor in trusted list: {0}")]
+    TrustAnchor(#[from] CertificateError),
+}
+
 pub trait ServerSettings: Sized {
     type ValidationError: std::error::Error + Send + Sync + 'static;
 
     fn new(config_file: &str, env_prefix: &str) -> Result<Self, ConfigError>;
     fn validate(&self) -> Result<(), Self::ValidationError>;
     fn server_settings(&self) -> &Settings;
+    fn server_settings_mut(&mut self) -> &mut Settings;
 }
 
 pub fn verify_key_pairs(
@@ -3,6 +3,7 @@ use std::time::Duration;
 use serde::Deserialize;
 use serde_with::DurationSeconds;
 use serde_with::serde_as;
+use token_status_list::status_list_token::StatusListTokenFormat;
 
 #[serde_as]
 #[derive(Debug, Clone, Deserialize)]
@@ -16,6 +17,10 @@ pub struct StatusListTokenCacheSettings {
     #[serde(default, rename = "error_ttl_in_sec")]
     #[serde_as(as = "DurationSeconds")]
     pub error_ttl: Duration,
+
+    /// The format in which Status List Tokens are requested from the issuer.
+    #[serde(default)]
+    pub format: StatusListTokenFormat,
 }
 
 impl Default for StatusListTokenCacheSettings {
@@ -24,6 +29,7 @@ impl Default for StatusListTokenCacheSettings {
             capacity: 100,
             default_ttl: Duration::from_secs(0),
             error_ttl: Duration::from_secs(0),
+            format: StatusListTokenFormat::default(),
         }
     }
 }
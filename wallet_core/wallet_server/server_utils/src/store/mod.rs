@@ -1,6 +1,10 @@
 #[cfg(feature = "postgres")]
 pub mod postgres;
 
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
 use openid4vc::server_state::Expirable;
 use openid4vc::server_state::HasProgress;
 use openid4vc::server_state::MemorySessionStore;
@@ -14,6 +18,7 @@ use openid4vc::server_state::SessionToken;
 use postgres::PostgresSessionStore;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
+use tokio::sync::broadcast;
 use url::Url;
 
 /// This enum effectively switches between the different types that implement `DisclosureSessionStore`,
@@ -83,6 +88,18 @@ where
         }
     }
 
+    async fn write_if_unchanged(
+        &self,
+        session: SessionState<T>,
+        last_active: DateTime<Utc>,
+    ) -> Result<bool, SessionStoreError> {
+        match self {
+            #[cfg(feature = "postgres")]
+            SessionStoreVariant::Postgres(postgres) => postgres.write_if_unchanged(session, last_active).await,
+            SessionStoreVariant::Memory(memory) => memory.write_if_unchanged(session, last_active).await,
+        }
+    }
+
     async fn cleanup(&self) -> Result<(), SessionStoreError> {
         match self {
             #[cfg(feature = "postgres")]
@@ -92,4 +109,25 @@ where
             SessionStoreVariant::Memory(memory) => memory.cleanup().await,
         }
     }
+
+    async fn claim_pending_notifications(
+        &self,
+        claim_duration: Duration,
+    ) -> Result<Vec<SessionState<T>>, SessionStoreError> {
+        match self {
+            #[cfg(feature = "postgres")]
+            SessionStoreVariant::Postgres(postgres) => postgres.claim_pending_notifications(claim_duration).await,
+            SessionStoreVariant::Memory(memory) => memory.claim_pending_notifications(claim_duration).await,
+        }
+    }
+
+    async fn subscribe(&self) -> Result<broadcast::Receiver<SessionToken>, SessionStoreError> {
+        match self {
+            #[cfg(feature = "postgres")]
+            SessionStoreVariant::Postgres(postgres) => {
+                <PostgresSessionStore as SessionStore<T>>::subscribe(postgres).await
+            }
+            SessionStoreVariant::Memory(memory) => memory.subscribe().await,
+        }
+    }
 }
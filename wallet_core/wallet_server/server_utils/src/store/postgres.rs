@@ -1,3 +1,7 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::Weak;
 use std::time::Duration;
 
 use chrono::DateTime;
@@ -5,6 +9,7 @@ use chrono::Utc;
 use openid4vc::server_state::Expirable;
 use openid4vc::server_state::HasProgress;
 use openid4vc::server_state::Progress;
+use openid4vc::server_state::SESSION_CHANGES_CAPACITY;
 use openid4vc::server_state::SessionDataType;
 use openid4vc::server_state::SessionState;
 use openid4vc::server_state::SessionStore;
@@ -13,19 +18,31 @@ use openid4vc::server_state::SessionStoreTimeouts;
 use openid4vc::server_state::SessionToken;
 use sea_orm::ActiveValue;
 use sea_orm::ColumnTrait;
+use sea_orm::Condition;
 use sea_orm::ConnectOptions;
 use sea_orm::Database;
 use sea_orm::DatabaseConnection;
 use sea_orm::DbErr;
 use sea_orm::EntityTrait;
 use sea_orm::QueryFilter;
+use sea_orm::QuerySelect;
 use sea_orm::SqlErr;
 use sea_orm::TransactionTrait;
 use sea_orm::sea_query::Expr;
+use sea_orm::sea_query::LockBehavior;
+use sea_orm::sea_query::LockType;
 use sea_orm::sea_query::OnConflict;
+use sea_orm::sqlx;
+use sea_orm::sqlx::postgres::PgListener;
+use serde::Deserialize;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
+use tokio::sync::OnceCell;
+use tokio::sync::broadcast;
+use tokio::task::AbortHandle;
+use tokio::time;
 use tracing::log::LevelFilter;
+use tracing::warn;
 use url::Url;
 use utils::generator::Generator;
 use utils::generator::TimeGenerator;
@@ -35,6 +52,10 @@ use crate::entity::session_state;
 const DB_ACQUIRE_TIMEOUT: Duration = Duration::from_secs(10);
 const DB_CONNECT_TIMEOUT: Duration = Duration::from_secs(3);
 
+/// The channel on which the `session_state` trigger notifies changes, see the migrations.
+const SESSION_STATE_CHANNEL: &str = "session_state_changed";
+const LISTENER_RETRY_DELAY: Duration = Duration::from_secs(1);
+
 #[derive(Debug, Clone, Copy, strum::Display, strum::EnumString)]
 #[strum(serialize_all = "snake_case")]
 enum SessionStatus {
@@ -71,6 +92,88 @@ pub struct PostgresSessionStore<G = TimeGenerator> {
     pub timeouts: SessionStoreTimeouts,
     time: G,
     connection: DatabaseConnection,
+    changes: Arc<SessionChanges>,
+}
+
+/// The payload of a notification sent by the `session_state` trigger.
+#[derive(Debug, Deserialize)]
+struct SessionStateChanged {
+    r#type: String,
+    token: String,
+}
+
+/// Forwards the notifications sent by the `session_state` trigger to the subscribers of the session type concerned.
+/// The listener is only started by the first subscriber, so that stores that are never subscribed to do not occupy a
+/// database connection.
+#[derive(Debug, Default)]
+struct SessionChanges {
+    senders: Mutex<HashMap<String, broadcast::Sender<SessionToken>>>,
+    listener_task: OnceCell<AbortHandle>,
+}
+
+impl SessionChanges {
+    fn subscribe(&self, session_type: &str) -> broadcast::Receiver<SessionToken> {
+        self.senders
+            .lock()
+            .unwrap()
+            .entry(session_type.to_string())
+            .or_insert_with(|| broadcast::channel(SESSION_CHANGES_CAPACITY).0)
+            .subscribe()
+    }
+
+    fn notify(&self, payload: &str) {
+        let changed = match serde_json::from_str::<SessionStateChanged>(payload) {
+            Ok(changed) => changed,
+            Err(error) => {
+                warn!("could not parse session state notification: {error}");
+                return;
+            }
+        };
+
+        if let Some(sender) = self.senders.lock().unwrap().get(&changed.r#type) {
+            // Sending only fails if there are no subscribers, which is fine.
+            let _ = sender.send(changed.token.into());
+        }
+    }
+}
+
+impl Drop for SessionChanges {
+    fn drop(&mut self) {
+        if let Some(listener_task) = self.listener_task.get() {
+            listener_task.abort();
+        }
+    }
+}
+
+async fn start_listener(
+    connection: &DatabaseConnection,
+    changes: Weak<SessionChanges>,
+) -> Result<AbortHandle, sqlx::Error> {
+    let mut listener = PgListener::connect_with(connection.get_postgres_connection_pool()).await?;
+    listener.listen(SESSION_STATE_CHANNEL).await?;
+
+    let listener_task = tokio::spawn(async move {
+        loop {
+            match listener.recv().await {
+                Ok(notification) => {
+                    // Stop listening once all clones of the session store have been dropped.
+                    let Some(changes) = changes.upgrade() else {
+                        break;
+                    };
+
+                    changes.notify(notification.payload());
+                }
+                // The listener reconnects on the next call to `recv()`, so wait a while before trying again.
+                Err(error) => {
+                    warn!("error receiving session state notification: {error}");
+                    time::sleep(LISTENER_RETRY_DELAY).await;
+                }
+            }
+        }
+    })
+    .abort_handle();
+
+    Ok(listener_task)
 }
 
 impl<G> PostgresSessionStore<G> {
@@ -79,6 +182,7 @@ impl<G> PostgresSessionStore<G> {
             timeouts,
             time,
             connection,
+            changes: Arc::default(),
         }
     }
 }
@@ -101,31 +205,7 @@ where
             .await
             .map_err(|e| SessionStoreError::Other(e.into()))?;
 
-        state
-            .map(|state| {
-                // Decode both the status and data columns.
-                let status = state
-                    .status
-                    .parse::<SessionStatus>()
-                    .map_err(|e| SessionStoreError::Deserialize(e.into()))?;
-                let mut data =
-                    serde_json::from_value::<T>(state.data).map_err(|e| SessionStoreError::Deserialize(e.into()))?;
-
-                // If the status is expired, expire the data.
-                if matches!(status, SessionStatus::Expired) {
-                    data.expire();
-                }
-
-                // Otherwise, convert the remaining columns and return the session state.
-                let state = SessionState {
-                    data,
-                    token: state.token.into(),
-                    last_active: state.last_active_date_time.into(),
-                };
-
-                Ok(state)
-            })
-            .transpose()
+        state.map(session_state_from_model).transpose()
     }
 
     async fn write(&self, session: SessionState<T>, is_new: bool) -> Result<(), SessionStoreError> {
@@ -134,6 +214,8 @@ where
 
         // Insert new value, with data serialized to JSON.
         let status = SessionStatus::from(session.data.progress()); // This cannot be `Expired`.
+        let notification_due = session.data.notification_due();
+        let expires_at = session.data.expires_at();
         let query = session_state::Entity::insert(session_state::ActiveModel {
             r#type: ActiveValue::set(T::TYPE.to_string()),
             token: ActiveValue::set(session.token.into()),
@@ -142,6 +224,8 @@ where
             ),
             status: ActiveValue::set(status.to_string()),
             last_active_date_time: ActiveValue::set(session.last_active.into()),
+            notification_due_date_time: ActiveValue::set(notification_due.map(Into::into)),
+            expiry_date_time: ActiveValue::set(expires_at.map(Into::into)),
         });
 
         // If this is an existing session, an update is allowed.
@@ -149,7 +233,12 @@ where
             true => query,
             false => query.on_conflict(
                 OnConflict::columns([session_state::PrimaryKey::Type, session_state::PrimaryKey::Token])
-                    .update_columns([session_state::Column::Data, session_state::Column::LastActiveDateTime])
+                    .update_columns([
+                        session_state::Column::Data,
+                        session_state::Column::LastActiveDateTime,
+                        session_state::Column::NotificationDueDateTime,
+                        session_state::Column::ExpiryDateTime,
+                    ])
                     .to_owned(),
             ),
         };
@@ -166,6 +255,37 @@ where
         Ok(())
     }
 
+    async fn write_if_unchanged(
+        &self,
+        session: SessionState<T>,
+        last_active: DateTime<Utc>,
+    ) -> Result<bool, SessionStoreError> {
+        let notification_due = session.data.notification_due();
+        let expires_at = session.data.expires_at();
+        let data = serde_json::to_value(session.data).map_err(|e| SessionStoreError::Serialize(Box::new(e)))?;
+
+        // Only update the row if its last active time is unchanged, which is checked atomically by the database.
+        let result = session_state::Entity::update_many()
+            .col_expr(session_state::Column::Data, Expr::value(data))
+            .col_expr(
+                session_state::Column::LastActiveDateTime,
+                Expr::value(session.last_active),
+            )
+            .col_expr(
+                session_state::Column::NotificationDueDateTime,
+                Expr::value(notification_due),
+            )
+            .col_expr(session_state::Column::ExpiryDateTime, Expr::value(expires_at))
+            .filter(session_state::Column::Type.eq(T::TYPE.to_string()))
+            .filter(session_state::Column::Token.eq(session.token.to_string()))
+            .filter(session_state::Column::LastActiveDateTime.eq(last_active))
+            .exec(&self.connection)
+            .await
+            .map_err(|e| SessionStoreError::Other(e.into()))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
     async fn cleanup(&self) -> Result<(), SessionStoreError> {
         let now = self.time.generate();
         let succeeded_cutoff = now - self.timeouts.successful_deletion;
@@ -175,9 +295,11 @@ where
         self.connection
             .transaction::<_, (), DbErr>(|transaction| {
                 Box::pin(async move {
-                    // Remove all succeeded sessions that are older than the "successful_deletion" timeout.
+                    // Remove all succeeded sessions that are older than the "successful_deletion" timeout,
+                    // unless they still have a notification to send. This also applies to the queries below.
                     session_state::Entity::delete_many()
                         .filter(session_state::Column::Type.eq(T::TYPE.to_string()))
+                        .filter(session_state::Column::NotificationDueDateTime.is_null())
                         .filter(session_state::Column::Status.eq(SessionStatus::Succeeded.to_string()))
                         .filter(session_state::Column::LastActiveDateTime.lt(succeeded_cutoff))
                         .exec(transaction)
@@ -186,6 +308,7 @@ where
                     // Remove all failed and expired sessions that are older than the "failed_deletion" timeout.
                     session_state::Entity::delete_many()
                         .filter(session_state::Column::Type.eq(T::TYPE.to_string()))
+                        .filter(session_state::Column::NotificationDueDateTime.is_null())
                         .filter(
                             session_state::Column::Status
                                 .is_in([SessionStatus::Failed.to_string(), SessionStatus::Expired.to_string()]),
@@ -194,8 +317,8 @@ where
                         .exec(transaction)
                         .await?;
 
-                    // For all active sessions that are older than the "expiration" timeout,
-                    // update the last active time and set the status to expired.
+                    // For all active sessions that are older than the "expiration" timeout, or that are past their own
+                    // expiry time, update the last active time and set the status to expired.
                     session_state::Entity::update_many()
                         .col_expr(
                             session_state::Column::Status,
@@ -203,8 +326,17 @@ where
                         )
                         .col_expr(session_state::Column::LastActiveDateTime, Expr::value(now))
                         .filter(session_state::Column::Type.eq(T::TYPE.to_string()))
+                        .filter(session_state::Column::NotificationDueDateTime.is_null())
                         .filter(session_state::Column::Status.eq(SessionStatus::Active.to_string()))
-                        .filter(session_state::Column::LastActiveDateTime.lt(expiry_cutoff))
+                        .filter(
+                            Condition::any()
+                                .add(
+                                    Condition::all()
+                                        .add(session_state::Column::ExpiryDateTime.is_null())
+                                        .add(session_state::Column::LastActiveDateTime.lt(expiry_cutoff)),
+                                )
+                                .add(session_state::Column::ExpiryDateTime.lt(now)),
+                        )
                         .exec(transaction)
                         .await?;
 
@@ -216,4 +348,81 @@ where
 
         Ok(())
     }
+
+    async fn claim_pending_notifications(
+        &self,
+        claim_duration: Duration,
+    ) -> Result<Vec<SessionState<T>>, SessionStoreError> {
+        let now = self.time.generate();
+        let claimed_until = now + claim_duration;
+
+        // Lock the due rows, skipping those that are being claimed by another instance, and postpone their notification
+        // due time. Writing the session resets the column to the due time reported by the session data.
+        let states = self
+            .connection
+            .transaction(|transaction| {
+                Box::pin(async move {
+                    let states = session_state::Entity::find()
+                        .filter(session_state::Column::Type.eq(T::TYPE.to_string()))
+                        .filter(session_state::Column::NotificationDueDateTime.lte(now))
+                        .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+                        .all(transaction)
+                        .await?;
+
+                    if !states.is_empty() {
+                        session_state::Entity::update_many()
+                            .col_expr(
+                                session_state::Column::NotificationDueDateTime,
+                                Expr::value(claimed_until),
+                            )
+                            .filter(session_state::Column::Type.eq(T::TYPE.to_string()))
+                            .filter(session_state::Column::Token.is_in(states.iter().map(|state| state.token.clone())))
+                            .exec(transaction)
+                            .await?;
+                    }
+
+                    Ok::<_, DbErr>(states)
+                })
+            })
+            .await
+            .map_err(|e| SessionStoreError::Other(e.into()))?;
+
+        states.into_iter().map(session_state_from_model).collect()
+    }
+
+    async fn subscribe(&self) -> Result<broadcast::Receiver<SessionToken>, SessionStoreError> {
+        self.changes
+            .listener_task
+            .get_or_try_init(|| start_listener(&self.connection, Arc::downgrade(&self.changes)))
+            .await
+            .map_err(|e| SessionStoreError::Other(e.into()))?;
+
+        Ok(self.changes.subscribe(T::TYPE))
+    }
+}
+
+fn session_state_from_model<T>(state: session_state::Model) -> Result<SessionState<T>, SessionStoreError>
+where
+    T: Expirable + DeserializeOwned,
+{
+    // Decode both the status and data columns.
+    let status = state
+        .status
+        .parse::<SessionStatus>()
+        .map_err(|e| SessionStoreError::Deserialize(e.into()))?;
+    let mut data = serde_json::from_value::<T>(state.data).map_err(|e| SessionStoreError::Deserialize(e.into()))?;
+
+    // If the status is expired, expire the data.
+    if matches!(status, SessionStatus::Expired) {
+        data.expire();
+    }
+
+    // Otherwise, convert the remaining columns and return the session state.
+    let state = SessionState {
+        data,
+        token: state.token.into(),
+        last_active: state.last_active_date_time.into(),
+    };
+
+    Ok(state)
 }
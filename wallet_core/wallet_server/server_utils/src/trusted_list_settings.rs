@@ -0,0 +1,135 @@
+use chrono::DateTime;
+use chrono::Utc;
+use crypto::trust_anchor::BorrowingTrustAnchor;
+use crypto::trusted_list::FetchedTrustedLists;
+use crypto::trusted_list::TrustedListError;
+use crypto::trusted_list::TrustedListFetcher;
+use serde::Deserialize;
+use serde_with::base64::Base64;
+use serde_with::serde_as;
+use url::Url;
+use utils::generator::Generator;
+
+/// An ETSI TS 119 612 trusted list, or list of trusted lists, from which trust anchors are loaded on startup. The
+/// certificates of granted services of the configured service types are added to the respective trust anchors.
+#[serde_as]
+#[derive(Clone, Deserialize)]
+pub struct TrustedListSettings {
+    /// Location of the trusted list, either a `https://` or a `file://` URL.
+    pub url: Url,
+
+    /// Certificates one of which the trusted list is expected to be signed with.
+    #[serde_as(as = "Vec<Base64>")]
+    pub signing_certificates: Vec<Vec<u8>>,
+
+    #[serde(default)]
+    pub issuer_service_types: Vec<String>,
+
+    #[serde(default)]
+    pub wrpac_service_types: Vec<String>,
+
+    #[serde(default)]
+    pub wrprc_service_types: Vec<String>,
+
+    /// Whether to continue with the trust anchors of the remaining lists when one of the lists pointed to by a list of
+    /// trusted lists cannot be fetched or verified. By default, this fails loading the trusted list altogether.
+    #[serde(default)]
+    pub allow_failing_pointers: bool,
+}
+
+/// Trust anchors obtained from a trusted list, grouped by the trust anchors they extend.
+#[derive(Debug, Default)]
+pub struct TrustedListAnchors {
+    pub issuer: Vec<BorrowingTrustAnchor>,
+    pub wrpac: Vec<BorrowingTrustAnchor>,
+    pub wrprc: Vec<BorrowingTrustAnchor>,
+}
+
+impl TrustedListSettings {
+    /// Fetch and verify the trusted list, including the lists it points to, and collect the trust anchors for the
+    /// configured service types.
+    pub async fn load(
+        &self,
+        fetcher: &TrustedListFetcher,
+        time: &impl Generator<DateTime<Utc>>,
+    ) -> Result<TrustedListAnchors, TrustedListError> {
+        let FetchedTrustedLists {
+            trusted_lists,
+            failed_pointers,
+        } = fetcher
+            .fetch_with_pointers(&self.url, self.signing_certificates.as_slice(), time)
+            .await?;
+
+        if !failed_pointers.is_empty() && !self.allow_failing_pointers {
+            return Err(TrustedListError::FailedPointers(
+                failed_pointers.into_iter().map(|(url, _)| url).collect(),
+            ));
+        }
+
+        let mut anchors = TrustedListAnchors::default();
+        for trusted_list in &trusted_lists {
+            for (service_types, target) in [
+                (&self.issuer_service_types, &mut anchors.issuer),
+                (&self.wrpac_service_types, &mut anchors.wrpac),
+                (&self.wrprc_service_types, &mut anchors.wrprc),
+            ] {
+                for service_type in service_types {
+                    target.extend(trusted_list.trust_anchors(service_type)?);
+                }
+            }
+        }
+
+        Ok(anchors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use crypto::server_keys::generate::Ca;
+    use crypto::trusted_list::SERVICE_TYPE_CA_PKC;
+    use crypto::trusted_list::SERVICE_TYPE_CA_QC;
+    use crypto::trusted_list::mock::signed_trusted_list_xml;
+    use http_utils::reqwest::default_reqwest_client_builder;
+    use utils::generator::mock::MockTimeGenerator;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_trusted_list_settings_load_from_file() {
+        let operator_ca = Ca::generate_mock();
+        let operator = operator_ca.generate_wrpac_verifier_mock().unwrap();
+        let issuer_ca = Ca::generate_issuer_mock_ca().unwrap();
+        let wrpac_ca = Ca::generate_wrpac_mock_ca().unwrap();
+
+        let xml = signed_trusted_list_xml(
+            &[
+                (SERVICE_TYPE_CA_QC, issuer_ca.certificate().as_ref()),
+                (SERVICE_TYPE_CA_PKC, wrpac_ca.certificate().as_ref()),
+            ],
+            &[],
+            Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap(),
+            &operator,
+        );
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("trusted_list.xml");
+        std::fs::write(&path, xml).unwrap();
+
+        let settings = TrustedListSettings {
+            url: Url::from_file_path(&path).unwrap(),
+            signing_certificates: vec![operator.certificate().to_vec()],
+            issuer_service_types: vec![SERVICE_TYPE_CA_QC.to_string()],
+            wrpac_service_types: vec![SERVICE_TYPE_CA_PKC.to_string()],
+            wrprc_service_types: vec![],
+            allow_failing_pointers: false,
+        };
+
+        let fetcher = TrustedListFetcher::new(default_reqwest_client_builder().build().unwrap());
+        let anchors = settings.load(&fetcher, &MockTimeGenerator::epoch()).await.unwrap();
+
+        assert_eq!(anchors.issuer, vec![issuer_ca.to_borrowing_trust_anchor()]);
+        assert_eq!(anchors.wrpac, vec![wrpac_ca.to_borrowing_trust_anchor()]);
+        assert!(anchors.wrprc.is_empty());
+    }
+}
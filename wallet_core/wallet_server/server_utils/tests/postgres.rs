@@ -10,6 +10,7 @@ use openid4vc::server_state::Progress;
 use openid4vc::server_state::SessionDataType;
 use openid4vc::server_state::SessionStoreTimeouts;
 use openid4vc::server_state::test;
+use openid4vc::server_state::test::NotificationData;
 use openid4vc::server_state::test::RandomData;
 use parking_lot::RwLock;
 use serde::Deserialize;
@@ -23,6 +24,7 @@ struct MockSessionData {
     #[serde(with = "ProgressDef")]
     progress: Progress,
     is_expired: bool,
+    notification_due: Option<DateTime<Utc>>,
     data: Vec<u8>,
 }
 
@@ -38,6 +40,7 @@ impl MockSessionData {
         Self {
             progress,
             is_expired: false,
+            notification_due: None,
             data: crypto::utils::random_bytes(32),
         }
     }
@@ -53,6 +56,10 @@ impl HasProgress for MockSessionData {
     fn progress(&self) -> Progress {
         self.progress
     }
+
+    fn notification_due(&self) -> Option<DateTime<Utc>> {
+        self.notification_due
+    }
 }
 
 impl Expirable for MockSessionData {
@@ -71,6 +78,15 @@ impl RandomData for MockSessionData {
     }
 }
 
+impl NotificationData for MockSessionData {
+    fn new_with_notification_due(notification_due: DateTime<Utc>) -> Self {
+        Self {
+            notification_due: Some(notification_due),
+            ..Self::new(Progress::Finished { has_succeeded: true })
+        }
+    }
+}
+
 impl SessionDataType for MockSessionData {
     const TYPE: &'static str = "mockdata";
 }
@@ -104,6 +120,22 @@ async fn test_get_write() {
     test::test_session_store_get_write::<MockSessionData>(&session_store).await;
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_write_if_unchanged() {
+    let db_setup = DbSetup::create().await;
+    let session_store = postgres_session_store(&db_setup).await;
+
+    test::test_session_store_write_if_unchanged::<MockSessionData>(&session_store).await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_subscribe() {
+    let db_setup = DbSetup::create().await;
+    let session_store = postgres_session_store(&db_setup).await;
+
+    test::test_session_store_subscribe::<MockSessionData>(&session_store).await;
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_cleanup_expiration() {
     let db_setup = DbSetup::create().await;
@@ -142,3 +174,11 @@ async fn test_cleanup_failed_deletion() {
     )
     .await;
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_claim_pending_notifications() {
+    let db_setup = DbSetup::create().await;
+    let (session_store, mock_time) = postgres_session_store_with_mock_time(&db_setup).await;
+
+    test::test_session_store_claim_pending_notifications::<MockSessionData>(&session_store, mock_time.as_ref()).await;
+}
@@ -30,7 +30,8 @@ async fn main_impl(settings: VerifierSettings) -> Result<()> {
     ));
 
     // Note that HTTP is explicitly allowed for the retrieval of status lists.
-    let status_list_client = HttpStatusListClient::new(default_reqwest_client_builder())?;
+    let status_list_client = HttpStatusListClient::new(default_reqwest_client_builder())?
+        .with_format(settings.status_list_token_cache_settings.format);
 
     // This will block until the server shuts down.
     server::serve(settings, hsm, sessions, status_list_client).await
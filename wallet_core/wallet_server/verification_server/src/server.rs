@@ -2,17 +2,25 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use axum::Router;
+use crypto::x509::revocation::RevocationChecker;
 use hsm::service::Pkcs11Hsm;
 use http_utils::health::create_health_router;
+use http_utils::reqwest::HttpClient;
+use http_utils::reqwest::default_reqwest_client_builder;
 use openid4vc::server_state::SessionStore;
 use openid4vc::verifier::DisclosureData;
+use openid4vc::webhook::HttpWebhookClient;
 use openid4vc_server::verifier::VerifierFactory;
+use sd_jwt::jwt_vc_issuer::HttpJwtVcIssuerMetadataClient;
+use sd_jwt::jwt_vc_issuer::JwtVcIssuerKeyResolver;
+use sd_jwt::jwt_vc_issuer::JwtVcIssuerPolicy;
 use server_utils::server::add_cache_control_no_store_layer;
 use server_utils::server::check_internal_listener_with_settings;
 use server_utils::server::create_internal_listener;
 use server_utils::server::create_wallet_listener;
 use server_utils::server::listen;
 use server_utils::server::secure_internal_router;
+use server_utils::settings::CERTIFICATE_REVOCATION_CACHE_CAPACITY;
 use token_status_list::verification::client::StatusListClient;
 use token_status_list::verification::verifier::RevocationVerifier;
 use tokio::net::TcpListener;
@@ -74,7 +82,9 @@ where
         TimeGenerator,
     );
 
-    let (wallet_disclosure_router, requester_router) = VerifierFactory::new(
+    let webhook_client = HttpWebhookClient::new(HttpClient::try_new(default_reqwest_client_builder())?);
+
+    let verifier_factory = VerifierFactory::new(
         settings.public_url.join_base_url("disclosure/sessions"),
         settings.universal_link_base_url,
         usecases,
@@ -82,7 +92,29 @@ where
         settings.wallet_client_ids,
         settings.extending_vct_values.unwrap_or_default(),
     )
-    .create_routers(settings.allow_origins, disclosure_sessions, revocation_verifier, None);
+    .with_webhook_client(Arc::new(webhook_client));
+
+    let verifier_factory = match settings.jwt_vc_issuer_key_resolution {
+        Some(key_resolution) => verifier_factory.with_jwt_vc_issuer_key_resolver(JwtVcIssuerKeyResolver::new(
+            Arc::new(HttpJwtVcIssuerMetadataClient::new(default_reqwest_client_builder())?),
+            JwtVcIssuerPolicy::new(key_resolution.allowed_issuers),
+            key_resolution.jwks_cache_capacity,
+            key_resolution.jwks_cache_ttl,
+        )),
+        None => verifier_factory,
+    };
+
+    let verifier_factory = match settings.server_settings.certificate_revocation {
+        Some(policy) => verifier_factory.with_certificate_revocation_checker(RevocationChecker::new_with_client(
+            policy,
+            default_reqwest_client_builder().build()?,
+            CERTIFICATE_REVOCATION_CACHE_CAPACITY,
+        )),
+        None => verifier_factory,
+    };
+
+    let (wallet_disclosure_router, requester_router) =
+        verifier_factory.create_routers(settings.allow_origins, disclosure_sessions, revocation_verifier, None);
 
     let requester_router = secure_internal_router(&settings.server_settings.internal_server, requester_router);
     listen(
@@ -1,11 +1,13 @@
 use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 
 use config::Config;
 use config::ConfigError;
 use config::Environment;
 use config::File;
+use crypto::x509::CertificateError;
 use dcql::Query;
 use derive_more::AsRef;
 use derive_more::From;
@@ -15,7 +17,9 @@ use hsm::service::Pkcs11Hsm;
 use http_utils::urls::BaseUrl;
 use http_utils::urls::CorsOrigin;
 use http_utils::urls::DEFAULT_UNIVERSAL_LINK_BASE;
+use http_utils::urls::HttpsUri;
 use nutype::nutype;
+use openid4vc::openid4vp::ClientId;
 use openid4vc::return_url::ReturnUrlTemplate;
 use openid4vc::server_state::SessionStore;
 use openid4vc::server_state::SessionStoreTimeouts;
@@ -23,9 +27,12 @@ use openid4vc::verifier::DisclosureData;
 use openid4vc::verifier::RpInitiatedUseCase;
 use openid4vc::verifier::RpInitiatedUseCases;
 use openid4vc::verifier::SessionTypeReturnUrl;
+use openid4vc::verifier::UseCaseClientIdScheme;
 use openid4vc::verifier::UseCaseData;
+use openid4vc::webhook::Webhook;
 use ring::hmac;
 use serde::Deserialize;
+use serde_with::DurationSeconds;
 use serde_with::hex::Hex;
 use serde_with::serde_as;
 use server_utils::keys::PrivateKeyVariant;
@@ -69,20 +76,61 @@ pub struct VerifierSettings {
     #[serde(default)]
     pub status_list_token_cache_settings: StatusListTokenCacheSettings,
 
+    /// If set, SD-JWTs without an `x5c` header are verified by resolving the issuer key using JWT VC Issuer Metadata.
+    pub jwt_vc_issuer_key_resolution: Option<JwtVcIssuerKeyResolutionSettings>,
+
     #[serde(flatten)]
     pub server_settings: Settings,
 }
 
+#[serde_as]
+#[derive(Clone, Deserialize)]
+pub struct JwtVcIssuerKeyResolutionSettings {
+    /// The issuer identifiers for which the issuer key may be resolved, all other issuers are rejected.
+    pub allowed_issuers: Vec<HttpsUri>,
+
+    #[serde(default = "default_jwks_cache_capacity")]
+    pub jwks_cache_capacity: u64,
+
+    #[serde(default = "default_jwks_cache_ttl", rename = "jwks_cache_ttl_in_sec")]
+    #[serde_as(as = "DurationSeconds")]
+    pub jwks_cache_ttl: Duration,
+}
+
+fn default_jwks_cache_capacity() -> u64 {
+    100
+}
+
+fn default_jwks_cache_ttl() -> Duration {
+    Duration::from_secs(60 * 60)
+}
+
 #[derive(Clone, From, AsRef, IntoIterator, Deserialize)]
 pub struct UseCasesSettings(HashMap<String, UseCaseSettings>);
 
 #[nutype(validate(predicate = |v| v.len() >= MIN_KEY_LENGTH_BYTES), derive(Clone, TryFrom, AsRef, Deserialize))]
 pub struct EphemeralIdSecret(Vec<u8>);
 
+#[derive(Debug, thiserror::Error)]
+pub enum VerifierSettingsValidationError {
+    #[error("error verifying certificate: {0}")]
+    CertificateVerification(#[from] CertificateVerificationError),
+    #[error("could not determine client_id of usecase `{1}`: {0}")]
+    ClientId(#[source] CertificateError, String),
+    #[error("host of public_url {public_url} does not match client_id {client_id} of usecase `{use_case_id}`")]
+    PublicUrlHostMismatch {
+        use_case_id: String,
+        client_id: ClientId,
+        public_url: BaseUrl,
+    },
+}
+
 #[derive(Clone, Deserialize)]
 pub struct UseCaseSettings {
     #[serde(default)]
     pub session_type_return_url: SessionTypeReturnUrl,
+    #[serde(default)]
+    pub client_id_scheme: UseCaseClientIdScheme,
     #[serde(flatten)]
     pub key_pair: KeyPair,
 
@@ -94,6 +142,9 @@ pub struct UseCaseSettings {
 
     #[serde(default)]
     pub accept_undetermined_revocation_status: bool,
+
+    // Notify the requester at this webhook when a session of this usecase has finished.
+    pub webhook: Option<Webhook>,
 }
 
 impl UseCasesSettings {
@@ -122,11 +173,16 @@ impl UseCasesSettings {
 impl UseCaseSettings {
     pub async fn parse(self, hsm: Option<Pkcs11Hsm>) -> Result<RpInitiatedUseCase<PrivateKeyVariant>, anyhow::Error> {
         let use_case = RpInitiatedUseCase::new(
-            UseCaseData::new(self.key_pair.parse(hsm).await?, self.session_type_return_url),
+            UseCaseData::try_new_with_client_id_scheme(
+                self.key_pair.parse(hsm).await?,
+                self.session_type_return_url,
+                self.client_id_scheme,
+            )?,
             self.dcql_query.map(TryInto::try_into).transpose()?,
             self.return_url_template,
             self.disclosure_base_deep_link,
             self.accept_undetermined_revocation_status,
+            self.webhook,
         );
 
         Ok(use_case)
@@ -140,7 +196,7 @@ impl From<&EphemeralIdSecret> for hmac::Key {
 }
 
 impl ServerSettings for VerifierSettings {
-    type ValidationError = CertificateVerificationError;
+    type ValidationError = VerifierSettingsValidationError;
 
     fn new(config_file: &str, env_prefix: &str) -> Result<Self, ConfigError> {
         let default_store_timeouts = SessionStoreTimeouts::default();
@@ -192,7 +248,7 @@ impl ServerSettings for VerifierSettings {
         Ok(config)
     }
 
-    fn validate(&self) -> Result<(), CertificateVerificationError> {
+    fn validate(&self) -> Result<(), VerifierSettingsValidationError> {
         tracing::debug!("verifying verifier.usecases certificates");
 
         let time = TimeGenerator;
@@ -206,10 +262,36 @@ impl ServerSettings for VerifierSettings {
 
         verify_key_pairs(&key_pairs, &self.server_settings.wrpac_trust_anchors, None, &time)?;
 
+        // The wallet requires the host of the `response_uri` to equal an `x509_san_dns` client_id. As the
+        // `response_uri` is derived from `public_url`, check that this holds for every such use case.
+        for (use_case_id, usecase) in self.usecases.as_ref() {
+            if !matches!(usecase.client_id_scheme, UseCaseClientIdScheme::X509SanDns) {
+                continue;
+            }
+
+            let client_id = usecase
+                .client_id_scheme
+                .client_id_for_certificate(&usecase.key_pair.certificate)
+                .map_err(|error| VerifierSettingsValidationError::ClientId(error, use_case_id.clone()))?;
+
+            let public_url_client_id = self.public_url.as_ref().host_str().map(ClientId::x509_san_dns);
+            if public_url_client_id.as_ref() != Some(&client_id) {
+                return Err(VerifierSettingsValidationError::PublicUrlHostMismatch {
+                    use_case_id: use_case_id.clone(),
+                    client_id,
+                    public_url: self.public_url.clone(),
+                });
+            }
+        }
+
         Ok(())
     }
 
     fn server_settings(&self) -> &Settings {
         &self.server_settings
     }
+
+    fn server_settings_mut(&mut self) -> &mut Settings {
+        &mut self.server_settings
+    }
 }
@@ -6,19 +6,23 @@ use crypto::server_keys::generate::Ca;
 use crypto::trust_anchor::TrustAnchors;
 use crypto::x509::CertificateError;
 use openid4vc::verifier::SessionTypeReturnUrl;
+use openid4vc::verifier::UseCaseClientIdScheme;
 use server_utils::settings::CertificateVerificationError;
 use server_utils::settings::ServerSettings;
 use verification_server::settings::UseCaseSettings;
 use verification_server::settings::VerifierSettings;
+use verification_server::settings::VerifierSettingsValidationError;
 
 fn to_use_case(key_pair: KeyPair) -> UseCaseSettings {
     UseCaseSettings {
         session_type_return_url: SessionTypeReturnUrl::Both,
+        client_id_scheme: UseCaseClientIdScheme::X509Hash,
         key_pair: key_pair.into(),
         dcql_query: None,
         return_url_template: None,
         disclosure_base_deep_link: None,
         accept_undetermined_revocation_status: false,
+        webhook: None,
     }
 }
 
@@ -58,7 +62,10 @@ fn test_settings_no_wrpac_trust_anchors() {
     settings.server_settings.wrpac_trust_anchors = TrustAnchors::empty();
 
     let error = settings.validate().expect_err("should fail");
-    assert_matches!(error, CertificateVerificationError::MissingTrustAnchors);
+    assert_matches!(
+        error,
+        VerifierSettingsValidationError::CertificateVerification(CertificateVerificationError::MissingTrustAnchors)
+    );
 }
 
 #[test]
@@ -81,6 +88,43 @@ fn test_settings_wrong_wrpac_ca() {
     let error = settings.validate().expect_err("should fail");
     assert_matches!(
         error,
-        CertificateVerificationError::InvalidCertificate(CertificateError::Verification(_), key) if key == "wrong_ca"
+        VerifierSettingsValidationError::CertificateVerification(
+            CertificateVerificationError::InvalidCertificate(CertificateError::Verification(_), key)
+        ) if key == "wrong_ca"
+    );
+}
+
+#[test]
+fn test_settings_x509_san_dns_public_url() {
+    let mut settings =
+        VerifierSettings::new("verification_server.toml", "verification_server").expect("default settings");
+    settings.public_url = "https://rp.example.com/".parse().unwrap();
+
+    let wrpac_ca = Ca::generate_wrpac_mock_ca().expect("generate WRPAC CA");
+    let wrpac_cert = wrpac_ca
+        .generate_wrpac_verifier_san_dns_mock("rp.example.com")
+        .expect("generate wrpac cert");
+
+    let mut usecases: HashMap<String, UseCaseSettings> = HashMap::new();
+    usecases.insert(
+        "san_dns".to_string(),
+        UseCaseSettings {
+            client_id_scheme: UseCaseClientIdScheme::X509SanDns,
+            ..to_use_case(wrpac_cert)
+        },
+    );
+
+    settings.usecases = usecases.into();
+    settings.server_settings.wrpac_trust_anchors = TrustAnchors::from(&wrpac_ca);
+
+    settings.validate().expect("should succeed");
+
+    // The wallet will reject the response_uri if its host does not match the client_id.
+    settings.public_url = "https://other.example.com/".parse().unwrap();
+
+    let error = settings.validate().expect_err("should fail");
+    assert_matches!(
+        error,
+        VerifierSettingsValidationError::PublicUrlHostMismatch { use_case_id, .. } if use_case_id == "san_dns"
     );
 }
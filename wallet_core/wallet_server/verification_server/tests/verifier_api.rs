@@ -50,6 +50,7 @@ use openid4vc::verifier::DisclosureData;
 use openid4vc::verifier::SessionType;
 use openid4vc::verifier::SessionTypeReturnUrl;
 use openid4vc::verifier::StatusResponse;
+use openid4vc::verifier::UseCaseClientIdScheme;
 use openid4vc_server::verifier::StartDisclosureRequest;
 use openid4vc_server::verifier::StartDisclosureResponse;
 use openid4vc_server::verifier::StatusParams;
@@ -141,11 +142,13 @@ async fn wallet_server_settings_and_listener(
         USECASE_NAME.to_string(),
         UseCaseSettings {
             session_type_return_url: SessionTypeReturnUrl::SameDevice,
+            client_id_scheme: UseCaseClientIdScheme::X509Hash,
             key_pair: usecase_keypair.into(),
             dcql_query: None,
             return_url_template: None,
             disclosure_base_deep_link: None,
             accept_undetermined_revocation_status: false,
+            webhook: None,
         },
     )])
     .into();
@@ -164,6 +167,8 @@ async fn wallet_server_settings_and_listener(
         issuer_trust_anchors,
         wrpac_trust_anchors: TrustAnchors::empty(),
         wrprc_trust_anchors: TrustAnchors::empty(),
+        trusted_lists: vec![],
+        certificate_revocation: None,
 
         hsm: None,
     };
@@ -185,6 +190,8 @@ async fn wallet_server_settings_and_listener(
         extending_vct_values: None,
 
         status_list_token_cache_settings: StatusListTokenCacheSettings::default(),
+
+        jwt_vc_issuer_key_resolution: None,
     };
 
     (settings, listener, issuer_ca, wrpac_trust_anchors)
@@ -555,6 +562,25 @@ async fn get_status_ok(client: &Client, status_url: Url) -> StatusResponse {
     response.json::<StatusResponse>().await.unwrap()
 }
 
+/// Read the next server-sent event containing a status from `response`, or `None` if the stream has ended.
+async fn next_status_event(response: &mut Response, buffer: &mut String) -> Option<StatusResponse> {
+    loop {
+        if let Some(end) = buffer.find("\n\n") {
+            let event = buffer.drain(..end + 2).collect::<String>();
+
+            // Skip any events without data, such as keep-alive comments.
+            if let Some(data) = event.lines().find_map(|line| line.strip_prefix("data:")) {
+                return Some(serde_json::from_str(data.trim()).unwrap());
+            }
+
+            continue;
+        }
+
+        let chunk = response.chunk().await.unwrap()?;
+        buffer.push_str(str::from_utf8(&chunk).unwrap());
+    }
+}
+
 async fn start_disclosure<S>(
     disclosure_sessions: Arc<S>,
     request: &StartDisclosureRequest,
@@ -615,6 +641,47 @@ async fn test_disclosure_missing_session_type() {
     );
 }
 
+#[tokio::test]
+async fn test_disclosure_status_events() {
+    let (settings, client, session_token, _, _, _) = start_disclosure(
+        Arc::new(MemorySessionStore::default()),
+        &EXAMPLE_START_DISCLOSURE_REQUEST,
+    )
+    .await;
+
+    let mut events_url = format_status_url(&settings.public_url, &session_token, Some(SessionType::SameDevice));
+    events_url.path_segments_mut().unwrap().push("events");
+
+    let mut response = client.get(events_url).send().await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get(http::header::CONTENT_TYPE).unwrap(),
+        "text/event-stream"
+    );
+
+    // The first event should contain the current status of the session.
+    let mut buffer = String::new();
+    assert_matches!(
+        next_status_event(&mut response, &mut buffer).await,
+        Some(StatusResponse::Created { ul: Some(_) })
+    );
+
+    // Cancelling the session should result in a new event, after which the stream ends.
+    let cancel_url = settings
+        .public_url
+        .join(&format!("disclosure/sessions/{session_token}"));
+    let response_cancel = client.delete(cancel_url).send().await.unwrap();
+
+    assert_eq!(response_cancel.status(), StatusCode::NO_CONTENT);
+
+    assert_matches!(
+        next_status_event(&mut response, &mut buffer).await,
+        Some(StatusResponse::Cancelled)
+    );
+    assert_matches!(next_status_event(&mut response, &mut buffer).await, None);
+}
+
 #[tokio::test]
 async fn test_disclosure_cancel() {
     let (settings, client, session_token, internal_url, _, _) = start_disclosure(